@@ -1,3 +1,11 @@
+// Backends the browser may connect to. The address can be changed at runtime in Settings →
+// Połączenie z serwerem, so a LAN host or remote server has to be listed in
+// NEXT_PUBLIC_ALLOWED_BACKEND_ORIGINS (space or comma separated) at build time.
+const allowedBackendOrigins = (process.env.NEXT_PUBLIC_ALLOWED_BACKEND_ORIGINS || 'http://localhost:8000 http://backend:8000')
+  .split(/[\s,]+/)
+  .filter(Boolean);
+const connectSrc = ["'self'", ...allowedBackendOrigins, 'https://api.github.com'].join(' ');
+
 /** @type {import('next').NextConfig} */
 const nextConfig = {
   reactStrictMode: true,
@@ -40,7 +48,7 @@ const nextConfig = {
               "style-src 'self' 'unsafe-inline' https://fonts.googleapis.com",
               "font-src 'self' https://fonts.gstatic.com",
               "img-src 'self' data: blob: https:",
-              "connect-src 'self' http://localhost:8000 http://backend:8000",
+              `connect-src ${connectSrc}`,
               "frame-ancestors 'none'",
               "base-uri 'self'",
               "form-action 'self'",
@@ -114,6 +122,9 @@ const nextConfig = {
   // Environment variables validation
   env: {
     CUSTOM_KEY: process.env.CUSTOM_KEY,
+    NEXT_PUBLIC_APP_VERSION: require('./package.json').version,
+    // Settings warn about backend addresses the CSP above would block
+    NEXT_PUBLIC_ALLOWED_BACKEND_ORIGINS: allowedBackendOrigins.join(' '),
   },
 };
 
@@ -0,0 +1,127 @@
+'use client';
+
+import React, { useState } from 'react';
+import { Download } from 'lucide-react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Button } from '@/components/ui/Button';
+import { LoadingState } from '@/components/ui/LoadingState';
+import { DateRangeSelector, RangePreset, getPresetRange } from '@/components/analytics/DateRangeSelector';
+import { MonthlySpendingChart } from '@/components/analytics/MonthlySpendingChart';
+import { CategoryPieChart } from '@/components/analytics/CategoryPieChart';
+import { StoreComparisonChart } from '@/components/analytics/StoreComparisonChart';
+import { MonthlyReportView } from '@/components/analytics/MonthlyReportView';
+import { BudgetCard } from '@/components/budget/BudgetCard';
+import { useSpendingStats } from '@/hooks/useSpendingStats';
+import { useTranslation } from '@/hooks/useTranslation';
+import { downloadFile, toCsv } from '@/lib/exporters';
+import type { MessageKey } from '@/lib/i18n';
+import { formatMoney, money, toDecimalString } from '@/lib/money';
+import { DateRange, SpendingStats } from '@/types/analytics';
+
+function exportStats(stats: SpendingStats, range: DateRange, t: (key: MessageKey) => string) {
+  const sections: [string, SpendingStats['monthly']][] = [
+    [t('analytics.csv.month'), stats.monthly],
+    [t('analytics.csv.category'), stats.by_category],
+    [t('analytics.csv.store'), stats.by_store],
+  ];
+  const rows = sections.flatMap(([section, buckets]) =>
+    buckets.map((bucket) => [section, bucket.label, toDecimalString(money(bucket.total)), bucket.count]),
+  );
+  downloadFile(toCsv([t('analytics.csv.section'), t('analytics.csv.label'), t('analytics.csv.amount'), t('analytics.csv.count')], rows), `wydatki_${range.from}_${range.to}.csv`, 'csv');
+}
+
+export default function AnalyticsPage() {
+  const { t } = useTranslation();
+  const [preset, setPreset] = useState<RangePreset>('3months');
+  const [range, setRange] = useState<DateRange>(() => getPresetRange('3months'));
+  const { data: stats, isLoading, error } = useSpendingStats(range);
+
+  const handleRangeChange = (nextPreset: RangePreset, nextRange: DateRange) => {
+    setPreset(nextPreset);
+    setRange(nextRange);
+  };
+
+  return (
+    <div className="container mx-auto p-6 space-y-6">
+      <div className="flex flex-wrap items-start justify-between gap-4">
+        <div>
+          <h1 className="text-3xl font-bold text-gray-900 mb-2">{t('analytics.title')}</h1>
+          <p className="text-gray-600">{t('analytics.subtitle')}</p>
+        </div>
+        <Button
+          variant="outline"
+          onClick={() => stats && exportStats(stats, range, t)}
+          disabled={!stats || stats.receipts_count === 0}
+        >
+          <Download className="h-4 w-4 mr-2" />
+          {t('analytics.exportCsv')}
+        </Button>
+      </div>
+
+      <BudgetCard />
+
+      <MonthlyReportView />
+
+      <DateRangeSelector preset={preset} range={range} onChange={handleRangeChange} />
+
+      {isLoading && <LoadingState className="p-8" />}
+
+      {error && (
+        <div className="p-4 text-center text-red-600">
+          {t('analytics.error', { error: (error as Error).message })}
+        </div>
+      )}
+
+      {stats && (
+        <>
+          <div className="grid grid-cols-1 sm:grid-cols-2 gap-4">
+            <Card>
+              <CardHeader>
+                <CardTitle>{t('analytics.total')}</CardTitle>
+              </CardHeader>
+              <CardContent>
+                <p className="text-3xl font-bold">{formatMoney(money(stats.total))}</p>
+              </CardContent>
+            </Card>
+            <Card>
+              <CardHeader>
+                <CardTitle>{t('analytics.receiptsCount')}</CardTitle>
+              </CardHeader>
+              <CardContent>
+                <p className="text-3xl font-bold">{stats.receipts_count}</p>
+              </CardContent>
+            </Card>
+          </div>
+
+          <Card>
+            <CardHeader>
+              <CardTitle>{t('analytics.monthly')}</CardTitle>
+            </CardHeader>
+            <CardContent>
+              <MonthlySpendingChart data={stats.monthly} />
+            </CardContent>
+          </Card>
+
+          <div className="grid grid-cols-1 lg:grid-cols-2 gap-6">
+            <Card>
+              <CardHeader>
+                <CardTitle>{t('analytics.byCategory')}</CardTitle>
+              </CardHeader>
+              <CardContent>
+                <CategoryPieChart data={stats.by_category} />
+              </CardContent>
+            </Card>
+            <Card>
+              <CardHeader>
+                <CardTitle>{t('analytics.byStore')}</CardTitle>
+              </CardHeader>
+              <CardContent>
+                <StoreComparisonChart data={stats.by_store} />
+              </CardContent>
+            </Card>
+          </div>
+        </>
+      )}
+    </div>
+  );
+}
@@ -1,46 +1,84 @@
 'use client';
 
-import React from 'react';
+import React, { useEffect, useState } from 'react';
 import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
 import { FloatingActionButton } from '@/components/ui/FloatingActionButton';
 import { MessageCircle } from 'lucide-react';
+import { ConversationSidebar } from '@/components/chat/ConversationSidebar';
 import { MessageList } from '@/components/chat/MessageList';
 import { MessageInput } from '@/components/chat/MessageInput';
+import { TranscriptActions } from '@/components/chat/TranscriptActions';
 import { useChat } from '@/hooks/useChat';
+import { useTranslation } from '@/hooks/useTranslation';
 
 export default function ChatPage() {
-  const { messages, isLoading, error, sendMessage, usePerplexity, togglePerplexity } = useChat('general');
+  const { t } = useTranslation();
+  const {
+    messages,
+    isLoading,
+    sendMessage,
+    clarify,
+    usePerplexity,
+    togglePerplexity,
+    replyLanguage,
+    setReplyLanguage,
+    attachments,
+    setAttachments,
+    newChat,
+    openConversation,
+    sessionId,
+    togglePin,
+    summarize,
+    isSummarizing,
+  } = useChat('general');
+  const [prompt, setPrompt] = useState('');
+
+  // Deep link web+foodsave://chat?prompt=... wstawia pytanie do pola, bez wysyłania;
+  // ?conversation=... otwiera zapisaną rozmowę (np. z powiadomienia o wyniku rutyny)
+  useEffect(() => {
+    const params = new URLSearchParams(window.location.search);
+    setPrompt(params.get('prompt') ?? '');
+    const conversation = params.get('conversation');
+    if (conversation) openConversation(conversation);
+  }, []);
 
   return (
-    <div className="relative h-[calc(100vh-100px)]">
+    <div className="relative h-[calc(100vh-100px)] md:grid md:grid-cols-[16rem_1fr] md:gap-4">
+      <Card className="hidden md:block h-full p-3">
+        <ConversationSidebar context="general" sessionId={sessionId} onOpen={openConversation} onNew={newChat} />
+      </Card>
       <Card className="h-full flex flex-col">
         <CardHeader>
-          <CardTitle>Asystent AI</CardTitle>
-          {error && (
-            <div className="mt-2 p-2 bg-red-100 text-red-700 rounded">
-              {error}
-            </div>
-          )}
+          <div className="flex flex-wrap items-center justify-between gap-2">
+            <CardTitle>{t('chat.title')}</CardTitle>
+            <TranscriptActions messages={messages} onSummarize={() => void summarize()} isSummarizing={isSummarizing} />
+          </div>
         </CardHeader>
 
         <CardContent className="flex-grow overflow-auto p-4">
-          <MessageList messages={messages} isLoading={isLoading} />
+          <MessageList messages={messages} isLoading={isLoading} onClarify={clarify} onTogglePin={togglePin} />
         </CardContent>
 
         <div className="p-4 border-t">
           <MessageInput
             onSendMessage={sendMessage}
             isLoading={isLoading}
-            placeholder="Zadaj pytanie lub opisz swój problem..."
+            placeholder={t('chat.placeholder')}
+            initialValue={prompt}
+            draftKey="general"
             usePerplexity={usePerplexity}
             onTogglePerplexity={togglePerplexity}
+            replyLanguage={replyLanguage}
+            onChangeReplyLanguage={setReplyLanguage}
+            attachments={attachments}
+            onChangeAttachments={setAttachments}
           />
         </div>
       </Card>
       <FloatingActionButton
-        onClick={() => { /* TODO: Implement new chat functionality */ }}
+        onClick={newChat}
         icon={MessageCircle}
-        label="Nowy czat"
+        label={t('chat.new')}
         variant="extended"
       />
     </div>
@@ -1,31 +1,41 @@
 'use client';
 
-import React from 'react';
+import React, { useState } from 'react';
 import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Modal } from '@/components/ui/Modal';
 import { PantryList } from '@/components/cooking/PantryList';
 import { CookingChat } from '@/components/cooking/CookingChat';
+import { CookedRecipeDialog } from '@/components/cooking/CookedRecipeDialog';
+import { RecipeCard } from '@/components/cooking/RecipeCard';
 import { useCooking } from '@/hooks/useCooking';
+import { useTranslation } from '@/hooks/useTranslation';
+import { Recipe } from '@/types/cooking';
 
 export default function CookingPage() {
+  const { t } = useTranslation();
   const {
     pantryItems,
+    recipes,
     isLoading,
     error,
     addPantryItem,
     updatePantryItem,
     deletePantryItem,
+    mergeDuplicateItems,
     messages,
     sendCookingMessage,
     usePerplexity,
     togglePerplexity,
   } = useCooking();
+  const [openRecipe, setOpenRecipe] = useState<Recipe | null>(null);
+  const [cookedRecipe, setCookedRecipe] = useState<Recipe | null>(null);
 
   return (
     <div className="grid grid-cols-1 lg:grid-cols-2 gap-4 h-full">
       {/* Lewa strona - Zarządzanie spiżarnią */}
       <Card className="flex flex-col">
         <CardHeader>
-          <CardTitle>Moja Spiżarnia</CardTitle>
+          <CardTitle>{t('cooking.pantryTitle')}</CardTitle>
         </CardHeader>
         <CardContent className="flex-grow">
           <PantryList
@@ -33,6 +43,7 @@ export default function CookingPage() {
             onAddItem={addPantryItem}
             onUpdateItem={updatePantryItem}
             onDeleteItem={deletePantryItem}
+            onMergeItems={mergeDuplicateItems}
           />
         </CardContent>
       </Card>
@@ -40,12 +51,53 @@ export default function CookingPage() {
       {/* Prawa strona - Czat o gotowaniu */}
       <Card className="flex flex-col">
         <CardHeader>
-          <CardTitle>Asystent Gotowania</CardTitle>
+          <CardTitle>{t('cooking.assistantTitle')}</CardTitle>
         </CardHeader>
         <CardContent className="flex-grow">
-          <CookingChat />
+          <CookingChat onCooked={setCookedRecipe} />
         </CardContent>
       </Card>
+
+      {recipes.length > 0 && (
+        <Card className="lg:col-span-2">
+          <CardHeader>
+            <CardTitle>{t('cooking.recipesTitle')}</CardTitle>
+          </CardHeader>
+          <CardContent className="grid grid-cols-1 gap-3 sm:grid-cols-2 lg:grid-cols-3">
+            {recipes.map((recipe) => (
+              <RecipeCard key={recipe.id} recipe={recipe} onSelect={setOpenRecipe} onCooked={setCookedRecipe} />
+            ))}
+          </CardContent>
+        </Card>
+      )}
+
+      <Modal open={!!openRecipe} onClose={() => setOpenRecipe(null)} title={openRecipe?.name} size="lg">
+        {openRecipe && (
+          <div className="space-y-4 text-sm">
+            <ul className="list-disc pl-5">
+              {openRecipe.ingredients.map((ingredient) => (
+                <li key={ingredient.id}>
+                  {ingredient.name} – {ingredient.quantity} {ingredient.unit}
+                  {ingredient.optional && <span className="text-gray-500"> {t('cooking.optional')}</span>}
+                </li>
+              ))}
+            </ul>
+            <ol className="list-decimal space-y-1 pl-5">
+              {openRecipe.instructions.map((step, index) => (
+                <li key={index}>{step}</li>
+              ))}
+            </ol>
+          </div>
+        )}
+      </Modal>
+      {cookedRecipe && (
+        <CookedRecipeDialog
+          recipe={cookedRecipe}
+          pantryItems={pantryItems}
+          onClose={() => setCookedRecipe(null)}
+          onUpdateItem={updatePantryItem}
+        />
+      )}
     </div>
   );
 }
@@ -2,13 +2,44 @@
 import { WeatherSection } from '@/components/WeatherSection';
 import { ChatInterface } from '@/components/chat/ChatInterface';
 import { LLMModelSettings } from '@/components/dashboard/LLMModelSettings';
+import { BudgetProgressWidget } from '@/components/budget/BudgetProgressWidget';
+import { RecentReceipts } from '@/components/dashboard/RecentReceipts';
+import { MonthlyReportCard } from '@/components/dashboard/MonthlyReportCard';
+import { RecentActivities } from '@/components/dashboard/RecentActivities';
+import { DashboardToolbar } from '@/components/dashboard/DashboardToolbar';
+import { GreetingHeader } from '@/components/dashboard/GreetingHeader';
+import { QuickActions } from '@/components/dashboard/QuickActions';
+import { ShoppingListPreview } from '@/components/dashboard/ShoppingListPreview';
+import { RemindersWidget } from '@/components/dashboard/RemindersWidget';
 
 export default function Dashboard() {
   return (
     <div className="p-6 space-y-6">
+      <div className="flex flex-wrap items-start justify-between gap-4">
+        <GreetingHeader />
+        <DashboardToolbar />
+      </div>
+
+      {/* Szybkie akcje */}
+      <QuickActions />
+
       {/* Sekcja pogodowa */}
       <WeatherSection />
 
+      {/* Budżet miesięczny, podsumowanie poprzedniego miesiąca i ostatnie paragony */}
+      <div className="grid grid-cols-1 lg:grid-cols-3 gap-6">
+        <BudgetProgressWidget />
+        <MonthlyReportCard />
+        <RecentReceipts />
+      </div>
+
+      {/* Przypomnienia, lista zakupów i ostatnie aktywności */}
+      <div className="grid grid-cols-1 lg:grid-cols-3 gap-6">
+        <RemindersWidget />
+        <ShoppingListPreview />
+        <RecentActivities />
+      </div>
+
       {/* Ustawienia modeli LLM */}
       <LLMModelSettings />
 
@@ -61,12 +61,12 @@ export default function RootLayout({
         <Providers>
           <div className="flex">
             {/* Desktop Sidebar */}
-            <div className="hidden md:block">
+            <div className="hidden md:block" data-focus-region="navigation">
               <SidebarNavigation />
             </div>
 
             {/* Main Content */}
-            <main className="flex-1">
+            <main className="flex-1" data-focus-region="main">
               <div className="p-4">
                 {children}
               </div>
@@ -74,7 +74,7 @@ export default function RootLayout({
           </div>
 
           {/* Mobile Bottom Navigation */}
-          <div className="md:hidden">
+          <div className="md:hidden" data-focus-region="navigation">
             <BottomNavigation />
           </div>
         </Providers>
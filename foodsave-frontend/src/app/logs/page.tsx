@@ -0,0 +1,139 @@
+'use client';
+
+import { useState } from 'react';
+import { Copy, Download, Trash2 } from 'lucide-react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Button } from '@/components/ui/Button';
+import { MetricsPanel } from '@/components/diagnostics/MetricsPanel';
+import { useLogRecords } from '@/hooks/useLogRecords';
+import { useTranslation } from '@/hooks/useTranslation';
+import { downloadFile, toJson } from '@/lib/exporters';
+import {
+  LOG_LEVEL_NAMES,
+  LogLevel,
+  clearLogs,
+  filterLogRecords,
+  formatLogRecords,
+  logModules,
+} from '@/lib/logger';
+import { pushToast } from '@/lib/toasts';
+
+const LEVEL_STYLES: Record<LogLevel, string> = {
+  [LogLevel.DEBUG]: 'text-muted-foreground',
+  [LogLevel.INFO]: 'text-blue-700',
+  [LogLevel.WARN]: 'text-amber-700',
+  [LogLevel.ERROR]: 'text-red-700',
+};
+
+const LEVELS = [LogLevel.DEBUG, LogLevel.INFO, LogLevel.WARN, LogLevel.ERROR];
+
+// Diagnostics page, not linked from the navigation (reachable from Settings → Diagnostics)
+export default function LogsPage() {
+  const { t } = useTranslation();
+  const records = useLogRecords();
+  const [minLevel, setMinLevel] = useState(LogLevel.DEBUG);
+  const [module, setModule] = useState('');
+  const [query, setQuery] = useState('');
+
+  const visible = filterLogRecords(records, { minLevel, module, query });
+
+  const handleCopy = async () => {
+    try {
+      await navigator.clipboard.writeText(formatLogRecords(visible));
+      pushToast(t('logs.copied'), { severity: 'success' });
+    } catch {
+      pushToast(t('logs.copyFailed'), { severity: 'error' });
+    }
+  };
+
+  const handleExport = () => {
+    const stamp = new Date().toISOString().slice(0, 19).replace(/:/g, '-');
+    downloadFile(toJson(visible), `foodsave_logs_${stamp}.json`, 'json');
+  };
+
+  return (
+    <div className="container mx-auto p-6 space-y-6">
+      <div>
+        <h1 className="text-3xl font-bold text-gray-900 mb-2">{t('logs.title')}</h1>
+        <p className="text-gray-600">{t('logs.subtitle')}</p>
+      </div>
+
+      <MetricsPanel />
+
+      <Card>
+        <CardHeader>
+          <div className="flex flex-wrap items-center justify-between gap-2">
+            <CardTitle>{t('logs.count', { count: visible.length })}</CardTitle>
+            <div className="flex flex-wrap gap-2">
+              <Button variant="outline" size="sm" onClick={handleCopy} disabled={visible.length === 0}>
+                <Copy className="h-4 w-4 mr-2" />
+                {t('logs.copy')}
+              </Button>
+              <Button variant="outline" size="sm" onClick={handleExport} disabled={visible.length === 0}>
+                <Download className="h-4 w-4 mr-2" />
+                {t('logs.export')}
+              </Button>
+              <Button variant="outline" size="sm" onClick={clearLogs} disabled={records.length === 0}>
+                <Trash2 className="h-4 w-4 mr-2" />
+                {t('settings.data.clear')}
+              </Button>
+            </div>
+          </div>
+        </CardHeader>
+        <CardContent className="space-y-3">
+          <div className="flex flex-wrap gap-2">
+            <select
+              value={minLevel}
+              onChange={(e) => setMinLevel(Number(e.target.value) as LogLevel)}
+              aria-label={t('logs.level')}
+              className="rounded-md border px-2 py-2 text-sm"
+            >
+              {LEVELS.map((level) => (
+                <option key={level} value={level}>
+                  {LOG_LEVEL_NAMES[level]}+
+                </option>
+              ))}
+            </select>
+            <select
+              value={module}
+              onChange={(e) => setModule(e.target.value)}
+              aria-label={t('logs.module')}
+              className="rounded-md border px-2 py-2 text-sm"
+            >
+              <option value="">{t('logs.allModules')}</option>
+              {logModules(records).map((name) => (
+                <option key={name} value={name}>
+                  {name}
+                </option>
+              ))}
+            </select>
+            <input
+              type="search"
+              value={query}
+              onChange={(e) => setQuery(e.target.value)}
+              placeholder={t('logs.search')}
+              aria-label={t('logs.search')}
+              data-primary-input
+              className="flex-1 min-w-[12rem] rounded-md border px-3 py-2 text-sm"
+            />
+          </div>
+
+          {visible.length === 0 ? (
+            <p className="text-sm text-muted-foreground">{t('logs.empty')}</p>
+          ) : (
+            <ol className="max-h-[60vh] overflow-auto rounded-md border font-mono text-xs divide-y">
+              {[...visible].reverse().map((entry) => (
+                <li key={entry.id} className="px-3 py-1.5">
+                  <span className="text-muted-foreground">{entry.timestamp.slice(11, 23)}</span>{' '}
+                  <span className={`font-semibold ${LEVEL_STYLES[entry.level]}`}>{LOG_LEVEL_NAMES[entry.level]}</span>{' '}
+                  <span className="text-muted-foreground">[{entry.module}]</span> {entry.message}
+                  {entry.details && <pre className="mt-1 whitespace-pre-wrap break-all text-muted-foreground">{entry.details}</pre>}
+                </li>
+              ))}
+            </ol>
+          )}
+        </CardContent>
+      </Card>
+    </div>
+  );
+}
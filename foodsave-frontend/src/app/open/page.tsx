@@ -0,0 +1,17 @@
+'use client';
+
+import { useEffect } from 'react';
+import { useRouter } from 'next/navigation';
+import { deepLinkPath } from '@/lib/deepLinks';
+
+// Cel zarejestrowanego handlera protokołu: /open?uri=web+foodsave://...
+export default function OpenDeepLink() {
+  const router = useRouter();
+
+  useEffect(() => {
+    const uri = new URLSearchParams(window.location.search).get('uri');
+    router.replace(deepLinkPath(uri));
+  }, [router]);
+
+  return null;
+}
@@ -1,5 +1,17 @@
-import { redirect } from 'next/navigation';
+'use client';
+
+import { useEffect } from 'react';
+import { useRouter } from 'next/navigation';
+import { loadSettings } from '@/lib/settings';
+import { startupPath } from '@/lib/startup';
 
 export default function Home() {
-  redirect('/dashboard');
+  const router = useRouter();
+
+  // Ustawienia są w localStorage, więc wybór strony startowej odbywa się po stronie klienta
+  useEffect(() => {
+    router.replace(startupPath(loadSettings().restoreLastPage));
+  }, [router]);
+
+  return null;
 }
@@ -0,0 +1,122 @@
+'use client';
+
+import React, { useState } from 'react';
+import { Bell, Pencil, Repeat, Trash2 } from 'lucide-react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Button } from '@/components/ui/Button';
+import { EmptyState } from '@/components/ui/EmptyState';
+import { ReminderForm } from '@/components/reminders/ReminderForm';
+import { RoutinesCard } from '@/components/reminders/RoutinesCard';
+import { useReminders } from '@/hooks/useReminders';
+import { useTranslation } from '@/hooks/useTranslation';
+import { REPEAT_LABELS, isOverdue } from '@/lib/reminders';
+import { requestNotificationPermission } from '@/lib/notifications';
+
+function formatDue(iso: string, locale: string): string {
+  return new Date(iso).toLocaleString(locale, {
+    weekday: 'short',
+    day: 'numeric',
+    month: 'short',
+    hour: '2-digit',
+    minute: '2-digit',
+  });
+}
+
+export default function RemindersPage() {
+  const { reminders, addReminder, toggleReminder, updateReminder, removeReminder } = useReminders();
+  const { t, locale } = useTranslation();
+  const [editingId, setEditingId] = useState<string | null>(null);
+
+  // Aktywne na górze, potem wg terminu
+  const sorted = [...reminders].sort(
+    (a, b) => Number(a.done) - Number(b.done) || a.dueAt.localeCompare(b.dueAt),
+  );
+
+  return (
+    <div className="container mx-auto p-6 space-y-6">
+      <div>
+        <h1 className="text-3xl font-bold text-gray-900 mb-2">{t('reminders.title')}</h1>
+        <p className="text-gray-600">{t('reminders.subtitle')}</p>
+      </div>
+
+      <Card>
+        <CardHeader>
+          <CardTitle>{t('reminders.new')}</CardTitle>
+        </CardHeader>
+        <CardContent>
+          <ReminderForm
+            onSubmit={({ title, dueAt, repeat }) => {
+              addReminder(title, dueAt, repeat);
+              requestNotificationPermission();
+            }}
+          />
+        </CardContent>
+      </Card>
+
+      <Card>
+        <CardHeader>
+          <CardTitle>{t('reminders.all')}</CardTitle>
+          <p className="text-sm text-muted-foreground">{t('reminders.count', { count: reminders.length })}</p>
+        </CardHeader>
+        <CardContent>
+          {sorted.length === 0 ? (
+            <EmptyState message={t('reminders.empty')} icon={Bell} />
+          ) : (
+            <ul className="divide-y">
+              {sorted.map((reminder) =>
+                editingId === reminder.id ? (
+                  <li key={reminder.id} className="py-3">
+                    <ReminderForm
+                      initial={reminder}
+                      onSubmit={(values) => {
+                        updateReminder(reminder.id, { ...values, dueAt: values.dueAt.toISOString(), done: false });
+                        setEditingId(null);
+                      }}
+                      onCancel={() => setEditingId(null)}
+                    />
+                  </li>
+                ) : (
+                  <li key={reminder.id} className="flex items-center gap-3 py-2">
+                    <input
+                      type="checkbox"
+                      checked={reminder.done}
+                      onChange={() => toggleReminder(reminder.id)}
+                      aria-label={`Wykonane: ${reminder.title}`}
+                      className="h-4 w-4"
+                    />
+                    <div className="flex-1 min-w-0">
+                      <p className={reminder.done ? 'line-through text-gray-400' : ''}>{reminder.title}</p>
+                      <p className={`text-sm ${isOverdue(reminder) ? 'text-red-600' : 'text-gray-500'}`}>
+                        <Bell className="inline h-3 w-3 mr-1" />
+                        {formatDue(reminder.dueAt, locale)}
+                        {reminder.repeat !== 'none' && (
+                          <span className="ml-2">
+                            <Repeat className="inline h-3 w-3 mr-1" />
+                            {t(REPEAT_LABELS[reminder.repeat])}
+                          </span>
+                        )}
+                      </p>
+                    </div>
+                    <Button variant="ghost" size="sm" onClick={() => setEditingId(reminder.id)} aria-label={t('common.edit')}>
+                      <Pencil className="h-4 w-4" />
+                    </Button>
+                    <Button
+                      variant="ghost"
+                      size="sm"
+                      onClick={() => removeReminder(reminder.id)}
+                      aria-label={`${t('common.delete')} ${reminder.title}`}
+                    >
+                      <Trash2 className="h-4 w-4" />
+                    </Button>
+                  </li>
+                ),
+              )}
+            </ul>
+          )}
+        </CardContent>
+      </Card>
+
+      <RoutinesCard />
+    </div>
+  );
+}
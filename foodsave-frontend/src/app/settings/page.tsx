@@ -0,0 +1,301 @@
+'use client';
+
+import { useEffect, useState } from 'react';
+import { Search } from 'lucide-react';
+import { Button } from '@/components/ui/Button';
+import { I18nProvider } from '@/components/I18nProvider';
+import { AboutSettings } from '@/components/settings/AboutSettings';
+import { BackendConnectionSettings } from '@/components/settings/BackendConnectionSettings';
+import { CalendarExportSettings } from '@/components/settings/CalendarExportSettings';
+import { DataSettings } from '@/components/settings/DataSettings';
+import { GeneralSettings } from '@/components/settings/GeneralSettings';
+import { LoggingSettings } from '@/components/settings/LoggingSettings';
+import { NotificationSettings } from '@/components/settings/NotificationSettings';
+import { ProfileSettings } from '@/components/settings/ProfileSettings';
+import { ReceiptSettings } from '@/components/settings/ReceiptSettings';
+import { SettingsTransfer } from '@/components/settings/SettingsTransfer';
+import { ShortcutSettings } from '@/components/settings/ShortcutSettings';
+import { StartupSettings } from '@/components/settings/StartupSettings';
+import { ThemeSettings } from '@/components/settings/ThemeSettings';
+import { UiScaleSettings } from '@/components/settings/UiScaleSettings';
+import { AccessibilitySettings } from '@/components/settings/AccessibilitySettings';
+import { UnsavedChangesBanner } from '@/components/settings/UnsavedChangesBanner';
+import { useSettingsDraft } from '@/hooks/useSettingsDraft';
+import { useUnsavedChangesPrompt } from '@/hooks/useUnsavedChangesPrompt';
+import { MessageKey, translate } from '@/lib/i18n';
+import { loadSettings } from '@/lib/settings';
+import { applyAccessibility, applyTheme, applyUiScale, scrollBehavior } from '@/lib/theme';
+import { validateBackendUrl } from '@/lib/url';
+import { SETTINGS_SECTIONS, SettingsItem, filterSettingsItems } from '@/lib/settingsSections';
+
+export default function SettingsPage() {
+  const { draft, saved, change, save, discard, isDirty, isLoaded } = useSettingsDraft();
+  // Strona ustawień od razu pokazuje wybrany (jeszcze niezapisany) język
+  const t = (key: MessageKey) => translate(draft.language, key);
+  const [query, setQuery] = useState('');
+  useUnsavedChangesPrompt(isDirty, t('common.unsavedChanges'));
+
+  // Podgląd motywu na żywo; po wyjściu bez zapisu wraca zapisany motyw
+  useEffect(() => {
+    if (isLoaded) applyTheme(draft.theme, draft.palette);
+  }, [isLoaded, draft.theme, draft.palette]);
+  useEffect(() => {
+    if (isLoaded) applyUiScale(draft.uiScale);
+  }, [isLoaded, draft.uiScale]);
+  useEffect(() => {
+    if (isLoaded) applyAccessibility(draft.highContrast, draft.reducedMotion);
+  }, [isLoaded, draft.highContrast, draft.reducedMotion]);
+  useEffect(
+    () => () => {
+      const stored = loadSettings();
+      applyTheme(stored.theme, stored.palette);
+      applyUiScale(stored.uiScale);
+      applyAccessibility(stored.highContrast, stored.reducedMotion);
+    },
+    [],
+  );
+
+  const backendUrl = draft.backendUrl ? validateBackendUrl(draft.backendUrl, draft.language) : null;
+  const canSave = isDirty && (backendUrl === null || backendUrl.valid);
+
+  const handleSave = () => {
+    if (!canSave) return;
+    save(backendUrl ? { backendUrl: backendUrl.normalized } : {});
+  };
+
+  const items: SettingsItem[] = [
+    {
+      id: 'general',
+      section: 'general',
+      keywords: ['settings.general.title', 'settings.general.userName', 'settings.language.title'],
+      node: (
+        <GeneralSettings userName={draft.userName} language={draft.language} onChange={change} />
+      ),
+    },
+    {
+      id: 'profiles',
+      section: 'general',
+      keywords: ['profiles.title', 'profiles.switch'],
+      node: <ProfileSettings />,
+    },
+    {
+      id: 'receipts',
+      section: 'general',
+      keywords: ['settings.receipts.title', 'settings.receipts.language', 'settings.receipts.archive'],
+      node: (
+        <ReceiptSettings
+          language={draft.receiptLanguage}
+          retentionMonths={draft.receiptRetentionMonths}
+          onChange={change}
+        />
+      ),
+    },
+    {
+      id: 'startup',
+      section: 'general',
+      keywords: ['settings.startup.title', 'settings.startup.restoreLastPage', 'settings.startup.deepLinks'],
+      node: (
+        <StartupSettings
+          restoreLastPage={draft.restoreLastPage}
+          onChange={(restoreLastPage) => change({ restoreLastPage })}
+        />
+      ),
+    },
+    {
+      id: 'theme',
+      section: 'appearance',
+      keywords: [
+        'settings.appearance.title',
+        'settings.appearance.theme',
+        'settings.appearance.dark',
+        'settings.appearance.accent',
+        'settings.appearance.customPalette',
+      ],
+      node: (
+        <ThemeSettings
+          value={draft.theme}
+          onChange={(theme) => change({ theme })}
+          palette={draft.palette}
+          onPaletteChange={(palette) => change({ palette })}
+        />
+      ),
+    },
+    {
+      id: 'scale',
+      section: 'appearance',
+      keywords: ['settings.scale.title', 'settings.scale.label'],
+      node: <UiScaleSettings value={draft.uiScale} onChange={(uiScale) => change({ uiScale })} />,
+    },
+    {
+      id: 'accessibility',
+      section: 'appearance',
+      keywords: [
+        'settings.accessibility.title',
+        'settings.accessibility.highContrast',
+        'settings.accessibility.reducedMotion',
+      ],
+      node: (
+        <AccessibilitySettings
+          highContrast={draft.highContrast}
+          reducedMotion={draft.reducedMotion}
+          onChange={change}
+        />
+      ),
+    },
+    {
+      id: 'backend',
+      section: 'backend',
+      keywords: ['settings.backend.title', 'settings.backend.address', 'settings.backend.test'],
+      node: (
+        <BackendConnectionSettings value={draft.backendUrl} onChange={(value) => change({ backendUrl: value })} />
+      ),
+    },
+    {
+      id: 'notifications',
+      section: 'notifications',
+      keywords: [
+        'settings.notifications.title',
+        'settings.notifications.enable',
+        'settings.notifications.master',
+        'settings.notifications.categories',
+      ],
+      node: (
+        <NotificationSettings
+          enabled={draft.notificationsEnabled}
+          categories={draft.notificationCategories}
+          onChange={change}
+        />
+      ),
+    },
+    {
+      id: 'calendarExport',
+      section: 'notifications',
+      keywords: ['settings.calendarExport.title', 'settings.calendarExport.subscribe'],
+      node: <CalendarExportSettings />,
+    },
+    {
+      id: 'data',
+      section: 'privacy',
+      keywords: [
+        'settings.data.title',
+        'settings.data.cache',
+        'settings.data.chat',
+        'settings.data.ocr',
+        'settings.data.all',
+      ],
+      node: <DataSettings />,
+    },
+    {
+      id: 'logging',
+      section: 'privacy',
+      keywords: ['settings.logging.title', 'settings.logging.level', 'settings.logging.persist', 'logs.title'],
+      node: (
+        <LoggingSettings
+          level={draft.logLevel}
+          persist={draft.persistLogs}
+          metrics={draft.collectMetrics}
+          onChange={change}
+        />
+      ),
+    },
+    {
+      id: 'transfer',
+      section: 'privacy',
+      keywords: ['settings.transfer.title', 'settings.transfer.export', 'settings.transfer.import'],
+      node: <SettingsTransfer saved={saved} onImport={change} />,
+    },
+    {
+      id: 'shortcuts',
+      section: 'shortcuts',
+      keywords: ['settings.shortcuts.title', 'settings.shortcuts.reset'],
+      node: <ShortcutSettings value={draft.shortcuts} onChange={(shortcuts) => change({ shortcuts })} />,
+    },
+    {
+      id: 'about',
+      section: 'about',
+      keywords: ['settings.about.title', 'settings.about.checkUpdates', 'settings.about.releaseNotes'],
+      node: (
+        <AboutSettings
+          checkForUpdates={draft.checkForUpdates}
+          onChange={(checkForUpdates) => change({ checkForUpdates })}
+        />
+      ),
+    },
+  ];
+
+  const visibleItems = filterSettingsItems(items, query, t);
+
+  return (
+    <I18nProvider locale={draft.language}>
+      <div className="container mx-auto p-6 space-y-6">
+        <div className="flex flex-wrap items-start justify-between gap-4">
+          <div>
+            <h1 className="text-3xl font-bold text-gray-900 mb-2">{t('settings.title')}</h1>
+            <p className="text-gray-600">{t('settings.subtitle')}</p>
+          </div>
+          <Button onClick={handleSave} disabled={!canSave}>
+            {t('common.save')}
+          </Button>
+        </div>
+
+        {isDirty && <UnsavedChangesBanner canSave={canSave} onSave={handleSave} onDiscard={discard} />}
+
+        <div className="grid grid-cols-1 md:grid-cols-[14rem_1fr] gap-6">
+          <aside className="space-y-3 md:sticky md:top-4 self-start">
+            <div className="relative">
+              <Search className="absolute left-2 top-2.5 h-4 w-4 text-muted-foreground" />
+              <input
+                type="search"
+                value={query}
+                onChange={(e) => setQuery(e.target.value)}
+                placeholder={t('settings.search')}
+                data-primary-input
+                aria-label={t('settings.search')}
+                className="w-full rounded-md border py-2 pl-8 pr-3 text-sm"
+              />
+            </div>
+            <nav className="flex md:flex-col gap-1 overflow-x-auto">
+              {SETTINGS_SECTIONS.map(({ id, titleKey }) => {
+                const count = visibleItems.filter((item) => item.section === id).length;
+                return (
+                  <a
+                    key={id}
+                    href={`#settings-${id}`}
+                    onClick={(e) => {
+                      e.preventDefault();
+                      document.getElementById(`settings-${id}`)?.scrollIntoView({ behavior: scrollBehavior() });
+                    }}
+                    aria-disabled={count === 0}
+                    className={`whitespace-nowrap rounded-md px-3 py-2 text-sm hover:bg-accent ${
+                      count === 0 ? 'pointer-events-none text-muted-foreground opacity-50' : ''
+                    }`}
+                  >
+                    {t(titleKey)}
+                  </a>
+                );
+              })}
+            </nav>
+          </aside>
+
+          <div className="space-y-8">
+            {visibleItems.length === 0 && (
+              <p className="text-sm text-muted-foreground">{t('settings.noResults')}</p>
+            )}
+            {SETTINGS_SECTIONS.map(({ id, titleKey }) => {
+              const sectionItems = visibleItems.filter((item) => item.section === id);
+              if (sectionItems.length === 0) return null;
+              return (
+                <section key={id} id={`settings-${id}`} className="space-y-4 scroll-mt-4">
+                  <h2 className="text-lg font-semibold">{t(titleKey)}</h2>
+                  {sectionItems.map((item) => (
+                    <div key={item.id}>{item.node}</div>
+                  ))}
+                </section>
+              );
+            })}
+          </div>
+        </div>
+      </div>
+    </I18nProvider>
+  );
+}
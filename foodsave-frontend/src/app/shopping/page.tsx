@@ -1,6 +1,7 @@
 'use client';
 
-import React, { useState } from 'react';
+import React, { Suspense, useEffect, useState } from 'react';
+import { useQueryClient } from '@tanstack/react-query';
 import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
 import { ReceiptUploader } from '@/components/shopping/ReceiptUploader';
 import { ProductTable } from '@/components/shopping/ProductTable';
@@ -9,84 +10,200 @@ import { MessageInput } from '@/components/chat/MessageInput';
 import { MessageList } from '@/components/chat/MessageList';
 import { useChat } from '@/hooks/useChat';
 import { ReceiptDataTable } from '@/components/shopping/ReceiptDataTable';
+import { ReceiptExport } from '@/components/shopping/ReceiptExport';
+import { ReceiptMetaForm } from '@/components/shopping/ReceiptMetaForm';
+import { ReceiptHistory } from '@/components/shopping/ReceiptHistory';
+import { ShoppingList } from '@/components/shopping/ShoppingList';
 import { ApiService } from '@/services/ApiService';
 import { Product } from '@/types/shopping';
+import { downloadFile, parsedReceiptToCsv, toJson } from '@/lib/exporters';
+import { logActivity } from '@/lib/activityLog';
+import { loadSettings } from '@/lib/settings';
+import { notify } from '@/lib/notifications';
+import { reportError } from '@/lib/errors';
+import { trackTask } from '@/lib/tasks';
+import { getRecoveryData, saveReceiptDraft } from '@/lib/recovery';
+import { measure } from '@/lib/metrics';
+import { lineTotal, money, sumMoney, toAmount } from '@/lib/money';
+import { localIsoDate } from '@/lib/time';
+import { checkOffPurchased } from '@/lib/shoppingList';
+import { saveReceiptImage } from '@/lib/receiptImages';
+import { productFromOcrItem } from '@/lib/receiptLines';
+import { pushToast } from '@/lib/toasts';
+import { Button } from '@/components/ui/Button';
+import { useTranslation } from '@/hooks/useTranslation';
 
 export default function ShoppingPage() {
   const { products, isLoading: shoppingLoading, error: shoppingError, fetchProducts } = useShopping();
-  const { messages, isLoading: chatLoading, error: chatError, sendMessage } = useChat('shopping');
+  const {
+    messages,
+    isLoading: chatLoading,
+    sendMessage,
+    clarify,
+    togglePin,
+    replyLanguage,
+    setReplyLanguage,
+    attachments,
+    setAttachments,
+  } = useChat('shopping');
+  const { t } = useTranslation();
+  const queryClient = useQueryClient();
 
   const [processingStep, setProcessingStep] = useState<'upload' | 'ocr' | 'analyze' | 'edit' | 'saving' | 'done'>('upload');
   const [ocrText, setOcrText] = useState<string | null>(null);
   const [analyzedProducts, setAnalyzedProducts] = useState<Product[]>([]);
   const [receiptMeta, setReceiptMeta] = useState<any>(null);
-  const [error, setError] = useState<string | null>(null);
+  // Skan bieżącego paragonu - po zapisie zostaje na urządzeniu do ponownego przetworzenia
+  const [receiptFile, setReceiptFile] = useState<File | null>(null);
 
   const isLoading = shoppingLoading || chatLoading || processingStep === 'ocr' || processingStep === 'analyze' || processingStep === 'saving';
 
+  // Po awarii: /shopping?restore=receipt wraca do edycji niezapisanego paragonu
+  useEffect(() => {
+    if (new URLSearchParams(window.location.search).get('restore') !== 'receipt') return;
+    const draft = getRecoveryData().receipt;
+    if (!draft) return;
+    setOcrText(draft.ocrText);
+    setAnalyzedProducts(draft.products);
+    setReceiptMeta(draft.meta);
+    setProcessingStep('edit');
+  }, []);
+
+  useEffect(() => {
+    if (shoppingError) {
+      reportError(new Error(shoppingError), t('shopping.errorProducts'), fetchProducts);
+    }
+  }, [shoppingError, fetchProducts, t]);
+
   const handleFileUpload = async (file: File) => {
-    setError(null);
     setProcessingStep('ocr');
+    setReceiptFile(file);
     try {
       // 1. OCR
-      const ocrRes: any = await ApiService.uploadReceipt(file);
+      const ocrRes: any = await trackTask('ocr', t('shopping.taskOcr'), () =>
+        measure('ocr', () =>
+          ApiService.uploadReceipt(file, undefined, undefined, { language: loadSettings().receiptLanguage }),
+        ),
+      );
       const ocrText = ocrRes?.data?.text || '';
+      const language: string | undefined = ocrRes?.data?.language;
       setOcrText(ocrText);
       setProcessingStep('analyze');
       // 2. Analiza
-      const analyzeRes: any = await ApiService.analyzeReceipt(ocrText);
+      const analyzeRes: any = await trackTask('ocr', t('shopping.taskAnalyze'), () =>
+        ApiService.analyzeReceipt(ocrText, language),
+      );
       const data = analyzeRes?.data || {};
-      setAnalyzedProducts(data.items || []);
-      setReceiptMeta({
-        store: data.store_name,
-        date: data.date,
-        total: data.total,
-      });
+      const meta = { store: data.store_name, date: data.date, total: data.total };
+      const items: Product[] = (data.items || []).map(productFromOcrItem);
+      setAnalyzedProducts(items);
+      setReceiptMeta(meta);
+      saveReceiptDraft({ ocrText, products: items, meta });
+      logActivity(
+        'receipt_scan',
+        data.store_name ? t('shopping.activityScannedAt', { store: data.store_name }) : t('shopping.activityScanned'),
+        '/shopping',
+      );
       setProcessingStep('edit');
+      if (document.hidden) {
+        notify({
+          category: 'ocr',
+          title: t('shopping.processedTitle'),
+          body: t('shopping.processedBody', { count: items.length }),
+          href: '/shopping',
+        });
+      }
     } catch (err: any) {
-      setError(err?.message || 'Błąd podczas przetwarzania paragonu');
+      reportError(err, t('shopping.errorProcessing'), () => handleFileUpload(file));
       setProcessingStep('upload');
     }
   };
 
   const handleSaveProducts = async (editedProducts: Product[]) => {
     setProcessingStep('saving');
-    setError(null);
     try {
       // 3. Zapis do bazy
       const payload = {
         trip_date: receiptMeta?.date || new Date().toISOString().slice(0, 10),
-        store_name: receiptMeta?.store || 'Nieznany sklep',
-        total_amount: receiptMeta?.total || 0,
+        store_name: receiptMeta?.store || t('shopping.unknownStore'),
+        // Bez sumy z OCR liczymy ją z pozycji (w groszach)
+        total_amount: receiptMeta?.total || toAmount(sumMoney(editedProducts.map(p => lineTotal(p.price, p.quantity)))),
         products: editedProducts.map(p => ({
           name: p.name,
           quantity: p.quantity,
           unit: p.unit,
-          price: p.price,
+          unit_price: toAmount(money(p.price)),
           category: p.category,
-          expiry_date: p.expiry_date || null,
+          expiration_date: p.expiry_date || null,
         })),
       };
-      await ApiService.saveReceiptData(payload);
+      const saveRes: any = await trackTask('receipt-save', t('shopping.taskSave'), () =>
+        ApiService.saveReceiptData(payload),
+      );
+      const tripId = saveRes?.data?.trip_id;
+      if (tripId && receiptFile) {
+        void saveReceiptImage(tripId, receiptFile, payload.trip_date, loadSettings().receiptRetentionMonths);
+      }
+      logActivity(
+        'receipt_saved',
+        t('shopping.activitySaved', { store: payload.store_name, count: editedProducts.length }),
+        tripId ? `/shopping?receipt=${tripId}` : '/shopping',
+      );
       setProcessingStep('done');
+      saveReceiptDraft(null);
+      const bought = checkOffPurchased(editedProducts.map(p => p.name));
+      if (bought.length > 0) {
+        pushToast(t('shopping.checkedOff', { items: bought.map(item => item.name).join(', ') }), { severity: 'success' });
+      }
+      queryClient.invalidateQueries({ queryKey: ['shopping-trips'] });
+      queryClient.invalidateQueries({ queryKey: ['spending-stats'] });
       await fetchProducts();
       setTimeout(() => {
         setProcessingStep('upload');
         setOcrText(null);
         setAnalyzedProducts([]);
         setReceiptMeta(null);
+        setReceiptFile(null);
       }, 2000);
     } catch (err: any) {
-      setError(err?.message || 'Błąd podczas zapisu produktów');
+      reportError(err, t('shopping.errorSave'), () => handleSaveProducts(editedProducts));
       setProcessingStep('edit');
     }
   };
 
+  // Wyblakły paragon: ten sam edytor i zapis co po OCR, tylko bez skanu
+  const handleManualEntry = () => {
+    const meta = { store: '', date: localIsoDate(new Date()), manual: true };
+    const rows: Product[] = [{ id: `row-${Date.now()}`, name: '', quantity: 1, unit: 'szt.', price: 0 }];
+    setOcrText(null);
+    setReceiptFile(null);
+    setAnalyzedProducts(rows);
+    setReceiptMeta(meta);
+    saveReceiptDraft({ ocrText: null, products: rows, meta });
+    setProcessingStep('edit');
+  };
+
+  const handleMetaChange = (meta: { store?: string; date?: string }) => {
+    const updated = { ...receiptMeta, ...meta };
+    setReceiptMeta(updated);
+    saveReceiptDraft({ ocrText, products: analyzedProducts, meta: updated });
+  };
+
+  const handleExportParsed = (format: 'csv' | 'json') => {
+    const name = `paragon_${receiptMeta?.date || new Date().toISOString().slice(0, 10)}.${format}`;
+    const content = format === 'csv'
+      ? parsedReceiptToCsv(analyzedProducts, receiptMeta || {})
+      : toJson({ ...receiptMeta, items: analyzedProducts });
+    downloadFile(content, name, format);
+  };
+
   const handleCancelEdit = () => {
+    saveReceiptDraft(null);
     setProcessingStep('upload');
     setOcrText(null);
     setAnalyzedProducts([]);
     setReceiptMeta(null);
+    setReceiptFile(null);
   };
 
   return (
@@ -98,35 +215,45 @@ export default function ShoppingPage() {
             <ReceiptUploader
               onUpload={handleFileUpload}
               isUploading={isLoading}
+              onManualEntry={handleManualEntry}
             />
           )}
           {processingStep === 'ocr' && (
-            <div className="p-4 text-center">Wykonywanie OCR...</div>
+            <div className="p-4 text-center">{t('shopping.stepOcr')}</div>
           )}
           {processingStep === 'analyze' && (
-            <div className="p-4 text-center">Analiza paragonu...</div>
+            <div className="p-4 text-center">{t('shopping.stepAnalyze')}</div>
           )}
-          {processingStep === 'edit' && analyzedProducts.length > 0 && (
-            <ReceiptDataTable
-              products={analyzedProducts}
-              onSave={handleSaveProducts}
-              onCancel={handleCancelEdit}
-            />
+          {processingStep === 'edit' && (
+            <>
+              <div className="flex justify-end gap-2 p-4 pb-0">
+                <Button variant="outline" size="sm" onClick={() => handleExportParsed('csv')}>{t('shopping.exportCsv')}</Button>
+                <Button variant="outline" size="sm" onClick={() => handleExportParsed('json')}>{t('shopping.exportJson')}</Button>
+              </div>
+              {receiptMeta?.manual && <ReceiptMetaForm meta={receiptMeta} onChange={handleMetaChange} />}
+              <ReceiptDataTable
+                products={analyzedProducts}
+                onSave={handleSaveProducts}
+                onCancel={handleCancelEdit}
+                purchaseDate={receiptMeta?.date}
+                onChange={(edited) => {
+                  setAnalyzedProducts(edited);
+                  saveReceiptDraft({ ocrText, products: edited, meta: receiptMeta });
+                }}
+              />
+            </>
           )}
           {processingStep === 'saving' && (
-            <div className="p-4 text-center">Zapisywanie produktów...</div>
+            <div className="p-4 text-center">{t('shopping.stepSaving')}</div>
           )}
           {processingStep === 'done' && (
-            <div className="p-4 text-center text-green-600">Produkty zostały zapisane!</div>
-          )}
-          {error && (
-            <div className="p-4 text-center text-red-600">{error}</div>
+            <div className="p-4 text-center text-green-600">{t('shopping.stepDone')}</div>
           )}
         </Card>
         {processingStep === 'upload' && (
           <Card>
             <CardHeader>
-              <CardTitle>Lista produktów</CardTitle>
+              <CardTitle>{t('shopping.productsTitle')}</CardTitle>
             </CardHeader>
             <CardContent>
               <ProductTable
@@ -136,25 +263,56 @@ export default function ShoppingPage() {
             </CardContent>
           </Card>
         )}
+        {processingStep === 'upload' && (
+          <Card id="shopping-list">
+            <CardHeader>
+              <CardTitle>{t('shopping.list.title')}</CardTitle>
+            </CardHeader>
+            <CardContent>
+              <ShoppingList />
+            </CardContent>
+          </Card>
+        )}
+        {processingStep === 'upload' && (
+          <Card>
+            <CardHeader>
+              <CardTitle>{t('shopping.historyTitle')}</CardTitle>
+            </CardHeader>
+            <CardContent>
+              <Suspense fallback={null}>
+                <ReceiptHistory />
+              </Suspense>
+            </CardContent>
+          </Card>
+        )}
+        {processingStep === 'upload' && (
+          <Card>
+            <CardHeader>
+              <CardTitle>{t('shopping.exportTitle')}</CardTitle>
+            </CardHeader>
+            <CardContent>
+              <ReceiptExport />
+            </CardContent>
+          </Card>
+        )}
       </div>
       {/* Prawa strona - Czat zakupowy */}
       <Card className="flex flex-col">
         <CardHeader>
-          <CardTitle>Asystent zakupowy</CardTitle>
-          {(shoppingError || chatError) && (
-            <div className="mt-2 p-2 bg-red-100 text-red-700 rounded">
-              {shoppingError || chatError}
-            </div>
-          )}
+          <CardTitle>{t('shopping.assistantTitle')}</CardTitle>
         </CardHeader>
         <CardContent className="flex-grow overflow-auto p-4">
-          <MessageList messages={messages} isLoading={chatLoading} />
+          <MessageList messages={messages} isLoading={chatLoading} onClarify={clarify} onTogglePin={togglePin} />
         </CardContent>
         <div className="p-4 border-t">
           <MessageInput
             onSendMessage={sendMessage}
             isLoading={chatLoading}
-            placeholder="Zapytaj o produkty, promocje, lub poproś o listę zakupów..."
+            placeholder={t('shopping.assistantPlaceholder')}
+            replyLanguage={replyLanguage}
+            onChangeReplyLanguage={setReplyLanguage}
+            attachments={attachments}
+            onChangeAttachments={setAttachments}
           />
         </div>
       </Card>
@@ -0,0 +1,200 @@
+"use client";
+
+import { useEffect } from 'react';
+import { v4 as uuidv4 } from 'uuid';
+import queryClient from '@/lib/queryClient';
+import { dueReminders, getReminders, subscribeReminders } from '@/lib/reminders';
+import { LeftoverStage, getLeftovers, leftoverAlerts } from '@/lib/leftovers';
+import { ROUTINE_TAG, dueRoutines, getRoutines, markRoutineRun, subscribeRoutines } from '@/lib/routines';
+import { saveConversation } from '@/lib/chatHistory';
+import { profileSessionId } from '@/lib/profiles';
+import { trackTask } from '@/lib/tasks';
+import { Message } from '@/types/chat';
+import { canNotify, isCategoryEnabled, notify } from '@/lib/notifications';
+import { isOnline } from '@/lib/network';
+import { shouldPersistQuery } from '@/lib/offlineCache';
+import { registerJob, runJobNow, startScheduler } from '@/lib/scheduler';
+import { loadSettings } from '@/lib/settings';
+import { pushToast } from '@/lib/toasts';
+import { purgeReceiptImages } from '@/lib/receiptImages';
+import {
+  buildMonthlyReport,
+  formatMonth,
+  isReportSent,
+  lastCompletedMonth,
+  markReportSent,
+  monthRange,
+  monthlyReportSummary,
+  shiftMonth,
+} from '@/lib/monthlyReport';
+import { MessageKey, translate } from '@/lib/i18n';
+import { checkForUpdate, markUpdateNotified, shouldNotifyUpdate } from '@/lib/updates';
+import { ApiService } from '@/services/ApiService';
+import { useTabLeader } from '@/hooks/useTabLeader';
+import { loadContextAttachment } from '@/hooks/useChat';
+import { localIsoDate } from '@/lib/time';
+import { createLogger } from '@/lib/logger';
+
+const MINUTE = 60 * 1000;
+const log = createLogger('background-jobs');
+
+function checkReminders() {
+  const { language } = loadSettings();
+  for (const reminder of dueReminders(getReminders())) {
+    notify({
+      category: 'reminder',
+      title: translate(language, 'notifications.reminder'),
+      body: reminder.title,
+      href: '/reminders',
+      onceKey: `reminder:${reminder.id}:${reminder.dueAt}`,
+    });
+  }
+}
+
+async function checkExpiringProducts() {
+  if (!isOnline()) return;
+  const expiring = await ApiService.getExpiringProducts(7);
+  if (expiring.length === 0) return;
+  const { language } = loadSettings();
+  // Raz dziennie przypomnij o produktach, którym kończy się termin
+  notify({
+    category: 'pantry',
+    title: translate(language, 'notifications.expiringTitle'),
+    body: translate(language, 'notifications.expiringBody', { count: expiring.length }),
+    href: '/dashboard',
+    onceKey: `pantry-expiry:${localIsoDate(new Date())}`,
+  });
+}
+
+const LEFTOVER_MESSAGES: Record<Exclude<LeftoverStage, 'fresh'>, MessageKey> = {
+  soon: 'notifications.leftover.soon',
+  today: 'notifications.leftover.today',
+  overdue: 'notifications.leftover.overdue',
+};
+
+// Każdy etap przypomina się osobno; powiadomienie zablokowane limitem wraca przy następnym sprawdzeniu
+function checkLeftovers() {
+  const { language } = loadSettings();
+  for (const { leftover, stage } of leftoverAlerts(getLeftovers())) {
+    notify({
+      category: 'pantry',
+      title: translate(language, 'notifications.leftoversTitle'),
+      body: translate(language, LEFTOVER_MESSAGES[stage], { name: leftover.name }),
+      href: '/cooking',
+      onceKey: `leftover:${leftover.id}:${stage}`,
+    });
+  }
+}
+
+// Sends due routine prompts; the answer lands in saved conversations and in a notification.
+// Without a connection, or when the assistant fails, routines stay due and run again later.
+// The scheduler never runs the job twice at once, so a slow answer can't duplicate a routine.
+async function runRoutines() {
+  if (!isOnline()) return;
+  const { language } = loadSettings();
+  for (const routine of dueRoutines(getRoutines())) {
+    const sessionId = profileSessionId(uuidv4());
+    const attachments = await Promise.all(routine.attachments.map(loadContextAttachment));
+    const response = await trackTask('chat', translate(language, 'notifications.routineTask', { name: routine.name }), () =>
+      ApiService.sendChatMessage({ message: routine.prompt, session_id: sessionId, attachments }),
+    ).catch((error: unknown) => {
+      log.error(`Routine "${routine.name}" failed`, error);
+      return null;
+    });
+    const answer: string | undefined = response?.response;
+    if (!answer) continue;
+    const messages: Message[] = [
+      { id: uuidv4(), role: 'user', content: routine.prompt, timestamp: Date.now(), attachments: routine.attachments },
+      { id: uuidv4(), role: 'assistant', content: answer, data: response?.data || null, timestamp: Date.now() },
+    ];
+    saveConversation('general', sessionId, messages, [ROUTINE_TAG]);
+    markRoutineRun(routine.id, sessionId);
+    notify({
+      category: 'chat',
+      title: `FoodSave – ${routine.name}`,
+      body: answer.slice(0, 120),
+      href: `/chat?conversation=${encodeURIComponent(sessionId)}`,
+      onceKey: `routine:${routine.id}:${sessionId}`,
+    });
+  }
+}
+
+// Odświeża dane trzymane offline, także te z niewidocznych teraz stron; bez połączenia czeka
+async function syncOfflineData() {
+  if (!queryClient || !isOnline()) return;
+  await queryClient.invalidateQueries({ predicate: shouldPersistQuery, refetchType: 'all' });
+}
+
+// Po zakończeniu miesiąca raz wysyła jego podsumowanie; bez połączenia albo z wyciszonymi
+// powiadomieniami czeka, nie pobierając statystyk co godzinę
+async function sendMonthlyReport() {
+  const month = lastCompletedMonth();
+  if (isReportSent(month) || !isOnline() || !canNotify() || !isCategoryEnabled('budget')) return;
+  const [current, previous] = await Promise.all([
+    ApiService.getSpendingStats(monthRange(month)),
+    ApiService.getSpendingStats(monthRange(shiftMonth(month, -1))),
+  ]);
+  // Miesiąc bez paragonów nie ma czego podsumować - nie wracamy do niego
+  if (current.receipts_count === 0) {
+    markReportSent(month);
+    return;
+  }
+  const { language } = loadSettings();
+  const shown = notify({
+    category: 'budget',
+    title: translate(language, 'report.notificationTitle', { month: formatMonth(month, language) }),
+    body: monthlyReportSummary(buildMonthlyReport(month, current, previous), language),
+    href: '/analytics#monthly-report',
+    onceKey: `monthly-report:${month}`,
+  });
+  if (shown) markReportSent(month);
+}
+
+// Skrócenie okresu przechowywania w ustawieniach działa najpóźniej po dobie
+async function purgeOldReceipts() {
+  await purgeReceiptImages(loadSettings().receiptRetentionMonths);
+}
+
+async function checkUpdates() {
+  const { checkForUpdates, language } = loadSettings();
+  if (!checkForUpdates || !isOnline()) return;
+  const release = await checkForUpdate();
+  if (!release || !shouldNotifyUpdate(release)) return;
+  markUpdateNotified(release);
+  pushToast(translate(language, 'updates.available', { version: release.version }), {
+    duration: 0,
+    action: { label: translate(language, 'updates.details'), onClick: () => window.location.assign('/settings#settings-about') },
+  });
+}
+
+// Background jobs that keep working while the app is open in any tab, not just on the
+// page that shows the data. Only the leader tab runs them.
+export function BackgroundJobs() {
+  const isLeader = useTabLeader();
+
+  useEffect(() => {
+    if (!isLeader) return;
+    const unregister = [
+      registerJob({ id: 'reminders', intervalMs: MINUTE, run: checkReminders }),
+      registerJob({ id: 'routines', intervalMs: MINUTE, run: runRoutines }),
+      registerJob({ id: 'pantry-expiry', intervalMs: 60 * MINUTE, run: checkExpiringProducts }),
+      registerJob({ id: 'leftovers', intervalMs: 15 * MINUTE, run: checkLeftovers }),
+      registerJob({ id: 'offline-sync', intervalMs: 15 * MINUTE, run: syncOfflineData }),
+      registerJob({ id: 'update-check', intervalMs: 60 * MINUTE, run: checkUpdates }),
+      registerJob({ id: 'monthly-report', intervalMs: 60 * MINUTE, run: sendMonthlyReport }),
+      registerJob({ id: 'receipt-retention', intervalMs: 24 * 60 * MINUTE, run: purgeOldReceipts }),
+    ];
+    const stop = startScheduler();
+    const unsubscribe = subscribeReminders(() => void runJobNow('reminders'));
+    // "Uruchom teraz" w dowolnej karcie
+    const unsubscribeRoutines = subscribeRoutines(() => void runJobNow('routines'));
+    return () => {
+      stop();
+      unsubscribe();
+      unsubscribeRoutines();
+      unregister.forEach((fn) => fn());
+    };
+  }, [isLeader]);
+
+  return null;
+}
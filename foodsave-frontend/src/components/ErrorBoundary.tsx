@@ -5,6 +5,7 @@ import { Card, CardContent, CardHeader, CardTitle } from './ui/Card';
 import { Button } from './ui/Button';
 import { AlertTriangle, RefreshCw } from 'lucide-react';
 import logger from '../lib/logger';
+import { I18nContext } from './I18nProvider';
 
 interface Props {
   children: ReactNode;
@@ -19,6 +20,9 @@ interface State {
 }
 
 export class ErrorBoundary extends Component<Props, State> {
+  static contextType = I18nContext;
+  declare context: React.ContextType<typeof I18nContext>;
+
   constructor(props: Props) {
     super(props);
     this.state = { hasError: false };
@@ -56,7 +60,7 @@ export class ErrorBoundary extends Component<Props, State> {
     if (error) {
       // TODO: Implement error reporting mechanism
       logger.error('Reporting error:', { error, errorInfo });
-      alert('Error has been reported. Thank you for your feedback.');
+      alert(this.context.t('errorBoundary.reported'));
     }
   };
 
@@ -77,23 +81,24 @@ export class ErrorBoundary extends Component<Props, State> {
       }
 
       // Default error UI
+      const { t } = this.context;
       return (
         <Card className="max-w-md mx-auto mt-8">
           <CardHeader>
             <CardTitle className="flex items-center gap-2 text-red-600">
               <AlertTriangle className="h-5 w-5" />
-              Something went wrong
+              {t('errorBoundary.title')}
             </CardTitle>
           </CardHeader>
           <CardContent className="space-y-4">
             <p className="text-gray-600">
-              We&apos;re sorry, but something unexpected happened. Please try refreshing the page or contact support if the problem persists.
+              {t('errorBoundary.body')}
             </p>
 
             {process.env.NODE_ENV === 'development' && this.state.error && (
               <details className="text-sm">
                 <summary className="cursor-pointer text-gray-500 hover:text-gray-700">
-                  Error Details (Development)
+                  {t('errorBoundary.details')}
                 </summary>
                 <pre className="mt-2 p-2 bg-gray-100 rounded text-xs overflow-auto">
                   {this.state.error.toString()}
@@ -105,10 +110,10 @@ export class ErrorBoundary extends Component<Props, State> {
             <div className="flex gap-2">
               <Button onClick={this.handleRetry} className="flex items-center gap-2">
                 <RefreshCw className="h-4 w-4" />
-                Try Again
+                {t('errorBoundary.retry')}
               </Button>
               <Button variant="outline" onClick={this.handleReportError}>
-                Report Error
+                {t('errorBoundary.report')}
               </Button>
             </div>
           </CardContent>
@@ -0,0 +1,41 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { useIsFetching, useIsMutating } from '@tanstack/react-query';
+import { useTasks } from '@/hooks/useTasks';
+import { useTranslation } from '@/hooks/useTranslation';
+
+// Krótkie zapytania nie powinny migać paskiem
+const SHOW_DELAY_MS = 300;
+
+export function GlobalProgress() {
+  const tasks = useTasks();
+  const { t } = useTranslation();
+  const fetching = useIsFetching();
+  const mutating = useIsMutating();
+  const busy = tasks.length + fetching + mutating > 0;
+  const [visible, setVisible] = useState(false);
+
+  useEffect(() => {
+    if (!busy) {
+      setVisible(false);
+      return;
+    }
+    const timer = setTimeout(() => setVisible(true), SHOW_DELAY_MS);
+    return () => clearTimeout(timer);
+  }, [busy]);
+
+  if (!visible) return null;
+
+  return (
+    <div
+      role="progressbar"
+      aria-busy="true"
+      aria-label={tasks.length > 0 ? tasks.map((task) => task.label).join(', ') : t('common.loadingData')}
+      title={tasks.map((task) => task.label).join('\n') || undefined}
+      className="fixed inset-x-0 top-0 z-50 h-0.5 overflow-hidden bg-primary/20"
+    >
+      <div className="h-full w-full animate-pulse bg-primary" />
+    </div>
+  );
+}
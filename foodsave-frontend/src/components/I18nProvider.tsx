@@ -0,0 +1,37 @@
+"use client";
+
+import React, { createContext, useEffect, useMemo } from 'react';
+import { Locale, MessageArgs, MessageKey, translate } from '@/lib/i18n';
+import { useSettings } from '@/hooks/useSettings';
+
+export interface I18nContextValue {
+  locale: Locale;
+  t: (key: MessageKey, args?: MessageArgs) => string;
+}
+
+export const I18nContext = createContext<I18nContextValue>({
+  locale: 'pl',
+  t: (key, args) => translate('pl', key, args),
+});
+
+interface I18nProviderProps {
+  children: React.ReactNode;
+  // Nadpisuje zapisany język, np. podgląd na stronie ustawień
+  locale?: Locale;
+}
+
+export function I18nProvider({ children, locale: override }: I18nProviderProps) {
+  const { settings } = useSettings();
+  const locale = override ?? settings.language;
+
+  useEffect(() => {
+    if (!override) document.documentElement.lang = locale;
+  }, [locale, override]);
+
+  const value = useMemo<I18nContextValue>(
+    () => ({ locale, t: (key, args) => translate(locale, key, args) }),
+    [locale],
+  );
+
+  return <I18nContext.Provider value={value}>{children}</I18nContext.Provider>;
+}
@@ -0,0 +1,47 @@
+"use client";
+
+import { useEffect } from 'react';
+import { useRouter } from 'next/navigation';
+import { cycleFocusRegion, focusPrimaryInput } from '@/lib/focus';
+import { loadSettings } from '@/lib/settings';
+import { SHORTCUT_EVENT, eventToBinding, findShortcut, hasModifier, isEditableTarget } from '@/lib/shortcuts';
+import { dismissLatestToast } from '@/lib/toasts';
+
+// Global keyboard handler: configurable shortcuts from settings plus the fixed "/" and Esc keys.
+// Dialogs handle their own Esc and call preventDefault, so it doesn't also close a toast.
+export function KeyboardShortcuts() {
+  const router = useRouter();
+
+  useEffect(() => {
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.defaultPrevented || e.repeat) return;
+      const binding = eventToBinding(e);
+      if (!binding) return;
+
+      if (binding === 'Escape') {
+        // Esc obsłużony już np. przez okno dialogowe nie zamyka dodatkowo powiadomienia
+        if (!e.defaultPrevented && dismissLatestToast()) e.preventDefault();
+        return;
+      }
+      // Bez Ctrl/Alt/Meta skrót nie może przeszkadzać w pisaniu
+      if (isEditableTarget(e.target) && !hasModifier(binding)) return;
+      if (binding === '/' && focusPrimaryInput()) {
+        e.preventDefault();
+        return;
+      }
+
+      const action = findShortcut(binding, loadSettings().shortcuts);
+      if (!action) return;
+      e.preventDefault();
+      if (action.href) router.push(action.href);
+      else if (action.id === 'focus.nextRegion') cycleFocusRegion(1);
+      else if (action.id === 'focus.previousRegion') cycleFocusRegion(-1);
+      else window.dispatchEvent(new CustomEvent(SHORTCUT_EVENT, { detail: action.id }));
+    };
+
+    window.addEventListener('keydown', onKeyDown);
+    return () => window.removeEventListener('keydown', onKeyDown);
+  }, [router]);
+
+  return null;
+}
@@ -0,0 +1,62 @@
+"use client";
+
+import { useEffect } from 'react';
+import { usePathname } from 'next/navigation';
+import { rememberPage, rememberScroll, savedScroll } from '@/lib/startup';
+
+// Strony doładowują dane - próbujemy przewinąć przez chwilę, aż treść będzie dość wysoka
+const RESTORE_ATTEMPTS = 20;
+const SAVE_DELAY_MS = 200;
+
+// Remembers the current page and its scroll position, and restores the position on return
+export function LastPageTracker() {
+  const pathname = usePathname();
+
+  useEffect(() => {
+    if (!pathname) return;
+    rememberPage(pathname);
+
+    const target = savedScroll(pathname);
+    let attempts = 0;
+    let frame = 0;
+    const restore = () => {
+      window.scrollTo(0, target);
+      attempts += 1;
+      if (Math.abs(window.scrollY - target) > 1 && attempts < RESTORE_ATTEMPTS) {
+        frame = requestAnimationFrame(restore);
+      }
+    };
+    if (target > 0) frame = requestAnimationFrame(restore);
+
+    // Przewijanie przez użytkownika przerywa przywracanie pozycji
+    const stopRestoring = () => cancelAnimationFrame(frame);
+    let lastY = window.scrollY;
+    let timer: ReturnType<typeof setTimeout> | undefined;
+    const save = () => {
+      clearTimeout(timer);
+      timer = undefined;
+      rememberScroll(pathname, lastY);
+    };
+    const onScroll = () => {
+      lastY = window.scrollY;
+      clearTimeout(timer);
+      timer = setTimeout(save, SAVE_DELAY_MS);
+    };
+    window.addEventListener('scroll', onScroll, { passive: true });
+    window.addEventListener('pagehide', save);
+    window.addEventListener('wheel', stopRestoring, { passive: true });
+    window.addEventListener('touchstart', stopRestoring, { passive: true });
+    window.addEventListener('keydown', stopRestoring);
+    return () => {
+      stopRestoring();
+      if (timer) save();
+      window.removeEventListener('scroll', onScroll);
+      window.removeEventListener('pagehide', save);
+      window.removeEventListener('wheel', stopRestoring);
+      window.removeEventListener('touchstart', stopRestoring);
+      window.removeEventListener('keydown', stopRestoring);
+    };
+  }, [pathname]);
+
+  return null;
+}
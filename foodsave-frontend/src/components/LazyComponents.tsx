@@ -5,6 +5,8 @@ import dynamic from 'next/dynamic';
 import { Suspense } from 'react';
 import { Spinner } from './ui/Spinner';
 import logger from '../lib/logger';
+import type { MessageKey } from '../lib/i18n';
+import { useTranslation } from '../hooks/useTranslation';
 
 // Add type definition for gtag
 declare global {
@@ -14,39 +16,45 @@ declare global {
 }
 
 // Loading component for lazy-loaded components
-const LoadingFallback = ({ message = 'Loading...' }: { message?: string }) => (
-  <div className="flex items-center justify-center p-8">
-    <div className="flex flex-col items-center gap-2">
-      <Spinner size="lg" />
-      <p className="text-sm text-gray-500">{message}</p>
+const LoadingFallback = ({ message = 'lazy.loading' }: { message?: MessageKey }) => {
+  const { t } = useTranslation();
+  return (
+    <div className="flex items-center justify-center p-8">
+      <div className="flex flex-col items-center gap-2">
+        <Spinner size="lg" />
+        <p className="text-sm text-gray-500">{t(message)}</p>
+      </div>
     </div>
-  </div>
-);
+  );
+};
 
 // Error fallback for lazy-loaded components
-const ErrorFallback = ({ error, retry }: { error: Error; retry: () => void }) => (
-  <div className="flex flex-col items-center justify-center p-8 text-center">
-    <div className="text-red-500 mb-4">
-      <svg className="w-12 h-12 mx-auto" fill="none" stroke="currentColor" viewBox="0 0 24 24">
-        <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M12 9v2m0 4h.01m-6.938 4h13.856c1.54 0 2.502-1.667 1.732-2.5L13.732 4c-.77-.833-1.964-.833-2.732 0L3.732 16.5c-.77.833.192 2.5 1.732 2.5z" />
-      </svg>
+const ErrorFallback = ({ error, retry }: { error: Error; retry: () => void }) => {
+  const { t } = useTranslation();
+  return (
+    <div className="flex flex-col items-center justify-center p-8 text-center">
+      <div className="text-red-500 mb-4">
+        <svg className="w-12 h-12 mx-auto" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+          <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M12 9v2m0 4h.01m-6.938 4h13.856c1.54 0 2.502-1.667 1.732-2.5L13.732 4c-.77-.833-1.964-.833-2.732 0L3.732 16.5c-.77.833.192 2.5 1.732 2.5z" />
+        </svg>
+      </div>
+      <h3 className="text-lg font-semibold mb-2">{t('lazy.failed')}</h3>
+      <p className="text-gray-600 mb-4">{error.message}</p>
+      <button
+        onClick={retry}
+        className="px-4 py-2 bg-blue-500 text-white rounded hover:bg-blue-600 transition-colors"
+      >
+        {t('errorBoundary.retry')}
+      </button>
     </div>
-    <h3 className="text-lg font-semibold mb-2">Failed to load component</h3>
-    <p className="text-gray-600 mb-4">{error.message}</p>
-    <button
-      onClick={retry}
-      className="px-4 py-2 bg-blue-500 text-white rounded hover:bg-blue-600 transition-colors"
-    >
-      Try Again
-    </button>
-  </div>
-);
+  );
+};
 
 // Dynamic imports for heavy components
 export const LazyBackupManager = dynamic(
   () => import('./backup/BackupManager').then(mod => ({ default: mod.BackupManager })),
   {
-    loading: () => <LoadingFallback message="Loading backup manager..." />,
+    loading: () => <LoadingFallback message="lazy.backupManager" />,
     ssr: false, // Disable SSR for this component as it's heavy
   }
 );
@@ -54,7 +62,7 @@ export const LazyBackupManager = dynamic(
 export const LazyRAGManager = dynamic(
   () => import('./rag/RAGManager').then(mod => ({ default: mod.RAGManager })),
   {
-    loading: () => <LoadingFallback message="Loading RAG manager..." />,
+    loading: () => <LoadingFallback message="lazy.ragManager" />,
     ssr: false,
   }
 );
@@ -62,7 +70,7 @@ export const LazyRAGManager = dynamic(
 export const LazyRAGUpload = dynamic(
   () => import('./rag/RAGUpload').then(mod => ({ default: mod.default })),
   {
-    loading: () => <LoadingFallback message="Loading RAG upload..." />,
+    loading: () => <LoadingFallback message="lazy.ragUpload" />,
     ssr: false,
   }
 );
@@ -70,7 +78,7 @@ export const LazyRAGUpload = dynamic(
 export const LazyChatInterface = dynamic(
   () => import('./chat/ChatInterface').then(mod => ({ default: mod.ChatInterface })),
   {
-    loading: () => <LoadingFallback message="Loading chat interface..." />,
+    loading: () => <LoadingFallback message="lazy.chat" />,
     ssr: true, // Enable SSR for chat as it's core functionality
   }
 );
@@ -78,7 +86,7 @@ export const LazyChatInterface = dynamic(
 export const LazyCookingChat = dynamic(
   () => import('./cooking/CookingChat').then(mod => ({ default: mod.CookingChat })),
   {
-    loading: () => <LoadingFallback message="Loading cooking assistant..." />,
+    loading: () => <LoadingFallback message="lazy.cooking" />,
     ssr: true,
   }
 );
@@ -86,7 +94,7 @@ export const LazyCookingChat = dynamic(
 export const LazyProductTable = dynamic(
   () => import('./shopping/ProductTable').then(mod => ({ default: mod.ProductTable })),
   {
-    loading: () => <LoadingFallback message="Loading product table..." />,
+    loading: () => <LoadingFallback message="lazy.productTable" />,
     ssr: true,
   }
 );
@@ -94,7 +102,7 @@ export const LazyProductTable = dynamic(
 export const LazyReceiptUploader = dynamic(
   () => import('./shopping/ReceiptUploader').then(mod => ({ default: mod.ReceiptUploader })),
   {
-    loading: () => <LoadingFallback message="Loading receipt uploader..." />,
+    loading: () => <LoadingFallback message="lazy.receiptUploader" />,
     ssr: false,
   }
 );
@@ -102,7 +110,7 @@ export const LazyReceiptUploader = dynamic(
 export const LazyPantryList = dynamic(
   () => import('./cooking/PantryList').then(mod => ({ default: mod.PantryList })),
   {
-    loading: () => <LoadingFallback message="Loading pantry list..." />,
+    loading: () => <LoadingFallback message="lazy.pantry" />,
     ssr: true,
   }
 );
@@ -0,0 +1,33 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { Politeness, subscribeAnnouncements } from '@/lib/announcer';
+
+// Visually hidden live regions; the text is cleared first so a repeated message is read again
+export function LiveAnnouncer() {
+  const [messages, setMessages] = useState<Record<Politeness, string>>({ polite: '', assertive: '' });
+
+  useEffect(() => {
+    let timer: ReturnType<typeof setTimeout> | undefined;
+    const unsubscribe = subscribeAnnouncements((message, politeness) => {
+      setMessages((prev) => ({ ...prev, [politeness]: '' }));
+      clearTimeout(timer);
+      timer = setTimeout(() => setMessages((prev) => ({ ...prev, [politeness]: message })), 100);
+    });
+    return () => {
+      clearTimeout(timer);
+      unsubscribe();
+    };
+  }, []);
+
+  return (
+    <>
+      <div aria-live="polite" aria-atomic="true" className="sr-only">
+        {messages.polite}
+      </div>
+      <div aria-live="assertive" aria-atomic="true" className="sr-only">
+        {messages.assertive}
+      </div>
+    </>
+  );
+}
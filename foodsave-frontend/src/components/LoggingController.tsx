@@ -0,0 +1,22 @@
+"use client";
+
+import { useEffect } from 'react';
+import logger, { LOG_LEVEL_BY_NAME, setLogPersistence } from '@/lib/logger';
+import { setMetricsEnabled } from '@/lib/metrics';
+import { loadSettings, subscribeSettings } from '@/lib/settings';
+
+// Applies the log level, log persistence and metrics opt-in chosen in settings
+export function LoggingController() {
+  useEffect(() => {
+    const apply = () => {
+      const { logLevel, persistLogs, collectMetrics } = loadSettings();
+      logger.setLevel(LOG_LEVEL_BY_NAME[logLevel] ?? LOG_LEVEL_BY_NAME.info);
+      setLogPersistence(persistLogs);
+      setMetricsEnabled(collectMetrics);
+    };
+    apply();
+    return subscribeSettings(apply);
+  }, []);
+
+  return null;
+}
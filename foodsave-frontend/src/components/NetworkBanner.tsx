@@ -0,0 +1,56 @@
+"use client";
+
+import { useEffect, useRef } from 'react';
+import { onlineManager } from '@tanstack/react-query';
+import { WifiOff } from 'lucide-react';
+import { startNetworkMonitor } from '@/lib/network';
+import { queryCacheSavedAt } from '@/lib/offlineCache';
+import { formatRelativeTime } from '@/lib/time';
+import { pushToast } from '@/lib/toasts';
+import { resolveBaseUrl } from '@/services/ApiService';
+import { useNetworkStatus } from '@/hooks/useNetworkStatus';
+import { useTranslation } from '@/hooks/useTranslation';
+import type { MessageKey } from '@/lib/i18n';
+
+const MESSAGES: Record<'offline' | 'unreachable', MessageKey> = {
+  offline: 'network.offline',
+  unreachable: 'network.unreachable',
+};
+
+// Thin banner while offline or while the backend is unreachable. react-query is told
+// about it too, so queries pause and pages read from the offline cache until it's back.
+export function NetworkBanner() {
+  const status = useNetworkStatus();
+  const { t, locale } = useTranslation();
+  const wasDisconnected = useRef(false);
+
+  useEffect(() => startNetworkMonitor(resolveBaseUrl), []);
+
+  useEffect(() => {
+    onlineManager.setOnline(status === 'online');
+    if (status !== 'online') {
+      wasDisconnected.current = true;
+    } else if (wasDisconnected.current) {
+      wasDisconnected.current = false;
+      // react-query sam odświeża zapytania po odzyskaniu połączenia
+      pushToast(t('network.restored'), { severity: 'success' });
+    }
+  }, [status, t]);
+
+  if (status === 'online') return null;
+
+  const savedAt = queryCacheSavedAt();
+
+  return (
+    <div
+      role="status"
+      className="fixed top-0 inset-x-0 z-40 flex items-center justify-center gap-2 bg-amber-100 py-1 text-xs text-amber-900"
+    >
+      <WifiOff className="h-3 w-3" />
+      {t('network.showingSaved', {
+        status: t(MESSAGES[status]),
+        savedAt: savedAt ? formatRelativeTime(savedAt, new Date(), locale) : t('network.savedEarlier'),
+      })}
+    </div>
+  );
+}
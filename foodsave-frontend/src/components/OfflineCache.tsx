@@ -0,0 +1,14 @@
+"use client";
+
+import { useEffect } from 'react';
+import { useQueryClient } from '@tanstack/react-query';
+import { persistQueryCache } from '@/lib/offlineCache';
+
+// Mirrors the query cache in localStorage; NetworkBanner tells the user when it's in use
+export function OfflineCache() {
+  const queryClient = useQueryClient();
+
+  useEffect(() => persistQueryCache(queryClient), [queryClient]);
+
+  return null;
+}
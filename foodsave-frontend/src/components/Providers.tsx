@@ -4,6 +4,21 @@ import React from 'react';
 import { QueryClientProvider } from '@tanstack/react-query';
 import { ReactQueryDevtools } from '@tanstack/react-query-devtools';
 import queryClient from '@/lib/queryClient';
+import { BackgroundJobs } from '@/components/BackgroundJobs';
+import { ThemeController } from '@/components/ThemeController';
+import { I18nProvider } from '@/components/I18nProvider';
+import { KeyboardShortcuts } from '@/components/KeyboardShortcuts';
+import { GlobalProgress } from '@/components/GlobalProgress';
+import { LastPageTracker } from '@/components/LastPageTracker';
+import { LiveAnnouncer } from '@/components/LiveAnnouncer';
+import { LoggingController } from '@/components/LoggingController';
+import { NetworkBanner } from '@/components/NetworkBanner';
+import { OfflineCache } from '@/components/OfflineCache';
+import { QueryErrorReporter } from '@/components/QueryErrorReporter';
+import { QuickAsk } from '@/components/chat/QuickAsk';
+import { SessionRecovery } from '@/components/SessionRecovery';
+import { TabPresence } from '@/components/TabPresence';
+import { Toaster } from '@/components/Toaster';
 
 interface ProvidersProps {
   children: React.ReactNode;
@@ -12,12 +27,43 @@ interface ProvidersProps {
 export function Providers({ children }: ProvidersProps) {
   // Don't render QueryClientProvider if queryClient is not available (SSR)
   if (!queryClient) {
-    return <>{children}</>;
+    return (
+      <I18nProvider>
+        {children}
+        <ThemeController />
+        <LoggingController />
+        <NetworkBanner />
+        <KeyboardShortcuts />
+        <QuickAsk />
+        <LastPageTracker />
+        <SessionRecovery />
+        <BackgroundJobs />
+        <Toaster />
+        <LiveAnnouncer />
+        <TabPresence />
+      </I18nProvider>
+    );
   }
 
   return (
     <QueryClientProvider client={queryClient}>
-      {children}
+      <I18nProvider>
+        <GlobalProgress />
+        <OfflineCache />
+        <QueryErrorReporter />
+        {children}
+        <ThemeController />
+        <LoggingController />
+        <NetworkBanner />
+        <KeyboardShortcuts />
+        <QuickAsk />
+        <LastPageTracker />
+        <SessionRecovery />
+        <BackgroundJobs />
+        <Toaster />
+        <LiveAnnouncer />
+        <TabPresence />
+      </I18nProvider>
 
       {/* React Query DevTools - only in development */}
       {process.env.NODE_ENV === 'development' && (
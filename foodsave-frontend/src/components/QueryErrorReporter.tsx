@@ -0,0 +1,47 @@
+"use client";
+
+import { useEffect } from 'react';
+import { Query, useQueryClient } from '@tanstack/react-query';
+import { reportError } from '@/lib/errors';
+import { isOnline } from '@/lib/network';
+import type { MessageKey } from '@/lib/i18n';
+import { useTranslation } from '@/hooks/useTranslation';
+
+// Co się nie udało pobrać - dla zapytań spoza tej listy można podać meta.errorContext
+const QUERY_CONTEXTS: Record<string, MessageKey> = {
+  products: 'queries.products',
+  'shopping-trips': 'queries.trips',
+  'spending-stats': 'queries.spendingStats',
+  'weather-forecast': 'queries.weather',
+  'calendar-events': 'queries.calendar',
+};
+
+function queryErrorContext(query: Query, t: (key: MessageKey) => string): string | undefined {
+  const fromMeta = query.meta?.errorContext;
+  if (typeof fromMeta === 'string') return fromMeta;
+  const key = QUERY_CONTEXTS[String(query.queryKey[0])];
+  return key && t(key);
+}
+
+// Failed queries end up as an error toast with "Ponów" that refetches the same query,
+// instead of a dead error message on the page.
+export function QueryErrorReporter() {
+  const client = useQueryClient();
+  const { t } = useTranslation();
+
+  useEffect(() => {
+    return client.getQueryCache().subscribe((event) => {
+      if (event.type !== 'updated' || event.action.type !== 'error') return;
+      const { query } = event;
+      // Bez połączenia wystarcza baner; odświeżanie w tle nieużywanych danych nie zaczepia
+      if (!isOnline() || query.getObserversCount() === 0) return;
+      const context = queryErrorContext(query, t);
+      if (!context) return;
+      reportError(event.action.error, context, () => {
+        void client.refetchQueries({ queryKey: query.queryKey, exact: true });
+      });
+    });
+  }, [client, t]);
+
+  return null;
+}
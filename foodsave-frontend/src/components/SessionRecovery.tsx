@@ -0,0 +1,60 @@
+"use client";
+
+import { useEffect } from 'react';
+import { useRouter } from 'next/navigation';
+import {
+  HEARTBEAT_MS,
+  RecoveryData,
+  beginSession,
+  discardRecovery,
+  endSession,
+  getRecoveryData,
+  hasRecoverableData,
+  heartbeat,
+} from '@/lib/recovery';
+import { pushToast } from '@/lib/toasts';
+import type { MessageKey } from '@/lib/i18n';
+import { useTranslation } from '@/hooks/useTranslation';
+
+function restoreTarget(data: RecoveryData): string {
+  if (data.receipt) return '/shopping?restore=receipt';
+  const draft = data.chatDrafts.general ?? Object.values(data.chatDrafts)[0] ?? '';
+  return `/chat?prompt=${encodeURIComponent(draft)}`;
+}
+
+function describe(data: RecoveryData): MessageKey {
+  return data.receipt ? 'recovery.receipt' : 'recovery.message';
+}
+
+// Keeps the session marker alive and, after a crash, offers to restore unsaved work
+export function SessionRecovery() {
+  const router = useRouter();
+  const { t } = useTranslation();
+
+  useEffect(() => {
+    const start = beginSession();
+    const data = getRecoveryData();
+    if (start === 'fresh') {
+      // Poprawne zamknięcie - szkice z poprzedniej sesji nie są już potrzebne
+      discardRecovery();
+    } else if (start === 'crashed' && hasRecoverableData(data)) {
+      pushToast(t('recovery.prompt', { what: t(describe(data)) }), {
+        severity: 'warning',
+        duration: 0,
+        action: { label: t('recovery.restore'), onClick: () => router.push(restoreTarget(data)) },
+      });
+    }
+
+    const timer = window.setInterval(() => heartbeat(), HEARTBEAT_MS);
+    const onPageShow = () => heartbeat();
+    window.addEventListener('pagehide', endSession);
+    window.addEventListener('pageshow', onPageShow);
+    return () => {
+      window.clearInterval(timer);
+      window.removeEventListener('pagehide', endSession);
+      window.removeEventListener('pageshow', onPageShow);
+    };
+  }, [router, t]);
+
+  return null;
+}
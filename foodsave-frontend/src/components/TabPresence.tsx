@@ -0,0 +1,25 @@
+"use client";
+
+import { useEffect, useRef } from 'react';
+import { useTabRole } from '@/hooks/useTabLeader';
+import { subscribeActiveProfileChange } from '@/lib/profiles';
+import { pushToast } from '@/lib/toasts';
+import { useTranslation } from '@/hooks/useTranslation';
+
+// Lets the user know when FoodSave is already open in another tab of this browser,
+// and reloads this tab when another one switches the household profile
+export function TabPresence() {
+  const role = useTabRole();
+  const shown = useRef(false);
+  const { t } = useTranslation();
+
+  useEffect(() => subscribeActiveProfileChange(() => window.location.reload()), []);
+
+  useEffect(() => {
+    if (role !== 'follower' || shown.current) return;
+    shown.current = true;
+    pushToast(t('tabs.openElsewhere'));
+  }, [role, t]);
+
+  return null;
+}
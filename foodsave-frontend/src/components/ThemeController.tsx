@@ -0,0 +1,27 @@
+"use client";
+
+import { useEffect } from 'react';
+import { loadSettings, subscribeSettings } from '@/lib/settings';
+import { applyAccessibility, applyTheme, applyUiScale, subscribeSystemTheme } from '@/lib/theme';
+
+// Applies the saved theme, palette, UI scale and accessibility modes, and follows the OS
+// preferences for those set to "system"
+export function ThemeController() {
+  useEffect(() => {
+    const apply = () => {
+      const { theme, palette, uiScale, highContrast, reducedMotion } = loadSettings();
+      applyTheme(theme, palette);
+      applyUiScale(uiScale);
+      applyAccessibility(highContrast, reducedMotion);
+    };
+    apply();
+    const unsubscribeSettings = subscribeSettings(apply);
+    const unsubscribeSystem = subscribeSystemTheme(apply);
+    return () => {
+      unsubscribeSettings();
+      unsubscribeSystem();
+    };
+  }, []);
+
+  return null;
+}
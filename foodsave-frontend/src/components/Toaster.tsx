@@ -0,0 +1,70 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { AlertCircle, AlertTriangle, CheckCircle2, Info, X } from 'lucide-react';
+import { useTranslation } from '@/hooks/useTranslation';
+import { Toast, ToastSeverity, dismissToast, getToasts, runToastAction, subscribeToasts } from '@/lib/toasts';
+
+const SEVERITY_STYLES: Record<ToastSeverity, string> = {
+  info: 'border-blue-200 bg-blue-50 text-blue-900',
+  success: 'border-green-200 bg-green-50 text-green-900',
+  warning: 'border-amber-200 bg-amber-50 text-amber-900',
+  error: 'border-red-200 bg-red-50 text-red-900',
+};
+
+const SEVERITY_ICONS: Record<ToastSeverity, typeof Info> = {
+  info: Info,
+  success: CheckCircle2,
+  warning: AlertTriangle,
+  error: AlertCircle,
+};
+
+export function Toaster() {
+  const { t } = useTranslation();
+  const [toasts, setToasts] = useState<Toast[]>([]);
+
+  useEffect(() => {
+    const refresh = () => setToasts(getToasts());
+    refresh();
+    return subscribeToasts(refresh);
+  }, []);
+
+  return (
+    <div
+      aria-live="polite"
+      data-focus-region={toasts.length > 0 ? 'notifications' : undefined}
+      className="fixed bottom-20 right-4 z-50 flex w-80 max-w-[calc(100vw-2rem)] flex-col gap-2 md:bottom-4"
+    >
+      {toasts.map((toast) => {
+        const Icon = SEVERITY_ICONS[toast.severity];
+        return (
+          <div
+            key={toast.id}
+            role={toast.severity === 'error' ? 'alert' : 'status'}
+            className={`flex items-start gap-2 rounded-md border p-3 text-sm shadow-md ${SEVERITY_STYLES[toast.severity]}`}
+          >
+            <Icon className="h-4 w-4 mt-0.5 shrink-0" />
+            <span className="flex-1">{toast.message}</span>
+            {toast.action && (
+              <button
+                type="button"
+                onClick={() => runToastAction(toast.id)}
+                className="shrink-0 font-semibold underline-offset-2 hover:underline"
+              >
+                {toast.action.label}
+              </button>
+            )}
+            <button
+              type="button"
+              onClick={() => dismissToast(toast.id)}
+              aria-label={t('common.close')}
+              className="shrink-0 opacity-60 hover:opacity-100"
+            >
+              <X className="h-4 w-4" />
+            </button>
+          </div>
+        );
+      })}
+    </div>
+  );
+}
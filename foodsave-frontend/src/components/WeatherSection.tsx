@@ -3,6 +3,20 @@ import React, { useEffect, useState, useCallback } from 'react';
 import { Card, CardContent, CardHeader, CardTitle } from './ui/Card';
 import { ApiService, ApiError } from '@/services/ApiService';
 import logger from '../lib/logger';
+import { ForecastRow } from './dashboard/ForecastRow';
+import { WeatherIcon } from './dashboard/WeatherIcon';
+import { WidgetRefreshButton } from './dashboard/WidgetRefreshButton';
+import { WeatherAlertBanner } from './dashboard/WeatherAlertBanner';
+import { useWidgetRefresh } from '@/hooks/useWidgetRefresh';
+import { WEATHER_TINTS, cachedWeatherAdvice, saveWeatherAdvice, weatherKind } from '@/lib/weather';
+import { localIsoDate } from '@/lib/time';
+import { activeWeatherCity } from '@/lib/settings';
+import { useSettings } from '@/hooks/useSettings';
+import { useTranslation } from '@/hooks/useTranslation';
+import { WeatherLocationSwitcher } from './dashboard/WeatherLocationSwitcher';
+import { WeatherAlert, WeatherForecastDay } from '@/types/api';
+import { EmptyState } from '@/components/ui/EmptyState';
+import { LoadingState } from '@/components/ui/LoadingState';
 
 interface WeatherData {
   location: string;
@@ -11,24 +25,64 @@ interface WeatherData {
   icon: string;
 }
 
+// Ponawianie po błędzie: 5 s, 10 s, 20 s, ... maks. 2 min, potem tylko ręcznie
+const RETRY_BASE_MS = 5_000;
+const RETRY_MAX_MS = 120_000;
+const MAX_AUTO_RETRIES = 5;
+
 export function WeatherSection() {
+  const { t } = useTranslation();
   const [weatherData, setWeatherData] = useState<WeatherData[]>([]);
+  const [forecast, setForecast] = useState<WeatherForecastDay[]>([]);
+  const [alerts, setAlerts] = useState<WeatherAlert[]>([]);
+  const [advice, setAdvice] = useState<string | null>(null);
   const [isLoading, setIsLoading] = useState(true);
   const [error, setError] = useState<string | null>(null);
+  const [retryCount, setRetryCount] = useState(0);
+  const { settings, updateSettings, isLoaded } = useSettings();
+  const location = activeWeatherCity(settings);
 
   const fetchWeather = useCallback(async (signal?: AbortSignal) => {
     setIsLoading(true);
     setError(null);
 
     try {
-      // Lokacje, dla których chcemy pobrać pogodę
-      const locations = ['Ząbki', 'Warszawa'];
+      // Wybrana zapisana lokalizacja
+      const locations = [location];
       logger.info('Fetching weather for locations:', locations);
 
       const data = await ApiService.getWeather(locations, signal);
       logger.info('Weather data received:', data);
 
       setWeatherData(data as WeatherData[]);
+      setRetryCount(0);
+
+      // Prognoza wielodniowa dla pierwszej lokalizacji - brak nie blokuje karty
+      try {
+        const forecastData = await ApiService.getWeatherForecast(locations[0], 5, signal);
+        setForecast(forecastData.forecast ?? []);
+        setAlerts(forecastData.alerts ?? []);
+      } catch (forecastErr) {
+        logger.info('Weather forecast unavailable:', forecastErr);
+        setForecast([]);
+        setAlerts([]);
+      }
+
+      // Podpowiedź "co ubrać" - opcjonalna, generowana przez LLM raz dziennie dla lokalizacji
+      try {
+        const day = localIsoDate(new Date());
+        let hint = cachedWeatherAdvice(locations[0], day);
+        if (!hint) {
+          const adviceData = await ApiService.getWeatherAdvice(locations[0], signal);
+          hint = adviceData.hint;
+          // Podpowiedź z reguł (LLM niedostępny) nie blokuje kolejnej próby
+          if (adviceData.source === 'llm') saveWeatherAdvice(locations[0], day, hint);
+        }
+        setAdvice(hint);
+      } catch (adviceErr) {
+        logger.info('Weather advice unavailable:', adviceErr);
+        setAdvice(null);
+      }
     } catch (err) {
       // Check for all possible cancellation/abort error types
       const isCanceled =
@@ -42,13 +96,15 @@ export function WeatherSection() {
 
       // Log as error only for non-cancellation errors
       logger.error('Weather fetch error:', err);
-      setError('Nie udało się pobrać danych pogodowych.');
+      setError(t('weather.error'));
     } finally {
       setIsLoading(false);
     }
-  }, []);
+  }, [location, t]);
 
   useEffect(() => {
+    // Wait for stored settings so we don't fetch the default location first
+    if (!isLoaded) return;
     const controller = new AbortController();
     let isMounted = true;
 
@@ -69,7 +125,27 @@ export function WeatherSection() {
       isMounted = false;
       controller.abort();
     };
-  }, [fetchWeather]);
+  }, [fetchWeather, isLoaded]);
+
+  // Capped exponential backoff instead of retrying immediately
+  useEffect(() => {
+    if (!error || isLoading || retryCount >= MAX_AUTO_RETRIES) return;
+    const delay = Math.min(RETRY_BASE_MS * 2 ** retryCount, RETRY_MAX_MS);
+    const timer = setTimeout(() => {
+      setRetryCount((count) => count + 1);
+      fetchWeather();
+    }, delay);
+    return () => clearTimeout(timer);
+  }, [error, isLoading, retryCount, fetchWeather]);
+
+  const handleRetry = () => {
+    setRetryCount(0);
+    fetchWeather();
+  };
+
+  useWidgetRefresh('weather', () => {
+    fetchWeather();
+  });
 
   // DEBUG: Print render state
   logger.debug('Render: isLoading', { isLoading, error, weatherData });
@@ -77,19 +153,44 @@ export function WeatherSection() {
   return (
     <Card>
       <CardHeader>
-        <CardTitle>Prognoza pogody</CardTitle>
+        <div className="flex items-center justify-between">
+          <CardTitle>{t('weather.title')}</CardTitle>
+          <div className="flex items-center gap-1">
+            <WeatherLocationSwitcher
+              locations={settings.weatherLocations}
+              active={location}
+              onSelect={(city) => updateSettings({ activeWeatherLocation: city })}
+              onChange={(weatherLocations) => updateSettings({ weatherLocations })}
+            />
+            <WidgetRefreshButton onClick={() => fetchWeather()} isRefreshing={isLoading} label={t('weather.refresh')} />
+          </div>
+        </div>
       </CardHeader>
       <CardContent>
+        {weatherData.length > 0 && <WeatherAlertBanner location={weatherData[0].location} alerts={alerts} />}
         <div className="space-y-2">
-          {isLoading && <p>Ładowanie prognozy...</p>}
-          {error && weatherData.length === 0 && <p className="text-red-500">{error}</p>}
+          {isLoading && weatherData.length === 0 && <LoadingState label={t('weather.loading')} />}
+          {error && !isLoading && (
+            <div role="alert" className="flex items-center justify-between gap-2 p-2 rounded bg-red-50 text-sm">
+              <span className="text-red-600">
+                {weatherData.length > 0 ? t('weather.stale') : error}
+                {retryCount < MAX_AUTO_RETRIES && ` ${t('weather.retrying')}`}
+              </span>
+              <button type="button" onClick={handleRetry} className="px-2 py-1 rounded border border-red-200 text-red-700 hover:bg-red-100">
+                {t('common.retry')}
+              </button>
+            </div>
+          )}
           {!isLoading && weatherData.length === 0 && !error && (
-            <p className="text-gray-500">Brak danych pogodowych</p>
+            <EmptyState message={t('weather.empty')} />
           )}
-          {!isLoading && weatherData.length > 0 && weatherData.map((weather: WeatherData) => (
-            <div key={weather.location} className="flex items-center justify-between">
+          {weatherData.length > 0 && weatherData.map((weather: WeatherData) => (
+            <div
+              key={weather.location}
+              className={`flex items-center justify-between p-3 rounded-lg ${WEATHER_TINTS[weatherKind(weather.condition)]}`}
+            >
               <div className="flex items-center">
-                <span className="text-2xl mr-2">{weather.icon}</span>
+                <WeatherIcon condition={weather.condition} className="h-8 w-8 mr-3" />
                 <div>
                   <p className="font-medium">{weather.location}</p>
                   <p className="text-sm text-muted-foreground">{weather.condition}</p>
@@ -98,6 +199,14 @@ export function WeatherSection() {
               <p className="text-lg font-bold">{weather.temperature ? `${Math.round(weather.temperature)}°C` : 'N/A'}</p>
             </div>
           ))}
+          {advice && (
+            <p className="text-sm text-muted-foreground italic">👕 {advice}</p>
+          )}
+          {forecast.length > 0 && (
+            <div className="pt-3 mt-3 border-t">
+              <ForecastRow days={forecast} />
+            </div>
+          )}
         </div>
       </CardContent>
     </Card>
@@ -0,0 +1,72 @@
+"use client";
+
+import { SpendingBucket } from '@/types/analytics';
+import { EmptyState } from '@/components/ui/EmptyState';
+import { useTranslation } from '@/hooks/useTranslation';
+import { formatMoney, money } from '@/lib/money';
+
+const COLORS = ['#3b82f6', '#10b981', '#f59e0b', '#ef4444', '#8b5cf6', '#ec4899', '#14b8a6', '#6b7280'];
+
+interface CategoryPieChartProps {
+  data: SpendingBucket[];
+}
+
+// Point on the circle for a fraction of the full turn, starting at 12 o'clock
+function pointAt(fraction: number, radius: number): [number, number] {
+  const angle = 2 * Math.PI * fraction - Math.PI / 2;
+  return [radius + radius * Math.cos(angle), radius + radius * Math.sin(angle)];
+}
+
+export function CategoryPieChart({ data }: CategoryPieChartProps) {
+  const { t } = useTranslation();
+  const total = data.reduce((sum, b) => sum + b.total, 0);
+  if (total <= 0) {
+    return <EmptyState message={t('analytics.noData')} />;
+  }
+
+  const radius = 80;
+  let offset = 0;
+  const slices = data.map((bucket, index) => {
+    const fraction = bucket.total / total;
+    const color = COLORS[index % COLORS.length];
+    const start = offset;
+    offset += fraction;
+
+    // A single 100% slice cannot be drawn as an arc
+    if (fraction >= 0.9999) {
+      return <circle key={bucket.label} cx={radius} cy={radius} r={radius} fill={color} />;
+    }
+
+    const [x1, y1] = pointAt(start, radius);
+    const [x2, y2] = pointAt(offset, radius);
+    const largeArc = fraction > 0.5 ? 1 : 0;
+    return (
+      <path
+        key={bucket.label}
+        d={`M ${radius} ${radius} L ${x1} ${y1} A ${radius} ${radius} 0 ${largeArc} 1 ${x2} ${y2} Z`}
+        fill={color}
+      >
+        <title>{`${bucket.label}: ${formatMoney(money(bucket.total))}`}</title>
+      </path>
+    );
+  });
+
+  return (
+    <div className="flex flex-col sm:flex-row items-center gap-6">
+      <svg width={radius * 2} height={radius * 2} viewBox={`0 0 ${radius * 2} ${radius * 2}`} role="img" aria-label={t('analytics.byCategory')}>
+        {slices}
+      </svg>
+      <ul className="space-y-1 text-sm">
+        {data.map((bucket, index) => (
+          <li key={bucket.label} className="flex items-center gap-2">
+            <span className="inline-block w-3 h-3 rounded-sm" style={{ backgroundColor: COLORS[index % COLORS.length] }} />
+            <span className="font-medium">{bucket.label}</span>
+            <span className="text-gray-500">
+              {formatMoney(money(bucket.total))} ({((bucket.total / total) * 100).toFixed(0)}%)
+            </span>
+          </li>
+        ))}
+      </ul>
+    </div>
+  );
+}
@@ -0,0 +1,69 @@
+"use client";
+
+import { Input } from '@/components/ui/Input';
+import { useTranslation } from '@/hooks/useTranslation';
+import type { MessageKey } from '@/lib/i18n';
+import { localIsoDate } from '@/lib/time';
+import { DateRange } from '@/types/analytics';
+
+export type RangePreset = 'month' | '3months' | '12months' | 'custom';
+
+const PRESETS: { value: RangePreset; labelKey: MessageKey }[] = [
+  { value: 'month', labelKey: 'analytics.range.month' },
+  { value: '3months', labelKey: 'analytics.range.3months' },
+  { value: '12months', labelKey: 'analytics.range.12months' },
+  { value: 'custom', labelKey: 'analytics.range.custom' },
+];
+
+export function getPresetRange(preset: Exclude<RangePreset, 'custom'>, today: Date = new Date()): DateRange {
+  const monthsBack = preset === 'month' ? 0 : preset === '3months' ? 2 : 11;
+  const from = new Date(today.getFullYear(), today.getMonth() - monthsBack, 1);
+  return { from: localIsoDate(from), to: localIsoDate(today) };
+}
+
+interface DateRangeSelectorProps {
+  preset: RangePreset;
+  range: DateRange;
+  onChange: (preset: RangePreset, range: DateRange) => void;
+}
+
+export function DateRangeSelector({ preset, range, onChange }: DateRangeSelectorProps) {
+  const { t } = useTranslation();
+  return (
+    <div className="flex flex-wrap items-end gap-2">
+      {PRESETS.map(({ value, labelKey }) => (
+        <button
+          key={value}
+          type="button"
+          onClick={() => onChange(value, value === 'custom' ? range : getPresetRange(value))}
+          className={`px-3 py-2 text-sm rounded-md border transition-colors ${
+            preset === value
+              ? 'bg-primary text-primary-foreground border-primary'
+              : 'border-gray-300 hover:bg-gray-50'
+          }`}
+          aria-pressed={preset === value}
+        >
+          {t(labelKey)}
+        </button>
+      ))}
+      {preset === 'custom' && (
+        <div className="flex gap-2">
+          <Input
+            type="date"
+            label={t('analytics.range.from')}
+            value={range.from}
+            max={range.to}
+            onChange={(e) => onChange('custom', { ...range, from: e.target.value })}
+          />
+          <Input
+            type="date"
+            label={t('analytics.range.to')}
+            value={range.to}
+            min={range.from}
+            onChange={(e) => onChange('custom', { ...range, to: e.target.value })}
+          />
+        </div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,109 @@
+"use client";
+
+import { useState } from 'react';
+import { ChevronLeft, ChevronRight } from 'lucide-react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Button } from '@/components/ui/Button';
+import { LoadingState } from '@/components/ui/LoadingState';
+import { useMonthlyReport } from '@/hooks/useMonthlyReport';
+import { useTranslation } from '@/hooks/useTranslation';
+import { formatChange, formatMonth, lastCompletedMonth, shiftMonth } from '@/lib/monthlyReport';
+import { formatMoney } from '@/lib/money';
+import { localIsoDate } from '@/lib/time';
+
+// Wzrost wydatków na czerwono, spadek na zielono
+function changeClass(change: number | null): string {
+  if (change === null || Math.round(change * 100) === 0) return 'text-gray-500';
+  return change > 0 ? 'text-red-600' : 'text-emerald-600';
+}
+
+// Full monthly report; opened from the month-end notification and the dashboard card
+export function MonthlyReportView() {
+  const { t, locale } = useTranslation();
+  const [month, setMonth] = useState(() => lastCompletedMonth());
+  const currentMonth = localIsoDate(new Date()).slice(0, 7);
+  const { report, isLoading, error } = useMonthlyReport(month);
+
+  return (
+    <Card id="monthly-report" className="scroll-mt-6">
+      <CardHeader>
+        <div className="flex flex-wrap items-center justify-between gap-2">
+          <CardTitle>{t('report.title', { month: formatMonth(month, locale) })}</CardTitle>
+          <div className="flex gap-1">
+            <Button
+              variant="outline"
+              size="icon"
+              onClick={() => setMonth(shiftMonth(month, -1))}
+              aria-label={t('report.previous')}
+            >
+              <ChevronLeft className="h-4 w-4" />
+            </Button>
+            <Button
+              variant="outline"
+              size="icon"
+              onClick={() => setMonth(shiftMonth(month, 1))}
+              disabled={month >= currentMonth}
+              aria-label={t('report.next')}
+            >
+              <ChevronRight className="h-4 w-4" />
+            </Button>
+          </div>
+        </div>
+      </CardHeader>
+      <CardContent>
+        {isLoading ? (
+          <LoadingState size="sm" />
+        ) : error ? (
+          <p className="text-sm text-red-600">{t('report.error', { error: (error as Error).message })}</p>
+        ) : !report || report.receipts === 0 ? (
+          <p className="text-sm text-gray-500">{t('report.empty')}</p>
+        ) : (
+          <div className="space-y-4">
+            <div className="grid grid-cols-2 sm:grid-cols-4 gap-4 text-center">
+              <div>
+                <p className="text-2xl font-bold">{formatMoney(report.total)}</p>
+                <p className="text-xs text-gray-500">{t('report.spent')}</p>
+              </div>
+              <div>
+                <p className={`text-2xl font-bold ${changeClass(report.change)}`}>{formatChange(report.change)}</p>
+                <p className="text-xs text-gray-500">{t('report.versus', { amount: formatMoney(report.previousTotal) })}</p>
+              </div>
+              <div>
+                <p className="text-2xl font-bold">{report.receipts}</p>
+                <p className="text-xs text-gray-500">{t('report.receipts')}</p>
+              </div>
+              <div>
+                <p className={`text-2xl font-bold ${report.wastedCount > 0 ? 'text-amber-700' : ''}`}>
+                  {report.wastedCount}
+                </p>
+                <p className="text-xs text-gray-500">{t('report.wasted', { amount: formatMoney(report.wastedTotal) })}</p>
+              </div>
+            </div>
+            <table className="w-full text-sm">
+              <thead className="text-left text-xs text-gray-500">
+                <tr>
+                  <th className="py-1 font-medium">{t('report.category')}</th>
+                  <th className="py-1 font-medium text-right">{formatMonth(month, locale)}</th>
+                  <th className="py-1 font-medium text-right">{formatMonth(shiftMonth(month, -1), locale)}</th>
+                  <th className="py-1 font-medium text-right">{t('report.change')}</th>
+                </tr>
+              </thead>
+              <tbody className="divide-y">
+                {report.categories.map((category) => (
+                  <tr key={category.label}>
+                    <td className="py-1">{category.label}</td>
+                    <td className="py-1 text-right">{formatMoney(category.total)}</td>
+                    <td className="py-1 text-right text-gray-500">{formatMoney(category.previous)}</td>
+                    <td className={`py-1 text-right ${changeClass(category.change)}`}>
+                      {formatChange(category.change)}
+                    </td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          </div>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
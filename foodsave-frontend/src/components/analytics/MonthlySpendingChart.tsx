@@ -0,0 +1,35 @@
+"use client";
+
+import { SpendingBucket } from '@/types/analytics';
+import { EmptyState } from '@/components/ui/EmptyState';
+import { useTranslation } from '@/hooks/useTranslation';
+import { formatMoney, money } from '@/lib/money';
+
+interface MonthlySpendingChartProps {
+  data: SpendingBucket[];
+}
+
+export function MonthlySpendingChart({ data }: MonthlySpendingChartProps) {
+  const { t } = useTranslation();
+  if (data.length === 0) {
+    return <EmptyState message={t('analytics.noData')} />;
+  }
+
+  const max = Math.max(...data.map((b) => b.total), 1);
+
+  return (
+    <div className="flex items-end gap-2 h-56" role="img" aria-label={t('analytics.monthly')}>
+      {data.map((bucket) => (
+        <div key={bucket.label} className="flex-1 flex flex-col items-center justify-end h-full min-w-0">
+          <span className="text-xs text-gray-600 mb-1">{formatMoney(money(bucket.total), { wholeUnits: true })}</span>
+          <div
+            className="w-full bg-blue-500 rounded-t"
+            style={{ height: `${(bucket.total / max) * 100}%` }}
+            title={`${bucket.label}: ${formatMoney(money(bucket.total))} (${t('analytics.receipts', { count: bucket.count })})`}
+          />
+          <span className="text-xs text-gray-500 mt-1 truncate">{bucket.label}</span>
+        </div>
+      ))}
+    </div>
+  );
+}
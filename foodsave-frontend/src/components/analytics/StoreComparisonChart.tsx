@@ -0,0 +1,37 @@
+"use client";
+
+import { SpendingBucket } from '@/types/analytics';
+import { EmptyState } from '@/components/ui/EmptyState';
+import { useTranslation } from '@/hooks/useTranslation';
+import { formatMoney, money } from '@/lib/money';
+
+interface StoreComparisonChartProps {
+  data: SpendingBucket[];
+}
+
+export function StoreComparisonChart({ data }: StoreComparisonChartProps) {
+  const { t } = useTranslation();
+  if (data.length === 0) {
+    return <EmptyState message={t('analytics.noData')} />;
+  }
+
+  const max = Math.max(...data.map((b) => b.total), 1);
+
+  return (
+    <ul className="space-y-3">
+      {data.map((bucket) => (
+        <li key={bucket.label}>
+          <div className="flex justify-between text-sm mb-1">
+            <span className="font-medium">{bucket.label}</span>
+            <span className="text-gray-600">
+              {formatMoney(money(bucket.total))} · {t('analytics.receipts', { count: bucket.count })}
+            </span>
+          </div>
+          <div className="h-3 bg-gray-100 rounded">
+            <div className="h-3 bg-emerald-500 rounded" style={{ width: `${(bucket.total / max) * 100}%` }} />
+          </div>
+        </li>
+      ))}
+    </ul>
+  );
+}
@@ -5,6 +5,7 @@ import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
 import { Button } from '@/components/ui/Button';
 import { Input } from '@/components/ui/Input';
 import { ApiService } from '@/services/ApiService';
+import { useTranslation } from '@/hooks/useTranslation';
 
 interface Backup {
   name: string;
@@ -45,6 +46,7 @@ interface BackupResult {
 }
 
 export function BackupManager() {
+  const { t, locale } = useTranslation();
   const [isLoading, setIsLoading] = useState(false);
   const [backups, setBackups] = useState<Backup[]>([]);
   const [stats, setStats] = useState<BackupStats | null>(null);
@@ -112,22 +114,22 @@ export function BackupManager() {
       await loadBackups();
       await loadStats();
 
-      alert('Backup created successfully!');
+      alert(t('backup.created'));
     } catch (error) {
       console.error('Error creating backup:', error);
-      alert('Error creating backup');
+      alert(t('backup.createFailed'));
     } finally {
       setIsLoading(false);
     }
-  }, [backupName, loadBackups, loadStats]);
+  }, [backupName, loadBackups, loadStats, t]);
 
   const restoreBackup = useCallback(async () => {
     if (!selectedBackup) {
-      alert('Please select a backup to restore');
+      alert(t('backup.selectFirst'));
       return;
     }
 
-    if (!confirm(`Are you sure you want to restore backup "${selectedBackup}"? This will overwrite current data.`)) {
+    if (!confirm(t('backup.restoreConfirm', { name: selectedBackup }))) {
       return;
     }
 
@@ -140,16 +142,16 @@ export function BackupManager() {
 
       const response = await ApiService.post(`/api/v2/backup/restore/${selectedBackup}?${params}`) as any;
 
-      alert(`Backup restored successfully! Status: ${response.data.status}`);
+      alert(t('backup.restored', { status: response.data.status }));
       setSelectedBackup('');
       setRestoreComponents('');
     } catch (error) {
       console.error('Error restoring backup:', error);
-      alert('Error restoring backup');
+      alert(t('backup.restoreFailed'));
     } finally {
       setIsLoading(false);
     }
-  }, [selectedBackup, restoreComponents]);
+  }, [selectedBackup, restoreComponents, t]);
 
   const verifyBackup = useCallback(async (backupName: string) => {
     try {
@@ -157,17 +159,17 @@ export function BackupManager() {
       const response = await ApiService.post(`/api/v2/backup/verify/${backupName}`) as any;
 
       const status = response.data.overall_status;
-      alert(`Backup verification completed. Status: ${status}`);
+      alert(t('backup.verified', { status }));
     } catch (error) {
       console.error('Error verifying backup:', error);
-      alert('Error verifying backup');
+      alert(t('backup.verifyFailed'));
     } finally {
       setIsLoading(false);
     }
-  }, []);
+  }, [t]);
 
   const cleanupOldBackups = useCallback(async () => {
-    if (!confirm('Are you sure you want to cleanup old backups? This action cannot be undone.')) {
+    if (!confirm(t('backup.cleanupConfirm'))) {
       return;
     }
 
@@ -175,27 +177,27 @@ export function BackupManager() {
       setIsLoading(true);
       await ApiService.delete('/api/v2/backup/cleanup');
 
-      alert('Old backups cleaned up successfully!');
+      alert(t('backup.cleaned'));
       await loadBackups();
       await loadStats();
     } catch (error) {
       console.error('Error cleaning up backups:', error);
-      alert('Error cleaning up backups');
+      alert(t('backup.cleanupFailed'));
     } finally {
       setIsLoading(false);
     }
-  }, [loadBackups, loadStats]);
+  }, [loadBackups, loadStats, t]);
 
   const formatFileSize = (bytes: number) => {
-    if (bytes === 0) return '0 Bytes';
+    const sizes = [t('backup.bytes'), 'KB', 'MB', 'GB'];
+    if (bytes === 0) return `0 ${sizes[0]}`;
     const k = 1024;
-    const sizes = ['Bytes', 'KB', 'MB', 'GB'];
     const i = Math.floor(Math.log(bytes) / Math.log(k));
     return parseFloat((bytes / Math.pow(k, i)).toFixed(2)) + ' ' + sizes[i];
   };
 
   const formatDate = (dateString: string) => {
-    return new Date(dateString).toLocaleString();
+    return new Date(dateString).toLocaleString(locale);
   };
 
   return (
@@ -204,9 +206,9 @@ export function BackupManager() {
       <Card>
         <CardHeader>
           <CardTitle className="flex justify-between items-center">
-            Backup System Statistics
+            {t('backup.statsTitle')}
             <Button onClick={() => loadStats()} disabled={isLoading}>
-              {isLoading ? 'Loading...' : 'Refresh'}
+              {isLoading ? t('backup.loading') : t('backup.refresh')}
             </Button>
           </CardTitle>
         </CardHeader>
@@ -214,24 +216,24 @@ export function BackupManager() {
           {stats ? (
             <div className="grid grid-cols-2 gap-4">
               <div>
-                <p className="text-sm text-gray-500">Total Backups</p>
+                <p className="text-sm text-gray-500">{t('backup.totalBackups')}</p>
                 <p className="text-2xl font-bold">{stats.total_backups}</p>
               </div>
               <div>
-                <p className="text-sm text-gray-500">Total Size</p>
+                <p className="text-sm text-gray-500">{t('backup.totalSize')}</p>
                 <p className="text-2xl font-bold">{stats.total_size_mb.toFixed(2)} MB</p>
               </div>
               <div>
-                <p className="text-sm text-gray-500">Backup Directory Size</p>
+                <p className="text-sm text-gray-500">{t('backup.dirSize')}</p>
                 <p className="text-2xl font-bold">{stats.backup_dir_size_mb.toFixed(2)} MB</p>
               </div>
               <div>
-                <p className="text-sm text-gray-500">Verification</p>
-                <p className="text-2xl font-bold">{stats.verification_enabled ? 'Enabled' : 'Disabled'}</p>
+                <p className="text-sm text-gray-500">{t('backup.verification')}</p>
+                <p className="text-2xl font-bold">{stats.verification_enabled ? t('backup.enabled') : t('backup.disabled')}</p>
               </div>
             </div>
           ) : (
-            <p>Loading statistics...</p>
+            <p>{t('backup.statsLoading')}</p>
           )}
         </CardContent>
       </Card>
@@ -239,19 +241,19 @@ export function BackupManager() {
       {/* Create Backup Card */}
       <Card>
         <CardHeader>
-          <CardTitle>Create New Backup</CardTitle>
+          <CardTitle>{t('backup.createTitle')}</CardTitle>
         </CardHeader>
         <CardContent>
           <div className="flex gap-4">
             <Input
               type="text"
-              placeholder="Backup name (optional)"
+              placeholder={t('backup.namePlaceholder')}
               value={backupName}
               onChange={(e) => setBackupName(e.target.value)}
               className="flex-grow"
             />
             <Button onClick={createBackup} disabled={isLoading}>
-              {isLoading ? 'Creating...' : 'Create Backup'}
+              {isLoading ? t('backup.creating') : t('backup.create')}
             </Button>
           </div>
         </CardContent>
@@ -260,7 +262,7 @@ export function BackupManager() {
       {/* Backup List Card */}
       <Card>
         <CardHeader>
-          <CardTitle>Backup History</CardTitle>
+          <CardTitle>{t('backup.historyTitle')}</CardTitle>
         </CardHeader>
         <CardContent>
           <div className="space-y-4">
@@ -270,13 +272,13 @@ export function BackupManager() {
                   <div>
                     <h3 className="font-semibold">{backup.name}</h3>
                     <p className="text-sm text-gray-500">
-                      Created: {formatDate(backup.created_at)}
+                      {t('backup.createdAt', { date: formatDate(backup.created_at) })}
                     </p>
                     <p className="text-sm text-gray-500">
-                      Size: {formatFileSize(backup.total_size)}
+                      {t('backup.size', { size: formatFileSize(backup.total_size) })}
                     </p>
                     <p className="text-sm text-gray-500">
-                      Status: {backup.status}
+                      {t('backup.status', { status: backup.status })}
                     </p>
                   </div>
                   <div className="flex gap-2">
@@ -286,21 +288,21 @@ export function BackupManager() {
                       onClick={() => verifyBackup(backup.name)}
                       disabled={isLoading}
                     >
-                      Verify
+                      {t('backup.verify')}
                     </Button>
                     <Button
                       variant="outline"
                       size="sm"
                       onClick={() => setSelectedBackup(backup.name)}
                     >
-                      Restore
+                      {t('backup.restore')}
                     </Button>
                   </div>
                 </div>
               </div>
             ))}
             {backups.length === 0 && (
-              <p className="text-center text-gray-500">No backups found</p>
+              <p className="text-center text-gray-500">{t('backup.empty')}</p>
             )}
           </div>
         </CardContent>
@@ -310,25 +312,25 @@ export function BackupManager() {
       {selectedBackup && (
         <Card>
           <CardHeader>
-            <CardTitle>Restore Backup: {selectedBackup}</CardTitle>
+            <CardTitle>{t('backup.restoreTitle', { name: selectedBackup })}</CardTitle>
           </CardHeader>
           <CardContent>
             <div className="space-y-4">
               <Input
                 type="text"
-                placeholder="Components to restore (comma-separated, leave empty for all)"
+                placeholder={t('backup.componentsPlaceholder')}
                 value={restoreComponents}
                 onChange={(e) => setRestoreComponents(e.target.value)}
               />
               <div className="flex gap-2">
                 <Button onClick={restoreBackup} disabled={isLoading}>
-                  {isLoading ? 'Restoring...' : 'Restore'}
+                  {isLoading ? t('backup.restoring') : t('backup.restore')}
                 </Button>
                 <Button
                   variant="outline"
                   onClick={() => setSelectedBackup('')}
                 >
-                  Cancel
+                  {t('common.cancel')}
                 </Button>
               </div>
             </div>
@@ -339,7 +341,7 @@ export function BackupManager() {
       {/* Cleanup Card */}
       <Card>
         <CardHeader>
-          <CardTitle>Maintenance</CardTitle>
+          <CardTitle>{t('backup.maintenance')}</CardTitle>
         </CardHeader>
         <CardContent>
           <Button
@@ -347,7 +349,7 @@ export function BackupManager() {
             onClick={cleanupOldBackups}
             disabled={isLoading}
           >
-            {isLoading ? 'Cleaning...' : 'Cleanup Old Backups'}
+            {isLoading ? t('backup.cleaning') : t('backup.cleanup')}
           </Button>
         </CardContent>
       </Card>
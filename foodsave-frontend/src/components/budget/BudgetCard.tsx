@@ -0,0 +1,168 @@
+"use client";
+
+import { useState } from 'react';
+import { AlertTriangle, Pencil, Plus, Trash2 } from 'lucide-react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Button } from '@/components/ui/Button';
+import { Input } from '@/components/ui/Input';
+import { WidgetRefreshButton } from '@/components/dashboard/WidgetRefreshButton';
+import { useWidgetRefresh } from '@/hooks/useWidgetRefresh';
+import { useMonthlyBudget } from '@/hooks/useMonthlyBudget';
+import { useTranslation } from '@/hooks/useTranslation';
+import { BudgetSettings, BudgetStatus, budgetAlertText, budgetLabel } from '@/lib/budget';
+import { requestNotificationPermission } from '@/lib/notifications';
+import { formatMoney } from '@/lib/money';
+
+const LEVEL_COLORS: Record<BudgetStatus['level'], string> = {
+  ok: 'bg-emerald-500',
+  warning: 'bg-amber-500',
+  exceeded: 'bg-red-500',
+};
+
+function BudgetEditor({
+  budget,
+  onSave,
+  onCancel,
+}: {
+  budget: BudgetSettings;
+  onSave: (budget: BudgetSettings) => void;
+  onCancel: () => void;
+}) {
+  const { t } = useTranslation();
+  const [monthly, setMonthly] = useState(budget.monthly?.toString() ?? '');
+  const [rows, setRows] = useState(
+    Object.entries(budget.categories).map(([category, limit]) => ({ category, limit: limit.toString() })),
+  );
+
+  const handleSave = () => {
+    const categories: Record<string, number> = {};
+    for (const row of rows) {
+      const limit = parseFloat(row.limit.replace(',', '.'));
+      if (row.category.trim() && limit > 0) categories[row.category.trim()] = limit;
+    }
+    const monthlyLimit = parseFloat(monthly.replace(',', '.'));
+    onSave({ monthly: monthlyLimit > 0 ? monthlyLimit : null, categories });
+    requestNotificationPermission();
+  };
+
+  return (
+    <div className="space-y-3">
+      <Input
+        type="number"
+        min="0"
+        step="0.01"
+        label={t('budget.monthly')}
+        value={monthly}
+        onChange={(e) => setMonthly(e.target.value)}
+      />
+      <p className="text-sm font-medium text-gray-700">{t('budget.categoryLimits')}</p>
+      {rows.map((row, index) => (
+        <div key={index} className="flex items-end gap-2">
+          <Input
+            placeholder={t('budget.category')}
+            value={row.category}
+            onChange={(e) => setRows(rows.map((r, i) => (i === index ? { ...r, category: e.target.value } : r)))}
+          />
+          <Input
+            type="number"
+            min="0"
+            step="0.01"
+            placeholder={t('budget.limit')}
+            value={row.limit}
+            onChange={(e) => setRows(rows.map((r, i) => (i === index ? { ...r, limit: e.target.value } : r)))}
+          />
+          <Button variant="ghost" size="icon" aria-label={t('budget.removeLimit')} onClick={() => setRows(rows.filter((_, i) => i !== index))}>
+            <Trash2 className="h-4 w-4" />
+          </Button>
+        </div>
+      ))}
+      <Button variant="outline" size="sm" onClick={() => setRows([...rows, { category: '', limit: '' }])}>
+        <Plus className="h-4 w-4 mr-1" />
+        {t('budget.addCategory')}
+      </Button>
+      <div className="flex justify-end gap-2">
+        <Button variant="ghost" onClick={onCancel}>{t('common.cancel')}</Button>
+        <Button onClick={handleSave}>{t('common.save')}</Button>
+      </div>
+    </div>
+  );
+}
+
+export function BudgetCard() {
+  const { t, locale } = useTranslation();
+  const { budget, updateBudget, statuses, isFetching, refetch } = useMonthlyBudget();
+  const [isEditing, setIsEditing] = useState(false);
+  useWidgetRefresh('budget', refetch);
+
+  const alerts = statuses.filter((s) => s.level !== 'ok');
+
+  return (
+    <Card>
+      <CardHeader>
+        <div className="flex items-center justify-between">
+          <CardTitle>{t('budget.title')}</CardTitle>
+          {!isEditing && (
+            <div className="flex items-center gap-1">
+              <WidgetRefreshButton onClick={() => refetch()} isRefreshing={isFetching} label={t('budget.refresh')} />
+              <Button variant="ghost" size="icon" aria-label={t('budget.edit')} onClick={() => setIsEditing(true)}>
+                <Pencil className="h-4 w-4" />
+              </Button>
+            </div>
+          )}
+        </div>
+      </CardHeader>
+      <CardContent>
+        {isEditing ? (
+          <BudgetEditor
+            budget={budget}
+            onSave={(next) => {
+              updateBudget(next);
+              setIsEditing(false);
+            }}
+            onCancel={() => setIsEditing(false)}
+          />
+        ) : statuses.length === 0 ? (
+          <p className="text-sm text-gray-500">{t('budget.empty')}</p>
+        ) : (
+          <div className="space-y-3">
+            {alerts.map((status) => (
+              <div
+                key={`alert-${status.label}`}
+                role="alert"
+                className={`flex items-center gap-2 p-2 rounded text-sm ${
+                  status.level === 'exceeded' ? 'bg-red-50 text-red-700' : 'bg-amber-50 text-amber-700'
+                }`}
+              >
+                <AlertTriangle className="h-4 w-4 shrink-0" />
+                {budgetAlertText(status, locale)}
+              </div>
+            ))}
+            {statuses.map((status) => (
+              <div key={status.label}>
+                <div className="flex justify-between text-sm mb-1">
+                  <span className="font-medium">{budgetLabel(status, locale)}</span>
+                  <span className="text-gray-600">
+                    {formatMoney(status.spent)} / {formatMoney(status.limit)}
+                  </span>
+                </div>
+                <div
+                  className="h-2 bg-gray-100 rounded"
+                  role="progressbar"
+                  aria-valuemin={0}
+                  aria-valuemax={100}
+                  aria-valuenow={Math.round(status.ratio * 100)}
+                  aria-label={t('budget.progressLabel', { label: budgetLabel(status, locale) })}
+                >
+                  <div
+                    className={`h-2 rounded ${LEVEL_COLORS[status.level]}`}
+                    style={{ width: `${Math.min(status.ratio, 1) * 100}%` }}
+                  />
+                </div>
+              </div>
+            ))}
+          </div>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,91 @@
+"use client";
+
+import Link from 'next/link';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { WidgetRefreshButton } from '@/components/dashboard/WidgetRefreshButton';
+import { useMonthlyBudget } from '@/hooks/useMonthlyBudget';
+import { useWidgetRefresh } from '@/hooks/useWidgetRefresh';
+import { useTranslation } from '@/hooks/useTranslation';
+import { TOTAL_LABEL, budgetStatus, monthProgress } from '@/lib/budget';
+import { LoadingState } from '@/components/ui/LoadingState';
+import { formatMoney, money } from '@/lib/money';
+
+const LEVEL_COLORS = { ok: 'bg-emerald-500', warning: 'bg-amber-500', exceeded: 'bg-red-500' };
+
+// Kompaktowa karta na dashboard: wydatki bieżącego miesiąca vs budżet globalny
+export function BudgetProgressWidget() {
+  const { t } = useTranslation();
+  const { budget, stats, isLoading, isFetching, refetch } = useMonthlyBudget();
+  useWidgetRefresh('budget', refetch);
+
+  const spent = stats?.total ?? 0;
+  const limit = budget.monthly;
+
+  return (
+    <Card>
+      <CardHeader>
+        <div className="flex items-center justify-between">
+          <CardTitle>{t('budget.widget.title')}</CardTitle>
+          <WidgetRefreshButton onClick={() => refetch()} isRefreshing={isFetching} label={t('budget.widget.refresh')} />
+        </div>
+      </CardHeader>
+      <CardContent>
+        {isLoading ? (
+          <LoadingState size="sm" />
+        ) : !limit ? (
+          <div className="space-y-2">
+            <p className="text-3xl font-bold">{formatMoney(money(spent))}</p>
+            <p className="text-sm text-gray-500">
+              {t('budget.widget.notSet')}{' '}
+              <Link href="/analytics" className="text-primary underline">{t('budget.widget.set')}</Link>
+            </p>
+          </div>
+        ) : (
+          <BudgetProgressBody spent={spent} limit={limit} />
+        )}
+      </CardContent>
+    </Card>
+  );
+}
+
+function BudgetProgressBody({ spent, limit }: { spent: number; limit: number }) {
+  const { t } = useTranslation();
+  const status = budgetStatus(TOTAL_LABEL, spent, limit);
+  const { remaining, daysLeft, dailyAllowance } = monthProgress(spent, limit);
+
+  return (
+    <div className="space-y-3">
+      <div className="flex items-baseline justify-between">
+        <span className="text-3xl font-bold">{formatMoney(status.spent)}</span>
+        <span className="text-sm text-gray-600">{t('budget.widget.of', { amount: formatMoney(status.limit) })}</span>
+      </div>
+      <div
+        className="h-3 bg-gray-100 rounded"
+        role="progressbar"
+        aria-valuemin={0}
+        aria-valuemax={100}
+        aria-valuenow={Math.round(status.ratio * 100)}
+        aria-label={t('budget.widget.usage')}
+      >
+        <div
+          className={`h-3 rounded ${LEVEL_COLORS[status.level]}`}
+          style={{ width: `${Math.min(status.ratio, 1) * 100}%` }}
+        />
+      </div>
+      <div className="grid grid-cols-3 gap-2 text-center text-sm">
+        <div>
+          <p className={`font-semibold ${remaining.minor < 0 ? 'text-red-600' : ''}`}>{formatMoney(remaining)}</p>
+          <p className="text-xs text-gray-500">{remaining.minor < 0 ? t('budget.widget.over') : t('budget.widget.left')}</p>
+        </div>
+        <div>
+          <p className="font-semibold">{daysLeft}</p>
+          <p className="text-xs text-gray-500">{t('budget.widget.daysLeft')}</p>
+        </div>
+        <div>
+          <p className="font-semibold">{formatMoney(dailyAllowance)}</p>
+          <p className="text-xs text-gray-500">{t('budget.widget.daily')}</p>
+        </div>
+      </div>
+    </div>
+  );
+}
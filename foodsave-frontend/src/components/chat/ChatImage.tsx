@@ -0,0 +1,62 @@
+"use client";
+
+import { useState } from 'react';
+import Image from 'next/image';
+import { ImageOff } from 'lucide-react';
+import { Modal } from '@/components/ui/Modal';
+import { useTranslation } from '@/hooks/useTranslation';
+
+interface ChatImageProps {
+  src: string;
+  alt?: string;
+}
+
+// Image inside an assistant answer; a click opens it full size. Remote images are loaded
+// by the <img> itself - CSP img-src allows any https host, while fetch() would need CORS.
+export function ChatImage({ src, alt: altProp }: ChatImageProps) {
+  const { t } = useTranslation();
+  const alt = altProp ?? t('chat.image.alt');
+  const [zoomed, setZoomed] = useState(false);
+  const [failed, setFailed] = useState(false);
+
+  if (failed) {
+    return (
+      <a href={src} target="_blank" rel="noopener noreferrer" className="inline-flex items-center gap-1 text-xs text-gray-500">
+        <ImageOff className="h-4 w-4" />
+        {alt}
+      </a>
+    );
+  }
+
+  return (
+    <>
+      <button
+        type="button"
+        onClick={() => setZoomed(true)}
+        className="block cursor-zoom-in rounded focus:outline-none focus:ring-2 focus:ring-blue-500"
+        aria-label={t('chat.image.zoom', { alt })}
+      >
+        <Image
+          src={src}
+          alt={alt}
+          className="h-auto max-h-64 w-auto max-w-full rounded border object-contain"
+          width={480}
+          height={320}
+          loading="lazy"
+          onError={() => setFailed(true)}
+          unoptimized
+        />
+      </button>
+      <Modal open={zoomed} onClose={() => setZoomed(false)} ariaLabel={alt} size="full">
+        <Image
+          src={src}
+          alt={alt}
+          className="mx-auto h-auto max-h-[80vh] w-auto max-w-full object-contain"
+          width={1600}
+          height={1200}
+          unoptimized
+        />
+      </Modal>
+    </>
+  );
+}
@@ -2,16 +2,22 @@
 
 import React from 'react';
 import { useChat } from '@/hooks/useChat';
+import { useTranslation } from '@/hooks/useTranslation';
 import { MessageInput } from './MessageInput';
 import { MessageList } from './MessageList';
+import { TranscriptActions } from './TranscriptActions';
 import { Card } from '../ui/Card';
 
 export function ChatInterface() {
+  const { t } = useTranslation();
   const {
     messages,
     isLoading,
-    error,
     sendMessage,
+    clarify,
+    togglePin,
+    summarize,
+    isSummarizing,
     clearChat,
     usePerplexity,
     togglePerplexity,
@@ -22,18 +28,25 @@ export function ChatInterface() {
     isCookingMode,
     toggleCookingMode,
     streamingMessage,
+    replyLanguage,
+    setReplyLanguage,
+    attachments,
+    setAttachments,
   } = useChat();
 
   return (
     <Card className="flex flex-col h-[70vh] p-4" data-testid="chat-interface">
       <div className="flex justify-between items-center mb-4">
-        <h2 className="text-xl font-semibold">Asystent AI</h2>
-        <button
-          onClick={clearChat}
-          className="text-sm text-gray-500 hover:text-gray-700"
-        >
-          Wyczyść czat
-        </button>
+        <h2 className="text-xl font-semibold">{t('chat.title')}</h2>
+        <div className="flex items-center gap-3">
+          <TranscriptActions messages={messages} onSummarize={() => void summarize()} isSummarizing={isSummarizing} />
+          <button
+            onClick={clearChat}
+            className="text-sm text-gray-500 hover:text-gray-700"
+          >
+            {t('chat.clear')}
+          </button>
+        </div>
       </div>
 
       <div className="flex-grow overflow-y-auto mb-4 pr-2">
@@ -41,6 +54,8 @@ export function ChatInterface() {
           messages={messages}
           isLoading={isLoading}
           streamingMessage={streamingMessage}
+          onClarify={clarify}
+          onTogglePin={togglePin}
         />
       </div>
 
@@ -55,13 +70,11 @@ export function ChatInterface() {
         onToggleShoppingMode={toggleShoppingMode}
         isCookingMode={isCookingMode}
         onToggleCookingMode={toggleCookingMode}
+        replyLanguage={replyLanguage}
+        onChangeReplyLanguage={setReplyLanguage}
+        attachments={attachments}
+        onChangeAttachments={setAttachments}
       />
-
-      {error && (
-        <div className="mt-2 text-red-500 text-sm">
-          {error}
-        </div>
-      )}
     </Card>
   );
 }
@@ -0,0 +1,91 @@
+"use client";
+
+import { useEffect, useRef, useState } from 'react';
+import { Paperclip, X } from 'lucide-react';
+import { ContextAttachmentKind } from '@/types/chat';
+import { ATTACHMENT_KINDS, ATTACHMENT_LABELS } from '@/lib/chatAttachments';
+import { useTranslation } from '@/hooks/useTranslation';
+
+interface ContextAttachmentMenuProps {
+  selected: ContextAttachmentKind[];
+  onChange: (attachments: ContextAttachmentKind[]) => void;
+}
+
+// "Dołącz kontekst": app data sent along with the next question
+export function ContextAttachmentMenu({ selected, onChange }: ContextAttachmentMenuProps) {
+  const { t } = useTranslation();
+  const [open, setOpen] = useState(false);
+  const rootRef = useRef<HTMLDivElement>(null);
+
+  useEffect(() => {
+    if (!open) return;
+    const close = (e: MouseEvent) => {
+      if (!rootRef.current?.contains(e.target as Node)) setOpen(false);
+    };
+    document.addEventListener('mousedown', close);
+    return () => document.removeEventListener('mousedown', close);
+  }, [open]);
+
+  const toggle = (kind: ContextAttachmentKind) => {
+    onChange(selected.includes(kind) ? selected.filter((k) => k !== kind) : [...selected, kind]);
+  };
+
+  return (
+    <div ref={rootRef} className="relative mr-2">
+      <button
+        type="button"
+        onClick={() => setOpen((prev) => !prev)}
+        onKeyDown={(e) => e.key === 'Escape' && setOpen(false)}
+        className={`flex items-center gap-1 rounded-md border px-2 py-1.5 text-xs font-medium ${
+          selected.length > 0 ? 'bg-emerald-50 text-emerald-700 hover:bg-emerald-100' : 'text-gray-500 hover:bg-gray-100'
+        }`}
+        title={t('chat.context.hint')}
+        aria-haspopup="true"
+        aria-expanded={open}
+      >
+        <Paperclip size={14} />
+        {selected.length > 0 ? selected.length : t('chat.context.button')}
+      </button>
+      {open && (
+        <div
+          role="group"
+          aria-label={t('chat.context.title')}
+          onKeyDown={(e) => e.key === 'Escape' && setOpen(false)}
+          className="absolute bottom-full right-0 z-20 mb-2 w-60 rounded-md border bg-background p-2 shadow-lg"
+        >
+          <p className="mb-1 text-xs font-semibold text-gray-600">{t('chat.context.title')}</p>
+          {ATTACHMENT_KINDS.map((kind) => (
+            <label key={kind} className="flex cursor-pointer items-center gap-2 rounded px-1 py-1 text-sm hover:bg-gray-50">
+              <input type="checkbox" checked={selected.includes(kind)} onChange={() => toggle(kind)} />
+              {t(ATTACHMENT_LABELS[kind])}
+            </label>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+}
+
+// Removable chips for the attachments selected for the next question
+export function SelectedAttachments({ selected, onChange }: ContextAttachmentMenuProps) {
+  const { t } = useTranslation();
+  if (selected.length === 0) return null;
+  return (
+    <div className="mb-2 flex flex-wrap gap-1" aria-label={t('chat.context.attached')}>
+      {selected.map((kind) => (
+        <span key={kind} className="flex items-center gap-1 rounded-full bg-emerald-50 px-2 py-0.5 text-xs text-emerald-700">
+          <Paperclip className="h-3 w-3" />
+          {t(ATTACHMENT_LABELS[kind])}
+          <button
+            type="button"
+            onClick={() => onChange(selected.filter((k) => k !== kind))}
+            aria-label={t('chat.context.remove', { name: t(ATTACHMENT_LABELS[kind]) })}
+            className="text-emerald-500 hover:text-emerald-800"
+          >
+            <X className="h-3 w-3" />
+          </button>
+        </span>
+      ))}
+    </div>
+  );
+}
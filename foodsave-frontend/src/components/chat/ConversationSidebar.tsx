@@ -0,0 +1,176 @@
+"use client";
+
+import { FormEvent, useEffect, useState } from 'react';
+import { MessageSquarePlus, Tag, Trash2 } from 'lucide-react';
+import { Button } from '@/components/ui/Button';
+import {
+  conversationTitle,
+  deleteSavedConversation,
+  getChatHistory,
+  getConversationTags,
+  getSavedConversations,
+  setConversationTags,
+  subscribeChatHistory,
+} from '@/lib/chatHistory';
+import { Message } from '@/types/chat';
+import { useTranslation } from '@/hooks/useTranslation';
+
+interface ConversationSidebarProps {
+  context: string;
+  sessionId: string;
+  onOpen: (sessionId: string) => void;
+  onNew: () => void;
+}
+
+interface ConversationRow {
+  sessionId: string;
+  messages: Message[];
+  tags: string[];
+  current: boolean;
+}
+
+// Saved conversations of one chat context, with tags and filtering by tag
+export function ConversationSidebar({ context, sessionId, onOpen, onNew }: ConversationSidebarProps) {
+  const { t } = useTranslation();
+  const [, setRevision] = useState(0);
+  const [filter, setFilter] = useState<string | null>(null);
+  const [editing, setEditing] = useState<string | null>(null);
+  const [newTag, setNewTag] = useState('');
+
+  useEffect(() => subscribeChatHistory(() => setRevision((r) => r + 1)), []);
+
+  const current = getChatHistory(context);
+  const rows: ConversationRow[] = [
+    ...(current && current.sessionId === sessionId
+      ? [{ sessionId, messages: current.messages, tags: current.tags ?? [], current: true }]
+      : []),
+    ...getSavedConversations(context).map((c) => ({
+      sessionId: c.sessionId,
+      messages: c.messages,
+      tags: c.tags ?? [],
+      current: false,
+    })),
+  ];
+  const visible = filter ? rows.filter((row) => row.tags.includes(filter)) : rows;
+  const allTags = getConversationTags();
+
+  const toggleTag = (row: ConversationRow, tag: string) => {
+    setConversationTags(
+      row.sessionId,
+      row.tags.includes(tag) ? row.tags.filter((other) => other !== tag) : [...row.tags, tag],
+    );
+  };
+
+  const handleAddTag = (e: FormEvent, row: ConversationRow) => {
+    e.preventDefault();
+    if (!newTag.trim()) return;
+    setConversationTags(row.sessionId, [...row.tags, newTag]);
+    setNewTag('');
+  };
+
+  return (
+    <aside className="flex h-full flex-col gap-3" aria-label={t('chat.history.label')}>
+      <Button variant="outline" size="sm" onClick={onNew}>
+        <MessageSquarePlus className="h-4 w-4 mr-1" />
+        {t('chat.new')}
+      </Button>
+
+      <div role="group" aria-label={t('chat.history.filter')} className="flex flex-wrap gap-1 text-xs">
+        {[null, ...allTags].map((tag) => (
+          <button
+            key={tag ?? 'all'}
+            type="button"
+            aria-pressed={filter === tag}
+            onClick={() => setFilter(tag)}
+            className={`rounded-full border px-2 py-0.5 ${
+              filter === tag ? 'bg-primary text-primary-foreground' : 'hover:bg-accent'
+            }`}
+          >
+            {tag ?? t('chat.history.all')}
+          </button>
+        ))}
+      </div>
+
+      <ul className="flex-1 space-y-1 overflow-y-auto">
+        {visible.length === 0 && (
+          <li className="text-sm text-muted-foreground">{filter ? t('chat.history.emptyTag', { tag: filter }) : t('chat.history.empty')}</li>
+        )}
+        {visible.map((row) => (
+          <li key={row.sessionId} className={`rounded-md border p-2 ${row.current ? 'border-primary' : ''}`}>
+            <div className="flex items-start gap-1">
+              <button
+                type="button"
+                onClick={() => !row.current && onOpen(row.sessionId)}
+                aria-current={row.current ? 'true' : undefined}
+                className="flex-1 text-left text-sm"
+              >
+                {conversationTitle(row.messages)}
+                {row.current && <span className="ml-1 text-xs text-muted-foreground">{t('chat.history.current')}</span>}
+              </button>
+              <button
+                type="button"
+                onClick={() => setEditing(editing === row.sessionId ? null : row.sessionId)}
+                aria-label={t('chat.history.tags')}
+                aria-expanded={editing === row.sessionId}
+                className="p-1 text-muted-foreground hover:text-foreground"
+              >
+                <Tag className="h-3 w-3" />
+              </button>
+              {!row.current && (
+                <button
+                  type="button"
+                  onClick={() => deleteSavedConversation(row.sessionId)}
+                  aria-label={t('chat.history.delete')}
+                  className="p-1 text-muted-foreground hover:text-red-600"
+                >
+                  <Trash2 className="h-3 w-3" />
+                </button>
+              )}
+            </div>
+            {row.tags.length > 0 && editing !== row.sessionId && (
+              <div className="mt-1 flex flex-wrap gap-1">
+                {row.tags.map((tag) => (
+                  <span key={tag} className="rounded-full bg-secondary px-2 py-0.5 text-xs">
+                    {tag}
+                  </span>
+                ))}
+              </div>
+            )}
+            {editing === row.sessionId && (
+              <div className="mt-2 space-y-2">
+                <div className="flex flex-wrap gap-1 text-xs">
+                  {allTags.map((tag) => (
+                    <button
+                      key={tag}
+                      type="button"
+                      aria-pressed={row.tags.includes(tag)}
+                      onClick={() => toggleTag(row, tag)}
+                      className={`rounded-full border px-2 py-0.5 ${
+                        row.tags.includes(tag) ? 'bg-primary text-primary-foreground' : 'hover:bg-accent'
+                      }`}
+                    >
+                      {tag}
+                    </button>
+                  ))}
+                </div>
+                <form onSubmit={(e) => handleAddTag(e, row)} className="flex gap-1">
+                  <input
+                    value={newTag}
+                    onChange={(e) => setNewTag(e.target.value)}
+                    placeholder={t('chat.history.newTag')}
+                    aria-label={t('chat.history.newTag')}
+                    maxLength={30}
+                    className="min-w-0 flex-1 rounded-md border bg-background px-2 py-1 text-xs"
+                  />
+                  <Button type="submit" size="sm" variant="outline" disabled={!newTag.trim()}>
+                    {t('common.add')}
+                  </Button>
+                </form>
+              </div>
+            )}
+          </li>
+        ))}
+      </ul>
+    </aside>
+  );
+}
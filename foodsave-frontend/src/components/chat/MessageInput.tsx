@@ -1,15 +1,26 @@
 "use client";
 
-import React, { useState } from 'react';
+import React, { useEffect, useId, useLayoutEffect, useRef, useState } from 'react';
+import ReactMarkdown from 'react-markdown';
 import { Button } from '../ui/Button';
-import { Input } from '../ui/Input';
-import { MessageInputProps } from '@/types/chat';
-import { Search, Sparkles, Cpu, ShoppingCart, Soup } from 'lucide-react';
+import { MessageInputProps, ReplyLanguage } from '@/types/chat';
+import { ContextAttachmentMenu, SelectedAttachments } from './ContextAttachmentMenu';
+import { MAX_MESSAGE_LENGTH, isSendKey, messageLengthStatus } from '@/lib/chatInput';
+import { saveChatDraft } from '@/lib/recovery';
+import { useTranslation } from '@/hooks/useTranslation';
+import { Search, Sparkles, Cpu, ShoppingCart, Soup, Languages, Eye } from 'lucide-react';
+
+// Editor grows with its content up to this height, then scrolls
+const MAX_EDITOR_HEIGHT = 200;
+
+const NEXT_REPLY_LANGUAGE: Record<ReplyLanguage, ReplyLanguage> = { auto: 'pl', pl: 'en', en: 'auto' };
 
 export function MessageInput({
   onSendMessage,
   isLoading = false,
-  placeholder = "Wpisz wiadomość...",
+  placeholder: customPlaceholder,
+  initialValue = '',
+  draftKey,
   usePerplexity = false,
   onTogglePerplexity,
   useBielik = true,
@@ -18,19 +29,54 @@ export function MessageInput({
   onToggleShoppingMode,
   isCookingMode = false,
   onToggleCookingMode,
+  replyLanguage = 'auto',
+  onChangeReplyLanguage,
+  attachments = [],
+  onChangeAttachments,
 }: MessageInputProps) {
-  const [inputValue, setInputValue] = useState('');
+  const { t } = useTranslation();
+  const placeholder = customPlaceholder ?? t('chat.input.placeholder');
+  const [inputValue, setInputValue] = useState(initialValue);
+  const [showPreview, setShowPreview] = useState(false);
+  const textareaRef = useRef<HTMLTextAreaElement>(null);
+  const hintId = useId();
+  const lengthStatus = messageLengthStatus(inputValue.length);
+
+  useEffect(() => {
+    if (initialValue) setInputValue(initialValue);
+  }, [initialValue]);
+
+  useLayoutEffect(() => {
+    const textarea = textareaRef.current;
+    if (!textarea) return;
+    textarea.style.height = 'auto';
+    textarea.style.height = `${Math.min(textarea.scrollHeight, MAX_EDITOR_HEIGHT)}px`;
+  }, [inputValue]);
 
-  const handleInputChange = (e: React.ChangeEvent<HTMLInputElement>) => {
+  const handleInputChange = (e: React.ChangeEvent<HTMLTextAreaElement>) => {
     setInputValue(e.target.value);
+    if (draftKey) saveChatDraft(draftKey, e.target.value);
   };
 
-  const handleSubmit = (e: React.FormEvent) => {
-    e.preventDefault();
+  const submit = () => {
     const message = inputValue.trim();
-    if (message && !isLoading) {
+    if (message && !isLoading && lengthStatus !== 'over') {
       onSendMessage(message, usePerplexity, useBielik);
       setInputValue('');
+      setShowPreview(false);
+      if (draftKey) saveChatDraft(draftKey, '');
+    }
+  };
+
+  const handleSubmit = (e: React.FormEvent) => {
+    e.preventDefault();
+    submit();
+  };
+
+  const handleKeyDown = (e: React.KeyboardEvent<HTMLTextAreaElement>) => {
+    if (isSendKey(e.nativeEvent)) {
+      e.preventDefault();
+      submit();
     }
   };
 
@@ -47,78 +93,145 @@ export function MessageInput({
   };
 
   return (
-    <form onSubmit={handleSubmit} className="flex items-center mt-4">
-      <div className="flex-grow mr-2 relative">
-        <Input
-          type="text"
-          value={inputValue}
-          onChange={handleInputChange}
-          placeholder={placeholder}
-          className="pr-20"
-          disabled={isLoading}
-        />
-        <div className="absolute right-2 top-1/2 transform -translate-y-1/2 flex space-x-1">
-          {/* Cooking Mode toggle button */}
-          <button
-            type="button"
-            onClick={onToggleCookingMode}
-            className={`p-1 rounded-full transition-all duration-200 ${
-              isCookingMode
-                ? 'bg-orange-100 text-orange-600 hover:bg-orange-200'
-                : 'bg-gray-100 text-gray-400 hover:bg-gray-200 hover:text-gray-600'
-            }`}
-            title={isCookingMode ? 'Wyłącz tryb gotowania' : 'Włącz tryb gotowania'}
-          >
-            <Soup size={16} />
-          </button>
-          {/* Shopping Mode toggle button */}
-          <button
-            type="button"
-            onClick={onToggleShoppingMode}
-            className={`p-1 rounded-full transition-all duration-200 ${
-              isShoppingMode
-                ? 'bg-indigo-100 text-indigo-600 hover:bg-indigo-200'
-                : 'bg-gray-100 text-gray-400 hover:bg-gray-200 hover:text-gray-600'
+    <form onSubmit={handleSubmit} className="mt-4">
+      {showPreview && inputValue.trim() && (
+        <div
+          className="prose prose-sm max-w-none mb-2 max-h-48 overflow-auto rounded-md border border-dashed border-gray-300 p-2"
+          aria-label={t('chat.input.preview')}
+        >
+          <ReactMarkdown>{inputValue}</ReactMarkdown>
+        </div>
+      )}
+      {onChangeAttachments && <SelectedAttachments selected={attachments} onChange={onChangeAttachments} />}
+      <div className="flex items-end">
+        <div className="flex-grow mr-2 relative">
+          <textarea
+            ref={textareaRef}
+            rows={1}
+            value={inputValue}
+            onChange={handleInputChange}
+            onKeyDown={handleKeyDown}
+            placeholder={placeholder}
+            className={`block w-full resize-none overflow-y-auto px-3 py-2 pr-36 border rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500 ${
+              lengthStatus === 'over' ? 'border-red-500' : 'border-gray-300'
             }`}
-            title={isShoppingMode ? 'Wyłącz tryb zakupów' : 'Włącz tryb zakupów'}
-          >
-            <ShoppingCart size={16} />
-          </button>
-          {/* Model toggle button */}
+            aria-label={placeholder || t('chat.input.label')}
+            aria-describedby={hintId}
+            aria-invalid={lengthStatus === 'over'}
+            disabled={isLoading}
+            data-primary-input
+          />
+          <div className="absolute right-2 top-2 flex space-x-1">
+            {/* Markdown preview toggle button */}
+            <button
+              type="button"
+              onClick={() => setShowPreview((prev) => !prev)}
+              className={`p-1 rounded-full transition-all duration-200 ${
+                showPreview
+                  ? 'bg-teal-100 text-teal-600 hover:bg-teal-200'
+                  : 'bg-gray-100 text-gray-400 hover:bg-gray-200 hover:text-gray-600'
+              }`}
+              title={showPreview ? t('chat.input.previewHide') : t('chat.input.previewShow')}
+              aria-label={t('chat.input.previewToggle')}
+              aria-pressed={showPreview}
+            >
+              <Eye size={16} />
+            </button>
+            {/* Cooking Mode toggle button */}
+            <button
+              type="button"
+              onClick={onToggleCookingMode}
+              className={`p-1 rounded-full transition-all duration-200 ${
+                isCookingMode
+                  ? 'bg-orange-100 text-orange-600 hover:bg-orange-200'
+                  : 'bg-gray-100 text-gray-400 hover:bg-gray-200 hover:text-gray-600'
+              }`}
+              title={isCookingMode ? t('chat.input.cookingOff') : t('chat.input.cookingOn')}
+              aria-label={t('chat.input.cookingMode')}
+              aria-pressed={isCookingMode}
+            >
+              <Soup size={16} />
+            </button>
+            {/* Shopping Mode toggle button */}
+            <button
+              type="button"
+              onClick={onToggleShoppingMode}
+              className={`p-1 rounded-full transition-all duration-200 ${
+                isShoppingMode
+                  ? 'bg-indigo-100 text-indigo-600 hover:bg-indigo-200'
+                  : 'bg-gray-100 text-gray-400 hover:bg-gray-200 hover:text-gray-600'
+              }`}
+              title={isShoppingMode ? t('chat.input.shoppingOff') : t('chat.input.shoppingOn')}
+              aria-label={t('chat.input.shoppingMode')}
+              aria-pressed={isShoppingMode}
+            >
+              <ShoppingCart size={16} />
+            </button>
+            {/* Model toggle button */}
+            <button
+              type="button"
+              onClick={handleToggleModel}
+              className={`p-1 rounded-full transition-all duration-200 ${
+                useBielik
+                  ? 'bg-blue-100 text-blue-600 hover:bg-blue-200'
+                  : 'bg-green-100 text-green-600 hover:bg-green-200'
+              }`}
+              title={t('chat.input.switchModel', { model: useBielik ? 'Gemma' : 'Bielik' })}
+              aria-label={t('chat.input.model', useBielik ? { model: 'Bielik', other: 'Gemma' } : { model: 'Gemma', other: 'Bielik' })}
+            >
+              <Cpu size={16} />
+            </button>
+            {/* Perplexity toggle button */}
+            <button
+              type="button"
+              onClick={handleTogglePerplexity}
+              className={`p-1 rounded-full transition-all duration-200 ${
+                usePerplexity
+                  ? 'bg-purple-100 text-purple-600 hover:bg-purple-200'
+                  : 'bg-gray-100 text-gray-400 hover:bg-gray-200 hover:text-gray-600'
+              }`}
+              title={usePerplexity ? t('chat.input.perplexityOff') : t('chat.input.perplexityOn')}
+              aria-label={t('chat.input.perplexity')}
+              aria-pressed={usePerplexity}
+            >
+              <Sparkles size={16} />
+            </button>
+          </div>
+        </div>
+        {onChangeAttachments && <ContextAttachmentMenu selected={attachments} onChange={onChangeAttachments} />}
+        {onChangeReplyLanguage && (
           <button
             type="button"
-            onClick={handleToggleModel}
-            className={`p-1 rounded-full transition-all duration-200 ${
-              useBielik
-                ? 'bg-blue-100 text-blue-600 hover:bg-blue-200'
-                : 'bg-green-100 text-green-600 hover:bg-green-200'
+            onClick={() => onChangeReplyLanguage(NEXT_REPLY_LANGUAGE[replyLanguage])}
+            className={`mr-2 flex items-center gap-1 rounded-md border px-2 py-1.5 text-xs font-medium ${
+              replyLanguage === 'auto' ? 'text-gray-500 hover:bg-gray-100' : 'bg-blue-50 text-blue-700 hover:bg-blue-100'
             }`}
-            title={useBielik ? 'Przełącz na Gemma' : 'Przełącz na Bielik'}
+            title={t('chat.input.replyLanguageHint')}
+            aria-label={t('chat.input.replyLanguage', { language: t(`chat.replyLanguage.${replyLanguage}` as const) })}
           >
-            <Cpu size={16} />
+            <Languages size={14} />
+            {replyLanguage === 'auto' ? 'Auto' : replyLanguage.toUpperCase()}
           </button>
-          {/* Perplexity toggle button */}
-          <button
-            type="button"
-            onClick={handleTogglePerplexity}
-            className={`p-1 rounded-full transition-all duration-200 ${
-              usePerplexity
-                ? 'bg-purple-100 text-purple-600 hover:bg-purple-200'
-                : 'bg-gray-100 text-gray-400 hover:bg-gray-200 hover:text-gray-600'
-            }`}
-            title={usePerplexity ? 'Wyłącz Perplexity (użyj lokalnych modeli)' : 'Włącz Perplexity (użyj modeli online)'}
+        )}
+        <Button
+          type="submit"
+          disabled={isLoading || !inputValue.trim() || lengthStatus === 'over'}
+          isLoading={isLoading}
+        >
+          {t('chat.input.send')}
+        </Button>
+      </div>
+      <div className="mt-1 flex justify-between text-xs text-gray-500">
+        <span id={hintId}>{t('chat.input.hint')}</span>
+        {lengthStatus !== 'hidden' && (
+          <span
+            className={lengthStatus === 'over' ? 'font-medium text-red-600' : undefined}
+            aria-live="polite"
           >
-            <Sparkles size={16} />
-          </button>
-        </div>
+            {inputValue.length}/{MAX_MESSAGE_LENGTH}
+          </span>
+        )}
       </div>
-      <Button
-        type="submit"
-        disabled={isLoading || !inputValue.trim()}
-        isLoading={isLoading}
-      >
-        Wyślij
-      </Button>
     </form>
   );
 }
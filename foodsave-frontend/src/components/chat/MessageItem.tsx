@@ -1,39 +1,184 @@
+import { useMemo, useRef } from 'react';
 import ReactMarkdown from 'react-markdown';
+import { AlertCircle, Check, CheckCheck, ChefHat, Clock, FileText, Paperclip, Pin, PinOff, Printer } from 'lucide-react';
 import { Card } from '../ui/Card';
-import { Message } from '@/types/chat';
+import { ChatImage } from './ChatImage';
+import { Message, MessageStatus } from '@/types/chat';
+import { Recipe } from '@/types/cooking';
+import { buildPrintDocument, printDocument, recipeTitle } from '@/lib/print';
+import { ATTACHMENT_LABELS } from '@/lib/chatAttachments';
+import { extractImages, stripDataUris, withoutImageData } from '@/lib/chatImages';
+import { recipeFromAnswer } from '@/lib/recipeDeduction';
+import { formatRelativeTime } from '@/lib/time';
+import { useTranslation } from '@/hooks/useTranslation';
+import type { MessageKey } from '@/lib/i18n';
 
 interface MessageItemProps {
   message: Message;
   isStreaming?: boolean;
+  printable?: boolean;
+  onClarify?: (question: string, agent: string) => void;
+  onTogglePin?: (messageId: string) => void;
+  onCooked?: (recipe: Recipe) => void;
 }
 
-export function MessageItem({ message, isStreaming = false }: MessageItemProps) {
+// Subtle delivery icons next to the time of a user message
+const STATUS_ICONS: Partial<Record<MessageStatus, { icon: typeof Check; label: MessageKey; className: string }>> = {
+  sending: { icon: Check, label: 'chat.status.sending', className: 'text-gray-400' },
+  delivered: { icon: CheckCheck, label: 'chat.status.delivered', className: 'text-blue-500' },
+  failed: { icon: AlertCircle, label: 'chat.status.failed', className: 'text-red-500' },
+};
+
+export function MessageItem({
+  message,
+  isStreaming: streamingProp = false,
+  printable = false,
+  onClarify,
+  onTogglePin,
+  onCooked,
+}: MessageItemProps) {
   const isUser = message.role === 'user';
+  const isStreaming = streamingProp || message.status === 'streaming';
+  const status = isUser && message.status ? STATUS_ICONS[message.status] : undefined;
+  // Linki do obrazów i obrazy z danych (np. wykres z analityki) pokazujemy pod treścią
+  const images = useMemo(
+    () => (isUser || isStreaming ? [] : extractImages(message.content, message.data)),
+    [isUser, isStreaming, message.content, message.data],
+  );
+  // Odpowiedź z listą składników można odjąć od spiżarni po ugotowaniu
+  const recipe = useMemo(
+    () => (onCooked && !isUser && !isStreaming ? recipeFromAnswer(message.content) : undefined),
+    [onCooked, isUser, isStreaming, message.content],
+  );
+  const { t, locale } = useTranslation();
+  const contentRef = useRef<HTMLDivElement>(null);
+
+  // Drukujemy już wyrenderowany Markdown - React zadbał o escapowanie treści
+  const handlePrint = () => {
+    if (!contentRef.current) return;
+    void printDocument(buildPrintDocument(recipeTitle(message.content, locale), contentRef.current.innerHTML, undefined, locale));
+  };
 
   return (
-    <div className={`flex ${isUser ? 'justify-end' : 'justify-start'} mb-4`}>
+    <div id={`message-${message.id}`} className={`flex ${isUser ? 'justify-end' : 'justify-start'} mb-4`}>
       <div className={`flex ${isUser ? 'flex-row-reverse' : 'flex-row'} max-w-[80%]`}>
         <div
           className={`h-8 w-8 rounded-full flex items-center justify-center ${isUser ? 'bg-blue-500' : 'bg-gray-500'} text-white`}
         >
           {isUser ? '👤' : '🤖'}
         </div>
-        <Card className={`p-3 mx-2 ${isUser ? 'bg-blue-100' : 'bg-gray-100'}`}>
-          <div className="prose prose-sm max-w-none">
-            <ReactMarkdown>{message.content}</ReactMarkdown>
+        <Card
+          className={`p-3 mx-2 ${isUser ? 'bg-blue-100' : message.summary ? 'border-amber-300 bg-amber-50' : 'bg-gray-100'}`}
+        >
+          {message.summary && (
+            <p className="mb-1 flex items-center gap-1 text-xs font-semibold uppercase tracking-wide text-amber-800">
+              <FileText className="h-3 w-3" />
+              {t('chat.pinned.summary')}
+            </p>
+          )}
+          <div ref={contentRef} className="prose prose-sm max-w-none">
+            <ReactMarkdown
+              components={{ img: ({ src, alt }) => (src ? <ChatImage src={src} alt={alt || undefined} /> : null) }}
+            >
+              {stripDataUris(message.content)}
+            </ReactMarkdown>
             {isStreaming && (
               <span className="inline-block w-2 h-4 bg-gray-500 ml-1 animate-pulse"></span>
             )}
           </div>
+          {images.length > 0 && (
+            <div className="mt-2 flex flex-wrap gap-2">
+              {images.map((image) => (
+                <ChatImage key={image.src} src={image.src} alt={image.alt} />
+              ))}
+            </div>
+          )}
           {message.data && (
             <div className="mt-2 text-xs p-2 bg-white rounded">
               <pre className="whitespace-pre-wrap overflow-auto">
                 {typeof message.data === 'object'
-                  ? JSON.stringify(message.data, null, 2)
-                  : String(message.data)}
+                  ? JSON.stringify(withoutImageData(message.data), null, 2)
+                  : String(withoutImageData(message.data))}
               </pre>
             </div>
           )}
+          {message.clarification && onClarify && !isStreaming && (
+            <div role="group" aria-label={t('chat.message.clarify')} className="mt-2 flex flex-wrap items-center gap-1 text-xs">
+              <span className="text-gray-600">{t('chat.message.didYouMean')}</span>
+              {message.clarification.options.map((option) => (
+                <button
+                  key={option.agent}
+                  type="button"
+                  onClick={() => onClarify(message.clarification!.question, option.agent)}
+                  className="rounded-full border border-gray-300 bg-white px-2 py-0.5 hover:bg-gray-50"
+                >
+                  {option.label}
+                </button>
+              ))}
+            </div>
+          )}
+          {onTogglePin && !isUser && !isStreaming && (
+            <button
+              type="button"
+              onClick={() => onTogglePin(message.id)}
+              aria-pressed={!!message.pinned}
+              className="mt-1 mr-3 inline-flex items-center gap-1 text-xs text-gray-500 hover:text-gray-800"
+            >
+              {message.pinned ? <PinOff className="h-3 w-3" /> : <Pin className="h-3 w-3" />}
+              {message.pinned ? t('chat.pinned.unpin') : t('chat.message.pin')}
+            </button>
+          )}
+          {printable && !isUser && !isStreaming && (
+            <button
+              type="button"
+              onClick={handlePrint}
+              className="mt-1 mr-3 inline-flex items-center gap-1 text-xs text-gray-500 hover:text-gray-800"
+            >
+              <Printer className="h-3 w-3" />
+              {t('chat.message.print')}
+            </button>
+          )}
+          {recipe && onCooked && (
+            <button
+              type="button"
+              onClick={() => onCooked(recipe)}
+              className="mt-1 inline-flex items-center gap-1 text-xs text-gray-500 hover:text-gray-800"
+            >
+              <ChefHat className="h-3 w-3" />
+              {t('chat.message.cooked')}
+            </button>
+          )}
+          {message.attachments?.length ? (
+            <p className="mt-1 flex items-center gap-1 text-xs opacity-80">
+              <Paperclip className="h-3 w-3" />
+              {message.attachments.map((kind) => t(ATTACHMENT_LABELS[kind])).join(', ')}
+            </p>
+          ) : null}
+          {message.status === 'queued' && (
+            <span
+              className="mt-1 inline-flex items-center gap-1 rounded-full bg-amber-100 px-2 py-0.5 text-xs text-amber-800"
+              title={t('chat.message.queuedHint')}
+            >
+              <Clock className="h-3 w-3" />
+              {t('chat.message.queued')}
+            </span>
+          )}
+          {message.timestamp && !isStreaming && (
+            <div className="mt-1 flex items-center justify-end gap-1 text-xs text-gray-500">
+              <time
+                dateTime={new Date(message.timestamp).toISOString()}
+                title={new Date(message.timestamp).toLocaleString(locale)}
+              >
+                {formatRelativeTime(message.timestamp, new Date(), locale)}
+              </time>
+              {status && (
+                <span title={t(status.label)} className={status.className}>
+                  <status.icon className="h-3 w-3" aria-hidden="true" />
+                  <span className="sr-only">{t(status.label)}</span>
+                </span>
+              )}
+            </div>
+          )}
         </Card>
       </div>
     </div>
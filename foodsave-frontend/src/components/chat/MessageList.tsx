@@ -1,25 +1,41 @@
 import { MessageItem } from './MessageItem';
+import { PinnedMessages } from './PinnedMessages';
 import { MessageListProps } from '@/types/chat';
+import { scrollBehavior } from '@/lib/theme';
 import { useEffect, useRef } from 'react';
 
 export function MessageList({
   messages,
   isLoading = false,
-  streamingMessage = null
+  streamingMessage = null,
+  printable = false,
+  onClarify,
+  onTogglePin,
+  onCooked,
 }: MessageListProps) {
   const messagesEndRef = useRef<HTMLDivElement>(null);
 
   // Auto-scroll to bottom when messages change or streaming content updates
   useEffect(() => {
     if (messagesEndRef.current) {
-      messagesEndRef.current.scrollIntoView({ behavior: 'smooth' });
+      messagesEndRef.current.scrollIntoView({ behavior: scrollBehavior() });
     }
   }, [messages, streamingMessage?.content]);
 
   return (
     <div className="flex flex-col space-y-4">
-      {messages.map((message) => (
-        <MessageItem key={message.id} message={message} />
+      {onTogglePin && <PinnedMessages messages={messages} onTogglePin={onTogglePin} />}
+
+      {messages.map((message, index) => (
+        <MessageItem
+          key={message.id}
+          message={message}
+          printable={printable}
+          onTogglePin={onTogglePin}
+          onCooked={onCooked}
+          // Propozycje mają sens tylko pod ostatnią odpowiedzią
+          onClarify={index === messages.length - 1 && !isLoading ? onClarify : undefined}
+        />
       ))}
 
       {/* Display streaming message if available */}
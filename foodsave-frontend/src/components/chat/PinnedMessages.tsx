@@ -0,0 +1,60 @@
+import { Pin, X } from 'lucide-react';
+import { Message } from '@/types/chat';
+import { scrollBehavior } from '@/lib/theme';
+import { useTranslation } from '@/hooks/useTranslation';
+
+interface PinnedMessagesProps {
+  messages: Message[];
+  onTogglePin: (messageId: string) => void;
+}
+
+// Heading of the answer, or its first line when it has none
+function pinnedLabel(content: string): string {
+  const lines = content.split('\n').map((line) => line.trim()).filter(Boolean);
+  const line = lines.find((l) => /^#{1,6}\s/.test(l)) ?? lines[0] ?? '';
+  return line.replace(/^#+\s+/, '').replace(/[*_`>]/g, '').trim();
+}
+
+// Pinned assistant answers above the conversation; a click jumps to the answer
+export function PinnedMessages({ messages, onTogglePin }: PinnedMessagesProps) {
+  const { t } = useTranslation();
+  const pinned = messages.filter((m) => m.pinned);
+  if (pinned.length === 0) return null;
+
+  const jumpTo = (id: string) => {
+    document.getElementById(`message-${id}`)?.scrollIntoView({ behavior: scrollBehavior(), block: 'start' });
+  };
+
+  return (
+    <section
+      aria-label={t('chat.pinned.label')}
+      className="sticky top-0 z-10 rounded-md border bg-background/95 p-2 shadow-sm backdrop-blur"
+    >
+      <h3 className="mb-1 flex items-center gap-1 text-xs font-semibold text-gray-600">
+        <Pin className="h-3 w-3" />
+        {t('chat.pinned.title', { count: pinned.length })}
+      </h3>
+      <ul className="space-y-1">
+        {pinned.map((message) => (
+          <li key={message.id} className="flex items-center gap-2 text-sm">
+            <button
+              type="button"
+              onClick={() => jumpTo(message.id)}
+              className="min-w-0 flex-1 truncate text-left hover:underline"
+            >
+              {message.summary ? t('chat.pinned.summary') : pinnedLabel(message.content)}
+            </button>
+            <button
+              type="button"
+              onClick={() => onTogglePin(message.id)}
+              aria-label={t('chat.pinned.unpin')}
+              className="p-0.5 text-gray-400 hover:text-gray-700"
+            >
+              <X className="h-3 w-3" />
+            </button>
+          </li>
+        ))}
+      </ul>
+    </section>
+  );
+}
@@ -0,0 +1,121 @@
+"use client";
+
+import { FormEvent, useEffect, useRef, useState } from 'react';
+import { useRouter } from 'next/navigation';
+import { v4 as uuidv4 } from 'uuid';
+import { MessageCircle } from 'lucide-react';
+import { Button } from '@/components/ui/Button';
+import { Modal } from '@/components/ui/Modal';
+import { Spinner } from '@/components/ui/Spinner';
+import { useTranslation } from '@/hooks/useTranslation';
+import { ApiService } from '@/services/ApiService';
+import { profileStorageKey } from '@/lib/profiles';
+import { SHORTCUT_EVENT } from '@/lib/shortcuts';
+import { readJson, writeJson } from '@/lib/storage';
+import { trackTask } from '@/lib/tasks';
+
+const LAST_ANSWER_KEY = 'foodsave.quickAsk.last';
+
+interface QuickAnswer {
+  question: string;
+  answer: string;
+}
+
+// Small "ask without leaving the page" popup, opened with the app.quickAsk shortcut
+export function QuickAsk() {
+  const { t } = useTranslation();
+  const router = useRouter();
+  const [isOpen, setIsOpen] = useState(false);
+  const [question, setQuestion] = useState('');
+  const [last, setLast] = useState<QuickAnswer | null>(null);
+  const [isLoading, setIsLoading] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const sessionId = useRef(uuidv4());
+
+  useEffect(() => {
+    setLast(readJson<QuickAnswer | null>(profileStorageKey(LAST_ANSWER_KEY), null));
+    const onShortcut = (e: Event) => {
+      if ((e as CustomEvent).detail === 'app.quickAsk') setIsOpen((open) => !open);
+    };
+    window.addEventListener(SHORTCUT_EVENT, onShortcut);
+    return () => window.removeEventListener(SHORTCUT_EVENT, onShortcut);
+  }, []);
+
+  const handleSubmit = async (e: FormEvent) => {
+    e.preventDefault();
+    const asked = question.trim();
+    if (!asked || isLoading) return;
+    setIsLoading(true);
+    setError(null);
+    try {
+      let streamed = '';
+      const response = await trackTask('chat', t('quickAsk.title'), () =>
+        ApiService.sendChatMessage({ message: asked, session_id: sessionId.current }, (chunk) => {
+          if (chunk?.text) {
+            streamed += chunk.text;
+            setLast({ question: asked, answer: streamed });
+          }
+        }),
+      );
+      const answer: QuickAnswer = { question: asked, answer: response?.response || streamed };
+      setLast(answer);
+      writeJson(profileStorageKey(LAST_ANSWER_KEY), answer);
+      setQuestion('');
+    } catch {
+      setError(t('quickAsk.error'));
+    } finally {
+      setIsLoading(false);
+    }
+  };
+
+  return (
+    <Modal
+      open={isOpen}
+      onClose={() => setIsOpen(false)}
+      title={
+        <>
+          <MessageCircle className="h-4 w-4" />
+          {t('quickAsk.title')}
+        </>
+      }
+      closeLabel={t('common.close')}
+      position="top"
+    >
+      <form onSubmit={handleSubmit}>
+        <input
+          data-autofocus
+          value={question}
+          onChange={(e) => setQuestion(e.target.value)}
+          placeholder={t('quickAsk.placeholder')}
+          aria-label={t('quickAsk.placeholder')}
+          className="w-full rounded-md border px-3 py-2 text-sm"
+        />
+      </form>
+      <p className="text-xs text-muted-foreground mt-1">{t('quickAsk.hint')}</p>
+      {error && <p className="text-sm text-red-600 mt-3">{error}</p>}
+      {last && (
+        <div className="mt-3 max-h-64 overflow-auto rounded-md bg-muted/50 p-3 text-sm">
+          <p className="font-medium mb-1">{last.question}</p>
+          <p className="whitespace-pre-wrap">{last.answer}</p>
+        </div>
+      )}
+      {isLoading && !last?.answer && (
+        <div className="flex justify-center mt-3">
+          <Spinner />
+        </div>
+      )}
+      <div className="flex justify-end mt-3">
+        <Button
+          variant="link"
+          size="sm"
+          onClick={() => {
+            setIsOpen(false);
+            router.push('/chat');
+          }}
+        >
+          {t('quickAsk.openChat')}
+        </Button>
+      </div>
+    </Modal>
+  );
+}
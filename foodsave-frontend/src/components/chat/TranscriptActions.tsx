@@ -0,0 +1,90 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { Copy, FileText, Share2 } from 'lucide-react';
+import { Button } from '@/components/ui/Button';
+import { TranscriptFormat, canShareText, formatTranscript, shareText } from '@/lib/chatTranscript';
+import { reportError } from '@/lib/errors';
+import { pushToast } from '@/lib/toasts';
+import { useTranslation } from '@/hooks/useTranslation';
+import { Message } from '@/types/chat';
+
+interface TranscriptActionsProps {
+  messages: Message[];
+  title?: string;
+  onSummarize?: () => void;
+  isSummarizing?: boolean;
+}
+
+// "Podsumuj", "Kopiuj rozmowę" and "Udostępnij…" for the visible conversation
+export function TranscriptActions({
+  messages,
+  title: customTitle,
+  onSummarize,
+  isSummarizing = false,
+}: TranscriptActionsProps) {
+  const { t } = useTranslation();
+  const title = customTitle ?? t('chat.transcript.title');
+  const [format, setFormat] = useState<TranscriptFormat>('markdown');
+  const [canShare, setCanShare] = useState(false);
+
+  // navigator.share istnieje tylko w przeglądarce, więc sprawdzamy po zamontowaniu
+  useEffect(() => setCanShare(canShareText()), []);
+
+  const hasContent = messages.some((m) => !m.isError && m.content.trim());
+  const hasQuestions = messages.some((m) => m.role === 'user');
+
+  const handleCopy = async () => {
+    try {
+      await navigator.clipboard.writeText(formatTranscript(messages, format, title));
+      pushToast(t('chat.transcript.copied'), { severity: 'success' });
+    } catch {
+      pushToast(t('chat.transcript.copyFailed'), { severity: 'error' });
+    }
+  };
+
+  const handleShare = async () => {
+    try {
+      await shareText(title, formatTranscript(messages, format, title));
+    } catch (error) {
+      reportError(error, t('chat.transcript.sharing'));
+    }
+  };
+
+  return (
+    <div className="flex items-center gap-2">
+      <select
+        value={format}
+        onChange={(e) => setFormat(e.target.value as TranscriptFormat)}
+        aria-label={t('chat.transcript.format')}
+        className="rounded-md border bg-background px-2 py-1 text-sm"
+      >
+        <option value="markdown">Markdown</option>
+        <option value="text">{t('chat.transcript.text')}</option>
+      </select>
+      {onSummarize && (
+        <Button
+          variant="outline"
+          size="sm"
+          onClick={onSummarize}
+          disabled={!hasQuestions || isSummarizing}
+          isLoading={isSummarizing}
+          title={t('chat.transcript.summarizeHint')}
+        >
+          <FileText className="h-4 w-4 mr-1" />
+          {t('chat.transcript.summarize')}
+        </Button>
+      )}
+      <Button variant="outline" size="sm" onClick={() => void handleCopy()} disabled={!hasContent}>
+        <Copy className="h-4 w-4 mr-1" />
+        {t('chat.transcript.copy')}
+      </Button>
+      {canShare && (
+        <Button variant="outline" size="sm" onClick={() => void handleShare()} disabled={!hasContent}>
+          <Share2 className="h-4 w-4 mr-1" />
+          {t('chat.transcript.share')}
+        </Button>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,141 @@
+"use client";
+
+import { useRef, useState } from 'react';
+import { Modal } from '../ui/Modal';
+import { Button } from '../ui/Button';
+import { useTranslation } from '@/hooks/useTranslation';
+import { DeductionLine, planDeduction, remainingQuantity } from '@/lib/recipeDeduction';
+import { PantryItem, Recipe } from '@/types/cooking';
+
+interface CookedRecipeDialogProps {
+  recipe: Recipe;
+  pantryItems: PantryItem[];
+  onClose: () => void;
+  onUpdateItem: (id: string, updates: Partial<PantryItem>) => Promise<void>;
+}
+
+interface ReviewLine extends DeductionLine {
+  include: boolean;
+}
+
+// Review step before a cooked recipe is deducted from the pantry; rendered only while open
+export function CookedRecipeDialog({ recipe, pantryItems, onClose, onUpdateItem }: CookedRecipeDialogProps) {
+  const { t } = useTranslation();
+  // Plan liczony raz przy otwarciu - odświeżenie spiżarni nie nadpisuje poprawek użytkownika
+  const [lines, setLines] = useState<ReviewLine[]>(() =>
+    planDeduction(recipe.ingredients, pantryItems).map((line) => ({
+      ...line,
+      include: !!line.item && line.converted && !line.ingredient.optional,
+    })),
+  );
+  const [isSaving, setIsSaving] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  // Produkty już pomniejszone - ponowna próba po błędzie nie odejmuje ich drugi raz
+  const saved = useRef(new Set<string>());
+
+  const changeLine = (index: number, changes: Partial<ReviewLine>) =>
+    setLines((prev) => prev.map((line, i) => (i === index ? { ...line, ...changes } : line)));
+
+  const selected = lines.filter((line) => line.include && line.item && line.amount > 0);
+
+  const handleConfirm = async () => {
+    setIsSaving(true);
+    setError(null);
+    // Dwa składniki z tego samego produktu (np. mleko do ciasta i do kremu) odejmujemy razem
+    const totals = new Map<string, { item: PantryItem; amount: number }>();
+    for (const line of selected) {
+      const item = line.item as PantryItem;
+      const total = totals.get(item.id);
+      totals.set(item.id, { item, amount: (total?.amount ?? 0) + line.amount });
+    }
+    try {
+      for (const { item, amount } of Array.from(totals.values())) {
+        if (saved.current.has(item.id)) continue;
+        await onUpdateItem(item.id, { quantity: remainingQuantity(item, amount) });
+        saved.current.add(item.id);
+      }
+      onClose();
+    } catch (err) {
+      const reason = err instanceof Error ? err.message : t('cooking.unknownError');
+      setError(t('cooking.deduct.error', { reason }));
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  return (
+    <Modal open onClose={onClose} title={t('cooking.deduct.title', { name: recipe.name })} size="lg">
+      <div className="space-y-4">
+        <p className="text-sm text-gray-600">{t('cooking.deduct.hint')}</p>
+        <table className="w-full text-sm">
+          <thead className="text-left text-xs text-gray-500">
+            <tr>
+              <th className="py-1 font-medium" aria-label={t('cooking.deduct.include')} />
+              <th className="py-1 font-medium">{t('cooking.deduct.ingredient')}</th>
+              <th className="py-1 font-medium">{t('cooking.deduct.product')}</th>
+              <th className="py-1 font-medium">{t('cooking.deduct.include')}</th>
+              <th className="py-1 font-medium">{t('cooking.deduct.remaining')}</th>
+            </tr>
+          </thead>
+          <tbody className="divide-y">
+            {lines.map((line, index) => (
+              <tr key={`${line.ingredient.id}-${index}`} className={line.include ? '' : 'text-gray-400'}>
+                <td className="py-1">
+                  <input
+                    type="checkbox"
+                    checked={line.include}
+                    disabled={!line.item}
+                    onChange={(e) => changeLine(index, { include: e.target.checked })}
+                    aria-label={t('cooking.deduct.includeItem', { name: line.ingredient.name })}
+                  />
+                </td>
+                <td className="py-1">
+                  {line.ingredient.name}
+                  <span className="text-gray-500"> · {line.ingredient.quantity} {line.ingredient.unit}</span>
+                </td>
+                <td className="py-1">
+                  {line.item ? (
+                    `${line.item.name} (${line.item.quantity} ${line.item.unit ?? ''})`
+                  ) : (
+                    <span className="text-gray-400">{t('cooking.deduct.missing')}</span>
+                  )}
+                </td>
+                <td className="py-1">
+                  {line.item && (
+                    <span className="flex items-center gap-1">
+                      <input
+                        type="number"
+                        min={0}
+                        step="any"
+                        value={line.amount}
+                        onChange={(e) => changeLine(index, { amount: Math.max(0, parseFloat(e.target.value) || 0) })}
+                        aria-label={t('cooking.deduct.amount', { name: line.ingredient.name })}
+                        className="w-20 rounded-md border px-2 py-1"
+                      />
+                      <span className="text-gray-500">{line.item.unit}</span>
+                      {!line.converted && (
+                        <span className="text-xs text-amber-600" title={t('cooking.deduct.unitMismatch')}>
+                          {t('cooking.deduct.manual')}
+                        </span>
+                      )}
+                    </span>
+                  )}
+                </td>
+                <td className="py-1">{line.item && remainingQuantity(line.item, line.amount)}</td>
+              </tr>
+            ))}
+          </tbody>
+        </table>
+        {error && <p className="text-sm text-red-600">{error}</p>}
+        <div className="flex justify-end gap-2">
+          <Button variant="outline" onClick={onClose} disabled={isSaving}>
+            {t('common.cancel')}
+          </Button>
+          <Button onClick={() => void handleConfirm()} isLoading={isSaving} disabled={selected.length === 0}>
+            {t('cooking.deduct.submit', { count: selected.length })}
+          </Button>
+        </div>
+      </div>
+    </Modal>
+  );
+}
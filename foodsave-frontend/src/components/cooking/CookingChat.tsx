@@ -3,10 +3,16 @@ import { Card } from '../ui/Card';
 import { MessageList } from '../chat/MessageList';
 import { MessageInput } from '../chat/MessageInput';
 import { Message } from '@/types/chat';
-import { CookingChatProps } from '@/types/cooking';
+import { Recipe } from '@/types/cooking';
 import { useCooking } from '@/hooks/useCooking';
+import { useTranslation } from '@/hooks/useTranslation';
 
-export function CookingChat() {
+interface CookingChatProps {
+  onCooked?: (recipe: Recipe) => void;
+}
+
+export function CookingChat({ onCooked }: CookingChatProps) {
+  const { t } = useTranslation();
   const {
     messages,
     isLoading,
@@ -21,10 +27,10 @@ export function CookingChat() {
   return (
     <div className="flex flex-col h-full">
       <div className="flex justify-between items-center mb-4">
-        <h2 className="text-xl font-semibold">Asystent Kulinarny</h2>
+        <h2 className="text-xl font-semibold">{t('cooking.chat.title')}</h2>
       </div>
 
-      <MessageList messages={messages} />
+      <MessageList messages={messages} printable onCooked={onCooked} />
 
       <MessageInput
         onSendMessage={sendCookingMessage}
@@ -0,0 +1,102 @@
+"use client";
+
+import { FormEvent, useState } from 'react';
+import { Plus, Soup } from 'lucide-react';
+import { Button } from '../ui/Button';
+import { Input } from '../ui/Input';
+import { useLeftovers } from '@/hooks/useLeftovers';
+import { useTranslation } from '@/hooks/useTranslation';
+import { LeftoverStage, leftoverStage, suggestEatBy } from '@/lib/leftovers';
+import { formatRelativeDay, localIsoDate } from '@/lib/time';
+
+const STAGE_STYLES: Record<LeftoverStage, string> = {
+  fresh: 'text-gray-500',
+  soon: 'text-amber-600',
+  today: 'text-orange-600 font-medium',
+  overdue: 'text-red-600 font-medium',
+};
+
+// Leftovers get their own section above the pantry: they spoil within days
+export function LeftoversSection() {
+  const { t, locale } = useTranslation();
+  const { leftovers, addLeftover, removeLeftover } = useLeftovers();
+  const [isAdding, setIsAdding] = useState(false);
+  const [name, setName] = useState('');
+  const [cookedOn, setCookedOn] = useState(() => localIsoDate(new Date()));
+  const [eatBy, setEatBy] = useState(() => suggestEatBy(localIsoDate(new Date())));
+
+  // Termin podąża za datą ugotowania; użytkownik może go potem zmienić
+  const changeCookedOn = (value: string) => {
+    setCookedOn(value);
+    if (value) setEatBy(suggestEatBy(value));
+  };
+
+  const handleSubmit = (e: FormEvent) => {
+    e.preventDefault();
+    if (!name.trim() || !cookedOn || !eatBy) return;
+    addLeftover(name, cookedOn, eatBy);
+    setName('');
+    setIsAdding(false);
+  };
+
+  return (
+    <section className="border-b p-4" aria-labelledby="leftovers-heading">
+      <div className="mb-2 flex items-center justify-between">
+        <h3 id="leftovers-heading" className="flex items-center gap-2 font-semibold">
+          <Soup className="h-4 w-4" />
+          {t('cooking.leftovers.title')}
+        </h3>
+        {!isAdding && (
+          <Button variant="ghost" size="sm" onClick={() => setIsAdding(true)}>
+            <Plus className="mr-1 h-4 w-4" />
+            {t('cooking.leftovers.add')}
+          </Button>
+        )}
+      </div>
+      {isAdding && (
+        <form onSubmit={handleSubmit} className="mb-3 grid grid-cols-1 gap-2 sm:grid-cols-[1fr_9rem_9rem_auto] sm:items-end">
+          <Input
+            label={t('cooking.leftovers.name')}
+            value={name}
+            onChange={(e) => setName(e.target.value)}
+            placeholder={t('cooking.leftovers.namePlaceholder')}
+            autoFocus
+          />
+          <Input label={t('cooking.leftovers.cookedOn')} type="date" value={cookedOn} onChange={(e) => changeCookedOn(e.target.value)} />
+          <Input label={t('cooking.leftovers.eatBy')} type="date" value={eatBy} onChange={(e) => setEatBy(e.target.value)} />
+          <div className="flex gap-2">
+            <Button type="submit" disabled={!name.trim()}>
+              {t('common.save')}
+            </Button>
+            <Button type="button" variant="outline" onClick={() => setIsAdding(false)}>
+              {t('common.cancel')}
+            </Button>
+          </div>
+        </form>
+      )}
+      {leftovers.length === 0 ? (
+        !isAdding && <p className="text-sm text-gray-500">{t('cooking.leftovers.empty')}</p>
+      ) : (
+        <ul className="divide-y">
+          {leftovers.map((leftover) => {
+            const stage = leftoverStage(leftover);
+            return (
+              <li key={leftover.id} className="flex items-center justify-between gap-2 py-2">
+                <div>
+                  <div className="font-medium">{leftover.name}</div>
+                  <time dateTime={leftover.eatBy} title={leftover.eatBy} className={`text-xs ${STAGE_STYLES[stage]}`}>
+                    {stage === 'overdue' ? t('cooking.expired') : t('cooking.leftovers.eatBy')}:{' '}
+                    {formatRelativeDay(leftover.eatBy, undefined, locale)}
+                  </time>
+                </div>
+                <Button variant="outline" size="sm" onClick={() => removeLeftover(leftover.id)}>
+                  {t('cooking.leftovers.eaten')}
+                </Button>
+              </li>
+            );
+          })}
+        </ul>
+      )}
+    </section>
+  );
+}
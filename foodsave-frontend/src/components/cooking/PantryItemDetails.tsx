@@ -0,0 +1,210 @@
+"use client";
+
+import { FormEvent, useEffect, useMemo, useState } from 'react';
+import { Plus, X } from 'lucide-react';
+import { Modal } from '../ui/Modal';
+import { Input } from '../ui/Input';
+import { Button } from '../ui/Button';
+import { useTranslation } from '@/hooks/useTranslation';
+import { AttributeRow, attributeRows, attributesFromRows } from '@/lib/pantryAttributes';
+import { duplicateCandidates, mergePantryItems } from '@/lib/pantryMerge';
+import type { MessageArgs, MessageKey } from '@/lib/i18n';
+import { PantryItem } from '@/types/cooking';
+
+interface PantryItemDetailsProps {
+  item: PantryItem | null;
+  items?: PantryItem[]; // the whole pantry, for merging duplicates
+  onClose: () => void;
+  onSave: (id: string, updates: Partial<PantryItem>) => Promise<void>;
+  onMerge?: (keptId: string, mergedId: string) => Promise<void>;
+}
+
+type Translate = (key: MessageKey, args?: MessageArgs) => string;
+
+function formatQuantity(item: Partial<PantryItem>, t: Translate): string {
+  return item.quantity != null ? `${item.quantity} ${item.unit ?? ''}`.trim() : t('cooking.details.noQuantity');
+}
+
+interface MergeSectionProps {
+  item: PantryItem;
+  items: PantryItem[];
+  onMerge: (keptId: string, mergedId: string) => Promise<void>;
+  onMerged: () => void;
+}
+
+// Absorbs a duplicate (e.g. the same product under another OCR spelling) into this item
+function MergeSection({ item, items, onMerge, onMerged }: MergeSectionProps) {
+  const { t } = useTranslation();
+  // Podobne nazwy na początku listy, reszta spiżarni pod nimi
+  const options = useMemo(() => {
+    const duplicates = duplicateCandidates(item, items);
+    return [...duplicates, ...items.filter((other) => other.id !== item.id && !duplicates.includes(other))];
+  }, [item, items]);
+  const [mergedId, setMergedId] = useState('');
+  const [isMerging, setIsMerging] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+  const merged = options.find((other) => other.id === mergedId);
+  const preview = merged ? mergePantryItems(item, merged) : null;
+
+  const handleMerge = async () => {
+    if (!merged) return;
+    setIsMerging(true);
+    setError(null);
+    try {
+      await onMerge(item.id, merged.id);
+      onMerged();
+    } catch (err) {
+      setError(t('cooking.details.mergeError', { reason: err instanceof Error ? err.message : t('cooking.unknownError') }));
+    } finally {
+      setIsMerging(false);
+    }
+  };
+
+  if (options.length === 0) return null;
+
+  return (
+    <section className="space-y-2 border-t pt-4" aria-labelledby="pantry-merge-heading">
+      <h3 id="pantry-merge-heading" className="text-sm font-medium text-gray-700">
+        {t('cooking.details.mergeTitle')}
+      </h3>
+      <select
+        value={mergedId}
+        onChange={(e) => setMergedId(e.target.value)}
+        aria-label={t('cooking.details.mergeLabel')}
+        className="w-full rounded-md border px-3 py-2 text-sm"
+      >
+        <option value="">{t('cooking.details.choose')}</option>
+        {options.map((other) => (
+          <option key={other.id} value={other.id}>
+            {other.name} ({formatQuantity(other, t)})
+          </option>
+        ))}
+      </select>
+      {merged && preview && (
+        <p className="text-sm text-gray-600">
+          {t('cooking.details.mergePreview', { merged: merged.name, kept: item.name, quantity: formatQuantity(preview, t) })}
+          {preview.expiry_date && t('cooking.details.mergeExpiry', { date: preview.expiry_date })}.
+        </p>
+      )}
+      {error && <p className="text-sm text-red-600">{error}</p>}
+      <Button type="button" variant="outline" size="sm" onClick={() => void handleMerge()} disabled={!merged} isLoading={isMerging}>
+        {t('cooking.details.merge')}
+      </Button>
+    </section>
+  );
+}
+
+// Notes, custom attributes and merge history of one pantry item
+export function PantryItemDetails({ item, items = [], onClose, onSave, onMerge }: PantryItemDetailsProps) {
+  const { t } = useTranslation();
+  const [notes, setNotes] = useState('');
+  const [rows, setRows] = useState<AttributeRow[]>([]);
+  const [isSaving, setIsSaving] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  useEffect(() => {
+    if (!item) return;
+    setError(null);
+    setNotes(item.notes ?? '');
+    setRows(attributeRows(item.attributes));
+  }, [item]);
+
+  const changeRow = (index: number, changes: Partial<AttributeRow>) =>
+    setRows((prev) => prev.map((row, i) => (i === index ? { ...row, ...changes } : row)));
+
+  const handleSubmit = async (e: FormEvent) => {
+    e.preventDefault();
+    if (!item) return;
+    setIsSaving(true);
+    setError(null);
+    try {
+      await onSave(item.id, { notes: notes.trim(), attributes: attributesFromRows(rows) });
+      onClose();
+    } catch (err) {
+      // Okno zostaje otwarte, żeby można było ponowić zapis
+      setError(t('cooking.details.saveError', { reason: err instanceof Error ? err.message : t('cooking.unknownError') }));
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  return (
+    <Modal open={!!item} onClose={onClose} title={item?.name}>
+      <form onSubmit={handleSubmit} className="space-y-4">
+        <p className="text-sm text-gray-500">{item?.unified_category}</p>
+        <div className="space-y-2">
+          <label htmlFor="pantry-item-notes" className="block text-sm font-medium text-gray-700">
+            {t('cooking.details.notes')}
+          </label>
+          <textarea
+            id="pantry-item-notes"
+            rows={3}
+            value={notes}
+            onChange={(e) => setNotes(e.target.value)}
+            placeholder={t('cooking.details.notesPlaceholder')}
+            className="w-full rounded-md border px-3 py-2 text-sm"
+          />
+        </div>
+        <fieldset className="space-y-2">
+          <legend className="mb-1 text-sm font-medium text-gray-700">{t('cooking.details.attributes')}</legend>
+          {rows.map((row, index) => (
+            <div key={index} className="flex items-center gap-2">
+              <Input
+                value={row.key}
+                onChange={(e) => changeRow(index, { key: e.target.value })}
+                placeholder={t('cooking.details.attributeNamePlaceholder')}
+                aria-label={t('cooking.details.attributeName')}
+              />
+              <Input
+                value={row.value}
+                onChange={(e) => changeRow(index, { value: e.target.value })}
+                placeholder={t('cooking.details.attributeValuePlaceholder')}
+                aria-label={t('cooking.details.attributeValue')}
+              />
+              <Button
+                type="button"
+                variant="ghost"
+                size="icon"
+                onClick={() => setRows((prev) => prev.filter((_, i) => i !== index))}
+                aria-label={t('cooking.details.removeAttribute')}
+              >
+                <X className="h-4 w-4" />
+              </Button>
+            </div>
+          ))}
+          <Button type="button" variant="outline" size="sm" onClick={() => setRows((prev) => [...prev, { key: '', value: '' }])}>
+            <Plus className="mr-1 h-4 w-4" />
+            {t('cooking.details.addAttribute')}
+          </Button>
+        </fieldset>
+        {error && <p className="text-sm text-red-600">{error}</p>}
+        <div className="flex justify-end gap-2">
+          <Button type="button" variant="outline" onClick={onClose}>
+            {t('common.cancel')}
+          </Button>
+          <Button type="submit" isLoading={isSaving}>
+            {t('common.save')}
+          </Button>
+        </div>
+      </form>
+      {item?.merged_from && item.merged_from.length > 0 && (
+        <div className="mt-4 border-t pt-4 text-sm">
+          <h3 className="mb-1 font-medium text-gray-700">{t('cooking.details.mergedTitle')}</h3>
+          <ul className="space-y-1 text-gray-600">
+            {item.merged_from.map((origin) => (
+              <li key={origin.id}>
+                {origin.name} · {formatQuantity(origin, t)}
+                {origin.purchase_date && <span className="text-gray-400"> · {t('cooking.details.bought', { date: origin.purchase_date })}</span>}
+              </li>
+            ))}
+          </ul>
+        </div>
+      )}
+      {item && onMerge && (
+        <div className="mt-4">
+          <MergeSection key={item.id} item={item} items={items} onMerge={onMerge} onMerged={onClose} />
+        </div>
+      )}
+    </Modal>
+  );
+}
@@ -1,23 +1,116 @@
 "use client";
 
 import { useState } from 'react';
+import { CalendarDays, LayoutGrid, LayoutList, RefreshCw, Star, Table } from 'lucide-react';
 import { Card } from '../ui/Card';
 import { Input } from '../ui/Input';
 import { Button } from '../ui/Button';
+import { EmptyState } from '../ui/EmptyState';
+import { LeftoversSection } from './LeftoversSection';
+import { PantryItemDetails } from './PantryItemDetails';
+import { PantryTimeline } from './PantryTimeline';
+import { useSettings } from '@/hooks/useSettings';
+import { useTranslation } from '@/hooks/useTranslation';
+import { daysBetween, formatRelativeDay } from '@/lib/time';
+import { formatAttribute } from '@/lib/pantryAttributes';
+import { filterPantry } from '@/lib/pantryQuery';
+import { outOfStockStaples, restockStaples } from '@/lib/pantryStaples';
+import { suggestExpiryDate } from '@/lib/shelfLife';
+import { pushToast } from '@/lib/toasts';
+import type { MessageKey } from '@/lib/i18n';
+import { PantryView } from '@/lib/settings';
 import { PantryItem, PantryListProps } from '@/types/cooking';
 
+const VIEWS: { value: PantryView; labelKey: MessageKey; icon: typeof LayoutList }[] = [
+  { value: 'cards', labelKey: 'cooking.view.cards', icon: LayoutList },
+  { value: 'grid', labelKey: 'cooking.view.grid', icon: LayoutGrid },
+  { value: 'table', labelKey: 'cooking.view.table', icon: Table },
+  { value: 'timeline', labelKey: 'cooking.view.timeline', icon: CalendarDays },
+];
+
+// "ważne do jutra" reads better than a raw date; the date stays in the tooltip
+function ExpiryLabel({ date }: { date: string }) {
+  const { t, locale } = useTranslation();
+  const days = daysBetween(new Date(), new Date(`${date}T00:00:00`));
+  const color = days < 0 ? 'text-red-600' : days <= 3 ? 'text-amber-600' : 'text-gray-500';
+  return (
+    <time dateTime={date} title={date} className={`text-xs ${color}`}>
+      {days < 0 ? t('cooking.expired') : t('cooking.validUntil')}: {formatRelativeDay(date, undefined, locale)}
+    </time>
+  );
+}
+
+// Opens the item details when the list can edit items, plain text otherwise
+function ItemName({ item, onOpen, className = '' }: { item: PantryItem; onOpen?: (item: PantryItem) => void; className?: string }) {
+  if (!onOpen) return <span className={className} title={item.name}>{item.name}</span>;
+  return (
+    <button type="button" onClick={() => onOpen(item)} className={`text-left hover:underline ${className}`} title={item.name}>
+      {item.name}
+    </button>
+  );
+}
+
+// Staples are restocked automatically; the star marks and toggles them
+function StapleToggle({ item, onToggle }: { item: PantryItem; onToggle?: (item: PantryItem) => void }) {
+  const { t } = useTranslation();
+  if (!onToggle) {
+    return item.staple ? <Star className="h-4 w-4 shrink-0 fill-amber-400 text-amber-500" aria-label={t('cooking.staple.label')} /> : null;
+  }
+  return (
+    <button
+      type="button"
+      onClick={() => onToggle(item)}
+      aria-pressed={!!item.staple}
+      aria-label={t('cooking.staple.toggle', { name: item.name })}
+      title={item.staple ? t('cooking.staple.unmark') : t('cooking.staple.mark')}
+      className="shrink-0 rounded p-1 hover:bg-gray-100"
+    >
+      <Star className={`h-4 w-4 ${item.staple ? 'fill-amber-400 text-amber-500' : 'text-gray-400'}`} />
+    </button>
+  );
+}
+
 export function PantryList({
   items,
   isLoading = false,
   onAddItem,
   onDeleteItem,
-  onUpdateItem
+  onUpdateItem,
+  onMergeItems
 }: PantryListProps) {
+  const { t } = useTranslation();
   const [newItem, setNewItem] = useState('');
+  const [query, setQuery] = useState('');
+  const [selected, setSelected] = useState<PantryItem | null>(null);
+  // Duże spiżarnie w kartach zajmują dużo miejsca - układ zapisany w ustawieniach
+  const { settings, updateSettings } = useSettings();
+  const view = VIEWS.some((option) => option.value === settings.pantryView) ? settings.pantryView : 'cards';
+  // "maslo" finds "Masło extra", "pomidory" finds "Pomidor malinowy"; notes and attributes count too.
+  // Operators narrow it down further: "cat:nabiał exp<7d", "qty=0", "attr:dieta", "is:staple"
+  const visibleItems = filterPantry(items, query);
+
+  const missingStaples = outOfStockStaples(items);
+  // Błąd pokazuje hook spiżarni, przełącznik po prostu zostaje w poprzednim stanie
+  const toggleStaple =
+    onUpdateItem && ((item: PantryItem) => onUpdateItem(item.id, { staple: !item.staple }).catch(() => undefined));
+
+  const handleRestock = () => {
+    const added = restockStaples(items);
+    pushToast(
+      added.length > 0
+        ? t('cooking.restocked', { items: added.map((entry) => entry.name).join(', ') })
+        : t('cooking.restockNothing'),
+    );
+  };
 
   const handleAddItem = async () => {
     if (newItem.trim() && onAddItem) {
-      await onAddItem({ name: newItem, unified_category: 'Nieskategoryzowane' });
+      // Data ważności z typowej trwałości produktu; można ją potem poprawić
+      await onAddItem({
+        name: newItem,
+        unified_category: 'Nieskategoryzowane',
+        expiry_date: suggestExpiryDate(newItem, 'Nieskategoryzowane'),
+      });
       setNewItem('');
     }
   };
@@ -26,61 +119,188 @@ export function PantryList({
     return <Card className="h-[calc(100vh-200px)] animate-pulse" />;
   }
 
+  const emptyState =
+    items.length === 0 ? (
+      <EmptyState message={t('cooking.pantryEmpty')} align="center" />
+    ) : visibleItems.length === 0 ? (
+      <EmptyState message={t('cooking.noMatches', { query })} align="center" />
+    ) : null;
+
   return (
     <Card className="h-[calc(100vh-200px)] overflow-auto">
       <div className="p-4 border-b">
-        <h2 className="text-xl font-semibold mb-4">Moja Spiżarnia</h2>
+        <div className="mb-4 flex items-center justify-between gap-2">
+          <h2 className="text-xl font-semibold">{t('cooking.pantryTitle')}</h2>
+          <div className="flex rounded-md border" role="group" aria-label={t('cooking.view.label')}>
+            {VIEWS.map(({ value, labelKey, icon: Icon }, index) => (
+              <button
+                key={value}
+                type="button"
+                onClick={() => updateSettings({ pantryView: value })}
+                aria-pressed={view === value}
+                aria-label={t(labelKey)}
+                title={t(labelKey)}
+                className={`flex items-center gap-1 px-2 py-1 text-sm ${index > 0 ? 'border-l' : ''} ${
+                  view === value ? 'bg-gray-100 font-medium' : 'text-gray-500'
+                }`}
+              >
+                <Icon className="h-4 w-4" />
+                <span className="hidden sm:inline">{t(labelKey)}</span>
+              </button>
+            ))}
+          </div>
+        </div>
         <div className="flex gap-2">
           <Input
             type="text"
             value={newItem}
             onChange={(e) => setNewItem(e.target.value)}
-            placeholder="Dodaj nowy produkt..."
+            placeholder={t('cooking.addPlaceholder')}
             className="flex-grow"
           />
           <Button onClick={handleAddItem} disabled={!newItem.trim()}>
-            Dodaj
+            {t('common.add')}
           </Button>
         </div>
+        {missingStaples.length > 0 && (
+          <Button variant="outline" size="sm" onClick={handleRestock} className="mt-2">
+            <RefreshCw className="mr-1 h-4 w-4" />
+            {t('cooking.restock', { count: missingStaples.length })}
+          </Button>
+        )}
+        {items.length > 0 && (
+          <Input
+            type="search"
+            value={query}
+            onChange={(e) => setQuery(e.target.value)}
+            placeholder={t('cooking.searchPlaceholder')}
+            aria-label={t('cooking.searchLabel')}
+            title={t('cooking.searchHint')}
+            className="mt-2"
+          />
+        )}
       </div>
 
-      <div className="divide-y">
-        {items.map((item) => (
-          <div key={item.id} className="p-3 hover:bg-gray-50">
-            <div className="flex justify-between items-center">
-              <div>
-                <div className="font-medium">{item.name}</div>
-                <div className="text-sm text-gray-500">{item.unified_category}</div>
+      <LeftoversSection />
+
+      {view === 'timeline' ? (
+        <PantryTimeline items={visibleItems} />
+      ) : emptyState ? (
+        emptyState
+      ) : view === 'grid' ? (
+        <ul className="grid grid-cols-2 gap-2 p-3 sm:grid-cols-3 lg:grid-cols-4">
+          {visibleItems.map((item) => (
+            <li key={item.id} className="flex flex-col rounded-md border p-2 hover:bg-gray-50">
+              <div className="flex items-center justify-between gap-1">
+                <ItemName item={item} onOpen={onUpdateItem && setSelected} className="truncate text-sm font-medium" />
+                <StapleToggle item={item} onToggle={toggleStaple} />
               </div>
-              <div className="flex gap-2">
-                {onUpdateItem && (
-                  <Button
-                    variant="ghost"
-                    size="sm"
-                    onClick={() => onUpdateItem(item.id, {})}
-                  >
-                    Edytuj
-                  </Button>
-                )}
-                {onDeleteItem && (
-                  <Button
-                    variant="destructive"
-                    size="sm"
-                    onClick={() => onDeleteItem(item.id)}
-                  >
-                    Usuń
-                  </Button>
-                )}
+              {item.expiry_date && <ExpiryLabel date={item.expiry_date} />}
+              {onDeleteItem && (
+                <button
+                  type="button"
+                  onClick={() => onDeleteItem(item.id)}
+                  className="mt-1 self-end text-xs text-red-600 hover:underline"
+                  aria-label={t('cooking.remove', { name: item.name })}
+                >
+                  {t('common.delete')}
+                </button>
+              )}
+            </li>
+          ))}
+        </ul>
+      ) : view === 'table' ? (
+        <table className="w-full text-sm">
+          <thead className="bg-gray-50 text-left text-xs text-gray-500">
+            <tr>
+              <th className="px-3 py-1 font-medium">{t('shopping.column.name')}</th>
+              <th className="px-3 py-1 font-medium">{t('shopping.column.category')}</th>
+              <th className="px-3 py-1 font-medium">{t('cooking.column.expiry')}</th>
+              <th className="px-3 py-1" aria-label={t('shopping.column.actions')} />
+            </tr>
+          </thead>
+          <tbody className="divide-y">
+            {visibleItems.map((item) => (
+              <tr key={item.id} className="hover:bg-gray-50">
+                <td className="px-3 py-1 font-medium">
+                  <div className="flex items-center gap-1">
+                    <StapleToggle item={item} onToggle={toggleStaple} />
+                    <ItemName item={item} onOpen={onUpdateItem && setSelected} />
+                  </div>
+                </td>
+                <td className="px-3 py-1 text-gray-500">{item.unified_category}</td>
+                <td className="px-3 py-1">{item.expiry_date && <ExpiryLabel date={item.expiry_date} />}</td>
+                <td className="px-3 py-1 text-right">
+                  {onDeleteItem && (
+                    <button
+                      type="button"
+                      onClick={() => onDeleteItem(item.id)}
+                      className="text-xs text-red-600 hover:underline"
+                      aria-label={t('cooking.remove', { name: item.name })}
+                    >
+                      {t('common.delete')}
+                    </button>
+                  )}
+                </td>
+              </tr>
+            ))}
+          </tbody>
+        </table>
+      ) : (
+        <div className="divide-y">
+          {visibleItems.map((item) => (
+            <div key={item.id} className="p-3 hover:bg-gray-50">
+              <div className="flex justify-between items-center">
+                <div>
+                  <div className="font-medium">{item.name}</div>
+                  <div className="text-sm text-gray-500">{item.unified_category}</div>
+                  {item.expiry_date && <ExpiryLabel date={item.expiry_date} />}
+                  {item.notes && <p className="text-sm text-gray-600">{item.notes}</p>}
+                  {item.attributes && Object.keys(item.attributes).length > 0 && (
+                    <ul className="mt-1 flex flex-wrap gap-1">
+                      {Object.entries(item.attributes).map(([key, value]) => (
+                        <li key={key} className="rounded-full bg-gray-100 px-2 py-0.5 text-xs text-gray-700">
+                          {formatAttribute(key, value)}
+                        </li>
+                      ))}
+                    </ul>
+                  )}
+                </div>
+                <div className="flex items-center gap-2">
+                  <StapleToggle item={item} onToggle={toggleStaple} />
+                  {onUpdateItem && (
+                    <Button
+                      variant="ghost"
+                      size="sm"
+                      onClick={() => setSelected(item)}
+                    >
+                      {t('common.edit')}
+                    </Button>
+                  )}
+                  {onDeleteItem && (
+                    <Button
+                      variant="destructive"
+                      size="sm"
+                      onClick={() => onDeleteItem(item.id)}
+                    >
+                      {t('common.delete')}
+                    </Button>
+                  )}
+                </div>
               </div>
             </div>
-          </div>
-        ))}
-        {items.length === 0 && (
-          <div className="p-4 text-center text-gray-500">
-            Twoja spiżarnia jest pusta
-          </div>
-        )}
-      </div>
+          ))}
+        </div>
+      )}
+      {onUpdateItem && (
+        <PantryItemDetails
+          item={selected}
+          items={items}
+          onClose={() => setSelected(null)}
+          onSave={onUpdateItem}
+          onMerge={onMergeItems}
+        />
+      )}
     </Card>
   );
 }
@@ -0,0 +1,71 @@
+"use client";
+
+import { useMemo } from 'react';
+import { EmptyState } from '../ui/EmptyState';
+import { useTranslation } from '@/hooks/useTranslation';
+import type { Locale, MessageKey } from '@/lib/i18n';
+import { expiryTimeline } from '@/lib/pantryTimeline';
+import { PantryItem } from '@/types/cooking';
+
+interface PantryTimelineProps {
+  items: PantryItem[];
+}
+
+function dayLabel(date: string, offset: number, t: (key: MessageKey) => string, locale: Locale): string {
+  if (offset === 0) return t('cooking.timeline.today');
+  if (offset === 1) return t('cooking.timeline.tomorrow');
+  return new Date(`${date}T00:00:00`).toLocaleDateString(locale, { weekday: 'short', day: 'numeric', month: 'short' });
+}
+
+// Upcoming expirations as day columns; scrolls horizontally
+export function PantryTimeline({ items }: PantryTimelineProps) {
+  const { t, locale } = useTranslation();
+  const { overdue, days, later } = useMemo(() => expiryTimeline(items), [items]);
+
+  if (overdue.length === 0 && later === 0 && days.every((day) => day.items.length === 0)) {
+    return <EmptyState message={t('cooking.timeline.empty')} align="center" />;
+  }
+
+  return (
+    <div className="p-4">
+      <ol className="flex gap-2 overflow-x-auto pb-2" aria-label={t('cooking.timeline.label')}>
+        {overdue.length > 0 && (
+          <li className="w-36 shrink-0 rounded-md border border-red-200 bg-red-50 p-2">
+            <h3 className="mb-1 text-xs font-semibold text-red-700">{t('cooking.expired')}</h3>
+            <ul className="space-y-1 text-sm">
+              {overdue.map((item) => (
+                <li key={item.id} className="truncate" title={`${item.name} (${item.expiry_date})`}>
+                  {item.name}
+                </li>
+              ))}
+            </ul>
+          </li>
+        )}
+        {days.map((day) => (
+          <li
+            key={day.date}
+            className={`w-36 shrink-0 rounded-md border p-2 ${
+              day.items.length === 0 ? 'opacity-50' : day.offset <= 3 ? 'border-amber-200 bg-amber-50' : ''
+            }`}
+          >
+            <h3 className="mb-1 text-xs font-semibold text-gray-700">
+              <time dateTime={day.date}>{dayLabel(day.date, day.offset, t, locale)}</time>
+            </h3>
+            {day.items.length > 0 ? (
+              <ul className="space-y-1 text-sm">
+                {day.items.map((item) => (
+                  <li key={item.id} className="truncate" title={item.name}>
+                    {item.name}
+                  </li>
+                ))}
+              </ul>
+            ) : (
+              <p className="text-xs text-gray-400">—</p>
+            )}
+          </li>
+        ))}
+      </ol>
+      {later > 0 && <p className="mt-2 text-xs text-gray-500">{t('cooking.timeline.later', { count: later })}</p>}
+    </div>
+  );
+}
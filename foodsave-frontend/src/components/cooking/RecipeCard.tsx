@@ -0,0 +1,31 @@
+"use client";
+
+import { ChefHat, Clock } from 'lucide-react';
+import { Button } from '../ui/Button';
+import { useTranslation } from '@/hooks/useTranslation';
+import { RecipeCardProps } from '@/types/cooking';
+
+export function RecipeCard({ recipe, onSelect, onCooked }: RecipeCardProps) {
+  const { t } = useTranslation();
+  return (
+    <div className="flex flex-col gap-2 rounded-md border p-3">
+      <button type="button" onClick={() => onSelect(recipe)} className="text-left font-medium hover:underline">
+        {recipe.name}
+      </button>
+      {recipe.description && <p className="line-clamp-2 text-sm text-gray-600">{recipe.description}</p>}
+      <div className="flex items-center gap-3 text-xs text-gray-500">
+        <span className="flex items-center gap-1">
+          <Clock className="h-3 w-3" />
+          {recipe.prepTime + recipe.cookTime} min
+        </span>
+        <span>{t('cooking.recipe.ingredients', { count: recipe.ingredients.length })}</span>
+      </div>
+      {onCooked && (
+        <Button variant="outline" size="sm" onClick={() => onCooked(recipe)} className="self-start">
+          <ChefHat className="mr-1 h-4 w-4" />
+          {t('cooking.recipe.cooked')}
+        </Button>
+      )}
+    </div>
+  );
+}
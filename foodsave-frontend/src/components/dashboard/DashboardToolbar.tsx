@@ -0,0 +1,68 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { RefreshCw, Timer } from 'lucide-react';
+import { Button } from '@/components/ui/Button';
+import { useTranslation } from '@/hooks/useTranslation';
+import {
+  REFRESH_OPTIONS,
+  RefreshIntervals,
+  WIDGET_LABELS,
+  WidgetId,
+  loadRefreshIntervals,
+  requestRefreshAll,
+  saveRefreshIntervals,
+} from '@/lib/dashboardRefresh';
+import { formatDuration } from '@/lib/time';
+
+export function DashboardToolbar() {
+  const { t, locale } = useTranslation();
+  const [showSettings, setShowSettings] = useState(false);
+  const [intervals, setIntervals] = useState<RefreshIntervals | null>(null);
+
+  useEffect(() => {
+    setIntervals(loadRefreshIntervals());
+  }, []);
+
+  const handleChange = (widget: WidgetId, minutes: number) => {
+    if (!intervals) return;
+    const next = { ...intervals, [widget]: minutes };
+    setIntervals(next);
+    saveRefreshIntervals(next);
+  };
+
+  return (
+    <div className="relative flex justify-end gap-2">
+      <Button variant="outline" size="sm" onClick={() => setShowSettings(!showSettings)} aria-expanded={showSettings}>
+        <Timer className="h-4 w-4 mr-2" />
+        {t('dashboard.autoRefresh')}
+      </Button>
+      <Button variant="outline" size="sm" onClick={requestRefreshAll}>
+        <RefreshCw className="h-4 w-4 mr-2" />
+        {t('dashboard.refreshAll')}
+      </Button>
+      {showSettings && intervals && (
+        <div className="absolute right-0 top-full mt-2 z-10 w-72 p-4 space-y-3 rounded-md border bg-card shadow-lg">
+          {(Object.keys(WIDGET_LABELS) as WidgetId[]).map((widget) => (
+            <label key={widget} className="flex items-center justify-between text-sm">
+              <span>{t(WIDGET_LABELS[widget])}</span>
+              <select
+                value={intervals[widget]}
+                onChange={(e) => handleChange(widget, Number(e.target.value))}
+                className="border rounded px-2 py-1 bg-background"
+              >
+                {REFRESH_OPTIONS.map((minutes) => (
+                  <option key={minutes} value={minutes}>
+                    {minutes === 0
+                      ? t('dashboard.refreshOff')
+                      : t('dashboard.refreshEvery', { interval: formatDuration(minutes * 60_000, locale, 'short') })}
+                  </option>
+                ))}
+              </select>
+            </label>
+          ))}
+        </div>
+      )}
+    </div>
+  );
+}
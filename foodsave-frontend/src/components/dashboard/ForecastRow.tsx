@@ -0,0 +1,85 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { useTranslation } from '@/hooks/useTranslation';
+import type { Locale } from '@/lib/i18n';
+import { WeatherForecastDay } from '@/types/api';
+import { WeatherIcon } from './WeatherIcon';
+
+interface ForecastRowProps {
+  days: WeatherForecastDay[];
+}
+
+function formatTemp(value: number | null): string {
+  return value === null || value === undefined ? '–' : `${Math.round(value)}°`;
+}
+
+function weekday(date: string, locale: Locale): string {
+  return new Date(`${date}T12:00:00`).toLocaleDateString(locale, { weekday: 'short' });
+}
+
+export function ForecastRow({ days }: ForecastRowProps) {
+  const { t, locale } = useTranslation();
+  const [selected, setSelected] = useState<number | null>(null);
+
+  useEffect(() => {
+    if (selected === null) return;
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (e.key === 'Escape') setSelected(null);
+    };
+    window.addEventListener('keydown', onKeyDown);
+    return () => window.removeEventListener('keydown', onKeyDown);
+  }, [selected]);
+
+  if (days.length === 0) return null;
+
+  const detail = selected !== null ? days[selected] : null;
+
+  return (
+    <div className="relative">
+      <div className="grid gap-1" style={{ gridTemplateColumns: `repeat(${days.length}, minmax(0, 1fr))` }}>
+        {days.map((day, index) => (
+          <button
+            key={day.date}
+            type="button"
+            onClick={() => setSelected(selected === index ? null : index)}
+            aria-expanded={selected === index}
+            className={`flex flex-col items-center p-2 rounded-md text-sm transition-colors hover:bg-accent ${
+              selected === index ? 'bg-accent' : ''
+            }`}
+          >
+            <span className="text-xs text-muted-foreground capitalize">{weekday(day.date, locale)}</span>
+            <WeatherIcon condition={day.condition} className="h-6 w-6 my-1" />
+            <span className="font-medium">
+              {formatTemp(day.max_temp)} <span className="text-muted-foreground">{formatTemp(day.min_temp)}</span>
+            </span>
+            {day.chance_of_rain !== null && (
+              <span className="text-xs text-blue-600">💧 {Math.round(day.chance_of_rain)}%</span>
+            )}
+          </button>
+        ))}
+      </div>
+      {detail && (
+        <div
+          role="dialog"
+          aria-label={t('weather.forecastFor', { date: detail.date })}
+          className="absolute z-10 left-0 right-0 mt-2 p-3 rounded-md border bg-card shadow-lg text-sm"
+        >
+          <p className="font-semibold mb-1">
+            {new Date(`${detail.date}T12:00:00`).toLocaleDateString(locale, { weekday: 'long', day: 'numeric', month: 'long' })}
+          </p>
+          <p className="flex items-center gap-1">
+            <WeatherIcon condition={detail.condition} className="h-4 w-4" />
+            {detail.condition ?? t('weather.noDescription')}
+          </p>
+          <p>{t('weather.temperature', { min: formatTemp(detail.min_temp), max: formatTemp(detail.max_temp) })}</p>
+          <p>
+            {t('weather.rainChance', {
+              chance: detail.chance_of_rain !== null ? `${Math.round(detail.chance_of_rain)}%` : t('weather.noData'),
+            })}
+          </p>
+        </div>
+      )}
+    </div>
+  );
+}
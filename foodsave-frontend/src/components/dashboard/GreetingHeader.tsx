@@ -0,0 +1,110 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { useQuery } from '@tanstack/react-query';
+import { ApiService } from '@/services/ApiService';
+import { useSettings } from '@/hooks/useSettings';
+import { useTranslation } from '@/hooks/useTranslation';
+import {
+  buildDailySummary,
+  cachedPolishedSummary,
+  formatDailySummary,
+  savePolishedSummary,
+  timeOfDayGreeting,
+} from '@/lib/greeting';
+import { activeWeatherCity } from '@/lib/settings';
+import { localIsoDate } from '@/lib/time';
+
+export function GreetingHeader() {
+  const { t, locale } = useTranslation();
+  const { settings, updateSettings } = useSettings();
+  const [isEditingName, setIsEditingName] = useState(false);
+  const [nameDraft, setNameDraft] = useState('');
+
+  // Minute tick keeps the greeting current; the day key refreshes the summary daily
+  const [now, setNow] = useState(() => new Date());
+  useEffect(() => {
+    const timer = setInterval(() => setNow(new Date()), 60_000);
+    return () => clearInterval(timer);
+  }, []);
+  const day = localIsoDate(now);
+
+  const { data: expiring } = useQuery({
+    queryKey: ['products', 'expiring', 7, day],
+    queryFn: ({ signal }) => ApiService.getExpiringProducts(7, signal),
+    staleTime: 60 * 60 * 1000,
+  });
+
+  const location = activeWeatherCity(settings);
+  const { data: forecast } = useQuery({
+    queryKey: ['weather-forecast', location, day],
+    queryFn: ({ signal }) => ApiService.getWeatherForecast(location, 1, signal),
+    staleTime: 60 * 60 * 1000,
+  });
+
+  const summary = formatDailySummary(
+    buildDailySummary({
+      expiringCount: expiring?.length,
+      today: forecast?.forecast.find((d) => d.date === day) ?? forecast?.forecast[0],
+    }, locale),
+  );
+
+  // Wersja lokalna jest widoczna od razu i zostaje, gdy LLM jest niedostępny
+  const { data: polished } = useQuery({
+    queryKey: ['greeting-polish', day, summary],
+    queryFn: async ({ signal }) => {
+      const cached = cachedPolishedSummary(day, summary);
+      if (cached) return cached;
+      const { summary: text } = await ApiService.polishGreeting(summary, signal);
+      savePolishedSummary(day, summary, text);
+      return text;
+    },
+    enabled: summary !== '',
+    staleTime: Infinity,
+    retry: false,
+  });
+
+  const saveName = () => {
+    updateSettings({ userName: nameDraft.trim() });
+    setIsEditingName(false);
+  };
+
+  return (
+    <div>
+      <h1 className="text-2xl font-bold text-foreground">
+        {timeOfDayGreeting(now, locale)}
+        {isEditingName ? (
+          <>
+            ,{' '}
+            <input
+              autoFocus
+              value={nameDraft}
+              onChange={(e) => setNameDraft(e.target.value)}
+              onBlur={saveName}
+              onKeyDown={(e) => {
+                if (e.key === 'Enter') saveName();
+                if (e.key === 'Escape') setIsEditingName(false);
+              }}
+              placeholder={t('dashboard.name')}
+              aria-label={t('dashboard.name')}
+              className="w-40 border-b bg-transparent focus:outline-none"
+            />
+          </>
+        ) : (
+          <button
+            type="button"
+            onClick={() => {
+              setNameDraft(settings.userName);
+              setIsEditingName(true);
+            }}
+            title={t('dashboard.changeName')}
+            className="hover:underline decoration-dotted"
+          >
+            {settings.userName ? `, ${settings.userName}` : '!'}
+          </button>
+        )}
+      </h1>
+      {summary && <p className="text-muted-foreground mt-1">{polished ?? summary}</p>}
+    </div>
+  );
+}
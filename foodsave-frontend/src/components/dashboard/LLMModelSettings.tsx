@@ -7,8 +7,11 @@ import { LLMModel } from '@/types/api';
 import { Card } from '@/components/ui/Card';
 import { Button } from '@/components/ui/Button';
 import { Spinner } from '@/components/ui/Spinner';
+import { LoadingState } from '@/components/ui/LoadingState';
+import { useTranslation } from '@/hooks/useTranslation';
 
 export const LLMModelSettings: React.FC = () => {
+  const { t } = useTranslation();
   const [selectedModel, setSelectedModel] = useState<string>('');
   const queryClient = useQueryClient();
 
@@ -83,10 +86,7 @@ export const LLMModelSettings: React.FC = () => {
   if (isLoadingModels || isLoadingCurrent) {
     return (
       <Card className="p-6">
-        <div className="flex items-center justify-center space-x-2">
-          <Spinner />
-          <span>Ładowanie ustawień modeli...</span>
-        </div>
+        <LoadingState label={t('dashboard.llm.loading')} />
       </Card>
     );
   }
@@ -95,8 +95,8 @@ export const LLMModelSettings: React.FC = () => {
     return (
       <Card className="p-6">
         <div className="text-red-600">
-          <h3 className="font-semibold mb-2">Błąd ładowania ustawień</h3>
-          <p>Nie udało się załadować ustawień modeli LLM.</p>
+          <h3 className="font-semibold mb-2">{t('dashboard.llm.errorTitle')}</h3>
+          <p>{t('dashboard.llm.errorBody')}</p>
         </div>
       </Card>
     );
@@ -104,21 +104,21 @@ export const LLMModelSettings: React.FC = () => {
 
   return (
     <Card className="p-6">
-      <h2 className="text-xl font-semibold mb-4">Ustawienia Modelu LLM</h2>
+      <h2 className="text-xl font-semibold mb-4">{t('dashboard.llm.title')}</h2>
 
       {/* Current Model Display */}
       <div className="mb-6">
-        <h3 className="text-lg font-medium mb-2">Aktualny model:</h3>
+        <h3 className="text-lg font-medium mb-2">{t('dashboard.llm.current')}</h3>
         <div className="bg-gray-100 p-3 rounded-lg">
           <span className="font-mono text-sm">
-            {currentModel || (isLoadingCurrent ? 'Ładowanie...' : 'Nie wybrano modelu')}
+            {currentModel || (isLoadingCurrent ? t('dashboard.llm.loadingShort') : t('dashboard.llm.none'))}
           </span>
         </div>
       </div>
 
       {/* Model Selection */}
       <div className="mb-6">
-        <h3 className="text-lg font-medium mb-2">Wybierz model:</h3>
+        <h3 className="text-lg font-medium mb-2">{t('dashboard.llm.choose')}</h3>
         <div className="space-y-2 max-h-60 overflow-y-auto">
           {availableModels?.map((model: LLMModel) => (
             <div
@@ -142,7 +142,7 @@ export const LLMModelSettings: React.FC = () => {
                 <div className="flex-1">
                   <h4 className="font-medium text-sm">{model.name}</h4>
                   <p className="text-xs text-gray-600 mt-1">
-                    Rozmiar: {model.size} | Zmodyfikowano: {model.modified_at}
+                    {t('dashboard.llm.details', { size: model.size, modified: model.modified_at })}
                   </p>
                 </div>
                 {selectedModel === model.name && (
@@ -164,10 +164,10 @@ export const LLMModelSettings: React.FC = () => {
           {setModelMutation.isPending ? (
             <>
               <Spinner className="w-4 h-4 mr-2" />
-              Zapisywanie...
+              {t('dashboard.llm.saving')}
             </>
           ) : (
-            'Zapisz model'
+            t('dashboard.llm.save')
           )}
         </Button>
       </div>
@@ -176,7 +176,7 @@ export const LLMModelSettings: React.FC = () => {
       {setModelMutation.error && (
         <div className="mt-4 p-3 bg-red-50 border border-red-200 rounded-lg">
           <p className="text-red-700 text-sm">
-            Błąd podczas zapisywania modelu: {setModelMutation.error.message}
+            {t('dashboard.llm.saveError', { error: setModelMutation.error.message })}
           </p>
         </div>
       )}
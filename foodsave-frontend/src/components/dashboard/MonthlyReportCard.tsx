@@ -0,0 +1,69 @@
+"use client";
+
+import Link from 'next/link';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { LoadingState } from '@/components/ui/LoadingState';
+import { WidgetRefreshButton } from '@/components/dashboard/WidgetRefreshButton';
+import { useMonthlyReport } from '@/hooks/useMonthlyReport';
+import { useWidgetRefresh } from '@/hooks/useWidgetRefresh';
+import { useTranslation } from '@/hooks/useTranslation';
+import { TOP_CATEGORIES, formatChange, formatMonth, lastCompletedMonth } from '@/lib/monthlyReport';
+import { formatMoney } from '@/lib/money';
+
+// Podsumowanie zakończonego miesiąca; pełny raport w Analityce
+export function MonthlyReportCard() {
+  const { t, locale } = useTranslation();
+  const month = lastCompletedMonth();
+  const { report, isLoading, isFetching, refetch } = useMonthlyReport(month);
+  useWidgetRefresh('budget', () => void refetch());
+
+  return (
+    <Card>
+      <CardHeader>
+        <div className="flex items-center justify-between">
+          <CardTitle>{t('dashboard.report.title', { month: formatMonth(month, locale) })}</CardTitle>
+          <WidgetRefreshButton
+            onClick={() => void refetch()}
+            isRefreshing={isFetching}
+            label={t('dashboard.report.refresh')}
+          />
+        </div>
+      </CardHeader>
+      <CardContent>
+        {isLoading ? (
+          <LoadingState size="sm" />
+        ) : !report || report.receipts === 0 ? (
+          <p className="text-sm text-gray-500">{t('report.empty')}</p>
+        ) : (
+          <div className="space-y-3">
+            <div className="flex items-baseline justify-between">
+              <span className="text-3xl font-bold">{formatMoney(report.total)}</span>
+              <span
+                className={`text-sm ${report.change && report.change > 0 ? 'text-red-600' : 'text-emerald-600'}`}
+                title={t('dashboard.report.change')}
+              >
+                {formatChange(report.change)}
+              </span>
+            </div>
+            <ul className="text-sm space-y-1">
+              {report.categories.slice(0, TOP_CATEGORIES).map((category) => (
+                <li key={category.label} className="flex justify-between">
+                  <span>{category.label}</span>
+                  <span className="text-gray-600">{formatMoney(category.total)}</span>
+                </li>
+              ))}
+            </ul>
+            {report.wastedCount > 0 && (
+              <p className="text-sm text-amber-700">
+                {t('report.summary.wasted', { count: report.wastedCount, amount: formatMoney(report.wastedTotal) })}
+              </p>
+            )}
+            <Link href="/analytics#monthly-report" className="text-sm text-primary underline">
+              {t('dashboard.report.full')}
+            </Link>
+          </div>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
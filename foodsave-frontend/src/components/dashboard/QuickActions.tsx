@@ -0,0 +1,158 @@
+"use client";
+
+import { FormEvent, useState } from 'react';
+import Link from 'next/link';
+import { v4 as uuidv4 } from 'uuid';
+import { PackagePlus, Send } from 'lucide-react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Button } from '@/components/ui/Button';
+import { useTranslation } from '@/hooks/useTranslation';
+import { ApiService } from '@/services/ApiService';
+import { PantryItem } from '@/types/cooking';
+import { logActivity } from '@/lib/activityLog';
+import { appendChatMessages, getChatHistory } from '@/lib/chatHistory';
+import { profileSessionId } from '@/lib/profiles';
+
+function QuickAddPantryItem() {
+  const { t } = useTranslation();
+  const [name, setName] = useState('');
+  const [quantity, setQuantity] = useState('1');
+  const [status, setStatus] = useState<{ ok: boolean; text: string } | null>(null);
+  const [isSaving, setIsSaving] = useState(false);
+
+  const handleSubmit = async (e: FormEvent) => {
+    e.preventDefault();
+    if (!name.trim()) return;
+    setIsSaving(true);
+    setStatus(null);
+    try {
+      // Ten sam endpoint, co spiżarnia na stronie gotowania
+      await ApiService.post<PantryItem>('/api/v1/pantry/items', {
+        name: name.trim(),
+        quantity: parseFloat(quantity.replace(',', '.')) || 1,
+        unit: 'szt',
+        unified_category: 'Inne',
+      });
+      logActivity('pantry_change', t('cooking.activity.added', { name: name.trim() }), '/cooking');
+      setStatus({ ok: true, text: t('dashboard.quick.added', { name: name.trim() }) });
+      setName('');
+      setQuantity('1');
+    } catch (err) {
+      setStatus({ ok: false, text: err instanceof Error ? err.message : t('dashboard.quick.addError') });
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  return (
+    <form onSubmit={handleSubmit} className="space-y-2">
+      <label className="block text-sm font-medium text-gray-700" htmlFor="quick-pantry-name">
+        {t('dashboard.quick.addLabel')}
+      </label>
+      <div className="flex gap-2">
+        <input
+          id="quick-pantry-name"
+          value={name}
+          onChange={(e) => setName(e.target.value)}
+          placeholder={t('dashboard.quick.addPlaceholder')}
+          className="flex-1 min-w-0 rounded-md border px-3 py-2 text-sm"
+        />
+        <input
+          value={quantity}
+          onChange={(e) => setQuantity(e.target.value)}
+          inputMode="decimal"
+          aria-label={t('shopping.column.quantity')}
+          className="w-16 rounded-md border px-2 py-2 text-sm"
+        />
+        <Button type="submit" size="icon" isLoading={isSaving} aria-label={t('dashboard.quick.add')} disabled={!name.trim()}>
+          <PackagePlus className="h-4 w-4" />
+        </Button>
+      </div>
+      {status && <p className={`text-sm ${status.ok ? 'text-green-600' : 'text-red-600'}`}>{status.text}</p>}
+    </form>
+  );
+}
+
+function QuickNoteToAssistant() {
+  const { t } = useTranslation();
+  const [note, setNote] = useState('');
+  const [reply, setReply] = useState<string | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const [isSending, setIsSending] = useState(false);
+
+  const handleSubmit = async (e: FormEvent) => {
+    e.preventDefault();
+    const content = note.trim();
+    if (!content) return;
+    setIsSending(true);
+    setReply(null);
+    setError(null);
+    try {
+      logActivity('chat', t('dashboard.quick.activity', { text: content.slice(0, 60) }), '/chat');
+      // Notatka trafia do bieżącej rozmowy czatu, żeby dało się ją tam kontynuować
+      const conversation = getChatHistory('general');
+      const sessionId = conversation?.sessionId ?? profileSessionId(uuidv4());
+      const sentAt = Date.now();
+      const response = await ApiService.sendChatMessage({
+        message: content,
+        session_id: sessionId,
+        agent_states: { weather: true, search: true, shopping: false, cooking: false },
+        useBielik: true,
+        language: conversation?.language === 'auto' ? undefined : conversation?.language,
+      });
+      const answer = response?.response || t('dashboard.quick.noted');
+      appendChatMessages('general', sessionId, [
+        { id: uuidv4(), role: 'user', content, timestamp: sentAt, status: 'delivered' },
+        { id: uuidv4(), role: 'assistant', content: answer, timestamp: Date.now() },
+      ]);
+      setReply(answer);
+      setNote('');
+    } catch (err) {
+      setError(err instanceof Error ? err.message : t('dashboard.quick.sendError'));
+    } finally {
+      setIsSending(false);
+    }
+  };
+
+  return (
+    <form onSubmit={handleSubmit} className="space-y-2">
+      <label className="block text-sm font-medium text-gray-700" htmlFor="quick-note">
+        {t('dashboard.quick.noteLabel')}
+      </label>
+      <div className="flex gap-2">
+        <input
+          id="quick-note"
+          value={note}
+          onChange={(e) => setNote(e.target.value)}
+          placeholder={t('dashboard.quick.notePlaceholder')}
+          className="flex-1 min-w-0 rounded-md border px-3 py-2 text-sm"
+        />
+        <Button type="submit" size="icon" isLoading={isSending} aria-label={t('dashboard.quick.send')} disabled={!note.trim()}>
+          <Send className="h-4 w-4" />
+        </Button>
+      </div>
+      {error && <p className="text-sm text-red-600">{error}</p>}
+      {reply && (
+        <div className="text-sm p-2 rounded bg-muted">
+          <p className="line-clamp-3">{reply}</p>
+          <Link href="/chat" className="text-primary underline text-xs">{t('dashboard.quick.continue')}</Link>
+        </div>
+      )}
+    </form>
+  );
+}
+
+export function QuickActions() {
+  const { t } = useTranslation();
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>{t('dashboard.quick.title')}</CardTitle>
+      </CardHeader>
+      <CardContent className="grid grid-cols-1 md:grid-cols-2 gap-6">
+        <QuickAddPantryItem />
+        <QuickNoteToAssistant />
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,68 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import Link from 'next/link';
+import { Camera, MessageCircle, Package, Receipt } from 'lucide-react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { useActivityLog } from '@/hooks/useActivityLog';
+import { ActivityType } from '@/lib/activityLog';
+import { formatRelativeTime } from '@/lib/time';
+import { useTranslation } from '@/hooks/useTranslation';
+import { EmptyState } from '@/components/ui/EmptyState';
+
+const ICONS: Record<ActivityType, typeof Camera> = {
+  receipt_scan: Camera,
+  receipt_saved: Receipt,
+  pantry_change: Package,
+  chat: MessageCircle,
+};
+
+export function RecentActivities() {
+  const activities = useActivityLog(8);
+  const { t, locale } = useTranslation();
+  // Re-render every minute so relative timestamps stay fresh
+  const [now, setNow] = useState(() => new Date());
+  useEffect(() => {
+    const timer = setInterval(() => setNow(new Date()), 60_000);
+    return () => clearInterval(timer);
+  }, []);
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>{t('dashboard.activities.title')}</CardTitle>
+      </CardHeader>
+      <CardContent>
+        {activities.length === 0 ? (
+          <EmptyState message={t('dashboard.activities.empty')} />
+        ) : (
+          <ul className="space-y-1">
+            {activities.map((activity) => {
+              const Icon = ICONS[activity.type];
+              const content = (
+                <>
+                  <Icon className="h-4 w-4 shrink-0 text-muted-foreground" />
+                  <span className="flex-1 truncate">{activity.title}</span>
+                  <time dateTime={activity.timestamp} className="text-xs text-muted-foreground whitespace-nowrap">
+                    {formatRelativeTime(activity.timestamp, now, locale)}
+                  </time>
+                </>
+              );
+              return (
+                <li key={activity.id}>
+                  {activity.href ? (
+                    <Link href={activity.href} className="flex items-center gap-2 p-2 rounded-md text-sm hover:bg-accent">
+                      {content}
+                    </Link>
+                  ) : (
+                    <div className="flex items-center gap-2 p-2 text-sm">{content}</div>
+                  )}
+                </li>
+              );
+            })}
+          </ul>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,60 @@
+"use client";
+
+import Link from 'next/link';
+import { useQuery } from '@tanstack/react-query';
+import { Receipt } from 'lucide-react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { receiptTotal } from '@/components/shopping/ReceiptHistory';
+import { formatMoney } from '@/lib/money';
+import { ApiService } from '@/services/ApiService';
+import { useWidgetRefresh } from '@/hooks/useWidgetRefresh';
+import { useTranslation } from '@/hooks/useTranslation';
+import { WidgetRefreshButton } from './WidgetRefreshButton';
+import { EmptyState } from '@/components/ui/EmptyState';
+import { LoadingState } from '@/components/ui/LoadingState';
+
+const RECENT_COUNT = 5;
+
+export function RecentReceipts() {
+  const { t } = useTranslation();
+  const { data: trips = [], isLoading, isFetching, error, refetch } = useQuery({
+    queryKey: ['shopping-trips', 'recent', RECENT_COUNT],
+    queryFn: ({ signal }) => ApiService.getShoppingTrips({ limit: RECENT_COUNT }, signal),
+  });
+  useWidgetRefresh('receipts', refetch);
+
+  return (
+    <Card>
+      <CardHeader>
+        <div className="flex items-center justify-between">
+          <CardTitle>{t('dashboard.widget.receipts')}</CardTitle>
+          <WidgetRefreshButton onClick={() => refetch()} isRefreshing={isFetching} label={t('dashboard.receipts.refresh')} />
+        </div>
+      </CardHeader>
+      <CardContent>
+        {isLoading && <LoadingState size="sm" />}
+        {error && <p className="text-sm text-red-600">{t('dashboard.receipts.error')}</p>}
+        {!isLoading && !error && trips.length === 0 && (
+          <EmptyState message={t('dashboard.receipts.empty')} />
+        )}
+        <ul className="space-y-1">
+          {trips.map((trip) => (
+            <li key={trip.id}>
+              <Link
+                href={`/shopping?receipt=${trip.id}`}
+                className="flex items-center justify-between p-2 rounded-md hover:bg-accent"
+              >
+                <span className="flex items-center gap-2">
+                  <Receipt className="h-4 w-4 text-muted-foreground" />
+                  <span className="font-medium">{trip.store_name}</span>
+                  <span className="text-sm text-muted-foreground">{trip.trip_date}</span>
+                </span>
+                <span className="font-semibold">{formatMoney(receiptTotal(trip))}</span>
+              </Link>
+            </li>
+          ))}
+        </ul>
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,168 @@
+"use client";
+
+import React, { useState } from 'react';
+import Link from 'next/link';
+import { useQuery } from '@tanstack/react-query';
+import { CalendarDays, Repeat, Trash2 } from 'lucide-react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Button } from '@/components/ui/Button';
+import { Input } from '@/components/ui/Input';
+import { useReminders } from '@/hooks/useReminders';
+import { useSettings } from '@/hooks/useSettings';
+import { useTranslation } from '@/hooks/useTranslation';
+import type { Locale } from '@/lib/i18n';
+import { isOverdue, remindersForToday } from '@/lib/reminders';
+import { ApiService } from '@/services/ApiService';
+import { EmptyState } from '@/components/ui/EmptyState';
+import { localIsoDate } from '@/lib/time';
+
+function formatTime(iso: string, locale: Locale): string {
+  return new Date(iso).toLocaleTimeString(locale, { hour: '2-digit', minute: '2-digit' });
+}
+
+function formatDue(iso: string, today: Date, locale: Locale): string {
+  const due = new Date(iso);
+  if (due.toDateString() === today.toDateString()) return formatTime(iso, locale);
+  return `${due.toLocaleDateString(locale, { day: 'numeric', month: 'short' })} ${formatTime(iso, locale)}`;
+}
+
+export function RemindersWidget() {
+  const { t, locale } = useTranslation();
+  const { reminders, addReminder, toggleReminder, removeReminder } = useReminders();
+  const { settings, updateSettings, isLoaded } = useSettings();
+  const [title, setTitle] = useState('');
+  const [time, setTime] = useState('');
+  const [showCalendarForm, setShowCalendarForm] = useState(false);
+  const [icsUrl, setIcsUrl] = useState('');
+
+  const now = new Date();
+  const today = localIsoDate(now);
+  const todays = remindersForToday(reminders, now);
+
+  const { data: events = [], error: eventsError } = useQuery({
+    queryKey: ['calendar-events', settings.calendarIcsUrl, today],
+    queryFn: ({ signal }) => ApiService.getCalendarEvents(settings.calendarIcsUrl, today, signal),
+    enabled: isLoaded && settings.calendarIcsUrl !== '',
+    staleTime: 15 * 60 * 1000,
+  });
+
+  const handleAdd = (e: React.FormEvent) => {
+    e.preventDefault();
+    if (!title.trim()) return;
+    const [hours, minutes] = (time || '23:59').split(':').map(Number);
+    const dueAt = new Date(now.getFullYear(), now.getMonth(), now.getDate(), hours, minutes);
+    addReminder(title, dueAt);
+    setTitle('');
+    setTime('');
+  };
+
+  const handleSaveCalendar = (e: React.FormEvent) => {
+    e.preventDefault();
+    updateSettings({ calendarIcsUrl: icsUrl.trim() });
+    setShowCalendarForm(false);
+  };
+
+  return (
+    <Card>
+      <CardHeader>
+        <div className="flex items-center justify-between">
+          <CardTitle>{t('dashboard.today.title')}</CardTitle>
+          <div className="flex items-center gap-1">
+            <Link href="/reminders" className="text-sm text-primary hover:underline">
+              {t('dashboard.today.all')}
+            </Link>
+            <Button
+              variant="ghost"
+              size="sm"
+              onClick={() => {
+                setIcsUrl(settings.calendarIcsUrl);
+                setShowCalendarForm((v) => !v);
+              }}
+              title={t('dashboard.today.calendar')}
+            >
+              <CalendarDays className="h-4 w-4" />
+            </Button>
+          </div>
+        </div>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        {showCalendarForm && (
+          <form onSubmit={handleSaveCalendar} className="flex items-end gap-2">
+            <div className="flex-1">
+              <Input
+                label={t('dashboard.today.calendarUrl')}
+                value={icsUrl}
+                onChange={(e) => setIcsUrl(e.target.value)}
+                placeholder="https://..."
+              />
+            </div>
+            <Button type="submit" size="sm">
+              {t('common.save')}
+            </Button>
+          </form>
+        )}
+
+        {todays.length === 0 && events.length === 0 ? (
+          <EmptyState message={t('dashboard.today.empty')} />
+        ) : (
+          <ul className="space-y-1">
+            {events.map((event, index) => (
+              <li key={`event-${index}`} className="flex items-center gap-2 p-1 text-sm">
+                <CalendarDays className="h-4 w-4 text-muted-foreground" />
+                <span className="flex-1">{event.summary}</span>
+                <span className="text-muted-foreground">{event.all_day ? t('dashboard.today.allDay') : formatTime(event.start, locale)}</span>
+              </li>
+            ))}
+            {todays.map((reminder) => {
+              const overdue = isOverdue(reminder, now);
+              return (
+                <li key={reminder.id} className="group flex items-center gap-2 p-1 rounded hover:bg-accent">
+                  <input
+                    type="checkbox"
+                    checked={reminder.done}
+                    onChange={() => toggleReminder(reminder.id)}
+                    className="h-4 w-4"
+                  />
+                  <span className={`flex-1 ${reminder.done ? 'line-through text-muted-foreground' : ''}`}>
+                    {reminder.title}
+                    {reminder.repeat !== 'none' && <Repeat className="inline h-3 w-3 ml-1 text-muted-foreground" />}
+                  </span>
+                  <span className={`text-sm ${overdue ? 'text-red-600 font-medium' : 'text-muted-foreground'}`}>
+                    {formatDue(reminder.dueAt, now, locale)}
+                  </span>
+                  <button
+                    type="button"
+                    onClick={() => removeReminder(reminder.id)}
+                    className="opacity-0 group-hover:opacity-100 text-muted-foreground hover:text-red-600"
+                    aria-label={t('dashboard.today.remove')}
+                  >
+                    <Trash2 className="h-4 w-4" />
+                  </button>
+                </li>
+              );
+            })}
+          </ul>
+        )}
+        {eventsError && <p className="text-xs text-red-600">{t('dashboard.today.calendarError')}</p>}
+
+        <form onSubmit={handleAdd} className="flex gap-2">
+          <input
+            value={title}
+            onChange={(e) => setTitle(e.target.value)}
+            placeholder={t('dashboard.today.placeholder')}
+            className="flex-1 rounded-md border px-3 py-1 text-sm"
+          />
+          <input
+            type="time"
+            value={time}
+            onChange={(e) => setTime(e.target.value)}
+            className="rounded-md border px-2 py-1 text-sm"
+          />
+          <Button type="submit" size="sm" disabled={!title.trim()}>
+            {t('common.add')}
+          </Button>
+        </form>
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,47 @@
+"use client";
+
+import Link from 'next/link';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { useShoppingList } from '@/hooks/useShoppingList';
+import { useTranslation } from '@/hooks/useTranslation';
+
+const PREVIEW_COUNT = 5;
+
+export function ShoppingListPreview() {
+  const { t } = useTranslation();
+  const { items, toggleItem } = useShoppingList();
+  const unchecked = items.filter((item) => !item.checked);
+
+  return (
+    <Card>
+      <CardHeader>
+        <div className="flex items-center justify-between">
+          <CardTitle>{t('shopping.list.title')}</CardTitle>
+          <Link href="/shopping#shopping-list" className="text-sm text-primary hover:underline">
+            {t('dashboard.list.all')}
+          </Link>
+        </div>
+      </CardHeader>
+      <CardContent>
+        {unchecked.length === 0 ? (
+          <p className="text-sm text-gray-500">{t('dashboard.list.done')}</p>
+        ) : (
+          <ul className="space-y-1">
+            {unchecked.slice(0, PREVIEW_COUNT).map((item) => (
+              <li key={item.id}>
+                <label className="flex items-center gap-2 p-1 rounded hover:bg-accent cursor-pointer">
+                  <input type="checkbox" checked={false} onChange={() => toggleItem(item.id)} className="h-4 w-4" />
+                  <span className="flex-1">{item.name}</span>
+                  {item.quantity && <span className="text-sm text-muted-foreground">{item.quantity}</span>}
+                </label>
+              </li>
+            ))}
+          </ul>
+        )}
+        {unchecked.length > PREVIEW_COUNT && (
+          <p className="text-xs text-muted-foreground mt-2">{t('dashboard.list.more', { count: unchecked.length - PREVIEW_COUNT })}</p>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,60 @@
+"use client";
+
+import { useEffect, useMemo } from 'react';
+import { AlertTriangle } from 'lucide-react';
+import { WeatherAlert } from '@/types/api';
+import { useTranslation } from '@/hooks/useTranslation';
+import { notify } from '@/lib/notifications';
+
+// Only moderate and severe alerts (watch/warning) get the banner
+const MIN_SEVERITY = 2;
+
+interface WeatherAlertBannerProps {
+  location: string;
+  alerts: WeatherAlert[];
+}
+
+export function WeatherAlertBanner({ location, alerts }: WeatherAlertBannerProps) {
+  const { t, locale } = useTranslation();
+  const severe = useMemo(() => alerts.filter((alert) => alert.severity >= MIN_SEVERITY), [alerts]);
+
+  useEffect(() => {
+    severe.forEach((alert) => {
+      notify({
+        category: 'weather',
+        title: t('weather.alertTitle', { location }),
+        body: alert.headline,
+        href: '/dashboard',
+        onceKey: `weather:${location}:${alert.event}:${alert.effective ?? alert.headline}`,
+      });
+    });
+  }, [severe, location, t]);
+
+  if (severe.length === 0) return null;
+
+  return (
+    <div role="alert" className="space-y-2 mb-3">
+      {severe.map((alert) => (
+        <div
+          key={`${alert.event}-${alert.headline}`}
+          className={`flex gap-2 p-3 rounded-lg border text-sm ${
+            alert.severity >= 3 ? 'bg-red-50 border-red-200 text-red-800' : 'bg-amber-50 border-amber-200 text-amber-800'
+          }`}
+        >
+          <AlertTriangle className="h-5 w-5 shrink-0" />
+          <div>
+            <p className="font-semibold">{alert.headline || alert.event}</p>
+            {alert.description && <p className="mt-1 line-clamp-3">{alert.description}</p>}
+            {alert.expires && (
+              <p className="mt-1 text-xs opacity-80">
+                {t('weather.alertUntil', {
+                  date: new Date(alert.expires).toLocaleString(locale, { dateStyle: 'short', timeStyle: 'short' }),
+                })}
+              </p>
+            )}
+          </div>
+        </div>
+      ))}
+    </div>
+  );
+}
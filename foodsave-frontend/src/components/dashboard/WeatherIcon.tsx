@@ -0,0 +1,24 @@
+"use client";
+
+import { Cloud, CloudFog, CloudLightning, CloudRain, CloudSnow, CloudSun, Sun } from 'lucide-react';
+import { WeatherKind, weatherKind } from '@/lib/weather';
+
+const ICONS: Record<WeatherKind, { icon: typeof Sun; color: string }> = {
+  sunny: { icon: Sun, color: 'text-amber-500' },
+  partly: { icon: CloudSun, color: 'text-sky-500' },
+  cloudy: { icon: Cloud, color: 'text-slate-500' },
+  rainy: { icon: CloudRain, color: 'text-blue-500' },
+  snowy: { icon: CloudSnow, color: 'text-indigo-400' },
+  stormy: { icon: CloudLightning, color: 'text-violet-600' },
+  foggy: { icon: CloudFog, color: 'text-gray-500' },
+};
+
+interface WeatherIconProps {
+  condition: string | null | undefined;
+  className?: string;
+}
+
+export function WeatherIcon({ condition, className = 'h-6 w-6' }: WeatherIconProps) {
+  const { icon: Icon, color } = ICONS[weatherKind(condition)];
+  return <Icon className={`${className} ${color}`} aria-label={condition || undefined} />;
+}
@@ -0,0 +1,96 @@
+"use client";
+
+import { FormEvent, useState } from 'react';
+import { MapPin, Plus, X } from 'lucide-react';
+import { useTranslation } from '@/hooks/useTranslation';
+import { SavedLocation } from '@/lib/settings';
+
+interface WeatherLocationSwitcherProps {
+  locations: SavedLocation[];
+  active: string;
+  onSelect: (city: string) => void;
+  onChange: (locations: SavedLocation[]) => void;
+}
+
+export function WeatherLocationSwitcher({ locations, active, onSelect, onChange }: WeatherLocationSwitcherProps) {
+  const { t } = useTranslation();
+  const [isManaging, setIsManaging] = useState(false);
+  const [label, setLabel] = useState('');
+  const [city, setCity] = useState('');
+
+  const handleAdd = (e: FormEvent) => {
+    e.preventDefault();
+    if (!city.trim()) return;
+    const entry = { label: label.trim() || city.trim(), city: city.trim() };
+    onChange([...locations.filter((l) => l.city !== entry.city), entry]);
+    onSelect(entry.city);
+    setLabel('');
+    setCity('');
+  };
+
+  return (
+    <div className="relative flex items-center gap-1">
+      <select
+        value={active}
+        onChange={(e) => onSelect(e.target.value)}
+        aria-label={t('weather.location')}
+        className="text-sm border rounded px-2 py-1 bg-background"
+      >
+        {locations.map((l) => (
+          <option key={l.city} value={l.city} title={l.city}>
+            {l.label}
+          </option>
+        ))}
+      </select>
+      <button
+        type="button"
+        onClick={() => setIsManaging(!isManaging)}
+        aria-expanded={isManaging}
+        aria-label={t('weather.manage')}
+        className="p-1.5 rounded-full text-muted-foreground hover:bg-accent"
+      >
+        <MapPin className="h-4 w-4" />
+      </button>
+      {isManaging && (
+        <div className="absolute right-0 top-full mt-2 z-10 w-72 p-3 space-y-2 rounded-md border bg-card shadow-lg text-sm">
+          <ul className="space-y-1">
+            {locations.map((l) => (
+              <li key={l.city} className="flex items-center justify-between">
+                <span>
+                  <span className="font-medium">{l.label}</span>
+                  <span className="text-muted-foreground"> · {l.city}</span>
+                </span>
+                <button
+                  type="button"
+                  onClick={() => onChange(locations.filter((x) => x.city !== l.city))}
+                  disabled={locations.length === 1}
+                  aria-label={t('weather.remove', { label: l.label })}
+                  className="p-1 text-muted-foreground hover:text-red-600 disabled:opacity-40"
+                >
+                  <X className="h-3 w-3" />
+                </button>
+              </li>
+            ))}
+          </ul>
+          <form onSubmit={handleAdd} className="flex gap-1">
+            <input
+              value={label}
+              onChange={(e) => setLabel(e.target.value)}
+              placeholder={t('weather.labelPlaceholder')}
+              className="w-28 min-w-0 border rounded px-2 py-1"
+            />
+            <input
+              value={city}
+              onChange={(e) => setCity(e.target.value)}
+              placeholder={t('weather.cityPlaceholder')}
+              className="flex-1 min-w-0 border rounded px-2 py-1"
+            />
+            <button type="submit" aria-label={t('weather.add')} className="p-1 rounded hover:bg-accent" disabled={!city.trim()}>
+              <Plus className="h-4 w-4" />
+            </button>
+          </form>
+        </div>
+      )}
+    </div>
+  );
+}
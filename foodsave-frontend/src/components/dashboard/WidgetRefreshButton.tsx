@@ -0,0 +1,26 @@
+"use client";
+
+import { RefreshCw } from 'lucide-react';
+import { useTranslation } from '@/hooks/useTranslation';
+
+interface WidgetRefreshButtonProps {
+  onClick: () => void;
+  isRefreshing: boolean;
+  label?: string;
+}
+
+export function WidgetRefreshButton({ onClick, isRefreshing, label }: WidgetRefreshButtonProps) {
+  const { t } = useTranslation();
+  return (
+    <button
+      type="button"
+      onClick={onClick}
+      disabled={isRefreshing}
+      aria-label={label ?? t('dashboard.refresh')}
+      title={label ?? t('dashboard.refresh')}
+      className="p-1.5 rounded-full text-muted-foreground hover:bg-accent disabled:opacity-60"
+    >
+      <RefreshCw className={`h-4 w-4 ${isRefreshing ? 'animate-spin' : ''}`} />
+    </button>
+  );
+}
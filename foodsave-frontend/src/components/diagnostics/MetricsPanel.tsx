@@ -0,0 +1,80 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { Trash2 } from 'lucide-react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Button } from '@/components/ui/Button';
+import { useTranslation } from '@/hooks/useTranslation';
+import { MetricSample, clearMetrics, getMetrics, isMetricsEnabled, subscribeMetrics, summarizeMetrics } from '@/lib/metrics';
+
+// Percentiles per API endpoint and OCR, slowest first
+export function MetricsPanel() {
+  const { t, locale } = useTranslation();
+  const [samples, setSamples] = useState<MetricSample[]>([]);
+  const [enabled, setEnabled] = useState(false);
+
+  useEffect(() => {
+    const refresh = () => {
+      setSamples(getMetrics());
+      setEnabled(isMetricsEnabled());
+    };
+    refresh();
+    return subscribeMetrics(refresh);
+  }, []);
+
+  const rows = summarizeMetrics(samples);
+  const ms = (value: number) =>
+    value < 1000 ? `${value} ms` : `${(value / 1000).toLocaleString(locale, { maximumFractionDigits: 1 })} s`;
+
+  return (
+    <Card>
+      <CardHeader>
+        <div className="flex flex-wrap items-center justify-between gap-2">
+          <CardTitle>{t('metrics.title')}</CardTitle>
+          <Button variant="outline" size="sm" onClick={clearMetrics} disabled={samples.length === 0}>
+            <Trash2 className="h-4 w-4 mr-2" />
+            {t('settings.data.clear')}
+          </Button>
+        </div>
+      </CardHeader>
+      <CardContent>
+        {!enabled ? (
+          <p className="text-sm text-muted-foreground">{t('metrics.disabled')}</p>
+        ) : rows.length === 0 ? (
+          <p className="text-sm text-muted-foreground">{t('metrics.empty')}</p>
+        ) : (
+          <div className="overflow-auto">
+            <table className="w-full text-sm">
+              <thead>
+                <tr className="text-left text-muted-foreground">
+                  <th className="py-1 pr-3 font-medium">{t('metrics.name')}</th>
+                  <th className="py-1 px-2 font-medium text-right">{t('metrics.count')}</th>
+                  <th className="py-1 px-2 font-medium text-right">{t('metrics.errors')}</th>
+                  <th className="py-1 px-2 font-medium text-right">p50</th>
+                  <th className="py-1 px-2 font-medium text-right">p90</th>
+                  <th className="py-1 px-2 font-medium text-right">p99</th>
+                  <th className="py-1 pl-2 font-medium text-right">{t('metrics.max')}</th>
+                </tr>
+              </thead>
+              <tbody className="divide-y font-mono text-xs">
+                {rows.map((row) => (
+                  <tr key={row.name}>
+                    <td className="py-1 pr-3 break-all">{row.name}</td>
+                    <td className="py-1 px-2 text-right">{row.count}</td>
+                    <td className={`py-1 px-2 text-right ${row.errorRate > 0.05 ? 'text-red-700' : ''}`}>
+                      {Math.round(row.errorRate * 100)}%
+                    </td>
+                    <td className="py-1 px-2 text-right">{ms(row.p50)}</td>
+                    <td className="py-1 px-2 text-right">{ms(row.p90)}</td>
+                    <td className="py-1 px-2 text-right">{ms(row.p99)}</td>
+                    <td className="py-1 pl-2 text-right">{ms(row.max)}</td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          </div>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
@@ -2,18 +2,21 @@
 
 import Link from 'next/link';
 import { usePathname } from 'next/navigation';
+import type { MessageKey } from '@/lib/i18n';
+import { useTranslation } from '@/hooks/useTranslation';
 
-const navItems = [
-  { name: 'Panel', href: '/dashboard', icon: '🏠' },
-  { name: 'Czat', href: '/chat', icon: '💬' },
-  { name: 'Zakupy', href: '/shopping', icon: '🛒' },
-  { name: 'Gotowanie', href: '/cooking', icon: '👨‍🍳' },
-  { name: 'RAG System', href: '/rag', icon: '📚' },
-  { name: 'Backup', href: '/backup', icon: '💾' },
+const navItems: { nameKey: MessageKey; href: string; icon: string }[] = [
+  { nameKey: 'nav.dashboard', href: '/dashboard', icon: '🏠' },
+  { nameKey: 'nav.chat', href: '/chat', icon: '💬' },
+  { nameKey: 'nav.shopping', href: '/shopping', icon: '🛒' },
+  { nameKey: 'nav.cooking', href: '/cooking', icon: '👨‍🍳' },
+  { nameKey: 'nav.rag', href: '/rag', icon: '📚' },
+  { nameKey: 'nav.backup', href: '/backup', icon: '💾' },
 ];
 
 export function Sidebar() {
   const pathname = usePathname();
+  const { t } = useTranslation();
 
   return (
     <aside className="w-64 flex-shrink-0 bg-gray-800 text-white flex flex-col">
@@ -23,7 +26,7 @@ export function Sidebar() {
       <nav className="flex-grow p-4">
         <ul>
           {navItems.map((item) => (
-            <li key={item.name} className="mb-2">
+            <li key={item.href} className="mb-2">
               <Link
                 href={item.href}
                 className={`flex items-center p-3 rounded-lg transition-colors ${
@@ -33,7 +36,7 @@ export function Sidebar() {
                 }`}
               >
                 <span className="mr-3 text-2xl">{item.icon}</span>
-                <span>{item.name}</span>
+                <span>{t(item.nameKey)}</span>
               </Link>
             </li>
           ))}
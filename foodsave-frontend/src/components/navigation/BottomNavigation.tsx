@@ -1,40 +1,48 @@
 'use client';
 import { useRouter, usePathname } from 'next/navigation';
 import { cn } from '@/lib/utils';
+import { useTranslation } from '@/hooks/useTranslation';
+import { MessageKey } from '@/lib/i18n';
 import { Home, MessageCircle, ShoppingCart, ChefHat } from 'lucide-react';
 
-const navigationItems = [
-  { path: '/dashboard', icon: Home, label: 'Dashboard' },
-  { path: '/chat', icon: MessageCircle, label: 'Czat' },
-  { path: '/shopping', icon: ShoppingCart, label: 'Zakupy' },
-  { path: '/cooking', icon: ChefHat, label: 'Gotowanie' },
+const navigationItems: { path: string; icon: typeof Home; labelKey: MessageKey }[] = [
+  { path: '/dashboard', icon: Home, labelKey: 'nav.dashboard' },
+  { path: '/chat', icon: MessageCircle, labelKey: 'nav.chat' },
+  { path: '/shopping', icon: ShoppingCart, labelKey: 'nav.shopping' },
+  { path: '/cooking', icon: ChefHat, labelKey: 'nav.cooking' },
 ];
 
 export function BottomNavigation() {
   const router = useRouter();
   const pathname = usePathname();
+  const { t } = useTranslation();
 
   return (
-    <div className="fixed bottom-0 left-0 z-50 w-full h-16 bg-background border-t" data-testid="mobile-navigation">
+    <nav
+      aria-label={t('nav.main')}
+      className="fixed bottom-0 left-0 z-50 w-full h-16 bg-background border-t"
+      data-testid="mobile-navigation"
+    >
       <div className="grid h-full max-w-lg grid-cols-4 mx-auto font-medium">
-        {navigationItems.map(({ path, icon: Icon, label }) => {
+        {navigationItems.map(({ path, icon: Icon, labelKey }) => {
           const isActive = pathname === path;
 
           return (
             <button
               key={path}
               onClick={() => router.push(path)}
+              aria-current={isActive ? 'page' : undefined}
               className={cn(
                 "inline-flex flex-col items-center justify-center px-5 hover:bg-gray-50 dark:hover:bg-gray-800 group",
                 isActive ? "text-primary" : "text-gray-500 dark:text-gray-400"
               )}
             >
-              <Icon className="w-5 h-5 mb-2" />
-              <span className="text-sm">{label}</span>
+              <Icon className="w-5 h-5 mb-2" aria-hidden />
+              <span className="text-sm">{t(labelKey)}</span>
             </button>
           );
         })}
       </div>
-    </div>
+    </nav>
   );
 }
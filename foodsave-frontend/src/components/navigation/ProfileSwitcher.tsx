@@ -0,0 +1,46 @@
+'use client';
+
+import { UserRound } from 'lucide-react';
+import { useTranslation } from '@/hooks/useTranslation';
+import { activateProfile, useProfiles } from '@/hooks/useProfiles';
+
+interface ProfileSwitcherProps {
+  compact?: boolean; // collapsed sidebar: only the initial
+}
+
+export function ProfileSwitcher({ compact = false }: ProfileSwitcherProps) {
+  const { t } = useTranslation();
+  const { profiles, active } = useProfiles();
+
+  // Jeden domownik - nie ma między kim przełączać
+  if (!active || profiles.length < 2) return null;
+
+  if (compact) {
+    return (
+      <div
+        title={active.name}
+        className="mx-auto mt-2 h-8 w-8 rounded-full bg-primary/20 text-primary flex items-center justify-center font-semibold"
+      >
+        {active.name.charAt(0).toUpperCase()}
+      </div>
+    );
+  }
+
+  return (
+    <label className="flex items-center gap-2 px-4 pt-3 text-sm">
+      <UserRound className="h-4 w-4 text-muted-foreground" aria-hidden />
+      <span className="sr-only">{t('profiles.switch')}</span>
+      <select
+        value={active.id}
+        onChange={(e) => activateProfile(e.target.value)}
+        className="flex-1 min-w-0 rounded-md border bg-background px-2 py-1"
+      >
+        {profiles.map((profile) => (
+          <option key={profile.id} value={profile.id}>
+            {profile.name}
+          </option>
+        ))}
+      </select>
+    </label>
+  );
+}
@@ -1,21 +1,38 @@
 'use client';
-import { useState } from 'react';
+import { useEffect, useState } from 'react';
 import { useRouter, usePathname } from 'next/navigation';
 import { cn } from '@/lib/utils';
-import { Home, MessageCircle, ShoppingCart, ChefHat, Menu, FileText } from 'lucide-react';
+import { useTranslation } from '@/hooks/useTranslation';
+import { MessageKey } from '@/lib/i18n';
+import { isSidebarCollapsed, rememberSidebarCollapsed } from '@/lib/startup';
+import { ProfileSwitcher } from './ProfileSwitcher';
+import { Home, MessageCircle, ShoppingCart, ChefHat, Menu, FileText, BarChart3, Bell, Settings } from 'lucide-react';
 
-const navigationItems = [
-  { path: '/dashboard', icon: Home, label: 'Dashboard' },
-  { path: '/chat', icon: MessageCircle, label: 'Czat' },
-  { path: '/shopping', icon: ShoppingCart, label: 'Zakupy' },
-  { path: '/cooking', icon: ChefHat, label: 'Gotowanie' },
-  { path: '/rag', icon: FileText, label: 'RAG' },
+const navigationItems: { path: string; icon: typeof Home; labelKey: MessageKey }[] = [
+  { path: '/dashboard', icon: Home, labelKey: 'nav.dashboard' },
+  { path: '/chat', icon: MessageCircle, labelKey: 'nav.chat' },
+  { path: '/shopping', icon: ShoppingCart, labelKey: 'nav.shopping' },
+  { path: '/cooking', icon: ChefHat, labelKey: 'nav.cooking' },
+  { path: '/analytics', icon: BarChart3, labelKey: 'nav.analytics' },
+  { path: '/reminders', icon: Bell, labelKey: 'nav.reminders' },
+  { path: '/rag', icon: FileText, labelKey: 'nav.rag' },
+  { path: '/settings', icon: Settings, labelKey: 'nav.settings' },
 ];
 
 export function SidebarNavigation() {
   const [isCollapsed, setIsCollapsed] = useState(false);
   const router = useRouter();
   const pathname = usePathname();
+  const { t } = useTranslation();
+
+  useEffect(() => {
+    setIsCollapsed(isSidebarCollapsed());
+  }, []);
+
+  const toggleCollapsed = () => {
+    rememberSidebarCollapsed(!isCollapsed);
+    setIsCollapsed(!isCollapsed);
+  };
 
   return (
     <div className={cn("relative h-screen bg-card border-r transition-all duration-300", isCollapsed ? "w-20" : "w-64")} data-testid="sidebar-navigation">
@@ -23,17 +40,26 @@ export function SidebarNavigation() {
         {!isCollapsed && (
           <h1 className="text-xl font-bold text-foreground">FoodSave AI</h1>
         )}
-        <button onClick={() => setIsCollapsed(!isCollapsed)} className="p-2 rounded-full hover:bg-accent">
-          <Menu className="text-foreground" />
+        <button
+          onClick={toggleCollapsed}
+          aria-label={t('nav.toggleSidebar')}
+          aria-expanded={!isCollapsed}
+          className="p-2 rounded-full hover:bg-accent"
+        >
+          <Menu className="text-foreground" aria-hidden />
         </button>
       </div>
-      <nav className="mt-4 px-2">
-        {navigationItems.map(({ path, icon: Icon, label }) => {
+      <ProfileSwitcher compact={isCollapsed} />
+      <nav className="mt-4 px-2" aria-label={t('nav.main')}>
+        {navigationItems.map(({ path, icon: Icon, labelKey }) => {
           const isActive = pathname === path;
           return (
             <a
               key={path}
               href={path}
+              aria-current={isActive ? 'page' : undefined}
+              aria-label={isCollapsed ? t(labelKey) : undefined}
+              title={isCollapsed ? t(labelKey) : undefined}
               onClick={(e) => {
                 e.preventDefault();
                 router.push(path);
@@ -49,9 +75,9 @@ export function SidebarNavigation() {
                 "flex items-center",
                 isActive && !isCollapsed ? "bg-primary/20 rounded-full" : ""
               )}>
-                <Icon className={cn("h-6 w-6", !isCollapsed && "mr-3 ml-1")} />
+                <Icon className={cn("h-6 w-6", !isCollapsed && "mr-3 ml-1")} aria-hidden />
               </div>
-              {!isCollapsed && <span className="ml-1">{label}</span>}
+              {!isCollapsed && <span className="ml-1">{t(labelKey)}</span>}
             </a>
           );
         })}
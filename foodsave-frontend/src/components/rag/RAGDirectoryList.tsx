@@ -1,4 +1,5 @@
 import React, { useEffect, useState } from 'react';
+import { useTranslation } from '@/hooks/useTranslation';
 
 interface Directory {
   path: string;
@@ -29,6 +30,7 @@ export default function RAGDirectoryList({
   onDirectoryDeleted,
   onDirectoryRenamed
 }: RAGDirectoryListProps) {
+  const { t } = useTranslation();
   const [directories, setDirectories] = useState<Directory[]>([]);
   const [loading, setLoading] = useState(true);
   const [error, setError] = useState<string | null>(null);
@@ -56,11 +58,11 @@ export default function RAGDirectoryList({
     setError(null);
     try {
       const response = await fetch('/api/v2/rag/directories');
-      if (!response.ok) throw new Error('Failed to fetch directories');
+      if (!response.ok) throw new Error(t('rag.dirs.loadFailed'));
       const data = await response.json();
       setDirectories(data.directories || []);
     } catch (err: any) {
-      setError(err.message || 'Unknown error');
+      setError(err.message || t('rag.unknownError'));
     } finally {
       setLoading(false);
     }
@@ -80,7 +82,7 @@ export default function RAGDirectoryList({
 
   const handleCreateDirectory = async () => {
     if (!newDirectoryName.trim()) {
-      setCreateError('Directory name cannot be empty');
+      setCreateError(t('rag.dirs.nameEmpty'));
       return;
     }
 
@@ -98,7 +100,7 @@ export default function RAGDirectoryList({
 
       if (!response.ok) {
         const errorData = await response.json();
-        throw new Error(errorData.message || 'Failed to create directory');
+        throw new Error(errorData.message || t('rag.dirs.createFailed'));
       }
 
       await fetchDirectories();
@@ -106,7 +108,7 @@ export default function RAGDirectoryList({
       setNewDirectoryName('');
       onDirectoryCreated?.();
     } catch (err: any) {
-      setCreateError(err.message || 'Unknown error');
+      setCreateError(err.message || t('rag.unknownError'));
     } finally {
       setCreating(false);
     }
@@ -125,7 +127,7 @@ export default function RAGDirectoryList({
 
       if (!response.ok) {
         const errorData = await response.json();
-        throw new Error(errorData.message || 'Failed to delete directory');
+        throw new Error(errorData.message || t('rag.dirs.deleteFailed'));
       }
 
       await fetchDirectories();
@@ -133,7 +135,7 @@ export default function RAGDirectoryList({
       setDeletingDirectory(null);
       onDirectoryDeleted?.();
     } catch (err: any) {
-      setDeleteError(err.message || 'Unknown error');
+      setDeleteError(err.message || t('rag.unknownError'));
     } finally {
       setDeleting(false);
     }
@@ -141,7 +143,7 @@ export default function RAGDirectoryList({
 
   const handleRenameDirectory = async () => {
     if (!renamingDirectory || !newDirectoryPath.trim()) {
-      setRenameError('Please enter a new directory name');
+      setRenameError(t('rag.dirs.newNameEmpty'));
       return;
     }
 
@@ -159,7 +161,7 @@ export default function RAGDirectoryList({
 
       if (!response.ok) {
         const errorData = await response.json();
-        throw new Error(errorData.message || 'Failed to rename directory');
+        throw new Error(errorData.message || t('rag.dirs.renameFailed'));
       }
 
       await fetchDirectories();
@@ -168,7 +170,7 @@ export default function RAGDirectoryList({
       setNewDirectoryPath('');
       onDirectoryRenamed?.();
     } catch (err: any) {
-      setRenameError(err.message || 'Unknown error');
+      setRenameError(err.message || t('rag.unknownError'));
     } finally {
       setRenaming(false);
     }
@@ -184,57 +186,57 @@ export default function RAGDirectoryList({
       const response = await fetch(`/api/v2/rag/directories/${encodeURIComponent(directory)}/stats`);
       if (!response.ok) {
         const errorData = await response.json();
-        throw new Error(errorData.message || 'Failed to fetch directory stats');
+        throw new Error(errorData.message || t('rag.dirs.statsFailed'));
       }
 
       const data = await response.json();
       setDirectoryStats(data.stats);
     } catch (err: any) {
-      setStatsError(err.message || 'Unknown error');
+      setStatsError(err.message || t('rag.unknownError'));
     } finally {
       setLoadingStats(false);
     }
   };
 
-  if (loading) return <div className="py-4 text-gray-500">Loading directories...</div>;
-  if (error) return <div className="py-4 text-red-500">Error: {error}</div>;
+  if (loading) return <div className="py-4 text-gray-500">{t('rag.dirs.loading')}</div>;
+  if (error) return <div className="py-4 text-red-500">{t('rag.dirs.error', { error })}</div>;
 
   return (
     <div className="bg-white rounded-lg shadow-md p-6 mt-6">
       <div className="flex justify-between items-center mb-4">
-        <h2 className="text-xl font-semibold">RAG Directories</h2>
+        <h2 className="text-xl font-semibold">{t('rag.dirs.title')}</h2>
         <div className="flex items-center space-x-2">
           {selectedDirectory && (
             <button
               onClick={handleBackToAll}
               className="text-sm text-blue-600 hover:text-blue-800 underline"
             >
-              ← Back to all directories
+              {t('rag.dirs.back')}
             </button>
           )}
           <button
             onClick={() => setShowCreateModal(true)}
             className="px-3 py-1 bg-blue-600 text-white text-sm rounded hover:bg-blue-700 transition-colors"
           >
-            + Create Directory
+            {t('rag.dirs.create')}
           </button>
         </div>
       </div>
 
       {directories.length === 0 ? (
-        <div className="py-4 text-gray-500">No directories found.</div>
+        <div className="py-4 text-gray-500">{t('rag.dirs.empty')}</div>
       ) : selectedDirectory ? (
         <div className="py-2">
           <div className="flex justify-between items-center p-3 bg-blue-50 rounded-lg">
             <span className="font-mono text-blue-700">{selectedDirectory}</span>
             <div className="flex items-center space-x-2">
               <span className="text-sm text-blue-600">
-                {directories.find(d => d.path === selectedDirectory)?.document_count || 0} documents
+                {t('rag.documents.count', { count: directories.find(d => d.path === selectedDirectory)?.document_count || 0 })}
               </span>
               <button
                 onClick={() => handleShowStats(selectedDirectory)}
                 className="p-1 text-blue-600 hover:text-blue-800 hover:bg-blue-100 rounded"
-                title="View statistics"
+                title={t('rag.dirs.stats')}
               >
                 📊
               </button>
@@ -245,7 +247,7 @@ export default function RAGDirectoryList({
                   setShowRenameModal(true);
                 }}
                 className="p-1 text-blue-600 hover:text-blue-800 hover:bg-blue-100 rounded"
-                title="Rename directory"
+                title={t('rag.dirs.rename')}
               >
                 ✏️
               </button>
@@ -255,7 +257,7 @@ export default function RAGDirectoryList({
                   setShowDeleteModal(true);
                 }}
                 className="p-1 text-red-600 hover:text-red-800 hover:bg-red-100 rounded"
-                title="Delete directory"
+                title={t('rag.dirs.delete')}
               >
                 🗑️
               </button>
@@ -274,11 +276,11 @@ export default function RAGDirectoryList({
                   <span className="font-mono text-gray-700">{dir.path}</span>
                 </button>
                 <div className="flex items-center space-x-2">
-                  <span className="text-sm text-gray-500">{dir.document_count} documents</span>
+                  <span className="text-sm text-gray-500">{t('rag.documents.count', { count: dir.document_count })}</span>
                   <button
                     onClick={() => handleShowStats(dir.path)}
                     className="p-1 text-gray-600 hover:text-gray-800 hover:bg-gray-100 rounded"
-                    title="View statistics"
+                    title={t('rag.dirs.stats')}
                   >
                     📊
                   </button>
@@ -289,7 +291,7 @@ export default function RAGDirectoryList({
                       setShowRenameModal(true);
                     }}
                     className="p-1 text-gray-600 hover:text-gray-800 hover:bg-gray-100 rounded"
-                    title="Rename directory"
+                    title={t('rag.dirs.rename')}
                   >
                     ✏️
                   </button>
@@ -299,7 +301,7 @@ export default function RAGDirectoryList({
                       setShowDeleteModal(true);
                     }}
                     className="p-1 text-red-600 hover:text-red-800 hover:bg-red-100 rounded"
-                    title="Delete directory"
+                    title={t('rag.dirs.delete')}
                   >
                     🗑️
                   </button>
@@ -314,11 +316,11 @@ export default function RAGDirectoryList({
       {showCreateModal && (
         <div className="fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50">
           <div className="bg-white rounded-lg p-6 w-96 max-w-full mx-4">
-            <h3 className="text-lg font-semibold mb-4">Create New Directory</h3>
+            <h3 className="text-lg font-semibold mb-4">{t('rag.dirs.createTitle')}</h3>
 
             <div className="mb-4">
               <label htmlFor="directory-name" className="block text-sm font-medium text-gray-700 mb-2">
-                Directory Name
+                {t('rag.dirs.name')}
               </label>
               <input
                 id="directory-name"
@@ -326,7 +328,7 @@ export default function RAGDirectoryList({
                 value={newDirectoryName}
                 onChange={(e) => setNewDirectoryName(e.target.value)}
                 className="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
-                placeholder="e.g., recipes, guides, work"
+                placeholder={t('rag.dirs.namePlaceholder')}
                 onKeyPress={(e) => e.key === 'Enter' && handleCreateDirectory()}
               />
             </div>
@@ -347,14 +349,14 @@ export default function RAGDirectoryList({
                 className="px-4 py-2 text-gray-600 hover:text-gray-800"
                 disabled={creating}
               >
-                Cancel
+                {t('common.cancel')}
               </button>
               <button
                 onClick={handleCreateDirectory}
                 disabled={creating || !newDirectoryName.trim()}
                 className="px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed"
               >
-                {creating ? 'Creating...' : 'Create'}
+                {creating ? t('rag.dirs.creating') : t('rag.dirs.createSubmit')}
               </button>
             </div>
           </div>
@@ -365,14 +367,14 @@ export default function RAGDirectoryList({
       {showDeleteModal && deletingDirectory && (
         <div className="fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50">
           <div className="bg-white rounded-lg p-6 w-96 max-w-full mx-4">
-            <h3 className="text-lg font-semibold mb-4 text-red-600">Delete Directory</h3>
+            <h3 className="text-lg font-semibold mb-4 text-red-600">{t('rag.dirs.deleteTitle')}</h3>
 
             <div className="mb-4">
               <p className="text-sm text-gray-600 mb-2">
-                Are you sure you want to delete the directory <strong>{deletingDirectory}</strong>?
+                {t('rag.dirs.deleteConfirm')}<strong>{deletingDirectory}</strong>?
               </p>
               <p className="text-sm text-red-600">
-                All documents in this directory will be moved to the default directory.
+                {t('rag.dirs.deleteHint')}
               </p>
             </div>
 
@@ -392,14 +394,14 @@ export default function RAGDirectoryList({
                 className="px-4 py-2 text-gray-600 hover:text-gray-800"
                 disabled={deleting}
               >
-                Cancel
+                {t('common.cancel')}
               </button>
               <button
                 onClick={handleDeleteDirectory}
                 disabled={deleting}
                 className="px-4 py-2 bg-red-600 text-white rounded hover:bg-red-700 disabled:opacity-50 disabled:cursor-not-allowed"
               >
-                {deleting ? 'Deleting...' : 'Delete'}
+                {deleting ? t('rag.bulk.deleting') : t('common.delete')}
               </button>
             </div>
           </div>
@@ -410,15 +412,15 @@ export default function RAGDirectoryList({
       {showRenameModal && renamingDirectory && (
         <div className="fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50">
           <div className="bg-white rounded-lg p-6 w-96 max-w-full mx-4">
-            <h3 className="text-lg font-semibold mb-4">Rename Directory</h3>
+            <h3 className="text-lg font-semibold mb-4">{t('rag.dirs.renameTitle')}</h3>
 
             <div className="mb-4">
               <p className="text-sm text-gray-600 mb-2">
-                Renaming: <strong>{renamingDirectory}</strong>
+                {t('rag.dirs.renaming')}<strong>{renamingDirectory}</strong>
               </p>
 
               <label htmlFor="new-directory-name" className="block text-sm font-medium text-gray-700 mb-2">
-                New Directory Name
+                {t('rag.dirs.newName')}
               </label>
               <input
                 id="new-directory-name"
@@ -426,7 +428,7 @@ export default function RAGDirectoryList({
                 value={newDirectoryPath}
                 onChange={(e) => setNewDirectoryPath(e.target.value)}
                 className="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
-                placeholder="Enter new directory name"
+                placeholder={t('rag.dirs.newNamePlaceholder')}
                 onKeyPress={(e) => e.key === 'Enter' && handleRenameDirectory()}
               />
             </div>
@@ -448,14 +450,14 @@ export default function RAGDirectoryList({
                 className="px-4 py-2 text-gray-600 hover:text-gray-800"
                 disabled={renaming}
               >
-                Cancel
+                {t('common.cancel')}
               </button>
               <button
                 onClick={handleRenameDirectory}
                 disabled={renaming || !newDirectoryPath.trim()}
                 className="px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed"
               >
-                {renaming ? 'Renaming...' : 'Rename'}
+                {renaming ? t('rag.dirs.renameSubmitting') : t('rag.dirs.renameSubmit')}
               </button>
             </div>
           </div>
@@ -466,7 +468,7 @@ export default function RAGDirectoryList({
       {showStatsModal && statsDirectory && (
         <div className="fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50">
           <div className="bg-white rounded-lg p-6 w-96 max-w-full mx-4">
-            <h3 className="text-lg font-semibold mb-4">Directory Statistics</h3>
+            <h3 className="text-lg font-semibold mb-4">{t('rag.dirs.statsTitle')}</h3>
 
             <div className="mb-4">
               <p className="text-sm text-gray-600 mb-4">
@@ -476,7 +478,7 @@ export default function RAGDirectoryList({
               {loadingStats ? (
                 <div className="text-center py-4">
                   <div className="animate-spin rounded-full h-6 w-6 border-b-2 border-blue-600 mx-auto"></div>
-                  <p className="text-sm text-gray-500 mt-2">Loading statistics...</p>
+                  <p className="text-sm text-gray-500 mt-2">{t('rag.dirs.statsLoading')}</p>
                 </div>
               ) : statsError ? (
                 <div className="p-2 bg-red-50 text-red-600 text-sm rounded">
@@ -487,30 +489,30 @@ export default function RAGDirectoryList({
                   <div className="grid grid-cols-2 gap-4">
                     <div className="p-3 bg-blue-50 rounded">
                       <div className="text-2xl font-bold text-blue-600">{directoryStats.total_documents}</div>
-                      <div className="text-xs text-blue-600">Documents</div>
+                      <div className="text-xs text-blue-600">{t('rag.dirs.documents')}</div>
                     </div>
                     <div className="p-3 bg-green-50 rounded">
                       <div className="text-2xl font-bold text-green-600">{directoryStats.total_chunks}</div>
-                      <div className="text-xs text-green-600">Chunks</div>
+                      <div className="text-xs text-green-600">{t('rag.dirs.chunks')}</div>
                     </div>
                   </div>
 
                   <div className="space-y-2 text-sm">
                     <div className="flex justify-between">
-                      <span className="text-gray-600">Total Tags:</span>
+                      <span className="text-gray-600">{t('rag.dirs.totalTags')}</span>
                       <span className="font-medium">{directoryStats.total_tags}</span>
                     </div>
                     <div className="flex justify-between">
-                      <span className="text-gray-600">Recent Activity:</span>
-                      <span className="font-medium">{directoryStats.recent_activity} docs</span>
+                      <span className="text-gray-600">{t('rag.dirs.recentActivity')}</span>
+                      <span className="font-medium">{t('rag.documents.count', { count: directoryStats.recent_activity })}</span>
                     </div>
                     <div className="flex justify-between">
-                      <span className="text-gray-600">Avg Chunks/Doc:</span>
+                      <span className="text-gray-600">{t('rag.dirs.avgChunks')}</span>
                       <span className="font-medium">{directoryStats.average_chunks_per_document}</span>
                     </div>
                     {directoryStats.file_types.length > 0 && (
                       <div>
-                        <span className="text-gray-600">File Types:</span>
+                        <span className="text-gray-600">{t('rag.dirs.fileTypes')}</span>
                         <div className="flex flex-wrap gap-1 mt-1">
                           {directoryStats.file_types.map((type) => (
                             <span key={type} className="px-2 py-1 bg-gray-100 text-gray-700 text-xs rounded">
@@ -535,7 +537,7 @@ export default function RAGDirectoryList({
                 }}
                 className="px-4 py-2 text-gray-600 hover:text-gray-800"
               >
-                Close
+                {t('common.close')}
               </button>
             </div>
           </div>
@@ -5,6 +5,7 @@ import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
 import { Button } from '@/components/ui/Button';
 import { Input } from '@/components/ui/Input';
 import { ApiService } from '@/services/ApiService';
+import { useTranslation } from '@/hooks/useTranslation';
 
 interface RAGStats {
   vector_store: {
@@ -37,6 +38,7 @@ interface SyncResult {
 }
 
 export function RAGManager() {
+  const { t } = useTranslation();
   const [isLoading, setIsLoading] = useState(false);
   const [stats, setStats] = useState<RAGStats | null>(null);
   const [searchQuery, setSearchQuery] = useState('');
@@ -119,14 +121,14 @@ export function RAGManager() {
       // Reload stats
       await loadStats();
 
-      alert('Document uploaded successfully!');
+      alert(t('rag.manager.uploaded'));
     } catch (error) {
       console.error('Error uploading document:', error);
-      alert('Error uploading document');
+      alert(t('rag.manager.uploadFailed'));
     } finally {
       setIsLoading(false);
     }
-  }, [selectedFile, uploadCategory, uploadTags, loadStats]);
+  }, [selectedFile, uploadCategory, uploadTags, loadStats, t]);
 
   const handleFileChange = (e: ChangeEvent<HTMLInputElement>) => {
     if (e.target.files && e.target.files[0]) {
@@ -140,9 +142,9 @@ export function RAGManager() {
       <Card>
         <CardHeader>
           <CardTitle className="flex justify-between items-center">
-            RAG System Statistics
+            {t('rag.manager.statsTitle')}
             <Button onClick={loadStats} disabled={isLoading}>
-              {isLoading ? 'Loading...' : 'Refresh'}
+              {isLoading ? t('rag.manager.loading') : t('rag.manager.refresh')}
             </Button>
           </CardTitle>
         </CardHeader>
@@ -150,20 +152,20 @@ export function RAGManager() {
           {stats ? (
             <div className="grid grid-cols-2 gap-4">
               <div>
-                <h4 className="font-semibold mb-2">Vector Store</h4>
-                <p>Total Chunks: {stats.vector_store.total_chunks}</p>
-                <p>Total Documents: {stats.vector_store.total_documents}</p>
+                <h4 className="font-semibold mb-2">{t('rag.manager.vectorStore')}</h4>
+                <p>{t('rag.manager.totalChunks', { count: stats.vector_store.total_chunks })}</p>
+                <p>{t('rag.manager.totalDocuments', { count: stats.vector_store.total_documents })}</p>
               </div>
               <div>
-                <h4 className="font-semibold mb-2">Processor</h4>
-                <p>Chunk Size: {stats.processor.chunk_size}</p>
-                <p>Chunk Overlap: {stats.processor.chunk_overlap}</p>
-                <p>Local Embeddings: {stats.processor.use_local_embeddings ? 'Yes' : 'No'}</p>
-                <p>Pinecone: {stats.processor.use_pinecone ? 'Yes' : 'No'}</p>
+                <h4 className="font-semibold mb-2">{t('rag.manager.processor')}</h4>
+                <p>{t('rag.manager.chunkSize', { value: stats.processor.chunk_size })}</p>
+                <p>{t('rag.manager.chunkOverlap', { value: stats.processor.chunk_overlap })}</p>
+                <p>{t('rag.manager.localEmbeddings', { value: t(stats.processor.use_local_embeddings ? 'rag.manager.yes' : 'rag.manager.no') })}</p>
+                <p>{t('rag.manager.pinecone', { value: t(stats.processor.use_pinecone ? 'rag.manager.yes' : 'rag.manager.no') })}</p>
               </div>
             </div>
           ) : (
-            <p>Click &quot;Refresh&quot; to load statistics</p>
+            <p>{t('rag.manager.statsHint')}</p>
           )}
         </CardContent>
       </Card>
@@ -171,11 +173,11 @@ export function RAGManager() {
       {/* Document Upload Card */}
       <Card>
         <CardHeader>
-          <CardTitle>Upload Document</CardTitle>
+          <CardTitle>{t('rag.manager.uploadTitle')}</CardTitle>
         </CardHeader>
         <CardContent className="space-y-4">
           <div>
-            <label htmlFor="file-input" className="block text-sm font-medium mb-2">Select File</label>
+            <label htmlFor="file-input" className="block text-sm font-medium mb-2">{t('rag.manager.selectFile')}</label>
             <input
               id="file-input"
               type="file"
@@ -187,21 +189,21 @@ export function RAGManager() {
 
           <div className="grid grid-cols-2 gap-4">
             <div>
-              <label htmlFor="category-input" className="block text-sm font-medium mb-2">Category (optional)</label>
+              <label htmlFor="category-input" className="block text-sm font-medium mb-2">{t('rag.manager.category')}</label>
               <Input
                 id="category-input"
                 value={uploadCategory}
                 onChange={(e: ChangeEvent<HTMLInputElement>) => setUploadCategory(e.target.value)}
-                placeholder="e.g., recipes, guides"
+                placeholder={t('rag.manager.categoryPlaceholder')}
               />
             </div>
             <div>
-              <label htmlFor="tags-input" className="block text-sm font-medium mb-2">Tags (optional)</label>
+              <label htmlFor="tags-input" className="block text-sm font-medium mb-2">{t('rag.manager.tags')}</label>
               <Input
                 id="tags-input"
                 value={uploadTags}
                 onChange={(e: ChangeEvent<HTMLInputElement>) => setUploadTags(e.target.value)}
-                placeholder="e.g., cooking, healthy, quick"
+                placeholder={t('rag.manager.tagsPlaceholder')}
               />
             </div>
           </div>
@@ -211,7 +213,7 @@ export function RAGManager() {
             disabled={!selectedFile || isLoading}
             className="w-full"
           >
-            {isLoading ? 'Uploading...' : 'Upload Document'}
+            {isLoading ? t('rag.manager.uploading') : t('rag.manager.uploadTitle')}
           </Button>
         </CardContent>
       </Card>
@@ -219,7 +221,7 @@ export function RAGManager() {
       {/* Database Sync Card */}
       <Card>
         <CardHeader>
-          <CardTitle>Database Synchronization</CardTitle>
+          <CardTitle>{t('rag.manager.syncTitle')}</CardTitle>
         </CardHeader>
         <CardContent>
           <div className="grid grid-cols-2 gap-4 mb-4">
@@ -227,41 +229,41 @@ export function RAGManager() {
               onClick={() => syncDatabase('receipts')}
               disabled={isLoading}
             >
-              Sync Receipts
+              {t('rag.manager.syncReceipts')}
             </Button>
             <Button
               onClick={() => syncDatabase('pantry')}
               disabled={isLoading}
             >
-              Sync Pantry
+              {t('rag.manager.syncPantry')}
             </Button>
             <Button
               onClick={() => syncDatabase('conversations')}
               disabled={isLoading}
             >
-              Sync Conversations
+              {t('rag.manager.syncConversations')}
             </Button>
             <Button
               onClick={() => syncDatabase('all')}
               disabled={isLoading}
               className="bg-blue-600 hover:bg-blue-700"
             >
-              Sync All
+              {t('rag.manager.syncAll')}
             </Button>
           </div>
 
           {syncResult && (
             <div className="mt-4 p-4 bg-gray-50 rounded">
-              <h4 className="font-semibold mb-2">Sync Results:</h4>
-              <p>Total Chunks: {syncResult.total_chunks}</p>
+              <h4 className="font-semibold mb-2">{t('rag.manager.syncResults')}</h4>
+              <p>{t('rag.manager.totalChunks', { count: syncResult.total_chunks })}</p>
               {syncResult.results.receipts && (
-                <p>Receipts: {syncResult.results.receipts.processed_trips} trips, {syncResult.results.receipts.total_chunks} chunks</p>
+                <p>{t('rag.manager.syncReceiptsResult', { trips: syncResult.results.receipts.processed_trips, chunks: syncResult.results.receipts.total_chunks })}</p>
               )}
               {syncResult.results.pantry && (
-                <p>Pantry: {syncResult.results.pantry.processed_products} products, {syncResult.results.pantry.total_chunks} chunks</p>
+                <p>{t('rag.manager.syncPantryResult', { products: syncResult.results.pantry.processed_products, chunks: syncResult.results.pantry.total_chunks })}</p>
               )}
               {syncResult.results.conversations && (
-                <p>Conversations: {syncResult.results.conversations.processed_conversations} conversations, {syncResult.results.conversations.total_chunks} chunks</p>
+                <p>{t('rag.manager.syncConversationsResult', { conversations: syncResult.results.conversations.processed_conversations, chunks: syncResult.results.conversations.total_chunks })}</p>
               )}
             </div>
           )}
@@ -271,40 +273,40 @@ export function RAGManager() {
       {/* Search Card */}
       <Card>
         <CardHeader>
-          <CardTitle>Search Documents</CardTitle>
+          <CardTitle>{t('rag.manager.searchTitle')}</CardTitle>
         </CardHeader>
         <CardContent>
           <div className="flex gap-2 mb-4">
             <Input
               value={searchQuery}
               onChange={(e: ChangeEvent<HTMLInputElement>) => setSearchQuery(e.target.value)}
-              placeholder="Enter search query..."
+              placeholder={t('rag.manager.searchPlaceholder')}
               onKeyPress={(e: KeyboardEvent<HTMLInputElement>) => e.key === 'Enter' && searchDocuments()}
             />
             <Button onClick={searchDocuments} disabled={!searchQuery.trim() || isLoading}>
-              {isLoading ? 'Searching...' : 'Search'}
+              {isLoading ? t('rag.manager.searching') : t('rag.manager.search')}
             </Button>
           </div>
 
           {searchResults.length > 0 && (
             <div className="space-y-4">
-              <h4 className="font-semibold">Search Results ({searchResults.length})</h4>
+              <h4 className="font-semibold">{t('rag.manager.results', { count: searchResults.length })}</h4>
               {searchResults.map((result, index) => (
                 <div key={index} className="p-4 border rounded">
                   <div className="flex justify-between items-start mb-2">
                     <span className="text-sm text-gray-600">
-                      Similarity: {(result.similarity * 100).toFixed(1)}%
+                      {t('rag.manager.similarity', { percent: (result.similarity * 100).toFixed(1) })}
                     </span>
                     <span className="text-sm text-gray-600">
-                      Source: {result.source_id}
+                      {t('rag.manager.source', { source: result.source_id })}
                     </span>
                   </div>
                   <p className="text-sm">{result.text}</p>
                   {result.metadata && (
                     <div className="mt-2 text-xs text-gray-500">
-                      <p>Type: {result.metadata.type}</p>
-                      {result.metadata.category && <p>Category: {result.metadata.category}</p>}
-                      {result.metadata.date && <p>Date: {result.metadata.date}</p>}
+                      <p>{t('rag.manager.type', { type: result.metadata.type })}</p>
+                      {result.metadata.category && <p>{t('rag.manager.categoryValue', { category: result.metadata.category })}</p>}
+                      {result.metadata.date && <p>{t('rag.manager.date', { date: result.metadata.date })}</p>}
                     </div>
                   )}
                 </div>
@@ -4,6 +4,7 @@ import React, { useState, useCallback } from 'react';
 import { Upload, File, Trash2, Search, Plus, AlertCircle, CheckCircle } from 'lucide-react';
 import { useDropzone } from 'react-dropzone';
 import RAGDirectoryList from './RAGDirectoryList';
+import { useTranslation } from '@/hooks/useTranslation';
 
 interface RAGDocument {
   document_id: string;
@@ -22,6 +23,7 @@ interface RAGUploadProps {
 }
 
 export default function RAGUpload({ onUpload }: RAGUploadProps) {
+  const { t, locale } = useTranslation();
   const [documents, setDocuments] = useState<RAGDocument[]>([]);
   const [uploading, setUploading] = useState(false);
   const [query, setQuery] = useState('');
@@ -126,15 +128,15 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
 
       if (response.ok) {
         const result = await response.json();
-        setQueryResult(result.answer || 'No answer found');
+        setQueryResult(result.answer || t('rag.query.noAnswer'));
         setSearchResults(result.sources || []);
         setShowSearchResults(true);
       } else {
-        setQueryResult('Error querying RAG system');
+        setQueryResult(t('rag.query.error'));
         setSearchResults([]);
       }
     } catch (error) {
-      setQueryResult('Error querying RAG system');
+      setQueryResult(t('rag.query.error'));
       setSearchResults([]);
     } finally {
       setQuerying(false);
@@ -164,7 +166,7 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
 
   const executeMove = async () => {
     if (!movingDocument || !targetDirectory.trim()) {
-      setMoveError('Please select a target directory');
+      setMoveError(t('rag.move.noTarget'));
       return;
     }
 
@@ -182,7 +184,7 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
 
       if (!response.ok) {
         const errorData = await response.json();
-        throw new Error(errorData.message || 'Failed to move document');
+        throw new Error(errorData.message || t('rag.move.failed'));
       }
 
       // Update the document in the local state
@@ -196,7 +198,7 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
       setMovingDocument(null);
       setTargetDirectory('');
     } catch (err: any) {
-      setMoveError(err.message || 'Unknown error');
+      setMoveError(err.message || t('rag.unknownError'));
     } finally {
       setMoving(false);
     }
@@ -224,7 +226,7 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
 
   const executeBulkMove = async () => {
     if (selectedDocuments.size === 0 || !bulkTargetDirectory.trim()) {
-      setBulkError('Please select documents and enter a target directory');
+      setBulkError(t('rag.bulk.noTarget'));
       return;
     }
 
@@ -245,7 +247,7 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
 
       if (!response.ok) {
         const errorData = await response.json();
-        throw new Error(errorData.message || 'Failed to bulk move documents');
+        throw new Error(errorData.message || t('rag.bulk.moveFailed'));
       }
 
       // Refresh documents list
@@ -254,7 +256,7 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
       setShowBulkMoveModal(false);
       setBulkTargetDirectory('');
     } catch (err: any) {
-      setBulkError(err.message || 'Unknown error');
+      setBulkError(err.message || t('rag.unknownError'));
     } finally {
       setBulkOperating(false);
     }
@@ -262,7 +264,7 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
 
   const executeBulkDelete = async () => {
     if (selectedDocuments.size === 0) {
-      setBulkError('Please select documents to delete');
+      setBulkError(t('rag.bulk.noSelection'));
       return;
     }
 
@@ -282,7 +284,7 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
 
       if (!response.ok) {
         const errorData = await response.json();
-        throw new Error(errorData.message || 'Failed to bulk delete documents');
+        throw new Error(errorData.message || t('rag.bulk.deleteFailed'));
       }
 
       // Refresh documents list
@@ -290,7 +292,7 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
       setSelectedDocuments(new Set());
       setShowBulkDeleteModal(false);
     } catch (err: any) {
-      setBulkError(err.message || 'Unknown error');
+      setBulkError(err.message || t('rag.unknownError'));
     } finally {
       setBulkOperating(false);
     }
@@ -330,11 +332,11 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
         onDirectoryRenamed={loadDocuments}
       />
       <div className="text-center">
-        <h1 className="text-3xl font-bold text-gray-900 mb-2">RAG Knowledge Base</h1>
+        <h1 className="text-3xl font-bold text-gray-900 mb-2">{t('rag.title')}</h1>
         <p className="text-gray-600">
           {selectedDirectory
-            ? `Documents in: ${selectedDirectory}`
-            : 'Upload documents to enhance AI responses with your knowledge'
+            ? t('rag.subtitleDirectory', { directory: selectedDirectory })
+            : t('rag.subtitle')
           }
         </p>
       </div>
@@ -343,7 +345,7 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
       <div className="bg-white rounded-lg shadow-md p-6">
         <h2 className="text-xl font-semibold mb-4 flex items-center">
           <Upload className="w-5 h-5 mr-2" />
-          Upload Documents
+          {t('rag.upload.title')}
         </h2>
 
         <div className="space-y-4">
@@ -358,14 +360,14 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
             <input {...getInputProps()} />
             <Upload className="w-12 h-12 mx-auto text-gray-400 mb-4" />
             {isDragActive ? (
-              <p className="text-blue-600">Drop the files here...</p>
+              <p className="text-blue-600">{t('rag.upload.drop')}</p>
             ) : (
               <div>
                 <p className="text-gray-600 mb-2">
-                  Drag & drop files here, or click to select
+                  {t('rag.upload.prompt')}
                 </p>
                 <p className="text-sm text-gray-500">
-                  Supported: PDF, TXT, DOCX, MD, RTF
+                  {t('rag.upload.supported')}
                 </p>
               </div>
             )}
@@ -374,7 +376,7 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
           <div className="grid grid-cols-1 md:grid-cols-2 gap-4">
             <div>
               <label htmlFor="description-input" className="block text-sm font-medium text-gray-700 mb-1">
-                Description (optional)
+                {t('rag.upload.description')}
               </label>
               <input
                 id="description-input"
@@ -382,12 +384,12 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
                 value={description}
                 onChange={(e) => setDescription(e.target.value)}
                 className="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
-                placeholder="Brief description of the document"
+                placeholder={t('rag.upload.descriptionPlaceholder')}
               />
             </div>
             <div>
               <label htmlFor="tags-input" className="block text-sm font-medium text-gray-700 mb-1">
-                Tags (comma-separated)
+                {t('rag.upload.tags')}
               </label>
               <input
                 id="tags-input"
@@ -395,7 +397,7 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
                 value={tags}
                 onChange={(e) => setTags(e.target.value)}
                 className="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
-                placeholder="tag1, tag2, tag3"
+                placeholder={t('rag.upload.tagsPlaceholder')}
               />
             </div>
           </div>
@@ -403,7 +405,7 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
           {uploading && (
             <div className="flex items-center justify-center text-blue-600">
               <div className="animate-spin rounded-full h-6 w-6 border-b-2 border-blue-600 mr-2"></div>
-              Uploading documents...
+              {t('rag.upload.uploading')}
             </div>
           )}
         </div>
@@ -414,11 +416,11 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
         <div className="flex justify-between items-center mb-4">
           <h2 className="text-xl font-semibold flex items-center">
             <Search className="w-5 h-5 mr-2" />
-            Query Knowledge Base
+            {t('rag.query.title')}
           </h2>
           {selectedDirectory && (
             <div className="text-sm text-blue-600 bg-blue-50 px-3 py-1 rounded-full">
-              📁 Searching in: {selectedDirectory}
+              📁 {t('rag.query.searchingIn', { directory: selectedDirectory })}
             </div>
           )}
         </div>
@@ -431,8 +433,8 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
             className="flex-1 px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
             placeholder={
               selectedDirectory
-                ? `Ask a question about documents in ${selectedDirectory}...`
-                : "Ask a question about your documents..."
+                ? t('rag.query.placeholderDirectory', { directory: selectedDirectory })
+                : t('rag.query.placeholder')
             }
             onKeyPress={(e) => e.key === 'Enter' && handleQuery()}
           />
@@ -446,26 +448,26 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
             ) : (
               <Search className="w-4 h-4 mr-2" />
             )}
-            Query
+            {t('rag.query.submit')}
           </button>
         </div>
 
         {queryResult && (
           <div className="mt-4 space-y-4">
             <div className="p-4 bg-gray-50 rounded-md">
-              <h3 className="font-medium text-gray-900 mb-2">Answer:</h3>
+              <h3 className="font-medium text-gray-900 mb-2">{t('rag.query.answer')}</h3>
               <p className="text-gray-700">{queryResult}</p>
             </div>
 
             {showSearchResults && searchResults.length > 0 && (
               <div className="p-4 bg-blue-50 rounded-md">
-                <h3 className="font-medium text-gray-900 mb-3">Sources ({searchResults.length}):</h3>
+                <h3 className="font-medium text-gray-900 mb-3">{t('rag.query.sources', { count: searchResults.length })}</h3>
                 <div className="space-y-3">
                   {searchResults.map((source, index) => (
                     <div key={index} className="p-3 bg-white rounded border">
                       <div className="flex items-center justify-between mb-2">
                         <span className="font-medium text-sm text-gray-900">
-                          {source.filename || 'Unknown document'}
+                          {source.filename || t('rag.query.unknownDocument')}
                         </span>
                         {source.metadata?.directory_path && (
                           <span className="text-xs text-blue-600 bg-blue-100 px-2 py-1 rounded">
@@ -474,11 +476,11 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
                         )}
                       </div>
                       <p className="text-sm text-gray-700 leading-relaxed">
-                        {source.content || source.text || 'No content available'}
+                        {source.content || source.text || t('rag.query.noContent')}
                       </p>
                       {source.score && (
                         <div className="mt-2 text-xs text-gray-500">
-                          Relevance: {Math.round(source.score * 100)}%
+                          {t('rag.query.relevance', { percent: Math.round(source.score * 100) })}
                         </div>
                       )}
                     </div>
@@ -495,7 +497,7 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
         <div className="flex justify-between items-center mb-4">
           <h2 className="text-xl font-semibold flex items-center">
             <File className="w-5 h-5 mr-2" />
-            Uploaded Documents ({filteredDocuments.length})
+            {t('rag.documents.title', { count: filteredDocuments.length })}
           </h2>
 
           {filteredDocuments.length > 0 && (
@@ -504,25 +506,25 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
                 onClick={handleSelectAll}
                 className="text-sm text-blue-600 hover:text-blue-800 underline"
               >
-                {selectedDocuments.size === filteredDocuments.length ? 'Deselect All' : 'Select All'}
+                {selectedDocuments.size === filteredDocuments.length ? t('rag.documents.deselectAll') : t('rag.documents.selectAll')}
               </button>
 
               {selectedDocuments.size > 0 && (
                 <>
                   <span className="text-sm text-gray-500">
-                    ({selectedDocuments.size} selected)
+                    {t('rag.documents.selected', { count: selectedDocuments.size })}
                   </span>
                   <button
                     onClick={() => setShowBulkMoveModal(true)}
                     className="px-3 py-1 bg-blue-600 text-white text-sm rounded hover:bg-blue-700 transition-colors"
                   >
-                    Move Selected
+                    {t('rag.documents.moveSelected')}
                   </button>
                   <button
                     onClick={() => setShowBulkDeleteModal(true)}
                     className="px-3 py-1 bg-red-600 text-white text-sm rounded hover:bg-red-700 transition-colors"
                   >
-                    Delete Selected
+                    {t('rag.documents.deleteSelected')}
                   </button>
                 </>
               )}
@@ -533,8 +535,8 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
         {filteredDocuments.length === 0 ? (
           <div className="text-center py-8 text-gray-500">
             <File className="w-12 h-12 mx-auto mb-4 text-gray-300" />
-            <p>No documents uploaded yet</p>
-            <p className="text-sm">Upload your first document to get started</p>
+            <p>{t('rag.documents.empty')}</p>
+            <p className="text-sm">{t('rag.documents.emptyHint')}</p>
           </div>
         ) : (
           <div className="space-y-3">
@@ -561,10 +563,10 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
                       <p className="text-sm text-gray-600">{doc.description}</p>
                     )}
                     <div className="flex items-center space-x-4 text-xs text-gray-500 mt-1">
-                      <span>{doc.chunks_count} chunks</span>
+                      <span>{t('rag.documents.chunks', { count: doc.chunks_count })}</span>
                       {doc.uploaded_at && (
                         <span>
-                          {new Date(doc.uploaded_at).toLocaleDateString()}
+                          {new Date(doc.uploaded_at).toLocaleDateString(locale)}
                         </span>
                       )}
                       {doc.metadata?.directory_path && (
@@ -598,7 +600,7 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
                   <button
                     onClick={() => handleMoveDocument(doc)}
                     className="p-1 text-blue-600 hover:text-blue-800 hover:bg-blue-50 rounded"
-                    title="Move document"
+                    title={t('rag.documents.move')}
                   >
                     📁
                   </button>
@@ -606,7 +608,7 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
                   <button
                     onClick={() => handleDeleteDocument(doc.document_id)}
                     className="p-1 text-red-600 hover:text-red-800 hover:bg-red-50 rounded"
-                    title="Delete document"
+                    title={t('rag.documents.delete')}
                   >
                     <Trash2 className="w-4 h-4" />
                   </button>
@@ -621,18 +623,18 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
       {showMoveModal && movingDocument && (
         <div className="fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50">
           <div className="bg-white rounded-lg p-6 w-96 max-w-full mx-4">
-            <h3 className="text-lg font-semibold mb-4">Move Document</h3>
+            <h3 className="text-lg font-semibold mb-4">{t('rag.move.title')}</h3>
 
             <div className="mb-4">
               <p className="text-sm text-gray-600 mb-2">
-                Moving: <strong>{movingDocument.filename}</strong>
+                {t('rag.move.moving')}<strong>{movingDocument.filename}</strong>
               </p>
               <p className="text-sm text-gray-600 mb-4">
-                From: <span className="font-mono">{movingDocument.metadata?.directory_path || 'default'}</span>
+                {t('rag.move.from')}<span className="font-mono">{movingDocument.metadata?.directory_path || t('rag.move.defaultDirectory')}</span>
               </p>
 
               <label htmlFor="target-directory" className="block text-sm font-medium text-gray-700 mb-2">
-                To Directory
+                {t('rag.move.to')}
               </label>
               <input
                 id="target-directory"
@@ -640,7 +642,7 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
                 value={targetDirectory}
                 onChange={(e) => setTargetDirectory(e.target.value)}
                 className="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
-                placeholder="Enter directory name"
+                placeholder={t('rag.move.placeholder')}
                 onKeyPress={(e) => e.key === 'Enter' && executeMove()}
               />
             </div>
@@ -662,14 +664,14 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
                 className="px-4 py-2 text-gray-600 hover:text-gray-800"
                 disabled={moving}
               >
-                Cancel
+                {t('common.cancel')}
               </button>
               <button
                 onClick={executeMove}
                 disabled={moving || !targetDirectory.trim()}
                 className="px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed"
               >
-                {moving ? 'Moving...' : 'Move'}
+                {moving ? t('rag.move.submitting') : t('rag.move.submit')}
               </button>
             </div>
           </div>
@@ -680,15 +682,15 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
       {showBulkMoveModal && (
         <div className="fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50">
           <div className="bg-white rounded-lg p-6 w-96 max-w-full mx-4">
-            <h3 className="text-lg font-semibold mb-4">Move Selected Documents</h3>
+            <h3 className="text-lg font-semibold mb-4">{t('rag.bulk.moveTitle')}</h3>
 
             <div className="mb-4">
               <p className="text-sm text-gray-600 mb-2">
-                Moving <strong>{selectedDocuments.size} documents</strong>
+                {t('rag.bulk.moving')}<strong>{t('rag.documents.count', { count: selectedDocuments.size })}</strong>
               </p>
 
               <label htmlFor="bulk-target-directory" className="block text-sm font-medium text-gray-700 mb-2">
-                To Directory
+                {t('rag.move.to')}
               </label>
               <input
                 id="bulk-target-directory"
@@ -696,7 +698,7 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
                 value={bulkTargetDirectory}
                 onChange={(e) => setBulkTargetDirectory(e.target.value)}
                 className="w-full px-3 py-2 border border-gray-300 rounded-md focus:outline-none focus:ring-2 focus:ring-blue-500"
-                placeholder="Enter directory name"
+                placeholder={t('rag.move.placeholder')}
                 onKeyPress={(e) => e.key === 'Enter' && executeBulkMove()}
               />
             </div>
@@ -717,14 +719,14 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
                 className="px-4 py-2 text-gray-600 hover:text-gray-800"
                 disabled={bulkOperating}
               >
-                Cancel
+                {t('common.cancel')}
               </button>
               <button
                 onClick={executeBulkMove}
                 disabled={bulkOperating || !bulkTargetDirectory.trim()}
                 className="px-4 py-2 bg-blue-600 text-white rounded hover:bg-blue-700 disabled:opacity-50 disabled:cursor-not-allowed"
               >
-                {bulkOperating ? 'Moving...' : 'Move'}
+                {bulkOperating ? t('rag.move.submitting') : t('rag.move.submit')}
               </button>
             </div>
           </div>
@@ -735,14 +737,14 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
       {showBulkDeleteModal && (
         <div className="fixed inset-0 bg-black bg-opacity-50 flex items-center justify-center z-50">
           <div className="bg-white rounded-lg p-6 w-96 max-w-full mx-4">
-            <h3 className="text-lg font-semibold mb-4 text-red-600">Delete Selected Documents</h3>
+            <h3 className="text-lg font-semibold mb-4 text-red-600">{t('rag.bulk.deleteTitle')}</h3>
 
             <div className="mb-4">
               <p className="text-sm text-gray-600 mb-2">
-                Are you sure you want to delete <strong>{selectedDocuments.size} documents</strong>?
+                {t('rag.bulk.deleteConfirm')}<strong>{t('rag.documents.count', { count: selectedDocuments.size })}</strong>?
               </p>
               <p className="text-sm text-red-600">
-                This action cannot be undone.
+                {t('rag.bulk.irreversible')}
               </p>
             </div>
 
@@ -761,14 +763,14 @@ export default function RAGUpload({ onUpload }: RAGUploadProps) {
                 className="px-4 py-2 text-gray-600 hover:text-gray-800"
                 disabled={bulkOperating}
               >
-                Cancel
+                {t('common.cancel')}
               </button>
               <button
                 onClick={executeBulkDelete}
                 disabled={bulkOperating}
                 className="px-4 py-2 bg-red-600 text-white rounded hover:bg-red-700 disabled:opacity-50 disabled:cursor-not-allowed"
               >
-                {bulkOperating ? 'Deleting...' : 'Delete'}
+                {bulkOperating ? t('rag.bulk.deleting') : t('common.delete')}
               </button>
             </div>
           </div>
@@ -0,0 +1,85 @@
+"use client";
+
+import { FormEvent, useState } from 'react';
+import { Button } from '@/components/ui/Button';
+import { Input } from '@/components/ui/Input';
+import { useTranslation } from '@/hooks/useTranslation';
+import { REPEAT_LABELS, Reminder, ReminderRepeat } from '@/lib/reminders';
+import { localIsoDate } from '@/lib/time';
+
+export interface ReminderFormValues {
+  title: string;
+  dueAt: Date;
+  repeat: ReminderRepeat;
+}
+
+interface ReminderFormProps {
+  initial?: Reminder;
+  onSubmit: (values: ReminderFormValues) => void;
+  onCancel?: () => void;
+}
+
+function toTimeInput(date: Date): string {
+  return `${String(date.getHours()).padStart(2, '0')}:${String(date.getMinutes()).padStart(2, '0')}`;
+}
+
+export function ReminderForm({ initial, onSubmit, onCancel }: ReminderFormProps) {
+  const { t } = useTranslation();
+  const initialDue = initial ? new Date(initial.dueAt) : new Date();
+  const [title, setTitle] = useState(initial?.title ?? '');
+  const [day, setDay] = useState(localIsoDate(initialDue));
+  const [time, setTime] = useState(initial ? toTimeInput(initialDue) : '09:00');
+  const [repeat, setRepeat] = useState<ReminderRepeat>(initial?.repeat ?? 'none');
+
+  const handleSubmit = (e: FormEvent) => {
+    e.preventDefault();
+    if (!title.trim() || !day) return;
+    const [year, month, date] = day.split('-').map(Number);
+    const [hours, minutes] = (time || '00:00').split(':').map(Number);
+    onSubmit({ title: title.trim(), dueAt: new Date(year, month - 1, date, hours, minutes), repeat });
+    if (!initial) setTitle('');
+  };
+
+  return (
+    <form onSubmit={handleSubmit} className="grid grid-cols-1 sm:grid-cols-4 gap-3 items-end">
+      <div className="sm:col-span-4">
+        <Input
+          label={t('reminders.form.title')}
+          value={title}
+          onChange={(e) => setTitle(e.target.value)}
+          placeholder={t('reminders.form.titlePlaceholder')}
+          data-primary-input
+        />
+      </div>
+      <Input label={t('reminders.form.date')} type="date" value={day} onChange={(e) => setDay(e.target.value)} />
+      <Input label={t('reminders.form.time')} type="time" value={time} onChange={(e) => setTime(e.target.value)} />
+      <div className="space-y-2">
+        <label htmlFor="reminder-repeat" className="block text-sm font-medium text-gray-700">
+          {t('reminders.form.repeat')}
+        </label>
+        <select
+          id="reminder-repeat"
+          value={repeat}
+          onChange={(e) => setRepeat(e.target.value as ReminderRepeat)}
+          className="w-full rounded-md border px-3 py-2 text-sm"
+        >
+          {(Object.keys(REPEAT_LABELS) as ReminderRepeat[]).map((key) => (
+            <option key={key} value={key}>
+              {t(REPEAT_LABELS[key])}
+            </option>
+          ))}
+        </select>
+      </div>
+      <div className="flex gap-2">
+        <Button type="submit" disabled={!title.trim()}>
+          {initial ? t('common.save') : t('common.add')}
+        </Button>
+        {onCancel && (
+          <Button type="button" variant="outline" onClick={onCancel}>
+            {t('common.cancel')}
+          </Button>
+        )}
+      </div>
+    </form>
+  );
+}
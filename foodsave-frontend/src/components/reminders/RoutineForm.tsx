@@ -0,0 +1,115 @@
+"use client";
+
+import { FormEvent, useState } from 'react';
+import { Button } from '@/components/ui/Button';
+import { Input } from '@/components/ui/Input';
+import { useTranslation } from '@/hooks/useTranslation';
+import { ATTACHMENT_KINDS, ATTACHMENT_LABELS } from '@/lib/chatAttachments';
+import { Routine, RoutineValues } from '@/lib/routines';
+import { ContextAttachmentKind } from '@/types/chat';
+
+// Monday first, as in Polish calendars
+export const WEEK_DAYS = [1, 2, 3, 4, 5, 6, 0];
+
+// Short weekday name in the UI language (2024-01-07 was a Sunday)
+export function weekdayName(day: number, locale: string): string {
+  return new Date(2024, 0, 7 + day).toLocaleDateString(locale, { weekday: 'short' });
+}
+
+interface RoutineFormProps {
+  initial?: Routine;
+  onSubmit: (values: RoutineValues) => void;
+  onCancel?: () => void;
+}
+
+export function RoutineForm({ initial, onSubmit, onCancel }: RoutineFormProps) {
+  const { t, locale } = useTranslation();
+  const [name, setName] = useState(initial?.name ?? '');
+  const [prompt, setPrompt] = useState(initial?.prompt ?? '');
+  const [days, setDays] = useState<number[]>(initial?.days ?? [0]);
+  const [time, setTime] = useState(initial?.time ?? '18:00');
+  const [attachments, setAttachments] = useState<ContextAttachmentKind[]>(initial?.attachments ?? ['pantry']);
+
+  const valid = name.trim() && prompt.trim() && days.length > 0 && time;
+
+  const toggleDay = (day: number) =>
+    setDays((prev) => (prev.includes(day) ? prev.filter((d) => d !== day) : [...prev, day]));
+  const toggleAttachment = (kind: ContextAttachmentKind) =>
+    setAttachments((prev) => (prev.includes(kind) ? prev.filter((k) => k !== kind) : [...prev, kind]));
+
+  const handleSubmit = (e: FormEvent) => {
+    e.preventDefault();
+    if (!valid) return;
+    onSubmit({ name: name.trim(), prompt: prompt.trim(), days, time, attachments });
+    if (!initial) {
+      setName('');
+      setPrompt('');
+    }
+  };
+
+  return (
+    <form onSubmit={handleSubmit} className="space-y-3">
+      <div className="grid grid-cols-1 sm:grid-cols-[1fr_10rem] gap-3">
+        <Input
+          label={t('routines.form.name')}
+          value={name}
+          onChange={(e) => setName(e.target.value)}
+          placeholder={t('routines.form.namePlaceholder')}
+        />
+        <Input label={t('routines.form.time')} type="time" value={time} onChange={(e) => setTime(e.target.value)} />
+      </div>
+      <div className="space-y-2">
+        <label htmlFor="routine-prompt" className="block text-sm font-medium text-gray-700">
+          {t('routines.form.prompt')}
+        </label>
+        <textarea
+          id="routine-prompt"
+          rows={2}
+          value={prompt}
+          onChange={(e) => setPrompt(e.target.value)}
+          placeholder={t('routines.form.promptPlaceholder')}
+          className="w-full rounded-md border px-3 py-2 text-sm"
+        />
+      </div>
+      <fieldset>
+        <legend className="mb-1 text-sm font-medium text-gray-700">{t('routines.form.days')}</legend>
+        <div className="flex flex-wrap gap-1">
+          {WEEK_DAYS.map((day) => (
+            <button
+              key={day}
+              type="button"
+              onClick={() => toggleDay(day)}
+              aria-pressed={days.includes(day)}
+              className={`rounded-md border px-2 py-1 text-sm ${
+                days.includes(day) ? 'bg-primary text-primary-foreground border-primary' : 'hover:bg-gray-50'
+              }`}
+            >
+              {weekdayName(day, locale)}
+            </button>
+          ))}
+        </div>
+      </fieldset>
+      <fieldset>
+        <legend className="mb-1 text-sm font-medium text-gray-700">{t('routines.form.attachments')}</legend>
+        <div className="flex flex-wrap gap-4">
+          {ATTACHMENT_KINDS.map((kind) => (
+            <label key={kind} className="flex items-center gap-2 text-sm">
+              <input type="checkbox" checked={attachments.includes(kind)} onChange={() => toggleAttachment(kind)} />
+              {t(ATTACHMENT_LABELS[kind])}
+            </label>
+          ))}
+        </div>
+      </fieldset>
+      <div className="flex gap-2">
+        <Button type="submit" disabled={!valid}>
+          {initial ? t('common.save') : t('common.add')}
+        </Button>
+        {onCancel && (
+          <Button type="button" variant="outline" onClick={onCancel}>
+            {t('common.cancel')}
+          </Button>
+        )}
+      </div>
+    </form>
+  );
+}
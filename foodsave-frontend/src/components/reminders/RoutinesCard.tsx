@@ -0,0 +1,117 @@
+"use client";
+
+import { useState } from 'react';
+import Link from 'next/link';
+import { CalendarClock, Pencil, Play, Trash2 } from 'lucide-react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Button } from '@/components/ui/Button';
+import { EmptyState } from '@/components/ui/EmptyState';
+import { RoutineForm, WEEK_DAYS, weekdayName } from '@/components/reminders/RoutineForm';
+import { useRoutines } from '@/hooks/useRoutines';
+import { useTranslation } from '@/hooks/useTranslation';
+import { requestNotificationPermission } from '@/lib/notifications';
+import { Routine } from '@/lib/routines';
+
+function scheduleText(routine: Routine, locale: string): string {
+  const days = WEEK_DAYS.filter((day) => routine.days.includes(day)).map((day) => weekdayName(day, locale));
+  return `${days.join(', ')} ${routine.time}`;
+}
+
+// Assistant routines: prompts sent on a schedule, managed next to the reminders
+export function RoutinesCard() {
+  const { routines, addRoutine, updateRoutine, removeRoutine, runRoutineNow } = useRoutines();
+  const { t, locale } = useTranslation();
+  const [editingId, setEditingId] = useState<string | null>(null);
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>{t('routines.title')}</CardTitle>
+        <p className="text-sm text-muted-foreground">{t('routines.subtitle')}</p>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <RoutineForm
+          onSubmit={(values) => {
+            addRoutine(values);
+            requestNotificationPermission();
+          }}
+        />
+        {routines.length === 0 ? (
+          <EmptyState message={t('routines.empty')} icon={CalendarClock} />
+        ) : (
+          <ul className="divide-y border-t">
+            {routines.map((routine) =>
+              editingId === routine.id ? (
+                <li key={routine.id} className="py-3">
+                  <RoutineForm
+                    initial={routine}
+                    onSubmit={(values) => {
+                      updateRoutine(routine.id, values);
+                      setEditingId(null);
+                    }}
+                    onCancel={() => setEditingId(null)}
+                  />
+                </li>
+              ) : (
+                <li key={routine.id} className="flex items-center gap-3 py-2">
+                  <input
+                    type="checkbox"
+                    checked={routine.enabled}
+                    onChange={() => updateRoutine(routine.id, { enabled: !routine.enabled })}
+                    aria-label={`${t('routines.enabled')}: ${routine.name}`}
+                    className="h-4 w-4"
+                  />
+                  <div className="flex-1 min-w-0">
+                    <p className={routine.enabled ? '' : 'text-gray-400'}>{routine.name}</p>
+                    <p className="truncate text-sm text-gray-500" title={routine.prompt}>
+                      <CalendarClock className="inline h-3 w-3 mr-1" />
+                      {scheduleText(routine, locale)}
+                      {routine.nextRunAt &&
+                        ` · ${t('routines.next', {
+                          date: new Date(routine.nextRunAt).toLocaleString(locale, {
+                            weekday: 'short',
+                            day: 'numeric',
+                            month: 'short',
+                            hour: '2-digit',
+                            minute: '2-digit',
+                          }),
+                        })}`}
+                    </p>
+                    {routine.lastSessionId && (
+                      <Link
+                        href={`/chat?conversation=${encodeURIComponent(routine.lastSessionId)}`}
+                        className="text-xs text-blue-600 hover:underline"
+                      >
+                        {t('routines.lastResult')}
+                      </Link>
+                    )}
+                  </div>
+                  <Button
+                    variant="ghost"
+                    size="sm"
+                    onClick={() => runRoutineNow(routine.id)}
+                    aria-label={`${t('routines.runNow')}: ${routine.name}`}
+                    title={t('routines.runNow')}
+                  >
+                    <Play className="h-4 w-4" />
+                  </Button>
+                  <Button variant="ghost" size="sm" onClick={() => setEditingId(routine.id)} aria-label={t('common.edit')}>
+                    <Pencil className="h-4 w-4" />
+                  </Button>
+                  <Button
+                    variant="ghost"
+                    size="sm"
+                    onClick={() => removeRoutine(routine.id)}
+                    aria-label={`${t('common.delete')} ${routine.name}`}
+                  >
+                    <Trash2 className="h-4 w-4" />
+                  </Button>
+                </li>
+              ),
+            )}
+          </ul>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
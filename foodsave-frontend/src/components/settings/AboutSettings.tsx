@@ -0,0 +1,90 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { ExternalLink } from 'lucide-react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Button } from '@/components/ui/Button';
+import { useTranslation } from '@/hooks/useTranslation';
+import { APP_VERSION, ReleaseInfo, availableUpdate, checkForUpdate } from '@/lib/updates';
+import { errorMessage } from '@/lib/toasts';
+
+interface AboutSettingsProps {
+  checkForUpdates: boolean;
+  onChange: (checkForUpdates: boolean) => void;
+}
+
+type CheckState = { status: 'idle' | 'checking' | 'upToDate' } | { status: 'failed'; error: string };
+
+export function AboutSettings({ checkForUpdates, onChange }: AboutSettingsProps) {
+  const { t } = useTranslation();
+  const [update, setUpdate] = useState<ReleaseInfo | null>(null);
+  const [check, setCheck] = useState<CheckState>({ status: 'idle' });
+
+  // Wynik ostatniego sprawdzenia (np. z zadania w tle)
+  useEffect(() => {
+    setUpdate(availableUpdate());
+  }, []);
+
+  const checkNow = async () => {
+    setCheck({ status: 'checking' });
+    try {
+      const release = await checkForUpdate(true);
+      setUpdate(release);
+      setCheck({ status: release ? 'idle' : 'upToDate' });
+    } catch (error) {
+      setCheck({ status: 'failed', error: errorMessage(error, '') });
+    }
+  };
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>{t('settings.about.title')}</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-3">
+        <p className="text-sm">FoodSave AI · {t('settings.about.version', { version: APP_VERSION })}</p>
+        <div>
+          <label className="flex items-center gap-2 text-sm">
+            <input
+              type="checkbox"
+              checked={checkForUpdates}
+              onChange={(e) => onChange(e.target.checked)}
+              className="h-4 w-4"
+            />
+            {t('settings.about.checkUpdates')}
+          </label>
+          <p className="text-xs text-muted-foreground mt-1">{t('settings.about.checkUpdatesHint')}</p>
+        </div>
+        <Button variant="outline" size="sm" onClick={checkNow} disabled={check.status === 'checking'}>
+          {t('settings.about.checkNow')}
+        </Button>
+        {check.status === 'upToDate' && <p className="text-sm text-muted-foreground">{t('settings.about.upToDate')}</p>}
+        {check.status === 'failed' && (
+          <p className="text-sm text-red-600">{t('settings.about.checkFailed', { error: check.error })}</p>
+        )}
+        {update && (
+          <div className="rounded-md border p-3 space-y-2">
+            <p className="text-sm font-medium">{t('settings.about.available', { version: update.version })}</p>
+            {update.notes && (
+              <details>
+                <summary className="cursor-pointer text-sm">{t('settings.about.releaseNotes')}</summary>
+                <p className="mt-2 whitespace-pre-wrap text-xs text-muted-foreground">{update.notes}</p>
+              </details>
+            )}
+            {update.url && (
+              <a
+                href={update.url}
+                target="_blank"
+                rel="noopener noreferrer"
+                className="inline-flex items-center gap-1 text-sm text-primary hover:underline"
+              >
+                <ExternalLink className="h-4 w-4" />
+                GitHub
+              </a>
+            )}
+          </div>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,70 @@
+"use client";
+
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { useTranslation } from '@/hooks/useTranslation';
+import { MessageKey } from '@/lib/i18n';
+import { ACCESSIBILITY_LABELS, AccessibilityPreference } from '@/lib/theme';
+
+interface AccessibilitySettingsProps {
+  highContrast: AccessibilityPreference;
+  reducedMotion: AccessibilityPreference;
+  onChange: (change: { highContrast?: AccessibilityPreference; reducedMotion?: AccessibilityPreference }) => void;
+}
+
+interface PreferenceGroupProps {
+  labelKey: MessageKey;
+  value: AccessibilityPreference;
+  onChange: (value: AccessibilityPreference) => void;
+}
+
+function PreferenceGroup({ labelKey, value, onChange }: PreferenceGroupProps) {
+  const { t } = useTranslation();
+  return (
+    <div className="flex flex-wrap items-center justify-between gap-2">
+      <span className="text-sm font-medium">{t(labelKey)}</span>
+      <div role="radiogroup" aria-label={t(labelKey)} className="inline-flex rounded-md border p-1 gap-1">
+        {(Object.keys(ACCESSIBILITY_LABELS) as AccessibilityPreference[]).map((option) => {
+          const checked = value === option;
+          return (
+            <button
+              key={option}
+              type="button"
+              role="radio"
+              aria-checked={checked}
+              onClick={() => onChange(option)}
+              className={`rounded px-3 py-1.5 text-sm transition-colors ${
+                checked ? 'bg-primary text-primary-foreground' : 'hover:bg-accent'
+              }`}
+            >
+              {t(ACCESSIBILITY_LABELS[option])}
+            </button>
+          );
+        })}
+      </div>
+    </div>
+  );
+}
+
+export function AccessibilitySettings({ highContrast, reducedMotion, onChange }: AccessibilitySettingsProps) {
+  const { t } = useTranslation();
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>{t('settings.accessibility.title')}</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-3">
+        <PreferenceGroup
+          labelKey="settings.accessibility.highContrast"
+          value={highContrast}
+          onChange={(value) => onChange({ highContrast: value })}
+        />
+        <PreferenceGroup
+          labelKey="settings.accessibility.reducedMotion"
+          value={reducedMotion}
+          onChange={(value) => onChange({ reducedMotion: value })}
+        />
+        <p className="text-xs text-muted-foreground">{t('settings.accessibility.hint')}</p>
+      </CardContent>
+    </Card>
+  );
+}
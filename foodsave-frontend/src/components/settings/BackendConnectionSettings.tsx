@@ -0,0 +1,76 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { CheckCircle2, PlugZap, XCircle } from 'lucide-react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Button } from '@/components/ui/Button';
+import { Input } from '@/components/ui/Input';
+import { useTranslation } from '@/hooks/useTranslation';
+import { ConnectionTestResult, testBackendConnection } from '@/lib/backendConnection';
+import { validateBackendUrl } from '@/lib/url';
+import { API_BASE_URL } from '@/services/ApiService';
+
+interface BackendConnectionSettingsProps {
+  value: string; // '' = adres domyślny ze zmiennej środowiskowej
+  onChange: (backendUrl: string) => void;
+}
+
+export function BackendConnectionSettings({ value, onChange }: BackendConnectionSettingsProps) {
+  const { t, locale } = useTranslation();
+  const url = value || API_BASE_URL;
+  const [isTesting, setIsTesting] = useState(false);
+  const [result, setResult] = useState<ConnectionTestResult | null>(null);
+  const validation = validateBackendUrl(url, locale);
+
+  // Wynik testu dotyczy konkretnego adresu
+  useEffect(() => {
+    setResult(null);
+  }, [url]);
+
+  const handleTest = async () => {
+    setIsTesting(true);
+    setResult(await testBackendConnection(validation.normalized, undefined, locale));
+    setIsTesting(false);
+  };
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>{t('settings.backend.title')}</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <Input
+          label={t('settings.backend.address')}
+          value={url}
+          // Adres domyślny nie jest zapisywany, żeby dalej działała zmienna środowiskowa
+          onChange={(e) => onChange(e.target.value === API_BASE_URL ? '' : e.target.value)}
+          placeholder={API_BASE_URL}
+          error={validation.error}
+          helperText={validation.warning}
+        />
+
+        {result && (
+          <div
+            className={`flex items-start gap-2 text-sm ${
+              !result.ok ? 'text-red-600' : result.reason ? 'text-yellow-700' : 'text-green-700'
+            }`}
+          >
+            {result.ok ? <CheckCircle2 className="h-4 w-4 mt-0.5" /> : <XCircle className="h-4 w-4 mt-0.5" />}
+            <span>
+              {result.ok
+                ? t('settings.backend.connected', { ms: result.latencyMs ?? 0 })
+                : t('settings.backend.disconnected')}
+              {result.version && ` · ${t('settings.backend.version', { version: result.version })}`}
+              {result.reason && ` · ${result.reason}`}
+            </span>
+          </div>
+        )}
+
+        <Button variant="outline" onClick={handleTest} isLoading={isTesting} disabled={!validation.valid}>
+          <PlugZap className="h-4 w-4 mr-2" />
+          {t('settings.backend.test')}
+        </Button>
+      </CardContent>
+    </Card>
+  );
+}
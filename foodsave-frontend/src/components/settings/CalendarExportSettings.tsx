@@ -0,0 +1,99 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Button } from '@/components/ui/Button';
+import { useTranslation } from '@/hooks/useTranslation';
+import { ApiService, resolveBaseUrl } from '@/services/ApiService';
+import { reportError } from '@/lib/errors';
+
+const EXPIRY_FEED_PATH = '/api/v2/calendar/expiry.ics?days=30';
+
+export function CalendarExportSettings() {
+  const { t } = useTranslation();
+  const [feedUrl, setFeedUrl] = useState('');
+  const [copied, setCopied] = useState(false);
+  // null do czasu odpowiedzi backendu
+  const [enabled, setEnabled] = useState<boolean | null>(null);
+  const [isSaving, setIsSaving] = useState(false);
+
+  // Adres backendu znany dopiero w przeglądarce (ustawienia w localStorage)
+  useEffect(() => {
+    setFeedUrl(`${resolveBaseUrl().replace(/\/+$/, '')}${EXPIRY_FEED_PATH}`);
+    const controller = new AbortController();
+    ApiService.getExpiryFeedEnabled(controller.signal)
+      .then((status) => setEnabled(status.enabled))
+      .catch(() => undefined);
+    return () => controller.abort();
+  }, []);
+
+  const toggle = async (next: boolean) => {
+    setIsSaving(true);
+    try {
+      const status = await ApiService.setExpiryFeedEnabled(next);
+      setEnabled(status.enabled);
+    } catch (error) {
+      reportError(error, t('settings.calendarExport.saveFailed'), () => void toggle(next));
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  const copy = async () => {
+    try {
+      await navigator.clipboard.writeText(feedUrl);
+      setCopied(true);
+    } catch {
+      setCopied(false);
+    }
+  };
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>{t('settings.calendarExport.title')}</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-3">
+        <p className="text-sm text-muted-foreground">{t('settings.calendarExport.hint')}</p>
+        <label className="flex items-center gap-2 text-sm">
+          <input
+            type="checkbox"
+            checked={enabled ?? false}
+            disabled={enabled === null || isSaving}
+            onChange={(e) => void toggle(e.target.checked)}
+            className="h-4 w-4"
+          />
+          {t('settings.calendarExport.enabled')}
+        </label>
+        <p className="text-xs text-muted-foreground">{t('settings.calendarExport.enabledHint')}</p>
+        {enabled && (
+          <>
+            <input
+              readOnly
+              value={feedUrl}
+              onFocus={(e) => e.target.select()}
+              aria-label={t('settings.calendarExport.feedUrl')}
+              className="w-full rounded-md border px-3 py-2 text-sm font-mono bg-muted"
+            />
+            <div className="flex flex-wrap gap-2">
+              <Button variant="outline" size="sm" onClick={copy} disabled={!feedUrl}>
+                {copied ? t('settings.calendarExport.copied') : t('settings.calendarExport.copy')}
+              </Button>
+              <Button
+                variant="outline"
+                size="sm"
+                disabled={!feedUrl}
+                onClick={() => (window.location.href = feedUrl.replace(/^https?:/, 'webcal:'))}
+              >
+                {t('settings.calendarExport.subscribe')}
+              </Button>
+              <Button variant="outline" size="sm" disabled={!feedUrl} onClick={() => window.open(feedUrl, '_blank')}>
+                {t('settings.calendarExport.download')}
+              </Button>
+            </div>
+          </>
+        )}
+      </CardContent>
+    </Card>
+  );
+}
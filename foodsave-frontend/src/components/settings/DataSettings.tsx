@@ -0,0 +1,146 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { useQueryClient } from '@tanstack/react-query';
+import { Trash2 } from 'lucide-react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Button } from '@/components/ui/Button';
+import { useConfirmDialog } from '@/components/ui/ConfirmDialog';
+import { useTranslation } from '@/hooks/useTranslation';
+import { ActivityType, clearActivities, getActivities, subscribeActivities } from '@/lib/activityLog';
+import { MessageKey } from '@/lib/i18n';
+import { clearChatHistory, getAllChatHistory, getSavedConversations, subscribeChatHistory } from '@/lib/chatHistory';
+import { clearLocalData, formatBytes, jsonBytes, localDataBytes } from '@/lib/localData';
+import { clearOfflineCache } from '@/lib/offlineCache';
+import { clearImageCache } from '@/lib/imageCache';
+import { clearReceiptImages } from '@/lib/receiptImages';
+
+const CHAT_TYPES: ActivityType[] = ['chat'];
+const OCR_TYPES: ActivityType[] = ['receipt_scan', 'receipt_saved'];
+
+interface DataStore {
+  id: string;
+  titleKey: MessageKey;
+  entries: number;
+  bytes: number;
+  clear: () => void;
+}
+
+export function DataSettings() {
+  const { t, locale } = useTranslation();
+  const [confirm, confirmDialog] = useConfirmDialog();
+  const queryClient = useQueryClient();
+  // Zmiana wymusza ponowne policzenie rozmiarów
+  const [, setRevision] = useState(0);
+
+  useEffect(() => {
+    const refresh = () => setRevision((r) => r + 1);
+    const unsubscribeActivities = subscribeActivities(refresh);
+    const unsubscribeChat = subscribeChatHistory(refresh);
+    return () => {
+      unsubscribeActivities();
+      unsubscribeChat();
+    };
+  }, []);
+
+  const activities = getActivities();
+  const ofTypes = (types: ActivityType[]) => activities.filter((a) => types.includes(a.type));
+  const queries = queryClient.getQueryCache().getAll();
+  const chats = [...Object.values(getAllChatHistory()), ...getSavedConversations()];
+
+  const stores: DataStore[] = [
+    {
+      id: 'cache',
+      titleKey: 'settings.data.cache',
+      entries: queries.length,
+      bytes: queries.reduce((total, query) => total + jsonBytes(query.state.data), 0),
+      clear: () => {
+        queryClient.clear();
+        clearOfflineCache();
+        void clearImageCache();
+      },
+    },
+    {
+      id: 'chat',
+      titleKey: 'settings.data.chat',
+      entries: ofTypes(CHAT_TYPES).length + chats.reduce((total, chat) => total + chat.messages.length, 0),
+      bytes: jsonBytes(ofTypes(CHAT_TYPES)) + jsonBytes(chats),
+      clear: () => {
+        clearActivities(CHAT_TYPES);
+        clearChatHistory();
+      },
+    },
+    {
+      id: 'ocr',
+      titleKey: 'settings.data.ocr',
+      entries: ofTypes(OCR_TYPES).length,
+      bytes: jsonBytes(ofTypes(OCR_TYPES)),
+      clear: () => {
+        clearActivities(OCR_TYPES);
+        void clearReceiptImages();
+      },
+    },
+  ];
+
+  const handleClearAll = async () => {
+    const ok = await confirm({
+      title: t('settings.data.all'),
+      message: t('settings.data.allConfirm'),
+      confirmLabel: t('settings.data.clear'),
+      cancelLabel: t('common.cancel'),
+      destructive: true,
+    });
+    if (!ok) return;
+    queryClient.clear();
+    clearLocalData();
+    // Ustawienia i inne moduły czytają dane przy starcie - najprościej przeładować stronę
+    window.location.reload();
+  };
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>{t('settings.data.title')}</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-3">
+        <ul className="divide-y">
+          {stores.map((store) => (
+            <li key={store.id} className="flex items-center gap-3 py-2">
+              <div className="flex-1">
+                <p className="text-sm font-medium">{t(store.titleKey)}</p>
+                <p className="text-xs text-muted-foreground">
+                  {t('settings.data.entries', { count: store.entries })} · {formatBytes(store.bytes, locale)}
+                </p>
+              </div>
+              <Button
+                variant="outline"
+                size="sm"
+                disabled={store.entries === 0}
+                onClick={() => {
+                  store.clear();
+                  setRevision((r) => r + 1);
+                }}
+              >
+                {t('settings.data.clear')}
+              </Button>
+            </li>
+          ))}
+        </ul>
+
+        <div className="flex items-center gap-3 rounded-md border border-red-200 p-3">
+          <div className="flex-1">
+            <p className="text-sm font-medium">{t('settings.data.all')}</p>
+            <p className="text-xs text-muted-foreground">
+              {t('settings.data.allHint')} · {formatBytes(localDataBytes(), locale)}
+            </p>
+          </div>
+          <Button variant="destructive" size="sm" onClick={handleClearAll}>
+            <Trash2 className="h-4 w-4 mr-2" />
+            {t('settings.data.clear')}
+          </Button>
+        </div>
+        {confirmDialog}
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,48 @@
+"use client";
+
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Input } from '@/components/ui/Input';
+import { useTranslation } from '@/hooks/useTranslation';
+import { LOCALE_LABELS, Locale } from '@/lib/i18n';
+
+interface GeneralSettingsProps {
+  userName: string;
+  language: Locale;
+  onChange: (changes: { userName?: string; language?: Locale }) => void;
+}
+
+export function GeneralSettings({ userName, language, onChange }: GeneralSettingsProps) {
+  const { t } = useTranslation();
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>{t('settings.general.title')}</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <Input
+          label={t('settings.general.userName')}
+          value={userName}
+          onChange={(e) => onChange({ userName: e.target.value })}
+        />
+        <div className="space-y-2">
+          <label htmlFor="settings-language" className="block text-sm font-medium text-gray-700">
+            {t('settings.language.title')}
+          </label>
+          <select
+            id="settings-language"
+            value={language}
+            onChange={(e) => onChange({ language: e.target.value as Locale })}
+            className="rounded-md border px-3 py-2 text-sm"
+          >
+            {(Object.keys(LOCALE_LABELS) as Locale[]).map((locale) => (
+              <option key={locale} value={locale}>
+                {LOCALE_LABELS[locale]}
+              </option>
+            ))}
+          </select>
+        </div>
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,70 @@
+"use client";
+
+import Link from 'next/link';
+import { ScrollText } from 'lucide-react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { useTranslation } from '@/hooks/useTranslation';
+import { LOG_LEVEL_BY_NAME, LOG_LEVEL_NAMES, LogLevelName } from '@/lib/logger';
+
+interface LoggingSettingsProps {
+  level: LogLevelName;
+  persist: boolean;
+  metrics: boolean;
+  onChange: (changes: { logLevel?: LogLevelName; persistLogs?: boolean; collectMetrics?: boolean }) => void;
+}
+
+export function LoggingSettings({ level, persist, metrics, onChange }: LoggingSettingsProps) {
+  const { t } = useTranslation();
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>{t('settings.logging.title')}</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-3">
+        <label className="flex items-center gap-3 text-sm">
+          {t('settings.logging.level')}
+          <select
+            value={level}
+            onChange={(e) => onChange({ logLevel: e.target.value as LogLevelName })}
+            className="rounded-md border px-2 py-1 text-sm"
+          >
+            {(Object.keys(LOG_LEVEL_BY_NAME) as LogLevelName[]).map((name) => (
+              <option key={name} value={name}>
+                {LOG_LEVEL_NAMES[LOG_LEVEL_BY_NAME[name]]}
+              </option>
+            ))}
+          </select>
+        </label>
+        <div>
+          <label className="flex items-center gap-2 text-sm">
+            <input
+              type="checkbox"
+              checked={persist}
+              onChange={(e) => onChange({ persistLogs: e.target.checked })}
+              className="h-4 w-4"
+            />
+            {t('settings.logging.persist')}
+          </label>
+          <p className="text-xs text-muted-foreground mt-1">{t('settings.logging.persistHint')}</p>
+        </div>
+        <div>
+          <label className="flex items-center gap-2 text-sm">
+            <input
+              type="checkbox"
+              checked={metrics}
+              onChange={(e) => onChange({ collectMetrics: e.target.checked })}
+              className="h-4 w-4"
+            />
+            {t('settings.logging.metrics')}
+          </label>
+          <p className="text-xs text-muted-foreground mt-1">{t('settings.logging.metricsHint')}</p>
+        </div>
+        <Link href="/logs" className="inline-flex items-center gap-2 text-sm text-primary hover:underline">
+          <ScrollText className="h-4 w-4" />
+          {t('settings.logging.open')}
+        </Link>
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,92 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { Bell } from 'lucide-react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Button } from '@/components/ui/Button';
+import { useTranslation } from '@/hooks/useTranslation';
+import {
+  NOTIFICATION_CATEGORIES,
+  NotificationCategory,
+  requestNotificationPermission,
+} from '@/lib/notifications';
+
+type PermissionState = NotificationPermission | 'unsupported';
+
+function currentPermission(): PermissionState {
+  return 'Notification' in window ? Notification.permission : 'unsupported';
+}
+
+interface NotificationSettingsProps {
+  enabled: boolean;
+  categories: Partial<Record<NotificationCategory, boolean>>;
+  onChange: (changes: {
+    notificationsEnabled?: boolean;
+    notificationCategories?: Partial<Record<NotificationCategory, boolean>>;
+  }) => void;
+}
+
+export function NotificationSettings({ enabled, categories, onChange }: NotificationSettingsProps) {
+  const { t } = useTranslation();
+  const [permission, setPermission] = useState<PermissionState>('default');
+
+  useEffect(() => {
+    setPermission(currentPermission());
+  }, []);
+
+  const handleEnable = async () => {
+    await requestNotificationPermission();
+    setPermission(currentPermission());
+  };
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>{t('settings.notifications.title')}</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-3">
+        <p className="text-sm text-muted-foreground">{t(`settings.notifications.${permission}` as const)}</p>
+        <div className="flex flex-wrap gap-2">
+          {permission === 'default' && (
+            <Button variant="outline" onClick={handleEnable}>
+              <Bell className="h-4 w-4 mr-2" />
+              {t('settings.notifications.enable')}
+            </Button>
+          )}
+          {permission === 'granted' && (
+            <Button
+              variant="outline"
+              // Test omija ustawienia kategorii i limit – ma pokazać, czy przeglądarka wyświetla powiadomienia
+              onClick={() => new Notification('FoodSave', { body: t('settings.notifications.testBody') })}
+            >
+              {t('settings.notifications.test')}
+            </Button>
+          )}
+        </div>
+        <label className="flex items-center gap-2 text-sm">
+          <input
+            type="checkbox"
+            checked={enabled}
+            onChange={(e) => onChange({ notificationsEnabled: e.target.checked })}
+            className="h-4 w-4"
+          />
+          {t('settings.notifications.master')}
+        </label>
+        <fieldset disabled={!enabled} className="space-y-2 pl-6 disabled:opacity-50">
+          <legend className="text-sm font-medium mb-1">{t('settings.notifications.categories')}</legend>
+          {NOTIFICATION_CATEGORIES.map(({ id, labelKey }) => (
+            <label key={id} className="flex items-center gap-2 text-sm">
+              <input
+                type="checkbox"
+                checked={categories[id] !== false}
+                onChange={(e) => onChange({ notificationCategories: { ...categories, [id]: e.target.checked } })}
+                className="h-4 w-4"
+              />
+              {t(labelKey)}
+            </label>
+          ))}
+        </fieldset>
+      </CardContent>
+    </Card>
+  );
+}
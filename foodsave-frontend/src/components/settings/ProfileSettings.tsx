@@ -0,0 +1,92 @@
+"use client";
+
+import { FormEvent, useState } from 'react';
+import { Trash2 } from 'lucide-react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Button } from '@/components/ui/Button';
+import { useConfirmDialog } from '@/components/ui/ConfirmDialog';
+import { useTranslation } from '@/hooks/useTranslation';
+import { activateProfile, useProfiles } from '@/hooks/useProfiles';
+import { DEFAULT_PROFILE_ID, addProfile, removeProfile, renameProfile } from '@/lib/profiles';
+
+// Household members: each has their own chat history and settings
+export function ProfileSettings() {
+  const { t } = useTranslation();
+  const { profiles, active } = useProfiles();
+  const [confirm, confirmDialog] = useConfirmDialog();
+  const [name, setName] = useState('');
+
+  const handleAdd = (e: FormEvent) => {
+    e.preventDefault();
+    if (!name.trim()) return;
+    addProfile(name);
+    setName('');
+  };
+
+  const handleRemove = async (id: string, profileName: string) => {
+    const ok = await confirm({
+      title: t('profiles.remove'),
+      message: t('profiles.removeConfirm', { name: profileName }),
+      confirmLabel: t('profiles.remove'),
+      cancelLabel: t('common.cancel'),
+      destructive: true,
+    });
+    if (!ok) return;
+    const wasActive = active?.id === id;
+    removeProfile(id);
+    if (wasActive) window.location.reload();
+  };
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>{t('profiles.title')}</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-3">
+        <p className="text-sm text-muted-foreground">{t('profiles.hint')}</p>
+        <ul className="divide-y">
+          {profiles.map((profile) => (
+            <li key={profile.id} className="flex items-center gap-2 py-2">
+              <input
+                defaultValue={profile.name}
+                onBlur={(e) => e.target.value.trim() && renameProfile(profile.id, e.target.value)}
+                aria-label={t('profiles.name')}
+                className="flex-1 min-w-0 rounded-md border px-2 py-1 text-sm"
+              />
+              {profile.id === active?.id ? (
+                <span className="text-xs text-primary">{t('profiles.active')}</span>
+              ) : (
+                <Button variant="outline" size="sm" onClick={() => activateProfile(profile.id)}>
+                  {t('profiles.switchTo')}
+                </Button>
+              )}
+              {profile.id !== DEFAULT_PROFILE_ID && (
+                <button
+                  type="button"
+                  onClick={() => void handleRemove(profile.id, profile.name)}
+                  aria-label={t('profiles.remove')}
+                  className="p-1 text-gray-400 hover:text-red-600"
+                >
+                  <Trash2 className="h-4 w-4" />
+                </button>
+              )}
+            </li>
+          ))}
+        </ul>
+        <form onSubmit={handleAdd} className="flex gap-2">
+          <input
+            value={name}
+            onChange={(e) => setName(e.target.value)}
+            placeholder={t('profiles.newPlaceholder')}
+            aria-label={t('profiles.name')}
+            className="flex-1 min-w-0 rounded-md border px-3 py-2 text-sm"
+          />
+          <Button type="submit" size="sm" disabled={!name.trim()}>
+            {t('profiles.add')}
+          </Button>
+        </form>
+        {confirmDialog}
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,113 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { Trash2 } from 'lucide-react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Button } from '@/components/ui/Button';
+import { useConfirmDialog } from '@/components/ui/ConfirmDialog';
+import { useTranslation } from '@/hooks/useTranslation';
+import { formatBytes } from '@/lib/localData';
+import { ReceiptArchiveStats, clearReceiptImages, receiptArchiveStats } from '@/lib/receiptImages';
+import { OcrLanguage } from '@/types/shopping';
+
+const LANGUAGES: OcrLanguage[] = ['auto', 'pl', 'de', 'en'];
+const RETENTION_MONTHS = [3, 6, 12, 24, 0];
+
+interface ReceiptSettingsProps {
+  language: OcrLanguage;
+  retentionMonths: number;
+  onChange: (changes: { receiptLanguage?: OcrLanguage; receiptRetentionMonths?: number }) => void;
+}
+
+export function ReceiptSettings({ language, retentionMonths, onChange }: ReceiptSettingsProps) {
+  const { t, locale } = useTranslation();
+  const [confirm, confirmDialog] = useConfirmDialog();
+  const [archive, setArchive] = useState<ReceiptArchiveStats | null>(null);
+
+  useEffect(() => {
+    void receiptArchiveStats().then(setArchive);
+  }, []);
+
+  const handlePurge = async () => {
+    const ok = await confirm({
+      title: t('settings.receipts.purge'),
+      message: t('settings.receipts.purgeConfirm'),
+      confirmLabel: t('settings.receipts.purge'),
+      cancelLabel: t('common.cancel'),
+      destructive: true,
+    });
+    if (!ok) return;
+    await clearReceiptImages();
+    setArchive(await receiptArchiveStats());
+  };
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>{t('settings.receipts.title')}</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <div className="space-y-2">
+          <label htmlFor="settings-receipt-language" className="block text-sm font-medium text-gray-700">
+            {t('settings.receipts.language')}
+          </label>
+          <select
+            id="settings-receipt-language"
+            value={language}
+            onChange={(e) => onChange({ receiptLanguage: e.target.value as OcrLanguage })}
+            className="rounded-md border px-3 py-2 text-sm"
+          >
+            {LANGUAGES.map((option) => (
+              <option key={option} value={option}>
+                {t(`settings.receipts.${option}` as const)}
+              </option>
+            ))}
+          </select>
+          <p className="text-xs text-muted-foreground">{t('settings.receipts.languageHint')}</p>
+        </div>
+
+        <div className="space-y-2">
+          <p className="text-sm font-medium text-gray-700">{t('settings.receipts.archive')}</p>
+          <div className="flex flex-wrap items-center gap-3">
+            <p className="flex-1 text-xs text-muted-foreground">
+              {!archive || archive.count === 0
+                ? t('settings.receipts.archiveEmpty')
+                : [
+                    t('settings.receipts.scans', { count: archive.count }),
+                    formatBytes(archive.bytes, locale),
+                    archive.oldest &&
+                      t('settings.receipts.archiveSince', {
+                        date: new Date(`${archive.oldest}T00:00:00`).toLocaleDateString(locale),
+                      }),
+                  ]
+                    .filter(Boolean)
+                    .join(' · ')}
+            </p>
+            <Button variant="outline" size="sm" disabled={!archive?.count} onClick={() => void handlePurge()}>
+              <Trash2 className="h-4 w-4 mr-2" />
+              {t('settings.receipts.purge')}
+            </Button>
+          </div>
+          <label htmlFor="settings-receipt-retention" className="block text-sm text-gray-700">
+            {t('settings.receipts.retention')}
+          </label>
+          <select
+            id="settings-receipt-retention"
+            value={retentionMonths}
+            onChange={(e) => onChange({ receiptRetentionMonths: Number(e.target.value) })}
+            className="rounded-md border px-3 py-2 text-sm"
+          >
+            {RETENTION_MONTHS.map((months) => (
+              <option key={months} value={months}>
+                {months === 0
+                  ? t('settings.receipts.retentionForever')
+                  : t('settings.receipts.months', { count: months })}
+              </option>
+            ))}
+          </select>
+        </div>
+        {confirmDialog}
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,63 @@
+"use client";
+
+import { ChangeEvent, useRef, useState } from 'react';
+import { Download, Upload } from 'lucide-react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Button } from '@/components/ui/Button';
+import { useTranslation } from '@/hooks/useTranslation';
+import { downloadFile, toJson } from '@/lib/exporters';
+import { AppSettings } from '@/lib/settings';
+import { exportSettings, parseSettingsImport } from '@/lib/settingsTransfer';
+import { localIsoDate } from '@/lib/time';
+
+interface SettingsTransferProps {
+  saved: AppSettings;
+  // Zaimportowane wartości trafiają do edytowanej kopii - użytkownik zatwierdza je przyciskiem Zapisz
+  onImport: (changes: Partial<AppSettings>) => void;
+}
+
+export function SettingsTransfer({ saved, onImport }: SettingsTransferProps) {
+  const { t, locale } = useTranslation();
+  const fileInput = useRef<HTMLInputElement>(null);
+  const [message, setMessage] = useState<{ ok: boolean; text: string } | null>(null);
+
+  const handleExport = () => {
+    downloadFile(toJson(exportSettings(saved)), `foodsave-ustawienia-${localIsoDate(new Date())}.json`, 'json');
+  };
+
+  const handleFile = async (e: ChangeEvent<HTMLInputElement>) => {
+    const file = e.target.files?.[0];
+    e.target.value = '';
+    if (!file) return;
+    try {
+      const changes = parseSettingsImport(await file.text(), locale);
+      onImport(changes);
+      setMessage({ ok: true, text: t('settings.transfer.imported', { count: Object.keys(changes).length }) });
+    } catch (error) {
+      setMessage({ ok: false, text: (error as Error).message });
+    }
+  };
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>{t('settings.transfer.title')}</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-3">
+        <p className="text-sm text-muted-foreground">{t('settings.transfer.hint')}</p>
+        <div className="flex flex-wrap gap-2">
+          <Button variant="outline" onClick={handleExport}>
+            <Download className="h-4 w-4 mr-2" />
+            {t('settings.transfer.export')}
+          </Button>
+          <Button variant="outline" onClick={() => fileInput.current?.click()}>
+            <Upload className="h-4 w-4 mr-2" />
+            {t('settings.transfer.import')}
+          </Button>
+          <input ref={fileInput} type="file" accept="application/json,.json" onChange={handleFile} className="hidden" />
+        </div>
+        {message && <p className={`text-sm ${message.ok ? 'text-green-700' : 'text-red-600'}`}>{message.text}</p>}
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,91 @@
+"use client";
+
+import { KeyboardEvent, useState } from 'react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Button } from '@/components/ui/Button';
+import { useTranslation } from '@/hooks/useTranslation';
+import {
+  SHORTCUT_ACTIONS,
+  ShortcutBindings,
+  ShortcutId,
+  eventToBinding,
+  findShortcut,
+  hasModifier,
+  resolveBindings,
+} from '@/lib/shortcuts';
+
+interface ShortcutSettingsProps {
+  value: ShortcutBindings;
+  onChange: (shortcuts: ShortcutBindings) => void;
+}
+
+export function ShortcutSettings({ value, onChange }: ShortcutSettingsProps) {
+  const { t } = useTranslation();
+  const [recording, setRecording] = useState<ShortcutId | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const bindings = resolveBindings(value);
+
+  const handleKeyDown = (id: ShortcutId) => (e: KeyboardEvent<HTMLButtonElement>) => {
+    // Nagrywany skrót nie może uruchomić akcji globalnej
+    e.preventDefault();
+    e.stopPropagation();
+    if (e.key === 'Escape') {
+      setRecording(null);
+      return;
+    }
+    const binding = eventToBinding(e.nativeEvent);
+    if (!binding) return;
+    if (!hasModifier(binding)) {
+      setError(t('settings.shortcuts.needsModifier'));
+      return;
+    }
+    const conflict = findShortcut(binding, value);
+    if (conflict && conflict.id !== id) {
+      setError(t('settings.shortcuts.conflict', { binding, action: t(conflict.labelKey) }));
+      return;
+    }
+    onChange({ ...value, [id]: binding });
+    setRecording(null);
+    setError(null);
+  };
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>{t('settings.shortcuts.title')}</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-3">
+        <ul className="divide-y">
+          {SHORTCUT_ACTIONS.map((action) => {
+            const isRecording = recording === action.id;
+            return (
+              <li key={action.id} className="flex items-center gap-3 py-2">
+                <span className="flex-1 text-sm">{t(action.labelKey)}</span>
+                <button
+                  type="button"
+                  onClick={() => {
+                    setRecording(action.id);
+                    setError(null);
+                  }}
+                  onKeyDown={isRecording ? handleKeyDown(action.id) : undefined}
+                  onBlur={() => isRecording && setRecording(null)}
+                  aria-label={t('settings.shortcuts.change', { action: t(action.labelKey) })}
+                  className={`min-w-[8rem] rounded-md border px-2 py-1 font-mono text-xs ${
+                    isRecording ? 'border-primary ring-2 ring-primary' : 'bg-muted'
+                  }`}
+                >
+                  {isRecording ? t('settings.shortcuts.press') : bindings[action.id]}
+                </button>
+              </li>
+            );
+          })}
+        </ul>
+        {error && <p className="text-sm text-red-600">{error}</p>}
+        <p className="text-xs text-muted-foreground">{t('settings.shortcuts.builtIn')}</p>
+        <Button variant="outline" size="sm" onClick={() => onChange({})} disabled={Object.keys(value).length === 0}>
+          {t('settings.shortcuts.reset')}
+        </Button>
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,63 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Button } from '@/components/ui/Button';
+import { useTranslation } from '@/hooks/useTranslation';
+import { canRegisterDeepLinks, registerDeepLinks } from '@/lib/deepLinks';
+
+interface StartupSettingsProps {
+  restoreLastPage: boolean;
+  onChange: (restoreLastPage: boolean) => void;
+}
+
+export function StartupSettings({ restoreLastPage, onChange }: StartupSettingsProps) {
+  const { t } = useTranslation();
+  const [linksSupported, setLinksSupported] = useState(false);
+  const [linksStatus, setLinksStatus] = useState<'idle' | 'registered' | 'failed'>('idle');
+
+  useEffect(() => {
+    setLinksSupported(canRegisterDeepLinks());
+  }, []);
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>{t('settings.startup.title')}</CardTitle>
+      </CardHeader>
+      <CardContent>
+        <label className="flex items-center gap-2 text-sm">
+          <input
+            type="checkbox"
+            checked={restoreLastPage}
+            onChange={(e) => onChange(e.target.checked)}
+            className="h-4 w-4"
+          />
+          {t('settings.startup.restoreLastPage')}
+        </label>
+        <p className="text-xs text-muted-foreground mt-1">{t('settings.startup.hint')}</p>
+
+        <div className="mt-4 space-y-2">
+          <p className="text-sm">{t('settings.startup.deepLinks')}</p>
+          <Button
+            variant="outline"
+            size="sm"
+            disabled={!linksSupported}
+            onClick={() => setLinksStatus(registerDeepLinks() ? 'registered' : 'failed')}
+          >
+            {t('settings.startup.registerDeepLinks')}
+          </Button>
+          <p className="text-xs text-muted-foreground">
+            {!linksSupported
+              ? t('settings.startup.deepLinksUnsupported')
+              : linksStatus === 'registered'
+                ? t('settings.startup.deepLinksRegistered')
+                : linksStatus === 'failed'
+                  ? t('settings.startup.deepLinksFailed')
+                  : t('settings.startup.deepLinksHint')}
+          </p>
+        </div>
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,124 @@
+"use client";
+
+import { useState } from 'react';
+import { Monitor, Moon, Sun } from 'lucide-react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { useTranslation } from '@/hooks/useTranslation';
+import { MessageKey } from '@/lib/i18n';
+import { ACCENT_PRESETS, DEFAULT_PALETTE, THEME_LABELS, ThemeMode, ThemePalette } from '@/lib/theme';
+
+const THEME_ICONS: Record<ThemeMode, typeof Sun> = {
+  light: Sun,
+  dark: Moon,
+  system: Monitor,
+};
+
+interface ThemeSettingsProps {
+  value: ThemeMode;
+  onChange: (theme: ThemeMode) => void;
+  palette: ThemePalette;
+  onPaletteChange: (palette: ThemePalette) => void;
+}
+
+const PALETTE_FIELDS: { key: 'background' | 'surface'; labelKey: MessageKey }[] = [
+  { key: 'background', labelKey: 'settings.appearance.background' },
+  { key: 'surface', labelKey: 'settings.appearance.surface' },
+];
+
+export function ThemeSettings({ value, onChange, palette, onPaletteChange }: ThemeSettingsProps) {
+  const { t } = useTranslation();
+  const [customPalette, setCustomPalette] = useState(palette.background !== '' || palette.surface !== '');
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>{t('settings.appearance.title')}</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <div role="radiogroup" aria-label={t('settings.appearance.theme')} className="inline-flex rounded-md border p-1 gap-1">
+          {(Object.keys(THEME_LABELS) as ThemeMode[]).map((mode) => {
+            const Icon = THEME_ICONS[mode];
+            const checked = value === mode;
+            return (
+              <button
+                key={mode}
+                type="button"
+                role="radio"
+                aria-checked={checked}
+                onClick={() => onChange(mode)}
+                className={`flex items-center gap-2 rounded px-3 py-1.5 text-sm transition-colors ${
+                  checked ? 'bg-primary text-primary-foreground' : 'hover:bg-accent'
+                }`}
+              >
+                <Icon className="h-4 w-4" />
+                {t(THEME_LABELS[mode])}
+              </button>
+            );
+          })}
+        </div>
+
+        <div>
+          <p className="text-sm font-medium mb-2">{t('settings.appearance.accent')}</p>
+          <div className="flex flex-wrap items-center gap-2">
+            <button
+              type="button"
+              onClick={() => onPaletteChange({ ...palette, accent: '' })}
+              className={`rounded-full border px-3 py-1 text-xs ${palette.accent === '' ? 'ring-2 ring-primary' : ''}`}
+            >
+              {t('common.default')}
+            </button>
+            {ACCENT_PRESETS.map((color) => (
+              <button
+                key={color}
+                type="button"
+                aria-label={`Akcent ${color}`}
+                onClick={() => onPaletteChange({ ...palette, accent: color })}
+                className={`h-7 w-7 rounded-full border ${palette.accent === color ? 'ring-2 ring-offset-2 ring-primary' : ''}`}
+                style={{ backgroundColor: color }}
+              />
+            ))}
+            <input
+              type="color"
+              aria-label={t('settings.appearance.customAccent')}
+              value={palette.accent || ACCENT_PRESETS[0]}
+              onChange={(e) => onPaletteChange({ ...palette, accent: e.target.value })}
+              className="h-7 w-10 cursor-pointer rounded border"
+            />
+          </div>
+        </div>
+
+        <div>
+          <label className="flex items-center gap-2 text-sm">
+            <input
+              type="checkbox"
+              checked={customPalette}
+              onChange={(e) => {
+                setCustomPalette(e.target.checked);
+                if (!e.target.checked) onPaletteChange({ ...DEFAULT_PALETTE, accent: palette.accent });
+              }}
+              className="h-4 w-4"
+            />
+            {t('settings.appearance.customPalette')}
+          </label>
+          {customPalette && (
+            <div className="mt-2 flex flex-wrap gap-4">
+              {PALETTE_FIELDS.map(({ key, labelKey }) => (
+                <label key={key} className="flex items-center gap-2 text-sm">
+                  <input
+                    type="color"
+                    value={palette[key] || '#ffffff'}
+                    onChange={(e) => onPaletteChange({ ...palette, [key]: e.target.value })}
+                    className="h-7 w-10 cursor-pointer rounded border"
+                  />
+                  {t(labelKey)}
+                </label>
+              ))}
+            </div>
+          )}
+        </div>
+
+        <p className="text-xs text-muted-foreground">{t('settings.appearance.previewHint')}</p>
+      </CardContent>
+    </Card>
+  );
+}
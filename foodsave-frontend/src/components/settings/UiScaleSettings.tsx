@@ -0,0 +1,43 @@
+"use client";
+
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/Card';
+import { Button } from '@/components/ui/Button';
+import { useTranslation } from '@/hooks/useTranslation';
+import { UI_SCALE_MAX, UI_SCALE_MIN, clampUiScale } from '@/lib/theme';
+
+const SCALE_STEP = 10;
+
+interface UiScaleSettingsProps {
+  value: number;
+  onChange: (scale: number) => void;
+}
+
+export function UiScaleSettings({ value, onChange }: UiScaleSettingsProps) {
+  const { t } = useTranslation();
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>{t('settings.scale.title')}</CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-2">
+        <div className="flex items-center gap-3">
+          <input
+            type="range"
+            min={UI_SCALE_MIN}
+            max={UI_SCALE_MAX}
+            step={SCALE_STEP}
+            value={value}
+            onChange={(e) => onChange(clampUiScale(Number(e.target.value)))}
+            aria-label={t('settings.scale.label')}
+            className="flex-1"
+          />
+          <span className="w-12 text-right text-sm font-medium tabular-nums">{value}%</span>
+          <Button variant="outline" size="sm" onClick={() => onChange(100)} disabled={value === 100}>
+            {t('common.default')}
+          </Button>
+        </div>
+        <p className="text-xs text-muted-foreground">{t('settings.scale.hint')}</p>
+      </CardContent>
+    </Card>
+  );
+}
@@ -0,0 +1,34 @@
+"use client";
+
+import { AlertCircle } from 'lucide-react';
+import { Button } from '@/components/ui/Button';
+import { useTranslation } from '@/hooks/useTranslation';
+
+interface UnsavedChangesBannerProps {
+  canSave: boolean;
+  onSave: () => void;
+  onDiscard: () => void;
+}
+
+export function UnsavedChangesBanner({ canSave, onSave, onDiscard }: UnsavedChangesBannerProps) {
+  const { t } = useTranslation();
+  return (
+    <div
+      role="status"
+      className="sticky top-0 z-10 flex flex-wrap items-center justify-between gap-3 rounded-md border border-yellow-300 bg-yellow-50 p-3"
+    >
+      <span className="flex items-center gap-2 text-sm text-yellow-800">
+        <AlertCircle className="h-4 w-4" />
+        {t('settings.unsaved')}
+      </span>
+      <div className="flex gap-2">
+        <Button variant="outline" size="sm" onClick={onDiscard}>
+          {t('settings.discard')}
+        </Button>
+        <Button size="sm" onClick={onSave} disabled={!canSave}>
+          {t('common.save')}
+        </Button>
+      </div>
+    </div>
+  );
+}
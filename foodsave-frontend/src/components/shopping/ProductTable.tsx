@@ -1,5 +1,8 @@
 import { Product, ProductTableProps } from '@/types/shopping';
 import { Button } from '../ui/Button';
+import { EmptyState } from '../ui/EmptyState';
+import { formatMoney, money } from '@/lib/money';
+import { useTranslation } from '@/hooks/useTranslation';
 
 export function ProductTable({
   products,
@@ -7,12 +10,14 @@ export function ProductTable({
   onDeleteProduct,
   onEditProduct
 }: ProductTableProps) {
+  const { t } = useTranslation();
+
   if (isLoading) {
     return <div className="h-40 animate-pulse bg-gray-100 rounded" />;
   }
 
   if (products.length === 0) {
-    return <div className="text-center p-4">Brak produktów do wyświetlenia</div>;
+    return <EmptyState message={t('shopping.productsEmpty')} align="center" />;
   }
 
   return (
@@ -21,17 +26,17 @@ export function ProductTable({
         <thead className="bg-gray-50">
           <tr>
             <th scope="col" className="px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider">
-              Nazwa
+              {t('shopping.column.name')}
             </th>
             <th scope="col" className="px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider">
-              Ilość
+              {t('shopping.column.quantity')}
             </th>
             <th scope="col" className="px-6 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider">
-              Cena
+              {t('shopping.column.price')}
             </th>
             {onDeleteProduct && (
               <th scope="col" className="px-6 py-3 text-right text-xs font-medium text-gray-500 uppercase tracking-wider">
-                Akcje
+                {t('shopping.column.actions')}
               </th>
             )}
           </tr>
@@ -49,7 +54,7 @@ export function ProductTable({
                 <div className="text-sm text-gray-900">{product.quantity} {product.unit}</div>
               </td>
               <td className="px-6 py-4 whitespace-nowrap">
-                <div className="text-sm text-gray-900">{product.price} zł</div>
+                <div className="text-sm text-gray-900">{formatMoney(money(product.price))}</div>
               </td>
               {onDeleteProduct && (
                 <td className="px-6 py-4 whitespace-nowrap text-right text-sm font-medium">
@@ -58,14 +63,14 @@ export function ProductTable({
                       variant="ghost"
                       size="sm"
                       onClick={() => {
-                        const newName = prompt('Podaj nową nazwę produktu:', product.name);
+                        const newName = prompt(t('shopping.renamePrompt'), product.name);
                         if (newName) {
                           onEditProduct(product.id, { name: newName });
                         }
                       }}
                       className="mr-2"
                     >
-                      Edytuj
+                      {t('common.edit')}
                     </Button>
                   )}
                   <Button
@@ -73,7 +78,7 @@ export function ProductTable({
                     size="sm"
                     onClick={() => onDeleteProduct(product.id)}
                   >
-                    Usuń
+                    {t('common.delete')}
                   </Button>
                 </td>
               )}
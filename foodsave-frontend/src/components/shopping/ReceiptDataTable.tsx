@@ -1,21 +1,48 @@
 import React, { useState } from 'react';
+import { Combine, Plus, Scissors, Trash2 } from 'lucide-react';
 import { Button } from '../ui/Button';
 import { Input } from '../ui/Input';
+import { ReceiptLineSplitDialog } from './ReceiptLineSplitDialog';
 import { Product } from '@/types/shopping';
+import { formatMoney, lineTotal, sumMoney } from '@/lib/money';
+import { mergeReceiptLines } from '@/lib/receiptLines';
+import { suggestExpiryDate } from '@/lib/shelfLife';
+import { useTranslation } from '@/hooks/useTranslation';
 
 interface ReceiptDataTableProps {
   products: Product[];
   onSave: (products: Product[]) => void;
   onCancel: () => void;
+  onChange?: (products: Product[]) => void;
+  purchaseDate?: string; // YYYY-MM-DD from the receipt, start of the suggested shelf life
 }
 
-export function ReceiptDataTable({ products, onSave, onCancel }: ReceiptDataTableProps) {
-  const [editedProducts, setEditedProducts] = useState<Product[]>(
-    products.map(product => ({
-      ...product,
-      expiry_date: product.expiry_date || ''
-    }))
-  );
+// OCR rarely finds expiry dates; missing ones are prefilled from the shelf-life table
+function withSuggestedExpiry(products: Product[], purchaseDate?: string): { products: Product[]; suggested: number[] } {
+  // OCR podaje datę w różnych formatach - nieczytelna oznacza liczenie od dzisiaj
+  const from = purchaseDate && /^\d{4}-\d{2}-\d{2}$/.test(purchaseDate) ? new Date(`${purchaseDate}T00:00:00`) : new Date();
+  const suggested: number[] = [];
+  const prefilled = products.map((product, index) => {
+    if (product.expiry_date) return product;
+    const expiry = suggestExpiryDate(product.name, product.category, from);
+    if (expiry) suggested.push(index);
+    return { ...product, expiry_date: expiry || '' };
+  });
+  return { products: prefilled, suggested };
+}
+
+export function ReceiptDataTable({ products, onSave, onCancel, onChange, purchaseDate }: ReceiptDataTableProps) {
+  const { t } = useTranslation();
+  const [initial] = useState(() => withSuggestedExpiry(products, purchaseDate));
+  const [editedProducts, setEditedProducts] = useState<Product[]>(initial.products);
+  // Wiersze z szacowaną datą - znika po ręcznej zmianie daty
+  const [suggested, setSuggested] = useState<number[]>(initial.suggested);
+  const [splitting, setSplitting] = useState<number | null>(null);
+
+  const updateProducts = (updatedProducts: Product[]) => {
+    setEditedProducts(updatedProducts);
+    onChange?.(updatedProducts);
+  };
 
   const handleProductChange = (index: number, field: keyof Product, value: any) => {
     const updatedProducts = [...editedProducts];
@@ -23,29 +50,59 @@ export function ReceiptDataTable({ products, onSave, onCancel }: ReceiptDataTabl
       ...updatedProducts[index],
       [field]: value
     };
-    setEditedProducts(updatedProducts);
+    updateProducts(updatedProducts);
+    if (field === 'expiry_date') setSuggested(prev => prev.filter(i => i !== index));
+  };
+
+  // OCR skleił dwa produkty w jedną linię - obie części dziedziczą datę, także szacowaną
+  const handleSplit = (index: number, parts: [Product, Product]) => {
+    updateProducts([...editedProducts.slice(0, index), ...parts, ...editedProducts.slice(index + 1)]);
+    setSuggested(prev => prev.flatMap(i => (i < index ? [i] : i === index ? [i, i + 1] : [i + 1])));
+    setSplitting(null);
+  };
+
+  // OCR rozbił produkt na dwie linie - łączymy wiersz z następnym
+  const handleMerge = (index: number) => {
+    const merged = mergeReceiptLines(editedProducts[index], editedProducts[index + 1]);
+    updateProducts([...editedProducts.slice(0, index), merged, ...editedProducts.slice(index + 2)]);
+    setSuggested(prev => Array.from(new Set(prev.map(i => (i > index ? i - 1 : i)))));
+  };
+
+  const handleAddRow = () => {
+    updateProducts([...editedProducts, { id: `row-${Date.now()}`, name: '', quantity: 1, unit: 'szt.', price: 0 }]);
+  };
+
+  const handleRemoveRow = (index: number) => {
+    updateProducts(editedProducts.filter((_, i) => i !== index));
+    setSuggested(prev => prev.filter(i => i !== index).map(i => (i > index ? i - 1 : i)));
   };
 
+  // Puste wiersze (np. dodane ręcznie i niewypełnione) nie trafiają do zapisu
+  const namedProducts = editedProducts.filter(p => p.name.trim());
+
   const handleSave = () => {
-    onSave(editedProducts);
+    onSave(namedProducts);
   };
 
+  const itemsTotal = sumMoney(editedProducts.map(p => lineTotal(p.price, p.quantity)));
+
   return (
     <div className="bg-white rounded-lg shadow overflow-hidden">
       <div className="p-4 border-b">
-        <h3 className="text-lg font-semibold">Dane z paragonu</h3>
-        <p className="text-sm text-gray-500">Możesz edytować dane przed zapisaniem</p>
+        <h3 className="text-lg font-semibold">{t('shopping.receipt.title')}</h3>
+        <p className="text-sm text-gray-500">{t('shopping.receipt.hint')}</p>
       </div>
       <div className="overflow-x-auto">
         <table className="min-w-full divide-y divide-gray-200">
           <thead className="bg-gray-50">
             <tr>
-              <th className="px-4 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider">Produkt</th>
-              <th className="px-4 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider">Ilość</th>
-              <th className="px-4 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider">Jednostka</th>
-              <th className="px-4 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider">Cena</th>
-              <th className="px-4 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider">Kategoria</th>
-              <th className="px-4 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider">Data ważności</th>
+              <th className="px-4 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider">{t('shopping.column.product')}</th>
+              <th className="px-4 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider">{t('shopping.column.quantity')}</th>
+              <th className="px-4 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider">{t('shopping.column.unit')}</th>
+              <th className="px-4 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider">{t('shopping.column.price')}</th>
+              <th className="px-4 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider">{t('shopping.column.category')}</th>
+              <th className="px-4 py-3 text-left text-xs font-medium text-gray-500 uppercase tracking-wider">{t('shopping.column.expiry')}</th>
+              <th className="px-4 py-3" aria-label={t('shopping.column.actions')} />
             </tr>
           </thead>
           <tbody className="bg-white divide-y divide-gray-200">
@@ -64,6 +121,7 @@ export function ReceiptDataTable({ products, onSave, onCancel }: ReceiptDataTabl
                     value={product.quantity}
                     onChange={e => handleProductChange(index, 'quantity', parseFloat(e.target.value))}
                     className="w-20"
+                    step={product.sold_by_weight ? '0.001' : undefined}
                   />
                 </td>
                 <td className="px-4 py-2">
@@ -71,7 +129,7 @@ export function ReceiptDataTable({ products, onSave, onCancel }: ReceiptDataTabl
                     value={product.unit || ''}
                     onChange={e => handleProductChange(index, 'unit', e.target.value)}
                     className="w-16"
-                    placeholder="szt."
+                    placeholder={t('shopping.unitPiece')}
                   />
                 </td>
                 <td className="px-4 py-2">
@@ -81,7 +139,13 @@ export function ReceiptDataTable({ products, onSave, onCancel }: ReceiptDataTabl
                     onChange={e => handleProductChange(index, 'price', parseFloat(e.target.value))}
                     className="w-24"
                     step="0.01"
+                    aria-label={product.sold_by_weight ? t('shopping.receipt.pricePerKg') : t('shopping.column.price')}
                   />
+                  {product.sold_by_weight && (
+                    <span className="text-xs text-gray-500" title={t('shopping.receipt.byWeight')}>
+                      zł/kg · {formatMoney(lineTotal(product.price, product.quantity))}
+                    </span>
+                  )}
                 </td>
                 <td className="px-4 py-2">
                   <Input
@@ -97,18 +161,70 @@ export function ReceiptDataTable({ products, onSave, onCancel }: ReceiptDataTabl
                     onChange={e => handleProductChange(index, 'expiry_date', e.target.value)}
                     className="w-full"
                   />
+                  {suggested.includes(index) && (
+                    <span className="text-xs text-gray-500" title={t('shopping.receipt.estimatedHint')}>
+                      {t('shopping.receipt.estimated')}
+                    </span>
+                  )}
+                </td>
+                <td className="px-4 py-2 whitespace-nowrap">
+                  <Button
+                    variant="ghost"
+                    size="icon"
+                    onClick={() => setSplitting(index)}
+                    aria-label={t('shopping.receipt.splitLabel', { name: product.name })}
+                    title={t('shopping.receipt.splitTitle')}
+                  >
+                    <Scissors className="h-4 w-4" />
+                  </Button>
+                  <Button
+                    variant="ghost"
+                    size="icon"
+                    onClick={() => handleRemoveRow(index)}
+                    aria-label={t('shopping.receipt.removeLabel', { name: product.name })}
+                    title={t('shopping.receipt.removeTitle')}
+                  >
+                    <Trash2 className="h-4 w-4" />
+                  </Button>
+                  {index < editedProducts.length - 1 && (
+                    <Button
+                      variant="ghost"
+                      size="icon"
+                      onClick={() => handleMerge(index)}
+                      aria-label={t('shopping.receipt.mergeLabel', { name: product.name })}
+                      title={t('shopping.receipt.mergeTitle')}
+                    >
+                      <Combine className="h-4 w-4" />
+                    </Button>
+                  )}
                 </td>
               </tr>
             ))}
           </tbody>
         </table>
       </div>
-      <div className="p-4 border-t flex justify-end space-x-3">
+      {splitting !== null && editedProducts[splitting] && (
+        <ReceiptLineSplitDialog
+          product={editedProducts[splitting]}
+          onClose={() => setSplitting(null)}
+          onSplit={parts => handleSplit(splitting, parts)}
+        />
+      )}
+      <div className="px-4 py-2 border-t">
+        <Button variant="ghost" size="sm" onClick={handleAddRow}>
+          <Plus className="mr-1 h-4 w-4" />
+          {t('shopping.receipt.addRow')}
+        </Button>
+      </div>
+      <div className="p-4 border-t flex items-center justify-end space-x-3">
+        <span className="mr-auto text-sm text-gray-600">
+          {t('shopping.receipt.total')} <span className="font-semibold">{formatMoney(itemsTotal)}</span>
+        </span>
         <Button variant="secondary" onClick={onCancel}>
-          Anuluj
+          {t('common.cancel')}
         </Button>
-        <Button onClick={handleSave}>
-          Zapisz produkty
+        <Button onClick={handleSave} disabled={namedProducts.length === 0}>
+          {t('shopping.receipt.save')}
         </Button>
       </div>
     </div>
@@ -0,0 +1,66 @@
+"use client";
+
+import { useState } from 'react';
+import { Download } from 'lucide-react';
+import { Button } from '@/components/ui/Button';
+import { Input } from '@/components/ui/Input';
+import { useTranslation } from '@/hooks/useTranslation';
+import { getPresetRange } from '@/components/analytics/DateRangeSelector';
+import { ApiService } from '@/services/ApiService';
+import { ExportFormat, downloadFile, shoppingTripsToCsv, toJson } from '@/lib/exporters';
+
+// Eksport zapisanych paragonów z zakresu dat do CSV/JSON
+export function ReceiptExport() {
+  const { t } = useTranslation();
+  const [range, setRange] = useState(() => getPresetRange('month'));
+  const [isExporting, setIsExporting] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  const handleExport = async (format: ExportFormat) => {
+    setIsExporting(true);
+    setError(null);
+    try {
+      const trips = await ApiService.getShoppingTrips({ date_from: range.from, date_to: range.to, limit: 1000 });
+      if (trips.length === 0) {
+        setError(t('shopping.export.empty'));
+        return;
+      }
+      const content = format === 'csv' ? shoppingTripsToCsv(trips) : toJson(trips);
+      downloadFile(content, `paragony_${range.from}_${range.to}.${format}`, format);
+    } catch (err: any) {
+      setError(err?.message || t('shopping.export.error'));
+    } finally {
+      setIsExporting(false);
+    }
+  };
+
+  return (
+    <div className="space-y-3">
+      <div className="flex flex-wrap items-end gap-2">
+        <Input
+          type="date"
+          label={t('shopping.export.from')}
+          value={range.from}
+          max={range.to}
+          onChange={(e) => setRange({ ...range, from: e.target.value })}
+        />
+        <Input
+          type="date"
+          label={t('shopping.export.to')}
+          value={range.to}
+          min={range.from}
+          onChange={(e) => setRange({ ...range, to: e.target.value })}
+        />
+        <Button variant="outline" onClick={() => handleExport('csv')} isLoading={isExporting}>
+          <Download className="h-4 w-4 mr-2" />
+          CSV
+        </Button>
+        <Button variant="outline" onClick={() => handleExport('json')} isLoading={isExporting}>
+          <Download className="h-4 w-4 mr-2" />
+          JSON
+        </Button>
+      </div>
+      {error && <p className="text-sm text-red-600">{error}</p>}
+    </div>
+  );
+}
@@ -0,0 +1,148 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { useSearchParams } from 'next/navigation';
+import { useQuery } from '@tanstack/react-query';
+import { ChevronDown, ChevronRight, Printer, RefreshCw } from 'lucide-react';
+import { EmptyState } from '@/components/ui/EmptyState';
+import { LoadingState } from '@/components/ui/LoadingState';
+import { ReceiptReprocessDialog } from './ReceiptReprocessDialog';
+import { ApiService } from '@/services/ApiService';
+import { ShoppingTrip } from '@/types/shopping';
+import { Money, formatMoney, lineTotal, money, sumMoney } from '@/lib/money';
+import { buildPrintDocument, printDocument, receiptPrintHtml } from '@/lib/print';
+import { getReceiptImage } from '@/lib/receiptImages';
+import { scrollBehavior } from '@/lib/theme';
+import { useTranslation } from '@/hooks/useTranslation';
+
+export function receiptTotal(trip: ShoppingTrip): Money {
+  if (trip.total_amount != null) return money(trip.total_amount);
+  return sumMoney(trip.products.map((p) => lineTotal(p.unit_price, p.quantity)));
+}
+
+// Historia zapisanych paragonów; ?receipt=<id> rozwija i przewija do wpisu,
+// a paragon spoza ostatnich 50 pobiera osobno
+export function ReceiptHistory() {
+  const { t, locale } = useTranslation();
+  const searchParams = useSearchParams();
+  const highlighted = searchParams?.get('receipt');
+  const highlightedId = highlighted ? Number(highlighted) : null;
+  const [expanded, setExpanded] = useState<number | null>(highlightedId);
+  // Skan rozwiniętego paragonu, jeśli został na tym urządzeniu
+  const [image, setImage] = useState<File | undefined>();
+  const [reprocessing, setReprocessing] = useState<ShoppingTrip | null>(null);
+
+  const { data: trips = [], isLoading, error } = useQuery({
+    queryKey: ['shopping-trips', 'history'],
+    queryFn: ({ signal }) => ApiService.getShoppingTrips({ limit: 50 }, signal),
+  });
+
+  const linkedMissing =
+    highlightedId != null && !isLoading && !error && !trips.some((trip) => trip.id === highlightedId);
+  const { data: linkedTrip, isError: linkedNotFound } = useQuery({
+    queryKey: ['shopping-trips', 'detail', highlightedId],
+    queryFn: ({ signal }) => ApiService.getShoppingTrip(highlightedId as number, signal),
+    enabled: linkedMissing && Number.isInteger(highlightedId),
+  });
+  const shown = linkedMissing && linkedTrip ? [linkedTrip, ...trips] : trips;
+  const notFound = linkedMissing && (linkedNotFound || !Number.isInteger(highlightedId));
+
+  useEffect(() => {
+    if (!highlighted || shown.length === 0) return;
+    setExpanded(Number(highlighted));
+    document.getElementById(`receipt-${highlighted}`)?.scrollIntoView({ behavior: scrollBehavior(), block: 'center' });
+  }, [highlighted, shown.length]);
+
+  useEffect(() => {
+    setImage(undefined);
+    if (expanded == null) return;
+    let cancelled = false;
+    void getReceiptImage(expanded).then((file) => {
+      if (!cancelled) setImage(file);
+    });
+    return () => {
+      cancelled = true;
+    };
+  }, [expanded]);
+
+  if (isLoading) return <LoadingState />;
+  if (error) return <p className="text-sm text-red-600">{t('shopping.history.error')}</p>;
+  const notFoundNote = notFound && <p className="text-sm text-gray-500 mb-2">{t('shopping.history.notFound')}</p>;
+  if (shown.length === 0) {
+    return (
+      <>
+        {notFoundNote}
+        <EmptyState message={t('shopping.history.empty')} />
+      </>
+    );
+  }
+
+  return (
+    <>
+      {notFoundNote}
+      <ul className="divide-y">
+        {shown.map((trip) => {
+          const isOpen = expanded === trip.id;
+          return (
+            <li
+              key={trip.id}
+              id={`receipt-${trip.id}`}
+              className={String(trip.id) === highlighted ? 'bg-primary/5 rounded' : undefined}
+            >
+              <button
+                type="button"
+                onClick={() => setExpanded(isOpen ? null : trip.id)}
+                aria-expanded={isOpen}
+                className="w-full flex items-center justify-between py-2 px-1 text-left hover:bg-accent rounded"
+              >
+                <span className="flex items-center gap-2">
+                  {isOpen ? <ChevronDown className="h-4 w-4" /> : <ChevronRight className="h-4 w-4" />}
+                  <span className="font-medium">{trip.store_name}</span>
+                  <span className="text-sm text-gray-500">{trip.trip_date}</span>
+                </span>
+                <span className="font-semibold">{formatMoney(receiptTotal(trip))}</span>
+              </button>
+              {isOpen && (
+                <ul className="pl-8 pb-2 text-sm text-gray-700 space-y-1">
+                  {trip.products.map((p) => (
+                    <li key={p.id} className="flex justify-between">
+                      <span>
+                        {p.name} <span className="text-gray-500">× {p.quantity}{p.unit ? ` ${p.unit}` : ''}</span>
+                      </span>
+                      <span>{p.unit_price != null ? formatMoney(money(p.unit_price)) : '–'}</span>
+                    </li>
+                  ))}
+                  <li>
+                    <button
+                      type="button"
+                      onClick={() =>
+                        void printDocument(buildPrintDocument(t('shopping.history.printTitle'), receiptPrintHtml(trip, receiptTotal(trip), locale), undefined, locale))
+                      }
+                      className="inline-flex items-center gap-1 text-xs text-gray-500 hover:text-gray-800"
+                    >
+                      <Printer className="h-3 w-3" />
+                      {t('shopping.history.print')}
+                    </button>
+                    {image && (
+                      <button
+                        type="button"
+                        onClick={() => setReprocessing(trip)}
+                        className="ml-4 inline-flex items-center gap-1 text-xs text-gray-500 hover:text-gray-800"
+                      >
+                        <RefreshCw className="h-3 w-3" />
+                        {t('shopping.history.reprocess')}
+                      </button>
+                    )}
+                  </li>
+                </ul>
+              )}
+            </li>
+          );
+        })}
+      </ul>
+      {reprocessing && image && (
+        <ReceiptReprocessDialog trip={reprocessing} image={image} onClose={() => setReprocessing(null)} />
+      )}
+    </>
+  );
+}
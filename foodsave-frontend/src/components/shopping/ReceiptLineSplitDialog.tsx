@@ -0,0 +1,69 @@
+"use client";
+
+import { useState } from 'react';
+import { Modal } from '../ui/Modal';
+import { Button } from '../ui/Button';
+import { Input } from '../ui/Input';
+import { useTranslation } from '@/hooks/useTranslation';
+import { formatMoney, lineTotal, money, subtractMoney } from '@/lib/money';
+import { splitReceiptLine, suggestSplitNames } from '@/lib/receiptLines';
+import { Product } from '@/types/shopping';
+
+interface ReceiptLineSplitDialogProps {
+  product: Product;
+  onClose: () => void;
+  onSplit: (parts: [Product, Product]) => void;
+}
+
+// Rendered only while open; the second part's price follows from the line total
+export function ReceiptLineSplitDialog({ product, onClose, onSplit }: ReceiptLineSplitDialogProps) {
+  const { t } = useTranslation();
+  const [names, setNames] = useState<[string, string]>(() => suggestSplitNames(product.name));
+  const total = lineTotal(product.price, product.quantity);
+  const [firstPrice, setFirstPrice] = useState(() => (total.minor / 200).toFixed(2));
+
+  const first = money(parseFloat(firstPrice.replace(',', '.')));
+  const invalid = !names[0].trim() || !names[1].trim() || first.minor < 0 || first.minor > total.minor;
+
+  return (
+    <Modal open onClose={onClose} title={t('shopping.split.title')} size="lg">
+      <div className="space-y-4">
+        <p className="text-sm text-gray-600">
+          {t('shopping.split.hint', { name: product.name, total: formatMoney(total) })}
+        </p>
+        <div className="grid grid-cols-[1fr_auto] gap-2 items-center">
+          <Input
+            value={names[0]}
+            onChange={e => setNames([e.target.value, names[1]])}
+            aria-label={t('shopping.split.firstName')}
+          />
+          <Input
+            type="number"
+            min={0}
+            step="0.01"
+            value={firstPrice}
+            onChange={e => setFirstPrice(e.target.value)}
+            aria-label={t('shopping.split.firstPrice')}
+            className="w-28"
+          />
+          <Input
+            value={names[1]}
+            onChange={e => setNames([names[0], e.target.value])}
+            aria-label={t('shopping.split.secondName')}
+          />
+          <span className="w-28 px-3 text-sm text-gray-700">
+            {first.minor <= total.minor ? formatMoney(subtractMoney(total, first)) : '—'}
+          </span>
+        </div>
+        <div className="flex justify-end gap-2">
+          <Button variant="outline" onClick={onClose}>
+            {t('common.cancel')}
+          </Button>
+          <Button onClick={() => onSplit(splitReceiptLine(product, names, first))} disabled={invalid}>
+            {t('shopping.split.submit')}
+          </Button>
+        </div>
+      </div>
+    </Modal>
+  );
+}
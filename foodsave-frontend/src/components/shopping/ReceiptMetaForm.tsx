@@ -0,0 +1,40 @@
+"use client";
+
+import React from 'react';
+import { Input } from '../ui/Input';
+import { useTranslation } from '@/hooks/useTranslation';
+
+export interface ReceiptMeta {
+  store?: string;
+  date?: string;
+}
+
+interface ReceiptMetaFormProps {
+  meta: ReceiptMeta;
+  onChange: (meta: ReceiptMeta) => void;
+}
+
+// Sklep i data dla paragonu wpisywanego ręcznie - przy OCR pochodzą z analizy
+export function ReceiptMetaForm({ meta, onChange }: ReceiptMetaFormProps) {
+  const { t } = useTranslation();
+  return (
+    <div className="grid grid-cols-1 sm:grid-cols-2 gap-3 p-4 border-b">
+      <label className="text-sm">
+        <span className="block mb-1 text-gray-600">{t('shopping.meta.store')}</span>
+        <Input
+          value={meta.store || ''}
+          onChange={e => onChange({ ...meta, store: e.target.value })}
+          placeholder={t('shopping.meta.storePlaceholder')}
+        />
+      </label>
+      <label className="text-sm">
+        <span className="block mb-1 text-gray-600">{t('shopping.meta.date')}</span>
+        <Input
+          type="date"
+          value={meta.date || ''}
+          onChange={e => onChange({ ...meta, date: e.target.value })}
+        />
+      </label>
+    </div>
+  );
+}
@@ -0,0 +1,136 @@
+"use client";
+
+import { useState } from 'react';
+import { Modal } from '../ui/Modal';
+import { Button } from '../ui/Button';
+import { ApiService } from '@/services/ApiService';
+import { reportError } from '@/lib/errors';
+import { formatMoney, money, subtractMoney } from '@/lib/money';
+import { ReceiptDiff, ReceiptLine, ReceiptLineDiff, ReceiptLineField, diffReceipts } from '@/lib/receiptDiff';
+import { productFromOcrItem } from '@/lib/receiptLines';
+import { loadSettings } from '@/lib/settings';
+import { useTranslation } from '@/hooks/useTranslation';
+import { OCRItem, OcrLanguage, OcrOptions, ShoppingTrip } from '@/types/shopping';
+
+interface ReceiptReprocessDialogProps {
+  trip: ShoppingTrip;
+  image: File;
+  onClose: () => void;
+}
+
+const STATUS_STYLES: Record<ReceiptLineDiff['status'], string> = {
+  same: 'text-gray-500',
+  changed: 'bg-amber-50',
+  removed: 'bg-red-50 text-red-700 line-through',
+  added: 'bg-green-50 text-green-700',
+};
+
+function describe(line: ReceiptLine | undefined, fields: ReceiptLineField[], pieceUnit: string) {
+  if (!line) return <span className="text-gray-400">—</span>;
+  const mark = (field: ReceiptLineField, text: string) =>
+    fields.includes(field) ? <strong className="font-semibold">{text}</strong> : text;
+  return (
+    <>
+      {mark('name', line.name)}
+      <span className="text-gray-500">
+        {' · '}
+        {mark('quantity', String(line.quantity))} {mark('unit', line.unit || pieceUnit)} ×{' '}
+        {mark('price', line.price != null ? formatMoney(money(line.price)) : '–')}
+      </span>
+    </>
+  );
+}
+
+// Ponowny OCR zapisanego skanu z innymi opcjami; wynik tylko do porównania, historia się nie zmienia
+export function ReceiptReprocessDialog({ trip, image, onClose }: ReceiptReprocessDialogProps) {
+  const { t } = useTranslation();
+  const [options, setOptions] = useState<Required<OcrOptions>>(() => ({
+    language: loadSettings().receiptLanguage,
+    preprocess: true,
+  }));
+  const [isRunning, setIsRunning] = useState(false);
+  const [diff, setDiff] = useState<ReceiptDiff | null>(null);
+
+  const handleRun = async () => {
+    setIsRunning(true);
+    try {
+      const ocrRes: any = await ApiService.uploadReceipt(image, undefined, undefined, options);
+      const analyzeRes: any = await ApiService.analyzeReceipt(ocrRes?.data?.text || '', ocrRes?.data?.language);
+      const items: OCRItem[] = analyzeRes?.data?.items || [];
+      const before = trip.products.map((p) => ({ name: p.name, quantity: p.quantity, unit: p.unit, price: p.unit_price }));
+      setDiff(diffReceipts(before, items.map(productFromOcrItem)));
+    } catch (err) {
+      reportError(err, t('shopping.reprocess.error'), () => void handleRun());
+    } finally {
+      setIsRunning(false);
+    }
+  };
+
+  const changes = diff ? diff.lines.filter((line) => line.status !== 'same').length : 0;
+
+  return (
+    <Modal open onClose={onClose} title={t('shopping.reprocess.title', { store: trip.store_name, date: trip.trip_date })} size="lg">
+      <div className="space-y-4">
+        <div className="flex flex-wrap items-end gap-4 text-sm">
+          <label>
+            <span className="block mb-1 text-gray-600">{t('shopping.reprocess.language')}</span>
+            <select
+              value={options.language}
+              onChange={(e) => setOptions({ ...options, language: e.target.value as OcrLanguage })}
+              className="rounded-md border px-2 py-1"
+            >
+              <option value="auto">{t('settings.receipts.auto')}</option>
+              <option value="pl">{t('settings.receipts.pl')}</option>
+              <option value="de">{t('settings.receipts.de')}</option>
+              <option value="en">{t('settings.receipts.en')}</option>
+            </select>
+          </label>
+          <label className="flex items-center gap-2">
+            <input
+              type="checkbox"
+              checked={options.preprocess}
+              onChange={(e) => setOptions({ ...options, preprocess: e.target.checked })}
+            />
+            {t('shopping.reprocess.preprocess')}
+          </label>
+          <Button onClick={() => void handleRun()} isLoading={isRunning} className="ml-auto">
+            {diff ? t('shopping.reprocess.runAgain') : t('shopping.reprocess.run')}
+          </Button>
+        </div>
+
+        {diff && (
+          <>
+            <p className="text-sm text-gray-600">
+              {changes === 0 ? t('shopping.reprocess.same') : t('shopping.reprocess.changes', { count: changes })}{' '}
+              {t('shopping.reprocess.total')} {formatMoney(diff.totalBefore)} → {formatMoney(diff.totalAfter)}
+              {diff.totalAfter.minor !== diff.totalBefore.minor &&
+                ` (${diff.totalAfter.minor > diff.totalBefore.minor ? '+' : ''}${formatMoney(
+                  subtractMoney(diff.totalAfter, diff.totalBefore),
+                )})`}
+            </p>
+            <table className="w-full text-sm">
+              <thead className="text-left text-xs text-gray-500">
+                <tr>
+                  <th className="py-1 font-medium">{t('shopping.reprocess.before')}</th>
+                  <th className="py-1 font-medium">{t('shopping.reprocess.after')}</th>
+                </tr>
+              </thead>
+              <tbody className="divide-y">
+                {diff.lines.map((line, index) => (
+                  <tr key={index} className={STATUS_STYLES[line.status]}>
+                    <td className="py-1 pr-2">
+                      {line.status !== 'added' && describe(line.before, line.status === 'changed' ? line.fields : [], t('shopping.unitPiece'))}
+                    </td>
+                    <td className="py-1">
+                      {line.status !== 'removed' && describe(line.after, line.status === 'changed' ? line.fields : [], t('shopping.unitPiece'))}
+                    </td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          </>
+        )}
+      </div>
+    </Modal>
+  );
+}
@@ -4,15 +4,23 @@ import React, { useState } from 'react';
 import Image from 'next/image';
 import { Button } from '../ui/Button';
 import { Card } from '../ui/Card';
+import { Modal } from '../ui/Modal';
 import { ReceiptUploaderProps } from '@/types/shopping';
+import { useThumbnail } from '@/hooks/useThumbnail';
+import { useTranslation } from '@/hooks/useTranslation';
 
 export function ReceiptUploader({
   onUpload,
-  isUploading
+  isUploading,
+  onManualEntry
 }: ReceiptUploaderProps) {
+  const { t } = useTranslation();
   const [selectedFile, setSelectedFile] = useState<File | null>(null);
   const [previewUrl, setPreviewUrl] = useState<string | null>(null);
   const [isConfirming, setIsConfirming] = useState(false);
+  const [isPreviewOpen, setIsPreviewOpen] = useState(false);
+  // Mała miniatura w formularzu, pełny obraz dopiero w podglądzie
+  const thumbnailUrl = useThumbnail(selectedFile);
 
   const handleFileChange = (e: React.ChangeEvent<HTMLInputElement>) => {
     if (e.target.files && e.target.files[0]) {
@@ -45,7 +53,7 @@ export function ReceiptUploader({
 
   return (
     <Card className="p-4 mb-6">
-      <h3 className="text-lg font-semibold mb-3">Wgraj paragon</h3>
+      <h3 className="text-lg font-semibold mb-3">{t('shopping.upload.title')}</h3>
       {!isConfirming ? (
         <div className="flex flex-wrap items-center gap-3">
           <input
@@ -55,34 +63,58 @@ export function ReceiptUploader({
             className="flex-grow"
             disabled={isUploading}
           />
+          {onManualEntry && (
+            <Button variant="link" onClick={onManualEntry} disabled={isUploading}>
+              {t('shopping.upload.manual')}
+            </Button>
+          )}
         </div>
       ) : (
         <div className="mt-4">
           <div className="mb-4">
-            <p className="text-sm mb-2">Wybrany plik: {selectedFile?.name}</p>
+            <p className="text-sm mb-2">{t('shopping.upload.selected', { name: selectedFile?.name ?? '' })}</p>
             <div className="border rounded-md overflow-hidden mb-4 max-h-96">
               {selectedFile && selectedFile.type.startsWith('image/') && previewUrl ? (
-                <Image src={previewUrl} alt="Podgląd" className="w-full object-contain" width={300} height={300} />
+                <button
+                  type="button"
+                  onClick={() => setIsPreviewOpen(true)}
+                  className="block w-full cursor-zoom-in"
+                  aria-label={t('shopping.upload.zoom')}
+                >
+                  <Image
+                    src={thumbnailUrl ?? previewUrl}
+                    alt={t('shopping.upload.previewAlt')}
+                    className="w-full object-contain"
+                    width={300}
+                    height={300}
+                    unoptimized
+                  />
+                </button>
               ) : (
                 <div className="p-4 bg-gray-100 text-center">
-                  <p>Podgląd niedostępny dla plików PDF</p>
+                  <p>{t('shopping.upload.noPreview')}</p>
                 </div>
               )}
             </div>
+            {previewUrl && (
+              <Modal open={isPreviewOpen} onClose={() => setIsPreviewOpen(false)} title={selectedFile?.name} size="lg">
+                <Image src={previewUrl} alt={t('shopping.upload.imageAlt')} className="w-full object-contain" width={1200} height={1600} />
+              </Modal>
+            )}
             <div className="flex gap-3">
               <Button
                 onClick={handleUpload}
                 disabled={isUploading}
                 isLoading={isUploading}
               >
-                {isUploading ? 'Przetwarzanie...' : 'Zatwierdź i prześlij'}
+                {isUploading ? t('shopping.upload.processing') : t('shopping.upload.submit')}
               </Button>
               <Button
                 variant="secondary"
                 onClick={handleCancel}
                 disabled={isUploading}
               >
-                Anuluj
+                {t('common.cancel')}
               </Button>
             </div>
           </div>
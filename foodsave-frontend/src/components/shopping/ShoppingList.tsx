@@ -0,0 +1,121 @@
+"use client";
+
+import { FormEvent, useState } from 'react';
+import { Plus, Printer, RefreshCw, Trash2 } from 'lucide-react';
+import { Button } from '@/components/ui/Button';
+import { useShoppingList } from '@/hooks/useShoppingList';
+import { useShoppingListSync } from '@/hooks/useShoppingListSync';
+import { useTranslation } from '@/hooks/useTranslation';
+import { buildPrintDocument, printDocument, shoppingListPrintHtml } from '@/lib/print';
+import { ShoppingListQr } from './ShoppingListQr';
+import { SyncConflictDialog } from './SyncConflictDialog';
+
+const PROVIDER_NAMES = { todoist: 'Todoist', caldav: 'CalDAV' } as const;
+
+export function ShoppingList() {
+  const { t, locale } = useTranslation();
+  const { items, addItem, toggleItem, removeItem, clearChecked } = useShoppingList();
+  const { provider, isSyncing, sync, conflicts, resolveConflict, dismissConflict } = useShoppingListSync();
+  const [name, setName] = useState('');
+  const [quantity, setQuantity] = useState('');
+
+  const handleAdd = (e: FormEvent) => {
+    e.preventDefault();
+    if (!name.trim()) return;
+    addItem(name, quantity);
+    setName('');
+    setQuantity('');
+  };
+
+  // Niekupione na górze
+  const sorted = [...items].sort((a, b) => Number(a.checked) - Number(b.checked));
+  const checkedCount = items.filter((i) => i.checked).length;
+
+  return (
+    <div className="space-y-3">
+      <form onSubmit={handleAdd} className="flex gap-2">
+        <input
+          value={name}
+          onChange={(e) => setName(e.target.value)}
+          placeholder={t('shopping.list.addPlaceholder')}
+          data-primary-input
+          aria-label={t('shopping.list.name')}
+          className="flex-1 min-w-0 rounded-md border px-3 py-2 text-sm"
+        />
+        <input
+          value={quantity}
+          onChange={(e) => setQuantity(e.target.value)}
+          placeholder={t('shopping.column.quantity')}
+          aria-label={t('shopping.column.quantity')}
+          className="w-20 rounded-md border px-2 py-2 text-sm"
+        />
+        <Button type="submit" size="icon" aria-label={t('shopping.list.add')} disabled={!name.trim()}>
+          <Plus className="h-4 w-4" />
+        </Button>
+      </form>
+      {items.length === 0 ? (
+        <p className="text-sm text-gray-500">{t('shopping.list.empty')}</p>
+      ) : (
+        <ul className="divide-y">
+          {sorted.map((item) => (
+            <li key={item.id} className="flex items-center gap-2 py-2">
+              <input
+                type="checkbox"
+                checked={item.checked}
+                onChange={() => toggleItem(item.id)}
+                aria-label={t('shopping.list.bought', { name: item.name })}
+                className="h-4 w-4"
+              />
+              <span className={`flex-1 ${item.checked ? 'line-through text-gray-400' : ''}`}>
+                {item.name}
+                {item.quantity && <span className="text-sm text-gray-500"> · {item.quantity}</span>}
+              </span>
+              <button
+                type="button"
+                onClick={() => removeItem(item.id)}
+                aria-label={t('shopping.list.remove', { name: item.name })}
+                className="p-1 text-gray-400 hover:text-red-600"
+              >
+                <Trash2 className="h-4 w-4" />
+              </button>
+            </li>
+          ))}
+        </ul>
+      )}
+      <div className="flex flex-wrap gap-2">
+        {checkedCount > 0 && (
+          <Button variant="outline" size="sm" onClick={clearChecked}>
+            {t('shopping.list.clearChecked', { count: checkedCount })}
+          </Button>
+        )}
+        {items.length > 0 && <ShoppingListQr items={items} />}
+        {items.length > checkedCount && (
+          <Button
+            variant="outline"
+            size="sm"
+            onClick={() => void printDocument(buildPrintDocument(t('shopping.list.title'), shoppingListPrintHtml(items), undefined, locale))}
+          >
+            <Printer className="h-4 w-4 mr-1" />
+            {t('shopping.list.print')}
+          </Button>
+        )}
+        {provider && (
+          <Button variant="outline" size="sm" onClick={() => void sync()} disabled={isSyncing}>
+            <RefreshCw className={`h-4 w-4 mr-1 ${isSyncing ? 'animate-spin' : ''}`} />
+            {t('shopping.list.sync', { provider: PROVIDER_NAMES[provider] })}
+          </Button>
+        )}
+      </div>
+      {provider && (
+        <SyncConflictDialog
+          conflict={conflicts[0] ?? null}
+          item={items.find((item) => item.id === conflicts[0]?.id)}
+          providerName={PROVIDER_NAMES[provider]}
+          remaining={conflicts.length}
+          onResolve={resolveConflict}
+          onDismiss={dismissConflict}
+        />
+      )}
+    </div>
+  );
+}
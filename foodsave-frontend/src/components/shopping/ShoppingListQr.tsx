@@ -0,0 +1,58 @@
+"use client";
+
+import { useMemo, useState } from 'react';
+import { QrCode } from 'lucide-react';
+import { Button } from '@/components/ui/Button';
+import { Modal } from '@/components/ui/Modal';
+import { useTranslation } from '@/hooks/useTranslation';
+import { encodeQr, qrToSvgPath } from '@/lib/qrCode';
+import { ShoppingListItem, shoppingListToText } from '@/lib/shoppingList';
+
+interface ShoppingListQrProps {
+  items: ShoppingListItem[];
+}
+
+// Kod QR z listą do zeskanowania telefonem przed wyjściem do sklepu
+export function ShoppingListQr({ items }: ShoppingListQrProps) {
+  const { t, locale } = useTranslation();
+  const [open, setOpen] = useState(false);
+  const pending = items.filter((item) => !item.checked).length;
+
+  const qr = useMemo(() => {
+    if (!open) return null;
+    try {
+      return encodeQr(shoppingListToText(items, locale));
+    } catch {
+      return null; // lista za długa na jeden kod
+    }
+  }, [open, items, locale]);
+
+  return (
+    <>
+      <Button variant="outline" size="sm" onClick={() => setOpen(true)} disabled={pending === 0}>
+        <QrCode className="h-4 w-4 mr-1" />
+        {t('shopping.qr.button')}
+      </Button>
+      <Modal open={open} onClose={() => setOpen(false)} title={t('shopping.qr.title')} size="sm">
+        {qr ? (
+          <div className="space-y-2">
+            <svg
+              viewBox={`0 0 ${qr.size + 8} ${qr.size + 8}`}
+              role="img"
+              aria-label={t('shopping.qr.label', { count: pending })}
+              className="w-full h-auto bg-white"
+              shapeRendering="crispEdges"
+            >
+              <path d={qrToSvgPath(qr)} fill="#000" />
+            </svg>
+            <p className="text-sm text-muted-foreground text-center">
+              {t('shopping.qr.hint')}
+            </p>
+          </div>
+        ) : (
+          <p className="text-sm">{t('shopping.qr.tooLong')}</p>
+        )}
+      </Modal>
+    </>
+  );
+}
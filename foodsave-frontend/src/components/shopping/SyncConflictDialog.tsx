@@ -0,0 +1,142 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { Modal } from '@/components/ui/Modal';
+import { Button } from '@/components/ui/Button';
+import { useTranslation } from '@/hooks/useTranslation';
+import { ShoppingListItem } from '@/lib/shoppingList';
+import { ConflictChoice, ConflictSide, ConflictVersion, MergePicks, remoteVersion } from '@/lib/syncConflicts';
+import type { MessageKey } from '@/lib/i18n';
+import type { ShoppingListConflict } from '@/types/api';
+
+interface SyncConflictDialogProps {
+  conflict: ShoppingListConflict | null;
+  item: ShoppingListItem | undefined;
+  providerName: string;
+  remaining: number;
+  onResolve: (conflict: ShoppingListConflict, choice: ConflictChoice, picks?: MergePicks) => Promise<void>;
+  onDismiss: (conflict: ShoppingListConflict) => void;
+}
+
+const FIELDS: { key: keyof MergePicks; labelKey: MessageKey }[] = [
+  { key: 'name', labelKey: 'shopping.column.name' },
+  { key: 'quantity', labelKey: 'shopping.column.quantity' },
+];
+
+// One conflict at a time; the next one shows up after this one is resolved
+export function SyncConflictDialog({ conflict, item, providerName, remaining, onResolve, onDismiss }: SyncConflictDialogProps) {
+  const { t } = useTranslation();
+  const [merging, setMerging] = useState(false);
+  const [picks, setPicks] = useState<MergePicks>({ name: 'theirs', quantity: 'mine' });
+  const [isSaving, setIsSaving] = useState(false);
+
+  useEffect(() => {
+    setMerging(false);
+    setPicks({ name: 'theirs', quantity: 'mine' });
+  }, [conflict?.id]);
+
+  if (!conflict || !item) return null;
+
+  const versions: Record<ConflictSide, ConflictVersion> = {
+    mine: { name: item.name, quantity: item.quantity },
+    theirs: remoteVersion(conflict),
+  };
+  const sideLabels: Record<ConflictSide, string> = {
+    mine: t('shopping.sync.mine'),
+    theirs: t('shopping.sync.theirs', { provider: providerName }),
+  };
+
+  const resolve = async (choice: ConflictChoice) => {
+    setIsSaving(true);
+    try {
+      await onResolve(conflict, choice, choice === 'merge' ? picks : undefined);
+    } finally {
+      setIsSaving(false);
+    }
+  };
+
+  return (
+    <Modal
+      open
+      // Zamknięcie bez wyboru zostawia konflikt do następnej synchronizacji
+      onClose={() => onDismiss(conflict)}
+      title={t('shopping.sync.title')}
+      closeOnOverlay={false}
+    >
+      <div className="space-y-4">
+        <p className="text-sm text-gray-600">
+          {t('shopping.sync.body', { name: item.name, provider: providerName })}
+          {remaining > 1 && ` ${t('shopping.sync.remaining', { count: remaining })}`}
+        </p>
+        {merging ? (
+          <table className="w-full text-sm">
+            <thead className="text-left text-xs text-gray-500">
+              <tr>
+                <th className="py-1 font-medium" />
+                {(['mine', 'theirs'] as ConflictSide[]).map((side) => (
+                  <th key={side} className="py-1 font-medium">{sideLabels[side]}</th>
+                ))}
+              </tr>
+            </thead>
+            <tbody>
+              {FIELDS.map(({ key, labelKey }) => (
+                <tr key={key}>
+                  <th scope="row" className="py-1 pr-2 text-left font-medium text-gray-700">{t(labelKey)}</th>
+                  {(['mine', 'theirs'] as ConflictSide[]).map((side) => (
+                    <td key={side} className="py-1">
+                      <label className="flex items-center gap-2">
+                        <input
+                          type="radio"
+                          name={`merge-${key}`}
+                          checked={picks[key] === side}
+                          onChange={() => setPicks((prev) => ({ ...prev, [key]: side }))}
+                        />
+                        {versions[side][key] || <span className="text-gray-400">—</span>}
+                      </label>
+                    </td>
+                  ))}
+                </tr>
+              ))}
+            </tbody>
+          </table>
+        ) : (
+          <dl className="grid grid-cols-2 gap-3 text-sm">
+            {(['mine', 'theirs'] as ConflictSide[]).map((side) => (
+              <div key={side} className="rounded-md border p-3">
+                <dt className="text-xs text-gray-500">{sideLabels[side]}</dt>
+                <dd className="font-medium">
+                  {versions[side].name}
+                  {versions[side].quantity && <span className="text-gray-500"> · {versions[side].quantity}</span>}
+                </dd>
+              </div>
+            ))}
+          </dl>
+        )}
+        <div className="flex flex-wrap justify-end gap-2">
+          {merging ? (
+            <>
+              <Button variant="outline" onClick={() => setMerging(false)} disabled={isSaving}>
+                {t('shopping.sync.back')}
+              </Button>
+              <Button onClick={() => void resolve('merge')} isLoading={isSaving}>
+                {t('shopping.sync.saveMerged')}
+              </Button>
+            </>
+          ) : (
+            <>
+              <Button variant="outline" onClick={() => setMerging(true)} disabled={isSaving}>
+                {t('shopping.sync.merge')}
+              </Button>
+              <Button variant="outline" onClick={() => void resolve('theirs')} disabled={isSaving}>
+                {t('shopping.sync.keepTheirs', { provider: providerName })}
+              </Button>
+              <Button onClick={() => void resolve('mine')} isLoading={isSaving}>
+                {t('shopping.sync.keepMine')}
+              </Button>
+            </>
+          )}
+        </div>
+      </div>
+    </Modal>
+  );
+}
@@ -0,0 +1,34 @@
+import '@testing-library/jest-dom';
+import React from 'react';
+import { fireEvent, render, screen } from '@testing-library/react';
+import { ConfirmDialog } from './index';
+
+describe('ConfirmDialog', () => {
+  const setup = () => {
+    const onConfirm = jest.fn();
+    const onCancel = jest.fn();
+    render(
+      <ConfirmDialog open title="Usunąć dane?" confirmLabel="Usuń" destructive onConfirm={onConfirm} onCancel={onCancel} />,
+    );
+    return { onConfirm, onCancel };
+  };
+
+  it('potwierdza po kliknięciu przycisku', () => {
+    const { onConfirm } = setup();
+    expect(screen.getByRole('alertdialog')).toHaveAccessibleName('Usunąć dane?');
+    fireEvent.click(screen.getByRole('button', { name: 'Usuń' }));
+    expect(onConfirm).toHaveBeenCalledTimes(1);
+  });
+
+  it('Esc i domyślny fokus anulują', () => {
+    const { onCancel } = setup();
+    expect(screen.getByRole('button', { name: 'Anuluj' })).toHaveFocus();
+    fireEvent.keyDown(window, { key: 'Escape' });
+    expect(onCancel).toHaveBeenCalledTimes(1);
+  });
+
+  it('nie renderuje się, gdy jest zamknięte', () => {
+    render(<ConfirmDialog open={false} title="x" onConfirm={jest.fn()} onCancel={jest.fn()} />);
+    expect(screen.queryByRole('alertdialog')).not.toBeInTheDocument();
+  });
+});
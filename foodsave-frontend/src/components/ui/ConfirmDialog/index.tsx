@@ -0,0 +1,81 @@
+"use client";
+
+import React, { useCallback, useState } from 'react';
+import { Button } from '../Button';
+import { Modal } from '../Modal';
+import { useTranslation } from '@/hooks/useTranslation';
+
+export interface ConfirmOptions {
+  title: string;
+  message?: string;
+  confirmLabel?: string;
+  cancelLabel?: string;
+  destructive?: boolean;
+}
+
+interface ConfirmDialogProps extends ConfirmOptions {
+  open: boolean;
+  onConfirm: () => void;
+  onCancel: () => void;
+}
+
+export function ConfirmDialog({
+  open,
+  title,
+  message,
+  confirmLabel,
+  cancelLabel,
+  destructive = false,
+  onConfirm,
+  onCancel,
+}: ConfirmDialogProps) {
+  const { t } = useTranslation();
+  return (
+    <Modal open={open} onClose={onCancel} title={title} role="alertdialog" size="sm" showCloseButton={false}>
+      {message && <p className="text-sm text-gray-600">{message}</p>}
+      <div className="mt-4 flex justify-end gap-2">
+        {/* Domyślnie fokus na bezpiecznej opcji */}
+        <Button autoFocus variant="outline" onClick={onCancel}>
+          {cancelLabel ?? t('common.cancel')}
+        </Button>
+        <Button variant={destructive ? 'destructive' : 'default'} onClick={onConfirm}>
+          {confirmLabel ?? t('common.confirm')}
+        </Button>
+      </div>
+    </Modal>
+  );
+}
+
+/** Promise-based confirm() replacement; render the returned element once in the component. */
+export function useConfirmDialog(): [(options: ConfirmOptions) => Promise<boolean>, React.ReactElement] {
+  const [pending, setPending] = useState<(ConfirmOptions & { resolve: (ok: boolean) => void }) | null>(null);
+
+  const confirm = useCallback(
+    (options: ConfirmOptions) => new Promise<boolean>((resolve) => setPending({ ...options, resolve })),
+    [],
+  );
+  const close = useCallback(
+    (ok: boolean) => {
+      pending?.resolve(ok);
+      setPending(null);
+    },
+    [pending],
+  );
+  const onCancel = useCallback(() => close(false), [close]);
+
+  const dialog = (
+    <ConfirmDialog
+      open={pending !== null}
+      title={pending?.title ?? ''}
+      message={pending?.message}
+      confirmLabel={pending?.confirmLabel}
+      cancelLabel={pending?.cancelLabel}
+      destructive={pending?.destructive}
+      onConfirm={() => close(true)}
+      onCancel={onCancel}
+    />
+  );
+  return [confirm, dialog];
+}
+
+export default ConfirmDialog;
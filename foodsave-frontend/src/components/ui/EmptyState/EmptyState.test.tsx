@@ -0,0 +1,13 @@
+import '@testing-library/jest-dom';
+import React from 'react';
+import { render, screen } from '@testing-library/react';
+import { Bell } from 'lucide-react';
+import { EmptyState } from './index';
+
+describe('EmptyState', () => {
+  it('pokazuje komunikat i opcjonalną akcję', () => {
+    render(<EmptyState message="Brak przypomnień" icon={Bell} action={<button>Dodaj</button>} />);
+    expect(screen.getByRole('status')).toHaveTextContent('Brak przypomnień');
+    expect(screen.getByRole('button', { name: 'Dodaj' })).toBeInTheDocument();
+  });
+});
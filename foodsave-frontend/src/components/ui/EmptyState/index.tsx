@@ -0,0 +1,24 @@
+import React from 'react';
+import type { LucideIcon } from 'lucide-react';
+
+interface EmptyStateProps {
+  message: string;
+  icon?: LucideIcon;
+  action?: React.ReactNode; // np. przycisk "Dodaj"
+  align?: 'start' | 'center';
+  className?: string;
+}
+
+// "Nothing here yet" text shared by lists, charts and widgets
+export function EmptyState({ message, icon: Icon, action, align = 'start', className = '' }: EmptyStateProps) {
+  const alignClass = align === 'center' ? 'items-center text-center py-4' : 'items-start';
+  return (
+    <div role="status" className={`flex flex-col gap-2 text-sm text-gray-500 ${alignClass} ${className}`}>
+      {Icon && <Icon className="h-6 w-6 opacity-60" aria-hidden="true" />}
+      <p>{message}</p>
+      {action}
+    </div>
+  );
+}
+
+export default EmptyState;
@@ -1,5 +1,7 @@
 "use client";
 
+import { useId } from 'react';
+
 interface InputProps {
   className?: string;
   label?: string;
@@ -22,8 +24,9 @@ export function Input({
   id,
   ...props
 }: InputProps) {
-  // Generate a unique ID for the input if not provided
-  const inputId = id || `input-${Math.random().toString(36).substr(2, 9)}`;
+  // Stabilne id, żeby etykieta i opisy pozostały powiązane między renderami
+  const generatedId = useId();
+  const inputId = id || `input-${generatedId}`;
 
   return (
     <div className="space-y-2">
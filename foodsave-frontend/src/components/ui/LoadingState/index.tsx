@@ -0,0 +1,26 @@
+import React from 'react';
+import { Spinner } from '../Spinner';
+import { useTranslation } from '@/hooks/useTranslation';
+
+interface LoadingStateProps {
+  label?: string;
+  size?: 'sm' | 'md' | 'lg';
+  className?: string;
+}
+
+// Spinner with an optional caption, for sections waiting on data
+export function LoadingState({ label, size = 'md', className = '' }: LoadingStateProps) {
+  const { t } = useTranslation();
+  return (
+    <div
+      role="status"
+      aria-label={label ?? t('common.loading')}
+      className={`flex items-center justify-center gap-2 p-4 text-sm text-gray-500 ${className}`}
+    >
+      <Spinner size={size} />
+      {label && <span>{label}</span>}
+    </div>
+  );
+}
+
+export default LoadingState;
@@ -0,0 +1,52 @@
+import '@testing-library/jest-dom';
+import React, { useState } from 'react';
+import { fireEvent, render, screen } from '@testing-library/react';
+import { Modal } from './index';
+
+function Harness({ onClose = jest.fn() }: { onClose?: () => void }) {
+  const [open, setOpen] = useState(false);
+  return (
+    <>
+      <button onClick={() => setOpen(true)}>Otwórz</button>
+      <Modal
+        open={open}
+        onClose={() => {
+          onClose();
+          setOpen(false);
+        }}
+        title="Dodaj produkt"
+      >
+        <input aria-label="Nazwa" data-autofocus />
+      </Modal>
+    </>
+  );
+}
+
+describe('Modal', () => {
+  it('przenosi fokus do okna i oddaje go po zamknięciu', () => {
+    render(<Harness />);
+    const opener = screen.getByRole('button', { name: 'Otwórz' });
+    opener.focus();
+    fireEvent.click(opener);
+
+    expect(screen.getByRole('dialog')).toHaveAccessibleName('Dodaj produkt');
+    expect(screen.getByLabelText('Nazwa')).toHaveFocus();
+
+    fireEvent.keyDown(window, { key: 'Escape' });
+    expect(screen.queryByRole('dialog')).not.toBeInTheDocument();
+    expect(opener).toHaveFocus();
+  });
+
+  it('zamyka się kliknięciem w tło i przyciskiem zamykania', () => {
+    const onClose = jest.fn();
+    render(<Harness onClose={onClose} />);
+
+    fireEvent.click(screen.getByRole('button', { name: 'Otwórz' }));
+    fireEvent.mouseDown(screen.getByRole('dialog').parentElement!);
+    expect(onClose).toHaveBeenCalledTimes(1);
+
+    fireEvent.click(screen.getByRole('button', { name: 'Otwórz' }));
+    fireEvent.click(screen.getByRole('button', { name: 'Zamknij' }));
+    expect(onClose).toHaveBeenCalledTimes(2);
+  });
+});
@@ -0,0 +1,138 @@
+"use client";
+
+import React, { useEffect, useId, useRef } from 'react';
+import { createPortal } from 'react-dom';
+import { X } from 'lucide-react';
+import { Button } from '../Button';
+import { focusableElements, trapFocus } from '@/lib/focus';
+
+interface ModalProps {
+  open: boolean;
+  onClose: () => void;
+  title?: React.ReactNode;
+  ariaLabel?: string; // when there is no visible title
+  role?: 'dialog' | 'alertdialog';
+  size?: 'sm' | 'md' | 'lg' | 'full';
+  position?: 'center' | 'top';
+  closeOnOverlay?: boolean;
+  showCloseButton?: boolean;
+  closeLabel?: string;
+  className?: string;
+  children: React.ReactNode;
+}
+
+const SIZES = {
+  sm: 'max-w-sm',
+  md: 'max-w-lg',
+  lg: 'max-w-3xl',
+  full: 'max-w-[calc(100vw-2rem)]',
+} as const;
+
+// Otwarte okna, od najstarszego; Esc i Tab obsługuje tylko to na wierzchu
+const stack: HTMLElement[] = [];
+
+/**
+ * Overlay dialog rendered into document.body: focus moves inside on open (an autoFocus or
+ * [data-autofocus] element, else the first focusable one), Tab is trapped, Esc and a click
+ * on the backdrop close it, and focus returns to the opener afterwards.
+ */
+export function Modal({
+  open,
+  onClose,
+  title,
+  ariaLabel,
+  role = 'dialog',
+  size = 'md',
+  position = 'center',
+  closeOnOverlay = true,
+  showCloseButton = true,
+  closeLabel = 'Zamknij',
+  className = '',
+  children,
+}: ModalProps) {
+  const dialogRef = useRef<HTMLDivElement>(null);
+  const titleId = useId();
+  const onCloseRef = useRef(onClose);
+  onCloseRef.current = onClose;
+  // Zapamiętane przy renderze - autoFocus w oknie przenosi fokus jeszcze przed efektem
+  const openerRef = useRef<HTMLElement | null>(null);
+  if (open && !openerRef.current && typeof document !== 'undefined') {
+    openerRef.current = document.activeElement as HTMLElement | null;
+  }
+
+  useEffect(() => {
+    const dialog = dialogRef.current;
+    if (!open || !dialog) return;
+    stack.push(dialog);
+
+    if (!dialog.contains(document.activeElement)) {
+      const preferred = dialog.querySelector<HTMLElement>('[data-autofocus]');
+      (preferred ?? focusableElements(dialog)[0] ?? dialog).focus();
+    }
+
+    const previousOverflow = document.body.style.overflow;
+    document.body.style.overflow = 'hidden';
+
+    const onKeyDown = (e: KeyboardEvent) => {
+      if (stack[stack.length - 1] !== dialog) return;
+      if (e.key === 'Escape') {
+        e.preventDefault();
+        onCloseRef.current();
+        return;
+      }
+      trapFocus(e, dialog);
+    };
+    // Faza przechwytywania: Esc zamyka okno zanim zobaczą go globalne skróty
+    window.addEventListener('keydown', onKeyDown, true);
+
+    return () => {
+      window.removeEventListener('keydown', onKeyDown, true);
+      stack.splice(stack.indexOf(dialog), 1);
+      if (stack.length === 0) document.body.style.overflow = previousOverflow;
+      openerRef.current?.focus?.();
+      openerRef.current = null;
+    };
+  }, [open]);
+
+  if (!open || typeof document === 'undefined') return null;
+
+  return createPortal(
+    <div
+      className={`fixed inset-0 z-50 flex justify-center bg-black/40 p-4 ${
+        position === 'top' ? 'items-start pt-16' : 'items-center'
+      }`}
+      onMouseDown={(e) => {
+        if (closeOnOverlay && e.target === e.currentTarget) onClose();
+      }}
+    >
+      <div
+        ref={dialogRef}
+        role={role}
+        aria-modal="true"
+        aria-labelledby={title ? titleId : undefined}
+        aria-label={title ? undefined : ariaLabel}
+        tabIndex={-1}
+        className={`w-full ${SIZES[size]} max-h-[calc(100vh-2rem)] overflow-auto rounded-lg border bg-card p-5 shadow-xl focus:outline-none ${className}`}
+      >
+        {(title || showCloseButton) && (
+          <div className="mb-3 flex items-center justify-between gap-2">
+            {title && (
+              <h2 id={titleId} className="flex items-center gap-2 text-lg font-semibold">
+                {title}
+              </h2>
+            )}
+            {showCloseButton && (
+              <Button variant="ghost" size="icon" onClick={onClose} aria-label={closeLabel} className="ml-auto">
+                <X className="h-4 w-4" />
+              </Button>
+            )}
+          </div>
+        )}
+        {children}
+      </div>
+    </div>,
+    document.body,
+  );
+}
+
+export default Modal;
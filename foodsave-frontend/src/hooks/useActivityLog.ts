@@ -0,0 +1,16 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { Activity, getActivities, subscribeActivities } from '@/lib/activityLog';
+
+export function useActivityLog(limit = 10) {
+  const [activities, setActivities] = useState<Activity[]>([]);
+
+  useEffect(() => {
+    const refresh = () => setActivities(getActivities(limit));
+    refresh();
+    return subscribeActivities(refresh);
+  }, [limit]);
+
+  return activities;
+}
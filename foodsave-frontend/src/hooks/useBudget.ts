@@ -0,0 +1,20 @@
+"use client";
+
+import { useCallback, useEffect, useState } from 'react';
+import { BudgetSettings, emptyBudget, loadBudget, saveBudget } from '@/lib/budget';
+
+export function useBudget() {
+  // Start empty and read storage after mount to avoid hydration mismatch
+  const [budget, setBudget] = useState<BudgetSettings>(emptyBudget);
+
+  useEffect(() => {
+    setBudget(loadBudget());
+  }, []);
+
+  const updateBudget = useCallback((next: BudgetSettings) => {
+    setBudget(next);
+    saveBudget(next);
+  }, []);
+
+  return { budget, updateBudget };
+}
@@ -1,13 +1,71 @@
 "use client";
 
-import { useState, useEffect, useCallback } from 'react';
+import { useState, useEffect, useCallback, useRef } from 'react';
 import { v4 as uuidv4 } from 'uuid';
-import { Message } from '@/types/chat';
+import { ContextAttachment, ContextAttachmentKind, Message, MessageStatus, ReplyLanguage } from '@/types/chat';
+import { Product, ShoppingTrip } from '@/types/shopping';
 import { ApiService } from '@/services/ApiService';
+import { logActivity } from '@/lib/activityLog';
+import { announce, toSpokenText } from '@/lib/announcer';
+import { notify } from '@/lib/notifications';
+import { trackTask } from '@/lib/tasks';
+import { reportError } from '@/lib/errors';
+import {
+  archiveConversation,
+  clearChatHistory,
+  getChatHistory,
+  restoreConversation,
+  saveChatHistory,
+} from '@/lib/chatHistory';
+import { isOnline, subscribeNetwork } from '@/lib/network';
+import { profileSessionId } from '@/lib/profiles';
+import {
+  RECEIPT_DAYS,
+  pantryAttachment,
+  receiptsAttachment,
+  shoppingListAttachment,
+} from '@/lib/chatAttachments';
+import { readSnapshot, writeSnapshot } from '@/lib/offlineCache';
+import { getShoppingList } from '@/lib/shoppingList';
+import { localIsoDate } from '@/lib/time';
+import { translate } from '@/lib/i18n';
+import { loadSettings } from '@/lib/settings';
+import { useTranslation } from '@/hooks/useTranslation';
+
+// Greeting of a new conversation, in the language the interface had when it started
+function welcomeMessage(): Message {
+  return { id: uuidv4(), role: 'assistant', content: translate(loadSettings().language, 'chat.welcome') };
+}
+
+// Fresh snapshot of the attached data, taken when the question is actually sent
+export async function loadContextAttachment(kind: ContextAttachmentKind): Promise<ContextAttachment> {
+  switch (kind) {
+    case 'pantry': {
+      // Bez backendu wystarczy ostatnia zapamiętana lista produktów
+      const data = await (ApiService.getProducts() as Promise<Product[] | { products: Product[] }>).catch(() => undefined);
+      const products = data ? (Array.isArray(data) ? data : data.products) : readSnapshot<Product[]>('products');
+      return pantryAttachment(products ?? []);
+    }
+    case 'receipts': {
+      const today = new Date();
+      const from = new Date(today.getFullYear(), today.getMonth(), today.getDate() - (RECEIPT_DAYS - 1));
+      // Jak przy spiżarni: bez backendu ostatnio pobrane paragony, a w ostateczności pusty załącznik
+      const trips = await ApiService.getShoppingTrips({ date_from: localIsoDate(from), date_to: localIsoDate(today) })
+        .then((data) => {
+          writeSnapshot('receipts', data);
+          return data;
+        })
+        .catch(() => readSnapshot<ShoppingTrip[]>('receipts'));
+      return receiptsAttachment(trips ?? []);
+    }
+    case 'shopping_list':
+      return shoppingListAttachment(getShoppingList());
+  }
+}
 
 export function useChat(context: 'general' | 'shopping' | 'cooking' = 'general') {
   const [messages, setMessages] = useState<Message[]>([]);
-  const [isLoading, setIsLoading] = useState(false);
+  const { t } = useTranslation();
   const [error, setError] = useState<string | null>(null);
   const [sessionId, setSessionId] = useState<string>('');
   const [usePerplexity, setUsePerplexity] = useState(false);
@@ -15,34 +73,48 @@ export function useChat(context: 'general' | 'shopping' | 'cooking' = 'general')
   const [isShoppingMode, setIsShoppingMode] = useState(false);
   const [isCookingMode, setIsCookingMode] = useState(false);
   const [streamingMessage, setStreamingMessage] = useState<Message | null>(null);
+  const [replyLanguage, setReplyLanguage] = useState<ReplyLanguage>('auto');
+  const [attachments, setAttachments] = useState<ContextAttachmentKind[]>([]);
+  // Zajęty, dopóki któraś wiadomość jest w drodze albo odpowiedź się pisze
+  const isLoading = !!streamingMessage || messages.some(m => m.status === 'sending');
 
   useEffect(() => {
+    // Wznów zapisaną rozmowę - działa także bez połączenia z backendem
+    const saved = getChatHistory(context);
+    if (saved && saved.messages.length > 0) {
+      setSessionId(saved.sessionId);
+      setMessages(saved.messages);
+      setReplyLanguage(saved.language ?? 'auto');
+      return;
+    }
     // Initialize session on component mount
-    const newSessionId = uuidv4();
+    const newSessionId = profileSessionId(uuidv4());
     setSessionId(newSessionId);
-    setMessages([
-      {
-        id: uuidv4(),
-        role: 'assistant',
-        content: 'Cześć! Jestem Twoim asystentem FoodSave. W czym mogę dziś pomóc?'
-      }
-    ]);
+    setMessages([welcomeMessage()]);
+  }, [context]);
+
+  useEffect(() => {
+    if (sessionId) saveChatHistory(context, sessionId, messages, replyLanguage);
+  }, [context, sessionId, messages, replyLanguage]);
+
+  const setStatus = useCallback((id: string, status: MessageStatus) => {
+    setMessages(prev => prev.map(m => (m.id === id ? { ...m, status } : m)));
   }, []);
 
-  const sendMessage = useCallback(async (content: string, usePerplexity?: boolean, useBielik?: boolean) => {
+  // Asks the assistant about a user message that is already in the conversation
+  const askAssistant = useCallback(async (userMessage: Message, agent?: string) => {
+    const { content, usePerplexity, useBielik } = userMessage;
+    // Dostarczona, gdy backend zaczął odpowiadać
+    let delivered = false;
+    const markDelivered = () => {
+      if (delivered) return;
+      delivered = true;
+      setStatus(userMessage.id, 'delivered');
+    };
     try {
       setError(null);
-      setIsLoading(true);
-
-      // Add user message to the chat
-      const userMessage: Message = {
-        id: uuidv4(),
-        role: 'user',
-        content,
-        usePerplexity: usePerplexity || false,
-        useBielik: useBielik !== undefined ? useBielik : true
-      };
-      setMessages(prev => [...prev, userMessage]);
+      setStatus(userMessage.id, 'sending');
+      logActivity('chat', t('chat.activity', { text: content.slice(0, 60) }), context === 'general' ? '/chat' : `/${context}`);
 
       // Create a new empty assistant message for streaming
       const assistantMessageId = uuidv4();
@@ -50,15 +122,22 @@ export function useChat(context: 'general' | 'shopping' | 'cooking' = 'general')
         id: assistantMessageId,
         role: 'assistant',
         content: '',
+        timestamp: Date.now(),
         usePerplexity: usePerplexity || false,
         useBielik: useBielik !== undefined ? useBielik : true,
+        status: 'streaming',
       };
 
       // Set as the current streaming message
       setStreamingMessage(assistantMessage);
 
+      const snapshots = userMessage.attachments?.length
+        ? await Promise.all(userMessage.attachments.map(loadContextAttachment))
+        : undefined;
+
       // Send message to the API
-      const response = await ApiService.sendChatMessage({
+      let streamedText = '';
+      const response = await trackTask('chat', t('chat.taskReply'), () => ApiService.sendChatMessage({
         message: content,
         session_id: sessionId,
         agent_states: {
@@ -69,9 +148,14 @@ export function useChat(context: 'general' | 'shopping' | 'cooking' = 'general')
         },
         usePerplexity: usePerplexity || false,
         useBielik: useBielik !== undefined ? useBielik : true,
+        agent,
+        language: replyLanguage === 'auto' ? undefined : replyLanguage,
+        attachments: snapshots,
       }, (chunk) => {
+        markDelivered();
         // Handle streaming response
         if (chunk && chunk.text) {
+          streamedText += chunk.text;
           setStreamingMessage(prev => {
             if (!prev) return null;
             return {
@@ -81,7 +165,13 @@ export function useChat(context: 'general' | 'shopping' | 'cooking' = 'general')
             };
           });
         }
-      });
+      }));
+
+      markDelivered();
+
+      // Niepewny routing: pod odpowiedzią pojawiają się propozycje innych agentów
+      const options = response?.routing?.clarification;
+      const clarification = options?.length ? { question: content, options } : undefined;
 
       // After streaming is complete, add the final message to the list
       setMessages(prev => {
@@ -92,54 +182,183 @@ export function useChat(context: 'general' | 'shopping' | 'cooking' = 'general')
 
         // If we have a streaming message, use that as the final message
         if (streamingMessage && streamingMessage.content) {
-          return [...prev, streamingMessage];
+          return [...prev, { ...streamingMessage, status: undefined, clarification }];
         }
 
         // Fallback to the response from the API if no streaming content
         const finalMessage: Message = {
           id: assistantMessageId,
           role: 'assistant',
-          content: response?.response || 'Przepraszam, wystąpił błąd w przetwarzaniu.',
+          content: response?.response || t('chat.replyFailed'),
           data: response?.data || null,
+          timestamp: Date.now(),
           usePerplexity: usePerplexity || false,
           useBielik: useBielik !== undefined ? useBielik : true,
+          clarification,
         };
         return [...prev, finalMessage];
       });
 
       // Clear the streaming message
       setStreamingMessage(null);
+      // Czytnik ekranu nie śledzi strumieniowanego tekstu - odczytujemy gotową odpowiedź
+      announce(t('chat.announceReply', { text: toSpokenText(streamedText || response?.response || '') }));
+
+      // Długie odpowiedzi: daj znać, jeśli użytkownik przełączył się na inną kartę
+      if (document.hidden) {
+        notify({
+          category: 'chat',
+          title: t('chat.notificationTitle'),
+          body: (response?.response || content).slice(0, 120),
+          href: context === 'general' ? '/chat' : `/${context}`,
+        });
+      }
     } catch (err) {
-      const errorMessage = err instanceof Error ? err.message : 'Wystąpił nieznany błąd';
+      setStreamingMessage(null);
+      // Połączenie zerwane w trakcie - pytanie wraca do kolejki zamiast kończyć się błędem
+      if (!isOnline()) {
+        setStatus(userMessage.id, 'queued');
+        return;
+      }
+      setStatus(userMessage.id, 'failed');
+      // Toast z "Ponów" zadaje to samo pytanie jeszcze raz - bez jego duplikatu w rozmowie
+      const errorId = uuidv4();
+      const { message: errorMessage } = reportError(err, t('chat.errorContext'), () => {
+        setMessages(prev => prev.filter(m => m.id !== errorId));
+        void askRef.current(userMessage, agent);
+      });
       setError(errorMessage);
 
       const errorResponse: Message = {
-        id: uuidv4(),
+        id: errorId,
         role: 'assistant',
-        content: `Wystąpił błąd: ${errorMessage}`,
+        content: t('chat.errorReply', { error: errorMessage }),
         isError: true,
+        timestamp: Date.now(),
       };
       setMessages(prev => [...prev, errorResponse]);
-      setStreamingMessage(null);
+    }
+  }, [context, sessionId, isShoppingMode, isCookingMode, replyLanguage, streamingMessage, setStatus, t]);
+  const askRef = useRef(askAssistant);
+  askRef.current = askAssistant;
+
+  // `agent` pins the routing, when resending a question from clarification options
+  const sendMessage = useCallback(async (content: string, usePerplexity?: boolean, useBielik?: boolean, agent?: string) => {
+    const userMessage: Message = {
+      id: uuidv4(),
+      role: 'user',
+      content,
+      timestamp: Date.now(),
+      usePerplexity: usePerplexity || false,
+      useBielik: useBielik !== undefined ? useBielik : true,
+      ...(attachments.length > 0 && { attachments }),
+      // Zapamiętany, żeby pytanie z kolejki trafiło do tego samego agenta
+      ...(agent && { agent }),
+      // Bez połączenia pytanie czeka w kolejce i zostanie wysłane po jego powrocie
+      status: isOnline() ? 'sending' : 'queued',
+    };
+    setMessages(prev => [...prev, userMessage]);
+    // Załączniki dotyczą jednego pytania
+    setAttachments([]);
+    if (userMessage.status === 'sending') await askAssistant(userMessage, agent);
+  }, [askAssistant, attachments]);
+
+  const messagesRef = useRef(messages);
+  messagesRef.current = messages;
+  const flushingRef = useRef(false);
+
+  // Sends queued questions one by one, oldest first; each moves to the end so its answer follows it
+  const flushPending = useCallback(async () => {
+    if (flushingRef.current) return;
+    flushingRef.current = true;
+    try {
+      let next = messagesRef.current.find(m => m.status === 'queued');
+      while (next && isOnline()) {
+        const sent: Message = { ...next, status: 'sending' };
+        messagesRef.current = [...messagesRef.current.filter(m => m.id !== sent.id), sent];
+        setMessages(prev => [...prev.filter(m => m.id !== sent.id), sent]);
+        await askRef.current(sent, sent.agent);
+        next = messagesRef.current.find(m => m.status === 'queued' && m.id !== sent.id);
+      }
+    } finally {
+      flushingRef.current = false;
+    }
+  }, []);
+
+  useEffect(() => subscribeNetwork(() => {
+    if (isOnline()) void flushPending();
+  }), [flushPending]);
+
+  // Pytania z kolejki zapisane przed przeładowaniem strony
+  useEffect(() => {
+    if (sessionId && isOnline()) void flushPending();
+  }, [sessionId, flushPending]);
+
+  const [isSummarizing, setIsSummarizing] = useState(false);
+
+  // Pinned summary card of the conversation so far; earlier summaries aren't summarized again
+  const summarize = async () => {
+    const transcript = messages
+      .filter(m => (m.role === 'user' || m.role === 'assistant') && !m.isError && !m.summary && m.content.trim())
+      .map(m => ({ role: m.role as 'user' | 'assistant', content: m.content }));
+    if (!transcript.some(m => m.role === 'user')) return;
+    setIsSummarizing(true);
+    try {
+      const { summary } = await trackTask('chat', t('chat.pinned.summary'), () =>
+        ApiService.summarizeConversation(transcript, replyLanguage === 'auto' ? undefined : replyLanguage),
+      );
+      setMessages(prev => [
+        ...prev,
+        { id: uuidv4(), role: 'assistant', content: summary, timestamp: Date.now(), pinned: true, summary: true },
+      ]);
+    } catch (err) {
+      reportError(err, t('chat.pinned.summary'), () => void summarizeRef.current());
     } finally {
-      setIsLoading(false);
+      setIsSummarizing(false);
     }
-  }, [sessionId, isShoppingMode, isCookingMode, streamingMessage]);
+  };
+  const summarizeRef = useRef(summarize);
+  summarizeRef.current = summarize;
+
+  // Resends the question pinned to the agent picked from the clarification chips
+  const clarify = (question: string, agent: string) => {
+    void sendMessage(question, usePerplexity, useBielik, agent);
+  };
+
+  const startConversation = () => {
+    const newSessionId = profileSessionId(uuidv4());
+    setSessionId(newSessionId);
+    setMessages([welcomeMessage()]);
+    setStreamingMessage(null);
+    setReplyLanguage('auto');
+    setAttachments([]);
+  };
 
   // Function to clear chat history
   const clearChat = () => {
-    const newSessionId = uuidv4();
-    setSessionId(newSessionId);
-    setMessages([
-      {
-        id: uuidv4(),
-        role: 'assistant',
-        content: 'Cześć! Jestem Twoim asystentem FoodSave. W czym mogę dziś pomóc?'
-      }
-    ]);
+    clearChatHistory(context);
+    startConversation();
+  };
+
+  // Keeps the current conversation in the archive and starts a fresh one
+  const newChat = () => {
+    archiveConversation(context);
+    startConversation();
+  };
+
+  const openConversation = (savedSessionId: string) => {
+    const saved = restoreConversation(savedSessionId);
+    if (!saved) return;
+    setSessionId(saved.sessionId);
+    setMessages(saved.messages);
+    setReplyLanguage(saved.language ?? 'auto');
     setStreamingMessage(null);
   };
 
+  const togglePin = (messageId: string) => {
+    setMessages(prev => prev.map(m => (m.id === messageId ? { ...m, pinned: !m.pinned } : m)));
+  };
+
   // Function to toggle Perplexity
   const togglePerplexity = () => {
     setUsePerplexity((prev: boolean) => !prev);
@@ -163,7 +382,14 @@ export function useChat(context: 'general' | 'shopping' | 'cooking' = 'general')
     isLoading,
     error,
     sendMessage,
+    clarify,
     clearChat,
+    newChat,
+    openConversation,
+    togglePin,
+    summarize,
+    isSummarizing,
+    sessionId,
     usePerplexity,
     togglePerplexity,
     useBielik,
@@ -173,5 +399,9 @@ export function useChat(context: 'general' | 'shopping' | 'cooking' = 'general')
     isCookingMode,
     toggleCookingMode,
     streamingMessage,
+    replyLanguage,
+    setReplyLanguage,
+    attachments,
+    setAttachments,
   };
 }
@@ -1,10 +1,16 @@
 "use client";
 
-import { useState, useCallback, useEffect } from 'react';
+import { useState, useCallback, useEffect, useRef } from 'react';
 import { v4 as uuidv4 } from 'uuid';
 import { PantryItem, Recipe } from '@/types/cooking';
 import { Message } from '@/types/chat';
 import { ApiService } from '@/services/ApiService';
+import { logActivity } from '@/lib/activityLog';
+import { mergePantryItems } from '@/lib/pantryMerge';
+import { stapleRanOut } from '@/lib/pantryStaples';
+import { addMissingItems } from '@/lib/shoppingList';
+import { pushToast } from '@/lib/toasts';
+import { useTranslation } from '@/hooks/useTranslation';
 
 interface PantryResponse {
   items: PantryItem[];
@@ -24,14 +30,18 @@ interface ConversationResponse {
 }
 
 export function useCooking() {
+  const { t } = useTranslation();
   // State declarations
   const [pantryItems, setPantryItems] = useState<PantryItem[]>([]);
+  // Stan sprzed aktualizacji - potrzebny, żeby wykryć moment wyczerpania produktu
+  const pantryItemsRef = useRef(pantryItems);
+  pantryItemsRef.current = pantryItems;
   const [recipes, setRecipes] = useState<Recipe[]>([]);
   const [messages, setMessages] = useState<Message[]>([
     {
       id: uuidv4(),
       role: 'assistant',
-      content: 'Witaj w asystencie kulinarnym! Mogę pomóc Ci znaleźć przepisy na podstawie produktów w Twojej spiżarni lub doradzić, co można ugotować.'
+      content: t('cooking.chat.welcome')
     }
   ]);
   const [isLoading, setIsLoading] = useState(false);
@@ -93,13 +103,14 @@ export function useCooking() {
       setError(null);
       const newItem = await ApiService.post<PantryItem>('/api/v1/pantry/items', item);
       setPantryItems(items => [...items, newItem]);
+      logActivity('pantry_change', t('cooking.activity.added', { name: item.name }), '/cooking');
     } catch (err) {
       setError(err instanceof Error ? err.message : 'Failed to add pantry item');
       console.error('Error adding pantry item:', err);
     } finally {
       setIsLoading(false);
     }
-  }, []);
+  }, [t]);
 
   // Delete pantry item
   const deletePantryItem = useCallback(async (id: string) => {
@@ -108,13 +119,14 @@ export function useCooking() {
       setError(null);
       await ApiService.delete(`/api/v1/pantry/items/${id}`);
       setPantryItems(items => items.filter(item => item.id !== id));
+      logActivity('pantry_change', t('cooking.activity.deleted'), '/cooking');
     } catch (err) {
       setError(err instanceof Error ? err.message : 'Failed to delete pantry item');
       console.error('Error deleting pantry item:', err);
     } finally {
       setIsLoading(false);
     }
-  }, []);
+  }, [t]);
 
   // Update pantry item
   const updatePantryItem = useCallback(async (id: string, updates: Partial<PantryItem>) => {
@@ -122,18 +134,63 @@ export function useCooking() {
       setIsLoading(true);
       setError(null);
       const updatedItem = await ApiService.patch<PantryItem>(`/api/v1/pantry/items/${id}`, updates);
+      const before = pantryItemsRef.current.find(item => item.id === id);
+      const after = { ...before, ...updates, ...updatedItem } as PantryItem;
       setPantryItems(items =>
         items.map(item =>
           item.id === id ? { ...item, ...updatedItem } : item
         )
       );
+      if (stapleRanOut(before, after) && addMissingItems([after.name]).length > 0) {
+        pushToast(t('cooking.stapleRanOut', { name: after.name }));
+      }
+      logActivity('pantry_change', t('cooking.activity.updated', { name: updatedItem?.name ?? updates.name ?? t('cooking.activity.product') }), '/cooking');
     } catch (err) {
       setError(err instanceof Error ? err.message : 'Failed to update pantry item');
       console.error('Error updating pantry item:', err);
+      // Okna dialogowe zostają otwarte, gdy zapis się nie udał
+      throw err;
     } finally {
       setIsLoading(false);
     }
-  }, []);
+  }, [t]);
+
+  // Merge a duplicate into another item: the kept one is updated, the duplicate deleted
+  const mergeDuplicateItems = useCallback(async (keptId: string, mergedId: string) => {
+    const kept = pantryItemsRef.current.find(item => item.id === keptId);
+    const merged = pantryItemsRef.current.find(item => item.id === mergedId);
+    if (!kept || !merged) return;
+    try {
+      setIsLoading(true);
+      setError(null);
+      const updates = mergePantryItems(kept, merged);
+      const updatedItem = await ApiService.patch<PantryItem>(`/api/v1/pantry/items/${keptId}`, updates);
+      try {
+        await ApiService.delete(`/api/v1/pantry/items/${mergedId}`);
+      } catch (deleteError) {
+        // Duplikat został, więc cofamy zmiany w zachowanym produkcie - inaczej ilość liczyłaby się dwa razy
+        const rollback = Object.fromEntries(
+          Object.keys(updates).map(key => [key, kept[key as keyof PantryItem] ?? null])
+        ) as Partial<PantryItem>;
+        await ApiService.patch(`/api/v1/pantry/items/${keptId}`, rollback).catch(rollbackError =>
+          console.error('Error rolling back pantry merge:', rollbackError)
+        );
+        throw deleteError;
+      }
+      setPantryItems(items =>
+        items
+          .filter(item => item.id !== mergedId)
+          .map(item => (item.id === keptId ? { ...item, ...updates, ...updatedItem } : item))
+      );
+      logActivity('pantry_change', t('cooking.activity.merged', { merged: merged.name, kept: kept.name }), '/cooking');
+    } catch (err) {
+      setError(err instanceof Error ? err.message : 'Failed to merge pantry items');
+      console.error('Error merging pantry items:', err);
+      throw err;
+    } finally {
+      setIsLoading(false);
+    }
+  }, [t]);
 
   // Send message to cooking assistant
   const sendCookingMessage = useCallback(async (content: string, usePerplexity?: boolean, useBielik?: boolean) => {
@@ -150,6 +207,7 @@ export function useCooking() {
         useBielik: useBielik !== undefined ? useBielik : true
       };
       setMessages(prev => [...prev, userMessage]);
+      logActivity('chat', t('cooking.activity.chat', { text: content.slice(0, 60) }), '/cooking');
 
       // Send message to the API
       const response = await ApiService.sendChatMessage({
@@ -177,7 +235,7 @@ export function useCooking() {
       const assistantMessage: Message = {
         id: uuidv4(),
         role: 'assistant',
-        content: response.response || 'Przepraszam, wystąpił błąd w przetwarzaniu.',
+        content: response.response || t('cooking.chat.failed'),
         data: response.data,
         usePerplexity: usePerplexity || false,
         useBielik: useBielik !== undefined ? useBielik : true,
@@ -194,7 +252,7 @@ export function useCooking() {
     } finally {
       setIsLoading(false);
     }
-  }, [fetchPantryItems]);
+  }, [fetchPantryItems, t]);
 
   // Load initial data
   useEffect(() => {
@@ -213,6 +271,7 @@ export function useCooking() {
     addPantryItem,
     deletePantryItem,
     updatePantryItem,
+    mergeDuplicateItems,
     sendCookingMessage,
     usePerplexity,
     togglePerplexity,
@@ -0,0 +1,37 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import {
+  Leftover,
+  addLeftover,
+  getLeftovers,
+  removeLeftover,
+  restoreLeftover,
+  subscribeLeftovers,
+} from '@/lib/leftovers';
+import { useTranslation } from '@/hooks/useTranslation';
+import { pushToast } from '@/lib/toasts';
+
+export function useLeftovers() {
+  const { t } = useTranslation();
+  const [leftovers, setLeftovers] = useState<Leftover[]>([]);
+
+  useEffect(() => {
+    const refresh = () => setLeftovers(getLeftovers());
+    refresh();
+    return subscribeLeftovers(refresh);
+  }, []);
+
+  return {
+    leftovers,
+    addLeftover,
+    // Zjedzone albo wyrzucone - z możliwością cofnięcia
+    removeLeftover: (id: string) => {
+      const removed = removeLeftover(id);
+      if (!removed) return;
+      pushToast(t('cooking.leftovers.removed', { name: removed.name }), {
+        action: { label: t('common.undo'), onClick: () => restoreLeftover(removed) },
+      });
+    },
+  };
+}
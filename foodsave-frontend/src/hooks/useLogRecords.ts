@@ -0,0 +1,16 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { LogRecord, getLogRecords, subscribeLogs } from '@/lib/logger';
+
+export function useLogRecords(): LogRecord[] {
+  const [records, setRecords] = useState<LogRecord[]>([]);
+
+  useEffect(() => {
+    const refresh = () => setRecords(getLogRecords());
+    refresh();
+    return subscribeLogs(refresh);
+  }, []);
+
+  return records;
+}
@@ -0,0 +1,36 @@
+"use client";
+
+import { useEffect, useMemo } from 'react';
+import { getPresetRange } from '@/components/analytics/DateRangeSelector';
+import { useBudget } from '@/hooks/useBudget';
+import { useSpendingStats } from '@/hooks/useSpendingStats';
+import { useTranslation } from '@/hooks/useTranslation';
+import { budgetAlertText, evaluateBudget, takeNewAlerts } from '@/lib/budget';
+import { notify } from '@/lib/notifications';
+
+// Current month's spending evaluated against the budget; fires 80%/100% alerts
+export function useMonthlyBudget() {
+  const { t, locale } = useTranslation();
+  const { budget, updateBudget } = useBudget();
+  const range = useMemo(() => getPresetRange('month'), []);
+  const { data: stats, isLoading, isFetching, refetch } = useSpendingStats(range);
+
+  const statuses = useMemo(
+    () => evaluateBudget(budget, stats?.total ?? 0, stats?.by_category ?? []),
+    [budget, stats],
+  );
+
+  useEffect(() => {
+    if (!stats) return;
+    takeNewAlerts(statuses, range.from.slice(0, 7)).forEach((status) =>
+      notify({
+        category: 'budget',
+        title: t('budget.notificationTitle'),
+        body: budgetAlertText(status, locale),
+        href: '/analytics',
+      }),
+    );
+  }, [statuses, stats, range, t, locale]);
+
+  return { budget, updateBudget, stats, statuses, isLoading, isFetching, refetch };
+}
@@ -0,0 +1,24 @@
+"use client";
+
+import { useMemo } from 'react';
+import { useSpendingStats } from '@/hooks/useSpendingStats';
+import { buildMonthlyReport, monthRange, shiftMonth } from '@/lib/monthlyReport';
+
+// Report for one month (YYYY-MM), compared with the month before
+export function useMonthlyReport(month: string) {
+  const current = useSpendingStats(useMemo(() => monthRange(month), [month]));
+  const previous = useSpendingStats(useMemo(() => monthRange(shiftMonth(month, -1)), [month]));
+
+  const report = useMemo(
+    () => (current.data ? buildMonthlyReport(month, current.data, previous.data) : undefined),
+    [month, current.data, previous.data],
+  );
+
+  return {
+    report,
+    isLoading: current.isLoading || previous.isLoading,
+    isFetching: current.isFetching || previous.isFetching,
+    error: current.error || previous.error,
+    refetch: () => Promise.all([current.refetch(), previous.refetch()]),
+  };
+}
@@ -0,0 +1,16 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { NetworkStatus, getNetworkStatus, subscribeNetwork } from '@/lib/network';
+
+export function useNetworkStatus(): NetworkStatus {
+  const [status, setStatus] = useState<NetworkStatus>('online');
+
+  useEffect(() => {
+    const refresh = () => setStatus(getNetworkStatus());
+    refresh();
+    return subscribeNetwork(refresh);
+  }, []);
+
+  return status;
+}
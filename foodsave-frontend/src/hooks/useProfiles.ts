@@ -0,0 +1,27 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { Profile, getActiveProfile, getProfiles, subscribeProfiles, switchProfile } from '@/lib/profiles';
+
+// Switching reloads the app, so every hook picks up the new profile's history and settings
+export function activateProfile(id: string) {
+  if (id === getActiveProfile().id) return;
+  switchProfile(id);
+  window.location.reload();
+}
+
+export function useProfiles() {
+  const [profiles, setProfiles] = useState<Profile[]>([]);
+  const [active, setActive] = useState<Profile | null>(null);
+
+  useEffect(() => {
+    const refresh = () => {
+      setProfiles(getProfiles());
+      setActive(getActiveProfile());
+    };
+    refresh();
+    return subscribeProfiles(refresh);
+  }, []);
+
+  return { profiles, active };
+}
@@ -0,0 +1,40 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import {
+  Reminder,
+  addReminder,
+  getReminders,
+  removeReminder,
+  restoreReminder,
+  subscribeReminders,
+  toggleReminder,
+  updateReminder,
+} from '@/lib/reminders';
+import { useTranslation } from '@/hooks/useTranslation';
+import { pushToast } from '@/lib/toasts';
+
+export function useReminders() {
+  const { t } = useTranslation();
+  const [reminders, setReminders] = useState<Reminder[]>([]);
+
+  useEffect(() => {
+    const refresh = () => setReminders(getReminders());
+    refresh();
+    return subscribeReminders(refresh);
+  }, []);
+
+  return {
+    reminders,
+    addReminder,
+    toggleReminder,
+    updateReminder,
+    removeReminder: (id: string) => {
+      const removed = removeReminder(id);
+      if (!removed) return;
+      pushToast(t('reminders.deleted', { title: removed.title }), {
+        action: { label: t('common.undo'), onClick: () => restoreReminder(removed) },
+      });
+    },
+  };
+}
@@ -0,0 +1,24 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import {
+  Routine,
+  addRoutine,
+  getRoutines,
+  removeRoutine,
+  runRoutineNow,
+  subscribeRoutines,
+  updateRoutine,
+} from '@/lib/routines';
+
+export function useRoutines() {
+  const [routines, setRoutines] = useState<Routine[]>([]);
+
+  useEffect(() => {
+    const refresh = () => setRoutines(getRoutines());
+    refresh();
+    return subscribeRoutines(refresh);
+  }, []);
+
+  return { routines, addRoutine, updateRoutine, removeRoutine, runRoutineNow };
+}
@@ -0,0 +1,25 @@
+"use client";
+
+import { useCallback, useEffect, useState } from 'react';
+import { AppSettings, DEFAULT_SETTINGS, loadSettings, subscribeSettings, updateSettings } from '@/lib/settings';
+
+export function useSettings() {
+  // Defaults on the first render, stored values after mount (no hydration mismatch)
+  const [settings, setSettings] = useState<AppSettings>(DEFAULT_SETTINGS);
+  const [isLoaded, setIsLoaded] = useState(false);
+
+  useEffect(() => {
+    const refresh = () => {
+      setSettings(loadSettings());
+      setIsLoaded(true);
+    };
+    refresh();
+    return subscribeSettings(refresh);
+  }, []);
+
+  const update = useCallback((changes: Partial<AppSettings>) => {
+    setSettings(updateSettings(changes));
+  }, []);
+
+  return { settings, updateSettings: update, isLoaded };
+}
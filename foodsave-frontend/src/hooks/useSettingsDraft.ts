@@ -0,0 +1,45 @@
+"use client";
+
+import { useCallback, useEffect, useState } from 'react';
+import { AppSettings, settingsEqual } from '@/lib/settings';
+import { useSettings } from './useSettings';
+
+// Editable copy of the settings; nothing is persisted until save()
+export function useSettingsDraft() {
+  const { settings, updateSettings, isLoaded } = useSettings();
+  const [draft, setDraft] = useState<AppSettings>(settings);
+  const [isReady, setIsReady] = useState(false);
+
+  useEffect(() => {
+    if (!isLoaded) return;
+    setDraft(settings);
+    setIsReady(true);
+    // Tylko po wczytaniu - późniejsze zmiany zapisanych ustawień nie nadpisują edycji
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [isLoaded]);
+
+  const change = useCallback((changes: Partial<AppSettings>) => {
+    setDraft((current) => ({ ...current, ...changes }));
+  }, []);
+
+  const save = useCallback(
+    (changes: Partial<AppSettings> = {}) => {
+      const next = { ...draft, ...changes };
+      updateSettings(next);
+      setDraft(next);
+    },
+    [draft, updateSettings],
+  );
+
+  const discard = useCallback(() => setDraft(settings), [settings]);
+
+  return {
+    draft,
+    saved: settings,
+    change,
+    save,
+    discard,
+    isDirty: isReady && !settingsEqual(draft, settings),
+    isLoaded: isReady,
+  };
+}
@@ -3,13 +3,15 @@
 import { useState, useCallback, useEffect } from 'react';
 import { Product, Receipt } from '@/types/shopping';
 import { ApiService } from '@/services/ApiService';
+import { readSnapshot, writeSnapshot } from '@/lib/offlineCache';
 
 interface ProductsResponse {
   products: Product[];
 }
 
 export function useShopping() {
-  const [products, setProducts] = useState<Product[]>([]);
+  // Ostatnia znana lista produktów - widoczna od razu i bez połączenia
+  const [products, setProducts] = useState<Product[]>(() => readSnapshot<Product[]>('products') ?? []);
   const [receipts, setReceipts] = useState<Receipt[]>([]);
   const [isLoading, setIsLoading] = useState(false);
   const [error, setError] = useState<string | null>(null);
@@ -24,8 +26,10 @@ export function useShopping() {
       // Ensure we're dealing with an array of products
       if (Array.isArray(data)) {
         setProducts(data);
+        writeSnapshot('products', data);
       } else if (data && 'products' in data && Array.isArray(data.products)) {
         setProducts(data.products);
+        writeSnapshot('products', data.products);
       } else {
         throw new Error('Unexpected data format from API');
       }
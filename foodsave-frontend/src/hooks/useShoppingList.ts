@@ -0,0 +1,60 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import {
+  ShoppingListItem,
+  addShoppingListItem,
+  clearCheckedItems,
+  findDuplicateItem,
+  getShoppingList,
+  removeShoppingListItem,
+  restoreShoppingListItems,
+  subscribeShoppingList,
+  toggleShoppingListItem,
+} from '@/lib/shoppingList';
+import { translate } from '@/lib/i18n';
+import { loadSettings } from '@/lib/settings';
+import { pushToast } from '@/lib/toasts';
+
+function offerUndo(removed: ShoppingListItem[]) {
+  if (removed.length === 0) return;
+  const { language } = loadSettings();
+  const message =
+    removed.length === 1
+      ? translate(language, 'shopping.list.removedOne', { name: removed[0].name })
+      : translate(language, 'shopping.list.removedMany', { count: removed.length });
+  pushToast(message, {
+    action: { label: translate(language, 'common.undo'), onClick: () => restoreShoppingListItems(removed) },
+  });
+}
+
+// Near-duplicates aren't added silently; the toast lets the user add them anyway
+function addItem(name: string, quantity?: string) {
+  const duplicate = findDuplicateItem(name);
+  if (duplicate) {
+    const { language } = loadSettings();
+    pushToast(translate(language, 'shopping.list.duplicate', { name: duplicate.name }), {
+      action: { label: translate(language, 'shopping.list.addAnyway'), onClick: () => addShoppingListItem(name, quantity) },
+    });
+    return;
+  }
+  addShoppingListItem(name, quantity);
+}
+
+export function useShoppingList() {
+  const [items, setItems] = useState<ShoppingListItem[]>([]);
+
+  useEffect(() => {
+    const refresh = () => setItems(getShoppingList());
+    refresh();
+    return subscribeShoppingList(refresh);
+  }, []);
+
+  return {
+    items,
+    addItem,
+    toggleItem: toggleShoppingListItem,
+    removeItem: (id: string) => offerUndo(removeShoppingListItem(id)),
+    clearChecked: () => offerUndo(clearCheckedItems()),
+  };
+}
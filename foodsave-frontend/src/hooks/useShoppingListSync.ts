@@ -0,0 +1,86 @@
+"use client";
+
+import { useCallback, useEffect, useRef, useState } from 'react';
+import { ApiService } from '@/services/ApiService';
+import { reportError } from '@/lib/errors';
+import { useTranslation } from '@/hooks/useTranslation';
+import { applyTaskSync, getRemovedSyncedIds, getShoppingList, updateShoppingListItem } from '@/lib/shoppingList';
+import { ConflictChoice, MergePicks, needsRemoteUpdate, resolvedVersion } from '@/lib/syncConflicts';
+import type { ShoppingListConflict, ShoppingListSyncStatus } from '@/types/api';
+
+// Two-way sync with Todoist/CalDAV when the backend has it configured:
+// on mount, whenever the window regains focus or the connection comes back, and on demand.
+// Items renamed in the task list are not overwritten; they wait in `conflicts` for the user.
+// Automatic syncs report only the first failure in a row, not one toast per focus.
+export function useShoppingListSync() {
+  const [status, setStatus] = useState<ShoppingListSyncStatus | null>(null);
+  const [isSyncing, setIsSyncing] = useState(false);
+  const [conflicts, setConflicts] = useState<ShoppingListConflict[]>([]);
+  const inFlight = useRef(false);
+  const failing = useRef(false);
+  const { t } = useTranslation();
+
+  const sync = useCallback(async (automatic = false) => {
+    if (inFlight.current) return;
+    inFlight.current = true;
+    setIsSyncing(true);
+    try {
+      const result = await ApiService.syncShoppingList(getShoppingList(), getRemovedSyncedIds());
+      applyTaskSync(result.checked, result.synced, result.removed ?? []);
+      setConflicts(result.conflicts ?? []);
+      failing.current = false;
+    } catch (error) {
+      if (!automatic || !failing.current) {
+        reportError(error, t('shopping.list.syncContext'), () => void sync());
+      }
+      failing.current = true;
+    } finally {
+      inFlight.current = false;
+      setIsSyncing(false);
+    }
+  }, [t]);
+
+  const resolveConflict = useCallback(async (conflict: ShoppingListConflict, choice: ConflictChoice, picks?: MergePicks) => {
+    const item = getShoppingList().find((i) => i.id === conflict.id);
+    if (item) {
+      const version = resolvedVersion(item, conflict, choice, picks);
+      try {
+        if (needsRemoteUpdate(conflict, version)) {
+          await ApiService.resolveShoppingListConflicts([{ ...item, ...version }]);
+        }
+      } catch (error) {
+        reportError(error, t('shopping.list.conflictContext'), () => void resolveConflict(conflict, choice, picks));
+        return;
+      }
+      updateShoppingListItem(item.id, version);
+    }
+    setConflicts((prev) => prev.filter((c) => c.id !== conflict.id));
+  }, [t]);
+
+  // Skipped for now; the next sync reports it again
+  const dismissConflict = useCallback((conflict: ShoppingListConflict) => {
+    setConflicts((prev) => prev.filter((c) => c.id !== conflict.id));
+  }, []);
+
+  useEffect(() => {
+    const controller = new AbortController();
+    ApiService.getShoppingListSyncStatus(controller.signal)
+      .then(setStatus)
+      .catch(() => setStatus({ enabled: false, provider: null }));
+    return () => controller.abort();
+  }, []);
+
+  useEffect(() => {
+    if (!status?.enabled) return;
+    void sync(true);
+    const onFocus = () => void sync(true);
+    window.addEventListener('focus', onFocus);
+    window.addEventListener('online', onFocus);
+    return () => {
+      window.removeEventListener('focus', onFocus);
+      window.removeEventListener('online', onFocus);
+    };
+  }, [status, sync]);
+
+  return { provider: status?.enabled ? status.provider : null, isSyncing, sync, conflicts, resolveConflict, dismissConflict };
+}
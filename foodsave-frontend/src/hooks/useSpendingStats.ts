@@ -0,0 +1,13 @@
+"use client";
+
+import { useQuery } from '@tanstack/react-query';
+import { ApiService } from '@/services/ApiService';
+import { DateRange } from '@/types/analytics';
+
+export function useSpendingStats(range: DateRange) {
+  return useQuery({
+    queryKey: ['spending-stats', range.from, range.to],
+    queryFn: ({ signal }) => ApiService.getSpendingStats(range, signal),
+    staleTime: 5 * 60 * 1000, // 5 minutes
+  });
+}
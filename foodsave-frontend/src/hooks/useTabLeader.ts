@@ -0,0 +1,21 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { TabRole, getTabRole, startTabLeadership, subscribeTabRole } from '@/lib/tabLeader';
+
+export function useTabRole(): TabRole {
+  const [role, setRole] = useState<TabRole>('pending');
+
+  useEffect(() => {
+    const refresh = () => setRole(getTabRole());
+    startTabLeadership();
+    refresh();
+    return subscribeTabRole(refresh);
+  }, []);
+
+  return role;
+}
+
+export function useTabLeader(): boolean {
+  return useTabRole() === 'leader';
+}
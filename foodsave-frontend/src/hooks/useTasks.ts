@@ -0,0 +1,17 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { Task, getActiveTasks, subscribeTasks } from '@/lib/tasks';
+
+// Active tasks, optionally only of one kind (for per-page spinners)
+export function useTasks(kind?: string): Task[] {
+  const [tasks, setTasks] = useState<Task[]>([]);
+
+  useEffect(() => {
+    const refresh = () => setTasks(getActiveTasks());
+    refresh();
+    return subscribeTasks(refresh);
+  }, []);
+
+  return kind ? tasks.filter((task) => task.kind === kind) : tasks;
+}
@@ -0,0 +1,32 @@
+"use client";
+
+import { useEffect, useState } from 'react';
+import { THUMBNAIL_SIZE, getThumbnailUrl, releaseImageUrl } from '@/lib/imageCache';
+
+// Thumbnail object URL for a local file; null until ready (or when it isn't an image)
+export function useThumbnail(file: File | null, maxSize: number = THUMBNAIL_SIZE): string | null {
+  const [url, setUrl] = useState<string | null>(null);
+
+  useEffect(() => {
+    setUrl(null);
+    if (!file || !file.type.startsWith('image/')) return;
+    let active = true;
+    let acquired: string | null = null;
+    getThumbnailUrl(file, maxSize)
+      .then((thumbnail) => {
+        if (!active) {
+          releaseImageUrl(thumbnail);
+          return;
+        }
+        acquired = thumbnail;
+        setUrl(thumbnail);
+      })
+      .catch(() => undefined);
+    return () => {
+      active = false;
+      if (acquired) releaseImageUrl(acquired);
+    };
+  }, [file, maxSize]);
+
+  return url;
+}
@@ -0,0 +1,8 @@
+"use client";
+
+import { useContext } from 'react';
+import { I18nContext } from '@/components/I18nProvider';
+
+export function useTranslation() {
+  return useContext(I18nContext);
+}
@@ -0,0 +1,37 @@
+"use client";
+
+import { useEffect } from 'react';
+import { useTranslation } from './useTranslation';
+
+// Warns before closing the tab and before following in-app links while there are unsaved edits
+export function useUnsavedChangesPrompt(isDirty: boolean, customMessage?: string) {
+  const { t } = useTranslation();
+  const message = customMessage ?? t('common.unsavedChanges');
+
+  useEffect(() => {
+    if (!isDirty) return;
+
+    const onBeforeUnload = (e: BeforeUnloadEvent) => {
+      e.preventDefault();
+      e.returnValue = '';
+    };
+    // Faza capture - przed handlerami nawigacji (router.push) w komponentach
+    const onClick = (e: MouseEvent) => {
+      const link = (e.target as HTMLElement | null)?.closest('a[href]');
+      if (!link || link.getAttribute('target') === '_blank') return;
+      // Kotwice w obrębie strony nie opuszczają jej
+      if (link.getAttribute('href')?.startsWith('#')) return;
+      if (!window.confirm(message)) {
+        e.preventDefault();
+        e.stopPropagation();
+      }
+    };
+
+    window.addEventListener('beforeunload', onBeforeUnload);
+    document.addEventListener('click', onClick, true);
+    return () => {
+      window.removeEventListener('beforeunload', onBeforeUnload);
+      document.removeEventListener('click', onClick, true);
+    };
+  }, [isDirty, message]);
+}
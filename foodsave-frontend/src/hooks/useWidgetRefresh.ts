@@ -0,0 +1,31 @@
+"use client";
+
+import { useEffect, useRef, useState } from 'react';
+import {
+  WidgetId,
+  loadRefreshIntervals,
+  subscribeRefreshAll,
+  subscribeRefreshIntervals,
+} from '@/lib/dashboardRefresh';
+
+// Calls `refresh` on the widget's configured interval and on "refresh all"
+export function useWidgetRefresh(widget: WidgetId, refresh: () => void) {
+  const refreshRef = useRef(refresh);
+  refreshRef.current = refresh;
+
+  const [minutes, setMinutes] = useState(0);
+
+  useEffect(() => {
+    const update = () => setMinutes(loadRefreshIntervals()[widget]);
+    update();
+    return subscribeRefreshIntervals(update);
+  }, [widget]);
+
+  useEffect(() => subscribeRefreshAll(() => refreshRef.current()), []);
+
+  useEffect(() => {
+    if (minutes <= 0) return;
+    const timer = setInterval(() => refreshRef.current(), minutes * 60_000);
+    return () => clearInterval(timer);
+  }, [minutes]);
+}
@@ -0,0 +1,34 @@
+import { clearActivities, getActivities, logActivity, subscribeActivities } from './activityLog';
+
+describe('activityLog', () => {
+  beforeEach(() => {
+    window.localStorage.clear();
+  });
+
+  it('zapisuje zdarzenia od najnowszego', () => {
+    logActivity('receipt_scan', 'Zeskanowano paragon');
+    logActivity('chat', 'Czat: przepis na zupę');
+
+    expect(getActivities().map((a) => a.type)).toEqual(['chat', 'receipt_scan']);
+  });
+
+  it('powiadamia subskrybentów o nowym zdarzeniu', () => {
+    const listener = jest.fn();
+    const unsubscribe = subscribeActivities(listener);
+
+    logActivity('pantry_change', 'Dodano mleko do spiżarni');
+    unsubscribe();
+    logActivity('pantry_change', 'Usunięto mleko ze spiżarni');
+
+    expect(listener).toHaveBeenCalledTimes(1);
+  });
+
+  it('czyści tylko wybrane rodzaje zdarzeń', () => {
+    logActivity('receipt_scan', 'Zeskanowano paragon');
+    logActivity('chat', 'Czat: przepis na zupę');
+
+    clearActivities(['chat']);
+
+    expect(getActivities().map((a) => a.type)).toEqual(['receipt_scan']);
+  });
+});
@@ -0,0 +1,45 @@
+// Local activity log feeding the dashboard "Ostatnie aktywności" card
+
+import { v4 as uuidv4 } from 'uuid';
+import { readJson, subscribeStorage, writeJson } from './storage';
+
+const STORAGE_KEY = 'foodsave.activity';
+const CHANGE_EVENT = 'foodsave:activity';
+const MAX_ENTRIES = 100;
+
+export type ActivityType = 'receipt_scan' | 'receipt_saved' | 'pantry_change' | 'chat';
+
+export interface Activity {
+  id: string;
+  type: ActivityType;
+  title: string;
+  href?: string;
+  timestamp: string; // ISO
+}
+
+export function getActivities(limit = MAX_ENTRIES): Activity[] {
+  return readJson<Activity[]>(STORAGE_KEY, []).slice(0, limit);
+}
+
+export function logActivity(type: ActivityType, title: string, href?: string): Activity {
+  const activity: Activity = { id: uuidv4(), type, title, href, timestamp: new Date().toISOString() };
+  // Newest first, capped so localStorage doesn't grow forever
+  writeJson(STORAGE_KEY, [activity, ...getActivities()].slice(0, MAX_ENTRIES));
+  if (typeof window !== 'undefined') {
+    window.dispatchEvent(new CustomEvent(CHANGE_EVENT));
+  }
+  return activity;
+}
+
+// Clears the whole log, or only entries of the given types
+export function clearActivities(types?: ActivityType[]): void {
+  writeJson(STORAGE_KEY, types ? getActivities().filter((a) => !types.includes(a.type)) : []);
+  if (typeof window !== 'undefined') {
+    window.dispatchEvent(new CustomEvent(CHANGE_EVENT));
+  }
+}
+
+// Notifies about changes from this tab and from other tabs (storage event)
+export function subscribeActivities(listener: () => void): () => void {
+  return subscribeStorage([STORAGE_KEY], CHANGE_EVENT, listener);
+}
@@ -0,0 +1,30 @@
+import { announce, subscribeAnnouncements, toSpokenText } from './announcer';
+
+describe('announcer', () => {
+  it('przekazuje komunikaty subskrybentom, pomija puste', () => {
+    const listener = jest.fn();
+    const unsubscribe = subscribeAnnouncements(listener);
+
+    announce('  Nowa odpowiedź  ');
+    announce('   ');
+    announce('Błąd', 'assertive');
+    unsubscribe();
+    announce('Po wypisaniu');
+
+    expect(listener.mock.calls).toEqual([
+      ['Nowa odpowiedź', 'polite'],
+      ['Błąd', 'assertive'],
+    ]);
+  });
+
+  it('usuwa formatowanie Markdown z czytanego tekstu', () => {
+    const markdown = '## Zupa **pomidorowa**\n\n- 1 kg [pomidorów](https://example.com)\n- `sól`';
+    expect(toSpokenText(markdown)).toBe('Zupa pomidorowa 1 kg pomidorów sól');
+  });
+
+  it('skraca długie odpowiedzi', () => {
+    const spoken = toSpokenText('a '.repeat(200), 20);
+    expect(spoken).toHaveLength(20);
+    expect(spoken.endsWith('…')).toBe(true);
+  });
+});
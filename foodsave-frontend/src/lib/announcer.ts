@@ -0,0 +1,33 @@
+// Screen reader announcements through ARIA live regions rendered by <LiveAnnouncer />.
+// Used for things that appear without focus moving there, e.g. a finished chat answer.
+
+export type Politeness = 'polite' | 'assertive';
+
+type Listener = (message: string, politeness: Politeness) => void;
+
+const listeners = new Set<Listener>();
+
+export function announce(message: string, politeness: Politeness = 'polite'): void {
+  const text = message.trim();
+  if (!text) return;
+  listeners.forEach((listener) => listener(text, politeness));
+}
+
+export function subscribeAnnouncements(listener: Listener): () => void {
+  listeners.add(listener);
+  return () => {
+    listeners.delete(listener);
+  };
+}
+
+// Markdown is read out literally ("gwiazdka gwiazdka"), so strip it and keep it short
+export function toSpokenText(markdown: string, maxLength = 300): string {
+  const text = markdown
+    .replace(/```[\s\S]*?```/g, ' ')
+    .replace(/!?\[([^\]]*)\]\([^)]*\)/g, '$1')
+    .replace(/^\s{0,3}(#{1,6}|>|[-*+]|\d+\.)\s+/gm, '')
+    .replace(/[*_`~]+/g, '')
+    .replace(/\s+/g, ' ')
+    .trim();
+  return text.length > maxLength ? `${text.slice(0, maxLength - 1).trimEnd()}…` : text;
+}
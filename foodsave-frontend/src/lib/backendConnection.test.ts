@@ -0,0 +1,40 @@
+import { testBackendConnection } from './backendConnection';
+
+const mockFetch = (impl: () => Promise<Partial<Response>>) => {
+  global.fetch = jest.fn(impl) as unknown as typeof fetch;
+};
+
+describe('testBackendConnection', () => {
+  it('zwraca wersję i status dla działającego serwera', async () => {
+    mockFetch(async () => ({ ok: true, status: 200, json: async () => ({ status: 'healthy', version: '1.0.0' }) }));
+
+    const result = await testBackendConnection('http://localhost:8000/');
+
+    expect(global.fetch).toHaveBeenCalledWith('http://localhost:8000/health', expect.anything());
+    expect(result.ok).toBe(true);
+    expect(result.version).toBe('1.0.0');
+    expect(result.latencyMs).toBeGreaterThanOrEqual(0);
+  });
+
+  it('traktuje 503 z /health jako osiągalny, ale niezdrowy serwer', async () => {
+    mockFetch(async () => ({ ok: false, status: 503, json: async () => ({ status: 'unhealthy', version: '1.0.0' }) }));
+
+    const result = await testBackendConnection('http://localhost:8000');
+
+    expect(result.ok).toBe(true);
+    expect(result.status).toBe('unhealthy');
+    expect(result.reason).toMatch(/część usług/);
+  });
+
+  it('podaje przyczynę dla 404 i błędu sieci', async () => {
+    mockFetch(async () => ({ ok: false, status: 404, json: async () => ({ detail: 'Not Found' }) }));
+    expect((await testBackendConnection('http://example.com')).reason).toMatch(/\/health/);
+
+    mockFetch(async () => {
+      throw new TypeError('Failed to fetch');
+    });
+    const result = await testBackendConnection('http://nope.invalid');
+    expect(result.ok).toBe(false);
+    expect(result.reason).toMatch(/Nie można połączyć/);
+  });
+});
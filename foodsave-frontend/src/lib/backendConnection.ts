@@ -0,0 +1,64 @@
+// Backend reachability check used by the settings page before saving a new URL
+
+import { Locale, translate } from './i18n';
+
+export interface ConnectionTestResult {
+  ok: boolean;
+  latencyMs?: number;
+  version?: string;
+  status?: string; // "healthy" | "unhealthy" reported by /health
+  reason?: string; // user-facing explanation of the failure, in the given locale
+}
+
+const DEFAULT_TIMEOUT_MS = 5000;
+
+export async function testBackendConnection(
+  baseUrl: string,
+  timeoutMs: number = DEFAULT_TIMEOUT_MS,
+  locale: Locale = 'pl',
+): Promise<ConnectionTestResult> {
+  const controller = new AbortController();
+  const timer = setTimeout(() => controller.abort(), timeoutMs);
+  const started = performance.now();
+
+  try {
+    const response = await fetch(`${baseUrl.replace(/\/+$/, '')}/health`, { signal: controller.signal });
+    const latencyMs = Math.round(performance.now() - started);
+
+    let body: { status?: string; version?: string } | null = null;
+    try {
+      body = await response.json();
+    } catch {
+      body = null;
+    }
+
+    // /health odpowiada 503, gdy serwer działa, ale któraś z usług jest niezdrowa
+    if (body && (response.ok || response.status === 503)) {
+      return {
+        ok: true,
+        latencyMs,
+        version: body.version,
+        status: body.status,
+        reason: response.ok ? undefined : translate(locale, 'connection.degraded'),
+      };
+    }
+    if (response.status === 404) {
+      return { ok: false, latencyMs, reason: translate(locale, 'connection.noHealth') };
+    }
+    if (!body) {
+      return { ok: false, latencyMs, reason: translate(locale, 'connection.badResponse', { status: response.status }) };
+    }
+    return { ok: false, latencyMs, reason: translate(locale, 'connection.httpError', { status: response.status }) };
+  } catch (error) {
+    if (controller.signal.aborted) {
+      return { ok: false, reason: translate(locale, 'connection.timeout', { seconds: timeoutMs / 1000 }) };
+    }
+    // fetch nie rozróżnia błędów DNS, odmowy połączenia i CORS
+    return {
+      ok: false,
+      reason: translate(locale, 'connection.unreachable'),
+    };
+  } finally {
+    clearTimeout(timer);
+  }
+}
@@ -0,0 +1,49 @@
+import { budgetStatus, evaluateBudget, monthProgress, takeNewAlerts } from './budget';
+import { money } from './money';
+
+describe('budget', () => {
+  beforeEach(() => {
+    window.localStorage.clear();
+  });
+
+  it('wyznacza poziom ostrzeżenia od 80% i przekroczenia od 100%', () => {
+    expect(budgetStatus('x', 79, 100).level).toBe('ok');
+    expect(budgetStatus('x', 80, 100).level).toBe('warning');
+    expect(budgetStatus('x', 100, 100).level).toBe('exceeded');
+  });
+
+  it('uwzględnia tylko kategorie z ustawionym limitem', () => {
+    const statuses = evaluateBudget(
+      { monthly: 1000, categories: { Nabiał: 100, Pieczywo: 0 } },
+      500,
+      [{ label: 'Nabiał', total: 90 }, { label: 'Pieczywo', total: 40 }],
+    );
+
+    expect(statuses.map((s) => s.label)).toEqual(['Łącznie', 'Nabiał']);
+    expect(statuses[1].level).toBe('warning');
+  });
+
+  it('powiadamia o progu tylko raz w miesiącu', () => {
+    const statuses = [budgetStatus('Łącznie', 900, 1000)];
+
+    expect(takeNewAlerts(statuses, '2024-05')).toHaveLength(1);
+    expect(takeNewAlerts(statuses, '2024-05')).toHaveLength(0);
+    expect(takeNewAlerts(statuses, '2024-06')).toHaveLength(1);
+  });
+
+  it('liczy pozostałą kwotę i dni do końca miesiąca', () => {
+    const progress = monthProgress(600, 1000, new Date(2024, 1, 20)); // 20 lutego 2024 (rok przestępny)
+
+    expect(progress.remaining).toEqual(money(400));
+    expect(progress.daysLeft).toBe(10);
+    expect(progress.dailyAllowance).toEqual(money(40));
+  });
+
+  it('liczy w groszach bez błędów zaokrągleń', () => {
+    const progress = monthProgress(99.9, 100, new Date(2024, 1, 29));
+
+    expect(progress.remaining.minor).toBe(10);
+    expect(progress.dailyAllowance.minor).toBe(10);
+    expect(budgetStatus('x', 0.7 + 0.1, 1).level).toBe('warning');
+  });
+});
@@ -0,0 +1,108 @@
+import { Locale, translate } from './i18n';
+import { Money, divideMoney, money, subtractMoney } from './money';
+import { readJson, writeJson } from './storage';
+
+const BUDGET_KEY = 'foodsave.budget';
+const NOTIFIED_KEY = 'foodsave.budget.notified';
+
+export const WARNING_THRESHOLD = 0.8;
+
+// Label of the overall status; also part of the alert keys, so it stays the same in every language
+export const TOTAL_LABEL = 'Łącznie';
+
+export interface BudgetSettings {
+  monthly: number | null; // globalny limit miesięczny w zł
+  categories: Record<string, number>; // limity per kategoria
+}
+
+export type BudgetLevel = 'ok' | 'warning' | 'exceeded';
+
+export interface BudgetStatus {
+  label: string;
+  spent: Money;
+  limit: Money;
+  ratio: number;
+  level: BudgetLevel;
+}
+
+export const emptyBudget: BudgetSettings = { monthly: null, categories: {} };
+
+export function loadBudget(): BudgetSettings {
+  return { ...emptyBudget, ...readJson<Partial<BudgetSettings>>(BUDGET_KEY, {}) };
+}
+
+export function saveBudget(budget: BudgetSettings): void {
+  writeJson(BUDGET_KEY, budget);
+}
+
+// Kwoty w zł jak z API/ustawień; porównujemy w groszach
+export function budgetStatus(label: string, spent: number, limit: number): BudgetStatus {
+  const spentMoney = money(spent);
+  const limitMoney = money(limit);
+  const ratio = limitMoney.minor > 0 ? spentMoney.minor / limitMoney.minor : 0;
+  const level: BudgetLevel = ratio >= 1 ? 'exceeded' : ratio >= WARNING_THRESHOLD ? 'warning' : 'ok';
+  return { label, spent: spentMoney, limit: limitMoney, ratio, level };
+}
+
+// Global status first, then categories that have a limit set
+export function evaluateBudget(
+  budget: BudgetSettings,
+  total: number,
+  byCategory: { label: string; total: number }[],
+): BudgetStatus[] {
+  const statuses: BudgetStatus[] = [];
+  if (budget.monthly && budget.monthly > 0) {
+    statuses.push(budgetStatus(TOTAL_LABEL, total, budget.monthly));
+  }
+  for (const [category, limit] of Object.entries(budget.categories)) {
+    if (limit <= 0) continue;
+    const spent = byCategory.find((b) => b.label === category)?.total ?? 0;
+    statuses.push(budgetStatus(category, spent, limit));
+  }
+  return statuses;
+}
+
+export function budgetLabel(status: BudgetStatus, locale: Locale = 'pl'): string {
+  return status.label === TOTAL_LABEL ? translate(locale, 'budget.total') : status.label;
+}
+
+export function budgetAlertText(status: BudgetStatus, locale: Locale = 'pl'): string {
+  const args = { label: budgetLabel(status, locale), percent: Math.round(status.ratio * 100) };
+  return translate(locale, status.level === 'exceeded' ? 'budget.exceeded' : 'budget.warning', args);
+}
+
+/**
+ * Returns statuses that crossed a threshold not yet announced this month
+ * and remembers them, so each alert fires once per month.
+ */
+export function takeNewAlerts(statuses: BudgetStatus[], month: string): BudgetStatus[] {
+  const notified = readJson<Record<string, string>>(NOTIFIED_KEY, {});
+  const fresh = statuses.filter((s) => {
+    if (s.level === 'ok') return false;
+    const key = `${month}:${s.label}:${s.level}`;
+    if (notified[key]) return false;
+    notified[key] = new Date().toISOString();
+    return true;
+  });
+  // Keep only entries for the current month
+  const pruned = Object.fromEntries(Object.entries(notified).filter(([key]) => key.startsWith(`${month}:`)));
+  writeJson(NOTIFIED_KEY, pruned);
+  return fresh;
+}
+
+export interface MonthProgress {
+  remaining: Money;
+  daysLeft: number; // including today
+  dailyAllowance: Money; // remaining / daysLeft rounded down, 0 when over budget
+}
+
+export function monthProgress(spent: number, limit: number, today: Date = new Date()): MonthProgress {
+  const lastDay = new Date(today.getFullYear(), today.getMonth() + 1, 0).getDate();
+  const daysLeft = lastDay - today.getDate() + 1;
+  const remaining = subtractMoney(money(limit), money(spent));
+  return {
+    remaining,
+    daysLeft,
+    dailyAllowance: remaining.minor > 0 ? divideMoney(remaining, daysLeft) : money(0),
+  };
+}
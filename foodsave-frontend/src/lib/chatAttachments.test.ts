@@ -0,0 +1,52 @@
+import {
+  MAX_ATTACHMENT_ITEMS,
+  pantryAttachment,
+  receiptsAttachment,
+  shoppingListAttachment,
+} from './chatAttachments';
+import { formatMoney, money } from './money';
+
+describe('chatAttachments', () => {
+  it('opisuje produkty ze spiżarni z ilością i datą ważności', () => {
+    const snapshot = pantryAttachment([
+      { id: '1', name: 'Mleko', quantity: 2, unit: 'l', price: 3.5, expiry_date: '2026-10-20T00:00:00' },
+      { id: '2', name: 'Sól', quantity: 0, unit: '', price: 2 },
+    ]);
+    expect(snapshot.title).toBe('Spiżarnia');
+    expect(snapshot.items).toEqual(['Mleko – 2 l (ważne do 2026-10-20)', 'Sól']);
+  });
+
+  it('streszcza paragony: data, sklep, kwota i produkty', () => {
+    const snapshot = receiptsAttachment([
+      {
+        id: 1,
+        trip_date: '2026-10-12',
+        store_name: 'Biedronka',
+        total_amount: 12.5,
+        products: [
+          { id: 1, trip_id: 1, name: 'Chleb', quantity: 1, is_consumed: false },
+          { id: 2, trip_id: 1, name: 'Masło', quantity: 1, is_consumed: false },
+        ],
+      },
+    ]);
+    expect(snapshot.items).toEqual([`2026-10-12 Biedronka, ${formatMoney(money(12.5))}: Chleb, Masło`]);
+  });
+
+  it('z listy zakupów bierze tylko nieodhaczone pozycje', () => {
+    const snapshot = shoppingListAttachment([
+      { id: '1', name: 'Jajka', quantity: '10 szt.', checked: false, createdAt: '' },
+      { id: '2', name: 'Kawa', checked: true, createdAt: '' },
+    ]);
+    expect(snapshot.items).toEqual(['Jajka (10 szt.)']);
+  });
+
+  it('ogranicza liczbę pozycji w załączniku', () => {
+    const items = Array.from({ length: MAX_ATTACHMENT_ITEMS + 10 }, (_, i) => ({
+      id: String(i),
+      name: `Produkt ${i}`,
+      checked: false,
+      createdAt: '',
+    }));
+    expect(shoppingListAttachment(items).items).toHaveLength(MAX_ATTACHMENT_ITEMS);
+  });
+});
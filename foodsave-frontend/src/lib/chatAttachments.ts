@@ -0,0 +1,55 @@
+// Snapshots of app data (pantry, recent receipts, shopping list) attached to chat questions
+
+import { ContextAttachment, ContextAttachmentKind } from '@/types/chat';
+import { Product, ShoppingTrip } from '@/types/shopping';
+import { formatMoney, money } from './money';
+import { ShoppingListItem } from './shoppingList';
+import { MessageKey, translate } from './i18n';
+
+// Keeps the prompt small even for a well-stocked pantry
+export const MAX_ATTACHMENT_ITEMS = 50;
+
+// Receipts from today and the six days before
+export const RECEIPT_DAYS = 7;
+
+export const ATTACHMENT_KINDS: ContextAttachmentKind[] = ['pantry', 'receipts', 'shopping_list'];
+
+export const ATTACHMENT_LABELS: Record<ContextAttachmentKind, MessageKey> = {
+  pantry: 'chat.attachment.pantry',
+  receipts: 'chat.attachment.receipts',
+  shopping_list: 'chat.attachment.shoppingList',
+};
+
+// The snapshot goes into the prompt, which stays Polish like the rest of the item lines
+function attachment(kind: ContextAttachmentKind, items: string[]): ContextAttachment {
+  return { kind, title: translate('pl', ATTACHMENT_LABELS[kind]), items: items.slice(0, MAX_ATTACHMENT_ITEMS) };
+}
+
+export function pantryAttachment(products: Product[]): ContextAttachment {
+  return attachment(
+    'pantry',
+    products.map((product) => {
+      const amount = product.quantity ? ` – ${product.quantity} ${product.unit ?? ''}`.trimEnd() : '';
+      const expiry = product.expiry_date ? ` (ważne do ${product.expiry_date.slice(0, 10)})` : '';
+      return `${product.name}${amount}${expiry}`;
+    }),
+  );
+}
+
+export function receiptsAttachment(trips: ShoppingTrip[]): ContextAttachment {
+  return attachment(
+    'receipts',
+    trips.map((trip) => {
+      const total = trip.total_amount != null ? `, ${formatMoney(money(trip.total_amount))}` : '';
+      const products = trip.products.map((product) => product.name).join(', ');
+      return `${trip.trip_date.slice(0, 10)} ${trip.store_name}${total}${products ? `: ${products}` : ''}`;
+    }),
+  );
+}
+
+export function shoppingListAttachment(items: ShoppingListItem[]): ContextAttachment {
+  return attachment(
+    'shopping_list',
+    items.filter((item) => !item.checked).map((item) => (item.quantity ? `${item.name} (${item.quantity})` : item.name)),
+  );
+}
@@ -0,0 +1,137 @@
+import {
+  appendChatMessages,
+  archiveConversation,
+  clearChatHistory,
+  conversationTitle,
+  getChatHistory,
+  getConversationTags,
+  getSavedConversations,
+  restoreConversation,
+  saveChatHistory,
+  saveConversation,
+  setConversationTags,
+} from './chatHistory';
+
+describe('chatHistory', () => {
+  beforeEach(() => {
+    window.localStorage.clear();
+  });
+
+  it('zapisuje rozmowy osobno dla kontekstów, bez błędów i pustych wiadomości', () => {
+    saveChatHistory('general', 's1', [
+      { id: '1', role: 'user', content: 'Co na obiad?' },
+      { id: '2', role: 'assistant', content: '' },
+      { id: '3', role: 'assistant', content: 'Wystąpił błąd', isError: true },
+    ]);
+    saveChatHistory('cooking', 's2', [{ id: '4', role: 'user', content: 'Przepis na pierogi' }]);
+
+    expect(getChatHistory('general')?.messages.map((m) => m.id)).toEqual(['1']);
+
+    clearChatHistory('general');
+    expect(getChatHistory('general')).toBeUndefined();
+    expect(getChatHistory('cooking')?.sessionId).toBe('s2');
+  });
+
+  it('zapamiętuje wymuszony język odpowiedzi rozmowy', () => {
+    saveChatHistory('general', 's1', [{ id: '1', role: 'user', content: 'Hello' }], 'en');
+    saveChatHistory('cooking', 's2', [{ id: '2', role: 'user', content: 'Cześć' }]);
+
+    expect(getChatHistory('general')?.language).toBe('en');
+    expect(getChatHistory('cooking')?.language).toBeUndefined();
+  });
+
+  it('dopisuje notatkę z pulpitu do bieżącej rozmowy', () => {
+    saveChatHistory('general', 's1', [{ id: '1', role: 'user', content: 'Hello' }], 'en');
+
+    appendChatMessages('general', 's1', [
+      { id: '2', role: 'user', content: 'Kup mleko' },
+      { id: '3', role: 'assistant', content: 'Zanotowane' },
+    ]);
+
+    expect(getChatHistory('general')?.messages.map((m) => m.id)).toEqual(['1', '2', '3']);
+    expect(getChatHistory('general')?.language).toBe('en');
+  });
+
+  it('dopisuje notatkę do zarchiwizowanej rozmowy, gdy w trakcie wysyłania zaczęto nową', () => {
+    saveChatHistory('general', 's1', [{ id: '1', role: 'user', content: 'Co na obiad?' }]);
+    archiveConversation('general');
+    saveChatHistory('general', 's2', [{ id: '2', role: 'user', content: 'Jaka pogoda?' }]);
+
+    appendChatMessages('general', 's1', [{ id: '3', role: 'user', content: 'Kup mleko' }]);
+
+    expect(getChatHistory('general')?.messages.map((m) => m.id)).toEqual(['2']);
+    expect(getSavedConversations('general')[0].messages.map((m) => m.id)).toEqual(['1', '3']);
+  });
+
+  it('archiwizuje rozmowę z tagami i filtruje zapisane rozmowy po tagu', () => {
+    saveChatHistory('general', 's1', [{ id: '1', role: 'user', content: 'Jak upiec chleb?' }]);
+    setConversationTags('s1', ['Gotowanie', 'Gotowanie', ' ']);
+    // Kolejny zapis tej samej rozmowy nie gubi tagów
+    saveChatHistory('general', 's1', [{ id: '1', role: 'user', content: 'Jak upiec chleb?' }]);
+    archiveConversation('general');
+    saveChatHistory('general', 's2', [{ id: '2', role: 'user', content: 'Drukarka nie działa' }]);
+    archiveConversation('general');
+
+    expect(getChatHistory('general')).toBeUndefined();
+    expect(getSavedConversations('general').map((c) => c.sessionId)).toEqual(['s2', 's1']);
+    expect(getSavedConversations('general', 'Gotowanie').map((c) => c.sessionId)).toEqual(['s1']);
+
+    setConversationTags('s2', ['Sprzęt']);
+    expect(getConversationTags()).toEqual(['Gotowanie', 'Zakupy', 'Pomoc techniczna', 'Sprzęt']);
+  });
+
+  it('nie archiwizuje rozmów bez pytań użytkownika', () => {
+    saveChatHistory('general', 's1', [{ id: '1', role: 'assistant', content: 'Cześć!' }]);
+    archiveConversation('general');
+
+    expect(getSavedConversations()).toEqual([]);
+  });
+
+  it('przywraca zapisaną rozmowę i archiwizuje bieżącą', () => {
+    saveChatHistory('general', 's1', [{ id: '1', role: 'user', content: 'Pierwsza' }]);
+    archiveConversation('general');
+    saveChatHistory('general', 's2', [{ id: '2', role: 'user', content: 'Druga' }]);
+
+    expect(restoreConversation('s1')?.sessionId).toBe('s1');
+    expect(getChatHistory('general')?.sessionId).toBe('s1');
+    expect(getSavedConversations().map((c) => c.sessionId)).toEqual(['s2']);
+  });
+
+  it('tytułem rozmowy jest pierwsze pytanie', () => {
+    expect(conversationTitle([{ id: '1', role: 'user', content: '  Co\nna obiad?' }])).toBe('Co na obiad?');
+    expect(conversationTitle([])).toBe('Nowa rozmowa');
+  });
+
+  it('zachowuje przypięte odpowiedzi spoza limitu wiadomości', () => {
+    const messages = Array.from({ length: 105 }, (_, i) => ({
+      id: String(i),
+      role: 'assistant' as const,
+      content: `Odpowiedź ${i}`,
+      pinned: i === 1,
+    }));
+    saveChatHistory('general', 's1', messages);
+
+    const ids = getChatHistory('general')?.messages.map((m) => m.id) ?? [];
+    expect(ids.length).toBe(101);
+    expect(ids[0]).toBe('1');
+    expect(ids[1]).toBe('5');
+  });
+
+  it('zapisuje rozmowę prosto do archiwum, nie ruszając bieżącej', () => {
+    saveChatHistory('general', 'open', [{ id: '1', role: 'user', content: 'Bieżąca' }]);
+    saveConversation('general', 'routine', [{ id: '2', role: 'user', content: 'Plan tygodnia' }], ['Rutyny']);
+
+    expect(getChatHistory('general')?.sessionId).toBe('open');
+    expect(getSavedConversations('general', 'Rutyny').map((c) => c.sessionId)).toEqual(['routine']);
+  });
+
+  it('wysyłanie przerwane przeładowaniem zapisuje jako nieudane', () => {
+    saveChatHistory('general', 's1', [
+      { id: '1', role: 'user', content: 'Dostarczone', status: 'delivered' },
+      { id: '2', role: 'user', content: 'W drodze', status: 'sending' },
+      { id: '3', role: 'user', content: 'Offline', status: 'queued' },
+    ]);
+
+    expect(getChatHistory('general')?.messages.map((m) => m.status)).toEqual(['delivered', 'failed', 'queued']);
+  });
+});
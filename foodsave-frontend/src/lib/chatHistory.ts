@@ -0,0 +1,171 @@
+// Chat conversations kept locally per context, so they survive reloads and work offline.
+// Finished conversations move to an archive ("Nowy czat") where they can be tagged and reopened.
+
+import { profileStorageKey } from './profiles';
+import { readJson, subscribeStorage, writeJson } from './storage';
+import { Message, ReplyLanguage } from '@/types/chat';
+
+const STORAGE_KEY = 'foodsave.chatHistory';
+const ARCHIVE_KEY = 'foodsave.chatArchive';
+const CHANGE_EVENT = 'foodsave:chat-history';
+const MAX_MESSAGES = 100;
+const MAX_ARCHIVED = 50;
+const TITLE_LENGTH = 60;
+
+export const DEFAULT_CHAT_TAGS = ['Gotowanie', 'Zakupy', 'Pomoc techniczna'];
+
+export interface ChatHistoryEntry {
+  sessionId: string;
+  messages: Message[];
+  language?: ReplyLanguage;
+  tags?: string[];
+}
+
+export interface SavedConversation extends ChatHistoryEntry {
+  context: string;
+  updatedAt: number;
+}
+
+type ChatHistoryStore = Record<string, ChatHistoryEntry>;
+
+function notify() {
+  window.dispatchEvent(new CustomEvent(CHANGE_EVENT));
+}
+
+function loadArchive(): SavedConversation[] {
+  return readJson<SavedConversation[]>(profileStorageKey(ARCHIVE_KEY), []);
+}
+
+function saveArchive(archive: SavedConversation[]) {
+  writeJson(profileStorageKey(ARCHIVE_KEY), archive.slice(0, MAX_ARCHIVED));
+}
+
+export function getChatHistory(context: string): ChatHistoryEntry | undefined {
+  return readJson<ChatHistoryStore>(profileStorageKey(STORAGE_KEY), {})[context];
+}
+
+export function getAllChatHistory(): ChatHistoryStore {
+  return readJson<ChatHistoryStore>(profileStorageKey(STORAGE_KEY), {});
+}
+
+export function saveChatHistory(
+  context: string,
+  sessionId: string,
+  messages: Message[],
+  language: ReplyLanguage = 'auto',
+): void {
+  // Błędy i puste wiadomości strumieniowe nie mają sensu po przeładowaniu;
+  // wysyłanie przerwane przeładowaniem strony zapisujemy jako nieudane
+  const valid = messages
+    .filter((m) => !m.isError && m.content)
+    .map((m) => (m.status === 'sending' ? { ...m, status: 'failed' as const } : m));
+  // Przypięte odpowiedzi zostają, nawet gdy wypadłyby poza limit wiadomości
+  const kept = [...valid.slice(0, -MAX_MESSAGES).filter((m) => m.pinned), ...valid.slice(-MAX_MESSAGES)];
+  const previous = getChatHistory(context);
+  const tags = previous?.sessionId === sessionId ? previous.tags : undefined;
+  const entry: ChatHistoryEntry = {
+    sessionId,
+    messages: kept,
+    ...(language !== 'auto' && { language }),
+    ...(tags?.length && { tags }),
+  };
+  writeJson(profileStorageKey(STORAGE_KEY), { ...getAllChatHistory(), [context]: entry });
+  notify();
+}
+
+// Adds messages sent from outside the chat (the dashboard quick note) to their conversation
+export function appendChatMessages(context: string, sessionId: string, messages: Message[]): void {
+  const current = getChatHistory(context);
+  if (!current || current.sessionId === sessionId) {
+    saveChatHistory(context, sessionId, [...(current?.messages ?? []), ...messages], current?.language);
+    return;
+  }
+  // Rozmowa została w międzyczasie zamknięta - notatka trafia do niej w archiwum
+  const archived = loadArchive().find((c) => c.sessionId === sessionId);
+  saveConversation(context, sessionId, [...(archived?.messages ?? []), ...messages], archived?.tags);
+}
+
+// Clears one conversation, or all of them together with the archive
+export function clearChatHistory(context?: string): void {
+  const kept = context ? Object.entries(getAllChatHistory()).filter(([key]) => key !== context) : [];
+  writeJson(profileStorageKey(STORAGE_KEY), Object.fromEntries(kept));
+  if (!context) window.localStorage.removeItem(profileStorageKey(ARCHIVE_KEY));
+  notify();
+}
+
+// First question of the conversation, shortened
+export function conversationTitle(messages: Message[]): string {
+  const first = messages.find((m) => m.role === 'user')?.content.replace(/\s+/g, ' ').trim();
+  if (!first) return 'Nowa rozmowa';
+  return first.length > TITLE_LENGTH ? `${first.slice(0, TITLE_LENGTH - 1).trimEnd()}…` : first;
+}
+
+// Moves the current conversation of a context to the archive; greetings-only chats are dropped
+export function archiveConversation(context: string): void {
+  const current = getChatHistory(context);
+  clearChatHistory(context);
+  if (!current || !current.messages.some((m) => m.role === 'user')) return;
+  const saved: SavedConversation = { ...current, context, updatedAt: Date.now() };
+  saveArchive([saved, ...loadArchive().filter((c) => c.sessionId !== current.sessionId)]);
+  notify();
+}
+
+// Stores a conversation that was never open in the chat, e.g. the result of a routine
+export function saveConversation(context: string, sessionId: string, messages: Message[], tags: string[] = []): void {
+  const saved: SavedConversation = { sessionId, messages, context, updatedAt: Date.now(), ...(tags.length && { tags }) };
+  saveArchive([saved, ...loadArchive().filter((c) => c.sessionId !== sessionId)]);
+  notify();
+}
+
+// Newest first, optionally only those with the given tag
+export function getSavedConversations(context?: string, tag?: string): SavedConversation[] {
+  return loadArchive()
+    .filter((c) => (!context || c.context === context) && (!tag || c.tags?.includes(tag)))
+    .sort((a, b) => b.updatedAt - a.updatedAt);
+}
+
+// Reopens an archived conversation; the one currently open in that context is archived
+export function restoreConversation(sessionId: string): SavedConversation | undefined {
+  const saved = loadArchive().find((c) => c.sessionId === sessionId);
+  if (!saved) return undefined;
+  archiveConversation(saved.context);
+  saveArchive(loadArchive().filter((c) => c.sessionId !== sessionId));
+  const entry: ChatHistoryEntry = {
+    sessionId: saved.sessionId,
+    messages: saved.messages,
+    language: saved.language,
+    tags: saved.tags,
+  };
+  writeJson(profileStorageKey(STORAGE_KEY), { ...getAllChatHistory(), [saved.context]: entry });
+  notify();
+  return saved;
+}
+
+export function deleteSavedConversation(sessionId: string): void {
+  saveArchive(loadArchive().filter((c) => c.sessionId !== sessionId));
+  notify();
+}
+
+// Tags work the same for the open conversation and the archived ones
+export function setConversationTags(sessionId: string, tags: string[]): void {
+  const unique = Array.from(new Set(tags.map((tag) => tag.trim()).filter(Boolean)));
+  const store = getAllChatHistory();
+  const context = Object.keys(store).find((key) => store[key].sessionId === sessionId);
+  if (context) {
+    writeJson(profileStorageKey(STORAGE_KEY), { ...store, [context]: { ...store[context], tags: unique } });
+  } else {
+    saveArchive(loadArchive().map((c) => (c.sessionId === sessionId ? { ...c, tags: unique } : c)));
+  }
+  notify();
+}
+
+// Suggested tags first, then the user's own
+export function getConversationTags(): string[] {
+  const used = [...Object.values(getAllChatHistory()), ...loadArchive()].flatMap((c) => c.tags ?? []);
+  return Array.from(new Set([...DEFAULT_CHAT_TAGS, ...used]));
+}
+
+export function subscribeChatHistory(listener: () => void): () => void {
+  const keys = () => [profileStorageKey(STORAGE_KEY), profileStorageKey(ARCHIVE_KEY)];
+  return subscribeStorage(keys, CHANGE_EVENT, listener);
+}
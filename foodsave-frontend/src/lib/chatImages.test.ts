@@ -0,0 +1,48 @@
+import { extractImages, isImageSource, stripDataUris, withoutImageData } from './chatImages';
+
+const PNG = 'data:image/png;base64,iVBORw0KGgo=';
+
+describe('chatImages', () => {
+  it('znajduje linki do obrazów i obrazy base64 w treści, pomijając obrazy Markdown', () => {
+    const images = extractImages(`Wykres: https://example.com/chart.png?v=2 oraz ![logo](https://example.com/logo.png) ${PNG}`);
+
+    expect(images.map((image) => image.src)).toEqual(['https://example.com/chart.png?v=2', PNG]);
+  });
+
+  it('zbiera obrazy z danych odpowiedzi bez powtórzeń', () => {
+    const images = extractImages('https://example.com/a.jpg', {
+      chart: { image_base64: 'QUJD', mime_type: 'image/png', title: 'Wydatki w październiku' },
+      links: ['https://example.com/a.jpg', 'https://example.com/raport.pdf'],
+    });
+
+    expect(images).toEqual([
+      { src: 'https://example.com/a.jpg', alt: 'Obraz z odpowiedzi' },
+      { src: 'data:image/png;base64,QUJD', alt: 'Wydatki w październiku' },
+    ]);
+  });
+
+  it('rozpoznaje tylko adresy obrazów', () => {
+    expect(isImageSource('https://example.com/photo.webp')).toBe(true);
+    expect(isImageSource(PNG)).toBe(true);
+    expect(isImageSource('https://example.com/index.html')).toBe(false);
+    expect(isImageSource('zwykły tekst')).toBe(false);
+  });
+
+  it('usuwa base64 z wyświetlanej treści', () => {
+    expect(stripDataUris(`Oto wykres: ${PNG}`)).toBe('Oto wykres: ');
+  });
+
+  it('pomija obrazy w podglądzie danych odpowiedzi', () => {
+    expect(
+      withoutImageData({
+        chart: { image_base64: 'QUJD', mime_type: 'image/png', title: 'Wydatki' },
+        items: [PNG, 'Mleko'],
+        total: 12.5,
+      }),
+    ).toEqual({
+      chart: { image_base64: '[obraz]', mime_type: 'image/png', title: 'Wydatki' },
+      items: ['[obraz]', 'Mleko'],
+      total: 12.5,
+    });
+  });
+});
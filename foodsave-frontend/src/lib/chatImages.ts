@@ -0,0 +1,84 @@
+// Images in assistant answers: bare image links, base64 data URIs and image fields in the
+// structured `data` (e.g. a chart from the analytics agent). Markdown images with a web
+// address are rendered by the Markdown renderer itself, so they are not collected here.
+
+export interface InlineImage {
+  src: string;
+  alt: string;
+}
+
+// Limits how deep and how many images are collected from one answer
+const MAX_IMAGES = 8;
+const MAX_DATA_DEPTH = 4;
+
+const IMAGE_URL = /https?:\/\/[^\s)<>"']+\.(?:png|jpe?g|gif|webp|svg)(?:\?[^\s)<>"']*)?/gi;
+const DATA_URI = /data:image\/(?:png|jpeg|gif|webp|svg\+xml);base64,[A-Za-z0-9+/=]+/g;
+const MARKDOWN_IMAGE = /!\[[^\]]*\]\(https?:[^)]*\)/g;
+
+export function isImageSource(value: string): boolean {
+  return new RegExp(`^(?:${IMAGE_URL.source}|${DATA_URI.source})$`, 'i').test(value.trim());
+}
+
+// Fields like {image_base64: "...", mime_type: "image/png"} carry the payload without the prefix
+function base64Field(record: Record<string, unknown>): string | undefined {
+  const payload = record.image_base64 ?? record.base64;
+  if (typeof payload !== 'string' || !/^[A-Za-z0-9+/=\s]+$/.test(payload)) return undefined;
+  const mime = typeof record.mime_type === 'string' ? record.mime_type : 'image/png';
+  return `data:${mime};base64,${payload.replace(/\s/g, '')}`;
+}
+
+function collectFromData(value: unknown, depth: number, found: InlineImage[]): void {
+  if (depth > MAX_DATA_DEPTH || found.length >= MAX_IMAGES || value == null) return;
+  if (typeof value === 'string') {
+    if (isImageSource(value)) found.push({ src: value.trim(), alt: 'Obraz z odpowiedzi' });
+    return;
+  }
+  if (Array.isArray(value)) {
+    value.forEach((item) => collectFromData(item, depth + 1, found));
+    return;
+  }
+  if (typeof value === 'object') {
+    const record = value as Record<string, unknown>;
+    const inline = base64Field(record);
+    if (inline) {
+      const alt = typeof record.title === 'string' ? record.title : 'Obraz z odpowiedzi';
+      found.push({ src: inline, alt });
+      return;
+    }
+    Object.values(record).forEach((item) => collectFromData(item, depth + 1, found));
+  }
+}
+
+export function extractImages(content: string, data?: unknown): InlineImage[] {
+  const text = content.replace(MARKDOWN_IMAGE, '');
+  const found: InlineImage[] = [
+    ...(text.match(IMAGE_URL) ?? []).map((src) => ({ src, alt: 'Obraz z odpowiedzi' })),
+    ...(text.match(DATA_URI) ?? []).map((src) => ({ src, alt: 'Obraz z odpowiedzi' })),
+  ];
+  collectFromData(data, 0, found);
+  const unique = found.filter((image, index) => found.findIndex((other) => other.src === image.src) === index);
+  return unique.slice(0, MAX_IMAGES);
+}
+
+// Answer text without the base64 blobs, which would otherwise fill the bubble
+export function stripDataUris(content: string): string {
+  return content.replace(DATA_URI, '');
+}
+
+const IMAGE_PLACEHOLDER = '[obraz]';
+
+// Structured data for the raw dump under an answer, with image payloads replaced by a
+// placeholder - the images themselves are already shown above it
+export function withoutImageData(value: unknown): unknown {
+  if (typeof value === 'string') return value.replace(DATA_URI, IMAGE_PLACEHOLDER);
+  if (Array.isArray(value)) return value.map(withoutImageData);
+  if (value && typeof value === 'object') {
+    const record = value as Record<string, unknown>;
+    const inline = base64Field(record) !== undefined;
+    const isPayload = (key: string) => inline && (key === 'image_base64' || key === 'base64');
+    return Object.fromEntries(
+      Object.entries(record).map(([key, item]) => [key, isPayload(key) ? IMAGE_PLACEHOLDER : withoutImageData(item)]),
+    );
+  }
+  return value;
+}
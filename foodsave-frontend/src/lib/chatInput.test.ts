@@ -0,0 +1,17 @@
+import { MAX_MESSAGE_LENGTH, isSendKey, messageLengthStatus } from './chatInput';
+
+describe('chatInput', () => {
+  it('wysyła Enterem, a Shift+Enter dodaje nową linię', () => {
+    expect(isSendKey({ key: 'Enter', shiftKey: false, isComposing: false })).toBe(true);
+    expect(isSendKey({ key: 'Enter', shiftKey: true, isComposing: false })).toBe(false);
+    expect(isSendKey({ key: 'Enter', shiftKey: false, isComposing: true })).toBe(false);
+    expect(isSendKey({ key: 'a', shiftKey: false, isComposing: false })).toBe(false);
+  });
+
+  it('pokazuje licznik przy długich wiadomościach i blokuje przekroczenie limitu', () => {
+    expect(messageLengthStatus(10)).toBe('hidden');
+    expect(messageLengthStatus(MAX_MESSAGE_LENGTH * 0.8)).toBe('near');
+    expect(messageLengthStatus(MAX_MESSAGE_LENGTH)).toBe('near');
+    expect(messageLengthStatus(MAX_MESSAGE_LENGTH + 1)).toBe('over');
+  });
+});
@@ -0,0 +1,17 @@
+// Chat editor rules: Enter sends, Shift+Enter adds a line, and long prompts get a length limit
+
+export const MAX_MESSAGE_LENGTH = 4000;
+// Licznik znaków pojawia się dopiero przy dłuższych wiadomościach
+const COUNTER_FROM = 0.8;
+
+export type LengthStatus = 'hidden' | 'near' | 'over';
+
+export function messageLengthStatus(length: number, max = MAX_MESSAGE_LENGTH): LengthStatus {
+  if (length > max) return 'over';
+  return length >= max * COUNTER_FROM ? 'near' : 'hidden';
+}
+
+// IME composition (e.g. accented letters on some layouts) also ends with Enter
+export function isSendKey(event: Pick<KeyboardEvent, 'key' | 'shiftKey' | 'isComposing'>): boolean {
+  return event.key === 'Enter' && !event.shiftKey && !event.isComposing;
+}
@@ -0,0 +1,23 @@
+import { formatTranscript } from './chatTranscript';
+import { Message } from '@/types/chat';
+
+const messages: Message[] = [
+  { id: '1', role: 'user', content: 'Co na obiad?' },
+  { id: '2', role: 'assistant', content: '## Pierogi\n\n- mąka\n- ser' },
+  { id: '3', role: 'assistant', content: 'Wystąpił błąd', isError: true },
+  { id: '4', role: 'assistant', content: '' },
+];
+
+describe('chatTranscript', () => {
+  it('zapisuje rozmowę jako zwykły tekst bez błędów i pustych wiadomości', () => {
+    expect(formatTranscript(messages, 'text', 'Rozmowa')).toBe(
+      'Rozmowa\n\nTy:\nCo na obiad?\n\nAsystent:\n## Pierogi\n\n- mąka\n- ser',
+    );
+  });
+
+  it('zapisuje rozmowę jako Markdown z rozdzielonymi wiadomościami', () => {
+    expect(formatTranscript(messages, 'markdown', 'Rozmowa')).toBe(
+      '# Rozmowa\n\n---\n\n**Ty**\n\nCo na obiad?\n\n---\n\n**Asystent**\n\n## Pierogi\n\n- mąka\n- ser',
+    );
+  });
+});
@@ -0,0 +1,46 @@
+// Plain-text and Markdown transcripts of a chat conversation, for copying and sharing
+
+import { Message } from '@/types/chat';
+
+export type TranscriptFormat = 'text' | 'markdown';
+
+const ROLE_NAMES: Record<Message['role'], string> = {
+  user: 'Ty',
+  assistant: 'Asystent',
+  system: 'System',
+};
+
+function timeLabel(timestamp?: number): string {
+  return timestamp
+    ? new Date(timestamp).toLocaleString('pl', { dateStyle: 'short', timeStyle: 'short' })
+    : '';
+}
+
+// Errors and empty (still streaming) messages are left out, like in the saved history
+export function formatTranscript(messages: Message[], format: TranscriptFormat, title = 'Rozmowa z asystentem FoodSave'): string {
+  const kept = messages.filter((m) => !m.isError && m.content.trim());
+  const parts = kept.map((m) => {
+    const time = timeLabel(m.timestamp);
+    if (format === 'markdown') {
+      return `**${ROLE_NAMES[m.role]}**${time ? ` _(${time})_` : ''}\n\n${m.content.trim()}`;
+    }
+    return `${ROLE_NAMES[m.role]}${time ? ` (${time})` : ''}:\n${m.content.trim()}`;
+  });
+  const heading = format === 'markdown' ? `# ${title}` : title;
+  return [heading, ...parts].join(format === 'markdown' ? '\n\n---\n\n' : '\n\n');
+}
+
+export function canShareText(): boolean {
+  return typeof navigator !== 'undefined' && typeof navigator.share === 'function';
+}
+
+// Resolves false when the user closed the share sheet
+export async function shareText(title: string, text: string): Promise<boolean> {
+  try {
+    await navigator.share({ title, text });
+    return true;
+  } catch (error) {
+    if (error instanceof DOMException && error.name === 'AbortError') return false;
+    throw error;
+  }
+}
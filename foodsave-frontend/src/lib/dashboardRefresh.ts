@@ -0,0 +1,46 @@
+// Auto-refresh configuration for dashboard widgets and the "refresh all" signal
+
+import type { MessageKey } from './i18n';
+import { readJson, writeJson } from './storage';
+
+const STORAGE_KEY = 'foodsave.dashboard.refresh';
+const INTERVALS_EVENT = 'foodsave:refresh-intervals';
+const REFRESH_ALL_EVENT = 'foodsave:dashboard-refresh';
+
+export type WidgetId = 'weather' | 'budget' | 'receipts';
+
+export const WIDGET_LABELS: Record<WidgetId, MessageKey> = {
+  weather: 'dashboard.widget.weather',
+  budget: 'dashboard.widget.budget',
+  receipts: 'dashboard.widget.receipts',
+};
+
+// Minutes; 0 disables auto-refresh
+export const REFRESH_OPTIONS = [0, 1, 5, 15, 30, 60];
+
+export type RefreshIntervals = Record<WidgetId, number>;
+
+const DEFAULT_INTERVALS: RefreshIntervals = { weather: 30, budget: 15, receipts: 5 };
+
+export function loadRefreshIntervals(): RefreshIntervals {
+  return { ...DEFAULT_INTERVALS, ...readJson<Partial<RefreshIntervals>>(STORAGE_KEY, {}) };
+}
+
+export function saveRefreshIntervals(intervals: RefreshIntervals): void {
+  writeJson(STORAGE_KEY, intervals);
+  window.dispatchEvent(new CustomEvent(INTERVALS_EVENT));
+}
+
+export function requestRefreshAll(): void {
+  window.dispatchEvent(new CustomEvent(REFRESH_ALL_EVENT));
+}
+
+export function subscribeRefreshAll(listener: () => void): () => void {
+  window.addEventListener(REFRESH_ALL_EVENT, listener);
+  return () => window.removeEventListener(REFRESH_ALL_EVENT, listener);
+}
+
+export function subscribeRefreshIntervals(listener: () => void): () => void {
+  window.addEventListener(INTERVALS_EVENT, listener);
+  return () => window.removeEventListener(INTERVALS_EVENT, listener);
+}
@@ -0,0 +1,22 @@
+import { deepLinkPath, parseDeepLink } from './deepLinks';
+
+describe('deepLinks', () => {
+  it('otwiera czat z gotowym pytaniem', () => {
+    expect(parseDeepLink('web+foodsave://chat?prompt=Co%20na%20obiad%3F')).toBe('/chat?prompt=Co%20na%20obiad%3F');
+    expect(parseDeepLink('myappassistant://chat?prompt=  ')).toBe('/chat');
+    expect(parseDeepLink('web+foodsave:chat?prompt=x')).toBe('/chat?prompt=x');
+  });
+
+  it('mapuje strony i kieruje OCR na skaner paragonów', () => {
+    expect(parseDeepLink('myappassistant://ocr?file=/tmp/paragon.jpg')).toBe('/shopping');
+    expect(parseDeepLink('web+foodsave://Reminders/')).toBe('/reminders');
+  });
+
+  it('odrzuca obce schematy i nieznane cele', () => {
+    expect(parseDeepLink('https://evil.example.com/chat')).toBeNull();
+    expect(parseDeepLink('web+foodsave://evil.example.com')).toBeNull();
+    expect(parseDeepLink('nie-adres')).toBeNull();
+    expect(deepLinkPath(null)).toBe('/dashboard');
+    expect(deepLinkPath('web+foodsave://nope')).toBe('/dashboard');
+  });
+});
@@ -0,0 +1,61 @@
+// Deep links: "web+foodsave://chat?prompt=..." (and the legacy "myappassistant://" form)
+// mapped onto internal app paths. Browsers only allow registering "web+" schemes.
+
+import { DEFAULT_START_PAGE } from './startup';
+
+export const DEEP_LINK_SCHEME = 'web+foodsave';
+const ACCEPTED_PROTOCOLS = [`${DEEP_LINK_SCHEME}:`, 'myappassistant:'];
+const MAX_PROMPT_LENGTH = 2000;
+
+const TARGETS: Record<string, string> = {
+  dashboard: '/dashboard',
+  chat: '/chat',
+  shopping: '/shopping',
+  // Strona nie może otworzyć lokalnego pliku ze ścieżki - "ocr?file=" otwiera skaner paragonów
+  ocr: '/shopping',
+  cooking: '/cooking',
+  analytics: '/analytics',
+  reminders: '/reminders',
+  settings: '/settings',
+};
+
+/** Internal path for a deep link URI, or null when the URI is not ours. */
+export function parseDeepLink(uri: string): string | null {
+  let url: URL;
+  try {
+    url = new URL(uri.trim());
+  } catch {
+    return null;
+  }
+  if (!ACCEPTED_PROTOCOLS.includes(url.protocol)) return null;
+
+  // "web+foodsave://chat" ma cel w host, "web+foodsave:chat" w pathname
+  const target = (url.host || url.pathname).replace(/^\/+|\/+$/g, '').toLowerCase();
+  const path = TARGETS[target];
+  if (!path) return null;
+
+  const prompt = url.searchParams.get('prompt')?.trim();
+  if (target === 'chat' && prompt) {
+    return `${path}?prompt=${encodeURIComponent(prompt.slice(0, MAX_PROMPT_LENGTH))}`;
+  }
+  return path;
+}
+
+export function deepLinkPath(uri: string | null): string {
+  return (uri && parseDeepLink(uri)) || DEFAULT_START_PAGE;
+}
+
+export function canRegisterDeepLinks(): boolean {
+  return typeof navigator !== 'undefined' && typeof navigator.registerProtocolHandler === 'function';
+}
+
+/** Asks the browser to open web+foodsave:// links in this app; false when unsupported or refused. */
+export function registerDeepLinks(): boolean {
+  if (!canRegisterDeepLinks()) return false;
+  try {
+    navigator.registerProtocolHandler(DEEP_LINK_SCHEME, `${window.location.origin}/open?uri=%s`);
+    return true;
+  } catch {
+    return false;
+  }
+}
@@ -0,0 +1,38 @@
+import { AppError, formatAppError, reportError, toAppError } from './errors';
+import { dismissToast, getToasts, runToastAction } from './toasts';
+
+describe('errors', () => {
+  afterEach(() => {
+    getToasts().forEach((toast) => dismissToast(toast.id));
+  });
+
+  it('opisuje błąd kontekstem i zachowuje oryginał', () => {
+    const original = new Error('HTTP error! status: 503');
+    const error = toAppError(original, 'Zapis paragonu');
+
+    expect(error).toBeInstanceOf(AppError);
+    expect(formatAppError(error)).toBe('Zapis paragonu: HTTP error! status: 503');
+    expect(error.original).toBe(original);
+    expect(toAppError(error, 'Inny kontekst')).toBe(error);
+    expect(toAppError('???', 'Czat').message).toBe('Wystąpił nieznany błąd');
+  });
+
+  it('pokazuje toast z przyciskiem ponowienia', () => {
+    const retry = jest.fn();
+    reportError(new Error('timeout'), 'Odczyt paragonu', retry);
+
+    const [toast] = getToasts();
+    expect(toast.severity).toBe('error');
+    expect(toast.message).toBe('Odczyt paragonu: timeout');
+    expect(toast.action?.label).toBe('Ponów');
+
+    runToastAction(toast.id);
+    expect(retry).toHaveBeenCalledTimes(1);
+    expect(getToasts()).toEqual([]);
+  });
+
+  it('bez akcji ponowienia toast ma tylko komunikat', () => {
+    reportError(new Error('brak danych'), 'Produkty');
+    expect(getToasts()[0].action).toBeUndefined();
+  });
+});
@@ -0,0 +1,42 @@
+// Structured UI errors: what failed (context) and how to try again (retry).
+// reportError logs them and shows an error toast whose "Ponów" re-runs the failed action.
+
+import { translate } from './i18n';
+import { createLogger } from './logger';
+import { loadSettings } from './settings';
+import { errorMessage, pushToast } from './toasts';
+
+const log = createLogger('errors');
+
+export class AppError extends Error {
+  readonly context: string; // np. "Zapis paragonu"
+  readonly retry?: () => void;
+  readonly original?: unknown;
+
+  constructor(context: string, message: string, options: { retry?: () => void; original?: unknown } = {}) {
+    super(message);
+    this.name = 'AppError';
+    this.context = context;
+    this.retry = options.retry;
+    this.original = options.original;
+  }
+}
+
+export function toAppError(error: unknown, context: string, retry?: () => void): AppError {
+  if (error instanceof AppError) return error;
+  return new AppError(context, errorMessage(error, translate(loadSettings().language, 'common.unknownError')), { retry, original: error });
+}
+
+export function formatAppError(error: AppError): string {
+  return `${error.context}: ${error.message}`;
+}
+
+export function reportError(error: unknown, context: string, retry?: () => void): AppError {
+  const appError = toAppError(error, context, retry);
+  log.error(formatAppError(appError), appError.original);
+  pushToast(formatAppError(appError), {
+    severity: 'error',
+    action: appError.retry ? { label: translate(loadSettings().language, 'common.retry'), onClick: appError.retry } : undefined,
+  });
+  return appError;
+}
@@ -0,0 +1,23 @@
+import { shoppingTripsToCsv, toCsv } from './exporters';
+
+describe('exporters', () => {
+  it('cytuje tylko komórki z separatorem lub cudzysłowem', () => {
+    expect(toCsv(['a', 'b'], [['x,y', 'z"q'], [1.5, null]])).toBe('a,b\r\n"x,y","z""q"\r\n1.5,');
+  });
+
+  it('spłaszcza paragony do wiersza na produkt', () => {
+    const csv = shoppingTripsToCsv([
+      {
+        id: 1,
+        trip_date: '2024-01-05',
+        store_name: 'Lidl',
+        total_amount: 9.98,
+        products: [
+          { id: 1, trip_id: 1, name: 'Mleko', quantity: 2, unit: 'l', unit_price: 4.99, category: 'Nabiał', is_consumed: false },
+        ],
+      },
+    ]);
+
+    expect(csv.split('\r\n')[1]).toBe('2024-01-05,Lidl,Mleko,2,l,4.99,9.98,Nabiał,');
+  });
+});
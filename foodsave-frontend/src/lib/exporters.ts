@@ -0,0 +1,71 @@
+// Shared CSV/JSON exporters for receipts and statistics
+
+import { Product, ShoppingTrip } from '@/types/shopping';
+import { lineTotal, toDecimalString } from './money';
+
+export type ExportFormat = 'csv' | 'json';
+
+export type CsvCell = string | number | boolean | null | undefined;
+
+function escapeCsvCell(value: CsvCell): string {
+  if (value === null || value === undefined) return '';
+  const text = String(value);
+  // Quote only when needed so numbers stay numbers in spreadsheets
+  return /[",;\n\r]/.test(text) ? `"${text.replace(/"/g, '""')}"` : text;
+}
+
+export function toCsv(header: string[], rows: CsvCell[][]): string {
+  return [header, ...rows].map((row) => row.map(escapeCsvCell).join(',')).join('\r\n');
+}
+
+export function toJson(data: unknown): string {
+  return JSON.stringify(data, null, 2);
+}
+
+export function downloadFile(content: string, filename: string, format: ExportFormat) {
+  const mime = format === 'csv' ? 'text/csv;charset=utf-8' : 'application/json';
+  // BOM lets Excel detect UTF-8 (Polish characters)
+  const blob = new Blob([format === 'csv' ? '\uFEFF' + content : content], { type: mime });
+  const url = URL.createObjectURL(blob);
+  const link = document.createElement('a');
+  link.href = url;
+  link.download = filename;
+  link.click();
+  URL.revokeObjectURL(url);
+}
+
+const RECEIPT_HEADER = ['data', 'sklep', 'produkt', 'ilość', 'jednostka', 'cena_jedn', 'wartość', 'kategoria', 'data_ważności'];
+
+// One row per product, with the receipt's date and store repeated
+export function shoppingTripsToCsv(trips: ShoppingTrip[]): string {
+  const rows = trips.flatMap((trip) =>
+    trip.products.map((p) => [
+      trip.trip_date,
+      trip.store_name,
+      p.name,
+      p.quantity,
+      p.unit,
+      p.unit_price,
+      p.unit_price != null ? toDecimalString(lineTotal(p.unit_price, p.quantity)) : '',
+      p.category,
+      p.expiration_date,
+    ]),
+  );
+  return toCsv(RECEIPT_HEADER, rows);
+}
+
+// Parsed (not yet saved) receipt straight from OCR analysis
+export function parsedReceiptToCsv(products: Product[], meta: { date?: string; store?: string } = {}): string {
+  const rows = products.map((p) => [
+    meta.date,
+    meta.store,
+    p.name,
+    p.quantity,
+    p.unit,
+    p.price,
+    p.price != null ? toDecimalString(lineTotal(p.price, p.quantity)) : '',
+    p.category,
+    p.expiry_date,
+  ]);
+  return toCsv(RECEIPT_HEADER, rows);
+}
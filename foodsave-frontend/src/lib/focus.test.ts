@@ -0,0 +1,54 @@
+import { cycleFocusRegion, focusPrimaryInput, trapFocus } from './focus';
+
+const visible = () => true;
+
+describe('focus', () => {
+  beforeEach(() => {
+    document.body.innerHTML = `
+      <nav data-focus-region="navigation"><a href="/dashboard" id="nav-link">Dashboard</a></nav>
+      <main data-focus-region="main">
+        <p id="text">Bez elementów interaktywnych</p>
+        <input id="search" data-primary-input />
+      </main>
+      <div data-focus-region="notifications"><span>Zapisano</span></div>
+    `;
+  });
+
+  it('przechodzi cyklicznie między obszarami strony', () => {
+    expect(cycleFocusRegion(1, document, visible)?.id).toBe('nav-link');
+    expect(cycleFocusRegion(1, document, visible)?.id).toBe('search');
+    // Obszar bez elementów interaktywnych dostaje fokus sam
+    expect(cycleFocusRegion(1, document, visible)?.dataset.focusRegion).toBe('notifications');
+    expect(cycleFocusRegion(1, document, visible)?.id).toBe('nav-link');
+    expect(cycleFocusRegion(-1, document, visible)?.dataset.focusRegion).toBe('notifications');
+  });
+
+  it('ustawia fokus na głównym polu strony', () => {
+    expect(focusPrimaryInput(document, visible)).toBe(true);
+    expect(document.activeElement?.id).toBe('search');
+
+    document.body.innerHTML = '<main></main>';
+    expect(focusPrimaryInput(document, visible)).toBe(false);
+  });
+
+  it('zatrzymuje Tab wewnątrz okna dialogowego', () => {
+    document.body.innerHTML = `
+      <button id="outside">Poza oknem</button>
+      <div id="dialog" tabindex="-1"><button id="first">Anuluj</button><button id="last">Usuń</button></div>
+    `;
+    const dialog = document.getElementById('dialog')!;
+    const tab = (shiftKey = false) => ({ key: 'Tab', shiftKey, preventDefault: jest.fn() });
+
+    document.getElementById('last')!.focus();
+    expect(trapFocus(tab(), dialog, visible)).toBe(true);
+    expect(document.activeElement?.id).toBe('first');
+
+    expect(trapFocus(tab(true), dialog, visible)).toBe(true);
+    expect(document.activeElement?.id).toBe('last');
+
+    // Środek listy obsługuje przeglądarka
+    document.getElementById('first')!.focus();
+    expect(trapFocus(tab(), dialog, visible)).toBe(false);
+    expect(trapFocus({ key: 'Enter', shiftKey: false, preventDefault: jest.fn() }, dialog, visible)).toBe(false);
+  });
+});
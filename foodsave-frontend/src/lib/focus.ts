@@ -0,0 +1,80 @@
+// Keyboard focus helpers: cycling between page regions and jumping to a page's main input
+
+const REGION_SELECTOR = '[data-focus-region]';
+const PRIMARY_INPUT_SELECTOR = '[data-primary-input]';
+const FOCUSABLE_SELECTOR = [
+  'a[href]',
+  'button:not([disabled])',
+  'input:not([disabled]):not([type="hidden"])',
+  'select:not([disabled])',
+  'textarea:not([disabled])',
+  '[tabindex]:not([tabindex="-1"])',
+].join(',');
+
+export type VisibilityCheck = (element: HTMLElement) => boolean;
+
+// Regiony ukryte przez CSS (np. dolna nawigacja na desktopie) nie mają prostokątów
+const isVisible: VisibilityCheck = (element) => element.getClientRects().length > 0;
+
+// Moves focus to the first focusable element of the next/previous visible region
+export function cycleFocusRegion(
+  direction: 1 | -1,
+  root: ParentNode = document,
+  visible: VisibilityCheck = isVisible,
+): HTMLElement | null {
+  const regions = Array.from(root.querySelectorAll<HTMLElement>(REGION_SELECTOR)).filter(visible);
+  if (regions.length === 0) return null;
+
+  const active = document.activeElement;
+  const current = regions.findIndex((region) => region.contains(active));
+  // Poza regionami: "dalej" zaczyna od pierwszego, "wstecz" od ostatniego
+  const start = current === -1 ? (direction === 1 ? -1 : 0) : current;
+  const target = regions[(start + direction + regions.length) % regions.length];
+
+  const focusable = Array.from(target.querySelectorAll<HTMLElement>(FOCUSABLE_SELECTOR)).find(visible);
+  const element = focusable ?? target;
+  if (!focusable && !element.hasAttribute('tabindex')) element.setAttribute('tabindex', '-1');
+  element.focus();
+  return element;
+}
+
+export function focusPrimaryInput(root: ParentNode = document, visible: VisibilityCheck = isVisible): boolean {
+  const input = Array.from(root.querySelectorAll<HTMLElement>(PRIMARY_INPUT_SELECTOR)).find(visible);
+  if (!input) return false;
+  input.focus();
+  return true;
+}
+
+export function focusableElements(container: ParentNode, visible: VisibilityCheck = isVisible): HTMLElement[] {
+  return Array.from(container.querySelectorAll<HTMLElement>(FOCUSABLE_SELECTOR)).filter(visible);
+}
+
+// Keeps Tab / Shift+Tab inside a dialog; returns whether focus was moved
+export function trapFocus(
+  event: Pick<KeyboardEvent, 'key' | 'shiftKey' | 'preventDefault'>,
+  container: HTMLElement,
+  visible: VisibilityCheck = isVisible,
+): boolean {
+  if (event.key !== 'Tab') return false;
+  const elements = focusableElements(container, visible);
+  if (elements.length === 0) {
+    event.preventDefault();
+    container.focus();
+    return true;
+  }
+  const first = elements[0];
+  const last = elements[elements.length - 1];
+  const active = document.activeElement;
+  const outside = !container.contains(active);
+  if (event.shiftKey && (active === first || outside)) {
+    event.preventDefault();
+    last.focus();
+    return true;
+  }
+  if (!event.shiftKey && (active === last || outside)) {
+    event.preventDefault();
+    first.focus();
+    return true;
+  }
+  return false;
+}
@@ -0,0 +1,32 @@
+import { findBestMatch, fuzzyScore, fuzzySearch, normalizeText, similarity } from './fuzzy';
+
+describe('fuzzy', () => {
+  it('usuwa polskie znaki i interpunkcję', () => {
+    expect(normalizeText('Żółć, Łódź!  3,2%')).toBe('zolc lodz 3 2');
+  });
+
+  it('dopasowuje zapytanie do dłuższej nazwy z paragonu', () => {
+    expect(fuzzyScore('mleko', 'Mleko UHT 3,2% Łaciate')).toBe(1);
+    expect(fuzzyScore('maslo', 'Masło extra')).toBe(1);
+    expect(fuzzyScore('mlek', 'Mleko')).toBeGreaterThan(0.8);
+    expect(fuzzyScore('jogurt', 'Mleko')).toBe(0);
+  });
+
+  it('toleruje literówki z OCR', () => {
+    expect(similarity('mleko łaciate', 'MLEK0 LACIATE')).toBeGreaterThan(0.75);
+  });
+
+  it('wyszukuje i sortuje od najlepszego dopasowania', () => {
+    const items = ['Pomidor malinowy', 'Mleko', 'Ser żółty', 'Pomidory koktajlowe'];
+
+    expect(fuzzySearch(items, 'pomidory', (item) => item)).toEqual(['Pomidory koktajlowe', 'Pomidor malinowy']);
+    expect(fuzzySearch(items, '  ', (item) => item)).toBe(items);
+  });
+
+  it('znajduje duplikat tylko powyżej progu', () => {
+    const names = ['Chleb żytni', 'Mleko 2%'];
+
+    expect(findBestMatch('mleko', names, (name) => name)).toBe('Mleko 2%');
+    expect(findBestMatch('jajka', names, (name) => name)).toBeUndefined();
+  });
+});
@@ -0,0 +1,99 @@
+// Fuzzy matching of product names: "Mleko UHT 3,2% Łaciate" should match "mleko laciate",
+// and OCR typos like "MLEK0 LACIATE" should still land on the same product
+
+// Lowercase, no Polish diacritics, punctuation as spaces
+export function normalizeText(text: string): string {
+  return text
+    .toLocaleLowerCase('pl')
+    .normalize('NFD')
+    .replace(/[\u0300-\u036f]/g, '')
+    .replace(/ł/g, 'l')
+    .replace(/[^a-z0-9]+/g, ' ')
+    .trim();
+}
+
+export function tokenize(text: string): string[] {
+  const normalized = normalizeText(text);
+  return normalized ? normalized.split(' ') : [];
+}
+
+function bigrams(token: string): string[] {
+  if (token.length < 2) return [token];
+  const result: string[] = [];
+  for (let i = 0; i < token.length - 1; i++) result.push(token.slice(i, i + 2));
+  return result;
+}
+
+// Dice coefficient on character bigrams, 0..1
+function tokenSimilarity(a: string, b: string): number {
+  if (a === b) return 1;
+  // "mlek" przy wpisywaniu ma znaleźć "mleko"
+  if (a.length >= 3 && b.startsWith(a)) return 0.9;
+  const left = bigrams(a);
+  const right = bigrams(b);
+  const pool = [...right];
+  let common = 0;
+  for (const gram of left) {
+    const index = pool.indexOf(gram);
+    if (index >= 0) {
+      common++;
+      pool.splice(index, 1);
+    }
+  }
+  return (2 * common) / (left.length + right.length);
+}
+
+// How well the query's tokens are covered by the candidate, 0..1 (not symmetric:
+// "mleko" fully matches "mleko uht 3 2")
+export function fuzzyScore(query: string, candidate: string): number {
+  const queryTokens = tokenize(query);
+  const candidateTokens = tokenize(candidate);
+  if (queryTokens.length === 0 || candidateTokens.length === 0) return 0;
+  const total = queryTokens.reduce(
+    (sum, token) => sum + Math.max(...candidateTokens.map((other) => tokenSimilarity(token, other))),
+    0,
+  );
+  return total / queryTokens.length;
+}
+
+// Symmetric similarity of two names, 0..1
+export function similarity(a: string, b: string): number {
+  return (fuzzyScore(a, b) + fuzzyScore(b, a)) / 2;
+}
+
+export const SEARCH_THRESHOLD = 0.6;
+export const MATCH_THRESHOLD = 0.75;
+
+// Items matching the query, best first; an empty query returns everything unchanged
+export function fuzzySearch<T>(
+  items: T[],
+  query: string,
+  getText: (item: T) => string,
+  threshold: number = SEARCH_THRESHOLD,
+): T[] {
+  if (tokenize(query).length === 0) return items;
+  return items
+    .map((item) => ({ item, score: fuzzyScore(query, getText(item)) }))
+    .filter(({ score }) => score >= threshold)
+    .sort((a, b) => b.score - a.score)
+    .map(({ item }) => item);
+}
+
+// The candidate most similar to the name, if it's similar enough (OCR linking, duplicates)
+export function findBestMatch<T>(
+  name: string,
+  candidates: T[],
+  getText: (item: T) => string,
+  threshold: number = MATCH_THRESHOLD,
+): T | undefined {
+  let best: T | undefined;
+  let bestScore = threshold;
+  for (const candidate of candidates) {
+    const score = similarity(name, getText(candidate));
+    if (score >= bestScore) {
+      best = candidate;
+      bestScore = score;
+    }
+  }
+  return best;
+}
@@ -0,0 +1,58 @@
+import {
+  buildDailySummary,
+  cachedPolishedSummary,
+  formatDailySummary,
+  pluralPl,
+  savePolishedSummary,
+  timeOfDayGreeting,
+} from './greeting';
+
+describe('greeting', () => {
+  beforeEach(() => {
+    window.localStorage.clear();
+  });
+
+  it('dobiera powitanie do pory dnia', () => {
+    expect(timeOfDayGreeting(new Date(2024, 0, 1, 8))).toBe('Dzień dobry');
+    expect(timeOfDayGreeting(new Date(2024, 0, 1, 20))).toBe('Dobry wieczór');
+  });
+
+  it('odmienia liczebniki po polsku', () => {
+    expect(pluralPl(1, 'produkt', 'produkty', 'produktów')).toBe('produkt');
+    expect(pluralPl(3, 'produkt', 'produkty', 'produktów')).toBe('produkty');
+    expect(pluralPl(12, 'produkt', 'produkty', 'produktów')).toBe('produktów');
+    expect(pluralPl(22, 'produkt', 'produkty', 'produktów')).toBe('produkty');
+  });
+
+  it('składa podsumowanie z produktów i pogody', () => {
+    const parts = buildDailySummary({
+      expiringCount: 3,
+      today: { date: '2024-01-01', min_temp: 2, max_temp: 6, condition: 'Rain', chance_of_rain: 80, icon: '' },
+    });
+
+    expect(parts).toEqual(['3 produkty tracą ważność w tym tygodniu', 'dziś może padać (80%)']);
+  });
+
+  it('podaje godzinę, od której ma padać', () => {
+    const parts = buildDailySummary({
+      today: { date: '2024-01-01', min_temp: 2, max_temp: 6, condition: 'Rain', chance_of_rain: 70, rain_from: '16:00', icon: '' },
+    });
+
+    expect(formatDailySummary(parts)).toBe('Dziś od 16:00 może padać (70%).');
+  });
+
+  it('mówi w języku interfejsu', () => {
+    const parts = buildDailySummary({ expiringCount: 1 }, 'en');
+
+    expect(timeOfDayGreeting(new Date(2024, 0, 1, 8), 'en')).toBe('Good morning');
+    expect(formatDailySummary(parts)).toBe('1 product expires this week.');
+  });
+
+  it('pamięta wygładzone podsumowanie tylko dla tego samego dnia i treści', () => {
+    savePolishedSummary('2024-01-01', 'Dziś do 6°C.', 'Dziś chłodno, maksymalnie 6°C.');
+
+    expect(cachedPolishedSummary('2024-01-01', 'Dziś do 6°C.')).toBe('Dziś chłodno, maksymalnie 6°C.');
+    expect(cachedPolishedSummary('2024-01-02', 'Dziś do 6°C.')).toBeUndefined();
+    expect(cachedPolishedSummary('2024-01-01', 'Dziś do 8°C.')).toBeUndefined();
+  });
+});
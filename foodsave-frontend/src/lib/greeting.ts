@@ -0,0 +1,74 @@
+// Dashboard greeting and daily summary built from local data
+
+import { WeatherForecastDay } from '@/types/api';
+import { Locale, translate } from './i18n';
+import { readJson, writeJson } from './storage';
+
+const POLISHED_KEY = 'foodsave.greeting.polished';
+
+export function timeOfDayGreeting(date: Date = new Date(), locale: Locale = 'pl'): string {
+  const hour = date.getHours();
+  if (hour < 5) return translate(locale, 'dashboard.greeting.night');
+  if (hour < 12) return translate(locale, 'dashboard.greeting.morning');
+  if (hour < 18) return translate(locale, 'dashboard.greeting.afternoon');
+  return translate(locale, 'dashboard.greeting.evening');
+}
+
+// Polish plural forms: 1 produkt, 2-4 produkty, 5+ produktów (12-14 also "produktów")
+export function pluralPl(count: number, one: string, few: string, many: string): string {
+  if (count === 1) return one;
+  const lastDigit = count % 10;
+  const lastTwo = count % 100;
+  if (lastDigit >= 2 && lastDigit <= 4 && (lastTwo < 12 || lastTwo > 14)) return few;
+  return many;
+}
+
+export interface DailySummaryInput {
+  expiringCount?: number;
+  today?: WeatherForecastDay;
+}
+
+export function buildDailySummary({ expiringCount, today }: DailySummaryInput, locale: Locale = 'pl'): string[] {
+  const parts: string[] = [];
+  if (expiringCount !== undefined) {
+    parts.push(
+      expiringCount === 0
+        ? translate(locale, 'dashboard.summary.nothingExpiring')
+        : translate(locale, 'dashboard.summary.expiring', { count: expiringCount }),
+    );
+  }
+  if (today) {
+    if ((today.chance_of_rain ?? 0) >= 50) {
+      const percent = Math.round(today.chance_of_rain ?? 0);
+      parts.push(
+        today.rain_from
+          ? translate(locale, 'dashboard.summary.rainFrom', { time: today.rain_from, percent })
+          : translate(locale, 'dashboard.summary.rain', { percent }),
+      );
+    } else if (today.max_temp !== null) {
+      parts.push(translate(locale, 'dashboard.summary.maxTemp', { temp: Math.round(today.max_temp) }));
+    }
+  }
+  return parts;
+}
+
+// One sentence shown under the greeting
+export function formatDailySummary(parts: string[]): string {
+  return parts.length > 0 ? `${parts.join(', ').replace(/^./, (c) => c.toUpperCase())}.` : '';
+}
+
+interface PolishedSummary {
+  day: string;
+  source: string;
+  text: string;
+}
+
+// LLM wording of today's summary, kept until the day or the local summary changes
+export function cachedPolishedSummary(day: string, source: string): string | undefined {
+  const cached = readJson<PolishedSummary | null>(POLISHED_KEY, null);
+  return cached && cached.day === day && cached.source === source ? cached.text : undefined;
+}
+
+export function savePolishedSummary(day: string, source: string, text: string): void {
+  writeJson(POLISHED_KEY, { day, source, text });
+}
@@ -0,0 +1,951 @@
+import type { Messages } from './index';
+
+export const en: Messages = {
+  'nav.dashboard': 'Dashboard',
+  'nav.chat': 'Chat',
+  'nav.shopping': 'Shopping',
+  'nav.cooking': 'Cooking',
+  'nav.analytics': 'Analytics',
+  'nav.reminders': 'Reminders',
+  'nav.rag': 'RAG',
+  'nav.settings': 'Settings',
+  'nav.main': 'Main navigation',
+  'nav.toggleSidebar': 'Collapse or expand the menu',
+
+  'common.save': 'Save',
+  'common.cancel': 'Cancel',
+  'common.add': 'Add',
+  'common.edit': 'Edit',
+  'common.delete': 'Delete',
+  'common.close': 'Close',
+  'common.retry': 'Retry',
+  'common.undo': 'Undo',
+  'common.default': 'Default',
+
+  'settings.title': 'Settings',
+  'settings.subtitle': 'FoodSave application configuration',
+  'settings.unsaved': 'You have unsaved changes',
+  'settings.discard': 'Discard',
+  'settings.search': 'Search settings...',
+  'settings.noResults': 'No settings match your search.',
+  'settings.section.general': 'General',
+  'settings.section.appearance': 'Appearance',
+  'settings.section.backend': 'Server',
+  'settings.section.notifications': 'Notifications',
+  'settings.section.privacy': 'Data & privacy',
+  'settings.general.title': 'General',
+  'settings.general.userName': 'Your name',
+  'settings.language.title': 'Language',
+  'settings.receipts.title': 'Receipts',
+  'settings.receipts.language': 'Receipt language',
+  'settings.receipts.languageHint': 'A hint for OCR. Automatic detection is slower than a chosen language.',
+  'settings.receipts.auto': 'Detect automatically',
+  'settings.receipts.pl': 'Polish',
+  'settings.receipts.de': 'German',
+  'settings.receipts.en': 'English',
+  'settings.receipts.archive': 'Scan archive',
+  'settings.receipts.archiveEmpty': 'No saved scans',
+  'settings.receipts.archiveSince': 'since {date}',
+  'settings.receipts.retention': 'Keep scans for',
+  'settings.receipts.retentionForever': 'forever',
+  'settings.receipts.purge': 'Delete scans',
+  'settings.receipts.purgeConfirm': 'Delete all saved receipt scans? Receipts stay in the history but can no longer be re-processed.',
+  'settings.receipts.scans': {
+    one: '{count} scan',
+    other: '{count} scans',
+  },
+  'settings.receipts.months': {
+    one: '{count} month',
+    other: '{count} months',
+  },
+  'settings.startup.title': 'Startup',
+  'settings.startup.restoreLastPage': 'Open the last visited page',
+  'settings.startup.hint': 'Applies when opening the app home page; the dashboard opens by default.',
+  'settings.startup.deepLinks': 'web+foodsave:// links (e.g. web+foodsave://chat?prompt=...)',
+  'settings.startup.registerDeepLinks': 'Open links in this app',
+  'settings.startup.deepLinksHint': 'The browser will ask for confirmation.',
+  'settings.startup.deepLinksRegistered': 'Request sent to the browser - confirm it if prompted.',
+  'settings.startup.deepLinksFailed': 'The browser refused to register the links.',
+  'settings.startup.deepLinksUnsupported': 'This browser does not support custom links.',
+  'settings.calendarExport.title': 'Expiry calendar',
+  'settings.calendarExport.hint': 'Subscribe to the feed in your calendar app to see product expiry dates with a reminder the day before.',
+  'settings.calendarExport.enabled': 'Publish the expiry feed',
+  'settings.calendarExport.enabledHint': 'The feed needs no login - anyone who knows the server address can see the expiry dates. Turning it off stops subscriptions from refreshing.',
+  'settings.calendarExport.saveFailed': 'Could not change the feed setting',
+  'settings.calendarExport.feedUrl': 'Calendar feed URL',
+  'settings.calendarExport.copy': 'Copy URL',
+  'settings.calendarExport.copied': 'Copied',
+  'settings.calendarExport.subscribe': 'Subscribe',
+  'settings.calendarExport.download': 'Download .ics',
+  'profiles.switch': 'Switch profile',
+  'profiles.title': 'Household members',
+  'profiles.hint': 'Each profile has its own chat history and settings; the shopping list, budget and reminders are shared.',
+  'profiles.name': 'Profile name',
+  'profiles.active': 'Active',
+  'profiles.switchTo': 'Switch',
+  'profiles.remove': 'Remove profile',
+  'profiles.removeConfirm': 'Remove profile "{name}" together with its chat history and settings?',
+  'profiles.newPlaceholder': 'Household member name...',
+  'profiles.add': 'Add',
+  'settings.notifications.title': 'Desktop notifications',
+  'settings.notifications.default': 'Notifications are not enabled yet.',
+  'settings.notifications.granted': 'Notifications are enabled.',
+  'settings.notifications.denied': 'Notifications are blocked in the browser settings.',
+  'settings.notifications.unsupported': 'This browser does not support notifications.',
+  'settings.notifications.enable': 'Enable notifications',
+  'settings.notifications.test': 'Send a test notification',
+  'settings.notifications.testBody': 'Notifications are working.',
+  'settings.notifications.master': 'Show FoodSave notifications',
+  'settings.notifications.categories': 'Notification types',
+  'settings.notifications.category.reminder': 'Reminders',
+  'settings.notifications.category.budget': 'Budget exceeded',
+  'settings.notifications.category.weather': 'Weather alerts',
+  'settings.notifications.category.pantry': 'Expiring products',
+  'settings.notifications.category.chat': 'Chat replies (while the tab is in the background)',
+  'settings.notifications.category.ocr': 'Receipt scan finished',
+  'settings.section.shortcuts': 'Keyboard shortcuts',
+  'settings.shortcuts.title': 'Keyboard shortcuts',
+  'settings.shortcuts.change': 'Change shortcut: {action}',
+  'settings.shortcuts.press': 'Press a shortcut...',
+  'settings.shortcuts.needsModifier': 'A shortcut must include Ctrl, Alt or Meta.',
+  'settings.shortcuts.conflict': 'Shortcut {binding} is already assigned to: {action}.',
+  'settings.shortcuts.reset': 'Restore defaults',
+  'settings.shortcuts.builtIn': 'Always available: / focuses the page input, Esc closes a dialog or notification.',
+  'shortcuts.focusNext': 'Next page region',
+  'shortcuts.focusPrevious': 'Previous page region',
+  'settings.data.title': 'Data in this browser',
+  'settings.data.cache': 'Request cache',
+  'settings.data.chat': 'Chat history',
+  'settings.data.ocr': 'Receipt scan history',
+  'settings.data.all': 'All local data',
+  'settings.data.allHint': 'Settings, reminders, shopping list, budget and history',
+  'settings.data.allConfirm': 'Delete all FoodSave data stored in this browser? This cannot be undone.',
+  'settings.data.clear': 'Clear',
+  'settings.logging.title': 'Diagnostics',
+  'settings.logging.level': 'Log level',
+  'settings.logging.persist': 'Keep the log across page reloads',
+  'settings.logging.persistHint': 'Entries are stored in this browser, in two rotated files of 250 entries.',
+  'settings.logging.metrics': 'Collect performance metrics (API response times, errors, OCR)',
+  'settings.logging.metricsHint': 'Data stays in this browser only; turning this off deletes it.',
+  'settings.logging.open': 'Open the application log',
+  'settings.section.about': 'About',
+  'settings.about.title': 'About',
+  'settings.about.version': 'Version {version}',
+  'settings.about.checkUpdates': 'Check for a new version once a day',
+  'settings.about.checkUpdatesHint': 'The app then connects to GitHub (api.github.com).',
+  'settings.about.checkNow': 'Check now',
+  'settings.about.upToDate': 'You have the latest version.',
+  'settings.about.available': 'A new version is available: {version}',
+  'settings.about.releaseNotes': 'Release notes',
+  'settings.about.checkFailed': 'Could not check for updates: {error}',
+  'updates.available': 'A new version of FoodSave is available ({version}).',
+  'updates.details': 'Details',
+  'settings.data.entries': {
+    one: '{count} entry',
+    other: '{count} entries',
+  },
+  'settings.appearance.title': 'Appearance',
+  'settings.appearance.theme': 'Theme',
+  'settings.appearance.light': 'Light',
+  'settings.appearance.dark': 'Dark',
+  'settings.appearance.system': 'System',
+  'settings.appearance.accent': 'Accent colour',
+  'settings.appearance.customAccent': 'Custom accent colour',
+  'settings.appearance.customPalette': 'Custom colour palette',
+  'settings.appearance.background': 'Background',
+  'settings.appearance.surface': 'Cards',
+  'settings.appearance.previewHint': 'The preview is applied immediately; save to keep the theme.',
+  'settings.scale.title': 'Interface size',
+  'settings.scale.label': 'Interface scale',
+  'settings.scale.hint': 'Scales text and spacing across the app.',
+  'settings.accessibility.title': 'Accessibility',
+  'settings.accessibility.highContrast': 'High contrast',
+  'settings.accessibility.reducedMotion': 'Reduce motion',
+  'settings.accessibility.on': 'On',
+  'settings.accessibility.off': 'Off',
+  'settings.accessibility.hint': '"System" follows the accessibility settings of your operating system. High contrast replaces the custom colour palette.',
+  'settings.backend.title': 'Server connection',
+  'settings.backend.address': 'FoodSave server address',
+  'settings.backend.test': 'Test connection',
+  'settings.backend.connected': 'Connected in {ms} ms',
+  'settings.backend.disconnected': 'No connection',
+  'settings.backend.version': 'version {version}',
+  'settings.transfer.title': 'Settings backup',
+  'settings.transfer.hint': 'A JSON file without private calendar links. Imported settings still need to be saved.',
+  'settings.transfer.export': 'Export settings',
+  'settings.transfer.import': 'Import settings',
+  'settings.transfer.imported': {
+    one: 'Loaded {count} setting',
+    other: 'Loaded {count} settings',
+  },
+
+  'logs.title': 'Application log',
+  'logs.subtitle': 'Recent events from this session – attach them to a bug report',
+  'logs.count': {
+    one: '{count} entry',
+    other: '{count} entries',
+  },
+  'logs.level': 'Minimum level',
+  'logs.module': 'Module',
+  'logs.allModules': 'All modules',
+  'logs.search': 'Search the log...',
+  'logs.empty': 'No entries match the filters.',
+  'logs.copy': 'Copy',
+  'logs.copied': 'Log copied to the clipboard',
+  'logs.copyFailed': 'Could not copy the log',
+  'logs.export': 'Export',
+  'metrics.title': 'Performance',
+  'metrics.disabled': 'Metrics collection is off. Turn it on in Settings → Diagnostics.',
+  'metrics.empty': 'No measurements yet - use the app and results will show up here.',
+  'metrics.name': 'Operation',
+  'metrics.count': 'Count',
+  'metrics.errors': 'Errors',
+  'metrics.max': 'Max',
+
+  'chat.title': 'AI assistant',
+  'chat.placeholder': 'Ask a question or describe your problem...',
+  'chat.new': 'New chat',
+  'chat.clear': 'Clear chat',
+  'chat.input.placeholder': 'Type a message...',
+  'chat.input.label': 'Message to the assistant',
+  'chat.input.preview': 'Message preview',
+  'chat.input.previewToggle': 'Markdown preview',
+  'chat.input.previewShow': 'Show Markdown preview',
+  'chat.input.previewHide': 'Hide preview',
+  'chat.input.cookingMode': 'Cooking mode',
+  'chat.input.cookingOn': 'Turn on cooking mode',
+  'chat.input.cookingOff': 'Turn off cooking mode',
+  'chat.input.shoppingMode': 'Shopping mode',
+  'chat.input.shoppingOn': 'Turn on shopping mode',
+  'chat.input.shoppingOff': 'Turn off shopping mode',
+  'chat.input.switchModel': 'Switch to {model}',
+  'chat.input.model': 'Model: {model}. Switch to {other}',
+  'chat.input.perplexity': 'Perplexity (online models)',
+  'chat.input.perplexityOn': 'Turn on Perplexity (use online models)',
+  'chat.input.perplexityOff': 'Turn off Perplexity (use local models)',
+  'chat.input.replyLanguageHint': 'Reply language for this conversation (independent of the interface language)',
+  'chat.input.replyLanguage': 'Reply language: {language}',
+  'chat.replyLanguage.auto': 'automatic',
+  'chat.replyLanguage.pl': 'Polish',
+  'chat.replyLanguage.en': 'English',
+  'chat.input.send': 'Send',
+  'chat.input.hint': 'Enter to send, Shift+Enter for a new line',
+  'chat.pinned.label': 'Pinned answers',
+  'chat.pinned.title': 'Pinned ({count})',
+  'chat.pinned.summary': 'Conversation summary',
+  'chat.pinned.unpin': 'Unpin',
+  'chat.transcript.title': 'Conversation with the FoodSave assistant',
+  'chat.transcript.format': 'Conversation format',
+  'chat.transcript.text': 'Text',
+  'chat.transcript.summarize': 'Summarize',
+  'chat.transcript.summarizeHint': 'Summarize the conversation, e.g. before archiving it',
+  'chat.transcript.copy': 'Copy conversation',
+  'chat.transcript.copied': 'Conversation copied to the clipboard',
+  'chat.transcript.copyFailed': 'Could not copy the conversation',
+  'chat.transcript.share': 'Share…',
+  'chat.transcript.sharing': 'Sharing the conversation',
+  'chat.context.button': 'Context',
+  'chat.context.hint': 'Attach app data to the question',
+  'chat.context.title': 'Attach context',
+  'chat.context.attached': 'Attached context',
+  'chat.context.remove': 'Remove: {name}',
+  'chat.history.label': 'Saved conversations',
+  'chat.history.filter': 'Filter by tag',
+  'chat.history.all': 'All',
+  'chat.history.empty': 'No conversations.',
+  'chat.history.emptyTag': 'No conversations tagged “{tag}”.',
+  'chat.history.current': '(current)',
+  'chat.history.tags': 'Conversation tags',
+  'chat.history.delete': 'Delete conversation',
+  'chat.history.newTag': 'New tag',
+
+  'quickAsk.title': 'Quick question',
+  'quickAsk.placeholder': 'Ask the assistant...',
+  'quickAsk.hint': 'Enter to send, Esc to close',
+  'quickAsk.openChat': 'Open full chat',
+  'quickAsk.error': 'Could not get an answer.',
+
+  'reminders.title': 'Reminders',
+  'reminders.subtitle': 'One-off and recurring reminders with desktop notifications',
+  'reminders.new': 'New reminder',
+  'reminders.all': 'All reminders',
+  'reminders.empty': 'You have no reminders yet.',
+  'reminders.deleted': 'Reminder “{title}” deleted',
+  'reminders.count': {
+    one: '{count} reminder',
+    other: '{count} reminders',
+  },
+  'reminders.form.title': 'Text',
+  'reminders.form.titlePlaceholder': 'e.g. Check the milk expiry date',
+  'reminders.form.date': 'Date',
+  'reminders.form.time': 'Time',
+  'reminders.form.repeat': 'Repeat',
+  'reminders.repeat.none': 'Once',
+  'reminders.repeat.daily': 'Daily',
+  'reminders.repeat.weekly': 'Weekly',
+  'reminders.repeat.monthly': 'Monthly',
+
+  'routines.title': 'Assistant routines',
+  'routines.subtitle': 'Prompts sent to the assistant on a schedule; answers go to saved conversations and notifications',
+  'routines.empty': 'You have no routines yet.',
+  'routines.enabled': 'Enabled',
+  'routines.next': 'next {date}',
+  'routines.runNow': 'Run now',
+  'routines.lastResult': 'Show latest result',
+  'routines.form.name': 'Name',
+  'routines.form.namePlaceholder': 'e.g. Weekly meal plan',
+  'routines.form.prompt': 'Prompt for the assistant',
+  'routines.form.promptPlaceholder': 'e.g. Plan meals for the whole week from my pantry',
+  'routines.form.time': 'Time',
+  'routines.form.days': 'Days of the week',
+  'routines.form.attachments': 'Attach data',
+
+  'shopping.errorProducts': 'Products',
+  'shopping.errorProcessing': 'Receipt processing',
+  'shopping.errorSave': 'Saving products',
+  'shopping.taskOcr': 'Reading the receipt',
+  'shopping.taskAnalyze': 'Analysing the receipt',
+  'shopping.taskSave': 'Saving the receipt',
+  'shopping.activityScanned': 'Receipt scanned',
+  'shopping.activityScannedAt': 'Receipt scanned – {store}',
+  'shopping.activitySaved': 'Receipt saved: {store} ({count} products)',
+  'shopping.processedTitle': 'Receipt processed',
+  'shopping.processedBody': 'Recognised {count} items – check them before saving.',
+  'shopping.unknownStore': 'Unknown shop',
+  'shopping.checkedOff': 'Checked off the shopping list: {items}',
+  'shopping.exportCsv': 'Export CSV',
+  'shopping.exportJson': 'Export JSON',
+  'shopping.stepOcr': 'Running OCR...',
+  'shopping.stepAnalyze': 'Analysing the receipt...',
+  'shopping.stepSaving': 'Saving products...',
+  'shopping.stepDone': 'Products saved!',
+  'shopping.productsTitle': 'Products',
+  'shopping.historyTitle': 'Receipt history',
+  'shopping.exportTitle': 'Receipt export',
+  'shopping.assistantTitle': 'Shopping assistant',
+  'shopping.assistantPlaceholder': 'Ask about products or deals, or ask for a shopping list...',
+  'shopping.column.name': 'Name',
+  'shopping.column.product': 'Product',
+  'shopping.column.quantity': 'Quantity',
+  'shopping.column.unit': 'Unit',
+  'shopping.column.price': 'Price',
+  'shopping.column.category': 'Category',
+  'shopping.column.expiry': 'Expiry date',
+  'shopping.column.actions': 'Actions',
+  'shopping.productsEmpty': 'No products to show',
+  'shopping.renamePrompt': 'New product name:',
+  'shopping.meta.store': 'Shop',
+  'shopping.meta.storePlaceholder': 'e.g. Lidl',
+  'shopping.meta.date': 'Purchase date',
+  'shopping.list.title': 'Shopping list',
+  'shopping.list.addPlaceholder': 'Add a product...',
+  'shopping.list.name': 'Product name',
+  'shopping.list.add': 'Add to the list',
+  'shopping.list.empty': 'The shopping list is empty.',
+  'shopping.list.bought': 'Bought: {name}',
+  'shopping.list.remove': 'Remove {name}',
+  'shopping.list.clearChecked': 'Remove bought ({count})',
+  'shopping.list.print': 'Print',
+  'shopping.list.sync': 'Sync with {provider}',
+  'shopping.qr.button': 'QR code',
+  'shopping.qr.title': 'Shopping list as a QR code',
+  'shopping.qr.label': 'QR code with the shopping list ({count} items)',
+  'shopping.qr.hint': 'Scan it with your phone camera – the list opens as text.',
+  'shopping.qr.tooLong': 'The list is too long for one QR code. Remove bought items or split the list.',
+  'shopping.export.empty': 'No receipts in the selected period.',
+  'shopping.export.error': 'Receipt export failed',
+  'shopping.export.from': 'From',
+  'shopping.export.to': 'To',
+  'shopping.upload.title': 'Upload a receipt',
+  'shopping.upload.manual': 'Enter a receipt by hand',
+  'shopping.upload.selected': 'Selected file: {name}',
+  'shopping.upload.zoom': 'Enlarge the receipt preview',
+  'shopping.upload.previewAlt': 'Preview',
+  'shopping.upload.noPreview': 'No preview for PDF files',
+  'shopping.upload.imageAlt': 'Receipt',
+  'shopping.upload.processing': 'Processing...',
+  'shopping.upload.submit': 'Confirm and upload',
+  'shopping.receipt.title': 'Receipt data',
+  'shopping.receipt.hint': 'You can edit the data before saving',
+  'shopping.receipt.pricePerKg': 'Price per kilogram',
+  'shopping.receipt.byWeight': 'Sold by weight – the quantity is the weight in kilograms',
+  'shopping.receipt.estimated': 'Estimated',
+  'shopping.receipt.estimatedHint': 'Based on the typical shelf life of products in this category',
+  'shopping.receipt.splitLabel': 'Split item {name}',
+  'shopping.receipt.splitTitle': 'Split into two products',
+  'shopping.receipt.removeLabel': 'Remove item {name}',
+  'shopping.receipt.removeTitle': 'Remove item',
+  'shopping.receipt.mergeLabel': 'Merge {name} with the next item',
+  'shopping.receipt.mergeTitle': 'Merge with the next item',
+  'shopping.receipt.addRow': 'Add item',
+  'shopping.receipt.total': 'Items total:',
+  'shopping.receipt.save': 'Save products',
+  'shopping.split.title': 'Split item',
+  'shopping.split.hint': 'Is "{name}" two products on one line? Enter the names and the price of the first one – the second gets the rest of {total}.',
+  'shopping.split.firstName': 'First product name',
+  'shopping.split.firstPrice': 'First product price',
+  'shopping.split.secondName': 'Second product name',
+  'shopping.split.submit': 'Split',
+  'shopping.history.error': 'Could not load the receipt history.',
+  'shopping.history.empty': 'No saved receipts.',
+  'shopping.history.notFound': 'The linked receipt was not found – it may have been deleted.',
+  'shopping.history.printTitle': 'Receipt',
+  'shopping.history.print': 'Print receipt',
+  'shopping.history.reprocess': 'Process again',
+  'shopping.reprocess.error': 'Reprocessing the receipt',
+  'shopping.reprocess.title': 'Process again: {store}, {date}',
+  'shopping.reprocess.language': 'Receipt language',
+  'shopping.reprocess.preprocess': 'Improve contrast and sharpness',
+  'shopping.reprocess.run': 'Process',
+  'shopping.reprocess.runAgain': 'Process once more',
+  'shopping.reprocess.same': 'Same result as the saved receipt.',
+  'shopping.reprocess.changes': 'Differences from the saved receipt: {count}.',
+  'shopping.reprocess.total': 'Total:',
+  'shopping.reprocess.before': 'Saved receipt',
+  'shopping.reprocess.after': 'New reading',
+  'shopping.sync.title': 'Item changed in two places',
+  'shopping.sync.body': '"{name}" was changed in {provider} after the list was sent. Choose which version to keep.',
+  'shopping.sync.remaining': 'Conflicts left: {count}.',
+  'shopping.sync.mine': 'On this device',
+  'shopping.sync.theirs': 'In {provider}',
+  'shopping.sync.back': 'Back',
+  'shopping.sync.saveMerged': 'Save merged',
+  'shopping.sync.merge': 'Merge',
+  'shopping.sync.keepTheirs': 'Keep {provider} version',
+  'shopping.sync.keepMine': 'Keep mine',
+
+  'cooking.pantryTitle': 'My pantry',
+  'cooking.assistantTitle': 'Cooking assistant',
+  'cooking.recipesTitle': 'Recipes',
+  'cooking.optional': '(optional)',
+  'cooking.chat.title': 'Cooking assistant',
+  'cooking.chat.welcome': 'Welcome to the cooking assistant! I can find recipes based on what is in your pantry or suggest what to cook.',
+  'cooking.chat.failed': 'Sorry, something went wrong while processing that.',
+  'cooking.activity.added': 'Added to the pantry: {name}',
+  'cooking.activity.deleted': 'Removed a product from the pantry',
+  'cooking.activity.updated': 'Updated in the pantry: {name}',
+  'cooking.activity.product': 'product',
+  'cooking.activity.merged': 'Merged in the pantry: {merged} → {kept}',
+  'cooking.activity.chat': 'Cooking chat: {text}',
+  'cooking.stapleRanOut': 'A staple ran out - “{name}” was added to the shopping list',
+  'cooking.view.label': 'Pantry view',
+  'cooking.view.cards': 'Cards',
+  'cooking.view.grid': 'Grid',
+  'cooking.view.table': 'Table',
+  'cooking.view.timeline': 'Timeline',
+  'cooking.expired': 'Expired',
+  'cooking.validUntil': 'Good',
+  'cooking.staple.label': 'Staple',
+  'cooking.staple.toggle': 'Staple: {name}',
+  'cooking.staple.unmark': 'Staple - click to unmark',
+  'cooking.staple.mark': 'Mark as a staple',
+  'cooking.restocked': 'Added to the shopping list: {items}',
+  'cooking.restockNothing': 'All missing staples are already on the list',
+  'cooking.restock': 'Restock staples ({count})',
+  'cooking.pantryEmpty': 'Your pantry is empty',
+  'cooking.noMatches': 'No products match “{query}”',
+  'cooking.addPlaceholder': 'Add a new product...',
+  'cooking.searchPlaceholder': 'Search the pantry... (e.g. cat:dairy exp<7d qty=0)',
+  'cooking.searchLabel': 'Search the pantry',
+  'cooking.searchHint': 'Operators: cat:category, exp<7d (days until expiry), qty=0, attr:attribute, is:staple',
+  'cooking.remove': 'Remove {name}',
+  'cooking.column.expiry': 'Expiry',
+  'cooking.timeline.empty': 'No product has an expiry date',
+  'cooking.timeline.label': 'Expiry timeline',
+  'cooking.timeline.today': 'Today',
+  'cooking.timeline.tomorrow': 'Tomorrow',
+  'cooking.timeline.later': 'Good for more than 2 weeks: {count}',
+  'cooking.recipe.ingredients': 'Ingredients: {count}',
+  'cooking.recipe.cooked': 'Cooked',
+  'cooking.deduct.title': 'Cooked: {name}',
+  'cooking.deduct.hint': 'Check how much to take out of the pantry. Untick the ingredients you do not use.',
+  'cooking.deduct.include': 'Deduct',
+  'cooking.deduct.includeItem': 'Deduct {name}',
+  'cooking.deduct.ingredient': 'Ingredient',
+  'cooking.deduct.product': 'Pantry product',
+  'cooking.deduct.remaining': 'Left',
+  'cooking.deduct.missing': 'not in the pantry',
+  'cooking.deduct.amount': 'Amount to deduct: {name}',
+  'cooking.deduct.unitMismatch': 'The recipe and the product use different units',
+  'cooking.deduct.manual': 'enter manually',
+  'cooking.deduct.error': 'Could not save the deduction: {reason}. Products already saved will not be deducted again.',
+  'cooking.deduct.submit': 'Deduct from the pantry ({count})',
+  'cooking.unknownError': 'unknown error',
+  'cooking.details.noQuantity': 'no quantity',
+  'cooking.details.mergeError': 'Could not merge: {reason}',
+  'cooking.details.saveError': 'Could not save: {reason}',
+  'cooking.details.mergeTitle': 'Merge with a duplicate',
+  'cooking.details.mergeLabel': 'Product to merge',
+  'cooking.details.choose': 'Choose a product…',
+  'cooking.details.mergePreview': '“{merged}” will be removed and “{kept}” will have: {quantity}',
+  'cooking.details.mergeExpiry': ', good until {date}',
+  'cooking.details.merge': 'Merge',
+  'cooking.details.notes': 'Notes',
+  'cooking.details.notesPlaceholder': 'e.g. for the party on Saturday',
+  'cooking.details.attributes': 'Attributes',
+  'cooking.details.attributeName': 'Attribute name',
+  'cooking.details.attributeNamePlaceholder': 'Name, e.g. diet',
+  'cooking.details.attributeValue': 'Attribute value',
+  'cooking.details.attributeValuePlaceholder': 'Value (optional)',
+  'cooking.details.removeAttribute': 'Remove attribute',
+  'cooking.details.addAttribute': 'Add attribute',
+  'cooking.details.mergedTitle': 'Merged products',
+  'cooking.details.bought': 'bought {date}',
+  'cooking.leftovers.title': 'Leftovers',
+  'cooking.leftovers.add': 'Add leftovers',
+  'cooking.leftovers.name': 'What is left',
+  'cooking.leftovers.namePlaceholder': 'e.g. stew from lunch',
+  'cooking.leftovers.cookedOn': 'Cooked on',
+  'cooking.leftovers.eatBy': 'Eat by',
+  'cooking.leftovers.empty': 'No leftovers in the fridge.',
+  'cooking.leftovers.eaten': 'Eaten',
+  'cooking.leftovers.removed': 'Removed leftovers: {name}',
+
+  'analytics.title': 'Spending analytics',
+  'analytics.subtitle': 'Shopping summary based on saved receipts',
+  'analytics.exportCsv': 'Export CSV',
+  'analytics.error': 'Could not load the statistics: {error}',
+  'analytics.total': 'Total spending',
+  'analytics.receiptsCount': 'Number of receipts',
+  'analytics.monthly': 'Monthly spending',
+  'analytics.byCategory': 'Spending by category',
+  'analytics.byStore': 'Shop comparison',
+  'analytics.noData': 'No data in the selected period.',
+  'analytics.receipts': {
+    one: '{count} receipt',
+    other: '{count} receipts',
+  },
+  'analytics.csv.section': 'section',
+  'analytics.csv.label': 'label',
+  'analytics.csv.amount': 'amount',
+  'analytics.csv.count': 'count',
+  'analytics.csv.month': 'month',
+  'analytics.csv.category': 'category',
+  'analytics.csv.store': 'shop',
+  'analytics.range.month': 'This month',
+  'analytics.range.3months': '3 months',
+  'analytics.range.12months': '12 months',
+  'analytics.range.custom': 'Custom range',
+  'analytics.range.from': 'From',
+  'analytics.range.to': 'To',
+  'report.title': 'Monthly report: {month}',
+  'report.previous': 'Previous month',
+  'report.next': 'Next month',
+  'report.error': 'Could not prepare the report: {error}',
+  'report.empty': 'No receipts from this month.',
+  'report.spent': 'spent',
+  'report.versus': 'versus {amount}',
+  'report.receipts': 'receipts',
+  'report.wasted': 'wasted ({amount})',
+  'report.category': 'Category',
+  'report.change': 'Change',
+  'report.summary.spent': 'Spent {amount}',
+  'report.summary.change': ' ({change} compared with the previous month)',
+  'report.summary.top': 'Most spent on: {categories}',
+  'report.summary.wasted': 'Wasted products: {count} ({amount})',
+  'report.notificationTitle': 'FoodSave – summary: {month}',
+  'budget.title': 'Budget for this month',
+  'budget.refresh': 'Refresh the budget',
+  'budget.edit': 'Edit the budget',
+  'budget.empty': 'No budget set. Click the pencil to add a limit.',
+  'budget.progressLabel': 'Budget {label}',
+  'budget.monthly': 'Monthly budget (PLN)',
+  'budget.categoryLimits': 'Category limits',
+  'budget.category': 'Category',
+  'budget.limit': 'Limit',
+  'budget.removeLimit': 'Remove limit',
+  'budget.addCategory': 'Add category',
+  'budget.total': 'Total',
+  'budget.exceeded': 'Budget “{label}” exceeded ({percent}%)',
+  'budget.warning': 'Budget “{label}” {percent}% used',
+  'budget.notificationTitle': 'FoodSave – budget',
+  'budget.widget.title': 'Spending this month',
+  'budget.widget.refresh': 'Refresh spending',
+  'budget.widget.notSet': 'No budget set.',
+  'budget.widget.set': 'Set a budget',
+  'budget.widget.of': 'of {amount}',
+  'budget.widget.usage': 'Budget usage',
+  'budget.widget.over': 'over budget',
+  'budget.widget.left': 'left',
+  'budget.widget.daysLeft': 'days left',
+  'budget.widget.daily': 'per day',
+
+  'dashboard.greeting.night': 'Good night',
+  'dashboard.greeting.morning': 'Good morning',
+  'dashboard.greeting.afternoon': 'Good afternoon',
+  'dashboard.greeting.evening': 'Good evening',
+  'dashboard.summary.nothingExpiring': 'nothing expires this week',
+  'dashboard.summary.expiring': {
+    one: '{count} product expires this week',
+    other: '{count} products expire this week',
+  },
+  'dashboard.summary.rain': 'rain likely today ({percent}%)',
+  'dashboard.summary.rainFrom': 'rain likely today from {time} ({percent}%)',
+  'dashboard.summary.maxTemp': 'up to {temp}°C today',
+  'dashboard.name': 'Your name',
+  'dashboard.changeName': 'Change name',
+  'dashboard.autoRefresh': 'Auto-refresh',
+  'dashboard.refreshAll': 'Refresh all',
+  'dashboard.refreshOff': 'Off',
+  'dashboard.refreshEvery': 'every {interval}',
+  'dashboard.refresh': 'Refresh',
+  'dashboard.widget.weather': 'Weather',
+  'dashboard.widget.budget': 'Budget',
+  'dashboard.widget.receipts': 'Recent receipts',
+  'dashboard.quick.title': 'Quick actions',
+  'dashboard.quick.addLabel': 'Quick add to the pantry',
+  'dashboard.quick.addPlaceholder': 'e.g. milk',
+  'dashboard.quick.add': 'Add to the pantry',
+  'dashboard.quick.added': 'Added: {name}',
+  'dashboard.quick.addError': 'Could not add the product',
+  'dashboard.quick.noteLabel': 'Quick note to the assistant',
+  'dashboard.quick.notePlaceholder': 'e.g. remind me about the weekend shopping',
+  'dashboard.quick.send': 'Send note',
+  'dashboard.quick.noted': 'The assistant took the note.',
+  'dashboard.quick.sendError': 'Could not send the note',
+  'dashboard.quick.continue': 'Continue in the chat',
+  'dashboard.quick.activity': 'Note: {text}',
+  'dashboard.activities.title': 'Recent activity',
+  'dashboard.activities.empty': 'No activity yet. Scan a receipt or talk to the assistant.',
+  'dashboard.receipts.refresh': 'Refresh receipts',
+  'dashboard.receipts.error': 'Could not load the receipts.',
+  'dashboard.receipts.empty': 'No receipts scanned yet.',
+  'dashboard.list.all': 'Whole list',
+  'dashboard.list.done': 'Everything bought.',
+  'dashboard.list.more': 'and {count} more...',
+  'dashboard.today.title': 'Today',
+  'dashboard.today.all': 'All',
+  'dashboard.today.calendar': 'ICS calendar',
+  'dashboard.today.calendarUrl': 'ICS calendar address (optional)',
+  'dashboard.today.empty': 'No reminders for today.',
+  'dashboard.today.allDay': 'all day',
+  'dashboard.today.remove': 'Remove reminder',
+  'dashboard.today.calendarError': 'Could not load the calendar.',
+  'dashboard.today.placeholder': 'New reminder...',
+  'dashboard.report.title': 'Summary: {month}',
+  'dashboard.report.refresh': 'Refresh summary',
+  'dashboard.report.change': 'Change compared with the previous month',
+  'dashboard.report.full': 'Full report',
+  'dashboard.llm.loading': 'Loading model settings...',
+  'dashboard.llm.errorTitle': 'Could not load the settings',
+  'dashboard.llm.errorBody': 'Could not load the LLM model settings.',
+  'dashboard.llm.title': 'LLM model settings',
+  'dashboard.llm.current': 'Current model:',
+  'dashboard.llm.loadingShort': 'Loading...',
+  'dashboard.llm.none': 'No model selected',
+  'dashboard.llm.choose': 'Choose a model:',
+  'dashboard.llm.details': 'Size: {size} | Modified: {modified}',
+  'dashboard.llm.saving': 'Saving...',
+  'dashboard.llm.save': 'Save model',
+  'dashboard.llm.saveError': 'Could not save the model: {error}',
+  'weather.title': 'Weather forecast',
+  'weather.refresh': 'Refresh weather',
+  'weather.loading': 'Loading the forecast...',
+  'weather.error': 'Could not load the weather.',
+  'weather.stale': 'Could not refresh the weather - showing the previous data.',
+  'weather.retrying': 'Retrying soon...',
+  'weather.empty': 'No weather data',
+  'weather.forecastFor': 'Forecast for {date}',
+  'weather.noDescription': 'No description',
+  'weather.temperature': 'Temperature: {min} – {max}C',
+  'weather.rainChance': 'Chance of rain: {chance}',
+  'weather.noData': 'no data',
+  'weather.alertTitle': 'Weather warning – {location}',
+  'weather.alertUntil': 'Until: {date}',
+  'weather.location': 'Weather location',
+  'weather.manage': 'Manage locations',
+  'weather.remove': 'Remove {label}',
+  'weather.labelPlaceholder': 'Name (e.g. Parents)',
+  'weather.cityPlaceholder': 'City',
+  'weather.add': 'Add location',
+
+  'rag.title': 'RAG Knowledge Base',
+  'rag.subtitle': 'Upload documents to enhance AI responses with your knowledge',
+  'rag.subtitleDirectory': 'Documents in: {directory}',
+  'rag.upload.title': 'Upload Documents',
+  'rag.upload.drop': 'Drop the files here...',
+  'rag.upload.prompt': 'Drag & drop files here, or click to select',
+  'rag.upload.supported': 'Supported: PDF, TXT, DOCX, MD, RTF',
+  'rag.upload.description': 'Description (optional)',
+  'rag.upload.descriptionPlaceholder': 'Brief description of the document',
+  'rag.upload.tags': 'Tags (comma-separated)',
+  'rag.upload.tagsPlaceholder': 'tag1, tag2, tag3',
+  'rag.upload.uploading': 'Uploading documents...',
+  'rag.query.title': 'Query Knowledge Base',
+  'rag.query.searchingIn': 'Searching in: {directory}',
+  'rag.query.placeholder': 'Ask a question about your documents...',
+  'rag.query.placeholderDirectory': 'Ask a question about documents in {directory}...',
+  'rag.query.submit': 'Query',
+  'rag.query.answer': 'Answer:',
+  'rag.query.noAnswer': 'No answer found',
+  'rag.query.error': 'Error querying RAG system',
+  'rag.query.sources': 'Sources ({count}):',
+  'rag.query.unknownDocument': 'Unknown document',
+  'rag.query.noContent': 'No content available',
+  'rag.query.relevance': 'Relevance: {percent}%',
+  'rag.documents.title': 'Uploaded Documents ({count})',
+  'rag.documents.selectAll': 'Select All',
+  'rag.documents.deselectAll': 'Deselect All',
+  'rag.documents.selected': '({count} selected)',
+  'rag.documents.moveSelected': 'Move Selected',
+  'rag.documents.deleteSelected': 'Delete Selected',
+  'rag.documents.empty': 'No documents uploaded yet',
+  'rag.documents.emptyHint': 'Upload your first document to get started',
+  'rag.documents.chunks': {
+    one: '{count} chunk',
+    other: '{count} chunks',
+  },
+  'rag.documents.count': {
+    one: '{count} document',
+    other: '{count} documents',
+  },
+  'rag.documents.move': 'Move document',
+  'rag.documents.delete': 'Delete document',
+  'rag.move.title': 'Move Document',
+  'rag.move.moving': 'Moving: ',
+  'rag.move.from': 'From: ',
+  'rag.move.defaultDirectory': 'default',
+  'rag.move.to': 'To Directory',
+  'rag.move.placeholder': 'Enter directory name',
+  'rag.move.submit': 'Move',
+  'rag.move.submitting': 'Moving...',
+  'rag.move.noTarget': 'Please select a target directory',
+  'rag.move.failed': 'Failed to move document',
+  'rag.bulk.moveTitle': 'Move Selected Documents',
+  'rag.bulk.moving': 'Moving ',
+  'rag.bulk.deleteTitle': 'Delete Selected Documents',
+  'rag.bulk.deleteConfirm': 'Are you sure you want to delete ',
+  'rag.bulk.irreversible': 'This action cannot be undone.',
+  'rag.bulk.deleting': 'Deleting...',
+  'rag.bulk.noTarget': 'Please select documents and enter a target directory',
+  'rag.bulk.noSelection': 'Please select documents to delete',
+  'rag.bulk.moveFailed': 'Failed to bulk move documents',
+  'rag.bulk.deleteFailed': 'Failed to bulk delete documents',
+  'rag.unknownError': 'Unknown error',
+  'rag.dirs.title': 'RAG Directories',
+  'rag.dirs.loading': 'Loading directories...',
+  'rag.dirs.error': 'Error: {error}',
+  'rag.dirs.loadFailed': 'Failed to fetch directories',
+  'rag.dirs.back': '← Back to all directories',
+  'rag.dirs.create': '+ Create Directory',
+  'rag.dirs.empty': 'No directories found.',
+  'rag.dirs.stats': 'View statistics',
+  'rag.dirs.rename': 'Rename directory',
+  'rag.dirs.delete': 'Delete directory',
+  'rag.dirs.createTitle': 'Create New Directory',
+  'rag.dirs.name': 'Directory Name',
+  'rag.dirs.namePlaceholder': 'e.g., recipes, guides, work',
+  'rag.dirs.nameEmpty': 'Directory name cannot be empty',
+  'rag.dirs.createSubmit': 'Create',
+  'rag.dirs.creating': 'Creating...',
+  'rag.dirs.createFailed': 'Failed to create directory',
+  'rag.dirs.deleteTitle': 'Delete Directory',
+  'rag.dirs.deleteConfirm': 'Are you sure you want to delete the directory ',
+  'rag.dirs.deleteHint': 'All documents in this directory will be moved to the default directory.',
+  'rag.dirs.deleteFailed': 'Failed to delete directory',
+  'rag.dirs.renameTitle': 'Rename Directory',
+  'rag.dirs.renaming': 'Renaming: ',
+  'rag.dirs.newName': 'New Directory Name',
+  'rag.dirs.newNamePlaceholder': 'Enter new directory name',
+  'rag.dirs.newNameEmpty': 'Please enter a new directory name',
+  'rag.dirs.renameSubmit': 'Rename',
+  'rag.dirs.renameSubmitting': 'Renaming...',
+  'rag.dirs.renameFailed': 'Failed to rename directory',
+  'rag.dirs.statsTitle': 'Directory Statistics',
+  'rag.dirs.statsLoading': 'Loading statistics...',
+  'rag.dirs.statsFailed': 'Failed to fetch directory stats',
+  'rag.dirs.documents': 'Documents',
+  'rag.dirs.chunks': 'Chunks',
+  'rag.dirs.totalTags': 'Total Tags:',
+  'rag.dirs.recentActivity': 'Recent Activity:',
+  'rag.dirs.avgChunks': 'Avg Chunks/Doc:',
+  'rag.dirs.fileTypes': 'File Types:',
+
+  'rag.manager.uploaded': 'Document uploaded successfully!',
+  'rag.manager.uploadFailed': 'Error uploading document',
+  'rag.manager.statsTitle': 'RAG System Statistics',
+  'rag.manager.loading': 'Loading...',
+  'rag.manager.refresh': 'Refresh',
+  'rag.manager.vectorStore': 'Vector Store',
+  'rag.manager.totalChunks': 'Total Chunks: {count}',
+  'rag.manager.totalDocuments': 'Total Documents: {count}',
+  'rag.manager.processor': 'Processor',
+  'rag.manager.chunkSize': 'Chunk Size: {value}',
+  'rag.manager.chunkOverlap': 'Chunk Overlap: {value}',
+  'rag.manager.localEmbeddings': 'Local Embeddings: {value}',
+  'rag.manager.pinecone': 'Pinecone: {value}',
+  'rag.manager.yes': 'Yes',
+  'rag.manager.no': 'No',
+  'rag.manager.statsHint': 'Click "Refresh" to load statistics',
+  'rag.manager.uploadTitle': 'Upload Document',
+  'rag.manager.selectFile': 'Select File',
+  'rag.manager.category': 'Category (optional)',
+  'rag.manager.categoryPlaceholder': 'e.g., recipes, guides',
+  'rag.manager.tags': 'Tags (optional)',
+  'rag.manager.tagsPlaceholder': 'e.g., cooking, healthy, quick',
+  'rag.manager.uploading': 'Uploading...',
+  'rag.manager.syncTitle': 'Database Synchronization',
+  'rag.manager.syncReceipts': 'Sync Receipts',
+  'rag.manager.syncPantry': 'Sync Pantry',
+  'rag.manager.syncConversations': 'Sync Conversations',
+  'rag.manager.syncAll': 'Sync All',
+  'rag.manager.syncResults': 'Sync Results:',
+  'rag.manager.syncReceiptsResult': 'Receipts: {trips} trips, {chunks} chunks',
+  'rag.manager.syncPantryResult': 'Pantry: {products} products, {chunks} chunks',
+  'rag.manager.syncConversationsResult': 'Conversations: {conversations} conversations, {chunks} chunks',
+  'rag.manager.searchTitle': 'Search Documents',
+  'rag.manager.searchPlaceholder': 'Enter search query...',
+  'rag.manager.searching': 'Searching...',
+  'rag.manager.search': 'Search',
+  'rag.manager.results': 'Search Results ({count})',
+  'rag.manager.similarity': 'Similarity: {percent}%',
+  'rag.manager.source': 'Source: {source}',
+  'rag.manager.type': 'Type: {type}',
+  'rag.manager.categoryValue': 'Category: {category}',
+  'rag.manager.date': 'Date: {date}',
+
+  'notifications.reminder': 'Reminder',
+  'notifications.expiringTitle': 'FoodSave – expiring products',
+  'notifications.expiringBody': 'Products close to their expiry date: {count}',
+  'notifications.leftoversTitle': 'FoodSave – leftovers',
+  'notifications.leftover.soon': '“{name}” needs to be eaten by tomorrow',
+  'notifications.leftover.today': '“{name}” needs to be eaten today',
+  'notifications.leftover.overdue': '“{name}” is past its date – check it before eating',
+  'notifications.routineTask': 'Routine: {name}',
+
+  'common.confirm': 'Confirm',
+  'common.loading': 'Loading',
+  'common.loadingData': 'Loading data',
+  'common.unknownError': 'An unknown error occurred',
+  'common.unsavedChanges': 'You have unsaved changes. Leave the page without saving?',
+  'nav.backup': 'Backups',
+  'network.offline': 'No connection',
+  'network.unreachable': 'The FoodSave server is not responding',
+  'network.restored': 'Connection restored – syncing data.',
+  'network.showingSaved': '{status} – showing data saved {savedAt}.',
+  'network.savedEarlier': 'earlier',
+  'recovery.receipt': 'the unsaved receipt',
+  'recovery.message': 'the unsent message',
+  'recovery.prompt': 'The previous session was not closed properly. Restore {what}?',
+  'recovery.restore': 'Restore',
+  'tabs.openElsewhere': 'FoodSave is already open in another tab – that tab sends the reminder notifications.',
+  'queries.products': 'Products',
+  'queries.trips': 'Receipts',
+  'queries.spendingStats': 'Spending statistics',
+  'queries.weather': 'Weather',
+  'queries.calendar': 'Calendar',
+  'lazy.failed': 'Failed to load component',
+  'errorBoundary.title': 'Something went wrong',
+  'errorBoundary.body': 'We\'re sorry, but something unexpected happened. Please try refreshing the page or contact support if the problem persists.',
+  'errorBoundary.details': 'Error Details (Development)',
+  'errorBoundary.retry': 'Try Again',
+  'errorBoundary.report': 'Report Error',
+  'errorBoundary.reported': 'Error has been reported. Thank you for your feedback.',
+  'chat.welcome': 'Hi! I\'m your FoodSave assistant. How can I help you today?',
+  'chat.replyFailed': 'Sorry, something went wrong while processing your message.',
+  'chat.errorReply': 'An error occurred: {error}',
+  'chat.errorContext': 'Assistant',
+  'chat.taskReply': 'The assistant is answering',
+  'chat.announceReply': 'Assistant: {text}',
+  'chat.notificationTitle': 'FoodSave – assistant\'s answer',
+  'chat.status.sending': 'Sending…',
+  'chat.status.delivered': 'Delivered',
+  'chat.status.failed': 'Failed to send',
+  'chat.message.clarify': 'Clarify the question',
+  'chat.message.didYouMean': 'Did you mean:',
+  'chat.message.pin': 'Pin',
+  'chat.message.print': 'Print',
+  'chat.message.cooked': 'Cooked',
+  'chat.message.queued': 'Waiting for connection',
+  'chat.message.queuedHint': 'The message will be sent automatically once the connection is back',
+  'chat.image.alt': 'Image from the answer',
+  'chat.image.zoom': 'Enlarge: {alt}',
+  'chat.attachment.pantry': 'Pantry',
+  'chat.attachment.receipts': 'This week\'s receipts',
+  'chat.attachment.shoppingList': 'Shopping list',
+  'shopping.list.removedOne': 'Removed “{name}” from the list',
+  'shopping.list.removedMany': 'Removed from the list: {count}',
+  'shopping.list.duplicate': '“{name}” is already on the list',
+  'shopping.list.addAnyway': 'Add anyway',
+  'shopping.list.syncContext': 'Shopping list sync',
+  'shopping.list.conflictContext': 'Resolving a shopping list conflict',
+  'shopping.unitPiece': 'pcs',
+
+  'lazy.loading': 'Loading...',
+  'lazy.backupManager': 'Loading backup manager...',
+  'lazy.ragManager': 'Loading RAG manager...',
+  'lazy.ragUpload': 'Loading RAG upload...',
+  'lazy.chat': 'Loading chat interface...',
+  'lazy.cooking': 'Loading cooking assistant...',
+  'lazy.productTable': 'Loading product table...',
+  'lazy.receiptUploader': 'Loading receipt uploader...',
+  'lazy.pantry': 'Loading pantry list...',
+
+  'chat.activity': 'Chat: {text}',
+
+  'backup.title': 'Backup System Management',
+  'backup.subtitle': 'Create, manage, and restore system backups following the 3-2-1 rule',
+  'backup.created': 'Backup created successfully!',
+  'backup.createFailed': 'Error creating backup',
+  'backup.selectFirst': 'Please select a backup to restore',
+  'backup.restoreConfirm': 'Are you sure you want to restore backup "{name}"? This will overwrite current data.',
+  'backup.restored': 'Backup restored successfully! Status: {status}',
+  'backup.restoreFailed': 'Error restoring backup',
+  'backup.verified': 'Backup verification completed. Status: {status}',
+  'backup.verifyFailed': 'Error verifying backup',
+  'backup.cleanupConfirm': 'Are you sure you want to cleanup old backups? This action cannot be undone.',
+  'backup.cleaned': 'Old backups cleaned up successfully!',
+  'backup.cleanupFailed': 'Error cleaning up backups',
+  'backup.statsTitle': 'Backup System Statistics',
+  'backup.loading': 'Loading...',
+  'backup.refresh': 'Refresh',
+  'backup.totalBackups': 'Total Backups',
+  'backup.totalSize': 'Total Size',
+  'backup.dirSize': 'Backup Directory Size',
+  'backup.verification': 'Verification',
+  'backup.enabled': 'Enabled',
+  'backup.disabled': 'Disabled',
+  'backup.statsLoading': 'Loading statistics...',
+  'backup.createTitle': 'Create New Backup',
+  'backup.namePlaceholder': 'Backup name (optional)',
+  'backup.creating': 'Creating...',
+  'backup.create': 'Create Backup',
+  'backup.historyTitle': 'Backup History',
+  'backup.createdAt': 'Created: {date}',
+  'backup.size': 'Size: {size}',
+  'backup.status': 'Status: {status}',
+  'backup.verify': 'Verify',
+  'backup.restore': 'Restore',
+  'backup.empty': 'No backups found',
+  'backup.restoreTitle': 'Restore Backup: {name}',
+  'backup.componentsPlaceholder': 'Components to restore (comma-separated, leave empty for all)',
+  'backup.restoring': 'Restoring...',
+  'backup.maintenance': 'Maintenance',
+  'backup.cleaning': 'Cleaning...',
+  'backup.cleanup': 'Cleanup Old Backups',
+  'backup.bytes': 'Bytes',
+
+  'url.empty': 'Enter the server address',
+  'url.scheme': 'The address must start with http:// or https://',
+  'url.invalid': 'Invalid URL',
+  'url.protocol': 'Only the http and https protocols are supported',
+  'url.queryOrHash': 'The address cannot contain query parameters or an anchor (#)',
+  'url.insecure': 'An http connection outside the local network is not encrypted',
+  'url.notAllowed': '{origin} is not listed in NEXT_PUBLIC_ALLOWED_BACKEND_ORIGINS, so the browser will block the connection',
+  'connection.degraded': 'The server is running, but some services are unavailable',
+  'connection.noHealth': 'No /health endpoint – this is probably not a FoodSave server',
+  'connection.badResponse': 'Invalid server response (HTTP {status})',
+  'connection.httpError': 'The server returned HTTP error {status}',
+  'connection.timeout': 'Timed out ({seconds} s)',
+  'connection.unreachable': 'Cannot connect to the server (wrong address, server down or blocked by CORS)',
+  'print.product': 'Product',
+  'print.quantity': 'Quantity',
+  'print.price': 'Price',
+  'print.value': 'Amount',
+  'print.total': 'Total',
+  'print.recipe': 'Recipe',
+  'settings.transfer.notJson': 'The file is not valid JSON',
+  'settings.transfer.notSettings': 'This is not a FoodSave settings file',
+  'settings.transfer.newerVersion': 'The file comes from a newer version of the app',
+  'chat.streamedReply': 'Processed the streamed response.',
+};
@@ -0,0 +1,16 @@
+import { translate } from './index';
+
+describe('i18n', () => {
+  it('tłumaczy klucze i podstawia parametry', () => {
+    expect(translate('pl', 'nav.settings')).toBe('Ustawienia');
+    expect(translate('en', 'nav.settings')).toBe('Settings');
+    expect(translate('en', 'settings.backend.connected', { ms: 42 })).toBe('Connected in 42 ms');
+  });
+
+  it('wybiera formę liczby mnogiej według języka', () => {
+    expect(translate('pl', 'reminders.count', { count: 1 })).toBe('1 przypomnienie');
+    expect(translate('pl', 'reminders.count', { count: 3 })).toBe('3 przypomnienia');
+    expect(translate('pl', 'reminders.count', { count: 5 })).toBe('5 przypomnień');
+    expect(translate('en', 'reminders.count', { count: 5 })).toBe('5 reminders');
+  });
+});
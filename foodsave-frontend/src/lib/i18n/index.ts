@@ -0,0 +1,40 @@
+// Minimal message catalogue with {placeholders} and CLDR plural forms.
+// Every page reads its texts from here; only keyword data (shelf life, fuzzy
+// matching, weather) and the prompts sent to the assistant stay Polish
+
+import { pl } from './pl';
+import { en } from './en';
+
+export type Locale = 'pl' | 'en';
+
+export const LOCALE_LABELS: Record<Locale, string> = {
+  pl: 'Polski',
+  en: 'English',
+};
+
+export type MessageKey = keyof typeof pl;
+export type PluralMessage = Partial<Record<Intl.LDMLPluralRule, string>> & { other: string };
+export type Message = string | PluralMessage;
+export type Messages = Record<MessageKey, Message>;
+export type MessageArgs = Record<string, string | number>;
+
+const CATALOGUES: Record<Locale, Messages> = { pl, en };
+
+function interpolate(template: string, args: MessageArgs): string {
+  return template.replace(/\{(\w+)\}/g, (match, name: string) =>
+    name in args ? String(args[name]) : match,
+  );
+}
+
+export function translate(locale: Locale, key: MessageKey, args: MessageArgs = {}): string {
+  const message = CATALOGUES[locale][key] ?? pl[key] ?? key;
+  if (typeof message === 'string') return interpolate(message, args);
+
+  const count = Number(args.count ?? 0);
+  const form = new Intl.PluralRules(locale).select(count);
+  return interpolate(message[form] ?? message.other, args);
+}
+
+export function isLocale(value: unknown): value is Locale {
+  return value === 'pl' || value === 'en';
+}
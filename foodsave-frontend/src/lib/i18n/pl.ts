@@ -0,0 +1,971 @@
+// Polish messages - the source of truth for message keys
+
+export const pl = {
+  'nav.dashboard': 'Dashboard',
+  'nav.chat': 'Czat',
+  'nav.shopping': 'Zakupy',
+  'nav.cooking': 'Gotowanie',
+  'nav.analytics': 'Analityka',
+  'nav.reminders': 'Przypomnienia',
+  'nav.rag': 'RAG',
+  'nav.settings': 'Ustawienia',
+  'nav.main': 'Nawigacja główna',
+  'nav.toggleSidebar': 'Zwiń lub rozwiń menu',
+
+  'common.save': 'Zapisz',
+  'common.cancel': 'Anuluj',
+  'common.add': 'Dodaj',
+  'common.edit': 'Edytuj',
+  'common.delete': 'Usuń',
+  'common.close': 'Zamknij',
+  'common.retry': 'Ponów',
+  'common.undo': 'Cofnij',
+  'common.default': 'Domyślny',
+
+  'settings.title': 'Ustawienia',
+  'settings.subtitle': 'Konfiguracja aplikacji FoodSave',
+  'settings.unsaved': 'Masz niezapisane zmiany',
+  'settings.discard': 'Odrzuć',
+  'settings.search': 'Szukaj ustawień...',
+  'settings.noResults': 'Brak ustawień pasujących do wyszukiwania.',
+  'settings.section.general': 'Ogólne',
+  'settings.section.appearance': 'Wygląd',
+  'settings.section.backend': 'Serwer',
+  'settings.section.notifications': 'Powiadomienia',
+  'settings.section.privacy': 'Dane i prywatność',
+  'settings.general.title': 'Ogólne',
+  'settings.general.userName': 'Twoje imię',
+  'settings.language.title': 'Język',
+  'settings.receipts.title': 'Paragony',
+  'settings.receipts.language': 'Język paragonów',
+  'settings.receipts.languageHint': 'Podpowiedź dla OCR. Automatyczne rozpoznawanie działa wolniej niż wybrany język.',
+  'settings.receipts.auto': 'Rozpoznawaj automatycznie',
+  'settings.receipts.pl': 'Polski',
+  'settings.receipts.de': 'Niemiecki',
+  'settings.receipts.en': 'Angielski',
+  'settings.receipts.archive': 'Archiwum skanów',
+  'settings.receipts.archiveEmpty': 'Brak zapisanych skanów',
+  'settings.receipts.archiveSince': 'od {date}',
+  'settings.receipts.retention': 'Przechowuj skany',
+  'settings.receipts.retentionForever': 'bez limitu',
+  'settings.receipts.purge': 'Usuń skany',
+  'settings.receipts.purgeConfirm': 'Usunąć wszystkie zapisane skany paragonów? Paragony zostaną w historii, ale nie będzie można przetworzyć ich ponownie.',
+  'settings.receipts.scans': {
+    one: '{count} skan',
+    few: '{count} skany',
+    many: '{count} skanów',
+    other: '{count} skanu',
+  },
+  'settings.receipts.months': {
+    one: '{count} miesiąc',
+    few: '{count} miesiące',
+    many: '{count} miesięcy',
+    other: '{count} miesiąca',
+  },
+  'settings.startup.title': 'Uruchamianie',
+  'settings.startup.restoreLastPage': 'Otwieraj ostatnio odwiedzoną stronę',
+  'settings.startup.hint': 'Dotyczy wejścia na stronę główną aplikacji; domyślnie otwiera się dashboard.',
+  'settings.startup.deepLinks': 'Linki web+foodsave:// (np. web+foodsave://chat?prompt=...)',
+  'settings.startup.registerDeepLinks': 'Otwieraj linki w tej aplikacji',
+  'settings.startup.deepLinksHint': 'Przeglądarka poprosi o potwierdzenie.',
+  'settings.startup.deepLinksRegistered': 'Wysłano prośbę do przeglądarki - potwierdź ją, jeśli się pojawi.',
+  'settings.startup.deepLinksFailed': 'Przeglądarka odrzuciła rejestrację linków.',
+  'settings.startup.deepLinksUnsupported': 'Ta przeglądarka nie obsługuje własnych linków.',
+  'settings.calendarExport.title': 'Kalendarz dat ważności',
+  'settings.calendarExport.hint': 'Zasubskrybuj kanał w aplikacji kalendarza, aby widzieć daty ważności produktów z przypomnieniem dzień wcześniej.',
+  'settings.calendarExport.enabled': 'Udostępniaj kanał dat ważności',
+  'settings.calendarExport.enabledHint': 'Kanał nie wymaga logowania - każdy, kto zna adres serwera, zobaczy daty ważności. Po wyłączeniu subskrypcje przestaną się odświeżać.',
+  'settings.calendarExport.saveFailed': 'Nie udało się zmienić ustawienia kanału',
+  'settings.calendarExport.feedUrl': 'Adres kanału kalendarza',
+  'settings.calendarExport.copy': 'Kopiuj adres',
+  'settings.calendarExport.copied': 'Skopiowano',
+  'settings.calendarExport.subscribe': 'Subskrybuj',
+  'settings.calendarExport.download': 'Pobierz .ics',
+  'profiles.switch': 'Przełącz profil',
+  'profiles.title': 'Domownicy',
+  'profiles.hint': 'Każdy profil ma własną historię rozmów i ustawienia; lista zakupów, budżet i przypomnienia są wspólne.',
+  'profiles.name': 'Nazwa profilu',
+  'profiles.active': 'Aktywny',
+  'profiles.switchTo': 'Przełącz',
+  'profiles.remove': 'Usuń profil',
+  'profiles.removeConfirm': 'Usunąć profil „{name}” razem z jego historią rozmów i ustawieniami?',
+  'profiles.newPlaceholder': 'Imię domownika...',
+  'profiles.add': 'Dodaj',
+  'settings.notifications.title': 'Powiadomienia na pulpicie',
+  'settings.notifications.default': 'Powiadomienia nie są jeszcze włączone.',
+  'settings.notifications.granted': 'Powiadomienia są włączone.',
+  'settings.notifications.denied': 'Powiadomienia są zablokowane w ustawieniach przeglądarki.',
+  'settings.notifications.unsupported': 'Ta przeglądarka nie obsługuje powiadomień.',
+  'settings.notifications.enable': 'Włącz powiadomienia',
+  'settings.notifications.test': 'Wyślij powiadomienie testowe',
+  'settings.notifications.testBody': 'Powiadomienia działają poprawnie.',
+  'settings.notifications.master': 'Pokazuj powiadomienia FoodSave',
+  'settings.notifications.categories': 'Rodzaje powiadomień',
+  'settings.notifications.category.reminder': 'Przypomnienia',
+  'settings.notifications.category.budget': 'Przekroczenie budżetu',
+  'settings.notifications.category.weather': 'Ostrzeżenia pogodowe',
+  'settings.notifications.category.pantry': 'Kończące się produkty',
+  'settings.notifications.category.chat': 'Odpowiedzi czatu (gdy karta jest w tle)',
+  'settings.notifications.category.ocr': 'Zakończone skanowanie paragonu',
+  'settings.section.shortcuts': 'Skróty klawiszowe',
+  'settings.shortcuts.title': 'Skróty klawiszowe',
+  'settings.shortcuts.change': 'Zmień skrót: {action}',
+  'settings.shortcuts.press': 'Naciśnij skrót...',
+  'settings.shortcuts.needsModifier': 'Skrót musi zawierać Ctrl, Alt lub Meta.',
+  'settings.shortcuts.conflict': 'Skrót {binding} jest już przypisany do: {action}.',
+  'settings.shortcuts.reset': 'Przywróć domyślne',
+  'settings.shortcuts.builtIn': 'Zawsze dostępne: / – pole wpisywania na stronie, Esc – zamknij okno lub powiadomienie.',
+  'shortcuts.focusNext': 'Następny obszar strony',
+  'shortcuts.focusPrevious': 'Poprzedni obszar strony',
+  'settings.data.title': 'Dane w tej przeglądarce',
+  'settings.data.cache': 'Pamięć podręczna zapytań',
+  'settings.data.chat': 'Historia czatu',
+  'settings.data.ocr': 'Historia skanów paragonów',
+  'settings.data.all': 'Wszystkie dane lokalne',
+  'settings.data.allHint': 'Ustawienia, przypomnienia, lista zakupów, budżet i historia',
+  'settings.data.allConfirm': 'Usunąć wszystkie dane FoodSave zapisane w tej przeglądarce? Tej operacji nie można cofnąć.',
+  'settings.data.clear': 'Wyczyść',
+  'settings.logging.title': 'Diagnostyka',
+  'settings.logging.level': 'Poziom dziennika',
+  'settings.logging.persist': 'Zachowuj dziennik po przeładowaniu strony',
+  'settings.logging.persistHint': 'Wpisy są zapisywane w tej przeglądarce, w dwóch rotowanych plikach po 250 wpisów.',
+  'settings.logging.metrics': 'Zbieraj metryki wydajności (czasy odpowiedzi API, błędy, OCR)',
+  'settings.logging.metricsHint': 'Dane zostają tylko w tej przeglądarce; wyłączenie je usuwa.',
+  'settings.logging.open': 'Otwórz dziennik aplikacji',
+  'settings.section.about': 'O aplikacji',
+  'settings.about.title': 'O aplikacji',
+  'settings.about.version': 'Wersja {version}',
+  'settings.about.checkUpdates': 'Sprawdzaj raz dziennie, czy jest nowa wersja',
+  'settings.about.checkUpdatesHint': 'Aplikacja łączy się wtedy z GitHubem (api.github.com).',
+  'settings.about.checkNow': 'Sprawdź teraz',
+  'settings.about.upToDate': 'Masz najnowszą wersję.',
+  'settings.about.available': 'Dostępna jest nowa wersja: {version}',
+  'settings.about.releaseNotes': 'Informacje o wydaniu',
+  'settings.about.checkFailed': 'Nie udało się sprawdzić aktualizacji: {error}',
+  'updates.available': 'Dostępna jest nowa wersja FoodSave ({version}).',
+  'updates.details': 'Szczegóły',
+  'settings.data.entries': {
+    one: '{count} wpis',
+    few: '{count} wpisy',
+    many: '{count} wpisów',
+    other: '{count} wpisu',
+  },
+  'settings.appearance.title': 'Wygląd',
+  'settings.appearance.theme': 'Motyw',
+  'settings.appearance.light': 'Jasny',
+  'settings.appearance.dark': 'Ciemny',
+  'settings.appearance.system': 'Systemowy',
+  'settings.appearance.accent': 'Kolor akcentu',
+  'settings.appearance.customAccent': 'Własny kolor akcentu',
+  'settings.appearance.customPalette': 'Własna paleta kolorów',
+  'settings.appearance.background': 'Tło',
+  'settings.appearance.surface': 'Karty',
+  'settings.appearance.previewHint': 'Podgląd jest widoczny od razu; zapisz, aby zachować motyw.',
+  'settings.scale.title': 'Rozmiar interfejsu',
+  'settings.scale.label': 'Skala interfejsu',
+  'settings.scale.hint': 'Skaluje tekst i odstępy w całej aplikacji.',
+  'settings.accessibility.title': 'Dostępność',
+  'settings.accessibility.highContrast': 'Wysoki kontrast',
+  'settings.accessibility.reducedMotion': 'Ograniczenie animacji',
+  'settings.accessibility.on': 'Włączone',
+  'settings.accessibility.off': 'Wyłączone',
+  'settings.accessibility.hint': '„Systemowy” korzysta z ustawień dostępności systemu operacyjnego. Wysoki kontrast zastępuje własną paletę kolorów.',
+  'settings.backend.title': 'Połączenie z serwerem',
+  'settings.backend.address': 'Adres serwera FoodSave',
+  'settings.backend.test': 'Testuj połączenie',
+  'settings.backend.connected': 'Połączono w {ms} ms',
+  'settings.backend.disconnected': 'Brak połączenia',
+  'settings.backend.version': 'wersja {version}',
+  'settings.transfer.title': 'Kopia ustawień',
+  'settings.transfer.hint': 'Plik JSON bez prywatnych adresów kalendarza. Zaimportowane ustawienia trzeba zapisać.',
+  'settings.transfer.export': 'Eksportuj ustawienia',
+  'settings.transfer.import': 'Importuj ustawienia',
+  'settings.transfer.imported': {
+    one: 'Wczytano {count} ustawienie',
+    few: 'Wczytano {count} ustawienia',
+    many: 'Wczytano {count} ustawień',
+    other: 'Wczytano {count} ustawienia',
+  },
+
+  'logs.title': 'Dziennik aplikacji',
+  'logs.subtitle': 'Ostatnie zdarzenia z tej sesji – dołącz je do zgłoszenia błędu',
+  'logs.count': {
+    one: '{count} wpis',
+    few: '{count} wpisy',
+    many: '{count} wpisów',
+    other: '{count} wpisu',
+  },
+  'logs.level': 'Minimalny poziom',
+  'logs.module': 'Moduł',
+  'logs.allModules': 'Wszystkie moduły',
+  'logs.search': 'Szukaj w dzienniku...',
+  'logs.empty': 'Brak wpisów spełniających kryteria.',
+  'logs.copy': 'Kopiuj',
+  'logs.copied': 'Skopiowano dziennik do schowka',
+  'logs.copyFailed': 'Nie udało się skopiować dziennika',
+  'logs.export': 'Eksportuj',
+  'metrics.title': 'Wydajność',
+  'metrics.disabled': 'Zbieranie metryk jest wyłączone. Włącz je w Ustawienia → Diagnostyka.',
+  'metrics.empty': 'Brak pomiarów - skorzystaj z aplikacji, a wyniki pojawią się tutaj.',
+  'metrics.name': 'Operacja',
+  'metrics.count': 'Liczba',
+  'metrics.errors': 'Błędy',
+  'metrics.max': 'Maks.',
+
+  'chat.title': 'Asystent AI',
+  'chat.placeholder': 'Zadaj pytanie lub opisz swój problem...',
+  'chat.new': 'Nowy czat',
+  'chat.clear': 'Wyczyść czat',
+  'chat.input.placeholder': 'Wpisz wiadomość...',
+  'chat.input.label': 'Wiadomość do asystenta',
+  'chat.input.preview': 'Podgląd wiadomości',
+  'chat.input.previewToggle': 'Podgląd Markdown',
+  'chat.input.previewShow': 'Pokaż podgląd Markdown',
+  'chat.input.previewHide': 'Ukryj podgląd',
+  'chat.input.cookingMode': 'Tryb gotowania',
+  'chat.input.cookingOn': 'Włącz tryb gotowania',
+  'chat.input.cookingOff': 'Wyłącz tryb gotowania',
+  'chat.input.shoppingMode': 'Tryb zakupów',
+  'chat.input.shoppingOn': 'Włącz tryb zakupów',
+  'chat.input.shoppingOff': 'Wyłącz tryb zakupów',
+  'chat.input.switchModel': 'Przełącz na {model}',
+  'chat.input.model': 'Model: {model}. Przełącz na {other}',
+  'chat.input.perplexity': 'Perplexity (modele online)',
+  'chat.input.perplexityOn': 'Włącz Perplexity (użyj modeli online)',
+  'chat.input.perplexityOff': 'Wyłącz Perplexity (użyj lokalnych modeli)',
+  'chat.input.replyLanguageHint': 'Język odpowiedzi w tej rozmowie (niezależny od języka interfejsu)',
+  'chat.input.replyLanguage': 'Język odpowiedzi: {language}',
+  'chat.replyLanguage.auto': 'automatyczny',
+  'chat.replyLanguage.pl': 'polski',
+  'chat.replyLanguage.en': 'angielski',
+  'chat.input.send': 'Wyślij',
+  'chat.input.hint': 'Enter – wyślij, Shift+Enter – nowa linia',
+  'chat.pinned.label': 'Przypięte odpowiedzi',
+  'chat.pinned.title': 'Przypięte ({count})',
+  'chat.pinned.summary': 'Podsumowanie rozmowy',
+  'chat.pinned.unpin': 'Odepnij',
+  'chat.transcript.title': 'Rozmowa z asystentem FoodSave',
+  'chat.transcript.format': 'Format rozmowy',
+  'chat.transcript.text': 'Tekst',
+  'chat.transcript.summarize': 'Podsumuj',
+  'chat.transcript.summarizeHint': 'Podsumuj rozmowę, np. przed jej archiwizacją',
+  'chat.transcript.copy': 'Kopiuj rozmowę',
+  'chat.transcript.copied': 'Skopiowano rozmowę do schowka',
+  'chat.transcript.copyFailed': 'Nie udało się skopiować rozmowy',
+  'chat.transcript.share': 'Udostępnij…',
+  'chat.transcript.sharing': 'Udostępnianie rozmowy',
+  'chat.context.button': 'Kontekst',
+  'chat.context.hint': 'Dołącz dane z aplikacji do pytania',
+  'chat.context.title': 'Dołącz kontekst',
+  'chat.context.attached': 'Dołączony kontekst',
+  'chat.context.remove': 'Usuń: {name}',
+  'chat.history.label': 'Zapisane rozmowy',
+  'chat.history.filter': 'Filtruj po tagu',
+  'chat.history.all': 'Wszystkie',
+  'chat.history.empty': 'Brak rozmów.',
+  'chat.history.emptyTag': 'Brak rozmów z tagiem „{tag}”.',
+  'chat.history.current': '(bieżąca)',
+  'chat.history.tags': 'Tagi rozmowy',
+  'chat.history.delete': 'Usuń rozmowę',
+  'chat.history.newTag': 'Nowy tag',
+
+  'quickAsk.title': 'Szybkie pytanie',
+  'quickAsk.placeholder': 'Zapytaj asystenta...',
+  'quickAsk.hint': 'Enter – wyślij, Esc – zamknij',
+  'quickAsk.openChat': 'Otwórz pełny czat',
+  'quickAsk.error': 'Nie udało się uzyskać odpowiedzi.',
+
+  'reminders.title': 'Przypomnienia',
+  'reminders.subtitle': 'Jednorazowe i cykliczne przypomnienia z powiadomieniami na pulpicie',
+  'reminders.new': 'Nowe przypomnienie',
+  'reminders.all': 'Wszystkie przypomnienia',
+  'reminders.empty': 'Nie masz jeszcze żadnych przypomnień.',
+  'reminders.deleted': 'Usunięto przypomnienie „{title}”',
+  'reminders.count': {
+    one: '{count} przypomnienie',
+    few: '{count} przypomnienia',
+    many: '{count} przypomnień',
+    other: '{count} przypomnienia',
+  },
+  'reminders.form.title': 'Treść',
+  'reminders.form.titlePlaceholder': 'np. Sprawdź datę ważności mleka',
+  'reminders.form.date': 'Data',
+  'reminders.form.time': 'Godzina',
+  'reminders.form.repeat': 'Powtarzanie',
+  'reminders.repeat.none': 'Jednorazowo',
+  'reminders.repeat.daily': 'Codziennie',
+  'reminders.repeat.weekly': 'Co tydzień',
+  'reminders.repeat.monthly': 'Co miesiąc',
+
+  'routines.title': 'Rutyny asystenta',
+  'routines.subtitle': 'Pytania wysyłane do asystenta według harmonogramu; odpowiedź trafia do zapisanych rozmów i powiadomień',
+  'routines.empty': 'Nie masz jeszcze żadnych rutyn.',
+  'routines.enabled': 'Włączona',
+  'routines.next': 'następnie {date}',
+  'routines.runNow': 'Uruchom teraz',
+  'routines.lastResult': 'Pokaż ostatni wynik',
+  'routines.form.name': 'Nazwa',
+  'routines.form.namePlaceholder': 'np. Plan posiłków na tydzień',
+  'routines.form.prompt': 'Pytanie do asystenta',
+  'routines.form.promptPlaceholder': 'np. Zaplanuj posiłki na cały tydzień z produktów w mojej spiżarni',
+  'routines.form.time': 'Godzina',
+  'routines.form.days': 'Dni tygodnia',
+  'routines.form.attachments': 'Dołącz dane',
+
+  'shopping.errorProducts': 'Produkty',
+  'shopping.errorProcessing': 'Przetwarzanie paragonu',
+  'shopping.errorSave': 'Zapis produktów',
+  'shopping.taskOcr': 'Odczytywanie paragonu',
+  'shopping.taskAnalyze': 'Analiza paragonu',
+  'shopping.taskSave': 'Zapisywanie paragonu',
+  'shopping.activityScanned': 'Zeskanowano paragon',
+  'shopping.activityScannedAt': 'Zeskanowano paragon – {store}',
+  'shopping.activitySaved': 'Zapisano paragon: {store} ({count} produktów)',
+  'shopping.processedTitle': 'Paragon przetworzony',
+  'shopping.processedBody': 'Rozpoznano {count} pozycji – sprawdź je przed zapisem.',
+  'shopping.unknownStore': 'Nieznany sklep',
+  'shopping.checkedOff': 'Odhaczono na liście zakupów: {items}',
+  'shopping.exportCsv': 'Eksport CSV',
+  'shopping.exportJson': 'Eksport JSON',
+  'shopping.stepOcr': 'Wykonywanie OCR...',
+  'shopping.stepAnalyze': 'Analiza paragonu...',
+  'shopping.stepSaving': 'Zapisywanie produktów...',
+  'shopping.stepDone': 'Produkty zostały zapisane!',
+  'shopping.productsTitle': 'Lista produktów',
+  'shopping.historyTitle': 'Historia paragonów',
+  'shopping.exportTitle': 'Eksport paragonów',
+  'shopping.assistantTitle': 'Asystent zakupowy',
+  'shopping.assistantPlaceholder': 'Zapytaj o produkty, promocje, lub poproś o listę zakupów...',
+  'shopping.column.name': 'Nazwa',
+  'shopping.column.product': 'Produkt',
+  'shopping.column.quantity': 'Ilość',
+  'shopping.column.unit': 'Jednostka',
+  'shopping.column.price': 'Cena',
+  'shopping.column.category': 'Kategoria',
+  'shopping.column.expiry': 'Data ważności',
+  'shopping.column.actions': 'Akcje',
+  'shopping.productsEmpty': 'Brak produktów do wyświetlenia',
+  'shopping.renamePrompt': 'Podaj nową nazwę produktu:',
+  'shopping.meta.store': 'Sklep',
+  'shopping.meta.storePlaceholder': 'np. Biedronka',
+  'shopping.meta.date': 'Data zakupów',
+  'shopping.list.title': 'Lista zakupów',
+  'shopping.list.addPlaceholder': 'Dodaj produkt...',
+  'shopping.list.name': 'Nazwa produktu',
+  'shopping.list.add': 'Dodaj do listy',
+  'shopping.list.empty': 'Lista zakupów jest pusta.',
+  'shopping.list.bought': 'Kupione: {name}',
+  'shopping.list.remove': 'Usuń {name}',
+  'shopping.list.clearChecked': 'Usuń kupione ({count})',
+  'shopping.list.print': 'Drukuj',
+  'shopping.list.sync': 'Synchronizuj z {provider}',
+  'shopping.qr.button': 'Kod QR',
+  'shopping.qr.title': 'Lista zakupów jako kod QR',
+  'shopping.qr.label': 'Kod QR z listą zakupów ({count} pozycji)',
+  'shopping.qr.hint': 'Zeskanuj aparatem telefonu – lista otworzy się jako tekst.',
+  'shopping.qr.tooLong': 'Lista jest za długa na jeden kod QR. Usuń kupione pozycje albo podziel listę.',
+  'shopping.export.empty': 'Brak paragonów w wybranym okresie.',
+  'shopping.export.error': 'Błąd podczas eksportu paragonów',
+  'shopping.export.from': 'Od',
+  'shopping.export.to': 'Do',
+  'shopping.upload.title': 'Wgraj paragon',
+  'shopping.upload.manual': 'Wpisz paragon ręcznie',
+  'shopping.upload.selected': 'Wybrany plik: {name}',
+  'shopping.upload.zoom': 'Powiększ podgląd paragonu',
+  'shopping.upload.previewAlt': 'Podgląd',
+  'shopping.upload.noPreview': 'Podgląd niedostępny dla plików PDF',
+  'shopping.upload.imageAlt': 'Paragon',
+  'shopping.upload.processing': 'Przetwarzanie...',
+  'shopping.upload.submit': 'Zatwierdź i prześlij',
+  'shopping.receipt.title': 'Dane z paragonu',
+  'shopping.receipt.hint': 'Możesz edytować dane przed zapisaniem',
+  'shopping.receipt.pricePerKg': 'Cena za kilogram',
+  'shopping.receipt.byWeight': 'Produkt na wagę – ilość to waga w kilogramach',
+  'shopping.receipt.estimated': 'Szacowana',
+  'shopping.receipt.estimatedHint': 'Na podstawie typowej trwałości produktów z tej kategorii',
+  'shopping.receipt.splitLabel': 'Podziel pozycję {name}',
+  'shopping.receipt.splitTitle': 'Podziel na dwa produkty',
+  'shopping.receipt.removeLabel': 'Usuń pozycję {name}',
+  'shopping.receipt.removeTitle': 'Usuń pozycję',
+  'shopping.receipt.mergeLabel': 'Połącz {name} z następną pozycją',
+  'shopping.receipt.mergeTitle': 'Połącz z następną pozycją',
+  'shopping.receipt.addRow': 'Dodaj pozycję',
+  'shopping.receipt.total': 'Suma pozycji:',
+  'shopping.receipt.save': 'Zapisz produkty',
+  'shopping.split.title': 'Podziel pozycję',
+  'shopping.split.hint': '„{name}” to dwa produkty w jednej linii? Podaj nazwy i cenę pierwszego – drugi dostanie resztę z {total}.',
+  'shopping.split.firstName': 'Nazwa pierwszego produktu',
+  'shopping.split.firstPrice': 'Cena pierwszego produktu',
+  'shopping.split.secondName': 'Nazwa drugiego produktu',
+  'shopping.split.submit': 'Podziel',
+  'shopping.history.error': 'Nie udało się pobrać historii paragonów.',
+  'shopping.history.empty': 'Brak zapisanych paragonów.',
+  'shopping.history.notFound': 'Nie znaleziono paragonu z linku – mógł zostać usunięty.',
+  'shopping.history.printTitle': 'Paragon',
+  'shopping.history.print': 'Drukuj paragon',
+  'shopping.history.reprocess': 'Przetwórz ponownie',
+  'shopping.reprocess.error': 'Ponowne przetwarzanie paragonu',
+  'shopping.reprocess.title': 'Przetwórz ponownie: {store}, {date}',
+  'shopping.reprocess.language': 'Język paragonu',
+  'shopping.reprocess.preprocess': 'Poprawa kontrastu i ostrości',
+  'shopping.reprocess.run': 'Przetwórz',
+  'shopping.reprocess.runAgain': 'Przetwórz jeszcze raz',
+  'shopping.reprocess.same': 'Wynik taki sam jak zapisany paragon.',
+  'shopping.reprocess.changes': 'Różnice względem zapisanego paragonu: {count}.',
+  'shopping.reprocess.total': 'Suma:',
+  'shopping.reprocess.before': 'Zapisany paragon',
+  'shopping.reprocess.after': 'Nowy odczyt',
+  'shopping.sync.title': 'Pozycja zmieniona w dwóch miejscach',
+  'shopping.sync.body': 'Pozycję „{name}” zmieniono w {provider} po wysłaniu listy. Wybierz, którą wersję zachować.',
+  'shopping.sync.remaining': 'Pozostało konfliktów: {count}.',
+  'shopping.sync.mine': 'Na tym urządzeniu',
+  'shopping.sync.theirs': 'W {provider}',
+  'shopping.sync.back': 'Wróć',
+  'shopping.sync.saveMerged': 'Zapisz scaloną',
+  'shopping.sync.merge': 'Scal',
+  'shopping.sync.keepTheirs': 'Zachowaj z {provider}',
+  'shopping.sync.keepMine': 'Zachowaj moją',
+
+  'cooking.pantryTitle': 'Moja Spiżarnia',
+  'cooking.assistantTitle': 'Asystent Gotowania',
+  'cooking.recipesTitle': 'Przepisy',
+  'cooking.optional': '(opcjonalnie)',
+  'cooking.chat.title': 'Asystent Kulinarny',
+  'cooking.chat.welcome': 'Witaj w asystencie kulinarnym! Mogę pomóc Ci znaleźć przepisy na podstawie produktów w Twojej spiżarni lub doradzić, co można ugotować.',
+  'cooking.chat.failed': 'Przepraszam, wystąpił błąd w przetwarzaniu.',
+  'cooking.activity.added': 'Dodano do spiżarni: {name}',
+  'cooking.activity.deleted': 'Usunięto produkt ze spiżarni',
+  'cooking.activity.updated': 'Zaktualizowano w spiżarni: {name}',
+  'cooking.activity.product': 'produkt',
+  'cooking.activity.merged': 'Scalono w spiżarni: {merged} → {kept}',
+  'cooking.activity.chat': 'Czat kulinarny: {text}',
+  'cooking.stapleRanOut': 'Skończył się produkt podstawowy - „{name}” dodano do listy zakupów',
+  'cooking.view.label': 'Widok spiżarni',
+  'cooking.view.cards': 'Karty',
+  'cooking.view.grid': 'Siatka',
+  'cooking.view.table': 'Tabela',
+  'cooking.view.timeline': 'Oś czasu',
+  'cooking.expired': 'Po terminie',
+  'cooking.validUntil': 'Ważne',
+  'cooking.staple.label': 'Produkt podstawowy',
+  'cooking.staple.toggle': 'Produkt podstawowy: {name}',
+  'cooking.staple.unmark': 'Produkt podstawowy - kliknij, aby odznaczyć',
+  'cooking.staple.mark': 'Oznacz jako produkt podstawowy',
+  'cooking.restocked': 'Dodano do listy zakupów: {items}',
+  'cooking.restockNothing': 'Wszystkie brakujące produkty podstawowe są już na liście',
+  'cooking.restock': 'Uzupełnij podstawowe ({count})',
+  'cooking.pantryEmpty': 'Twoja spiżarnia jest pusta',
+  'cooking.noMatches': 'Brak produktów pasujących do „{query}”',
+  'cooking.addPlaceholder': 'Dodaj nowy produkt...',
+  'cooking.searchPlaceholder': 'Szukaj w spiżarni... (np. cat:nabiał exp<7d qty=0)',
+  'cooking.searchLabel': 'Szukaj w spiżarni',
+  'cooking.searchHint': 'Operatory: cat:kategoria, exp<7d (dni do końca ważności), qty=0, attr:atrybut, is:staple',
+  'cooking.remove': 'Usuń {name}',
+  'cooking.column.expiry': 'Ważność',
+  'cooking.timeline.empty': 'Żaden produkt nie ma zapisanej daty ważności',
+  'cooking.timeline.label': 'Oś czasu dat ważności',
+  'cooking.timeline.today': 'Dzisiaj',
+  'cooking.timeline.tomorrow': 'Jutro',
+  'cooking.timeline.later': 'Ważne dłużej niż 2 tygodnie: {count}',
+  'cooking.recipe.ingredients': 'Składniki: {count}',
+  'cooking.recipe.cooked': 'Ugotowane',
+  'cooking.deduct.title': 'Ugotowano: {name}',
+  'cooking.deduct.hint': 'Sprawdź, ile odjąć ze spiżarni. Odznacz składniki, których nie używasz.',
+  'cooking.deduct.include': 'Odejmij',
+  'cooking.deduct.includeItem': 'Odejmij {name}',
+  'cooking.deduct.ingredient': 'Składnik',
+  'cooking.deduct.product': 'Produkt w spiżarni',
+  'cooking.deduct.remaining': 'Zostanie',
+  'cooking.deduct.missing': 'brak w spiżarni',
+  'cooking.deduct.amount': 'Ilość do odjęcia: {name}',
+  'cooking.deduct.unitMismatch': 'Jednostki przepisu i produktu się różnią',
+  'cooking.deduct.manual': 'wpisz ręcznie',
+  'cooking.deduct.error': 'Nie udało się zapisać odjęcia: {reason}. Zapisane już produkty nie zostaną odjęte ponownie.',
+  'cooking.deduct.submit': 'Odejmij ze spiżarni ({count})',
+  'cooking.unknownError': 'nieznany błąd',
+  'cooking.details.noQuantity': 'bez ilości',
+  'cooking.details.mergeError': 'Nie udało się scalić: {reason}',
+  'cooking.details.saveError': 'Nie udało się zapisać: {reason}',
+  'cooking.details.mergeTitle': 'Scal z duplikatem',
+  'cooking.details.mergeLabel': 'Produkt do scalenia',
+  'cooking.details.choose': 'Wybierz produkt…',
+  'cooking.details.mergePreview': '„{merged}” zostanie usunięty, a „{kept}” będzie mieć: {quantity}',
+  'cooking.details.mergeExpiry': ', ważne do {date}',
+  'cooking.details.merge': 'Scal',
+  'cooking.details.notes': 'Notatki',
+  'cooking.details.notesPlaceholder': 'np. na imprezę w sobotę',
+  'cooking.details.attributes': 'Atrybuty',
+  'cooking.details.attributeName': 'Nazwa atrybutu',
+  'cooking.details.attributeNamePlaceholder': 'Nazwa, np. dieta',
+  'cooking.details.attributeValue': 'Wartość atrybutu',
+  'cooking.details.attributeValuePlaceholder': 'Wartość (opcjonalnie)',
+  'cooking.details.removeAttribute': 'Usuń atrybut',
+  'cooking.details.addAttribute': 'Dodaj atrybut',
+  'cooking.details.mergedTitle': 'Scalone produkty',
+  'cooking.details.bought': 'kupione {date}',
+  'cooking.leftovers.title': 'Resztki',
+  'cooking.leftovers.add': 'Dodaj resztki',
+  'cooking.leftovers.name': 'Co zostało',
+  'cooking.leftovers.namePlaceholder': 'np. gulasz z obiadu',
+  'cooking.leftovers.cookedOn': 'Ugotowane',
+  'cooking.leftovers.eatBy': 'Zjeść do',
+  'cooking.leftovers.empty': 'Brak resztek w lodówce.',
+  'cooking.leftovers.eaten': 'Zjedzone',
+  'cooking.leftovers.removed': 'Usunięto resztki: {name}',
+
+  'analytics.title': 'Analityka wydatków',
+  'analytics.subtitle': 'Podsumowanie zakupów na podstawie zapisanych paragonów',
+  'analytics.exportCsv': 'Eksportuj CSV',
+  'analytics.error': 'Nie udało się pobrać statystyk: {error}',
+  'analytics.total': 'Suma wydatków',
+  'analytics.receiptsCount': 'Liczba paragonów',
+  'analytics.monthly': 'Wydatki miesięczne',
+  'analytics.byCategory': 'Wydatki według kategorii',
+  'analytics.byStore': 'Porównanie sklepów',
+  'analytics.noData': 'Brak danych w wybranym okresie.',
+  'analytics.receipts': {
+    one: '{count} paragon',
+    few: '{count} paragony',
+    many: '{count} paragonów',
+    other: '{count} paragonu',
+  },
+  'analytics.csv.section': 'sekcja',
+  'analytics.csv.label': 'etykieta',
+  'analytics.csv.amount': 'kwota',
+  'analytics.csv.count': 'liczba',
+  'analytics.csv.month': 'miesiąc',
+  'analytics.csv.category': 'kategoria',
+  'analytics.csv.store': 'sklep',
+  'analytics.range.month': 'Bieżący miesiąc',
+  'analytics.range.3months': '3 miesiące',
+  'analytics.range.12months': '12 miesięcy',
+  'analytics.range.custom': 'Własny zakres',
+  'analytics.range.from': 'Od',
+  'analytics.range.to': 'Do',
+  'report.title': 'Raport miesięczny: {month}',
+  'report.previous': 'Poprzedni miesiąc',
+  'report.next': 'Następny miesiąc',
+  'report.error': 'Nie udało się przygotować raportu: {error}',
+  'report.empty': 'Brak paragonów z tego miesiąca.',
+  'report.spent': 'wydatki',
+  'report.versus': 'wobec {amount}',
+  'report.receipts': 'paragony',
+  'report.wasted': 'zmarnowane ({amount})',
+  'report.category': 'Kategoria',
+  'report.change': 'Zmiana',
+  'report.summary.spent': 'Wydano {amount}',
+  'report.summary.change': ' ({change} wobec poprzedniego miesiąca)',
+  'report.summary.top': 'Najwięcej: {categories}',
+  'report.summary.wasted': 'Zmarnowane produkty: {count} ({amount})',
+  'report.notificationTitle': 'FoodSave – podsumowanie: {month}',
+  'budget.title': 'Budżet na ten miesiąc',
+  'budget.refresh': 'Odśwież budżet',
+  'budget.edit': 'Edytuj budżet',
+  'budget.empty': 'Nie ustawiono budżetu. Kliknij ołówek, aby dodać limit.',
+  'budget.progressLabel': 'Budżet {label}',
+  'budget.monthly': 'Miesięczny budżet (zł)',
+  'budget.categoryLimits': 'Limity kategorii',
+  'budget.category': 'Kategoria',
+  'budget.limit': 'Limit',
+  'budget.removeLimit': 'Usuń limit',
+  'budget.addCategory': 'Dodaj kategorię',
+  'budget.total': 'Łącznie',
+  'budget.exceeded': 'Budżet „{label}” przekroczony ({percent}%)',
+  'budget.warning': 'Budżet „{label}” wykorzystany w {percent}%',
+  'budget.notificationTitle': 'FoodSave – budżet',
+  'budget.widget.title': 'Wydatki w tym miesiącu',
+  'budget.widget.refresh': 'Odśwież wydatki',
+  'budget.widget.notSet': 'Nie ustawiono budżetu.',
+  'budget.widget.set': 'Ustaw budżet',
+  'budget.widget.of': 'z {amount}',
+  'budget.widget.usage': 'Wykorzystanie budżetu',
+  'budget.widget.over': 'ponad budżet',
+  'budget.widget.left': 'pozostało',
+  'budget.widget.daysLeft': 'dni do końca',
+  'budget.widget.daily': 'dziennie',
+
+  'dashboard.greeting.night': 'Dobranoc',
+  'dashboard.greeting.morning': 'Dzień dobry',
+  'dashboard.greeting.afternoon': 'Miłego popołudnia',
+  'dashboard.greeting.evening': 'Dobry wieczór',
+  'dashboard.summary.nothingExpiring': 'nic nie traci ważności w tym tygodniu',
+  'dashboard.summary.expiring': {
+    one: '{count} produkt traci ważność w tym tygodniu',
+    few: '{count} produkty tracą ważność w tym tygodniu',
+    many: '{count} produktów traci ważność w tym tygodniu',
+    other: '{count} produktu traci ważność w tym tygodniu',
+  },
+  'dashboard.summary.rain': 'dziś może padać ({percent}%)',
+  'dashboard.summary.rainFrom': 'dziś od {time} może padać ({percent}%)',
+  'dashboard.summary.maxTemp': 'dziś do {temp}°C',
+  'dashboard.name': 'Twoje imię',
+  'dashboard.changeName': 'Zmień imię',
+  'dashboard.autoRefresh': 'Auto-odświeżanie',
+  'dashboard.refreshAll': 'Odśwież wszystko',
+  'dashboard.refreshOff': 'Wyłączone',
+  'dashboard.refreshEvery': 'co {interval}',
+  'dashboard.refresh': 'Odśwież',
+  'dashboard.widget.weather': 'Pogoda',
+  'dashboard.widget.budget': 'Budżet',
+  'dashboard.widget.receipts': 'Ostatnie paragony',
+  'dashboard.quick.title': 'Szybkie akcje',
+  'dashboard.quick.addLabel': 'Szybko dodaj do spiżarni',
+  'dashboard.quick.addPlaceholder': 'np. mleko',
+  'dashboard.quick.add': 'Dodaj do spiżarni',
+  'dashboard.quick.added': 'Dodano: {name}',
+  'dashboard.quick.addError': 'Nie udało się dodać produktu',
+  'dashboard.quick.noteLabel': 'Szybka notatka do asystenta',
+  'dashboard.quick.notePlaceholder': 'np. przypomnij mi o zakupach na weekend',
+  'dashboard.quick.send': 'Wyślij notatkę',
+  'dashboard.quick.noted': 'Asystent przyjął notatkę.',
+  'dashboard.quick.sendError': 'Nie udało się wysłać notatki',
+  'dashboard.quick.continue': 'Kontynuuj w czacie',
+  'dashboard.quick.activity': 'Notatka: {text}',
+  'dashboard.activities.title': 'Ostatnie aktywności',
+  'dashboard.activities.empty': 'Brak aktywności. Zeskanuj paragon lub porozmawiaj z asystentem.',
+  'dashboard.receipts.refresh': 'Odśwież paragony',
+  'dashboard.receipts.error': 'Nie udało się pobrać paragonów.',
+  'dashboard.receipts.empty': 'Nie zeskanowano jeszcze żadnego paragonu.',
+  'dashboard.list.all': 'Cała lista',
+  'dashboard.list.done': 'Wszystko kupione.',
+  'dashboard.list.more': 'i {count} więcej...',
+  'dashboard.today.title': 'Dzisiaj',
+  'dashboard.today.all': 'Wszystkie',
+  'dashboard.today.calendar': 'Kalendarz ICS',
+  'dashboard.today.calendarUrl': 'Adres kalendarza ICS (opcjonalnie)',
+  'dashboard.today.empty': 'Brak przypomnień na dziś.',
+  'dashboard.today.allDay': 'cały dzień',
+  'dashboard.today.remove': 'Usuń przypomnienie',
+  'dashboard.today.calendarError': 'Nie udało się pobrać kalendarza.',
+  'dashboard.today.placeholder': 'Nowe przypomnienie...',
+  'dashboard.report.title': 'Podsumowanie: {month}',
+  'dashboard.report.refresh': 'Odśwież podsumowanie',
+  'dashboard.report.change': 'Zmiana wobec poprzedniego miesiąca',
+  'dashboard.report.full': 'Pełny raport',
+  'dashboard.llm.loading': 'Ładowanie ustawień modeli...',
+  'dashboard.llm.errorTitle': 'Błąd ładowania ustawień',
+  'dashboard.llm.errorBody': 'Nie udało się załadować ustawień modeli LLM.',
+  'dashboard.llm.title': 'Ustawienia Modelu LLM',
+  'dashboard.llm.current': 'Aktualny model:',
+  'dashboard.llm.loadingShort': 'Ładowanie...',
+  'dashboard.llm.none': 'Nie wybrano modelu',
+  'dashboard.llm.choose': 'Wybierz model:',
+  'dashboard.llm.details': 'Rozmiar: {size} | Zmodyfikowano: {modified}',
+  'dashboard.llm.saving': 'Zapisywanie...',
+  'dashboard.llm.save': 'Zapisz model',
+  'dashboard.llm.saveError': 'Błąd podczas zapisywania modelu: {error}',
+  'weather.title': 'Prognoza pogody',
+  'weather.refresh': 'Odśwież pogodę',
+  'weather.loading': 'Ładowanie prognozy...',
+  'weather.error': 'Nie udało się pobrać danych pogodowych.',
+  'weather.stale': 'Nie udało się odświeżyć pogody - wyświetlane są poprzednie dane.',
+  'weather.retrying': 'Ponowna próba wkrótce...',
+  'weather.empty': 'Brak danych pogodowych',
+  'weather.forecastFor': 'Prognoza na {date}',
+  'weather.noDescription': 'Brak opisu',
+  'weather.temperature': 'Temperatura: {min} – {max}C',
+  'weather.rainChance': 'Szansa opadów: {chance}',
+  'weather.noData': 'brak danych',
+  'weather.alertTitle': 'Ostrzeżenie pogodowe – {location}',
+  'weather.alertUntil': 'Do: {date}',
+  'weather.location': 'Lokalizacja pogody',
+  'weather.manage': 'Zarządzaj lokalizacjami',
+  'weather.remove': 'Usuń {label}',
+  'weather.labelPlaceholder': 'Nazwa (np. Rodzice)',
+  'weather.cityPlaceholder': 'Miasto',
+  'weather.add': 'Dodaj lokalizację',
+
+  'rag.title': 'Baza wiedzy RAG',
+  'rag.subtitle': 'Dodaj dokumenty, aby asystent odpowiadał na podstawie Twojej wiedzy',
+  'rag.subtitleDirectory': 'Dokumenty w: {directory}',
+  'rag.upload.title': 'Dodaj dokumenty',
+  'rag.upload.drop': 'Upuść pliki tutaj...',
+  'rag.upload.prompt': 'Przeciągnij i upuść pliki lub kliknij, aby wybrać',
+  'rag.upload.supported': 'Obsługiwane: PDF, TXT, DOCX, MD, RTF',
+  'rag.upload.description': 'Opis (opcjonalnie)',
+  'rag.upload.descriptionPlaceholder': 'Krótki opis dokumentu',
+  'rag.upload.tags': 'Tagi (oddzielone przecinkami)',
+  'rag.upload.tagsPlaceholder': 'tag1, tag2, tag3',
+  'rag.upload.uploading': 'Wysyłanie dokumentów...',
+  'rag.query.title': 'Zapytaj bazę wiedzy',
+  'rag.query.searchingIn': 'Szukanie w: {directory}',
+  'rag.query.placeholder': 'Zadaj pytanie o swoje dokumenty...',
+  'rag.query.placeholderDirectory': 'Zadaj pytanie o dokumenty w {directory}...',
+  'rag.query.submit': 'Zapytaj',
+  'rag.query.answer': 'Odpowiedź:',
+  'rag.query.noAnswer': 'Nie znaleziono odpowiedzi',
+  'rag.query.error': 'Błąd zapytania do systemu RAG',
+  'rag.query.sources': 'Źródła ({count}):',
+  'rag.query.unknownDocument': 'Nieznany dokument',
+  'rag.query.noContent': 'Brak treści',
+  'rag.query.relevance': 'Trafność: {percent}%',
+  'rag.documents.title': 'Dodane dokumenty ({count})',
+  'rag.documents.selectAll': 'Zaznacz wszystkie',
+  'rag.documents.deselectAll': 'Odznacz wszystkie',
+  'rag.documents.selected': '(zaznaczono: {count})',
+  'rag.documents.moveSelected': 'Przenieś zaznaczone',
+  'rag.documents.deleteSelected': 'Usuń zaznaczone',
+  'rag.documents.empty': 'Nie dodano jeszcze dokumentów',
+  'rag.documents.emptyHint': 'Dodaj pierwszy dokument, aby zacząć',
+  'rag.documents.chunks': {
+    one: '{count} fragment',
+    few: '{count} fragmenty',
+    many: '{count} fragmentów',
+    other: '{count} fragmenty',
+  },
+  'rag.documents.count': {
+    one: '{count} dokument',
+    few: '{count} dokumenty',
+    many: '{count} dokumentów',
+    other: '{count} dokumenty',
+  },
+  'rag.documents.move': 'Przenieś dokument',
+  'rag.documents.delete': 'Usuń dokument',
+  'rag.move.title': 'Przenieś dokument',
+  'rag.move.moving': 'Przenoszony: ',
+  'rag.move.from': 'Z: ',
+  'rag.move.defaultDirectory': 'domyślny',
+  'rag.move.to': 'Do katalogu',
+  'rag.move.placeholder': 'Podaj nazwę katalogu',
+  'rag.move.submit': 'Przenieś',
+  'rag.move.submitting': 'Przenoszenie...',
+  'rag.move.noTarget': 'Wybierz katalog docelowy',
+  'rag.move.failed': 'Nie udało się przenieść dokumentu',
+  'rag.bulk.moveTitle': 'Przenieś zaznaczone dokumenty',
+  'rag.bulk.moving': 'Przenoszone: ',
+  'rag.bulk.deleteTitle': 'Usuń zaznaczone dokumenty',
+  'rag.bulk.deleteConfirm': 'Czy na pewno usunąć ',
+  'rag.bulk.irreversible': 'Tej operacji nie można cofnąć.',
+  'rag.bulk.deleting': 'Usuwanie...',
+  'rag.bulk.noTarget': 'Zaznacz dokumenty i podaj katalog docelowy',
+  'rag.bulk.noSelection': 'Zaznacz dokumenty do usunięcia',
+  'rag.bulk.moveFailed': 'Nie udało się przenieść dokumentów',
+  'rag.bulk.deleteFailed': 'Nie udało się usunąć dokumentów',
+  'rag.unknownError': 'Nieznany błąd',
+  'rag.dirs.title': 'Katalogi RAG',
+  'rag.dirs.loading': 'Wczytywanie katalogów...',
+  'rag.dirs.error': 'Błąd: {error}',
+  'rag.dirs.loadFailed': 'Nie udało się pobrać katalogów',
+  'rag.dirs.back': '← Wszystkie katalogi',
+  'rag.dirs.create': '+ Nowy katalog',
+  'rag.dirs.empty': 'Brak katalogów.',
+  'rag.dirs.stats': 'Statystyki',
+  'rag.dirs.rename': 'Zmień nazwę katalogu',
+  'rag.dirs.delete': 'Usuń katalog',
+  'rag.dirs.createTitle': 'Nowy katalog',
+  'rag.dirs.name': 'Nazwa katalogu',
+  'rag.dirs.namePlaceholder': 'np. przepisy, poradniki, praca',
+  'rag.dirs.nameEmpty': 'Nazwa katalogu nie może być pusta',
+  'rag.dirs.createSubmit': 'Utwórz',
+  'rag.dirs.creating': 'Tworzenie...',
+  'rag.dirs.createFailed': 'Nie udało się utworzyć katalogu',
+  'rag.dirs.deleteTitle': 'Usuń katalog',
+  'rag.dirs.deleteConfirm': 'Czy na pewno usunąć katalog ',
+  'rag.dirs.deleteHint': 'Wszystkie dokumenty z tego katalogu trafią do katalogu domyślnego.',
+  'rag.dirs.deleteFailed': 'Nie udało się usunąć katalogu',
+  'rag.dirs.renameTitle': 'Zmień nazwę katalogu',
+  'rag.dirs.renaming': 'Zmieniana nazwa: ',
+  'rag.dirs.newName': 'Nowa nazwa katalogu',
+  'rag.dirs.newNamePlaceholder': 'Podaj nową nazwę katalogu',
+  'rag.dirs.newNameEmpty': 'Podaj nową nazwę katalogu',
+  'rag.dirs.renameSubmit': 'Zmień nazwę',
+  'rag.dirs.renameSubmitting': 'Zmienianie...',
+  'rag.dirs.renameFailed': 'Nie udało się zmienić nazwy katalogu',
+  'rag.dirs.statsTitle': 'Statystyki katalogu',
+  'rag.dirs.statsLoading': 'Wczytywanie statystyk...',
+  'rag.dirs.statsFailed': 'Nie udało się pobrać statystyk katalogu',
+  'rag.dirs.documents': 'Dokumenty',
+  'rag.dirs.chunks': 'Fragmenty',
+  'rag.dirs.totalTags': 'Tagi:',
+  'rag.dirs.recentActivity': 'Ostatnia aktywność:',
+  'rag.dirs.avgChunks': 'Średnio fragmentów na dokument:',
+  'rag.dirs.fileTypes': 'Typy plików:',
+
+  'rag.manager.uploaded': 'Dokument został dodany',
+  'rag.manager.uploadFailed': 'Nie udało się dodać dokumentu',
+  'rag.manager.statsTitle': 'Statystyki systemu RAG',
+  'rag.manager.loading': 'Wczytywanie...',
+  'rag.manager.refresh': 'Odśwież',
+  'rag.manager.vectorStore': 'Baza wektorowa',
+  'rag.manager.totalChunks': 'Fragmenty: {count}',
+  'rag.manager.totalDocuments': 'Dokumenty: {count}',
+  'rag.manager.processor': 'Przetwarzanie',
+  'rag.manager.chunkSize': 'Rozmiar fragmentu: {value}',
+  'rag.manager.chunkOverlap': 'Nakładanie fragmentów: {value}',
+  'rag.manager.localEmbeddings': 'Lokalne embeddingi: {value}',
+  'rag.manager.pinecone': 'Pinecone: {value}',
+  'rag.manager.yes': 'Tak',
+  'rag.manager.no': 'Nie',
+  'rag.manager.statsHint': 'Kliknij „Odśwież”, aby wczytać statystyki',
+  'rag.manager.uploadTitle': 'Dodaj dokument',
+  'rag.manager.selectFile': 'Wybierz plik',
+  'rag.manager.category': 'Kategoria (opcjonalnie)',
+  'rag.manager.categoryPlaceholder': 'np. przepisy, poradniki',
+  'rag.manager.tags': 'Tagi (opcjonalnie)',
+  'rag.manager.tagsPlaceholder': 'np. gotowanie, zdrowe, szybkie',
+  'rag.manager.uploading': 'Wysyłanie...',
+  'rag.manager.syncTitle': 'Synchronizacja bazy danych',
+  'rag.manager.syncReceipts': 'Synchronizuj paragony',
+  'rag.manager.syncPantry': 'Synchronizuj spiżarnię',
+  'rag.manager.syncConversations': 'Synchronizuj rozmowy',
+  'rag.manager.syncAll': 'Synchronizuj wszystko',
+  'rag.manager.syncResults': 'Wyniki synchronizacji:',
+  'rag.manager.syncReceiptsResult': 'Paragony: zakupy {trips}, fragmenty {chunks}',
+  'rag.manager.syncPantryResult': 'Spiżarnia: produkty {products}, fragmenty {chunks}',
+  'rag.manager.syncConversationsResult': 'Rozmowy: {conversations}, fragmenty {chunks}',
+  'rag.manager.searchTitle': 'Szukaj w dokumentach',
+  'rag.manager.searchPlaceholder': 'Wpisz zapytanie...',
+  'rag.manager.searching': 'Szukanie...',
+  'rag.manager.search': 'Szukaj',
+  'rag.manager.results': 'Wyniki wyszukiwania ({count})',
+  'rag.manager.similarity': 'Podobieństwo: {percent}%',
+  'rag.manager.source': 'Źródło: {source}',
+  'rag.manager.type': 'Typ: {type}',
+  'rag.manager.categoryValue': 'Kategoria: {category}',
+  'rag.manager.date': 'Data: {date}',
+
+  'notifications.reminder': 'Przypomnienie',
+  'notifications.expiringTitle': 'FoodSave – kończące się produkty',
+  'notifications.expiringBody': 'Produkty z kończącą się datą ważności: {count}',
+  'notifications.leftoversTitle': 'FoodSave – resztki',
+  'notifications.leftover.soon': '„{name}” trzeba zjeść najpóźniej jutro',
+  'notifications.leftover.today': '„{name}” trzeba zjeść dzisiaj',
+  'notifications.leftover.overdue': '„{name}” jest po terminie – sprawdź, zanim zjesz',
+  'notifications.routineTask': 'Rutyna: {name}',
+
+  'common.confirm': 'Potwierdź',
+  'common.loading': 'Ładowanie',
+  'common.loadingData': 'Ładowanie danych',
+  'common.unknownError': 'Wystąpił nieznany błąd',
+  'common.unsavedChanges': 'Masz niezapisane zmiany. Opuścić stronę bez zapisywania?',
+  'nav.backup': 'Kopie zapasowe',
+  'network.offline': 'Brak połączenia',
+  'network.unreachable': 'Serwer FoodSave nie odpowiada',
+  'network.restored': 'Połączenie przywrócone – synchronizuję dane.',
+  'network.showingSaved': '{status} – pokazuję dane zapisane {savedAt}.',
+  'network.savedEarlier': 'wcześniej',
+  'recovery.receipt': 'niezapisany paragon',
+  'recovery.message': 'niewysłaną wiadomość',
+  'recovery.prompt': 'Poprzednia sesja nie została poprawnie zamknięta. Przywrócić {what}?',
+  'recovery.restore': 'Przywróć',
+  'tabs.openElsewhere': 'FoodSave jest już otwarty w innej karcie – to ona wysyła powiadomienia o przypomnieniach.',
+  'queries.products': 'Produkty',
+  'queries.trips': 'Paragony',
+  'queries.spendingStats': 'Statystyki wydatków',
+  'queries.weather': 'Pogoda',
+  'queries.calendar': 'Kalendarz',
+  'lazy.failed': 'Nie udało się wczytać komponentu',
+  'errorBoundary.title': 'Coś poszło nie tak',
+  'errorBoundary.body': 'Przepraszamy, wystąpił nieoczekiwany błąd. Odśwież stronę, a jeśli problem się powtarza, zgłoś go.',
+  'errorBoundary.details': 'Szczegóły błędu (tryb deweloperski)',
+  'errorBoundary.retry': 'Spróbuj ponownie',
+  'errorBoundary.report': 'Zgłoś błąd',
+  'errorBoundary.reported': 'Błąd został zgłoszony. Dziękujemy!',
+  'chat.welcome': 'Cześć! Jestem Twoim asystentem FoodSave. W czym mogę dziś pomóc?',
+  'chat.replyFailed': 'Przepraszam, wystąpił błąd w przetwarzaniu.',
+  'chat.errorReply': 'Wystąpił błąd: {error}',
+  'chat.errorContext': 'Asystent',
+  'chat.taskReply': 'Asystent odpowiada',
+  'chat.announceReply': 'Asystent: {text}',
+  'chat.notificationTitle': 'FoodSave – odpowiedź asystenta',
+  'chat.status.sending': 'Wysyłanie…',
+  'chat.status.delivered': 'Dostarczono',
+  'chat.status.failed': 'Nie udało się wysłać',
+  'chat.message.clarify': 'Doprecyzuj pytanie',
+  'chat.message.didYouMean': 'Czy chodziło o:',
+  'chat.message.pin': 'Przypnij',
+  'chat.message.print': 'Drukuj',
+  'chat.message.cooked': 'Ugotowane',
+  'chat.message.queued': 'Oczekuje na połączenie',
+  'chat.message.queuedHint': 'Wiadomość zostanie wysłana automatycznie po odzyskaniu połączenia',
+  'chat.image.alt': 'Obraz z odpowiedzi',
+  'chat.image.zoom': 'Powiększ: {alt}',
+  'chat.attachment.pantry': 'Spiżarnia',
+  'chat.attachment.receipts': 'Paragony z tego tygodnia',
+  'chat.attachment.shoppingList': 'Lista zakupów',
+  'shopping.list.removedOne': 'Usunięto „{name}” z listy',
+  'shopping.list.removedMany': 'Usunięto z listy: {count}',
+  'shopping.list.duplicate': '„{name}” jest już na liście',
+  'shopping.list.addAnyway': 'Dodaj mimo to',
+  'shopping.list.syncContext': 'Synchronizacja listy zakupów',
+  'shopping.list.conflictContext': 'Rozwiązywanie konfliktu listy zakupów',
+  'shopping.unitPiece': 'szt.',
+
+  'lazy.loading': 'Wczytywanie...',
+  'lazy.backupManager': 'Wczytywanie kopii zapasowych...',
+  'lazy.ragManager': 'Wczytywanie menedżera RAG...',
+  'lazy.ragUpload': 'Wczytywanie bazy wiedzy...',
+  'lazy.chat': 'Wczytywanie czatu...',
+  'lazy.cooking': 'Wczytywanie asystenta gotowania...',
+  'lazy.productTable': 'Wczytywanie tabeli produktów...',
+  'lazy.receiptUploader': 'Wczytywanie skanera paragonów...',
+  'lazy.pantry': 'Wczytywanie spiżarni...',
+
+  'chat.activity': 'Czat: {text}',
+
+  'backup.title': 'Kopie zapasowe systemu',
+  'backup.subtitle': 'Twórz kopie zapasowe, zarządzaj nimi i przywracaj je zgodnie z zasadą 3-2-1',
+  'backup.created': 'Kopia zapasowa została utworzona',
+  'backup.createFailed': 'Nie udało się utworzyć kopii zapasowej',
+  'backup.selectFirst': 'Wybierz kopię zapasową do przywrócenia',
+  'backup.restoreConfirm': 'Przywrócić kopię „{name}”? Obecne dane zostaną nadpisane.',
+  'backup.restored': 'Kopia zapasowa została przywrócona. Status: {status}',
+  'backup.restoreFailed': 'Nie udało się przywrócić kopii zapasowej',
+  'backup.verified': 'Weryfikacja kopii zakończona. Status: {status}',
+  'backup.verifyFailed': 'Nie udało się zweryfikować kopii zapasowej',
+  'backup.cleanupConfirm': 'Usunąć stare kopie zapasowe? Tej operacji nie można cofnąć.',
+  'backup.cleaned': 'Stare kopie zapasowe zostały usunięte',
+  'backup.cleanupFailed': 'Nie udało się usunąć starych kopii zapasowych',
+  'backup.statsTitle': 'Statystyki kopii zapasowych',
+  'backup.loading': 'Wczytywanie...',
+  'backup.refresh': 'Odśwież',
+  'backup.totalBackups': 'Liczba kopii',
+  'backup.totalSize': 'Łączny rozmiar',
+  'backup.dirSize': 'Rozmiar katalogu kopii',
+  'backup.verification': 'Weryfikacja',
+  'backup.enabled': 'Włączona',
+  'backup.disabled': 'Wyłączona',
+  'backup.statsLoading': 'Wczytywanie statystyk...',
+  'backup.createTitle': 'Nowa kopia zapasowa',
+  'backup.namePlaceholder': 'Nazwa kopii (opcjonalnie)',
+  'backup.creating': 'Tworzenie...',
+  'backup.create': 'Utwórz kopię',
+  'backup.historyTitle': 'Historia kopii',
+  'backup.createdAt': 'Utworzono: {date}',
+  'backup.size': 'Rozmiar: {size}',
+  'backup.status': 'Status: {status}',
+  'backup.verify': 'Weryfikuj',
+  'backup.restore': 'Przywróć',
+  'backup.empty': 'Brak kopii zapasowych',
+  'backup.restoreTitle': 'Przywracanie kopii: {name}',
+  'backup.componentsPlaceholder': 'Składniki do przywrócenia (oddzielone przecinkami, puste = wszystkie)',
+  'backup.restoring': 'Przywracanie...',
+  'backup.maintenance': 'Konserwacja',
+  'backup.cleaning': 'Usuwanie...',
+  'backup.cleanup': 'Usuń stare kopie',
+  'backup.bytes': 'B',
+
+  'url.empty': 'Podaj adres serwera',
+  'url.scheme': 'Adres musi zaczynać się od http:// lub https://',
+  'url.invalid': 'Nieprawidłowy adres URL',
+  'url.protocol': 'Obsługiwane są tylko protokoły http i https',
+  'url.queryOrHash': 'Adres nie może zawierać parametrów ani kotwicy (#)',
+  'url.insecure': 'Połączenie http poza siecią lokalną nie jest szyfrowane',
+  'url.notAllowed': 'Adres {origin} nie jest na liście NEXT_PUBLIC_ALLOWED_BACKEND_ORIGINS – przeglądarka zablokuje połączenie',
+  'connection.degraded': 'Serwer działa, ale część usług jest niedostępna',
+  'connection.noHealth': 'Brak endpointu /health – to prawdopodobnie nie jest serwer FoodSave',
+  'connection.badResponse': 'Nieprawidłowa odpowiedź serwera (HTTP {status})',
+  'connection.httpError': 'Serwer zwrócił błąd HTTP {status}',
+  'connection.timeout': 'Przekroczono limit czasu ({seconds} s)',
+  'connection.unreachable': 'Nie można połączyć się z serwerem (zły adres, serwer wyłączony lub blokada CORS)',
+  'print.product': 'Produkt',
+  'print.quantity': 'Ilość',
+  'print.price': 'Cena',
+  'print.value': 'Wartość',
+  'print.total': 'Razem',
+  'print.recipe': 'Przepis',
+  'settings.transfer.notJson': 'Plik nie jest poprawnym plikiem JSON',
+  'settings.transfer.notSettings': 'To nie jest plik ustawień FoodSave',
+  'settings.transfer.newerVersion': 'Plik pochodzi z nowszej wersji aplikacji',
+  'chat.streamedReply': 'Przetworzono odpowiedź strumieniową.',
+} as const;
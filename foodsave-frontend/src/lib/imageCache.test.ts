@@ -0,0 +1,61 @@
+import { LruCache, clearImageCache, fileImageKey, getCachedImageUrl, releaseImageUrl } from './imageCache';
+
+describe('imageCache', () => {
+  it('usuwa najdawniej używany wpis po przekroczeniu limitu', () => {
+    const evicted: string[] = [];
+    const cache = new LruCache<string, number>(2, (key) => evicted.push(key));
+
+    cache.set('a', 1);
+    cache.set('b', 2);
+    cache.get('a');
+    cache.set('c', 3);
+
+    expect(evicted).toEqual(['b']);
+    expect(cache.get('a')).toBe(1);
+    expect(cache.get('b')).toBeUndefined();
+    expect(cache.size).toBe(2);
+  });
+
+  it('zwalnia poprzednią wartość przy nadpisaniu i czyszczeniu', () => {
+    const evicted: number[] = [];
+    const cache = new LruCache<string, number>(5, (_key, value) => evicted.push(value));
+
+    cache.set('a', 1);
+    cache.set('a', 2);
+    cache.set('b', 3);
+    cache.clear();
+
+    expect(evicted).toEqual([1, 2, 3]);
+    expect(cache.size).toBe(0);
+  });
+
+  it('unieważnia adres obrazka dopiero, gdy nikt go już nie wyświetla', async () => {
+    let next = 0;
+    const revoked: string[] = [];
+    URL.createObjectURL = jest.fn(() => `blob:${next++}`);
+    URL.revokeObjectURL = jest.fn((url: string) => revoked.push(url));
+    const load = () => Promise.resolve(new Blob(['x']));
+
+    const [first, second] = await Promise.all([getCachedImageUrl('a', load), getCachedImageUrl('a', load)]);
+    expect(first).toBe(second);
+    await clearImageCache();
+    expect(revoked).toEqual([]);
+
+    releaseImageUrl(first);
+    expect(revoked).toEqual([]);
+    releaseImageUrl(second);
+    expect(revoked).toEqual([first]);
+
+    const unused = await getCachedImageUrl('b', load);
+    releaseImageUrl(unused);
+    await clearImageCache();
+    expect(revoked).toEqual([first, unused]);
+  });
+
+  it('rozróżnia miniatury tego samego pliku po rozmiarze', () => {
+    const file = new File(['x'], 'paragon.jpg', { type: 'image/jpeg', lastModified: 1 });
+
+    expect(fileImageKey(file)).not.toBe(fileImageKey(file, 1200));
+    expect(fileImageKey(file)).toBe(fileImageKey(new File(['y'], 'paragon.jpg', { lastModified: 1 })));
+  });
+});
@@ -0,0 +1,177 @@
+// Shared image cache: decoded thumbnails live as object URLs in a small in-memory LRU,
+// the encoded thumbnails in Cache Storage so they survive a reload
+
+const DISK_CACHE_NAME = 'foodsave-images';
+const MEMORY_ENTRIES = 50;
+const DISK_ENTRIES = 200;
+export const THUMBNAIL_SIZE = 320;
+
+// Least-recently-used map; onEvict lets the owner free what the value holds
+export class LruCache<K, V> {
+  private entries = new Map<K, V>();
+
+  constructor(
+    private readonly maxEntries: number,
+    private readonly onEvict?: (key: K, value: V) => void,
+  ) {}
+
+  get size(): number {
+    return this.entries.size;
+  }
+
+  get(key: K): V | undefined {
+    const value = this.entries.get(key);
+    if (value === undefined) return undefined;
+    // Map zachowuje kolejność wstawiania – przeniesienie na koniec = "ostatnio używany"
+    this.entries.delete(key);
+    this.entries.set(key, value);
+    return value;
+  }
+
+  set(key: K, value: V): void {
+    const previous = this.entries.get(key);
+    if (previous !== undefined) {
+      this.entries.delete(key);
+      if (previous !== value) this.onEvict?.(key, previous);
+    }
+    this.entries.set(key, value);
+    while (this.entries.size > this.maxEntries) {
+      const [oldestKey, oldestValue] = this.entries.entries().next().value as [K, V];
+      this.entries.delete(oldestKey);
+      this.onEvict?.(oldestKey, oldestValue);
+    }
+  }
+
+  delete(key: K): void {
+    const value = this.entries.get(key);
+    if (value === undefined) return;
+    this.entries.delete(key);
+    this.onEvict?.(key, value);
+  }
+
+  clear(): void {
+    for (const [key, value] of this.entries) this.onEvict?.(key, value);
+    this.entries.clear();
+  }
+}
+
+// Object URLs still shown somewhere; an evicted URL is revoked once its last user releases it
+const users = new Map<string, number>();
+const orphaned = new Set<string>();
+
+function retain(url: string, count: number = 1): void {
+  users.set(url, (users.get(url) ?? 0) + count);
+}
+
+function revokeWhenUnused(url: string): void {
+  if (users.has(url)) orphaned.add(url);
+  else URL.revokeObjectURL(url);
+}
+
+// Every URL handed out by this module must be released when it is no longer displayed
+export function releaseImageUrl(url: string): void {
+  const count = (users.get(url) ?? 0) - 1;
+  if (count > 0) {
+    users.set(url, count);
+    return;
+  }
+  users.delete(url);
+  if (orphaned.delete(url)) URL.revokeObjectURL(url);
+}
+
+const memory = new LruCache<string, string>(MEMORY_ENTRIES, (_key, url) => revokeWhenUnused(url));
+// Równoległe żądania tego samego obrazka czekają na jedno ładowanie; waiters = liczba oczekujących
+const pending = new Map<string, { promise: Promise<string>; waiters: number }>();
+
+function hasDiskCache(): boolean {
+  return typeof window !== 'undefined' && 'caches' in window;
+}
+
+// Cache Storage keys must be URLs, so keys get a synthetic path
+function diskKey(key: string): string {
+  return `/__foodsave-images/${encodeURIComponent(key)}`;
+}
+
+async function readDisk(key: string): Promise<Blob | undefined> {
+  if (!hasDiskCache()) return undefined;
+  try {
+    const cache = await caches.open(DISK_CACHE_NAME);
+    const response = await cache.match(diskKey(key));
+    return response ? await response.blob() : undefined;
+  } catch {
+    return undefined;
+  }
+}
+
+async function writeDisk(key: string, blob: Blob): Promise<void> {
+  if (!hasDiskCache()) return;
+  try {
+    const cache = await caches.open(DISK_CACHE_NAME);
+    await cache.put(diskKey(key), new Response(blob, { headers: { 'Content-Type': blob.type } }));
+    // Klucze wracają w kolejności dodania – usuwamy najstarsze ponad limit
+    const keys = await cache.keys();
+    await Promise.all(keys.slice(0, Math.max(0, keys.length - DISK_ENTRIES)).map((request) => cache.delete(request)));
+  } catch {
+    // Pełny dysk albo tryb prywatny – wystarczy cache w pamięci
+  }
+}
+
+// Scales an image down so its longer side is at most maxSize (never up)
+export async function createThumbnail(source: Blob, maxSize: number = THUMBNAIL_SIZE): Promise<Blob> {
+  const bitmap = await createImageBitmap(source);
+  const scale = Math.min(1, maxSize / Math.max(bitmap.width, bitmap.height));
+  const canvas = document.createElement('canvas');
+  canvas.width = Math.round(bitmap.width * scale);
+  canvas.height = Math.round(bitmap.height * scale);
+  canvas.getContext('2d')?.drawImage(bitmap, 0, 0, canvas.width, canvas.height);
+  bitmap.close();
+  return new Promise((resolve, reject) =>
+    canvas.toBlob((blob) => (blob ? resolve(blob) : reject(new Error('Nie udało się utworzyć miniatury'))), 'image/jpeg', 0.8),
+  );
+}
+
+// Object URL of the image under key, loaded once; the caller must release it
+export function getCachedImageUrl(key: string, load: () => Promise<Blob>): Promise<string> {
+  const hit = memory.get(key);
+  if (hit) {
+    retain(hit);
+    return Promise.resolve(hit);
+  }
+  const inFlight = pending.get(key);
+  if (inFlight) {
+    inFlight.waiters += 1;
+    return inFlight.promise;
+  }
+
+  const entry = { waiters: 1, promise: Promise.resolve('') };
+  entry.promise = (async () => {
+    let blob = await readDisk(key);
+    if (!blob) {
+      blob = await load();
+      void writeDisk(key, blob);
+    }
+    const url = URL.createObjectURL(blob);
+    // Wszyscy oczekujący dostają ten sam URL, więc jest zajęty tyle razy, ilu ich jest
+    retain(url, entry.waiters);
+    memory.set(key, url);
+    return url;
+  })().finally(() => pending.delete(key));
+  pending.set(key, entry);
+  return entry.promise;
+}
+
+// Stable cache key for a picked file
+export function fileImageKey(file: File, maxSize: number = THUMBNAIL_SIZE): string {
+  return `thumb:${maxSize}:${file.name}:${file.size}:${file.lastModified}`;
+}
+
+// Object URL of a thumbnail for a local file (e.g. a receipt photo before upload); release it after use
+export function getThumbnailUrl(file: File, maxSize: number = THUMBNAIL_SIZE): Promise<string> {
+  return getCachedImageUrl(fileImageKey(file, maxSize), () => createThumbnail(file, maxSize));
+}
+
+// URLs still on screen stay valid until released
+export async function clearImageCache(): Promise<void> {
+  memory.clear();
+  if (hasDiskCache()) await caches.delete(DISK_CACHE_NAME).catch(() => false);
+}
@@ -0,0 +1,44 @@
+import {
+  addLeftover,
+  getLeftovers,
+  leftoverAlerts,
+  leftoverStage,
+  removeLeftover,
+  restoreLeftover,
+  suggestEatBy,
+} from './leftovers';
+
+describe('leftovers', () => {
+  beforeEach(() => {
+    window.localStorage.clear();
+  });
+
+  it('podpowiada termin zjedzenia kilka dni po ugotowaniu', () => {
+    expect(suggestEatBy('2024-05-30')).toBe('2024-06-02');
+  });
+
+  it('sortuje resztki od najszybciej psujących się i przywraca usunięte', () => {
+    addLeftover('Zupa', '2024-05-10', '2024-05-13');
+    const rice = addLeftover(' Ryż ', '2024-05-10', '2024-05-11');
+
+    expect(getLeftovers().map((l) => l.name)).toEqual(['Ryż', 'Zupa']);
+
+    const removed = removeLeftover(rice.id);
+    expect(getLeftovers().map((l) => l.name)).toEqual(['Zupa']);
+
+    restoreLeftover(removed!);
+    restoreLeftover(removed!);
+    expect(getLeftovers().map((l) => l.name)).toEqual(['Ryż', 'Zupa']);
+  });
+
+  it('przypomina dzień wcześniej, w dniu terminu i po terminie', () => {
+    const now = new Date(2024, 4, 12, 9, 0);
+    const leftover = (eatBy: string) => ({ id: eatBy, name: 'Gulasz', cookedOn: '2024-05-10', eatBy, createdAt: '' });
+
+    expect(leftoverStage(leftover('2024-05-15'), now)).toBe('fresh');
+    expect(leftoverStage(leftover('2024-05-13'), now)).toBe('soon');
+    expect(leftoverStage(leftover('2024-05-12'), now)).toBe('today');
+    expect(leftoverStage(leftover('2024-05-11'), now)).toBe('overdue');
+    expect(leftoverAlerts([leftover('2024-05-15'), leftover('2024-05-12')], now).map((a) => a.stage)).toEqual(['today']);
+  });
+});
@@ -0,0 +1,82 @@
+// Leftovers from cooked meals, kept locally. They spoil much faster than packaged food,
+// so every one is reminded about the day before, on the day and once it's overdue.
+
+import { v4 as uuidv4 } from 'uuid';
+import { readJson, subscribeStorage, writeJson } from './storage';
+import { daysBetween, localIsoDate } from './time';
+
+const STORAGE_KEY = 'foodsave.leftovers';
+const CHANGE_EVENT = 'foodsave:leftovers';
+
+// Typical fridge life of a cooked dish
+export const LEFTOVER_DAYS = 3;
+
+export interface Leftover {
+  id: string;
+  name: string;
+  cookedOn: string; // YYYY-MM-DD
+  eatBy: string; // YYYY-MM-DD
+  createdAt: string;
+}
+
+export type LeftoverStage = 'fresh' | 'soon' | 'today' | 'overdue';
+
+function parseDay(date: string): Date {
+  return new Date(`${date}T00:00:00`);
+}
+
+// Soonest to spoil first
+export function getLeftovers(): Leftover[] {
+  return readJson<Leftover[]>(STORAGE_KEY, []).sort((a, b) => a.eatBy.localeCompare(b.eatBy));
+}
+
+function save(leftovers: Leftover[]) {
+  writeJson(STORAGE_KEY, leftovers);
+  window.dispatchEvent(new CustomEvent(CHANGE_EVENT));
+}
+
+export function suggestEatBy(cookedOn: string): string {
+  const cooked = parseDay(cookedOn);
+  return localIsoDate(new Date(cooked.getFullYear(), cooked.getMonth(), cooked.getDate() + LEFTOVER_DAYS));
+}
+
+export function addLeftover(name: string, cookedOn: string, eatBy: string = suggestEatBy(cookedOn)): Leftover {
+  const leftover: Leftover = { id: uuidv4(), name: name.trim(), cookedOn, eatBy, createdAt: new Date().toISOString() };
+  save([...getLeftovers(), leftover]);
+  return leftover;
+}
+
+// Returns the removed leftover so the caller can offer Undo
+export function removeLeftover(id: string): Leftover | undefined {
+  const leftovers = getLeftovers();
+  save(leftovers.filter((l) => l.id !== id));
+  return leftovers.find((l) => l.id === id);
+}
+
+export function restoreLeftover(leftover: Leftover): void {
+  const leftovers = getLeftovers();
+  if (!leftovers.some((l) => l.id === leftover.id)) save([...leftovers, leftover]);
+}
+
+export function leftoverStage(leftover: Leftover, now: Date = new Date()): LeftoverStage {
+  const days = daysBetween(now, parseDay(leftover.eatBy));
+  if (days < 0) return 'overdue';
+  if (days === 0) return 'today';
+  if (days === 1) return 'soon';
+  return 'fresh';
+}
+
+// Leftovers that need a notification now; each stage is notified once per leftover
+export function leftoverAlerts(
+  leftovers: Leftover[],
+  now: Date = new Date(),
+): { leftover: Leftover; stage: Exclude<LeftoverStage, 'fresh'> }[] {
+  return leftovers.flatMap((leftover) => {
+    const stage = leftoverStage(leftover, now);
+    return stage === 'fresh' ? [] : [{ leftover, stage }];
+  });
+}
+
+export function subscribeLeftovers(listener: () => void): () => void {
+  return subscribeStorage([STORAGE_KEY], CHANGE_EVENT, listener);
+}
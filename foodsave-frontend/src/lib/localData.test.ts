@@ -0,0 +1,25 @@
+import { clearLocalData, formatBytes, jsonBytes, localDataBytes, localDataKeys } from './localData';
+
+describe('localData', () => {
+  beforeEach(() => {
+    window.localStorage.clear();
+  });
+
+  it('formatuje rozmiary w bajtach', () => {
+    expect(formatBytes(512)).toBe('512 B');
+    expect(formatBytes(1536)).toBe('1,5 KB');
+    expect(jsonBytes([1, 2])).toBe(10);
+  });
+
+  it('liczy i czyści tylko dane aplikacji', () => {
+    window.localStorage.setItem('foodsave.reminders', '[]');
+    window.localStorage.setItem('authToken', 'abc');
+
+    expect(localDataKeys()).toEqual(['foodsave.reminders']);
+    expect(localDataBytes()).toBe(('foodsave.reminders'.length + 2) * 2);
+
+    clearLocalData();
+    expect(localDataKeys()).toEqual([]);
+    expect(window.localStorage.getItem('authToken')).toBe('abc');
+  });
+});
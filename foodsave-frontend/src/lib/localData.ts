@@ -0,0 +1,42 @@
+// Sizes and cleanup of the data FoodSave keeps in this browser
+
+const KEY_PREFIX = 'foodsave.';
+
+// localStorage przechowuje napisy w UTF-16 - 2 bajty na znak
+export function jsonBytes(value: unknown): number {
+  return value === undefined ? 0 : JSON.stringify(value).length * 2;
+}
+
+export function formatBytes(bytes: number, locale = 'pl'): string {
+  const units = ['B', 'KB', 'MB'];
+  let value = bytes;
+  let unit = 0;
+  while (value >= 1024 && unit < units.length - 1) {
+    value /= 1024;
+    unit += 1;
+  }
+  const digits = unit === 0 ? 0 : 1;
+  return `${value.toLocaleString(locale, { maximumFractionDigits: digits })} ${units[unit]}`;
+}
+
+export function localDataKeys(): string[] {
+  if (typeof window === 'undefined') return [];
+  const keys: string[] = [];
+  for (let i = 0; i < window.localStorage.length; i++) {
+    const key = window.localStorage.key(i);
+    if (key?.startsWith(KEY_PREFIX)) keys.push(key);
+  }
+  return keys;
+}
+
+export function localDataBytes(): number {
+  return localDataKeys().reduce(
+    (total, key) => total + (key.length + (window.localStorage.getItem(key)?.length ?? 0)) * 2,
+    0,
+  );
+}
+
+// Usuwa wszystkie dane aplikacji (ustawienia, przypomnienia, listę zakupów, budżet, historię)
+export function clearLocalData(): void {
+  for (const key of localDataKeys()) window.localStorage.removeItem(key);
+}
@@ -0,0 +1,69 @@
+import {
+  LogLevel,
+  MAX_FILE_RECORDS,
+  clearLogs,
+  createLogger,
+  filterLogRecords,
+  formatLogRecords,
+  getLogRecords,
+  flushLogs,
+  logModules,
+  readPersistedLogs,
+  serializeDetails,
+  setLogPersistence,
+} from './logger';
+
+describe('logger', () => {
+  const api = createLogger('api');
+  const chat = createLogger('chat');
+
+  beforeEach(() => {
+    clearLogs();
+    api.setLevel(LogLevel.DEBUG);
+    jest.spyOn(console, 'debug').mockImplementation(() => {});
+    jest.spyOn(console, 'info').mockImplementation(() => {});
+    jest.spyOn(console, 'warn').mockImplementation(() => {});
+    jest.spyOn(console, 'error').mockImplementation(() => {});
+  });
+
+  afterEach(() => {
+    jest.restoreAllMocks();
+  });
+
+  it('zapisuje wpisy od ustawionego poziomu i filtruje je', () => {
+    api.setLevel(LogLevel.INFO);
+    api.debug('pominięty');
+    api.warn('GET /api/v2/receipts failed', { status: 502 });
+    chat.info('Odpowiedź gotowa');
+
+    const records = getLogRecords();
+    expect(records.map((r) => r.message)).toEqual(['GET /api/v2/receipts failed', 'Odpowiedź gotowa']);
+    expect(logModules(records)).toEqual(['api', 'chat']);
+    expect(filterLogRecords(records, { minLevel: LogLevel.WARN })).toHaveLength(1);
+    expect(filterLogRecords(records, { module: 'chat' })[0].message).toBe('Odpowiedź gotowa');
+    expect(filterLogRecords(records, { query: '502' })[0].module).toBe('api');
+    expect(formatLogRecords(records)).toMatch(/WARN {2}\[api\] GET \/api\/v2\/receipts failed \{"status":502\}/);
+  });
+
+  it('serializuje błędy i obiekty cykliczne', () => {
+    const cyclic: Record<string, unknown> = { name: 'a' };
+    cyclic.self = cyclic;
+
+    expect(serializeDetails([new Error('timeout')])).toContain('"message":"timeout"');
+    expect(serializeDetails([cyclic])).toBe('{"name":"a","self":"[Circular]"}');
+    expect(serializeDetails([])).toBeUndefined();
+  });
+
+  it('zapisuje dziennik w dwóch rotowanych plikach, a po wyłączeniu go usuwa', () => {
+    setLogPersistence(true);
+    for (let i = 0; i < MAX_FILE_RECORDS + 10; i++) api.info(`wpis ${i}`);
+    flushLogs();
+
+    const persisted = readPersistedLogs();
+    expect(persisted).toHaveLength(MAX_FILE_RECORDS + 10);
+    expect(persisted[persisted.length - 1].message).toBe(`wpis ${MAX_FILE_RECORDS + 9}`);
+
+    setLogPersistence(false);
+    expect(readPersistedLogs()).toEqual([]);
+  });
+});
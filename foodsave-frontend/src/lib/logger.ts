@@ -0,0 +1,239 @@
+// Application logger: writes to the console and keeps recent records in memory
+// for the diagnostics page (/logs), so users can attach them to bug reports.
+// Optionally the records are also kept in localStorage, rotated between two "files".
+
+import { readJson, writeJson } from './storage';
+
+export enum LogLevel {
+  DEBUG = 0,
+  INFO = 1,
+  WARN = 2,
+  ERROR = 3,
+}
+
+export const LOG_LEVEL_NAMES: Record<LogLevel, string> = {
+  [LogLevel.DEBUG]: 'DEBUG',
+  [LogLevel.INFO]: 'INFO',
+  [LogLevel.WARN]: 'WARN',
+  [LogLevel.ERROR]: 'ERROR',
+};
+
+export type LogLevelName = 'debug' | 'info' | 'warn' | 'error';
+
+export const LOG_LEVEL_BY_NAME: Record<LogLevelName, LogLevel> = {
+  debug: LogLevel.DEBUG,
+  info: LogLevel.INFO,
+  warn: LogLevel.WARN,
+  error: LogLevel.ERROR,
+};
+
+export interface LogRecord {
+  id: string; // unique across reloads, because persisted records are restored
+  timestamp: string; // ISO
+  level: LogLevel;
+  module: string;
+  message: string;
+  details?: string; // extra arguments, serialized
+}
+
+export interface LogFilter {
+  minLevel?: LogLevel;
+  module?: string;
+  query?: string;
+}
+
+const MAX_RECORDS = 500;
+const MAX_DETAILS_LENGTH = 2000;
+// Dwa "pliki": po zapełnieniu bieżący staje się poprzednim, a najstarszy znika
+const CURRENT_FILE_KEY = 'foodsave.logs.current';
+const PREVIOUS_FILE_KEY = 'foodsave.logs.previous';
+export const MAX_FILE_RECORDS = 250;
+const FLUSH_DELAY_MS = 1000;
+
+const SESSION = Date.now().toString(36);
+let records: LogRecord[] = [];
+let nextId = 1;
+let persist = false;
+let pending: LogRecord[] = [];
+let flushTimer: ReturnType<typeof setTimeout> | undefined;
+let currentLevel = LogLevel.DEBUG;
+const listeners = new Set<() => void>();
+
+const CONSOLE_METHODS: Record<LogLevel, 'debug' | 'info' | 'warn' | 'error'> = {
+  [LogLevel.DEBUG]: 'debug',
+  [LogLevel.INFO]: 'info',
+  [LogLevel.WARN]: 'warn',
+  [LogLevel.ERROR]: 'error',
+};
+
+// Błędy nie serializują się przez JSON.stringify, a obiekty mogą mieć cykle
+export function serializeDetails(args: unknown[]): string | undefined {
+  if (args.length === 0) return undefined;
+  const seen = new WeakSet<object>();
+  const text = args
+    .map((arg) => {
+      if (typeof arg === 'string') return arg;
+      try {
+        return JSON.stringify(arg, (_key, value) => {
+          if (value instanceof Error) return { name: value.name, message: value.message, stack: value.stack };
+          if (typeof value === 'object' && value !== null) {
+            if (seen.has(value)) return '[Circular]';
+            seen.add(value);
+          }
+          return value;
+        });
+      } catch {
+        return String(arg);
+      }
+    })
+    .join(' ');
+  return text.length > MAX_DETAILS_LENGTH ? `${text.slice(0, MAX_DETAILS_LENGTH)}…` : text;
+}
+
+function record(level: LogLevel, module: string, message: string, args: unknown[]) {
+  if (level < currentLevel) return;
+  console[CONSOLE_METHODS[level]](`[${module}] ${message}`, ...args);
+  const entry: LogRecord = {
+    id: `${SESSION}-${nextId++}`,
+    timestamp: new Date().toISOString(),
+    level,
+    module,
+    message,
+    details: serializeDetails(args),
+  };
+  records = [...records.slice(-(MAX_RECORDS - 1)), entry];
+  if (persist) {
+    pending.push(entry);
+    if (!flushTimer) flushTimer = setTimeout(flushLogs, FLUSH_DELAY_MS);
+  }
+  listeners.forEach((listener) => listener());
+}
+
+// Writes buffered records to storage, rotating the current file when it is full
+export function flushLogs(): void {
+  clearTimeout(flushTimer);
+  flushTimer = undefined;
+  if (!persist || pending.length === 0) return;
+  let current = [...readJson<LogRecord[]>(CURRENT_FILE_KEY, []), ...pending];
+  pending = [];
+  while (current.length > MAX_FILE_RECORDS) {
+    writeJson(PREVIOUS_FILE_KEY, current.slice(0, MAX_FILE_RECORDS));
+    current = current.slice(MAX_FILE_RECORDS);
+  }
+  writeJson(CURRENT_FILE_KEY, current);
+}
+
+export function readPersistedLogs(): LogRecord[] {
+  return [...readJson<LogRecord[]>(PREVIOUS_FILE_KEY, []), ...readJson<LogRecord[]>(CURRENT_FILE_KEY, [])];
+}
+
+// Turning persistence on restores earlier sessions into the buffer; turning it off deletes them
+export function setLogPersistence(enabled: boolean): void {
+  if (enabled === persist) return;
+  persist = enabled;
+  if (enabled) {
+    const known = new Set(records.map((entry) => entry.id));
+    const restored = readPersistedLogs().filter((entry) => !known.has(entry.id));
+    records = [...restored, ...records].slice(-MAX_RECORDS);
+    pending = records.filter((entry) => entry.id.startsWith(`${SESSION}-`));
+    flushLogs();
+  } else {
+    clearTimeout(flushTimer);
+    flushTimer = undefined;
+    pending = [];
+    if (typeof window !== 'undefined') {
+      window.localStorage.removeItem(CURRENT_FILE_KEY);
+      window.localStorage.removeItem(PREVIOUS_FILE_KEY);
+    }
+  }
+  listeners.forEach((listener) => listener());
+}
+
+export class Logger {
+  constructor(readonly module: string) {}
+
+  debug(message: string, ...args: unknown[]) {
+    record(LogLevel.DEBUG, this.module, message, args);
+  }
+
+  info(message: string, ...args: unknown[]) {
+    record(LogLevel.INFO, this.module, message, args);
+  }
+
+  warn(message: string, ...args: unknown[]) {
+    record(LogLevel.WARN, this.module, message, args);
+  }
+
+  error(message: string, ...args: unknown[]) {
+    record(LogLevel.ERROR, this.module, message, args);
+  }
+
+  // The level is shared by all module loggers
+  setLevel(level: LogLevel) {
+    currentLevel = level;
+  }
+
+  getLevel(): LogLevel {
+    return currentLevel;
+  }
+}
+
+export function createLogger(module: string): Logger {
+  return new Logger(module);
+}
+
+export function getLogRecords(): LogRecord[] {
+  return records;
+}
+
+export function clearLogs(): void {
+  records = [];
+  pending = [];
+  if (persist) {
+    writeJson(CURRENT_FILE_KEY, []);
+    writeJson(PREVIOUS_FILE_KEY, []);
+  }
+  listeners.forEach((listener) => listener());
+}
+
+export function subscribeLogs(listener: () => void): () => void {
+  listeners.add(listener);
+  return () => {
+    listeners.delete(listener);
+  };
+}
+
+export function filterLogRecords(list: LogRecord[], { minLevel, module, query }: LogFilter): LogRecord[] {
+  const needle = query?.trim().toLowerCase();
+  return list.filter(
+    (entry) =>
+      (minLevel === undefined || entry.level >= minLevel) &&
+      (!module || entry.module === module) &&
+      (!needle || `${entry.message} ${entry.details ?? ''}`.toLowerCase().includes(needle)),
+  );
+}
+
+export function logModules(list: LogRecord[]): string[] {
+  return Array.from(new Set(list.map((entry) => entry.module))).sort();
+}
+
+// Plain-text form for copying into a bug report
+export function formatLogRecords(list: LogRecord[]): string {
+  return list
+    .map((entry) => {
+      const line = `${entry.timestamp} ${LOG_LEVEL_NAMES[entry.level].padEnd(5)} [${entry.module}] ${entry.message}`;
+      return entry.details ? `${line} ${entry.details}` : line;
+    })
+    .join('\n');
+}
+
+const logger = createLogger('app');
+
+// Nieobsłużone błędy też trafiają do dziennika
+if (typeof window !== 'undefined') {
+  window.addEventListener('error', (e) => logger.error(e.message || 'Uncaught error', e.error));
+  window.addEventListener('unhandledrejection', (e) => logger.error('Unhandled promise rejection', e.reason));
+  window.addEventListener('pagehide', flushLogs);
+}
+
+export default logger;
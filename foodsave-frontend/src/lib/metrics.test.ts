@@ -0,0 +1,55 @@
+import {
+  clearMetrics,
+  endpointName,
+  getMetrics,
+  measure,
+  percentile,
+  recordMetric,
+  setMetricsEnabled,
+  summarizeMetrics,
+} from './metrics';
+
+describe('metrics', () => {
+  afterEach(() => {
+    setMetricsEnabled(false);
+    clearMetrics();
+  });
+
+  it('nic nie zapisuje bez zgody użytkownika', () => {
+    recordMetric('ocr', 1200);
+    expect(getMetrics()).toEqual([]);
+
+    setMetricsEnabled(true);
+    recordMetric('ocr', 1200);
+    expect(getMetrics()).toHaveLength(1);
+  });
+
+  it('mierzy także nieudane operacje', async () => {
+    setMetricsEnabled(true);
+    await expect(measure('ocr', () => Promise.reject(new Error('timeout')))).rejects.toThrow('timeout');
+    expect(getMetrics()[0]).toMatchObject({ name: 'ocr', ok: false });
+  });
+
+  it('grupuje adresy z identyfikatorami w jeden endpoint', () => {
+    expect(endpointName('get', '/api/food/shopping-trips/12?limit=5')).toBe('GET /api/food/shopping-trips/:id');
+    expect(endpointName(undefined, 'http://localhost:8000/api/v2/rag/documents')).toBe('GET /api/v2/rag/documents');
+  });
+
+  it('liczy percentyle metodą najbliższej rangi', () => {
+    const values = Array.from({ length: 100 }, (_, i) => i + 1);
+    expect(percentile(values, 50)).toBe(50);
+    expect(percentile(values, 99)).toBe(99);
+    expect(percentile([], 90)).toBe(0);
+  });
+
+  it('podsumowuje metryki, najwolniejsze najpierw', () => {
+    const at = Date.now();
+    const summary = summarizeMetrics([
+      { name: 'GET /a', ms: 100, ok: true, at },
+      { name: 'GET /a', ms: 300, ok: false, at },
+      { name: 'ocr', ms: 4000, ok: true, at },
+    ]);
+    expect(summary.map((row) => row.name)).toEqual(['ocr', 'GET /a']);
+    expect(summary[1]).toMatchObject({ count: 2, errorRate: 0.5, p50: 100, max: 300 });
+  });
+});
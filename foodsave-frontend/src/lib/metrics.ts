@@ -0,0 +1,130 @@
+// Opt-in performance metrics: API latencies, error rates and OCR durations, kept only
+// in this browser and summarized with percentiles on the diagnostics page (/logs).
+// Nothing is recorded until enabled in Settings → Diagnostics.
+
+import { readJson, writeJson } from './storage';
+
+const STORAGE_KEY = 'foodsave.metrics';
+const CHANGE_EVENT = 'foodsave:metrics';
+const MAX_SAMPLES = 1000;
+const FLUSH_DELAY_MS = 2000;
+
+export interface MetricSample {
+  name: string; // np. "GET /api/food/products/expiring", "ocr"
+  ms: number;
+  ok: boolean;
+  at: number; // epoch ms
+}
+
+export interface MetricSummary {
+  name: string;
+  count: number;
+  errorRate: number; // 0..1
+  p50: number;
+  p90: number;
+  p99: number;
+  max: number;
+}
+
+let enabled = false;
+let samples: MetricSample[] = [];
+let flushTimer: ReturnType<typeof setTimeout> | undefined;
+
+function notify() {
+  if (typeof window !== 'undefined') window.dispatchEvent(new CustomEvent(CHANGE_EVENT));
+}
+
+function flush() {
+  flushTimer = undefined;
+  writeJson(STORAGE_KEY, samples);
+}
+
+export function setMetricsEnabled(value: boolean): void {
+  if (value === enabled) return;
+  enabled = value;
+  if (enabled) {
+    samples = readJson<MetricSample[]>(STORAGE_KEY, []);
+  } else {
+    // Wyłączenie usuwa też zebrane dane
+    clearMetrics();
+  }
+  notify();
+}
+
+export function isMetricsEnabled(): boolean {
+  return enabled;
+}
+
+export function recordMetric(name: string, ms: number, ok = true): void {
+  if (!enabled) return;
+  samples = [...samples, { name, ms: Math.round(ms), ok, at: Date.now() }].slice(-MAX_SAMPLES);
+  if (flushTimer === undefined && typeof window !== 'undefined') flushTimer = setTimeout(flush, FLUSH_DELAY_MS);
+  notify();
+}
+
+// Times an async operation; failures are recorded too, then rethrown
+export async function measure<T>(name: string, run: () => Promise<T>): Promise<T> {
+  const started = performance.now();
+  try {
+    const result = await run();
+    recordMetric(name, performance.now() - started, true);
+    return result;
+  } catch (error) {
+    recordMetric(name, performance.now() - started, false);
+    throw error;
+  }
+}
+
+// "/api/food/shopping-trips/12?limit=5" -> "/api/food/shopping-trips/:id", so one endpoint is one metric
+export function endpointName(method: string | undefined, url: string | undefined): string {
+  const path = (url ?? '').replace(/^https?:\/\/[^/]+/, '').split('?')[0];
+  const normalized = path
+    .split('/')
+    .map((part) => (/^\d+$/.test(part) || /^[0-9a-f-]{32,36}$/i.test(part) ? ':id' : part))
+    .join('/');
+  return `${(method ?? 'get').toUpperCase()} ${normalized}`;
+}
+
+export function getMetrics(): MetricSample[] {
+  return samples;
+}
+
+// Nearest-rank percentile of sorted values
+export function percentile(sorted: number[], p: number): number {
+  if (sorted.length === 0) return 0;
+  const rank = Math.ceil((p / 100) * sorted.length);
+  return sorted[Math.min(sorted.length, Math.max(1, rank)) - 1];
+}
+
+// One row per metric, slowest (by p90) first
+export function summarizeMetrics(list: MetricSample[]): MetricSummary[] {
+  const groups = new Map<string, MetricSample[]>();
+  for (const sample of list) groups.set(sample.name, [...(groups.get(sample.name) ?? []), sample]);
+  return [...groups.entries()]
+    .map(([name, group]) => {
+      const durations = group.map((s) => s.ms).sort((a, b) => a - b);
+      return {
+        name,
+        count: group.length,
+        errorRate: group.filter((s) => !s.ok).length / group.length,
+        p50: percentile(durations, 50),
+        p90: percentile(durations, 90),
+        p99: percentile(durations, 99),
+        max: durations[durations.length - 1],
+      };
+    })
+    .sort((a, b) => b.p90 - a.p90);
+}
+
+export function clearMetrics(): void {
+  samples = [];
+  clearTimeout(flushTimer);
+  flushTimer = undefined;
+  if (typeof window !== 'undefined') window.localStorage.removeItem(STORAGE_KEY);
+  notify();
+}
+
+export function subscribeMetrics(listener: () => void): () => void {
+  window.addEventListener(CHANGE_EVENT, listener);
+  return () => window.removeEventListener(CHANGE_EVENT, listener);
+}
@@ -0,0 +1,51 @@
+import {
+  addMoney,
+  divideMoney,
+  formatMoney,
+  lineTotal,
+  money,
+  multiplyMoney,
+  sumMoney,
+  toAmount,
+  toDecimalString,
+} from './money';
+
+// Intl wstawia twarde spacje, w asercjach wygodniej mieć zwykłe
+const plain = (text: string) => text.replace(/\s/g, ' ');
+
+describe('money', () => {
+  it('przelicza kwoty na grosze i z powrotem', () => {
+    expect(money(12.99)).toEqual({ minor: 1299, currency: 'PLN' });
+    expect(money(null).minor).toBe(0);
+    expect(money(Number.NaN).minor).toBe(0);
+    expect(toAmount(money(4.35))).toBe(4.35);
+  });
+
+  it('sumuje bez błędów zmiennoprzecinkowych', () => {
+    expect(toAmount(addMoney(money(0.1), money(0.2)))).toBe(0.3);
+    expect(toAmount(sumMoney([money(4.99), money(4.99), money(4.99)]))).toBe(14.97);
+    expect(sumMoney([]).minor).toBe(0);
+  });
+
+  it('nie łączy kwot w różnych walutach', () => {
+    expect(() => addMoney(money(1), money(1, 'EUR'))).toThrow();
+  });
+
+  it('liczy wartość pozycji z ułamkową ilością', () => {
+    expect(lineTotal(5.99, 0.75).minor).toBe(449);
+    expect(lineTotal(3.5, null).minor).toBe(350);
+    expect(multiplyMoney(money(1.01), 3).minor).toBe(303);
+  });
+
+  it('dzieli kwotę w dół do pełnego grosza', () => {
+    expect(divideMoney(money(10), 3).minor).toBe(333);
+    expect(divideMoney(money(10), 0).minor).toBe(0);
+  });
+
+  it('formatuje kwoty w złotówkach', () => {
+    expect(plain(formatMoney(money(12.5)))).toBe('12,50 zł');
+    expect(plain(formatMoney(money(12345.678)))).toBe('12 345,68 zł');
+    expect(plain(formatMoney(money(12.5), { wholeUnits: true }))).toBe('13 zł');
+    expect(toDecimalString(money(-3))).toBe('-3.00');
+  });
+});
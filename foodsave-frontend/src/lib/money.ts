@@ -0,0 +1,80 @@
+// Money in minor units (grosze) – the API sends prices as floats, we convert once at the
+// edge so sums and budget math don't collect 0.30000000000000004-style errors
+
+import type { Locale } from './i18n';
+
+export const DEFAULT_CURRENCY = 'PLN';
+
+export interface Money {
+  minor: number; // integer, e.g. 1299 for 12,99 zł
+  currency: string; // ISO 4217
+}
+
+// 12.99 -> { minor: 1299 }; null/undefined/NaN count as zero
+export function money(amount: number | null | undefined, currency: string = DEFAULT_CURRENCY): Money {
+  const value = Number(amount);
+  return { minor: Number.isFinite(value) ? Math.round(value * 100) : 0, currency };
+}
+
+export function zero(currency: string = DEFAULT_CURRENCY): Money {
+  return { minor: 0, currency };
+}
+
+export function toAmount(value: Money): number {
+  return value.minor / 100;
+}
+
+function assertSameCurrency(a: Money, b: Money): void {
+  if (a.currency !== b.currency) {
+    throw new Error(`Nie można łączyć kwot w różnych walutach (${a.currency}, ${b.currency})`);
+  }
+}
+
+export function addMoney(a: Money, b: Money): Money {
+  assertSameCurrency(a, b);
+  return { minor: a.minor + b.minor, currency: a.currency };
+}
+
+export function subtractMoney(a: Money, b: Money): Money {
+  assertSameCurrency(a, b);
+  return { minor: a.minor - b.minor, currency: a.currency };
+}
+
+// Quantities can be fractional (0.75 kg), the result is rounded to a whole grosz
+export function multiplyMoney(value: Money, factor: number): Money {
+  return { minor: Math.round(value.minor * factor), currency: value.currency };
+}
+
+// Rounded down, so e.g. a daily allowance never adds up to more than the whole
+export function divideMoney(value: Money, parts: number): Money {
+  return { minor: parts > 0 ? Math.floor(value.minor / parts) : 0, currency: value.currency };
+}
+
+export function sumMoney(values: Money[], currency: string = DEFAULT_CURRENCY): Money {
+  return values.reduce(addMoney, zero(currency));
+}
+
+// Unit price times quantity (1 when unknown), as on a receipt line
+export function lineTotal(unitPrice: number | null | undefined, quantity?: number | null): Money {
+  return multiplyMoney(money(unitPrice), quantity ?? 1);
+}
+
+export interface FormatMoneyOptions {
+  locale?: Locale;
+  wholeUnits?: boolean; // "13 zł" instead of "12,50 zł", e.g. for chart labels
+}
+
+// "12,50 zł"
+export function formatMoney(value: Money, { locale = 'pl', wholeUnits = false }: FormatMoneyOptions = {}): string {
+  return new Intl.NumberFormat(locale, {
+    style: 'currency',
+    currency: value.currency,
+    minimumFractionDigits: wholeUnits ? 0 : 2,
+    maximumFractionDigits: wholeUnits ? 0 : 2,
+  }).format(toAmount(value));
+}
+
+// Plain "12.50" for CSV and other machine-readable output
+export function toDecimalString(value: Money): string {
+  return toAmount(value).toFixed(2);
+}
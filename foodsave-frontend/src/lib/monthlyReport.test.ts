@@ -0,0 +1,73 @@
+import {
+  buildMonthlyReport,
+  formatChange,
+  isReportSent,
+  lastCompletedMonth,
+  markReportSent,
+  monthRange,
+  monthlyReportSummary,
+  shiftMonth,
+} from './monthlyReport';
+import type { SpendingStats } from '@/types/analytics';
+
+function stats(total: number, categories: [string, number][], extra: Partial<SpendingStats> = {}): SpendingStats {
+  return {
+    date_from: null,
+    date_to: null,
+    total,
+    receipts_count: categories.length,
+    monthly: [],
+    by_category: categories.map(([label, value]) => ({ label, total: value, count: 1 })),
+    by_store: [],
+    ...extra,
+  };
+}
+
+describe('monthlyReport', () => {
+  beforeEach(() => window.localStorage.clear());
+
+  it('wyznacza zakończony miesiąc i jego zakres, także na przełomie roku', () => {
+    expect(lastCompletedMonth(new Date(2024, 0, 1, 9))).toBe('2023-12');
+    expect(shiftMonth('2024-03', -1)).toBe('2024-02');
+    expect(monthRange('2024-02')).toEqual({ from: '2024-02-01', to: '2024-02-29' });
+  });
+
+  it('porównuje sumy i kategorie z poprzednim miesiącem', () => {
+    const report = buildMonthlyReport(
+      '2024-04',
+      stats(550, [['Pieczywo', 50], ['Nabiał', 300], ['Mięso', 200]], { wasted_count: 2, wasted_total: 12.5 }),
+      stats(500, [['Nabiał', 250]]),
+    );
+
+    expect(report.total.minor).toBe(55000);
+    expect(report.change).toBeCloseTo(0.1);
+    expect(report.categories.map((c) => c.label)).toEqual(['Nabiał', 'Mięso', 'Pieczywo']);
+    expect(report.categories[0].change).toBeCloseTo(0.2);
+    expect(report.categories[1].change).toBeNull();
+    expect(report.wastedCount).toBe(2);
+  });
+
+  it('układa treść powiadomienia', () => {
+    const report = buildMonthlyReport('2024-04', stats(90, [['Nabiał', 90]]), stats(100, []));
+    const summary = monthlyReportSummary(report);
+
+    expect(summary).toContain('−10% wobec poprzedniego miesiąca');
+    expect(summary).toContain('Najwięcej: Nabiał');
+    expect(summary).not.toContain('Zmarnowane');
+    expect(formatChange(null)).toBe('–');
+  });
+
+  it('układa treść powiadomienia w języku interfejsu', () => {
+    const report = buildMonthlyReport('2024-04', stats(90, [['Nabiał', 90]]), stats(100, []));
+
+    expect(monthlyReportSummary(report, 'en')).toContain('−10% compared with the previous month');
+    expect(monthlyReportSummary(report, 'en')).toContain('Most spent on: Nabiał');
+  });
+
+  it('zapamiętuje wysłany raport', () => {
+    expect(isReportSent('2024-04')).toBe(false);
+    markReportSent('2024-04');
+    expect(isReportSent('2024-04')).toBe(true);
+    expect(isReportSent('2024-05')).toBe(false);
+  });
+});
@@ -0,0 +1,109 @@
+// End-of-month spending summary built from the stats of the finished month and the one
+// before it. Announced once as a notification, shown on the dashboard and in Analytics.
+
+import { Locale, translate } from './i18n';
+import { Money, formatMoney, money } from './money';
+import { readJson, writeJson } from './storage';
+import { localIsoDate } from './time';
+import type { DateRange, SpendingStats } from '@/types/analytics';
+
+const SENT_KEY = 'foodsave.monthlyReport.sent';
+
+export const TOP_CATEGORIES = 3;
+
+export interface CategoryChange {
+  label: string;
+  total: Money;
+  previous: Money;
+  change: number | null;
+}
+
+export interface MonthlyReport {
+  month: string; // YYYY-MM
+  total: Money;
+  previousTotal: Money;
+  change: number | null; // vs the previous month, null when there is nothing to compare with
+  receipts: number;
+  categories: CategoryChange[]; // largest first
+  wastedCount: number;
+  wastedTotal: Money;
+}
+
+function parseMonth(month: string): [number, number] {
+  const [year, monthNumber] = month.split('-').map(Number);
+  return [year, monthNumber - 1];
+}
+
+export function shiftMonth(month: string, delta: number): string {
+  const [year, index] = parseMonth(month);
+  return localIsoDate(new Date(year, index + delta, 1)).slice(0, 7);
+}
+
+// The month that has just ended - the report is about it until the next one ends
+export function lastCompletedMonth(now: Date = new Date()): string {
+  return shiftMonth(localIsoDate(now).slice(0, 7), -1);
+}
+
+export function monthRange(month: string): DateRange {
+  const [year, index] = parseMonth(month);
+  return { from: `${month}-01`, to: localIsoDate(new Date(year, index + 1, 0)) };
+}
+
+export function formatMonth(month: string, locale: Locale = 'pl'): string {
+  const [year, index] = parseMonth(month);
+  return new Date(year, index, 1).toLocaleDateString(locale, { month: 'long', year: 'numeric' });
+}
+
+function changeRatio(current: Money, previous: Money): number | null {
+  return previous.minor > 0 ? (current.minor - previous.minor) / previous.minor : null;
+}
+
+export function buildMonthlyReport(month: string, current: SpendingStats, previous?: SpendingStats): MonthlyReport {
+  const total = money(current.total);
+  const previousTotal = money(previous?.total ?? 0);
+  const categories = current.by_category.map((bucket) => {
+    const categoryTotal = money(bucket.total);
+    const before = money(previous?.by_category.find((b) => b.label === bucket.label)?.total ?? 0);
+    return { label: bucket.label, total: categoryTotal, previous: before, change: changeRatio(categoryTotal, before) };
+  });
+  return {
+    month,
+    total,
+    previousTotal,
+    change: changeRatio(total, previousTotal),
+    receipts: current.receipts_count,
+    categories: categories.sort((a, b) => b.total.minor - a.total.minor),
+    wastedCount: current.wasted_count ?? 0,
+    wastedTotal: money(current.wasted_total ?? 0),
+  };
+}
+
+// "+12%", "−5%"; a dash when there was nothing to compare with
+export function formatChange(change: number | null): string {
+  if (change === null) return '–';
+  const percent = Math.round(change * 100);
+  if (percent === 0) return '0%';
+  return percent > 0 ? `+${percent}%` : `−${Math.abs(percent)}%`;
+}
+
+export function monthlyReportSummary(report: MonthlyReport, locale: Locale = 'pl'): string {
+  const change =
+    report.change !== null ? translate(locale, 'report.summary.change', { change: formatChange(report.change) }) : '';
+  const parts = [`${translate(locale, 'report.summary.spent', { amount: formatMoney(report.total) })}${change}`];
+  const top = report.categories.slice(0, TOP_CATEGORIES).map((category) => category.label);
+  if (top.length > 0) parts.push(translate(locale, 'report.summary.top', { categories: top.join(', ') }));
+  if (report.wastedCount > 0) {
+    parts.push(
+      translate(locale, 'report.summary.wasted', { count: report.wastedCount, amount: formatMoney(report.wastedTotal) }),
+    );
+  }
+  return `${parts.join('. ')}.`;
+}
+
+export function isReportSent(month: string): boolean {
+  return readJson<string | null>(SENT_KEY, null) === month;
+}
+
+export function markReportSent(month: string): void {
+  writeJson(SENT_KEY, month);
+}
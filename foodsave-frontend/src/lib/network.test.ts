@@ -0,0 +1,23 @@
+import { getNetworkStatus, networkStatusFrom, startNetworkMonitor, subscribeNetwork } from './network';
+
+describe('network', () => {
+  it('rozróżnia brak sieci i niedostępny serwer', () => {
+    expect(networkStatusFrom(false, true)).toBe('offline');
+    expect(networkStatusFrom(true, false)).toBe('unreachable');
+    expect(networkStatusFrom(true, undefined)).toBe('online');
+  });
+
+  it('przechodzi w tryb niedostępności, gdy serwer nie odpowiada', async () => {
+    global.fetch = jest.fn().mockRejectedValue(new TypeError('Failed to fetch')) as unknown as typeof fetch;
+    const listener = jest.fn();
+    const unsubscribe = subscribeNetwork(listener);
+
+    const stop = startNetworkMonitor(() => 'http://localhost:8000');
+    await new Promise((resolve) => setTimeout(resolve, 0));
+
+    expect(getNetworkStatus()).toBe('unreachable');
+    expect(listener).toHaveBeenCalledTimes(1);
+    stop();
+    unsubscribe();
+  });
+});
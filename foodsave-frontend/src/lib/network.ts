@@ -0,0 +1,63 @@
+// Connectivity state: the browser's online/offline events plus a periodic probe of the
+// backend, so both "no network" and "server unreachable" switch the app to cached data.
+
+import { testBackendConnection } from './backendConnection';
+
+export type NetworkStatus = 'online' | 'offline' | 'unreachable';
+
+const PROBE_INTERVAL_MS = 30 * 1000;
+
+let status: NetworkStatus = 'online';
+const listeners = new Set<() => void>();
+
+function setStatus(next: NetworkStatus) {
+  if (next === status) return;
+  status = next;
+  listeners.forEach((listener) => listener());
+}
+
+export function getNetworkStatus(): NetworkStatus {
+  return status;
+}
+
+export function isOnline(): boolean {
+  return status === 'online';
+}
+
+export function subscribeNetwork(listener: () => void): () => void {
+  listeners.add(listener);
+  return () => {
+    listeners.delete(listener);
+  };
+}
+
+export function networkStatusFrom(browserOnline: boolean, backendReachable: boolean | undefined): NetworkStatus {
+  if (!browserOnline) return 'offline';
+  return backendReachable === false ? 'unreachable' : 'online';
+}
+
+export function startNetworkMonitor(baseUrl: () => string): () => void {
+  let backendReachable: boolean | undefined;
+  let stopped = false;
+  const update = () => setStatus(networkStatusFrom(navigator.onLine, backendReachable));
+
+  const probe = async () => {
+    if (navigator.onLine) {
+      const result = await testBackendConnection(baseUrl());
+      // Każda odpowiedź HTTP (nawet błąd) znaczy, że serwer jest osiągalny
+      backendReachable = result.ok || result.latencyMs !== undefined;
+    }
+    if (!stopped) update();
+  };
+
+  window.addEventListener('online', probe);
+  window.addEventListener('offline', update);
+  void probe();
+  const timer = window.setInterval(probe, PROBE_INTERVAL_MS);
+  return () => {
+    stopped = true;
+    window.clearInterval(timer);
+    window.removeEventListener('online', probe);
+    window.removeEventListener('offline', update);
+  };
+}
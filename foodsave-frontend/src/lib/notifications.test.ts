@@ -0,0 +1,49 @@
+import { notify, resetNotificationRateLimit } from './notifications';
+import { saveSettings, DEFAULT_SETTINGS } from './settings';
+
+const shown: string[] = [];
+
+class FakeNotification {
+  static permission = 'granted';
+  onclick: (() => void) | null = null;
+  constructor(title: string) {
+    shown.push(title);
+  }
+  close() {}
+}
+
+describe('notifications', () => {
+  beforeAll(() => {
+    Object.defineProperty(window, 'Notification', { value: FakeNotification, configurable: true });
+  });
+
+  beforeEach(() => {
+    window.localStorage.clear();
+    shown.length = 0;
+    resetNotificationRateLimit();
+  });
+
+  it('respektuje główny przełącznik i wyciszone kategorie', () => {
+    saveSettings({ ...DEFAULT_SETTINGS, notificationCategories: { budget: false } });
+    expect(notify({ category: 'budget', title: 'a', body: '' })).toBe(false);
+    expect(notify({ category: 'reminder', title: 'b', body: '' })).toBe(true);
+
+    resetNotificationRateLimit();
+    saveSettings({ ...DEFAULT_SETTINGS, notificationsEnabled: false });
+    expect(notify({ category: 'reminder', title: 'c', body: '' })).toBe(false);
+    expect(shown).toEqual(['b']);
+  });
+
+  it('pokazuje powiadomienie z danym kluczem tylko raz', () => {
+    expect(notify({ category: 'weather', title: 'a', body: '', onceKey: 'k' })).toBe(true);
+    resetNotificationRateLimit();
+    expect(notify({ category: 'weather', title: 'a', body: '', onceKey: 'k' })).toBe(false);
+  });
+
+  it('ogranicza częstotliwość powiadomień w kategorii', () => {
+    expect(notify({ category: 'chat', title: 'a', body: '' })).toBe(true);
+    expect(notify({ category: 'chat', title: 'b', body: '' })).toBe(false);
+    expect(notify({ category: 'ocr', title: 'c', body: '' })).toBe(true);
+    expect(shown).toEqual(['a', 'c']);
+  });
+});
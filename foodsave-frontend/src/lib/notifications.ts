@@ -0,0 +1,86 @@
+// Browser (desktop) notifications with categories, click-to-open, rate limiting
+// and the user's on/off switches from settings
+
+import { readJson, writeJson } from './storage';
+import { loadSettings } from './settings';
+import type { MessageKey } from './i18n';
+
+const SENT_KEY = 'foodsave.notifications.sent';
+const MAX_SENT = 200;
+
+export type NotificationCategory = 'reminder' | 'budget' | 'weather' | 'pantry' | 'chat' | 'ocr';
+
+export const NOTIFICATION_CATEGORIES: { id: NotificationCategory; labelKey: MessageKey }[] = [
+  { id: 'reminder', labelKey: 'settings.notifications.category.reminder' },
+  { id: 'budget', labelKey: 'settings.notifications.category.budget' },
+  { id: 'weather', labelKey: 'settings.notifications.category.weather' },
+  { id: 'pantry', labelKey: 'settings.notifications.category.pantry' },
+  { id: 'chat', labelKey: 'settings.notifications.category.chat' },
+  { id: 'ocr', labelKey: 'settings.notifications.category.ocr' },
+];
+
+// Co najwyżej jedno powiadomienie danej kategorii na tyle milisekund
+const CATEGORY_INTERVAL_MS = 10 * 1000;
+// I nie więcej niż tyle powiadomień łącznie na minutę
+const MAX_PER_MINUTE = 5;
+
+const lastShownByCategory = new Map<NotificationCategory, number>();
+let recentTimestamps: number[] = [];
+
+export interface NotifyOptions {
+  category: NotificationCategory;
+  title: string;
+  body: string;
+  href?: string; // page opened when the notification is clicked
+  onceKey?: string; // shown at most once per key, across reloads
+}
+
+export function canNotify(): boolean {
+  return typeof window !== 'undefined' && 'Notification' in window && Notification.permission === 'granted';
+}
+
+export async function requestNotificationPermission(): Promise<boolean> {
+  if (typeof window === 'undefined' || !('Notification' in window)) return false;
+  if (Notification.permission === 'default') {
+    await Notification.requestPermission();
+  }
+  return Notification.permission === 'granted';
+}
+
+export function isCategoryEnabled(category: NotificationCategory): boolean {
+  const { notificationsEnabled, notificationCategories } = loadSettings();
+  return notificationsEnabled && notificationCategories[category] !== false;
+}
+
+function takeRateLimitSlot(category: NotificationCategory, now: number): boolean {
+  const last = lastShownByCategory.get(category);
+  if (last !== undefined && now - last < CATEGORY_INTERVAL_MS) return false;
+  recentTimestamps = recentTimestamps.filter((t) => now - t < 60 * 1000);
+  if (recentTimestamps.length >= MAX_PER_MINUTE) return false;
+  lastShownByCategory.set(category, now);
+  recentTimestamps.push(now);
+  return true;
+}
+
+// Returns whether the notification was actually shown
+export function notify({ category, title, body, href, onceKey }: NotifyOptions): boolean {
+  if (!canNotify() || !isCategoryEnabled(category)) return false;
+
+  const sent = onceKey ? readJson<string[]>(SENT_KEY, []) : [];
+  if (onceKey && sent.includes(onceKey)) return false;
+  if (!takeRateLimitSlot(category, Date.now())) return false;
+  if (onceKey) writeJson(SENT_KEY, [onceKey, ...sent].slice(0, MAX_SENT));
+
+  const notification = new Notification(title, { body, tag: onceKey });
+  notification.onclick = () => {
+    window.focus();
+    if (href && window.location.pathname !== href) window.location.assign(href);
+    notification.close();
+  };
+  return true;
+}
+
+export function resetNotificationRateLimit(): void {
+  lastShownByCategory.clear();
+  recentTimestamps = [];
+}
@@ -0,0 +1,31 @@
+import { QueryClient } from '@tanstack/react-query';
+import { MAX_CACHE_AGE_MS, readSnapshot, restoreQueryCache, saveQueryCache, writeSnapshot } from './offlineCache';
+
+describe('offlineCache', () => {
+  beforeEach(() => {
+    window.localStorage.clear();
+  });
+
+  it('zapisuje tylko wybrane zapytania i odtwarza je w nowym kliencie', () => {
+    const client = new QueryClient();
+    client.setQueryData(['shopping-trips', 'history'], [{ id: 1 }]);
+    client.setQueryData(['llm-models'], ['bielik']);
+    saveQueryCache(client, 1000);
+
+    const restored = new QueryClient();
+    expect(restoreQueryCache(restored, 2000)).toBe(true);
+    expect(restored.getQueryData(['shopping-trips', 'history'])).toEqual([{ id: 1 }]);
+    expect(restored.getQueryData(['llm-models'])).toBeUndefined();
+  });
+
+  it('pomija zbyt stare dane', () => {
+    const client = new QueryClient();
+    client.setQueryData(['products', 'expiring', 7, '2024-05-01'], []);
+    saveQueryCache(client, 0);
+    expect(restoreQueryCache(new QueryClient(), MAX_CACHE_AGE_MS + 1)).toBe(false);
+
+    writeSnapshot('products', [{ name: 'Mleko' }], 0);
+    expect(readSnapshot('products', 1000)).toEqual([{ name: 'Mleko' }]);
+    expect(readSnapshot('products', MAX_CACHE_AGE_MS + 1)).toBeUndefined();
+  });
+});
@@ -0,0 +1,80 @@
+// Offline-first cache: server data is mirrored in localStorage so pages render instantly
+// (also without network) and react-query refreshes it in the background
+
+import { DehydratedState, Query, QueryClient, dehydrate, hydrate } from '@tanstack/react-query';
+import { readJson, writeJson } from './storage';
+
+const QUERY_CACHE_KEY = 'foodsave.offline.queries';
+const SNAPSHOT_PREFIX = 'foodsave.offline.';
+const SAVE_DELAY_MS = 1000;
+// Starsze dane raczej wprowadzą w błąd niż pomogą
+export const MAX_CACHE_AGE_MS = 7 * 24 * 60 * 60 * 1000;
+
+// Only data worth showing offline; model lists etc. are always fetched fresh
+export const PERSISTED_QUERY_ROOTS = [
+  'products',
+  'shopping-trips',
+  'spending-stats',
+  'weather-forecast',
+  'calendar-events',
+];
+
+interface Stored<T> {
+  savedAt: number;
+  data: T;
+}
+
+export function shouldPersistQuery(query: Pick<Query, 'queryKey' | 'state'>): boolean {
+  return query.state.status === 'success' && PERSISTED_QUERY_ROOTS.includes(String(query.queryKey[0]));
+}
+
+export function saveQueryCache(client: QueryClient, now = Date.now()): void {
+  const state = dehydrate(client, { shouldDehydrateQuery: shouldPersistQuery });
+  writeJson<Stored<DehydratedState>>(QUERY_CACHE_KEY, { savedAt: now, data: state });
+}
+
+export function restoreQueryCache(client: QueryClient, now = Date.now()): boolean {
+  const stored = readJson<Stored<DehydratedState> | null>(QUERY_CACHE_KEY, null);
+  if (!stored || now - stored.savedAt > MAX_CACHE_AGE_MS) return false;
+  // Odtworzone dane mają swoje stare dataUpdatedAt, więc i tak zostaną odświeżone
+  hydrate(client, stored.data);
+  return true;
+}
+
+// When the persisted data was last saved, for "dane sprzed 5 minut" style hints
+export function queryCacheSavedAt(): number | undefined {
+  return readJson<Stored<DehydratedState> | null>(QUERY_CACHE_KEY, null)?.savedAt;
+}
+
+// Restores the cache once and keeps saving it (debounced) as queries change
+export function persistQueryCache(client: QueryClient): () => void {
+  restoreQueryCache(client);
+  let timer: ReturnType<typeof setTimeout> | undefined;
+  const unsubscribe = client.getQueryCache().subscribe((event) => {
+    if (event.type !== 'updated' && event.type !== 'removed') return;
+    clearTimeout(timer);
+    timer = setTimeout(() => saveQueryCache(client), SAVE_DELAY_MS);
+  });
+  return () => {
+    clearTimeout(timer);
+    unsubscribe();
+  };
+}
+
+// Snapshots for data loaded outside react-query (e.g. the pantry product list)
+export function readSnapshot<T>(name: string, now = Date.now()): T | undefined {
+  const stored = readJson<Stored<T> | null>(SNAPSHOT_PREFIX + name, null);
+  return stored && now - stored.savedAt <= MAX_CACHE_AGE_MS ? stored.data : undefined;
+}
+
+export function writeSnapshot<T>(name: string, data: T, now = Date.now()): void {
+  writeJson<Stored<T>>(SNAPSHOT_PREFIX + name, { savedAt: now, data });
+}
+
+export function clearOfflineCache(): void {
+  if (typeof window === 'undefined') return;
+  for (let i = window.localStorage.length - 1; i >= 0; i--) {
+    const key = window.localStorage.key(i);
+    if (key?.startsWith(SNAPSHOT_PREFIX)) window.localStorage.removeItem(key);
+  }
+}
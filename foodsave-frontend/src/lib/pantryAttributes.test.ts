@@ -0,0 +1,34 @@
+import { attributeRows, attributesFromRows, formatAttribute, pantrySearchText } from './pantryAttributes';
+
+describe('pantryAttributes', () => {
+  it('zamienia wiersze edytora na atrybuty, pomijając puste klucze', () => {
+    const rows = attributeRows({ dieta: 'bezglutenowe' });
+
+    expect(rows).toEqual([{ key: 'dieta', value: 'bezglutenowe' }]);
+    expect(
+      attributesFromRows([
+        ...rows,
+        { key: ' na imprezę ', value: '' },
+        { key: '  ', value: 'bez klucza' },
+        { key: 'dieta', value: ' wegańskie ' },
+      ]),
+    ).toEqual({ dieta: 'wegańskie', 'na imprezę': '' });
+  });
+
+  it('pokazuje atrybut bez wartości jako samą etykietę', () => {
+    expect(formatAttribute('dieta', 'bezglutenowe')).toBe('dieta: bezglutenowe');
+    expect(formatAttribute('na imprezę', '')).toBe('na imprezę');
+  });
+
+  it('uwzględnia notatki i atrybuty w tekście do wyszukiwania', () => {
+    const text = pantrySearchText({
+      name: 'Makaron',
+      unified_category: 'Suche',
+      notes: 'z Lidla',
+      attributes: { dieta: 'bezglutenowe' },
+    });
+
+    expect(text).toContain('z Lidla');
+    expect(text).toContain('dieta bezglutenowe');
+  });
+});
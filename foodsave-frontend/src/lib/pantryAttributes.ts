@@ -0,0 +1,34 @@
+// Notes and custom attributes of pantry items. An attribute is a key with an optional
+// value: "dieta: bezglutenowe", or just a flag like "na imprezę".
+
+import type { PantryItem } from '@/types/cooking';
+
+export interface AttributeRow {
+  key: string;
+  value: string;
+}
+
+export function attributeRows(attributes: Record<string, string> = {}): AttributeRow[] {
+  return Object.entries(attributes).map(([key, value]) => ({ key, value }));
+}
+
+// Rows from the editor back into a record; empty keys are dropped, a repeated key keeps
+// the last value
+export function attributesFromRows(rows: AttributeRow[]): Record<string, string> {
+  const attributes: Record<string, string> = {};
+  for (const row of rows) {
+    const key = row.key.trim();
+    if (key) attributes[key] = row.value.trim();
+  }
+  return attributes;
+}
+
+export function formatAttribute(key: string, value: string): string {
+  return value ? `${key}: ${value}` : key;
+}
+
+// Everything the pantry search looks at, so "bezglutenowe" finds items tagged that way
+export function pantrySearchText(item: Pick<PantryItem, 'name' | 'unified_category' | 'notes' | 'attributes'>): string {
+  const attributes = Object.entries(item.attributes ?? {}).map(([key, value]) => `${key} ${value}`);
+  return [item.name, item.unified_category, item.notes ?? '', ...attributes].join(' ');
+}
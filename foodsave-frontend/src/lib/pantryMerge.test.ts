@@ -0,0 +1,48 @@
+import { duplicateCandidates, mergePantryItems } from './pantryMerge';
+
+const item = (id: string, name: string, extra: object = {}) => ({ id, name, unified_category: 'Nabiał', ...extra });
+
+describe('pantryMerge', () => {
+  it('proponuje do scalenia produkty o podobnej nazwie', () => {
+    const milk = item('1', 'MLEKO UHT 3,2%');
+    const items = [milk, item('2', 'Mleko UHT 3.2'), item('3', 'Chleb żytni')];
+
+    expect(duplicateCandidates(milk, items).map((i) => i.id)).toEqual(['2']);
+  });
+
+  it('sumuje ilości, bierze wcześniejszą datę ważności i zachowuje historię obu', () => {
+    const kept = item('1', 'Mleko', { quantity: 1, unit: 'l', expiry_date: '2024-06-10', notes: 'z Lidla' });
+    const merged = item('2', 'MLEKO UHT', {
+      quantity: 500,
+      unit: 'ml',
+      expiry_date: '2024-06-05',
+      purchase_date: '2024-05-20',
+      staple: true,
+      merged_from: [{ id: '0', name: 'MLEK0' }],
+    });
+
+    expect(mergePantryItems(kept, merged)).toEqual({
+      quantity: 1.5,
+      unit: 'l',
+      expiry_date: '2024-06-05',
+      purchase_date: '2024-05-20',
+      notes: 'z Lidla',
+      attributes: {},
+      staple: true,
+      merged_from: [
+        { id: '2', name: 'MLEKO UHT', purchase_date: '2024-05-20', quantity: 500, unit: 'ml' },
+        { id: '0', name: 'MLEK0' },
+      ],
+    });
+  });
+
+  it('nie dodaje ilości w jednostkach, których nie da się przeliczyć', () => {
+    const kept = item('1', 'Mąka', { quantity: 1, unit: 'kg' });
+
+    expect(mergePantryItems(kept, item('2', 'Mąka', { quantity: 2, unit: 'szt' })).quantity).toBe(1);
+    expect(mergePantryItems(item('3', 'Mąka'), item('2', 'Mąka', { quantity: 2, unit: 'szt' }))).toMatchObject({
+      quantity: 2,
+      unit: 'szt',
+    });
+  });
+});
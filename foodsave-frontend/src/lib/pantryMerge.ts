@@ -0,0 +1,53 @@
+// Merging duplicate pantry items, typically one product saved under two OCR spellings
+// ("MLEKO UHT 3,2%" and "Mleko UHT 3.2"). The kept item absorbs the other one.
+
+import { SEARCH_THRESHOLD, similarity } from './fuzzy';
+import { convertQuantity } from './recipeDeduction';
+import type { PantryItem, PantryItemOrigin } from '@/types/cooking';
+
+// Other items that look like the same product, most similar first
+export function duplicateCandidates(item: PantryItem, items: PantryItem[]): PantryItem[] {
+  return items
+    .filter((other) => other.id !== item.id)
+    .map((other) => ({ other, score: similarity(item.name, other.name) }))
+    .filter(({ score }) => score >= SEARCH_THRESHOLD)
+    .sort((a, b) => b.score - a.score)
+    .map(({ other }) => other);
+}
+
+function earlier(a?: string, b?: string): string | undefined {
+  if (!a || !b) return a || b;
+  return a < b ? a : b;
+}
+
+function origin(item: PantryItem): PantryItemOrigin {
+  return { id: item.id, name: item.name, purchase_date: item.purchase_date, quantity: item.quantity, unit: item.unit };
+}
+
+// Quantities are summed in the kept item's unit; when the units can't be converted
+// the kept quantity stays and the other one is only recorded in the history.
+// The sooner expiry date wins, so nothing is reported as fresher than it is.
+export function mergePantryItems(kept: PantryItem, merged: PantryItem): Partial<PantryItem> {
+  let quantity = kept.quantity;
+  let unit = kept.unit;
+  if (merged.quantity != null) {
+    const added = kept.quantity != null ? convertQuantity(merged.quantity, merged.unit, kept.unit) : undefined;
+    if (kept.quantity == null) {
+      quantity = merged.quantity;
+      unit = merged.unit;
+    } else if (added !== undefined) {
+      quantity = Math.round((kept.quantity + added) * 1000) / 1000;
+    }
+  }
+  const notes = [kept.notes, merged.notes].filter(Boolean).join('\n');
+  return {
+    quantity,
+    unit,
+    expiry_date: earlier(kept.expiry_date, merged.expiry_date),
+    purchase_date: earlier(kept.purchase_date, merged.purchase_date),
+    notes: notes || undefined,
+    attributes: { ...merged.attributes, ...kept.attributes },
+    staple: kept.staple || merged.staple,
+    merged_from: [...(kept.merged_from ?? []), origin(merged), ...(merged.merged_from ?? [])],
+  };
+}
@@ -0,0 +1,58 @@
+import { filterPantry, parsePantryQuery } from './pantryQuery';
+import type { PantryItem } from '@/types/cooking';
+
+const now = new Date(2024, 4, 10, 12);
+
+const items: PantryItem[] = [
+  { id: '1', name: 'Mleko UHT', quantity: 0, unit: 'l', unified_category: 'Nabiał', expiry_date: '2024-05-12' },
+  { id: '2', name: 'Jogurt naturalny', quantity: 2, unified_category: 'Nabiał', expiry_date: '2024-06-30' },
+  {
+    id: '3',
+    name: 'Makaron penne',
+    quantity: 1,
+    unified_category: 'Produkty sypkie',
+    attributes: { dieta: 'bezglutenowe' },
+    staple: true,
+  },
+  { id: '4', name: 'Płyn do naczyń', unified_category: 'Chemia domowa' },
+];
+
+const ids = (query: string) => filterPantry(items, query, now).map((item) => item.id);
+
+describe('pantryQuery', () => {
+  it('oddziela operatory od zwykłego tekstu', () => {
+    const parsed = parsePantryQuery('cat:nabiał  mleko exp<7d');
+
+    expect(parsed.text).toBe('mleko');
+    expect(parsed.predicates).toHaveLength(2);
+  });
+
+  it('filtruje po kategorii, także po angielskiej nazwie i w cudzysłowie', () => {
+    expect(ids('cat:nabiał')).toEqual(['1', '2']);
+    expect(ids('cat:dairy')).toEqual(['1', '2']);
+    expect(ids('cat:"chemia domowa"')).toEqual(['4']);
+  });
+
+  it('filtruje po dniach do końca ważności, pomijając produkty bez daty', () => {
+    expect(ids('exp<7d')).toEqual(['1']);
+    expect(ids('exp>=2w')).toEqual(['2']);
+  });
+
+  it('filtruje po ilości, pomijając produkty bez ilości', () => {
+    expect(ids('qty=0')).toEqual(['1']);
+    expect(ids('qty>0')).toEqual(['2', '3']);
+  });
+
+  it('obsługuje atrybuty i produkty podstawowe', () => {
+    expect(ids('attr:bezglutenowe')).toEqual(['3']);
+    expect(ids('is:staple')).toEqual(['3']);
+  });
+
+  it('łączy operatory z wyszukiwaniem tekstu', () => {
+    expect(ids('cat:nabiał jogurt')).toEqual(['2']);
+  });
+
+  it('traktuje nieznane operatory jak zwykły tekst', () => {
+    expect(parsePantryQuery('foo:bar exp<jutro').text).toBe('foo:bar exp<jutro');
+  });
+});
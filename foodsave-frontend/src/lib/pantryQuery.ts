@@ -0,0 +1,110 @@
+// Pantry filter with operators for power users: `cat:nabiał exp<7d qty=0 mleko`.
+// Operators become structured predicates; whatever is left is searched fuzzily.
+
+import { fuzzySearch, normalizeText } from './fuzzy';
+import { pantrySearchText } from './pantryAttributes';
+import { daysBetween } from './time';
+import type { PantryItem } from '@/types/cooking';
+
+type Comparator = '<' | '<=' | '>' | '>=' | '=';
+type Predicate = (item: PantryItem, now: Date) => boolean;
+
+export interface PantryQuery {
+  text: string;
+  predicates: Predicate[];
+}
+
+// English names for the categories from receipt analysis, so `cat:dairy` works too
+const CATEGORY_ALIASES: Record<string, string> = {
+  dairy: 'nabial',
+  meat: 'mieso',
+  fish: 'ryby',
+  bread: 'pieczywo',
+  vegetables: 'warzywa',
+  fruit: 'owoce',
+  drinks: 'napoje',
+  sweets: 'slodycze',
+};
+
+// key, comparator, value (optionally quoted: cat:"chemia domowa")
+const OPERATOR = /(\w+)(<=|>=|<|>|=|:)("[^"]*"|\S+)/g;
+
+function compare(value: number, comparator: Comparator | ':', target: number): boolean {
+  switch (comparator) {
+    case '<':
+      return value < target;
+    case '<=':
+      return value <= target;
+    case '>':
+      return value > target;
+    case '>=':
+      return value >= target;
+    default:
+      return value === target;
+  }
+}
+
+// "7d", "2w" or a bare number of days
+function parseDays(value: string): number | undefined {
+  const match = /^(-?\d+)([dw]?)$/i.exec(value);
+  if (!match) return undefined;
+  return Number(match[1]) * (match[2].toLowerCase() === 'w' ? 7 : 1);
+}
+
+function predicateFor(key: string, comparator: Comparator | ':', rawValue: string): Predicate | undefined {
+  const value = rawValue.replace(/^"|"$/g, '');
+  switch (key.toLowerCase()) {
+    case 'cat':
+    case 'kat': {
+      const wanted = normalizeText(value);
+      const alias = CATEGORY_ALIASES[wanted];
+      return (item) => {
+        const category = normalizeText(`${item.unified_category} ${item.category ?? ''}`);
+        return category.includes(wanted) || (!!alias && category.includes(alias));
+      };
+    }
+    case 'exp': {
+      const days = parseDays(value);
+      if (days === undefined) return undefined;
+      // Produkty bez daty ważności nie pasują do żadnego warunku na datę
+      return (item, now) => {
+        if (!item.expiry_date) return false;
+        const expiry = new Date(`${item.expiry_date.slice(0, 10)}T00:00:00`);
+        return compare(daysBetween(now, expiry), comparator, days);
+      };
+    }
+    case 'qty': {
+      const target = Number(value.replace(',', '.'));
+      if (Number.isNaN(target)) return undefined;
+      return (item) => item.quantity != null && compare(item.quantity, comparator, target);
+    }
+    case 'attr': {
+      const wanted = normalizeText(value);
+      return (item) =>
+        Object.entries(item.attributes ?? {}).some(([k, v]) => normalizeText(`${k} ${v}`).includes(wanted));
+    }
+    case 'is':
+      if (value.toLowerCase() === 'staple') return (item) => !!item.staple;
+      return undefined;
+    default:
+      return undefined;
+  }
+}
+
+// Unknown operators and unparsable values stay in the free text, so a typo never hides everything
+export function parsePantryQuery(query: string): PantryQuery {
+  const predicates: Predicate[] = [];
+  const text = query.replace(OPERATOR, (token, key: string, comparator: Comparator | ':', value: string) => {
+    const predicate = predicateFor(key, comparator, value);
+    if (!predicate) return token;
+    predicates.push(predicate);
+    return ' ';
+  });
+  return { text: text.replace(/\s+/g, ' ').trim(), predicates };
+}
+
+export function filterPantry(items: PantryItem[], query: string, now: Date = new Date()): PantryItem[] {
+  const { text, predicates } = parsePantryQuery(query);
+  const matching = items.filter((item) => predicates.every((predicate) => predicate(item, now)));
+  return fuzzySearch(matching, text, pantrySearchText);
+}
@@ -0,0 +1,38 @@
+import { isOutOfStock, restockStaples, stapleRanOut } from './pantryStaples';
+import { addShoppingListItem, getShoppingList } from './shoppingList';
+
+const item = (name: string, quantity?: number, staple = true) => ({
+  id: name,
+  name,
+  quantity,
+  staple,
+  unified_category: 'Inne',
+});
+
+describe('pantryStaples', () => {
+  beforeEach(() => {
+    window.localStorage.clear();
+  });
+
+  it('uznaje produkt za wyczerpany tylko przy zerowej ilości', () => {
+    expect(isOutOfStock(item('mleko', 0))).toBe(true);
+    expect(isOutOfStock(item('mleko', 1))).toBe(false);
+    expect(isOutOfStock(item('mleko'))).toBe(false);
+  });
+
+  it('zgłasza tylko przejście podstawowego produktu do zera', () => {
+    expect(stapleRanOut(item('mleko', 1), item('mleko', 0))).toBe(true);
+    expect(stapleRanOut(item('mleko', 0), item('mleko', 0))).toBe(false);
+    expect(stapleRanOut(item('mleko', 1, false), item('mleko', 0, false))).toBe(false);
+    expect(stapleRanOut(item('mleko', 0, false), item('mleko', 0))).toBe(true);
+  });
+
+  it('dodaje na listę brakujące produkty podstawowe, pomijając te już na niej', () => {
+    addShoppingListItem('Mleko');
+
+    const added = restockStaples([item('Mleko', 0), item('Chleb', 0), item('Jajka', 4), item('Sól', 0, false)]);
+
+    expect(added.map((i) => i.name)).toEqual(['Chleb']);
+    expect(getShoppingList().map((i) => i.name)).toEqual(['Mleko', 'Chleb']);
+  });
+});
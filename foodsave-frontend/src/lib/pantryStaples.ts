@@ -0,0 +1,24 @@
+// Staples: pantry items that should always be at home. One that runs out goes
+// straight onto the shopping list.
+
+import { addMissingItems, ShoppingListItem } from './shoppingList';
+import type { PantryItem } from '@/types/cooking';
+
+// Items without a tracked quantity are never treated as used up (the API may send null)
+export function isOutOfStock(item: Pick<PantryItem, 'quantity'>): boolean {
+  return item.quantity != null && item.quantity <= 0;
+}
+
+export function outOfStockStaples<T extends Pick<PantryItem, 'staple' | 'quantity'>>(items: T[]): T[] {
+  return items.filter((item) => item.staple && isOutOfStock(item));
+}
+
+// Called after an update; only the transition to zero adds the item, so an item
+// already crossed off the list doesn't come back on every edit
+export function stapleRanOut(before: PantryItem | undefined, after: PantryItem): boolean {
+  return !!after.staple && isOutOfStock(after) && !(before?.staple && isOutOfStock(before));
+}
+
+export function restockStaples(items: PantryItem[]): ShoppingListItem[] {
+  return addMissingItems(outOfStockStaples(items).map((item) => item.name));
+}
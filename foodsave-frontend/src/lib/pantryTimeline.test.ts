@@ -0,0 +1,28 @@
+import { expiryTimeline } from './pantryTimeline';
+
+const item = (name: string, expiry_date?: string) => ({ name, expiry_date });
+
+describe('pantryTimeline', () => {
+  const today = new Date(2024, 4, 10, 15, 30);
+
+  it('grupuje produkty według dnia końca ważności', () => {
+    const timeline = expiryTimeline(
+      [item('mleko', '2024-05-10'), item('ser', '2024-05-12T00:00:00'), item('jogurt', '2024-05-12'), item('sól')],
+      today,
+      7,
+    );
+
+    expect(timeline.days).toHaveLength(7);
+    expect(timeline.days[0]).toEqual({ date: '2024-05-10', offset: 0, items: [item('mleko', '2024-05-10')] });
+    expect(timeline.days[2].items.map((i) => i.name)).toEqual(['ser', 'jogurt']);
+    expect(timeline.days[1].items).toEqual([]);
+  });
+
+  it('oddziela produkty po terminie i te ważne dłużej niż zakres osi', () => {
+    const timeline = expiryTimeline([item('chleb', '2024-05-08'), item('ryż', '2025-01-01')], today, 7);
+
+    expect(timeline.overdue.map((i) => i.name)).toEqual(['chleb']);
+    expect(timeline.later).toBe(1);
+    expect(timeline.days.every((day) => day.items.length === 0)).toBe(true);
+  });
+});
@@ -0,0 +1,43 @@
+// Pantry expirations grouped per day for the timeline view
+
+import { daysBetween, localIsoDate } from './time';
+
+// Days shown on the timeline, today included
+export const TIMELINE_DAYS = 14;
+
+export interface TimelineDay<T> {
+  date: string; // YYYY-MM-DD
+  offset: number; // days from today
+  items: T[];
+}
+
+export interface ExpiryTimeline<T> {
+  overdue: T[];
+  days: TimelineDay<T>[];
+  later: number; // items expiring after the last shown day
+}
+
+// Every day of the range gets a column, so gaps between expirations stay visible.
+// Items without an expiry date are left out.
+export function expiryTimeline<T extends { expiry_date?: string }>(
+  items: T[],
+  today: Date = new Date(),
+  length: number = TIMELINE_DAYS,
+): ExpiryTimeline<T> {
+  const days: TimelineDay<T>[] = Array.from({ length }, (_, offset) => ({
+    date: localIsoDate(new Date(today.getFullYear(), today.getMonth(), today.getDate() + offset)),
+    offset,
+    items: [],
+  }));
+  const overdue: T[] = [];
+  let later = 0;
+
+  for (const item of items) {
+    if (!item.expiry_date) continue;
+    const offset = daysBetween(today, new Date(`${item.expiry_date.slice(0, 10)}T00:00:00`));
+    if (offset < 0) overdue.push(item);
+    else if (offset < length) days[offset].items.push(item);
+    else later += 1;
+  }
+  return { overdue, days, later };
+}
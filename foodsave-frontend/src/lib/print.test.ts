@@ -0,0 +1,50 @@
+import { money } from './money';
+import {
+  buildPrintDocument,
+  escapeHtml,
+  receiptPrintHtml,
+  recipeTitle,
+  shoppingListPrintHtml,
+} from './print';
+
+describe('print', () => {
+  it('escapuje znaki HTML', () => {
+    expect(escapeHtml(`<b>"Ser" & 'masło'</b>`)).toBe('&lt;b&gt;&quot;Ser&quot; &amp; &#39;masło&#39;&lt;/b&gt;');
+  });
+
+  it('drukuje tylko niekupione pozycje listy', () => {
+    const html = shoppingListPrintHtml([
+      { id: '1', name: 'Mleko', quantity: '2 l', checked: false, createdAt: '' },
+      { id: '2', name: 'Chleb', checked: true, createdAt: '' },
+      { id: '3', name: '<Masło>', checked: false, createdAt: '' },
+    ]);
+    expect(html).toBe('<ul class="checklist"><li>Mleko – 2 l</li><li>&lt;Masło&gt;</li></ul>');
+  });
+
+  it('podsumowuje paragon z wartością pozycji', () => {
+    const trip = {
+      id: 1,
+      trip_date: '2024-05-01',
+      store_name: 'Biedronka',
+      total_amount: 7,
+      products: [
+        { id: 1, trip_id: 1, name: 'Mleko', quantity: 2, unit: 'szt', unit_price: 3.5, is_consumed: false },
+      ],
+    };
+    const html = receiptPrintHtml(trip, money(7));
+    expect(html).toContain('Biedronka · 2024-05-01');
+    expect(html).toContain('2 szt');
+    expect(html.replace(/\s/g, ' ')).toContain('7,00 zł');
+  });
+
+  it('bierze tytuł przepisu z pierwszego nagłówka', () => {
+    expect(recipeTitle('Oto propozycja:\n## **Zupa pomidorowa**\n- pomidory')).toBe('Zupa pomidorowa');
+    expect(recipeTitle('Bez nagłówka')).toBe('Przepis');
+  });
+
+  it('składa samodzielny dokument z tytułem', () => {
+    const doc = buildPrintDocument('Lista <zakupów>', '<p>x</p>', new Date(2024, 4, 1));
+    expect(doc).toContain('<title>Lista &lt;zakupów&gt;</title>');
+    expect(doc).toContain('1.05.2024');
+  });
+});
@@ -0,0 +1,92 @@
+// Printing (and "Save as PDF" from the browser's print dialog) of the shopping list,
+// a recipe from the cooking assistant and a receipt summary. Each printout is a
+// standalone document in a hidden iframe, so the app layout never ends up on paper.
+
+import { Money, formatMoney, lineTotal, money } from './money';
+import type { ShoppingListItem } from './shoppingList';
+import type { ShoppingTrip } from '@/types/shopping';
+import { Locale, MessageKey, translate } from './i18n';
+
+const PRINT_STYLES = `
+  body { font-family: system-ui, sans-serif; color: #000; margin: 0; font-size: 12pt; }
+  h1 { font-size: 18pt; margin: 0 0 4pt; }
+  .meta { color: #555; margin: 0 0 12pt; font-size: 10pt; }
+  ul.checklist { list-style: none; padding: 0; }
+  ul.checklist li { padding: 4pt 0; border-bottom: 1px solid #ddd; }
+  ul.checklist li::before { content: ''; display: inline-block; width: 10pt; height: 10pt; border: 1px solid #000; margin-right: 8pt; vertical-align: -1pt; }
+  table { width: 100%; border-collapse: collapse; }
+  th, td { text-align: left; padding: 3pt 4pt; border-bottom: 1px solid #ddd; }
+  td.num, th.num { text-align: right; white-space: nowrap; }
+  tfoot td { font-weight: bold; border-bottom: none; }
+  @page { margin: 15mm; }
+`;
+
+export function escapeHtml(text: string): string {
+  return text
+    .replace(/&/g, '&amp;')
+    .replace(/</g, '&lt;')
+    .replace(/>/g, '&gt;')
+    .replace(/"/g, '&quot;')
+    .replace(/'/g, '&#39;');
+}
+
+// bodyHtml must already be escaped
+export function buildPrintDocument(
+  title: string,
+  bodyHtml: string,
+  printedAt: Date = new Date(),
+  locale: Locale = 'pl',
+): string {
+  return `<!DOCTYPE html>
+<html lang="${locale}"><head><meta charset="utf-8"><title>${escapeHtml(title)}</title><style>${PRINT_STYLES}</style></head>
+<body><h1>${escapeHtml(title)}</h1><p class="meta">FoodSave · ${escapeHtml(printedAt.toLocaleDateString(locale))}</p>
+${bodyHtml}</body></html>`;
+}
+
+// Only what's still to buy, with empty boxes to tick in the store
+export function shoppingListPrintHtml(items: ShoppingListItem[]): string {
+  const rows = items
+    .filter((item) => !item.checked)
+    .map((item) => `<li>${escapeHtml(item.name)}${item.quantity ? ` – ${escapeHtml(item.quantity)}` : ''}</li>`);
+  return `<ul class="checklist">${rows.join('')}</ul>`;
+}
+
+export function receiptPrintHtml(trip: ShoppingTrip, total: Money, locale: Locale = 'pl'): string {
+  const t = (key: MessageKey) => translate(locale, key);
+  const rows = trip.products.map((p) => {
+    const price = p.unit_price != null ? formatMoney(money(p.unit_price)) : '–';
+    const sum = p.unit_price != null ? formatMoney(lineTotal(p.unit_price, p.quantity)) : '–';
+    return `<tr><td>${escapeHtml(p.name)}</td><td class="num">${p.quantity}${p.unit ? ` ${escapeHtml(p.unit)}` : ''}</td><td class="num">${price}</td><td class="num">${sum}</td></tr>`;
+  });
+  return `<p>${escapeHtml(trip.store_name)} · ${escapeHtml(trip.trip_date)}</p>
+<table><thead><tr><th>${t('print.product')}</th><th class="num">${t('print.quantity')}</th><th class="num">${t('print.price')}</th><th class="num">${t('print.value')}</th></tr></thead>
+<tbody>${rows.join('')}</tbody>
+<tfoot><tr><td colspan="3">${t('print.total')}</td><td class="num">${formatMoney(total)}</td></tr></tfoot></table>`;
+}
+
+// Title for a printed assistant answer: its first Markdown heading, if any
+export function recipeTitle(markdown: string, locale: Locale = 'pl'): string {
+  const heading = markdown.split('\n').find((line) => /^#{1,6}\s+\S/.test(line));
+  return heading ? heading.replace(/^#+\s+/, '').replace(/[*_`]/g, '').trim() : translate(locale, 'print.recipe');
+}
+
+// Opens the print dialog for a standalone document; resolves once printing was handed off
+export function printDocument(html: string): Promise<void> {
+  return new Promise((resolve) => {
+    const frame = document.createElement('iframe');
+    frame.setAttribute('aria-hidden', 'true');
+    frame.style.cssText = 'position:fixed;right:0;bottom:0;width:0;height:0;border:0;';
+    frame.onload = () => {
+      const view = frame.contentWindow;
+      if (!view) return resolve();
+      // Ramka musi istnieć do końca drukowania; print() blokuje tylko w części przeglądarek
+      view.addEventListener('afterprint', () => frame.remove());
+      setTimeout(() => frame.remove(), 60_000);
+      view.focus();
+      view.print();
+      resolve();
+    };
+    frame.srcdoc = html;
+    document.body.appendChild(frame);
+  });
+}
@@ -0,0 +1,103 @@
+import { getChatHistory, saveChatHistory } from './chatHistory';
+import {
+  DEFAULT_PROFILE_ID,
+  addProfile,
+  getActiveProfile,
+  getProfiles,
+  profileSessionId,
+  profileStorageKey,
+  removeProfile,
+  subscribeActiveProfileChange,
+  switchProfile,
+} from './profiles';
+import { getRecoveryData, saveChatDraft } from './recovery';
+import { addRoutine, getRoutines } from './routines';
+import { loadSettings, updateSettings } from './settings';
+
+describe('profiles', () => {
+  beforeEach(() => {
+    window.localStorage.clear();
+  });
+
+  it('domyślny profil używa dotychczasowych kluczy', () => {
+    expect(getActiveProfile().id).toBe(DEFAULT_PROFILE_ID);
+    expect(profileStorageKey('foodsave.chatHistory')).toBe('foodsave.chatHistory');
+    expect(profileSessionId('abc')).toBe('abc');
+  });
+
+  it('rozdziela historię rozmów między profile', () => {
+    saveChatHistory('general', 's1', [{ id: '1', role: 'user', content: 'Cześć' }]);
+    const anna = addProfile('Anna');
+    switchProfile(anna.id);
+
+    expect(getChatHistory('general')).toBeUndefined();
+    expect(profileSessionId('abc')).toBe(`${anna.id}:abc`);
+
+    switchProfile(DEFAULT_PROFILE_ID);
+    expect(getChatHistory('general')?.messages).toHaveLength(1);
+  });
+
+  it('każdy profil ma własne rutyny', () => {
+    addRoutine({ name: 'Plan tygodnia', prompt: 'Zaplanuj posiłki', attachments: [], days: [0], time: '18:00' });
+    const anna = addProfile('Anna');
+    switchProfile(anna.id);
+
+    expect(getRoutines()).toEqual([]);
+
+    switchProfile(DEFAULT_PROFILE_ID);
+    expect(getRoutines().map((r) => r.name)).toEqual(['Plan tygodnia']);
+  });
+
+  it('nowy profil zaczyna z bieżącymi ustawieniami, potem ma własne', () => {
+    updateSettings({ userName: 'Marcin', language: 'en' });
+    const anna = addProfile(' Anna ');
+    switchProfile(anna.id);
+
+    expect(loadSettings().language).toBe('en');
+    updateSettings({ userName: 'Anna' });
+    switchProfile(DEFAULT_PROFILE_ID);
+    expect(loadSettings().userName).toBe('Marcin');
+  });
+
+  it('usuwa profil z jego danymi i wraca do domyślnego', () => {
+    const anna = addProfile('Anna');
+    switchProfile(anna.id);
+    saveChatHistory('general', 's1', [{ id: '1', role: 'user', content: 'Cześć' }]);
+    saveChatDraft('general', 'Co na obiad');
+
+    removeProfile(anna.id);
+    removeProfile(DEFAULT_PROFILE_ID);
+
+    expect(getProfiles().map((p) => p.id)).toEqual([DEFAULT_PROFILE_ID]);
+    expect(getActiveProfile().id).toBe(DEFAULT_PROFILE_ID);
+    expect(window.localStorage.getItem(`foodsave.chatHistory.${anna.id}`)).toBeNull();
+    expect(window.localStorage.getItem(`foodsave.recovery.${anna.id}`)).toBeNull();
+  });
+
+  it('nie pokazuje szkiców innego domownika', () => {
+    saveChatDraft('general', 'Co na obiad');
+    const anna = addProfile('Anna');
+    switchProfile(anna.id);
+
+    expect(getRecoveryData().chatDrafts).toEqual({});
+
+    switchProfile(DEFAULT_PROFILE_ID);
+    expect(getRecoveryData().chatDrafts).toEqual({ general: 'Co na obiad' });
+  });
+
+  it('zgłasza zmianę profilu dokonaną w innej karcie', () => {
+    const anna = addProfile('Anna');
+    const listener = jest.fn();
+    const unsubscribe = subscribeActiveProfileChange(listener);
+    const fromOtherTab = () => window.dispatchEvent(new StorageEvent('storage', { key: 'foodsave.profiles' }));
+
+    fromOtherTab();
+    expect(listener).not.toHaveBeenCalled();
+
+    switchProfile(anna.id);
+    fromOtherTab();
+    fromOtherTab();
+    expect(listener).toHaveBeenCalledTimes(1);
+    unsubscribe();
+  });
+});
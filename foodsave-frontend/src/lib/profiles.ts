@@ -0,0 +1,121 @@
+// Household profiles: each member has their own chat history, unsent drafts, preferences
+// and chat session namespace, while the shopping list, budget and reminders stay shared.
+// The default profile uses the original storage keys, so existing data stays where it was.
+
+import { v4 as uuidv4 } from 'uuid';
+import { readJson, subscribeStorage, writeJson } from './storage';
+
+const STORAGE_KEY = 'foodsave.profiles';
+const CHANGE_EVENT = 'foodsave:profiles';
+export const DEFAULT_PROFILE_ID = 'default';
+
+// Keys stored separately for every profile
+export const PROFILE_SCOPED_KEYS = [
+  'foodsave.chatHistory',
+  'foodsave.chatArchive',
+  'foodsave.settings',
+  'foodsave.routines',
+  'foodsave.recovery',
+  'foodsave.quickAsk.last',
+];
+
+export interface Profile {
+  id: string;
+  name: string;
+}
+
+interface ProfilesStore {
+  profiles: Profile[];
+  activeId: string;
+}
+
+const DEFAULT_PROFILE: Profile = { id: DEFAULT_PROFILE_ID, name: 'Domownik' };
+
+function load(): ProfilesStore {
+  const store = readJson<Partial<ProfilesStore>>(STORAGE_KEY, {});
+  const profiles = store.profiles?.length ? store.profiles : [DEFAULT_PROFILE];
+  const activeId = profiles.some((p) => p.id === store.activeId) ? (store.activeId as string) : profiles[0].id;
+  return { profiles, activeId };
+}
+
+function save(store: ProfilesStore) {
+  writeJson(STORAGE_KEY, store);
+  window.dispatchEvent(new CustomEvent(CHANGE_EVENT));
+}
+
+export function getProfiles(): Profile[] {
+  return load().profiles;
+}
+
+export function getActiveProfile(): Profile {
+  const { profiles, activeId } = load();
+  return profiles.find((p) => p.id === activeId) ?? profiles[0];
+}
+
+function scopedKey(key: string, profileId: string): string {
+  return profileId === DEFAULT_PROFILE_ID ? key : `${key}.${profileId}`;
+}
+
+// Storage key of per-profile data for the active profile
+export function profileStorageKey(key: string): string {
+  return scopedKey(key, getActiveProfile().id);
+}
+
+// Chat session ids carry the profile, so backend conversation memory isn't shared either
+export function profileSessionId(sessionId: string): string {
+  const { id } = getActiveProfile();
+  return id === DEFAULT_PROFILE_ID ? sessionId : `${id}:${sessionId}`;
+}
+
+// New profiles start with the current preferences (backend address, language, theme)
+export function addProfile(name: string): Profile {
+  const store = load();
+  const profile: Profile = { id: uuidv4().slice(0, 8), name: name.trim() };
+  const settingsKey = 'foodsave.settings';
+  const current = readJson<unknown>(scopedKey(settingsKey, store.activeId), null);
+  if (current !== null) writeJson(scopedKey(settingsKey, profile.id), current);
+  save({ ...store, profiles: [...store.profiles, profile] });
+  return profile;
+}
+
+export function renameProfile(id: string, name: string): void {
+  const store = load();
+  save({ ...store, profiles: store.profiles.map((p) => (p.id === id ? { ...p, name: name.trim() } : p)) });
+}
+
+// Removes a profile with its own data; the default profile can't be removed
+export function removeProfile(id: string): void {
+  if (id === DEFAULT_PROFILE_ID) return;
+  const store = load();
+  for (const key of PROFILE_SCOPED_KEYS) window.localStorage.removeItem(scopedKey(key, id));
+  save({
+    profiles: store.profiles.filter((p) => p.id !== id),
+    activeId: store.activeId === id ? DEFAULT_PROFILE_ID : store.activeId,
+  });
+}
+
+export function switchProfile(id: string): void {
+  const store = load();
+  if (store.activeId === id || !store.profiles.some((p) => p.id === id)) return;
+  save({ ...store, activeId: id });
+}
+
+// Another tab switched the active profile. This tab still holds the old profile's chat in
+// memory while profileStorageKey() already points at the new one, so it has to reload
+// before its next save lands in the wrong history. Storage events only reach other tabs.
+export function subscribeActiveProfileChange(listener: () => void): () => void {
+  let activeId = getActiveProfile().id;
+  const onStorage = (e: StorageEvent) => {
+    if (e.key !== STORAGE_KEY) return;
+    const nextId = getActiveProfile().id;
+    if (nextId === activeId) return;
+    activeId = nextId;
+    listener();
+  };
+  window.addEventListener('storage', onStorage);
+  return () => window.removeEventListener('storage', onStorage);
+}
+
+export function subscribeProfiles(listener: () => void): () => void {
+  return subscribeStorage([STORAGE_KEY], CHANGE_EVENT, listener);
+}
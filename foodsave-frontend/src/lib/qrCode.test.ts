@@ -0,0 +1,33 @@
+import { QR_MAX_BYTES, encodeQr, qrToSvgPath } from './qrCode';
+
+describe('qrCode', () => {
+  it('dobiera najmniejszą wersję mieszczącą tekst', () => {
+    expect(encodeQr('Mleko').size).toBe(21);
+    expect(encodeQr('x'.repeat(100)).size).toBe(41); // wersja 6
+  });
+
+  it('rysuje wzorce wyszukiwania w trzech rogach', () => {
+    const { size, modules } = encodeQr('Lista zakupów');
+    const finderRow = [true, true, true, true, true, true, true, false];
+    expect(modules[0].slice(0, 8)).toEqual(finderRow);
+    expect(modules[0].slice(size - 8).reverse()).toEqual(finderRow);
+    expect(modules[size - 1].slice(0, 8)).toEqual(finderRow);
+  });
+
+  it('daje ten sam kod dla tej samej maski co wybrana automatycznie', () => {
+    const text = 'Lista zakupów\n- Mleko (2 l)';
+    const auto = encodeQr(text);
+    const variants = Array.from({ length: 8 }, (_, mask) => encodeQr(text, mask));
+    expect(variants.some((variant) => JSON.stringify(variant) === JSON.stringify(auto))).toBe(true);
+  });
+
+  it('odrzuca zbyt długi tekst', () => {
+    expect(() => encodeQr('a'.repeat(QR_MAX_BYTES + 1))).toThrow('za długi');
+    expect(encodeQr('a'.repeat(QR_MAX_BYTES)).size).toBe(177);
+  });
+
+  it('zamienia moduły na ścieżkę SVG z marginesem', () => {
+    const path = qrToSvgPath(encodeQr('a'));
+    expect(path.startsWith('M4,4h1v1h-1z')).toBe(true);
+  });
+});
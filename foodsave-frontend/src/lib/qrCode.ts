@@ -0,0 +1,343 @@
+// Minimal QR code encoder (byte mode, error correction level M) for sharing
+// the shopping list with a phone. Follows ISO/IEC 18004; no external dependency.
+//
+// Adapted from the QR Code generator library by Project Nayuki:
+// https://www.nayuki.io/page/qr-code-generator-library
+//
+// Copyright (c) Project Nayuki. (MIT License)
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy of
+// this software and associated documentation files (the "Software"), to deal in
+// the Software without restriction, including without limitation the rights to
+// use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies of
+// the Software, and to permit persons to whom the Software is furnished to do so,
+// subject to the following conditions:
+// - The above copyright notice and this permission notice shall be included in
+//   all copies or substantial portions of the Software.
+// - The Software is provided "as is", without warranty of any kind, express or
+//   implied, including but not limited to the warranties of merchantability,
+//   fitness for a particular purpose and noninfringement. In no event shall the
+//   authors or copyright holders be liable for any claim, damages or other
+//   liability, whether in an action of contract, tort or otherwise, arising from,
+//   out of or in connection with the Software or the use or other dealings in the
+//   Software.
+
+// Per version 1–40, level M (index 0 unused)
+const ECC_CODEWORDS_PER_BLOCK = [
+  -1, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26, 26, 28, 28, 28, 28, 28, 28, 28,
+  28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+];
+const ECC_BLOCKS = [
+  -1, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16, 17, 17, 18, 20, 21, 23, 25, 26, 28, 29, 31,
+  33, 35, 37, 38, 40, 43, 45, 47, 49,
+];
+const FORMAT_BITS_LEVEL_M = 0;
+
+export interface QrCode {
+  size: number;
+  modules: boolean[][]; // [row][column], true = dark
+}
+
+function rawDataModules(version: number): number {
+  let result = (16 * version + 128) * version + 64;
+  if (version >= 2) {
+    const alignCount = Math.floor(version / 7) + 2;
+    result -= (25 * alignCount - 10) * alignCount - 55;
+    if (version >= 7) result -= 36;
+  }
+  return result;
+}
+
+function dataCodewords(version: number): number {
+  return Math.floor(rawDataModules(version) / 8) - ECC_CODEWORDS_PER_BLOCK[version] * ECC_BLOCKS[version];
+}
+
+// Largest text (in UTF-8 bytes) a QR code can hold at level M
+export const QR_MAX_BYTES = Math.floor((dataCodewords(40) * 8 - 4 - 16) / 8);
+
+// GF(2^8) with the QR polynomial x^8 + x^4 + x^3 + x^2 + 1
+function gfMultiply(x: number, y: number): number {
+  let z = 0;
+  for (let i = 7; i >= 0; i--) {
+    z = (z << 1) ^ ((z >>> 7) * 0x11d);
+    z ^= ((y >>> i) & 1) * x;
+  }
+  return z;
+}
+
+function reedSolomonDivisor(degree: number): number[] {
+  const result = new Array<number>(degree - 1).fill(0).concat([1]);
+  let root = 1;
+  for (let i = 0; i < degree; i++) {
+    for (let j = 0; j < result.length; j++) {
+      result[j] = gfMultiply(result[j], root);
+      if (j + 1 < result.length) result[j] ^= result[j + 1];
+    }
+    root = gfMultiply(root, 0x02);
+  }
+  return result;
+}
+
+function reedSolomonRemainder(data: number[], divisor: number[]): number[] {
+  const result = divisor.map(() => 0);
+  for (const byte of data) {
+    const factor = byte ^ (result.shift() as number);
+    result.push(0);
+    divisor.forEach((coefficient, i) => (result[i] ^= gfMultiply(coefficient, factor)));
+  }
+  return result;
+}
+
+// Data codewords for the text: mode, length, bytes, terminator and padding
+function encodeData(bytes: Uint8Array, version: number): number[] {
+  const bits: number[] = [];
+  const append = (value: number, length: number) => {
+    for (let i = length - 1; i >= 0; i--) bits.push((value >>> i) & 1);
+  };
+  const capacity = dataCodewords(version) * 8;
+  append(0b0100, 4);
+  append(bytes.length, version <= 9 ? 8 : 16);
+  bytes.forEach((byte) => append(byte, 8));
+  append(0, Math.min(4, capacity - bits.length));
+  append(0, (8 - (bits.length % 8)) % 8);
+  for (let pad = 0xec; bits.length < capacity; pad ^= 0xec ^ 0x11) append(pad, 8);
+
+  const codewords: number[] = [];
+  for (let i = 0; i < bits.length; i += 8) codewords.push(parseInt(bits.slice(i, i + 8).join(''), 2));
+  return codewords;
+}
+
+// Splits data into blocks, appends error correction to each and interleaves them
+function addErrorCorrection(data: number[], version: number): number[] {
+  const blockCount = ECC_BLOCKS[version];
+  const eccLength = ECC_CODEWORDS_PER_BLOCK[version];
+  const rawCodewords = Math.floor(rawDataModules(version) / 8);
+  const shortBlocks = blockCount - (rawCodewords % blockCount);
+  const shortBlockLength = Math.floor(rawCodewords / blockCount);
+  const divisor = reedSolomonDivisor(eccLength);
+
+  const blocks: number[][] = [];
+  for (let i = 0, k = 0; i < blockCount; i++) {
+    const block = data.slice(k, k + shortBlockLength - eccLength + (i < shortBlocks ? 0 : 1));
+    k += block.length;
+    const ecc = reedSolomonRemainder(block, divisor);
+    if (i < shortBlocks) block.push(0);
+    blocks.push(block.concat(ecc));
+  }
+
+  const result: number[] = [];
+  for (let i = 0; i < blocks[0].length; i++) {
+    blocks.forEach((block, j) => {
+      // Krótsze bloki mają w tym miejscu tylko wypełnienie
+      if (i !== shortBlockLength - eccLength || j >= shortBlocks) result.push(block[i]);
+    });
+  }
+  return result;
+}
+
+function alignmentPositions(version: number): number[] {
+  if (version === 1) return [];
+  const count = Math.floor(version / 7) + 2;
+  const step = version === 32 ? 26 : Math.ceil((version * 4 + 4) / (count * 2 - 2)) * 2;
+  const result = [6];
+  for (let position = version * 4 + 10; result.length < count; position -= step) result.splice(1, 0, position);
+  return result;
+}
+
+const MASKS: ((x: number, y: number) => boolean)[] = [
+  (x, y) => (x + y) % 2 === 0,
+  (_x, y) => y % 2 === 0,
+  (x) => x % 3 === 0,
+  (x, y) => (x + y) % 3 === 0,
+  (x, y) => (Math.floor(x / 3) + Math.floor(y / 2)) % 2 === 0,
+  (x, y) => ((x * y) % 2) + ((x * y) % 3) === 0,
+  (x, y) => (((x * y) % 2) + ((x * y) % 3)) % 2 === 0,
+  (x, y) => (((x + y) % 2) + ((x * y) % 3)) % 2 === 0,
+];
+
+class Matrix {
+  readonly modules: boolean[][];
+  readonly reserved: boolean[][]; // function patterns, never masked
+
+  constructor(readonly size: number) {
+    this.modules = Array.from({ length: size }, () => new Array<boolean>(size).fill(false));
+    this.reserved = Array.from({ length: size }, () => new Array<boolean>(size).fill(false));
+  }
+
+  setFunction(x: number, y: number, dark: boolean) {
+    this.modules[y][x] = dark;
+    this.reserved[y][x] = true;
+  }
+}
+
+function drawFunctionPatterns(matrix: Matrix, version: number) {
+  const { size } = matrix;
+  for (let i = 0; i < size; i++) {
+    matrix.setFunction(6, i, i % 2 === 0);
+    matrix.setFunction(i, 6, i % 2 === 0);
+  }
+  // Wzorce wyszukiwania w trzech rogach (z białą ramką)
+  for (const [cx, cy] of [
+    [3, 3],
+    [size - 4, 3],
+    [3, size - 4],
+  ]) {
+    for (let dy = -4; dy <= 4; dy++) {
+      for (let dx = -4; dx <= 4; dx++) {
+        const x = cx + dx;
+        const y = cy + dy;
+        const distance = Math.max(Math.abs(dx), Math.abs(dy));
+        if (x >= 0 && x < size && y >= 0 && y < size) matrix.setFunction(x, y, distance !== 2 && distance !== 4);
+      }
+    }
+  }
+  const positions = alignmentPositions(version);
+  const last = positions.length - 1;
+  positions.forEach((cx, i) =>
+    positions.forEach((cy, j) => {
+      if ((i === 0 && j === 0) || (i === 0 && j === last) || (i === last && j === 0)) return;
+      for (let dy = -2; dy <= 2; dy++) {
+        for (let dx = -2; dx <= 2; dx++) matrix.setFunction(cx + dx, cy + dy, Math.max(Math.abs(dx), Math.abs(dy)) !== 1);
+      }
+    }),
+  );
+  drawFormatBits(matrix, 0);
+  if (version >= 7) {
+    let remainder = version;
+    for (let i = 0; i < 12; i++) remainder = (remainder << 1) ^ ((remainder >>> 11) * 0x1f25);
+    const bits = (version << 12) | remainder;
+    for (let i = 0; i < 18; i++) {
+      const dark = ((bits >>> i) & 1) !== 0;
+      const a = size - 11 + (i % 3);
+      const b = Math.floor(i / 3);
+      matrix.setFunction(a, b, dark);
+      matrix.setFunction(b, a, dark);
+    }
+  }
+}
+
+function drawFormatBits(matrix: Matrix, mask: number) {
+  const data = (FORMAT_BITS_LEVEL_M << 3) | mask;
+  let remainder = data;
+  for (let i = 0; i < 10; i++) remainder = (remainder << 1) ^ ((remainder >>> 9) * 0x537);
+  const bits = ((data << 10) | remainder) ^ 0x5412;
+  const bit = (i: number) => ((bits >>> i) & 1) !== 0;
+  const { size } = matrix;
+
+  for (let i = 0; i <= 5; i++) matrix.setFunction(8, i, bit(i));
+  matrix.setFunction(8, 7, bit(6));
+  matrix.setFunction(8, 8, bit(7));
+  matrix.setFunction(7, 8, bit(8));
+  for (let i = 9; i < 15; i++) matrix.setFunction(14 - i, 8, bit(i));
+  for (let i = 0; i < 8; i++) matrix.setFunction(size - 1 - i, 8, bit(i));
+  for (let i = 8; i < 15; i++) matrix.setFunction(8, size - 15 + i, bit(i));
+  matrix.setFunction(8, size - 8, true);
+}
+
+// Zigzag placement of codewords in two-column strips, from the bottom-right corner
+function drawCodewords(matrix: Matrix, codewords: number[]) {
+  const { size } = matrix;
+  let i = 0;
+  for (let right = size - 1; right >= 1; right -= 2) {
+    if (right === 6) right = 5;
+    for (let vertical = 0; vertical < size; vertical++) {
+      for (let j = 0; j < 2; j++) {
+        const x = right - j;
+        const upward = ((right + 1) & 2) === 0;
+        const y = upward ? size - 1 - vertical : vertical;
+        if (!matrix.reserved[y][x] && i < codewords.length * 8) {
+          matrix.modules[y][x] = ((codewords[i >>> 3] >>> (7 - (i & 7))) & 1) !== 0;
+          i++;
+        }
+      }
+    }
+  }
+}
+
+function applyMask(matrix: Matrix, mask: number) {
+  for (let y = 0; y < matrix.size; y++) {
+    for (let x = 0; x < matrix.size; x++) {
+      if (!matrix.reserved[y][x] && MASKS[mask](x, y)) matrix.modules[y][x] = !matrix.modules[y][x];
+    }
+  }
+}
+
+// Simplified mask penalty: long runs, 2x2 blocks and dark/light balance
+// (the finder-like pattern rule is skipped; any mask still scans fine)
+function penalty({ size, modules }: Matrix): number {
+  let result = 0;
+  for (let a = 0; a < size; a++) {
+    let rowRun = 1;
+    let columnRun = 1;
+    for (let b = 1; b < size; b++) {
+      rowRun = modules[a][b] === modules[a][b - 1] ? rowRun + 1 : 1;
+      if (rowRun === 5) result += 3;
+      else if (rowRun > 5) result++;
+      columnRun = modules[b][a] === modules[b - 1][a] ? columnRun + 1 : 1;
+      if (columnRun === 5) result += 3;
+      else if (columnRun > 5) result++;
+    }
+  }
+  let dark = 0;
+  for (let y = 0; y < size; y++) {
+    for (let x = 0; x < size; x++) {
+      if (modules[y][x]) dark++;
+      if (
+        x < size - 1 &&
+        y < size - 1 &&
+        modules[y][x] === modules[y][x + 1] &&
+        modules[y][x] === modules[y + 1][x] &&
+        modules[y][x] === modules[y + 1][x + 1]
+      ) {
+        result += 3;
+      }
+    }
+  }
+  const total = size * size;
+  return result + (Math.ceil(Math.abs(dark * 20 - total * 10) / total) - 1) * 10;
+}
+
+// Encodes text in the smallest version that fits; mask is chosen automatically unless given
+export function encodeQr(text: string, mask?: number): QrCode {
+  const bytes = new TextEncoder().encode(text);
+  let version = 1;
+  while (version <= 40 && 4 + (version <= 9 ? 8 : 16) + bytes.length * 8 > dataCodewords(version) * 8) version++;
+  if (version > 40) throw new Error(`Tekst jest za długi na kod QR (maks. ${QR_MAX_BYTES} bajtów)`);
+
+  const size = version * 4 + 17;
+  const codewords = addErrorCorrection(encodeData(bytes, version), version);
+
+  const build = (candidate: number) => {
+    const matrix = new Matrix(size);
+    drawFunctionPatterns(matrix, version);
+    drawCodewords(matrix, codewords);
+    applyMask(matrix, candidate);
+    drawFormatBits(matrix, candidate);
+    return matrix;
+  };
+
+  let best = build(mask ?? 0);
+  if (mask === undefined) {
+    let bestPenalty = penalty(best);
+    for (let candidate = 1; candidate < MASKS.length; candidate++) {
+      const matrix = build(candidate);
+      const score = penalty(matrix);
+      if (score < bestPenalty) {
+        best = matrix;
+        bestPenalty = score;
+      }
+    }
+  }
+  return { size, modules: best.modules };
+}
+
+// SVG path of the dark modules, one unit per module, with a 4-module quiet zone
+export function qrToSvgPath({ size, modules }: QrCode, margin: number = 4): string {
+  const parts: string[] = [];
+  for (let y = 0; y < size; y++) {
+    for (let x = 0; x < size; x++) {
+      if (modules[y][x]) parts.push(`M${x + margin},${y + margin}h1v1h-1z`);
+    }
+  }
+  return parts.join('');
+}
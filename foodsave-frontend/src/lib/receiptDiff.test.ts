@@ -0,0 +1,39 @@
+import { diffReceipts } from './receiptDiff';
+
+describe('receiptDiff', () => {
+  it('paruje pozycje po podobnej nazwie i wskazuje zmienione pola', () => {
+    const diff = diffReceipts(
+      [
+        { name: 'Mleko UHT 3,2%', quantity: 1, unit: 'szt', price: 3.49 },
+        { name: 'Banany', quantity: 1, unit: 'szt', price: 4.36 },
+        { name: 'Chleb razowy', quantity: 1, unit: 'szt.', price: 5.99 },
+      ],
+      [
+        { name: 'Chleb razowy', quantity: 1, unit: 'szt', price: 5.99 },
+        { name: 'Mleko UHT 3.2%', quantity: 1, unit: 'szt', price: 3.49 },
+        { name: 'Banany', quantity: 0.436, unit: 'kg', price: 9.99 },
+      ],
+    );
+
+    expect(diff.lines.map((line) => line.status)).toEqual(['changed', 'changed', 'same']);
+    expect(diff.lines[0]).toMatchObject({ fields: ['name'] });
+    expect(diff.lines[1]).toMatchObject({ fields: ['quantity', 'unit', 'price'] });
+  });
+
+  it('pokazuje pozycje usunięte i dodane oraz sumy', () => {
+    const diff = diffReceipts(
+      [
+        { name: 'Masło extra', quantity: 1, price: 7.99 },
+        { name: 'XYZ 123', quantity: 1, price: 2 },
+      ],
+      [
+        { name: 'Masło extra', quantity: 1, price: 7.99 },
+        { name: 'Jogurt naturalny', quantity: 2, price: 1.5 },
+      ],
+    );
+
+    expect(diff.lines.map((line) => line.status)).toEqual(['same', 'removed', 'added']);
+    expect(diff.totalBefore.minor).toBe(999);
+    expect(diff.totalAfter.minor).toBe(1099);
+  });
+});
@@ -0,0 +1,59 @@
+// Comparing a re-processed receipt with the saved one, line by line. Lines are paired by
+// name similarity, since a different OCR run rarely reads a name exactly the same way.
+
+import { MATCH_THRESHOLD, findBestMatch } from './fuzzy';
+import { Money, lineTotal, sumMoney } from './money';
+
+export interface ReceiptLine {
+  name: string;
+  quantity: number;
+  unit?: string | null;
+  price?: number | null; // unit price
+}
+
+export type ReceiptLineField = 'name' | 'quantity' | 'unit' | 'price';
+
+export type ReceiptLineDiff =
+  | { status: 'same'; before: ReceiptLine; after: ReceiptLine }
+  | { status: 'changed'; before: ReceiptLine; after: ReceiptLine; fields: ReceiptLineField[] }
+  | { status: 'removed'; before: ReceiptLine }
+  | { status: 'added'; after: ReceiptLine };
+
+export interface ReceiptDiff {
+  lines: ReceiptLineDiff[];
+  totalBefore: Money;
+  totalAfter: Money;
+}
+
+function normalizedUnit(unit?: string | null): string {
+  return (unit ?? '').trim().toLowerCase().replace(/\.$/, '') || 'szt';
+}
+
+function changedFields(before: ReceiptLine, after: ReceiptLine): ReceiptLineField[] {
+  const fields: ReceiptLineField[] = [];
+  if (before.name.trim() !== after.name.trim()) fields.push('name');
+  if (before.quantity !== after.quantity) fields.push('quantity');
+  if (normalizedUnit(before.unit) !== normalizedUnit(after.unit)) fields.push('unit');
+  if (lineTotal(before.price, 1).minor !== lineTotal(after.price, 1).minor) fields.push('price');
+  return fields;
+}
+
+function total(lines: ReceiptLine[]): Money {
+  return sumMoney(lines.map((line) => lineTotal(line.price, line.quantity)));
+}
+
+// Saved lines in their order, each with its counterpart from the new parse; new lines last
+export function diffReceipts(before: ReceiptLine[], after: ReceiptLine[]): ReceiptDiff {
+  const unmatched = [...after];
+  const lines: ReceiptLineDiff[] = before.map((line) => {
+    const match = findBestMatch(line.name, unmatched, (candidate) => candidate.name, MATCH_THRESHOLD);
+    if (!match) return { status: 'removed', before: line };
+    unmatched.splice(unmatched.indexOf(match), 1);
+    const fields = changedFields(line, match);
+    return fields.length
+      ? { status: 'changed', before: line, after: match, fields }
+      : { status: 'same', before: line, after: match };
+  });
+  for (const line of unmatched) lines.push({ status: 'added', after: line });
+  return { lines, totalBefore: total(before), totalAfter: total(after) };
+}
@@ -0,0 +1,22 @@
+import { parseReceiptArchivePath, receiptArchivePath, retentionCutoff } from './receiptImages';
+
+describe('receiptImages', () => {
+  it('układa skany według daty paragonu', () => {
+    expect(receiptArchivePath(42, '2024-05-12')).toBe('/__foodsave-receipts/2024/05/12/42');
+    expect(parseReceiptArchivePath('http://localhost:3000/__foodsave-receipts/2024/05/12/42')).toEqual({
+      tripId: 42,
+      date: '2024-05-12',
+    });
+  });
+
+  it('nie rozpoznaje kluczy spoza archiwum', () => {
+    expect(parseReceiptArchivePath('http://localhost:3000/__foodsave-receipts/42')).toBeUndefined();
+  });
+
+  it('liczy pierwszy zachowany dzień z okresu przechowywania', () => {
+    const now = new Date(2024, 4, 10, 12);
+    expect(retentionCutoff(12, now)).toBe('2023-05-10');
+    expect(retentionCutoff(3, now)).toBe('2024-02-10');
+    expect(retentionCutoff(0, now)).toBeUndefined();
+  });
+});
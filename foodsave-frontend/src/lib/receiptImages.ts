@@ -0,0 +1,122 @@
+// Original receipt scans, kept on this device so a saved receipt can be re-processed with
+// different OCR options. The backend stores only the parsed items, never the image.
+// Scans are archived by receipt date (/YYYY/MM/DD/<trip id>) and expire after the
+// retention period from the settings.
+
+import { localIsoDate } from './time';
+
+const CACHE_NAME = 'foodsave-receipts';
+const PREFIX = '/__foodsave-receipts/';
+
+export interface ReceiptArchiveStats {
+  count: number;
+  bytes: number;
+  oldest?: string; // YYYY-MM-DD
+}
+
+function hasCache(): boolean {
+  return typeof window !== 'undefined' && 'caches' in window;
+}
+
+export function receiptArchivePath(tripId: number, date: string): string {
+  const [year, month, day] = date.slice(0, 10).split('-');
+  return `${PREFIX}${year}/${month}/${day}/${tripId}`;
+}
+
+// Cache keys come back as full URLs
+export function parseReceiptArchivePath(url: string): { tripId: number; date: string } | undefined {
+  const match = /\/__foodsave-receipts\/(\d{4})\/(\d{2})\/(\d{2})\/(\d+)$/.exec(url);
+  if (!match) return undefined;
+  return { tripId: Number(match[4]), date: `${match[1]}-${match[2]}-${match[3]}` };
+}
+
+// First day still kept; 0 months keeps everything
+export function retentionCutoff(months: number, now: Date = new Date()): string | undefined {
+  if (months <= 0) return undefined;
+  return localIsoDate(new Date(now.getFullYear(), now.getMonth() - months, now.getDate()));
+}
+
+async function findEntry(cache: Cache, tripId: number): Promise<Request | undefined> {
+  const keys = await cache.keys();
+  return keys.find((request) => parseReceiptArchivePath(request.url)?.tripId === tripId);
+}
+
+// Removes scans of receipts dated before the cutoff; returns how many were removed
+export async function purgeReceiptImages(months: number, now: Date = new Date()): Promise<number> {
+  const cutoff = retentionCutoff(months, now);
+  if (!hasCache() || !cutoff) return 0;
+  try {
+    const cache = await caches.open(CACHE_NAME);
+    const expired = (await cache.keys()).filter((request) => {
+      const entry = parseReceiptArchivePath(request.url);
+      return !entry || entry.date < cutoff;
+    });
+    await Promise.all(expired.map((request) => cache.delete(request)));
+    return expired.length;
+  } catch {
+    return 0;
+  }
+}
+
+export async function saveReceiptImage(
+  tripId: number,
+  file: File,
+  date: string,
+  retentionMonths = 0,
+): Promise<void> {
+  if (!hasCache()) return;
+  try {
+    const cache = await caches.open(CACHE_NAME);
+    await cache.put(
+      receiptArchivePath(tripId, date),
+      new Response(file, {
+        headers: {
+          'Content-Type': file.type,
+          'Content-Length': String(file.size),
+          'X-File-Name': encodeURIComponent(file.name),
+        },
+      }),
+    );
+    await purgeReceiptImages(retentionMonths);
+  } catch {
+    // Pełny dysk albo tryb prywatny - ponowne przetworzenie po prostu nie będzie dostępne
+  }
+}
+
+export async function getReceiptImage(tripId: number): Promise<File | undefined> {
+  if (!hasCache()) return undefined;
+  try {
+    const cache = await caches.open(CACHE_NAME);
+    const request = await findEntry(cache, tripId);
+    const response = request && (await cache.match(request));
+    if (!response) return undefined;
+    const name = decodeURIComponent(response.headers.get('X-File-Name') || `paragon-${tripId}`);
+    const blob = await response.blob();
+    return new File([blob], name, { type: blob.type || response.headers.get('Content-Type') || '' });
+  } catch {
+    return undefined;
+  }
+}
+
+// Rozmiar z nagłówka zapisanego przy archiwizacji, żeby nie czytać wszystkich obrazów
+export async function receiptArchiveStats(): Promise<ReceiptArchiveStats> {
+  const stats: ReceiptArchiveStats = { count: 0, bytes: 0 };
+  if (!hasCache()) return stats;
+  try {
+    const cache = await caches.open(CACHE_NAME);
+    for (const request of await cache.keys()) {
+      const response = await cache.match(request);
+      const entry = parseReceiptArchivePath(request.url);
+      stats.count += 1;
+      stats.bytes += Number(response?.headers.get('Content-Length')) || (await response?.blob())?.size || 0;
+      if (entry && (!stats.oldest || entry.date < stats.oldest)) stats.oldest = entry.date;
+    }
+  } catch {
+    // Bez dostępu do pamięci podręcznej pokazujemy puste archiwum
+  }
+  return stats;
+}
+
+export async function clearReceiptImages(): Promise<void> {
+  if (hasCache()) await caches.delete(CACHE_NAME).catch(() => false);
+}
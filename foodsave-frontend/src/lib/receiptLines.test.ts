@@ -0,0 +1,88 @@
+import { lineTotal, money, sumMoney } from './money';
+import { mergeReceiptLines, productFromOcrItem, splitReceiptLine, suggestSplitNames } from './receiptLines';
+import type { Product } from '@/types/shopping';
+
+const product = (overrides: Partial<Product>): Product => ({
+  id: '1',
+  name: 'Produkt',
+  quantity: 1,
+  unit: 'szt.',
+  price: 0,
+  ...overrides,
+});
+
+const total = (products: Product[]) => sumMoney(products.map((p) => lineTotal(p.price, p.quantity))).minor;
+
+describe('receiptLines', () => {
+  it('zachowuje wagę w kilogramach i cenę za kg dla produktów na wagę', () => {
+    const bananas = productFromOcrItem(
+      { name: 'Banany', quantity: 0.436, unit: 'kg', unit_price: 9.99, total_price: 4.36, sold_by_weight: true },
+      0,
+    );
+
+    expect(bananas).toMatchObject({ quantity: 0.436, unit: 'kg', price: 9.99, sold_by_weight: true });
+    expect(lineTotal(bananas.price, bananas.quantity).minor).toBe(436);
+  });
+
+  it('liczy cenę jednostkową z ceny końcowej, gdy jej brakuje', () => {
+    expect(productFromOcrItem({ name: 'Jogurt', quantity: 4, total_price: 7.96 }, 1)).toMatchObject({
+      id: 'ocr-1',
+      unit: 'szt.',
+      price: 1.99,
+      sold_by_weight: false,
+    });
+  });
+
+  it('proponuje podział nazwy w połowie słów', () => {
+    expect(suggestSplitNames('MLEKO UHT CHLEB RAZOWY')).toEqual(['MLEKO UHT', 'CHLEB RAZOWY']);
+    expect(suggestSplitNames('MLEKO')).toEqual(['MLEKO', '']);
+  });
+
+  it('dzieli pozycję, zachowując sumę linii', () => {
+    const line = product({ name: 'MLEKO CHLEB', quantity: 3, price: 3.33 });
+    const parts = splitReceiptLine(line, ['MLEKO', 'CHLEB'], money(4.5));
+
+    expect(parts.map((p) => [p.name, p.quantity, p.price])).toEqual([
+      ['MLEKO', 1, 4.5],
+      ['CHLEB', 1, 5.49],
+    ]);
+    expect(parts[1].id).not.toBe(parts[0].id);
+    expect(total(parts)).toBe(total([line]));
+  });
+
+  it('dzieli pozycję ważoną na sztuki', () => {
+    const parts = splitReceiptLine(
+      product({ quantity: 0.5, unit: 'kg', price: 10, sold_by_weight: true }),
+      ['A', 'B'],
+      money(2),
+    );
+
+    expect(parts.map((p) => [p.quantity, p.unit, p.price, p.sold_by_weight])).toEqual([
+      [1, 'szt.', 2, false],
+      [1, 'szt.', 3, false],
+    ]);
+  });
+
+  it('nie pozwala przydzielić pierwszej części więcej niż cała pozycja', () => {
+    const parts = splitReceiptLine(product({ price: 2 }), ['A', 'B'], money(5));
+
+    expect(parts.map((p) => p.price)).toEqual([2, 0]);
+  });
+
+  it('łączy fragment nazwy z pozycją z ceną bez zmiany ceny jednostkowej', () => {
+    const merged = mergeReceiptLines(
+      product({ name: 'SER GOUDA', price: 0, unit: '' }),
+      product({ id: '2', name: 'PLASTRY', quantity: 0.25, unit: 'kg', price: 39.96 }),
+    );
+
+    expect(merged).toMatchObject({ id: '1', name: 'SER GOUDA PLASTRY', quantity: 0.25, unit: 'kg', price: 39.96 });
+  });
+
+  it('łączy dwie pozycje z cenami w jedną o łącznej kwocie', () => {
+    const parts = [product({ name: 'A', quantity: 2, price: 1.15 }), product({ id: '2', name: 'B', price: 0.7 })];
+    const merged = mergeReceiptLines(parts[0], parts[1]);
+
+    expect(merged).toMatchObject({ name: 'A B', quantity: 1, price: 3 });
+    expect(total([merged])).toBe(total(parts));
+  });
+});
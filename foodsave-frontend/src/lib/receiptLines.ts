@@ -0,0 +1,86 @@
+// Fixing OCR line mistakes in the receipt editor: one line holding two products gets split,
+// a product broken over two lines gets merged. Line totals always add up to what was there.
+
+import { Money, addMoney, lineTotal, money, subtractMoney, toAmount } from './money';
+import type { OCRItem, Product } from '@/types/shopping';
+
+// An analysed OCR item as an editable line; weighed items keep the weight in kilograms
+// and the per-kg price, so 0,436 kg of bananas doesn't become "0.436 szt."
+export function productFromOcrItem(item: OCRItem, index: number): Product {
+  const quantity = item.quantity || 1;
+  const price = item.unit_price ?? (item.total_price != null ? item.total_price / quantity : 0);
+  return {
+    id: `ocr-${index}`,
+    name: item.name,
+    quantity,
+    unit: item.sold_by_weight ? 'kg' : item.unit || 'szt.',
+    price: toAmount(money(price)),
+    category: item.category,
+    sold_by_weight: !!item.sold_by_weight,
+  };
+}
+
+// Where a mis-joined line most likely breaks: the middle word boundary ("MLEKO UHT CHLEB RAZOWY")
+export function suggestSplitNames(name: string): [string, string] {
+  const words = name.trim().split(/\s+/).filter(Boolean);
+  if (words.length < 2) return [name.trim(), ''];
+  const middle = Math.ceil(words.length / 2);
+  return [words.slice(0, middle).join(' '), words.slice(middle).join(' ')];
+}
+
+// Both parts become single items priced at their share of the line (a weight no longer
+// applies); the second part gets whatever is left, so nothing is lost to rounding
+export function splitReceiptLine(
+  product: Product,
+  names: [string, string],
+  firstTotal: Money,
+): [Product, Product] {
+  const total = lineTotal(product.price, product.quantity);
+  const first = { ...total, minor: Math.min(Math.max(firstTotal.minor, 0), total.minor) };
+  const unit = product.sold_by_weight ? 'szt.' : product.unit;
+  const piece = { ...product, quantity: 1, unit, sold_by_weight: false };
+  return [
+    { ...piece, name: names[0].trim(), price: toAmount(first) },
+    {
+      ...piece,
+      id: `${product.id}-2`,
+      name: names[1].trim(),
+      price: toAmount(subtractMoney(total, first)),
+    },
+  ];
+}
+
+// The fragment without a price is usually just the rest of the name, so the priced one keeps
+// its quantity and unit price; two priced fragments become one item for their combined total
+export function mergeReceiptLines(first: Product, second: Product): Product {
+  const firstTotal = lineTotal(first.price, first.quantity);
+  const secondTotal = lineTotal(second.price, second.quantity);
+  const name = `${first.name.trim()} ${second.name.trim()}`.trim();
+  const base = {
+    ...second,
+    ...first,
+    unit: first.unit || second.unit,
+    category: first.category || second.category,
+    expiry_date: first.expiry_date || second.expiry_date,
+  };
+
+  if (secondTotal.minor === 0) return { ...base, name };
+  if (firstTotal.minor === 0) {
+    return {
+      ...base,
+      name,
+      quantity: second.quantity,
+      price: second.price,
+      unit: second.unit || first.unit,
+      sold_by_weight: second.sold_by_weight,
+    };
+  }
+  return {
+    ...base,
+    name,
+    quantity: 1,
+    unit: first.sold_by_weight || second.sold_by_weight ? 'szt.' : base.unit,
+    price: toAmount(addMoney(firstTotal, secondTotal)),
+    sold_by_weight: false,
+  };
+}
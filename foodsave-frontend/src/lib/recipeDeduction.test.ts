@@ -0,0 +1,66 @@
+import { convertQuantity, planDeduction, recipeFromAnswer, remainingQuantity } from './recipeDeduction';
+
+const ingredient = (name: string, quantity: number, unit: string) => ({ id: name, name, quantity, unit });
+const pantryItem = (name: string, quantity?: number, unit?: string) => ({
+  id: name,
+  name,
+  quantity,
+  unit,
+  unified_category: 'Inne',
+});
+
+describe('recipeDeduction', () => {
+  it('przelicza jednostki o wspólnej podstawie', () => {
+    expect(convertQuantity(250, 'ml', 'l')).toBe(0.25);
+    expect(convertQuantity(1.5, 'kg', 'g')).toBe(1500);
+    expect(convertQuantity(2, 'szt.', undefined)).toBe(2);
+    expect(convertQuantity(2, 'łyżka', 'g')).toBeUndefined();
+  });
+
+  it('dopasowuje składniki do produktów ze spiżarni z zapisaną ilością', () => {
+    const plan = planDeduction(
+      [ingredient('mleko', 250, 'ml'), ingredient('jajka', 2, 'szt'), ingredient('cukier', 2, 'łyżka'), ingredient('sól', 1, 'g')],
+      [pantryItem('Mleko UHT 3,2%', 1, 'l'), pantryItem('Jajka L', 10), pantryItem('Cukier biały', 1, 'kg'), pantryItem('Sól')],
+    );
+
+    expect(plan.map((line) => [line.item?.name, line.amount, line.converted])).toEqual([
+      ['Mleko UHT 3,2%', 0.25, true],
+      ['Jajka L', 2, true],
+      ['Cukier biały', 0, false],
+      [undefined, 0, false],
+    ]);
+  });
+
+  it('nie schodzi z ilością poniżej zera', () => {
+    expect(remainingQuantity(pantryItem('Mleko', 0.3, 'l'), 0.1)).toBe(0.2);
+    expect(remainingQuantity(pantryItem('Mleko', 0.3, 'l'), 1)).toBe(0);
+  });
+
+  it('odczytuje składniki z odpowiedzi szefa kuchni', () => {
+    const recipe = recipeFromAnswer(
+      [
+        '## Omlet z serem',
+        '',
+        '**Składniki:**',
+        '- 3 jajka',
+        '- 200 ml mleka',
+        '- ser żółty – 50 g',
+        '- 1,5 łyżki masła (opcjonalnie)',
+        '- sól do smaku',
+        '',
+        '### Przygotowanie',
+        '1. Roztrzep jajka.',
+      ].join('\n'),
+    );
+
+    expect(recipe?.name).toBe('Omlet z serem');
+    expect(recipe?.ingredients.map((i) => [i.name, i.quantity, i.unit, i.optional])).toEqual([
+      ['jajka', 3, '', false],
+      ['mleka', 200, 'ml', false],
+      ['ser żółty', 50, 'g', false],
+      ['masła', 1.5, 'łyżki', true],
+      ['sól do smaku', 0, '', false],
+    ]);
+    expect(recipeFromAnswer('Cześć! W czym mogę pomóc?')).toBeUndefined();
+  });
+});
@@ -0,0 +1,122 @@
+// Deducting a cooked recipe's ingredients from the pantry. The plan is only a proposal;
+// the user reviews the amounts before anything is saved.
+
+import { MATCH_THRESHOLD, fuzzyScore } from './fuzzy';
+import type { Ingredient, PantryItem, Recipe } from '@/types/cooking';
+
+// Units sharing a base can be converted into each other
+const UNITS: Record<string, { base: string; factor: number }> = {
+  g: { base: 'g', factor: 1 },
+  dag: { base: 'g', factor: 10 },
+  kg: { base: 'g', factor: 1000 },
+  ml: { base: 'ml', factor: 1 },
+  l: { base: 'ml', factor: 1000 },
+  szt: { base: 'szt', factor: 1 },
+};
+
+function unitInfo(unit?: string) {
+  const key = (unit ?? '').trim().toLowerCase().replace(/\.$/, '');
+  return UNITS[key || 'szt'];
+}
+
+// Quantity in the target unit; undefined when the units can't be compared ("łyżka" vs "g")
+export function convertQuantity(quantity: number, from?: string, to?: string): number | undefined {
+  const source = unitInfo(from);
+  const target = unitInfo(to);
+  if (!source || !target || source.base !== target.base) return undefined;
+  return (quantity * source.factor) / target.factor;
+}
+
+export interface DeductionLine {
+  ingredient: Ingredient;
+  item?: PantryItem; // matching pantry item with a tracked quantity
+  amount: number; // in the pantry item's unit
+  converted: boolean; // false when the amount couldn't be derived from the recipe
+}
+
+// Recipes say "mleko", the pantry holds "Mleko UHT 3,2%", so only the ingredient's words
+// have to match (as when checking off receipt items)
+function matchingItem(name: string, pantry: PantryItem[]): PantryItem | undefined {
+  let best: PantryItem | undefined;
+  let bestScore = MATCH_THRESHOLD;
+  for (const item of pantry) {
+    const score = fuzzyScore(name, item.name);
+    if (score >= bestScore) {
+      best = item;
+      bestScore = score;
+    }
+  }
+  return best;
+}
+
+export function planDeduction(ingredients: Ingredient[], pantry: PantryItem[]): DeductionLine[] {
+  const tracked = pantry.filter((item) => item.quantity != null);
+  return ingredients.map((ingredient) => {
+    const item = matchingItem(ingredient.name, tracked);
+    const amount = item ? convertQuantity(ingredient.quantity, ingredient.unit, item.unit) : undefined;
+    return { ingredient, item, amount: amount ?? 0, converted: amount !== undefined };
+  });
+}
+
+// Never below zero; rounded so 0.1 + 0.2 style leftovers don't show up in the pantry
+export function remainingQuantity(item: PantryItem, amount: number): number {
+  return Math.max(0, Math.round(((item.quantity ?? 0) - amount) * 1000) / 1000);
+}
+
+// The chef agent answers in Markdown, not with a structured recipe: "## Omlet", a
+// "Składniki" heading and a list like "- 200 ml mleka" or "- jajka – 2 szt."
+const INGREDIENTS_HEADING = /^\W*(składniki|ingredients)\b/i;
+const LIST_ITEM = /^\s*(?:[-*•]|\d+[.)])\s+(.+)$/;
+const AMOUNT = '(\\d+(?:[.,]\\d+)?)\\s*(kg|dag|g|ml|l|szt\\.?|łyż(?:ka|ki|ek)|łyżecz(?:ka|ki|ek)|szklan(?:ka|ki|ek))?';
+const LEADING_AMOUNT = new RegExp(`^${AMOUNT}\\s+(.+)$`, 'i');
+const TRAILING_AMOUNT = new RegExp(`^(.+?)\\s*[-–:]\\s*${AMOUNT}$`, 'i');
+
+function parseIngredient(text: string, index: number): Ingredient {
+  const plain = text.replace(/[*_`]/g, '').trim();
+  const optional = /\(?opcjonalnie\)?/i.test(plain);
+  const line = plain.replace(/\(?opcjonalnie\)?/i, '').replace(/\s*\([^)]*\)\s*$/, '').trim();
+  const leading = LEADING_AMOUNT.exec(line);
+  const trailing = leading ? null : TRAILING_AMOUNT.exec(line);
+  // Bez ilości ("sól do smaku") nic nie odejmujemy - użytkownik może wpisać ilość ręcznie
+  const [quantity, unit, name] = leading
+    ? [leading[1], leading[2], leading[3]]
+    : trailing
+      ? [trailing[2], trailing[3], trailing[1]]
+      : ['0', '', line];
+  return {
+    id: `ingredient-${index}`,
+    name: name.trim(),
+    quantity: Number(quantity.replace(',', '.')),
+    unit: unit ?? '',
+    optional,
+  };
+}
+
+export function recipeFromAnswer(markdown: string): Recipe | undefined {
+  const lines = markdown.split('\n');
+  const start = lines.findIndex((line) => INGREDIENTS_HEADING.test(line.replace(/^#+\s*/, '')));
+  if (start < 0) return undefined;
+
+  const ingredients: Ingredient[] = [];
+  for (const line of lines.slice(start + 1)) {
+    const item = LIST_ITEM.exec(line);
+    if (item) ingredients.push(parseIngredient(item[1], ingredients.length));
+    // Lista kończy się na pierwszej linii, która nie jest jej elementem
+    else if (line.trim() && ingredients.length > 0) break;
+  }
+  if (ingredients.length === 0) return undefined;
+
+  const heading = lines.find((line) => /^#{1,6}\s+\S/.test(line) && !INGREDIENTS_HEADING.test(line.replace(/^#+\s*/, '')));
+  const name = heading ? heading.replace(/^#+\s+/, '').replace(/[*_`]/g, '').trim() : 'Przepis';
+  return {
+    id: `answer-${name}`,
+    name,
+    description: '',
+    ingredients,
+    instructions: [],
+    prepTime: 0,
+    cookTime: 0,
+    servings: 1,
+    difficulty: 'easy',
+  };
+}
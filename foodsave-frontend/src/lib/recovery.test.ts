@@ -0,0 +1,43 @@
+import {
+  HEARTBEAT_MS,
+  beginSession,
+  discardRecovery,
+  endSession,
+  getRecoveryData,
+  hasRecoverableData,
+  saveChatDraft,
+  saveReceiptDraft,
+} from './recovery';
+
+describe('recovery', () => {
+  beforeEach(() => {
+    window.localStorage.clear();
+  });
+
+  it('rozpoznaje awarię po nieaktualnym znaczniku sesji', () => {
+    expect(beginSession(0)).toBe('fresh');
+    expect(beginSession(HEARTBEAT_MS)).toBe('shared');
+    expect(beginSession(10 * HEARTBEAT_MS)).toBe('crashed');
+
+    endSession();
+    expect(beginSession(20 * HEARTBEAT_MS)).toBe('fresh');
+  });
+
+  it('przechowuje szkice do czasu wysłania lub zapisu', () => {
+    saveChatDraft('general', 'Co na obiad z');
+    saveReceiptDraft({ ocrText: 'LIDL', products: [{ name: 'Mleko' } as never], meta: { store: 'Lidl' } });
+
+    const data = getRecoveryData();
+    expect(data.chatDrafts).toEqual({ general: 'Co na obiad z' });
+    expect(data.receipt?.meta?.store).toBe('Lidl');
+    expect(hasRecoverableData(data)).toBe(true);
+
+    saveChatDraft('general', '  ');
+    saveReceiptDraft(null);
+    expect(hasRecoverableData(getRecoveryData())).toBe(false);
+
+    saveChatDraft('general', 'x');
+    discardRecovery();
+    expect(getRecoveryData()).toEqual({ chatDrafts: {}, receipt: undefined });
+  });
+});
@@ -0,0 +1,73 @@
+// Crash recovery: work in progress (unsent chat drafts, a scanned receipt not yet saved)
+// is written to localStorage as it changes. A session marker with a heartbeat tells a
+// clean exit (marker removed on pagehide) from a crash or killed browser; only then is
+// restoring offered.
+
+import { profileStorageKey } from './profiles';
+import { readJson, writeJson } from './storage';
+import { Product } from '@/types/shopping';
+
+const SESSION_KEY = 'foodsave.session';
+const RECOVERY_KEY = 'foodsave.recovery';
+export const HEARTBEAT_MS = 15 * 1000;
+// Świeży heartbeat oznacza, że inna karta wciąż działa - to nie awaria
+const STALE_AFTER_MS = 2 * HEARTBEAT_MS;
+
+export interface ReceiptDraft {
+  ocrText: string | null;
+  products: Product[];
+  meta: { store?: string; date?: string; total?: number; manual?: boolean } | null;
+}
+
+export interface RecoveryData {
+  chatDrafts: Record<string, string>;
+  receipt?: ReceiptDraft;
+}
+
+export function getRecoveryData(): RecoveryData {
+  const data = readJson<Partial<RecoveryData>>(profileStorageKey(RECOVERY_KEY), {});
+  return { chatDrafts: data.chatDrafts ?? {}, receipt: data.receipt };
+}
+
+function updateRecovery(change: (data: RecoveryData) => RecoveryData) {
+  writeJson(profileStorageKey(RECOVERY_KEY), change(getRecoveryData()));
+}
+
+export function saveChatDraft(context: string, text: string): void {
+  updateRecovery((data) => {
+    const chatDrafts = { ...data.chatDrafts };
+    if (text.trim()) chatDrafts[context] = text;
+    else delete chatDrafts[context];
+    return { ...data, chatDrafts };
+  });
+}
+
+export function saveReceiptDraft(draft: ReceiptDraft | null): void {
+  updateRecovery((data) => ({ ...data, receipt: draft && draft.products.length > 0 ? draft : undefined }));
+}
+
+export function hasRecoverableData(data: RecoveryData): boolean {
+  return Object.keys(data.chatDrafts).length > 0 || Boolean(data.receipt);
+}
+
+export function discardRecovery(): void {
+  writeJson(profileStorageKey(RECOVERY_KEY), {});
+}
+
+// fresh: poprzednia sesja zamknięta poprawnie, shared: działa inna karta, crashed: awaria
+export type SessionStart = 'fresh' | 'shared' | 'crashed';
+
+export function beginSession(now = Date.now()): SessionStart {
+  const previous = readJson<{ heartbeat?: number } | null>(SESSION_KEY, null);
+  heartbeat(now);
+  if (typeof previous?.heartbeat !== 'number') return 'fresh';
+  return now - previous.heartbeat > STALE_AFTER_MS ? 'crashed' : 'shared';
+}
+
+export function heartbeat(now = Date.now()): void {
+  writeJson(SESSION_KEY, { heartbeat: now });
+}
+
+export function endSession(): void {
+  window.localStorage.removeItem(SESSION_KEY);
+}
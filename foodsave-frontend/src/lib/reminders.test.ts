@@ -0,0 +1,69 @@
+import {
+  Reminder,
+  addReminder,
+  getReminders,
+  isOverdue,
+  nextOccurrence,
+  remindersForToday,
+  toggleReminder,
+} from './reminders';
+
+const reminder = (id: string, dueAt: Date, done = false): Reminder => ({
+  id,
+  title: id,
+  dueAt: dueAt.toISOString(),
+  done,
+  repeat: 'none',
+});
+
+describe('reminders', () => {
+  const now = new Date(2024, 4, 10, 12, 0);
+
+  it('pokazuje dzisiejsze i zaległe przypomnienia, posortowane po terminie', () => {
+    const list = [
+      reminder('wieczór', new Date(2024, 4, 10, 20, 0)),
+      reminder('jutro', new Date(2024, 4, 11, 9, 0)),
+      reminder('wczoraj', new Date(2024, 4, 9, 9, 0)),
+      reminder('wczoraj-zrobione', new Date(2024, 4, 9, 10, 0), true),
+      reminder('rano', new Date(2024, 4, 10, 8, 0), true),
+    ];
+
+    expect(remindersForToday(list, now).map((r) => r.id)).toEqual(['wczoraj', 'rano', 'wieczór']);
+  });
+
+  it('oznacza jako zaległe tylko niewykonane po terminie', () => {
+    expect(isOverdue(reminder('a', new Date(2024, 4, 10, 8, 0)), now)).toBe(true);
+    expect(isOverdue(reminder('b', new Date(2024, 4, 10, 8, 0), true), now)).toBe(false);
+    expect(isOverdue(reminder('c', new Date(2024, 4, 10, 13, 0)), now)).toBe(false);
+  });
+
+  it('wylicza kolejne wystąpienie przypomnień cyklicznych', () => {
+    const sunday = new Date(2024, 4, 5, 18, 0);
+    expect(nextOccurrence(sunday, 'weekly', now)).toEqual(new Date(2024, 4, 12, 18, 0));
+    expect(nextOccurrence(sunday, 'daily', now)).toEqual(new Date(2024, 4, 10, 18, 0));
+    expect(nextOccurrence(new Date(2024, 0, 31, 9, 0), 'monthly')).toEqual(new Date(2024, 1, 29, 9, 0));
+    expect(nextOccurrence(sunday, 'none', now)).toBeNull();
+  });
+
+  it('odhaczenie cyklicznego przypomnienia przesuwa je na następny termin', () => {
+    window.localStorage.clear();
+    const milk = addReminder('Sprawdź datę ważności mleka', new Date(2024, 4, 5, 18, 0), 'weekly');
+
+    toggleReminder(milk.id, now);
+
+    const [updated] = getReminders();
+    expect(updated.done).toBe(false);
+    expect(new Date(updated.dueAt)).toEqual(new Date(2024, 4, 12, 18, 0));
+  });
+
+  it('miesięczne przypomnienie wraca do swojego dnia po krótszym miesiącu', () => {
+    window.localStorage.clear();
+    const rent = addReminder('Czynsz', new Date(2023, 0, 31, 9, 0), 'monthly');
+
+    toggleReminder(rent.id, new Date(2023, 0, 31, 10, 0));
+    expect(new Date(getReminders()[0].dueAt)).toEqual(new Date(2023, 1, 28, 9, 0));
+
+    toggleReminder(rent.id, new Date(2023, 1, 28, 10, 0));
+    expect(new Date(getReminders()[0].dueAt)).toEqual(new Date(2023, 2, 31, 9, 0));
+  });
+});
@@ -0,0 +1,127 @@
+// Reminders persisted locally and shown on the dashboard
+
+import { v4 as uuidv4 } from 'uuid';
+import { readJson, subscribeStorage, writeJson } from './storage';
+import type { MessageKey } from './i18n';
+
+const STORAGE_KEY = 'foodsave.reminders';
+const CHANGE_EVENT = 'foodsave:reminders';
+
+export type ReminderRepeat = 'none' | 'daily' | 'weekly' | 'monthly';
+
+export const REPEAT_LABELS: Record<ReminderRepeat, MessageKey> = {
+  none: 'reminders.repeat.none',
+  daily: 'reminders.repeat.daily',
+  weekly: 'reminders.repeat.weekly',
+  monthly: 'reminders.repeat.monthly',
+};
+
+export interface Reminder {
+  id: string;
+  title: string;
+  dueAt: string; // ISO datetime of the next occurrence
+  done: boolean;
+  repeat: ReminderRepeat;
+  anchorDay?: number; // day of the month set by the user, so monthly ones return to it after a short month
+}
+
+export function getReminders(): Reminder[] {
+  // Reminders saved before recurrence existed have no `repeat`
+  return readJson<Reminder[]>(STORAGE_KEY, []).map((r) => ({ ...r, repeat: r.repeat ?? 'none' }));
+}
+
+function save(reminders: Reminder[]) {
+  writeJson(STORAGE_KEY, reminders);
+  window.dispatchEvent(new CustomEvent(CHANGE_EVENT));
+}
+
+// Next occurrence strictly after `after`; monthly keeps `anchorDay`, clamped to the month length
+export function nextOccurrence(
+  dueAt: Date,
+  repeat: ReminderRepeat,
+  after: Date = dueAt,
+  anchorDay: number = dueAt.getDate(),
+): Date | null {
+  if (repeat === 'none') return null;
+  const day = dueAt.getDate();
+  let next = new Date(dueAt);
+  for (let step = 1; next <= after; step++) {
+    if (repeat === 'daily') {
+      next = new Date(dueAt.getFullYear(), dueAt.getMonth(), day + step, dueAt.getHours(), dueAt.getMinutes());
+    } else if (repeat === 'weekly') {
+      next = new Date(dueAt.getFullYear(), dueAt.getMonth(), day + 7 * step, dueAt.getHours(), dueAt.getMinutes());
+    } else {
+      const month = dueAt.getMonth() + step;
+      const lastDay = new Date(dueAt.getFullYear(), month + 1, 0).getDate();
+      next = new Date(dueAt.getFullYear(), month, Math.min(anchorDay, lastDay), dueAt.getHours(), dueAt.getMinutes());
+    }
+  }
+  return next;
+}
+
+export function addReminder(title: string, dueAt: Date, repeat: ReminderRepeat = 'none'): Reminder {
+  const reminder: Reminder = {
+    id: uuidv4(),
+    title: title.trim(),
+    dueAt: dueAt.toISOString(),
+    done: false,
+    repeat,
+    anchorDay: dueAt.getDate(),
+  };
+  save([...getReminders(), reminder]);
+  return reminder;
+}
+
+export function updateReminder(id: string, changes: Partial<Omit<Reminder, 'id'>>): void {
+  const anchor = changes.dueAt ? { anchorDay: new Date(changes.dueAt).getDate() } : {};
+  save(getReminders().map((r) => (r.id === id ? { ...r, ...changes, ...anchor } : r)));
+}
+
+// Completing a recurring reminder moves it to its next occurrence instead of marking it done
+export function toggleReminder(id: string, now: Date = new Date()): void {
+  save(
+    getReminders().map((r) => {
+      if (r.id !== id) return r;
+      const next = r.done ? null : nextOccurrence(new Date(r.dueAt), r.repeat, now, r.anchorDay);
+      return next ? { ...r, dueAt: next.toISOString() } : { ...r, done: !r.done };
+    }),
+  );
+}
+
+// Returns the removed reminder so the caller can offer Undo
+export function removeReminder(id: string): Reminder | undefined {
+  const reminders = getReminders();
+  save(reminders.filter((r) => r.id !== id));
+  return reminders.find((r) => r.id === id);
+}
+
+export function restoreReminder(reminder: Reminder): void {
+  const reminders = getReminders();
+  if (!reminders.some((r) => r.id === reminder.id)) save([...reminders, reminder]);
+}
+
+// Reminders whose time has come and that should trigger a notification
+export function dueReminders(reminders: Reminder[], now: Date = new Date()): Reminder[] {
+  return reminders.filter((r) => !r.done && new Date(r.dueAt).getTime() <= now.getTime());
+}
+
+export function isOverdue(reminder: Reminder, now: Date = new Date()): boolean {
+  return !reminder.done && new Date(reminder.dueAt).getTime() < now.getTime();
+}
+
+// Reminders due today plus overdue ones from earlier days, sorted by due time
+export function remindersForToday(reminders: Reminder[], now: Date = new Date()): Reminder[] {
+  const endOfDay = new Date(now.getFullYear(), now.getMonth(), now.getDate() + 1);
+  const startOfDay = new Date(now.getFullYear(), now.getMonth(), now.getDate());
+  return reminders
+    .filter((r) => {
+      const due = new Date(r.dueAt);
+      if (due >= endOfDay) return false;
+      return due >= startOfDay || !r.done;
+    })
+    .sort((a, b) => a.dueAt.localeCompare(b.dueAt));
+}
+
+export function subscribeReminders(listener: () => void): () => void {
+  return subscribeStorage([STORAGE_KEY], CHANGE_EVENT, listener);
+}
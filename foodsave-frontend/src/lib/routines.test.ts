@@ -0,0 +1,39 @@
+import { addRoutine, dueRoutines, getRoutines, markRoutineRun, nextRoutineRun, updateRoutine } from './routines';
+
+describe('routines', () => {
+  // Piątek, 10 maja 2024, 12:00
+  const now = new Date(2024, 4, 10, 12, 0);
+  const sundayEvening = { days: [0], time: '18:00' };
+
+  beforeEach(() => window.localStorage.clear());
+
+  it('wylicza najbliższe uruchomienie według dni tygodnia i godziny', () => {
+    expect(nextRoutineRun(sundayEvening, now)).toEqual(new Date(2024, 4, 12, 18, 0));
+    expect(nextRoutineRun({ days: [5], time: '18:00' }, now)).toEqual(new Date(2024, 4, 10, 18, 0));
+    // Dzisiejsza godzina już minęła - za tydzień
+    expect(nextRoutineRun({ days: [5], time: '08:00' }, now)).toEqual(new Date(2024, 4, 17, 8, 0));
+    expect(nextRoutineRun({ days: [], time: '08:00' }, now)).toBeNull();
+  });
+
+  it('po uruchomieniu planuje kolejne wykonanie', () => {
+    const routine = addRoutine({ name: 'Plan tygodnia', prompt: 'Zaplanuj posiłki', attachments: ['pantry'], ...sundayEvening }, now);
+    const sunday = new Date(2024, 4, 12, 18, 1);
+
+    expect(dueRoutines(getRoutines(), now)).toEqual([]);
+    expect(dueRoutines(getRoutines(), sunday).map((r) => r.id)).toEqual([routine.id]);
+
+    markRoutineRun(routine.id, 's1', sunday);
+    const [after] = getRoutines();
+    expect(after.nextRunAt).toBe(new Date(2024, 4, 19, 18, 0).toISOString());
+    expect(after.lastSessionId).toBe('s1');
+    expect(dueRoutines(getRoutines(), sunday)).toEqual([]);
+  });
+
+  it('wyłączona rutyna nie jest planowana', () => {
+    const routine = addRoutine({ name: 'Zakupy', prompt: 'Co kupić?', attachments: [], days: [1], time: '09:00' }, now);
+    updateRoutine(routine.id, { enabled: false }, now);
+
+    expect(getRoutines()[0].nextRunAt).toBeNull();
+    expect(dueRoutines(getRoutines(), new Date(2024, 5, 1))).toEqual([]);
+  });
+});
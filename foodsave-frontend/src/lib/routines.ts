@@ -0,0 +1,94 @@
+// Assistant routines: saved prompts sent to the assistant on a weekly schedule
+// (e.g. Sunday 18:00 "zaplanuj posiłki na tydzień"). The leader tab runs the due ones.
+// Each profile has its own routines; their results go to that profile's saved conversations.
+
+import { v4 as uuidv4 } from 'uuid';
+import { profileStorageKey } from './profiles';
+import { readJson, subscribeStorage, writeJson } from './storage';
+import type { ContextAttachmentKind } from '@/types/chat';
+
+const STORAGE_KEY = 'foodsave.routines';
+const CHANGE_EVENT = 'foodsave:routines';
+
+// Tag of the saved conversations with routine results
+export const ROUTINE_TAG = 'Rutyny';
+
+export interface RoutineSchedule {
+  days: number[]; // Date.getDay(): 0 = niedziela
+  time: string; // "HH:MM", local time
+}
+
+export interface Routine extends RoutineSchedule {
+  id: string;
+  name: string;
+  prompt: string;
+  attachments: ContextAttachmentKind[];
+  enabled: boolean;
+  nextRunAt: string | null; // ISO datetime; null when no day is selected
+  lastRunAt?: string;
+  lastSessionId?: string; // saved conversation with the latest result
+}
+
+export type RoutineValues = Pick<Routine, 'name' | 'prompt' | 'attachments' | 'days' | 'time'>;
+
+export function getRoutines(): Routine[] {
+  return readJson<Routine[]>(profileStorageKey(STORAGE_KEY), []);
+}
+
+function save(routines: Routine[]) {
+  writeJson(profileStorageKey(STORAGE_KEY), routines);
+  window.dispatchEvent(new CustomEvent(CHANGE_EVENT));
+}
+
+// First scheduled time strictly after `after`
+export function nextRoutineRun({ days, time }: RoutineSchedule, after: Date): Date | null {
+  const [hours, minutes] = time.split(':').map(Number);
+  for (let offset = 0; offset <= 7; offset++) {
+    const candidate = new Date(after.getFullYear(), after.getMonth(), after.getDate() + offset, hours, minutes);
+    if (days.includes(candidate.getDay()) && candidate > after) return candidate;
+  }
+  return null;
+}
+
+function scheduled(routine: Routine, now: Date): Routine {
+  const next = routine.enabled ? nextRoutineRun(routine, now) : null;
+  return { ...routine, nextRunAt: next ? next.toISOString() : null };
+}
+
+export function addRoutine(values: RoutineValues, now: Date = new Date()): Routine {
+  const routine = scheduled({ ...values, id: uuidv4(), name: values.name.trim(), enabled: true, nextRunAt: null }, now);
+  save([...getRoutines(), routine]);
+  return routine;
+}
+
+// Any change (schedule, enabling) reschedules the routine from now
+export function updateRoutine(id: string, changes: Partial<Omit<Routine, 'id'>>, now: Date = new Date()): void {
+  save(getRoutines().map((r) => (r.id === id ? scheduled({ ...r, ...changes }, now) : r)));
+}
+
+export function removeRoutine(id: string): void {
+  save(getRoutines().filter((r) => r.id !== id));
+}
+
+// "Uruchom teraz": the routine becomes due and the leader tab picks it up
+export function runRoutineNow(id: string, now: Date = new Date()): void {
+  save(getRoutines().map((r) => (r.id === id ? { ...r, nextRunAt: now.toISOString() } : r)));
+}
+
+// Routines whose time has come; one missed while the app was closed runs once on the next start
+export function dueRoutines(routines: Routine[], now: Date = new Date()): Routine[] {
+  return routines.filter((r) => r.nextRunAt !== null && new Date(r.nextRunAt).getTime() <= now.getTime());
+}
+
+// Called once the answer is saved; a failed run stays due and is retried
+export function markRoutineRun(id: string, sessionId: string, now: Date = new Date()): void {
+  save(
+    getRoutines().map((r) =>
+      r.id === id ? { ...scheduled(r, now), lastRunAt: now.toISOString(), lastSessionId: sessionId } : r,
+    ),
+  );
+}
+
+export function subscribeRoutines(listener: () => void): () => void {
+  return subscribeStorage(() => [profileStorageKey(STORAGE_KEY)], CHANGE_EVENT, listener);
+}
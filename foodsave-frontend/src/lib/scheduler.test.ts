@@ -0,0 +1,42 @@
+import { isJobDue, lastJobRuns, registerJob, runDueJobs, runJobNow } from './scheduler';
+
+describe('scheduler', () => {
+  beforeEach(() => {
+    window.localStorage.clear();
+  });
+
+  it('uruchamia zadanie dopiero po upływie interwału', async () => {
+    const run = jest.fn();
+    const unregister = registerJob({ id: 'pantry', intervalMs: 60_000, run });
+
+    await runDueJobs(1_000);
+    await runDueJobs(30_000);
+    expect(run).toHaveBeenCalledTimes(1);
+    expect(lastJobRuns()).toEqual({ pantry: 1_000 });
+
+    await runDueJobs(61_000);
+    expect(run).toHaveBeenCalledTimes(2);
+
+    await runJobNow('pantry', 62_000);
+    expect(run).toHaveBeenCalledTimes(3);
+    unregister();
+  });
+
+  it('błąd zadania nie zatrzymuje pozostałych', async () => {
+    const ok = jest.fn();
+    const unregisterFailing = registerJob({ id: 'sync', intervalMs: 1000, run: () => Promise.reject(new Error('offline')) });
+    const unregisterOk = registerJob({ id: 'reminders', intervalMs: 1000, run: ok });
+
+    await expect(runDueJobs(5_000)).resolves.toBeUndefined();
+    expect(ok).toHaveBeenCalledTimes(1);
+    unregisterFailing();
+    unregisterOk();
+  });
+
+  it('traktuje czas z przyszłości (zmiana zegara) jako termin', () => {
+    const job = { id: 'x', intervalMs: 1000, run: () => undefined };
+    expect(isJobDue(job, undefined, 0)).toBe(true);
+    expect(isJobDue(job, 5_000, 1_000)).toBe(true);
+    expect(isJobDue(job, 500, 1_000)).toBe(false);
+  });
+});
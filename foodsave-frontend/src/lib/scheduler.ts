@@ -0,0 +1,66 @@
+// Periodic background jobs (pantry expiry checks, reminders, data refresh). Jobs run in the
+// leader tab only; last run times are kept in localStorage so a reload doesn't re-run
+// everything at once.
+
+import { readJson, writeJson } from './storage';
+import { createLogger } from './logger';
+
+const LAST_RUN_KEY = 'foodsave.scheduler.lastRun';
+const TICK_MS = 30 * 1000;
+const log = createLogger('scheduler');
+
+export interface ScheduledJob {
+  id: string;
+  intervalMs: number;
+  run: () => void | Promise<void>;
+}
+
+const jobs = new Map<string, ScheduledJob>();
+const running = new Set<string>();
+
+export function registerJob(job: ScheduledJob): () => void {
+  jobs.set(job.id, job);
+  return () => {
+    if (jobs.get(job.id) === job) jobs.delete(job.id);
+  };
+}
+
+export function isJobDue(job: ScheduledJob, lastRun: number | undefined, now: number): boolean {
+  return lastRun === undefined || now - lastRun >= job.intervalMs || lastRun > now;
+}
+
+export function lastJobRuns(): Record<string, number> {
+  return readJson<Record<string, number>>(LAST_RUN_KEY, {});
+}
+
+async function runJob(job: ScheduledJob, now: number): Promise<void> {
+  // Długie zadanie nie jest uruchamiane drugi raz, zanim się skończy
+  if (running.has(job.id)) return;
+  running.add(job.id);
+  writeJson(LAST_RUN_KEY, { ...lastJobRuns(), [job.id]: now });
+  try {
+    await job.run();
+  } catch (error) {
+    log.error(`Job "${job.id}" failed`, error);
+  } finally {
+    running.delete(job.id);
+  }
+}
+
+export async function runDueJobs(now = Date.now()): Promise<void> {
+  const lastRuns = lastJobRuns();
+  const due = [...jobs.values()].filter((job) => isJobDue(job, lastRuns[job.id], now));
+  await Promise.all(due.map((job) => runJob(job, now)));
+}
+
+/** Runs a job right away (e.g. after its data changed), regardless of its interval. */
+export async function runJobNow(id: string, now = Date.now()): Promise<void> {
+  const job = jobs.get(id);
+  if (job) await runJob(job, now);
+}
+
+export function startScheduler(): () => void {
+  void runDueJobs();
+  const timer = window.setInterval(() => void runDueJobs(), TICK_MS);
+  return () => window.clearInterval(timer);
+}
@@ -0,0 +1,25 @@
+import { DEFAULT_SETTINGS, SETTINGS_VERSION, loadSettings, migrateSettings } from './settings';
+
+describe('settings', () => {
+  beforeEach(() => {
+    window.localStorage.clear();
+  });
+
+  it('migruje ustawienia bez wersji, uzupełniając paletę', () => {
+    const migrated = migrateSettings({ userName: 'Ola', palette: { accent: '#16a34a' } });
+
+    expect(migrated.version).toBe(SETTINGS_VERSION);
+    expect(migrated.userName).toBe('Ola');
+    expect(migrated.palette).toEqual({ ...DEFAULT_SETTINGS.palette, accent: '#16a34a' });
+  });
+
+  it('zachowuje preferencje przy wczytywaniu starego zapisu i zapisuje nową wersję', () => {
+    window.localStorage.setItem('foodsave.settings', JSON.stringify({ userName: 'Ola', theme: 'dark' }));
+
+    const settings = loadSettings();
+
+    expect(settings.userName).toBe('Ola');
+    expect(settings.theme).toBe('dark');
+    expect(JSON.parse(window.localStorage.getItem('foodsave.settings')!).version).toBe(SETTINGS_VERSION);
+  });
+});
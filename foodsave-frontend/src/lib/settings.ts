@@ -0,0 +1,136 @@
+// User preferences persisted in localStorage
+
+import { profileStorageKey } from './profiles';
+import { readJson, subscribeStorage, writeJson } from './storage';
+import { AccessibilityPreference, DEFAULT_PALETTE, ThemeMode, ThemePalette } from './theme';
+import { Locale } from './i18n';
+import { ShortcutBindings } from './shortcuts';
+import type { NotificationCategory } from './notifications';
+import type { LogLevelName } from './logger';
+import type { OcrLanguage } from '@/types/shopping';
+
+const STORAGE_KEY = 'foodsave.settings';
+const CHANGE_EVENT = 'foodsave:settings';
+
+// Bump together with a new entry in MIGRATIONS whenever the stored shape changes
+export const SETTINGS_VERSION = 1;
+
+// Layout of the pantry: detailed cards, compact grid, dense table or expiry timeline
+export type PantryView = 'cards' | 'grid' | 'table' | 'timeline';
+
+export interface SavedLocation {
+  label: string; // np. "Dom", "Praca"
+  city: string;
+}
+
+export interface AppSettings {
+  version: number;
+  language: Locale;
+  userName: string;
+  weatherLocations: SavedLocation[];
+  activeWeatherLocation: string; // city of the selected saved location
+  calendarIcsUrl: string; // optional public ICS feed shown next to reminders
+  backendUrl: string; // empty = NEXT_PUBLIC_API_BASE_URL
+  theme: ThemeMode;
+  palette: ThemePalette;
+  uiScale: number; // percent, 90–150
+  highContrast: AccessibilityPreference;
+  reducedMotion: AccessibilityPreference;
+  shortcuts: ShortcutBindings; // only bindings changed by the user
+  restoreLastPage: boolean;
+  notificationsEnabled: boolean;
+  notificationCategories: Partial<Record<NotificationCategory, boolean>>; // false = muted
+  logLevel: LogLevelName;
+  persistLogs: boolean; // keep the diagnostics log across reloads
+  collectMetrics: boolean; // opt-in local performance metrics
+  checkForUpdates: boolean; // daily check of GitHub releases
+  pantryView: PantryView;
+  receiptLanguage: OcrLanguage; // hint sent with every receipt scan
+  receiptRetentionMonths: number; // archived receipt scans; 0 keeps them forever
+}
+
+export const DEFAULT_SETTINGS: AppSettings = {
+  version: SETTINGS_VERSION,
+  language: 'pl',
+  userName: '',
+  weatherLocations: [
+    { label: 'Dom', city: 'Ząbki' },
+    { label: 'Praca', city: 'Warszawa' },
+  ],
+  activeWeatherLocation: 'Ząbki',
+  calendarIcsUrl: '',
+  backendUrl: '',
+  theme: 'system',
+  palette: DEFAULT_PALETTE,
+  uiScale: 100,
+  highContrast: 'system',
+  reducedMotion: 'system',
+  shortcuts: {},
+  restoreLastPage: false,
+  notificationsEnabled: true,
+  notificationCategories: {},
+  logLevel: 'info',
+  persistLogs: false,
+  collectMetrics: false,
+  checkForUpdates: false,
+  pantryView: 'cards',
+  receiptLanguage: 'auto',
+  receiptRetentionMonths: 12,
+};
+
+// Falls back to the first saved location if the active one was removed
+export function activeWeatherCity(settings: AppSettings): string {
+  const saved = settings.weatherLocations;
+  if (saved.some((l) => l.city === settings.activeWeatherLocation)) return settings.activeWeatherLocation;
+  return saved[0]?.city ?? DEFAULT_SETTINGS.activeWeatherLocation;
+}
+
+export function settingsEqual(a: AppSettings, b: AppSettings): boolean {
+  return (Object.keys(DEFAULT_SETTINGS) as (keyof AppSettings)[]).every(
+    (key) => JSON.stringify(a[key]) === JSON.stringify(b[key]),
+  );
+}
+
+type RawSettings = Record<string, unknown>;
+
+// Each entry upgrades settings stored at version N to version N + 1
+const MIGRATIONS: Record<number, (raw: RawSettings) => RawSettings> = {
+  // 0 -> 1: unversioned settings could hold a palette without some of its colours
+  0: (raw) => ({ ...raw, palette: { ...DEFAULT_PALETTE, ...(raw.palette as Partial<ThemePalette> | undefined) } }),
+};
+
+export function migrateSettings(raw: RawSettings): RawSettings {
+  let version = typeof raw.version === 'number' ? raw.version : 0;
+  let migrated = raw;
+  while (version < SETTINGS_VERSION) {
+    migrated = MIGRATIONS[version]?.(migrated) ?? migrated;
+    version += 1;
+  }
+  // Nowsza wersja (np. po cofnięciu aplikacji) - nieznane pola są po prostu ignorowane
+  return { ...migrated, version: Math.max(version, SETTINGS_VERSION) };
+}
+
+export function loadSettings(): AppSettings {
+  const raw = readJson<RawSettings>(profileStorageKey(STORAGE_KEY), {});
+  const migrated = migrateSettings(raw);
+  if (Object.keys(raw).length > 0 && raw.version !== migrated.version) {
+    // Zapis bez zdarzenia zmiany - wartości dla użytkownika się nie zmieniły
+    writeJson(profileStorageKey(STORAGE_KEY), migrated);
+  }
+  return { ...DEFAULT_SETTINGS, ...migrated } as AppSettings;
+}
+
+export function saveSettings(settings: AppSettings): void {
+  writeJson(profileStorageKey(STORAGE_KEY), settings);
+  window.dispatchEvent(new CustomEvent(CHANGE_EVENT));
+}
+
+export function updateSettings(changes: Partial<AppSettings>): AppSettings {
+  const next = { ...loadSettings(), ...changes };
+  saveSettings(next);
+  return next;
+}
+
+export function subscribeSettings(listener: () => void): () => void {
+  return subscribeStorage(() => [profileStorageKey(STORAGE_KEY)], CHANGE_EVENT, listener);
+}
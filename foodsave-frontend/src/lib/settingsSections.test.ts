@@ -0,0 +1,20 @@
+import { translate } from './i18n';
+import { filterSettingsItems } from './settingsSections';
+
+describe('filterSettingsItems', () => {
+  const items = [
+    { id: 'general', keywords: ['settings.language.title' as const] },
+    { id: 'backend', keywords: ['settings.backend.address' as const] },
+  ];
+  const t = (key: Parameters<typeof translate>[1]) => translate('pl', key);
+
+  it('zwraca wszystko dla pustego zapytania', () => {
+    expect(filterSettingsItems(items, '  ', t)).toHaveLength(2);
+  });
+
+  it('dopasowuje bez względu na wielkość liter i polskie znaki', () => {
+    expect(filterSettingsItems(items, 'jezyk', t).map((i) => i.id)).toEqual(['general']);
+    expect(filterSettingsItems(items, 'SERWERA', t).map((i) => i.id)).toEqual(['backend']);
+    expect(filterSettingsItems(items, 'xyz', t)).toEqual([]);
+  });
+});
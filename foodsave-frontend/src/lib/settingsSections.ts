@@ -0,0 +1,42 @@
+// Settings page sections and the search filter over their items
+
+import type { ReactNode } from 'react';
+import type { MessageKey } from './i18n';
+
+export type SettingsSectionId = 'general' | 'appearance' | 'backend' | 'notifications' | 'privacy' | 'shortcuts' | 'about';
+
+export const SETTINGS_SECTIONS: { id: SettingsSectionId; titleKey: MessageKey }[] = [
+  { id: 'general', titleKey: 'settings.section.general' },
+  { id: 'appearance', titleKey: 'settings.section.appearance' },
+  { id: 'backend', titleKey: 'settings.section.backend' },
+  { id: 'notifications', titleKey: 'settings.section.notifications' },
+  { id: 'privacy', titleKey: 'settings.section.privacy' },
+  { id: 'shortcuts', titleKey: 'settings.section.shortcuts' },
+  { id: 'about', titleKey: 'settings.section.about' },
+];
+
+export interface SettingsItem {
+  id: string;
+  section: SettingsSectionId;
+  keywords: MessageKey[]; // translated and matched against the search query
+  node: ReactNode;
+}
+
+// Polskie znaki nie powinny przeszkadzać w wyszukiwaniu ("jezyk" znajduje "Język")
+function normalize(text: string): string {
+  return text
+    .toLocaleLowerCase()
+    .normalize('NFD')
+    .replace(/[\u0300-\u036f]/g, '')
+    .replace(/ł/g, 'l');
+}
+
+export function filterSettingsItems<T extends Pick<SettingsItem, 'keywords'>>(
+  items: T[],
+  query: string,
+  t: (key: MessageKey) => string,
+): T[] {
+  const needle = normalize(query.trim());
+  if (!needle) return items;
+  return items.filter((item) => item.keywords.some((key) => normalize(t(key)).includes(needle)));
+}
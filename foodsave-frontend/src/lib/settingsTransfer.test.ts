@@ -0,0 +1,60 @@
+import { DEFAULT_SETTINGS } from './settings';
+import { DEFAULT_PALETTE } from './theme';
+import { exportSettings, parseSettingsImport } from './settingsTransfer';
+
+describe('settingsTransfer', () => {
+  it('eksportuje ustawienia bez prywatnego adresu kalendarza', () => {
+    const exported = exportSettings({ ...DEFAULT_SETTINGS, calendarIcsUrl: 'https://calendar/private-key/basic.ics' });
+
+    expect(exported.settings).not.toHaveProperty('calendarIcsUrl');
+    expect(exported.settings.theme).toBe('system');
+  });
+
+  it('importuje tylko znane klucze o poprawnym typie', () => {
+    const file = exportSettings({ ...DEFAULT_SETTINGS, theme: 'dark', uiScale: 120 });
+    const text = JSON.stringify({
+      ...file,
+      settings: { ...file.settings, unknown: 1, uiScale: 'duży', calendarIcsUrl: 'https://x' },
+    });
+
+    const imported = parseSettingsImport(text);
+    expect(imported.theme).toBe('dark');
+    expect(imported.weatherLocations).toEqual(DEFAULT_SETTINGS.weatherLocations);
+    expect(imported).not.toHaveProperty('uiScale');
+    expect(imported).not.toHaveProperty('unknown');
+    expect(imported).not.toHaveProperty('calendarIcsUrl');
+  });
+
+  it('sprawdza kształt zagnieżdżonych wartości', () => {
+    const file = exportSettings(DEFAULT_SETTINGS);
+    const text = JSON.stringify({
+      ...file,
+      settings: {
+        ...file.settings,
+        palette: null,
+        language: 'xx',
+        weatherLocations: [{ label: 'Dom', city: 'Gdańsk' }, { label: 1 }, null],
+        shortcuts: { 'app.quickAsk': 'Ctrl+K', broken: 5 },
+        notificationCategories: 'all',
+      },
+    });
+
+    const imported = parseSettingsImport(text);
+    expect(imported).not.toHaveProperty('palette');
+    expect(imported).not.toHaveProperty('language');
+    expect(imported).not.toHaveProperty('notificationCategories');
+    expect(imported.weatherLocations).toEqual([{ label: 'Dom', city: 'Gdańsk' }]);
+    expect(imported.shortcuts).toEqual({ 'app.quickAsk': 'Ctrl+K' });
+    expect(parseSettingsImport(JSON.stringify({ ...file, settings: { palette: { accent: 7 } } })).palette).toEqual(
+      DEFAULT_PALETTE,
+    );
+  });
+
+  it('odrzuca pliki, które nie są eksportem ustawień', () => {
+    expect(() => parseSettingsImport('nie json')).toThrow('JSON');
+    expect(() => parseSettingsImport('{"settings": {}}')).toThrow('FoodSave');
+    expect(() =>
+      parseSettingsImport(JSON.stringify({ format: 'foodsave-settings', settings: { version: 999 } })),
+    ).toThrow('nowszej wersji');
+  });
+});
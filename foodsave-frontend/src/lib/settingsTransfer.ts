@@ -0,0 +1,94 @@
+// Settings export/import as a JSON file, for backups and moving to another machine
+
+import { AppSettings, DEFAULT_SETTINGS, SETTINGS_VERSION, migrateSettings } from './settings';
+import { Locale, isLocale, translate } from './i18n';
+import { DEFAULT_PALETTE, ThemePalette } from './theme';
+
+const EXPORT_FORMAT = 'foodsave-settings';
+
+// Prywatne linki ICS (np. Google Calendar) zawierają klucz dostępu
+export const EXCLUDED_FROM_EXPORT: (keyof AppSettings)[] = ['calendarIcsUrl'];
+
+export interface SettingsExport {
+  format: typeof EXPORT_FORMAT;
+  exportedAt: string;
+  settings: Partial<AppSettings>;
+}
+
+export function exportSettings(settings: AppSettings, now: Date = new Date()): SettingsExport {
+  const exported: Partial<AppSettings> = { ...settings };
+  for (const key of EXCLUDED_FROM_EXPORT) delete exported[key];
+  return { format: EXPORT_FORMAT, exportedAt: now.toISOString(), settings: exported };
+}
+
+export class SettingsImportError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = 'SettingsImportError';
+  }
+}
+
+function isRecord(value: unknown): value is Record<string, unknown> {
+  return typeof value === 'object' && value !== null && !Array.isArray(value);
+}
+
+function pickValues<T>(value: unknown, isValid: (v: unknown) => v is T): Record<string, T> | undefined {
+  if (!isRecord(value)) return undefined;
+  return Object.fromEntries(Object.entries(value).filter((entry): entry is [string, T] => isValid(entry[1])));
+}
+
+const isString = (v: unknown): v is string => typeof v === 'string';
+const isBoolean = (v: unknown): v is boolean => typeof v === 'boolean';
+
+// Nested and enum values rebuilt from their valid parts; undefined drops the key
+const SHAPES: Partial<Record<keyof AppSettings, (value: unknown) => unknown>> = {
+  language: (value) => (isLocale(value) ? value : undefined),
+  palette: (value) => {
+    if (!isRecord(value)) return undefined;
+    const palette = { ...DEFAULT_PALETTE };
+    for (const key of Object.keys(DEFAULT_PALETTE) as (keyof ThemePalette)[]) {
+      if (isString(value[key])) palette[key] = value[key] as string;
+    }
+    return palette;
+  },
+  weatherLocations: (value) => {
+    if (!Array.isArray(value)) return undefined;
+    const locations = value
+      .filter((l): l is Record<string, string> => isRecord(l) && isString(l.label) && isString(l.city) && l.city.trim() !== '')
+      .map((l) => ({ label: l.label, city: l.city }));
+    return locations.length > 0 ? locations : undefined;
+  },
+  shortcuts: (value) => pickValues(value, isString),
+  notificationCategories: (value) => pickValues(value, isBoolean),
+};
+
+// Keeps only known keys whose value has the same JSON type and shape as the default
+export function parseSettingsImport(text: string, locale: Locale = 'pl'): Partial<AppSettings> {
+  let data: unknown;
+  try {
+    data = JSON.parse(text);
+  } catch {
+    throw new SettingsImportError(translate(locale, 'settings.transfer.notJson'));
+  }
+  const file = data as Partial<SettingsExport> | null;
+  if (!file || file.format !== EXPORT_FORMAT || typeof file.settings !== 'object' || !file.settings) {
+    throw new SettingsImportError(translate(locale, 'settings.transfer.notSettings'));
+  }
+
+  const fileVersion = (file.settings as { version?: unknown }).version;
+  if (typeof fileVersion === 'number' && fileVersion > SETTINGS_VERSION) {
+    throw new SettingsImportError(translate(locale, 'settings.transfer.newerVersion'));
+  }
+
+  const imported: Record<string, unknown> = {};
+  for (const [key, value] of Object.entries(migrateSettings(file.settings))) {
+    if (!(key in DEFAULT_SETTINGS) || EXCLUDED_FROM_EXPORT.includes(key as keyof AppSettings)) continue;
+    const fallback = DEFAULT_SETTINGS[key as keyof AppSettings];
+    if (value === null || Array.isArray(fallback) !== Array.isArray(value) || typeof fallback !== typeof value) continue;
+    const shape = SHAPES[key as keyof AppSettings];
+    const checked = shape ? shape(value) : value;
+    if (checked === undefined) continue;
+    imported[key] = checked;
+  }
+  return imported as Partial<AppSettings>;
+}
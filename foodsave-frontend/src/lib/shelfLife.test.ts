@@ -0,0 +1,21 @@
+import { shelfLifeFor, suggestExpiryDate } from './shelfLife';
+
+describe('shelfLife', () => {
+  const purchase = new Date(2024, 4, 30, 18, 0);
+
+  it('podpowiada datę ważności według kategorii i sposobu przechowywania', () => {
+    expect(suggestExpiryDate('Mleko UHT', 'Nabiał', purchase)).toBe('2024-06-06');
+    expect(suggestExpiryDate('Filet z kurczaka', 'Mięso', purchase, 'frozen')).toBe('2024-11-26');
+    expect(suggestExpiryDate('Sok jabłkowy', 'Napoje', purchase, 'frozen')).toBeUndefined();
+  });
+
+  it('rozpoznaje produkt po nazwie, gdy kategoria jest nieznana', () => {
+    expect(shelfLifeFor('CHLEB ŻYTNI 500G')?.fresh).toBe(3);
+    expect(shelfLifeFor('Jogurt naturalny', 'Inne')?.fresh).toBe(7);
+    expect(shelfLifeFor('Coś zupełnie innego')).toBeUndefined();
+  });
+
+  it('nie zgaduje po nazwie dla kategorii spoza żywności', () => {
+    expect(shelfLifeFor('Sól do zmywarki', 'Chemia domowa')).toBeUndefined();
+  });
+});
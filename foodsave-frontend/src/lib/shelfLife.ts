@@ -0,0 +1,87 @@
+// Typical shelf life per product category, used to prefill expiry dates that the user
+// or the receipt OCR didn't provide. Rough household values, not food-safety advice.
+
+import { normalizeText, tokenize } from './fuzzy';
+import { localIsoDate } from './time';
+
+export type StorageMode = 'fresh' | 'frozen' | 'opened';
+
+// Days; a missing mode means the suggestion makes no sense (e.g. frozen drinks)
+export interface ShelfLife {
+  fresh: number;
+  frozen?: number;
+  opened?: number;
+}
+
+interface ShelfLifeEntry extends ShelfLife {
+  keywords: string[]; // name prefixes for items without a known category
+}
+
+// Keys are the categories from receipt analysis (Nabiał, Mięso, ...)
+const SHELF_LIFE_TABLE: Record<string, ShelfLifeEntry> = {
+  Nabiał: {
+    fresh: 7,
+    frozen: 90,
+    opened: 3,
+    keywords: ['mleko', 'ser', 'jogurt', 'kefir', 'śmietan', 'masło', 'twaróg', 'maślank'],
+  },
+  Jaja: { fresh: 28, keywords: ['jaj'] },
+  Pieczywo: { fresh: 3, frozen: 90, opened: 3, keywords: ['chleb', 'bułk', 'bagietk', 'rogal', 'pieczyw'] },
+  Mięso: {
+    fresh: 3,
+    frozen: 180,
+    opened: 1,
+    keywords: ['mięs', 'kurczak', 'wołow', 'wieprz', 'mielon', 'filet', 'szynk', 'kiełbas', 'boczek', 'indyk'],
+  },
+  Ryby: { fresh: 2, frozen: 180, opened: 1, keywords: ['ryb', 'łosoś', 'dorsz', 'śledź', 'makrel', 'pstrąg'] },
+  Warzywa: {
+    fresh: 7,
+    frozen: 240,
+    opened: 3,
+    keywords: ['pomidor', 'ogór', 'sałat', 'marchew', 'ziemniak', 'cebul', 'papryk', 'brokuł', 'kapust', 'warzyw'],
+  },
+  Owoce: {
+    fresh: 5,
+    frozen: 240,
+    opened: 2,
+    keywords: ['jabł', 'banan', 'truskaw', 'pomarańcz', 'grusz', 'winogron', 'cytryn', 'owoc'],
+  },
+  Napoje: { fresh: 180, opened: 3, keywords: ['sok', 'woda', 'napój', 'nektar'] },
+  Słodycze: { fresh: 180, opened: 30, keywords: ['czekolad', 'baton', 'ciastk', 'cukierk', 'wafl'] },
+  'Produkty sypkie': {
+    fresh: 365,
+    opened: 90,
+    keywords: ['makaron', 'ryż', 'mąk', 'kasz', 'cukier', 'płatk'],
+  },
+  Konserwy: { fresh: 730, opened: 3, keywords: ['konserw', 'puszk'] },
+  Przyprawy: { fresh: 730, opened: 365, keywords: ['przypraw', 'pieprz', 'sól', 'oregano', 'bazyli'] },
+};
+
+const ENTRIES = Object.entries(SHELF_LIFE_TABLE).map(([category, entry]) => ({
+  category: normalizeText(category),
+  keywords: entry.keywords.map(normalizeText),
+  entry,
+}));
+
+// Category first; the name is only consulted when the category is unknown, so
+// "Sól do zmywarki" from "Chemia domowa" doesn't get a spice's date
+export function shelfLifeFor(name: string, category?: string): ShelfLife | undefined {
+  const normalizedCategory = category ? normalizeText(category) : '';
+  const byCategory = ENTRIES.find((e) => e.category === normalizedCategory);
+  if (byCategory) return byCategory.entry;
+  if (normalizedCategory && !['inne', 'nieskategoryzowane'].includes(normalizedCategory)) return undefined;
+
+  const tokens = tokenize(name);
+  return ENTRIES.find((e) => tokens.some((token) => e.keywords.some((keyword) => token.startsWith(keyword))))?.entry;
+}
+
+export function suggestExpiryDate(
+  name: string,
+  category?: string,
+  from: Date = new Date(),
+  mode: StorageMode = 'fresh',
+): string | undefined {
+  const days = shelfLifeFor(name, category)?.[mode];
+  if (days === undefined) return undefined;
+  return localIsoDate(new Date(from.getFullYear(), from.getMonth(), from.getDate() + days));
+}
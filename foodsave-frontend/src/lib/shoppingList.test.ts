@@ -0,0 +1,119 @@
+import {
+  addMissingItems,
+  addShoppingListItem,
+  applyTaskSync,
+  checkOffPurchased,
+  clearCheckedItems,
+  findDuplicateItem,
+  getRemovedSyncedIds,
+  getShoppingList,
+  removeShoppingListItem,
+  restoreShoppingListItems,
+  shoppingListToText,
+  toggleShoppingListItem,
+} from './shoppingList';
+
+describe('shoppingList', () => {
+  beforeEach(() => {
+    window.localStorage.clear();
+  });
+
+  it('dodaje, odhacza i czyści kupione pozycje', () => {
+    const milk = addShoppingListItem(' Mleko ', '2 l');
+    addShoppingListItem('Chleb');
+
+    toggleShoppingListItem(milk.id);
+    expect(getShoppingList().find((i) => i.id === milk.id)?.checked).toBe(true);
+
+    clearCheckedItems();
+    expect(getShoppingList().map((i) => i.name)).toEqual(['Chleb']);
+  });
+
+  it('przywraca usuniętą pozycję na jej miejsce', () => {
+    jest.useFakeTimers().setSystemTime(new Date('2024-05-01T10:00:00Z'));
+    const milk = addShoppingListItem('Mleko');
+    jest.setSystemTime(new Date('2024-05-01T10:01:00Z'));
+    addShoppingListItem('Chleb');
+    jest.useRealTimers();
+
+    const removed = removeShoppingListItem(milk.id);
+    expect(removed.map((i) => i.name)).toEqual(['Mleko']);
+
+    restoreShoppingListItems(removed);
+    restoreShoppingListItems(removed);
+    expect(getShoppingList().map((i) => i.name)).toEqual(['Mleko', 'Chleb']);
+  });
+
+  it('wykrywa podobną, jeszcze nie kupioną pozycję', () => {
+    const milk = addShoppingListItem('Mleko');
+
+    expect(findDuplicateItem('mleko 2%')?.id).toBe(milk.id);
+    expect(findDuplicateItem('Chleb')).toBeUndefined();
+
+    toggleShoppingListItem(milk.id);
+    expect(findDuplicateItem('mleko')).toBeUndefined();
+  });
+
+  it('dodaje tylko produkty, których jeszcze nie ma na liście', () => {
+    addShoppingListItem('Mleko');
+
+    const added = addMissingItems(['mleko 2%', 'Chleb', 'Jajka']);
+
+    expect(added.map((i) => i.name)).toEqual(['Chleb', 'Jajka']);
+    expect(getShoppingList().map((i) => i.name)).toEqual(['Mleko', 'Chleb', 'Jajka']);
+  });
+
+  it('odhacza pozycje kupione według paragonu', () => {
+    addShoppingListItem('mleko');
+    addShoppingListItem('masło');
+    addShoppingListItem('jajka');
+
+    const bought = checkOffPurchased(['MLEKO UHT 3,2% 1L', 'Maslo Extra 200g']);
+
+    expect(bought.map((i) => i.name)).toEqual(['mleko', 'masło']);
+    expect(getShoppingList().filter((i) => !i.checked).map((i) => i.name)).toEqual(['jajka']);
+  });
+
+  it('zapisuje wynik synchronizacji z listą zadań', () => {
+    const milk = addShoppingListItem('Mleko');
+    const bread = addShoppingListItem('Chleb');
+
+    applyTaskSync([milk.id], [milk.id, bread.id]);
+
+    const items = getShoppingList();
+    expect(items.map((i) => [i.name, i.checked, i.synced])).toEqual([
+      ['Mleko', true, true],
+      ['Chleb', false, true],
+    ]);
+  });
+
+  it('zapamiętuje usunięte i odznaczone pozycje do synchronizacji', () => {
+    const milk = addShoppingListItem('Mleko');
+    const bread = addShoppingListItem('Chleb');
+    const eggs = addShoppingListItem('Jajka');
+    applyTaskSync([milk.id], [milk.id, bread.id]);
+
+    toggleShoppingListItem(milk.id);
+    expect(getShoppingList().find((i) => i.id === milk.id)).toMatchObject({ checked: false, synced: false });
+
+    removeShoppingListItem(eggs.id); // nigdy nie wysłana - nie ma czego kasować
+    const removed = removeShoppingListItem(bread.id);
+    expect(getRemovedSyncedIds()).toEqual([bread.id]);
+
+    restoreShoppingListItems(removed);
+    expect(getRemovedSyncedIds()).toEqual([]);
+
+    removeShoppingListItem(bread.id);
+    applyTaskSync([], [], [bread.id]);
+    expect(getRemovedSyncedIds()).toEqual([]);
+  });
+
+  it('zamienia niekupione pozycje na tekst do udostępnienia', () => {
+    addShoppingListItem('Mleko', '2 l');
+    const bread = addShoppingListItem('Chleb');
+    toggleShoppingListItem(bread.id);
+    addShoppingListItem('Masło');
+
+    expect(shoppingListToText(getShoppingList())).toBe('Lista zakupów\n- Mleko (2 l)\n- Masło');
+  });
+});
@@ -0,0 +1,147 @@
+// Shopping list persisted locally and shared between the shopping page and the dashboard
+
+import { v4 as uuidv4 } from 'uuid';
+import { MATCH_THRESHOLD, findBestMatch, fuzzyScore } from './fuzzy';
+import { readJson, subscribeStorage, writeJson } from './storage';
+import { Locale, translate } from './i18n';
+
+const STORAGE_KEY = 'foodsave.shoppingList';
+// Exported items removed here; their tasks are deleted on the next Todoist/CalDAV sync
+const REMOVED_KEY = 'foodsave.shoppingList.removed';
+const CHANGE_EVENT = 'foodsave:shopping-list';
+
+export interface ShoppingListItem {
+  id: string;
+  name: string;
+  quantity?: string; // free text, e.g. "2 l"
+  checked: boolean;
+  createdAt: string;
+  synced?: boolean; // already exported to Todoist/CalDAV; cleared when unchecked, so the task reopens
+}
+
+export function getShoppingList(): ShoppingListItem[] {
+  return readJson<ShoppingListItem[]>(STORAGE_KEY, []);
+}
+
+function save(items: ShoppingListItem[]) {
+  writeJson(STORAGE_KEY, items);
+  window.dispatchEvent(new CustomEvent(CHANGE_EVENT));
+}
+
+export function getRemovedSyncedIds(): string[] {
+  return readJson<string[]>(REMOVED_KEY, []);
+}
+
+function rememberRemoved(removed: ShoppingListItem[]) {
+  const ids = removed.filter((item) => item.synced).map((item) => item.id);
+  if (ids.length > 0) writeJson(REMOVED_KEY, [...getRemovedSyncedIds(), ...ids]);
+}
+
+function forgetRemoved(ids: string[]) {
+  writeJson(REMOVED_KEY, getRemovedSyncedIds().filter((id) => !ids.includes(id)));
+}
+
+export function addShoppingListItem(name: string, quantity?: string): ShoppingListItem {
+  const item: ShoppingListItem = {
+    id: uuidv4(),
+    name: name.trim(),
+    quantity: quantity?.trim() || undefined,
+    checked: false,
+    createdAt: new Date().toISOString(),
+  };
+  save([...getShoppingList(), item]);
+  return item;
+}
+
+// Unchecked item that is probably the same product ("mleko" vs "Mleko 2%")
+export function findDuplicateItem(name: string): ShoppingListItem | undefined {
+  return findBestMatch(
+    name,
+    getShoppingList().filter((item) => !item.checked),
+    (item) => item.name,
+  );
+}
+
+// Adds the products that aren't on the list yet (e.g. restocking staples); returns what was added
+export function addMissingItems(names: string[]): ShoppingListItem[] {
+  const added: ShoppingListItem[] = [];
+  for (const name of names) {
+    if (!findDuplicateItem(name)) added.push(addShoppingListItem(name));
+  }
+  return added;
+}
+
+// Checks off list items bought on a scanned receipt. Receipt names carry extra
+// tokens ("Mleko UHT 3,2% 1L"), so only the list item's words need to match.
+export function checkOffPurchased(productNames: string[]): ShoppingListItem[] {
+  const items = getShoppingList();
+  const bought = items.filter(
+    (item) => !item.checked && productNames.some((name) => fuzzyScore(item.name, name) >= MATCH_THRESHOLD),
+  );
+  if (bought.length > 0) {
+    save(items.map((item) => (bought.includes(item) ? { ...item, checked: true } : item)));
+  }
+  return bought;
+}
+
+// Applies the result of a Todoist/CalDAV sync: items closed there get checked here
+export function applyTaskSync(checkedIds: string[], syncedIds: string[], removedIds: string[] = []): void {
+  if (removedIds.length > 0) forgetRemoved(removedIds);
+  save(
+    getShoppingList().map((item) => ({
+      ...item,
+      checked: item.checked || checkedIds.includes(item.id),
+      synced: item.synced || syncedIds.includes(item.id),
+    })),
+  );
+}
+
+// Plain-text list of what's still to buy, e.g. for a QR code scanned by a phone
+export function shoppingListToText(items: ShoppingListItem[], locale: Locale = 'pl'): string {
+  const lines = items
+    .filter((item) => !item.checked)
+    .map((item) => `- ${item.name}${item.quantity ? ` (${item.quantity})` : ''}`);
+  return [translate(locale, 'shopping.list.title'), ...lines].join('\n');
+}
+
+// Name and quantity taken from the task list when resolving a sync conflict
+export function updateShoppingListItem(id: string, changes: Pick<ShoppingListItem, 'name' | 'quantity'>): void {
+  save(getShoppingList().map((item) => (item.id === id ? { ...item, ...changes } : item)));
+}
+
+export function toggleShoppingListItem(id: string): void {
+  save(
+    getShoppingList().map((item) =>
+      item.id === id ? { ...item, checked: !item.checked, synced: item.checked ? false : item.synced } : item,
+    ),
+  );
+}
+
+// Both removals return what was removed, so the caller can offer Undo
+export function removeShoppingListItem(id: string): ShoppingListItem[] {
+  const items = getShoppingList();
+  const removed = items.filter((item) => item.id === id);
+  save(items.filter((item) => item.id !== id));
+  rememberRemoved(removed);
+  return removed;
+}
+
+export function clearCheckedItems(): ShoppingListItem[] {
+  const items = getShoppingList();
+  const removed = items.filter((item) => item.checked);
+  save(items.filter((item) => !item.checked));
+  rememberRemoved(removed);
+  return removed;
+}
+
+// Puts removed items back in their original order
+export function restoreShoppingListItems(removed: ShoppingListItem[]): void {
+  const items = getShoppingList();
+  const missing = removed.filter((item) => !items.some((i) => i.id === item.id));
+  forgetRemoved(removed.map((item) => item.id));
+  save([...items, ...missing].sort((a, b) => a.createdAt.localeCompare(b.createdAt)));
+}
+
+export function subscribeShoppingList(listener: () => void): () => void {
+  return subscribeStorage([STORAGE_KEY], CHANGE_EVENT, listener);
+}
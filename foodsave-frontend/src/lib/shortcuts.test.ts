@@ -0,0 +1,23 @@
+import { eventToBinding, findShortcut, resolveBindings } from './shortcuts';
+
+const key = (overrides: Partial<KeyboardEvent>) =>
+  ({ key: '', code: '', ctrlKey: false, altKey: false, shiftKey: false, metaKey: false, ...overrides }) as KeyboardEvent;
+
+describe('shortcuts', () => {
+  it('zamienia zdarzenie klawiatury na skrót', () => {
+    expect(eventToBinding(key({ key: '!', code: 'Digit1', altKey: true, shiftKey: true }))).toBe('Alt+Shift+1');
+    expect(eventToBinding(key({ key: 'k', code: 'KeyK', ctrlKey: true }))).toBe('Ctrl+K');
+    expect(eventToBinding(key({ key: 'Escape', code: 'Escape' }))).toBe('Escape');
+    expect(eventToBinding(key({ key: 'Shift', code: 'ShiftLeft', shiftKey: true }))).toBeNull();
+  });
+
+  it('uwzględnia własne przypisania użytkownika', () => {
+    const overrides = { 'go.chat': 'Ctrl+K' };
+
+    expect(resolveBindings(overrides)['go.chat']).toBe('Ctrl+K');
+    expect(findShortcut('Ctrl+K', overrides)?.href).toBe('/chat');
+    expect(findShortcut('Alt+Shift+2', overrides)).toBeUndefined();
+    expect(findShortcut('Alt+Shift+1')?.id).toBe('go.dashboard');
+    expect(findShortcut('Alt+Shift+K')).toMatchObject({ id: 'app.quickAsk', href: undefined });
+  });
+});
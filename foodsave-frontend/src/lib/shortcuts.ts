@@ -0,0 +1,86 @@
+// Keyboard shortcuts: action registry, default bindings and key matching.
+// Bindings are strings like "Alt+Shift+1"; user overrides live in AppSettings.shortcuts.
+
+import type { MessageKey } from './i18n';
+
+export type ShortcutId =
+  | 'go.dashboard'
+  | 'go.chat'
+  | 'go.shopping'
+  | 'go.cooking'
+  | 'go.analytics'
+  | 'go.reminders'
+  | 'go.settings'
+  | 'app.quickAsk'
+  | 'focus.nextRegion'
+  | 'focus.previousRegion';
+
+export interface ShortcutAction {
+  id: ShortcutId;
+  labelKey: MessageKey;
+  defaultBinding: string;
+  href?: string; // navigation shortcuts; the others are dispatched as SHORTCUT_EVENT
+}
+
+// Window event carrying the ShortcutId of a non-navigation shortcut
+export const SHORTCUT_EVENT = 'foodsave:shortcut';
+
+export const SHORTCUT_ACTIONS: ShortcutAction[] = [
+  { id: 'go.dashboard', labelKey: 'nav.dashboard', defaultBinding: 'Alt+Shift+1', href: '/dashboard' },
+  { id: 'go.chat', labelKey: 'nav.chat', defaultBinding: 'Alt+Shift+2', href: '/chat' },
+  { id: 'go.shopping', labelKey: 'nav.shopping', defaultBinding: 'Alt+Shift+3', href: '/shopping' },
+  { id: 'go.cooking', labelKey: 'nav.cooking', defaultBinding: 'Alt+Shift+4', href: '/cooking' },
+  { id: 'go.analytics', labelKey: 'nav.analytics', defaultBinding: 'Alt+Shift+5', href: '/analytics' },
+  { id: 'go.reminders', labelKey: 'nav.reminders', defaultBinding: 'Alt+Shift+6', href: '/reminders' },
+  { id: 'go.settings', labelKey: 'nav.settings', defaultBinding: 'Alt+Shift+7', href: '/settings' },
+  { id: 'app.quickAsk', labelKey: 'quickAsk.title', defaultBinding: 'Alt+Shift+K' },
+  { id: 'focus.nextRegion', labelKey: 'shortcuts.focusNext', defaultBinding: 'Alt+Shift+ArrowRight' },
+  { id: 'focus.previousRegion', labelKey: 'shortcuts.focusPrevious', defaultBinding: 'Alt+Shift+ArrowLeft' },
+];
+
+export type ShortcutBindings = Partial<Record<ShortcutId, string>>;
+
+const MODIFIER_KEYS = ['Control', 'Alt', 'Shift', 'Meta'];
+
+// Physical letter/digit keys, so Shift+1 is "Shift+1" rather than "!"
+function keyName(e: Pick<KeyboardEvent, 'key' | 'code'>): string {
+  if (e.code.startsWith('Key')) return e.code.slice(3);
+  if (e.code.startsWith('Digit')) return e.code.slice(5);
+  if (e.key === ' ') return 'Space';
+  return e.key.length === 1 ? e.key.toUpperCase() : e.key;
+}
+
+// null for a lone modifier press (still waiting for the actual key)
+export function eventToBinding(
+  e: Pick<KeyboardEvent, 'key' | 'code' | 'ctrlKey' | 'altKey' | 'shiftKey' | 'metaKey'>,
+): string | null {
+  if (MODIFIER_KEYS.includes(e.key)) return null;
+  const parts: string[] = [];
+  if (e.ctrlKey) parts.push('Ctrl');
+  if (e.altKey) parts.push('Alt');
+  if (e.shiftKey) parts.push('Shift');
+  if (e.metaKey) parts.push('Meta');
+  parts.push(keyName(e));
+  return parts.join('+');
+}
+
+export function hasModifier(binding: string): boolean {
+  return /^(Ctrl|Alt|Meta)\+/.test(binding);
+}
+
+export function resolveBindings(overrides: ShortcutBindings = {}): Record<ShortcutId, string> {
+  return Object.fromEntries(
+    SHORTCUT_ACTIONS.map((action) => [action.id, overrides[action.id] ?? action.defaultBinding]),
+  ) as Record<ShortcutId, string>;
+}
+
+export function findShortcut(binding: string, overrides: ShortcutBindings = {}): ShortcutAction | undefined {
+  const bindings = resolveBindings(overrides);
+  return SHORTCUT_ACTIONS.find((action) => bindings[action.id] === binding);
+}
+
+export function isEditableTarget(target: EventTarget | null): boolean {
+  const element = target as HTMLElement | null;
+  if (!element || !element.tagName) return false;
+  return ['INPUT', 'TEXTAREA', 'SELECT'].includes(element.tagName) || element.isContentEditable;
+}
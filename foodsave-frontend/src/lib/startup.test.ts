@@ -0,0 +1,32 @@
+import { DEFAULT_START_PAGE, rememberPage, rememberScroll, savedScroll, startupPath } from './startup';
+
+describe('startup', () => {
+  beforeEach(() => {
+    window.localStorage.clear();
+  });
+
+  it('otwiera ostatnią stronę tylko gdy opcja jest włączona', () => {
+    rememberPage('/shopping');
+
+    expect(startupPath(true)).toBe('/shopping');
+    expect(startupPath(false)).toBe(DEFAULT_START_PAGE);
+  });
+
+  it('ignoruje stronę główną i adresy zewnętrzne', () => {
+    rememberPage('/');
+    expect(startupPath(true)).toBe(DEFAULT_START_PAGE);
+
+    window.localStorage.setItem('foodsave.lastPage', JSON.stringify('//evil.example.com'));
+    expect(startupPath(true)).toBe(DEFAULT_START_PAGE);
+  });
+
+  it('pamięta pozycję przewinięcia dla ograniczonej liczby stron', () => {
+    rememberScroll('/analytics', 640.4);
+    expect(savedScroll('/analytics')).toBe(640);
+    expect(savedScroll('/chat')).toBe(0);
+
+    for (let i = 0; i < 20; i++) rememberScroll(`/page-${i}`, 100);
+    expect(savedScroll('/analytics')).toBe(0);
+    expect(savedScroll('/page-19')).toBe(100);
+  });
+});
@@ -0,0 +1,43 @@
+// Which page the app opens on (the dashboard, or the last visited page when enabled)
+// and the UI state restored with it: per-page scroll positions and the sidebar
+
+import { readJson, writeJson } from './storage';
+
+const LAST_PAGE_KEY = 'foodsave.lastPage';
+const SCROLL_KEY = 'foodsave.scrollPositions';
+const SIDEBAR_KEY = 'foodsave.sidebarCollapsed';
+const MAX_SCROLL_ENTRIES = 20;
+export const DEFAULT_START_PAGE = '/dashboard';
+
+export function rememberPage(path: string): void {
+  if (path === '/') return;
+  writeJson(LAST_PAGE_KEY, path);
+}
+
+export function startupPath(restoreLastPage: boolean): string {
+  if (!restoreLastPage) return DEFAULT_START_PAGE;
+  const last = readJson<string | null>(LAST_PAGE_KEY, null);
+  // Tylko ścieżki wewnętrzne - nie przekierowujemy na zewnętrzne adresy
+  return typeof last === 'string' && last.startsWith('/') && !last.startsWith('//') ? last : DEFAULT_START_PAGE;
+}
+
+export function rememberScroll(path: string, y: number): void {
+  const positions = readJson<Record<string, number>>(SCROLL_KEY, {});
+  delete positions[path];
+  // Najnowsze na końcu; najstarsze wypadają po przekroczeniu limitu
+  const entries = [...Object.entries(positions), [path, Math.max(0, Math.round(y))] as const];
+  writeJson(SCROLL_KEY, Object.fromEntries(entries.slice(-MAX_SCROLL_ENTRIES)));
+}
+
+export function savedScroll(path: string): number {
+  const y = readJson<Record<string, number>>(SCROLL_KEY, {})[path];
+  return typeof y === 'number' && y > 0 ? y : 0;
+}
+
+export function isSidebarCollapsed(): boolean {
+  return readJson<boolean>(SIDEBAR_KEY, false) === true;
+}
+
+export function rememberSidebarCollapsed(collapsed: boolean): void {
+  writeJson(SIDEBAR_KEY, collapsed);
+}
@@ -0,0 +1,23 @@
+import { subscribeStorage } from './storage';
+
+describe('subscribeStorage', () => {
+  it('reaguje na zdarzenie w tej karcie i na zmianę swoich kluczy w innej', () => {
+    const listener = jest.fn();
+    let key = 'foodsave.a';
+    const unsubscribe = subscribeStorage(() => [key], 'foodsave:a', listener);
+
+    window.dispatchEvent(new CustomEvent('foodsave:a'));
+    window.dispatchEvent(new StorageEvent('storage', { key: 'foodsave.a' }));
+    window.dispatchEvent(new StorageEvent('storage', { key: 'foodsave.b' }));
+    expect(listener).toHaveBeenCalledTimes(2);
+
+    // Klucze zależne od profilu są odczytywane przy każdym zdarzeniu
+    key = 'foodsave.b';
+    window.dispatchEvent(new StorageEvent('storage', { key: 'foodsave.b' }));
+    expect(listener).toHaveBeenCalledTimes(3);
+
+    unsubscribe();
+    window.dispatchEvent(new CustomEvent('foodsave:a'));
+    expect(listener).toHaveBeenCalledTimes(3);
+  });
+});
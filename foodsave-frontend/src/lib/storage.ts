@@ -0,0 +1,38 @@
+// Small JSON wrapper around localStorage, safe to call during SSR
+
+export function readJson<T>(key: string, fallback: T): T {
+  if (typeof window === 'undefined') return fallback;
+  try {
+    const raw = window.localStorage.getItem(key);
+    return raw === null ? fallback : (JSON.parse(raw) as T);
+  } catch {
+    return fallback;
+  }
+}
+
+export function writeJson<T>(key: string, value: T): void {
+  if (typeof window === 'undefined') return;
+  try {
+    window.localStorage.setItem(key, JSON.stringify(value));
+  } catch {
+    // Quota exceeded or storage disabled - settings simply won't persist
+  }
+}
+
+// Calls the listener on a store's change event in this tab and when one of its keys
+// changes in another tab. Keys that depend on the active profile are passed as a function.
+export function subscribeStorage(
+  keys: string[] | (() => string[]),
+  event: string,
+  listener: () => void,
+): () => void {
+  const onStorage = (e: StorageEvent) => {
+    if (e.key !== null && (typeof keys === 'function' ? keys() : keys).includes(e.key)) listener();
+  };
+  window.addEventListener(event, listener);
+  window.addEventListener('storage', onStorage);
+  return () => {
+    window.removeEventListener(event, listener);
+    window.removeEventListener('storage', onStorage);
+  };
+}
@@ -0,0 +1,21 @@
+import { needsRemoteUpdate, resolvedVersion } from './syncConflicts';
+
+describe('syncConflicts', () => {
+  const item = { id: 'a', name: 'Mleko', quantity: '2 l', checked: false, createdAt: '2024-05-01T10:00:00Z' };
+  const conflict = { id: 'a', name: 'Mleko 3,2%', quantity: null };
+
+  it('wybiera wersję lokalną, zdalną albo scaloną z wybranych pól', () => {
+    expect(resolvedVersion(item, conflict, 'mine')).toEqual({ name: 'Mleko', quantity: '2 l' });
+    expect(resolvedVersion(item, conflict, 'theirs')).toEqual({ name: 'Mleko 3,2%', quantity: undefined });
+    expect(resolvedVersion(item, conflict, 'merge')).toEqual({ name: 'Mleko 3,2%', quantity: '2 l' });
+    expect(resolvedVersion(item, conflict, 'merge', { name: 'mine', quantity: 'theirs' })).toEqual({
+      name: 'Mleko',
+      quantity: undefined,
+    });
+  });
+
+  it('aktualizuje listę zadań tylko wtedy, gdy zachowana wersja różni się od zdalnej', () => {
+    expect(needsRemoteUpdate(conflict, resolvedVersion(item, conflict, 'theirs'))).toBe(false);
+    expect(needsRemoteUpdate(conflict, resolvedVersion(item, conflict, 'merge'))).toBe(true);
+  });
+});
@@ -0,0 +1,45 @@
+// Resolving shopping list items changed both here and in Todoist/CalDAV
+
+import type { ShoppingListItem } from './shoppingList';
+import type { ShoppingListConflict } from '@/types/api';
+
+export type ConflictChoice = 'mine' | 'theirs' | 'merge';
+export type ConflictSide = 'mine' | 'theirs';
+
+// In a merge the name and the quantity are picked separately
+export interface MergePicks {
+  name: ConflictSide;
+  quantity: ConflictSide;
+}
+
+export interface ConflictVersion {
+  name: string;
+  quantity?: string;
+}
+
+export function remoteVersion(conflict: ShoppingListConflict): ConflictVersion {
+  return { name: conflict.name, quantity: conflict.quantity || undefined };
+}
+
+// Name and quantity the item ends up with on both sides
+export function resolvedVersion(
+  item: ShoppingListItem,
+  conflict: ShoppingListConflict,
+  choice: ConflictChoice,
+  picks: MergePicks = { name: 'theirs', quantity: 'mine' },
+): ConflictVersion {
+  const mine: ConflictVersion = { name: item.name, quantity: item.quantity };
+  const theirs = remoteVersion(conflict);
+  if (choice === 'mine') return mine;
+  if (choice === 'theirs') return theirs;
+  return {
+    name: (picks.name === 'mine' ? mine : theirs).name,
+    quantity: (picks.quantity === 'mine' ? mine : theirs).quantity,
+  };
+}
+
+// The task list only needs an update when it doesn't already hold the kept version
+export function needsRemoteUpdate(conflict: ShoppingListConflict, version: ConflictVersion): boolean {
+  const theirs = remoteVersion(conflict);
+  return theirs.name !== version.name || theirs.quantity !== version.quantity;
+}
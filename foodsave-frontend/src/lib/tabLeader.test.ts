@@ -0,0 +1,16 @@
+import { getTabRole, startTabLeadership, subscribeTabRole } from './tabLeader';
+
+describe('tabLeader', () => {
+  it('bez Web Locks każda karta zostaje liderem', () => {
+    const listener = jest.fn();
+    const unsubscribe = subscribeTabRole(listener);
+
+    expect(getTabRole()).toBe('pending');
+    startTabLeadership();
+    startTabLeadership();
+
+    expect(getTabRole()).toBe('leader');
+    expect(listener).toHaveBeenCalledTimes(1);
+    unsubscribe();
+  });
+});
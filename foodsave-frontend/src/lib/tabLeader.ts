@@ -0,0 +1,54 @@
+// One "leader" tab per browser runs the background jobs (see scheduler.ts), so opening
+// FoodSave in several tabs doesn't duplicate them. Uses the Web Locks API; the lock is
+// released by the browser when the leader tab closes and the next waiting tab takes over.
+
+const LOCK_NAME = 'foodsave:leader';
+
+export type TabRole = 'pending' | 'leader' | 'follower';
+
+let role: TabRole = 'pending';
+let started = false;
+const listeners = new Set<() => void>();
+
+function setRole(next: TabRole) {
+  role = next;
+  listeners.forEach((listener) => listener());
+}
+
+export function getTabRole(): TabRole {
+  return role;
+}
+
+// Holds the lock until the tab is closed
+const holdForever = () => new Promise<void>(() => {});
+
+// Safe to call many times; only the first call requests the lock
+export function startTabLeadership(): void {
+  if (started || typeof navigator === 'undefined') return;
+  started = true;
+  if (!('locks' in navigator)) {
+    // Bez Web Locks każda karta działa samodzielnie, jak dotąd
+    setRole('leader');
+    return;
+  }
+  navigator.locks.request(LOCK_NAME, { ifAvailable: true }, (lock) => {
+    if (lock) {
+      setRole('leader');
+      return holdForever();
+    }
+    // Inna karta już prowadzi - czekamy w kolejce, aż zostanie zamknięta
+    setRole('follower');
+    navigator.locks.request(LOCK_NAME, () => {
+      setRole('leader');
+      return holdForever();
+    });
+    return undefined;
+  });
+}
+
+export function subscribeTabRole(listener: () => void): () => void {
+  listeners.add(listener);
+  return () => {
+    listeners.delete(listener);
+  };
+}
@@ -0,0 +1,19 @@
+import { getActiveTasks, isTaskRunning, startTask, trackTask } from './tasks';
+
+describe('tasks', () => {
+  it('rejestruje zadanie do momentu zakończenia', () => {
+    const finish = startTask('ocr', 'Odczytywanie paragonu');
+    expect(isTaskRunning('ocr')).toBe(true);
+
+    finish();
+    finish();
+    expect(getActiveTasks()).toEqual([]);
+  });
+
+  it('kończy śledzone zadanie także po błędzie', async () => {
+    await expect(
+      trackTask('chat', 'Asystent odpowiada', () => Promise.reject(new Error('timeout'))),
+    ).rejects.toThrow('timeout');
+    expect(isTaskRunning('chat')).toBe(false);
+  });
+});
@@ -0,0 +1,53 @@
+// Registry of running async operations (OCR, chat replies, saves) driving the global progress bar
+
+import { v4 as uuidv4 } from 'uuid';
+
+export interface Task {
+  id: string;
+  kind: string; // np. 'ocr', 'chat' – pages can ask whether a kind is running
+  label: string;
+  startedAt: number;
+}
+
+let tasks: Task[] = [];
+const listeners = new Set<() => void>();
+
+function emit() {
+  listeners.forEach((listener) => listener());
+}
+
+export function getActiveTasks(): Task[] {
+  return tasks;
+}
+
+export function isTaskRunning(kind: string): boolean {
+  return tasks.some((task) => task.kind === kind);
+}
+
+// Returns a finish callback; calling it more than once is harmless
+export function startTask(kind: string, label: string): () => void {
+  const task: Task = { id: uuidv4(), kind, label, startedAt: Date.now() };
+  tasks = [...tasks, task];
+  emit();
+  return () => {
+    if (!tasks.includes(task)) return;
+    tasks = tasks.filter((t) => t !== task);
+    emit();
+  };
+}
+
+export async function trackTask<T>(kind: string, label: string, run: () => Promise<T>): Promise<T> {
+  const finish = startTask(kind, label);
+  try {
+    return await run();
+  } finally {
+    finish();
+  }
+}
+
+export function subscribeTasks(listener: () => void): () => void {
+  listeners.add(listener);
+  return () => {
+    listeners.delete(listener);
+  };
+}
@@ -0,0 +1,69 @@
+import {
+  applyAccessibility,
+  applyTheme,
+  applyUiScale,
+  clampUiScale,
+  hexToHsl,
+  resolvePreference,
+  resolveTheme,
+  scrollBehavior,
+} from './theme';
+
+describe('theme', () => {
+  it('rozwiązuje tryb systemowy według preferencji systemu', () => {
+    expect(resolveTheme('system', true)).toBe('dark');
+    expect(resolveTheme('system', false)).toBe('light');
+    expect(resolveTheme('dark', false)).toBe('dark');
+    expect(resolveTheme('light', true)).toBe('light');
+  });
+
+  it('przełącza klasę dark na elemencie html', () => {
+    applyTheme('dark');
+    expect(document.documentElement.classList.contains('dark')).toBe(true);
+    applyTheme('light');
+    expect(document.documentElement.classList.contains('dark')).toBe(false);
+  });
+
+  it('konwertuje kolor hex na HSL i ustawia zmienne akcentu', () => {
+    expect(hexToHsl('#2563eb')).toBe('221 83% 53%');
+    expect(hexToHsl('#ffffff')).toBe('0 0% 100%');
+    expect(hexToHsl('niebieski')).toBeNull();
+
+    applyTheme('light', { accent: '#16a34a', background: '', surface: '' });
+    expect(document.documentElement.style.getPropertyValue('--primary')).toBe(hexToHsl('#16a34a'));
+
+    applyTheme('light');
+    expect(document.documentElement.style.getPropertyValue('--primary')).toBe('');
+  });
+
+  it('ogranicza skalę interfejsu do 90–150% i ustawia rozmiar czcionki', () => {
+    expect(clampUiScale(80)).toBe(90);
+    expect(clampUiScale(200)).toBe(150);
+    expect(clampUiScale(NaN)).toBe(100);
+
+    applyUiScale(125);
+    expect(document.documentElement.style.fontSize).toBe('125%');
+    applyUiScale(100);
+    expect(document.documentElement.style.fontSize).toBe('');
+  });
+
+  it('rozwiązuje preferencje dostępności według ustawień systemu', () => {
+    expect(resolvePreference('system', true)).toBe(true);
+    expect(resolvePreference('system', false)).toBe(false);
+    expect(resolvePreference('on', false)).toBe(true);
+    expect(resolvePreference('off', true)).toBe(false);
+  });
+
+  it('przełącza klasy wysokiego kontrastu i ograniczenia animacji', () => {
+    const root = document.documentElement;
+    applyAccessibility('on', 'on');
+    expect(root.classList.contains('high-contrast')).toBe(true);
+    expect(root.classList.contains('reduce-motion')).toBe(true);
+    expect(scrollBehavior()).toBe('auto');
+
+    applyAccessibility('off', 'off');
+    expect(root.classList.contains('high-contrast')).toBe(false);
+    expect(root.classList.contains('reduce-motion')).toBe(false);
+    expect(scrollBehavior()).toBe('smooth');
+  });
+});
@@ -0,0 +1,153 @@
+// Light/dark theme applied through the `dark` class on <html> (tailwind darkMode: "class"),
+// plus the `high-contrast` and `reduce-motion` accessibility classes
+
+import type { MessageKey } from './i18n';
+
+export type ThemeMode = 'light' | 'dark' | 'system';
+
+export const THEME_LABELS: Record<ThemeMode, MessageKey> = {
+  light: 'settings.appearance.light',
+  dark: 'settings.appearance.dark',
+  system: 'settings.appearance.system',
+};
+
+// Custom colours as #rrggbb; empty string keeps the colour from globals.css
+export interface ThemePalette {
+  accent: string;
+  background: string;
+  surface: string;
+}
+
+export const DEFAULT_PALETTE: ThemePalette = { accent: '', background: '', surface: '' };
+
+export const ACCENT_PRESETS = ['#2563eb', '#16a34a', '#ea580c', '#db2777', '#7c3aed', '#0891b2'];
+
+const DARK_QUERY = '(prefers-color-scheme: dark)';
+const CONTRAST_QUERY = '(prefers-contrast: more)';
+const REDUCED_MOTION_QUERY = '(prefers-reduced-motion: reduce)';
+
+// "system" follows the OS setting where the browser exposes it
+export type AccessibilityPreference = 'system' | 'on' | 'off';
+
+export const ACCESSIBILITY_LABELS: Record<AccessibilityPreference, MessageKey> = {
+  system: 'settings.appearance.system',
+  on: 'settings.accessibility.on',
+  off: 'settings.accessibility.off',
+};
+
+// "#2563eb" -> "221 83% 53%" (format zmiennych CSS w globals.css)
+export function hexToHsl(hex: string): string | null {
+  const match = /^#?([\da-f]{2})([\da-f]{2})([\da-f]{2})$/i.exec(hex.trim());
+  if (!match) return null;
+  const [r, g, b] = match.slice(1).map((part) => parseInt(part, 16) / 255);
+  const max = Math.max(r, g, b);
+  const min = Math.min(r, g, b);
+  const l = (max + min) / 2;
+  let h = 0;
+  let s = 0;
+  if (max !== min) {
+    const d = max - min;
+    s = l > 0.5 ? d / (2 - max - min) : d / (max + min);
+    if (max === r) h = (g - b) / d + (g < b ? 6 : 0);
+    else if (max === g) h = (b - r) / d + 2;
+    else h = (r - g) / d + 4;
+    h *= 60;
+  }
+  return `${Math.round(h)} ${Math.round(s * 100)}% ${Math.round(l * 100)}%`;
+}
+
+// Dark text on light colours, light text on dark ones
+export function readableForeground(hex: string): string {
+  const hsl = hexToHsl(hex);
+  const lightness = hsl ? parseInt(hsl.split(' ')[2], 10) : 50;
+  return lightness > 60 ? '222.2 47.4% 11.2%' : '210 40% 98%';
+}
+
+export function resolveTheme(mode: ThemeMode, prefersDark: boolean): 'light' | 'dark' {
+  if (mode === 'system') return prefersDark ? 'dark' : 'light';
+  return mode;
+}
+
+function matchesMedia(query: string): boolean {
+  return typeof window !== 'undefined' && !!window.matchMedia?.(query).matches;
+}
+
+export function systemPrefersDark(): boolean {
+  return matchesMedia(DARK_QUERY);
+}
+
+export function resolvePreference(preference: AccessibilityPreference, systemValue: boolean): boolean {
+  if (preference === 'system') return systemValue;
+  return preference === 'on';
+}
+
+export function applyAccessibility(highContrast: AccessibilityPreference, reducedMotion: AccessibilityPreference): void {
+  if (typeof document === 'undefined') return;
+  const root = document.documentElement;
+  root.classList.toggle('high-contrast', resolvePreference(highContrast, matchesMedia(CONTRAST_QUERY)));
+  root.classList.toggle('reduce-motion', resolvePreference(reducedMotion, matchesMedia(REDUCED_MOTION_QUERY)));
+}
+
+// For scrollIntoView and other JS-driven motion that CSS can't turn off
+export function scrollBehavior(): ScrollBehavior {
+  if (typeof document === 'undefined') return 'auto';
+  return document.documentElement.classList.contains('reduce-motion') ? 'auto' : 'smooth';
+}
+
+// Sets each colour variable with its matching "-foreground" pair, or restores the CSS defaults
+function setColorVariables(root: HTMLElement, color: string, names: string[]) {
+  const hsl = hexToHsl(color);
+  for (const name of names) {
+    if (hsl) {
+      root.style.setProperty(name, hsl);
+      root.style.setProperty(`${name}-foreground`, readableForeground(color));
+    } else {
+      root.style.removeProperty(name);
+      root.style.removeProperty(`${name}-foreground`);
+    }
+  }
+}
+
+export function applyTheme(mode: ThemeMode, palette: ThemePalette = DEFAULT_PALETTE): void {
+  if (typeof document === 'undefined') return;
+  const root = document.documentElement;
+  const resolved = resolveTheme(mode, systemPrefersDark());
+  root.classList.toggle('dark', resolved === 'dark');
+  root.style.colorScheme = resolved;
+
+  setColorVariables(root, palette.accent, ['--primary']);
+  setColorVariables(root, palette.surface, ['--card', '--popover']);
+  // --background pairs with plain --foreground
+  const background = hexToHsl(palette.background);
+  if (background) {
+    root.style.setProperty('--background', background);
+    root.style.setProperty('--foreground', readableForeground(palette.background));
+  } else {
+    root.style.removeProperty('--background');
+    root.style.removeProperty('--foreground');
+  }
+}
+
+export const UI_SCALE_MIN = 90;
+export const UI_SCALE_MAX = 150;
+
+export function clampUiScale(scale: number): number {
+  if (!Number.isFinite(scale)) return 100;
+  return Math.min(UI_SCALE_MAX, Math.max(UI_SCALE_MIN, Math.round(scale)));
+}
+
+// Tailwind sizes text and spacing in rem, so scaling the root font size scales the whole UI
+export function applyUiScale(scale: number): void {
+  if (typeof document === 'undefined') return;
+  const clamped = clampUiScale(scale);
+  document.documentElement.style.fontSize = clamped === 100 ? '' : `${clamped}%`;
+}
+
+// OS changes of colour scheme, contrast and motion preferences
+export function subscribeSystemTheme(listener: () => void): () => void {
+  const queries = [DARK_QUERY, CONTRAST_QUERY, REDUCED_MOTION_QUERY]
+    .map((query) => window.matchMedia?.(query))
+    .filter((query): query is MediaQueryList => !!query);
+  queries.forEach((query) => query.addEventListener('change', listener));
+  return () => queries.forEach((query) => query.removeEventListener('change', listener));
+}
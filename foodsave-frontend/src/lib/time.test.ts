@@ -0,0 +1,49 @@
+import { daysBetween, formatDuration, formatRelativeDay, formatRelativeTime } from './time';
+
+const NOW = new Date(2024, 4, 10, 12, 0);
+const minutesAgo = (minutes: number) => new Date(NOW.getTime() - minutes * 60_000);
+
+describe('time', () => {
+  it('formatuje czas względny z polską odmianą', () => {
+    expect(formatRelativeTime(minutesAgo(0.5), NOW)).toBe('przed chwilą');
+    expect(formatRelativeTime(minutesAgo(2), NOW)).toBe('2 minuty temu');
+    expect(formatRelativeTime(minutesAgo(5), NOW)).toBe('5 minut temu');
+    expect(formatRelativeTime(minutesAgo(2 * 60), NOW)).toBe('2 godziny temu');
+    expect(formatRelativeTime(minutesAgo(-3 * 60), NOW)).toBe('za 3 godziny');
+  });
+
+  it('formatuje czas względny po angielsku', () => {
+    expect(formatRelativeTime(minutesAgo(0.5), NOW, 'en')).toBe('just now');
+    expect(formatRelativeTime(minutesAgo(22), NOW, 'en')).toBe('22 minutes ago');
+  });
+
+  it('liczy dni kalendarzowe niezależnie od godziny', () => {
+    expect(daysBetween(new Date(2024, 4, 10, 23, 59), new Date(2024, 4, 11, 0, 1))).toBe(1);
+    expect(daysBetween(NOW, new Date(2024, 4, 10, 0, 0))).toBe(0);
+  });
+
+  it('formatuje daty ważności względem dzisiaj', () => {
+    expect(formatRelativeDay('2024-05-10', NOW)).toBe('dzisiaj');
+    expect(formatRelativeDay('2024-05-11', NOW)).toBe('jutro');
+    expect(formatRelativeDay('2024-05-13', NOW)).toBe('za 3 dni');
+    expect(formatRelativeDay('2024-05-08', NOW)).toBe('przedwczoraj');
+    expect(formatRelativeDay('2024-05-15', NOW, 'en')).toBe('in 5 days');
+  });
+
+  it('odmienia jednostki czasu trwania', () => {
+    expect(formatDuration(60_000)).toBe('1 minuta');
+    expect(formatDuration(2 * 60_000)).toBe('2 minuty');
+    expect(formatDuration(5 * 60_000)).toBe('5 minut');
+    expect(formatDuration(12 * 60_000)).toBe('12 minut');
+    expect(formatDuration(22 * 60_000)).toBe('22 minuty');
+    expect(formatDuration(22 * 3600_000)).toBe('22 godziny');
+  });
+
+  it('pokazuje najwyżej dwie sąsiednie jednostki', () => {
+    expect(formatDuration(3600_000 + 5 * 60_000)).toBe('1 godzina 5 minut');
+    expect(formatDuration(3600_000 + 2_000)).toBe('1 godzina');
+    expect(formatDuration(3 * 86_400_000 + 2 * 3600_000 + 5_000)).toBe('3 dni 2 godziny');
+    expect(formatDuration(90_000, 'pl', 'short')).toBe('1 min 30 sek.');
+    expect(formatDuration(400)).toBe('0 sekund');
+  });
+});
@@ -0,0 +1,92 @@
+// Time formatting helpers; plural forms come from Intl, so "2 minuty" / "5 minut" just work
+
+import type { Locale } from './i18n';
+
+const DAY_MS = 24 * 3600 * 1000;
+
+const UNITS: [Intl.RelativeTimeFormatUnit, number][] = [
+  ['year', 365 * 24 * 3600],
+  ['month', 30 * 24 * 3600],
+  ['week', 7 * 24 * 3600],
+  ['day', 24 * 3600],
+  ['hour', 3600],
+  ['minute', 60],
+];
+
+const JUST_NOW: Record<Locale, string> = {
+  pl: 'przed chwilą',
+  en: 'just now',
+};
+
+// "przed chwilą", "5 minut temu", "wczoraj", "za 2 godziny", ...
+export function formatRelativeTime(
+  date: Date | string | number,
+  now: Date = new Date(),
+  locale: Locale = 'pl',
+): string {
+  const seconds = Math.round((new Date(date).getTime() - now.getTime()) / 1000);
+  if (Math.abs(seconds) < 45) return JUST_NOW[locale];
+
+  const rtf = new Intl.RelativeTimeFormat(locale, { numeric: 'auto' });
+  for (const [unit, unitSeconds] of UNITS) {
+    if (Math.abs(seconds) >= unitSeconds) {
+      return rtf.format(Math.round(seconds / unitSeconds), unit);
+    }
+  }
+  return rtf.format(Math.round(seconds / 60), 'minute');
+}
+
+// Whole calendar days between two dates, ignoring the time of day
+export function daysBetween(from: Date, to: Date): number {
+  const start = Date.UTC(from.getFullYear(), from.getMonth(), from.getDate());
+  const end = Date.UTC(to.getFullYear(), to.getMonth(), to.getDate());
+  return Math.round((end - start) / DAY_MS);
+}
+
+// YYYY-MM-DD of the local calendar day (toISOString would shift it to UTC)
+export function localIsoDate(date: Date): string {
+  const month = String(date.getMonth() + 1).padStart(2, '0');
+  const day = String(date.getDate()).padStart(2, '0');
+  return `${date.getFullYear()}-${month}-${day}`;
+}
+
+// Day-level variant for dates without a meaningful time, e.g. expiry dates:
+// "dzisiaj", "jutro", "za 3 dni", "2 dni temu"
+export function formatRelativeDay(
+  date: Date | string,
+  now: Date = new Date(),
+  locale: Locale = 'pl',
+): string {
+  // "2024-05-01" bez godziny to północ UTC – czytamy jako datę lokalną
+  const target = typeof date === 'string' && /^\d{4}-\d{2}-\d{2}$/.test(date) ? new Date(`${date}T00:00:00`) : new Date(date);
+  const rtf = new Intl.RelativeTimeFormat(locale, { numeric: 'auto' });
+  return rtf.format(daysBetween(now, target), 'day');
+}
+
+const DURATION_UNITS: [string, number][] = [
+  ['day', 24 * 3600],
+  ['hour', 3600],
+  ['minute', 60],
+  ['second', 1],
+];
+
+// "1 godzina 5 minut", "2 minuty", or "1 godz. 5 min" with style 'short'.
+// Shows at most the two largest non-zero units; sub-second durations count as 0 seconds.
+export function formatDuration(ms: number, locale: Locale = 'pl', style: 'long' | 'short' = 'long'): string {
+  let remaining = Math.max(0, Math.round(ms / 1000));
+  const parts: string[] = [];
+  for (const [unit, unitSeconds] of DURATION_UNITS) {
+    const value = Math.floor(remaining / unitSeconds);
+    remaining -= value * unitSeconds;
+    if (value > 0 && parts.length < 2) {
+      parts.push(new Intl.NumberFormat(locale, { style: 'unit', unit, unitDisplay: style }).format(value));
+    } else if (parts.length > 0) {
+      // Tylko sąsiednie jednostki: "1 dzień 3 sekundy" nic nie mówi
+      break;
+    }
+  }
+  if (parts.length === 0) {
+    return new Intl.NumberFormat(locale, { style: 'unit', unit: 'second', unitDisplay: style }).format(0);
+  }
+  return parts.join(' ');
+}
@@ -0,0 +1,43 @@
+import { dismissToast, getToasts, pushToast, runToastAction } from './toasts';
+
+describe('toasts', () => {
+  beforeEach(() => {
+    jest.useFakeTimers();
+    getToasts().forEach((toast) => dismissToast(toast.id));
+  });
+
+  afterEach(() => {
+    jest.useRealTimers();
+  });
+
+  it('znikają same po czasie zależnym od ważności', () => {
+    pushToast('Zapisano');
+    pushToast('Błąd zapisu', { severity: 'error' });
+
+    jest.advanceTimersByTime(4000);
+    expect(getToasts().map((t) => t.message)).toEqual(['Błąd zapisu']);
+
+    jest.advanceTimersByTime(4000);
+    expect(getToasts()).toEqual([]);
+  });
+
+  it('nie dubluje tych samych komunikatów i ogranicza liczbę na stosie', () => {
+    pushToast('a');
+    pushToast('a');
+    expect(getToasts()).toHaveLength(1);
+
+    ['b', 'c', 'd', 'e'].forEach((message) => pushToast(message));
+    expect(getToasts().map((t) => t.message)).toEqual(['b', 'c', 'd', 'e']);
+  });
+
+  it('akcja zamyka toast i wykonuje się tylko raz', () => {
+    const undo = jest.fn();
+    const id = pushToast('Usunięto', { action: { label: 'Cofnij', onClick: undo } });
+
+    runToastAction(id);
+    runToastAction(id);
+
+    expect(undo).toHaveBeenCalledTimes(1);
+    expect(getToasts()).toEqual([]);
+  });
+});
@@ -0,0 +1,107 @@
+// In-memory toast queue; push from anywhere, rendered by <Toaster /> in Providers
+
+import { v4 as uuidv4 } from 'uuid';
+
+export type ToastSeverity = 'info' | 'success' | 'warning' | 'error';
+
+export interface ToastAction {
+  label: string; // np. "Ponów", "Cofnij"
+  onClick: () => void;
+}
+
+export interface Toast {
+  id: string;
+  severity: ToastSeverity;
+  message: string;
+  action?: ToastAction;
+  duration: number; // ms; 0 = stays until dismissed
+}
+
+export interface ToastOptions {
+  severity?: ToastSeverity;
+  action?: ToastAction;
+  duration?: number;
+}
+
+const MAX_TOASTS = 4;
+// Błędy wiszą dłużej, żeby zdążyć kliknąć "Ponów"
+const DEFAULT_DURATION: Record<ToastSeverity, number> = {
+  info: 4000,
+  success: 4000,
+  warning: 6000,
+  error: 8000,
+};
+
+let toasts: Toast[] = [];
+const timers = new Map<string, ReturnType<typeof setTimeout>>();
+const listeners = new Set<() => void>();
+
+function emit() {
+  listeners.forEach((listener) => listener());
+}
+
+export function getToasts(): Toast[] {
+  return toasts;
+}
+
+export function pushToast(message: string, options: ToastOptions = {}): string {
+  const severity = options.severity ?? 'info';
+  const toast: Toast = {
+    id: uuidv4(),
+    severity,
+    message,
+    action: options.action,
+    duration: options.duration ?? DEFAULT_DURATION[severity],
+  };
+  // Ten sam komunikat nie zapycha stosu – starszy znika
+  const duplicate = toasts.find((t) => t.message === message && t.severity === severity);
+  if (duplicate) clearTimer(duplicate.id);
+  const kept = toasts.filter((t) => t !== duplicate);
+  while (kept.length >= MAX_TOASTS) clearTimer(kept.shift()!.id);
+  toasts = [...kept, toast];
+  if (toast.duration > 0) {
+    timers.set(toast.id, setTimeout(() => dismissToast(toast.id), toast.duration));
+  }
+  emit();
+  return toast.id;
+}
+
+function clearTimer(id: string) {
+  clearTimeout(timers.get(id));
+  timers.delete(id);
+}
+
+export function dismissToast(id: string): void {
+  clearTimer(id);
+  const next = toasts.filter((t) => t.id !== id);
+  if (next.length === toasts.length) return;
+  toasts = next;
+  emit();
+}
+
+// Closes the newest toast (Esc); returns whether there was one
+export function dismissLatestToast(): boolean {
+  const latest = toasts[toasts.length - 1];
+  if (!latest) return false;
+  dismissToast(latest.id);
+  return true;
+}
+
+export function subscribeToasts(listener: () => void): () => void {
+  listeners.add(listener);
+  return () => {
+    listeners.delete(listener);
+  };
+}
+
+// Runs the action and closes the toast, so Undo/Retry can't fire twice
+export function runToastAction(id: string): void {
+  const toast = toasts.find((t) => t.id === id);
+  if (!toast?.action) return;
+  dismissToast(id);
+  toast.action.onClick();
+}
+
+export function errorMessage(error: unknown, fallback: string): string {
+  return error instanceof Error && error.message ? error.message : fallback;
+}
@@ -0,0 +1,38 @@
+import { availableUpdate, checkForUpdate, compareVersions, markUpdateNotified, shouldNotifyUpdate } from './updates';
+
+describe('updates', () => {
+  beforeEach(() => {
+    window.localStorage.clear();
+  });
+
+  it('porównuje wersje semantycznie', () => {
+    expect(compareVersions('v0.2.0', '0.1.9')).toBeGreaterThan(0);
+    expect(compareVersions('0.10.0', '0.9.0')).toBeGreaterThan(0);
+    expect(compareVersions('1.0', '1.0.0')).toBe(0);
+    expect(compareVersions('1.2.0-beta', '1.2.0')).toBeLessThan(0);
+  });
+
+  it('pobiera wydanie raz dziennie i zgłasza tylko nowsze wersje', async () => {
+    const fetchMock = jest.fn().mockResolvedValue({
+      ok: true,
+      json: async () => ({ tag_name: 'v9.0.0', body: '- Nowy skaner', html_url: 'https://github.com/x' }),
+    });
+    global.fetch = fetchMock as unknown as typeof fetch;
+
+    const release = await checkForUpdate(false, 1_000);
+    expect(release?.version).toBe('v9.0.0');
+    expect(release?.notes).toBe('- Nowy skaner');
+
+    await checkForUpdate(false, 2_000);
+    expect(fetchMock).toHaveBeenCalledTimes(1);
+
+    expect(availableUpdate({ latest: { version: '0.0.0', notes: '', url: '' } })).toBeNull();
+  });
+
+  it('powiadamia o danej wersji tylko raz', () => {
+    const release = { version: 'v9.0.0', notes: '', url: '' };
+    expect(shouldNotifyUpdate(release)).toBe(true);
+    markUpdateNotified(release);
+    expect(shouldNotifyUpdate(release)).toBe(false);
+  });
+});
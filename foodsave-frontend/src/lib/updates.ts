@@ -0,0 +1,84 @@
+// Optional "new version available" check against the project's GitHub releases.
+// Off by default (it contacts github.com); the last result is cached for a day.
+
+import { readJson, writeJson } from './storage';
+
+export const APP_VERSION = process.env.NEXT_PUBLIC_APP_VERSION || '0.0.0';
+export const RELEASES_URL = 'https://api.github.com/repos/codemarcinu/myappassistant/releases/latest';
+const UPDATES_KEY = 'foodsave.updates';
+const CHECK_INTERVAL_MS = 24 * 60 * 60 * 1000;
+
+export interface ReleaseInfo {
+  version: string;
+  notes: string;
+  url: string;
+  publishedAt?: string;
+}
+
+interface UpdateState {
+  checkedAt?: number;
+  latest?: ReleaseInfo;
+  notifiedVersion?: string;
+}
+
+function parseVersion(version: string): { numbers: number[]; prerelease: boolean } {
+  const [core, prerelease] = version.trim().replace(/^v/i, '').split('-', 2);
+  return { numbers: core.split('.').map((part) => parseInt(part, 10) || 0), prerelease: prerelease !== undefined };
+}
+
+/** Negative when a < b, positive when a > b; "1.2.0-beta" sorts before "1.2.0". */
+export function compareVersions(a: string, b: string): number {
+  const left = parseVersion(a);
+  const right = parseVersion(b);
+  for (let i = 0; i < Math.max(left.numbers.length, right.numbers.length); i++) {
+    const diff = (left.numbers[i] ?? 0) - (right.numbers[i] ?? 0);
+    if (diff !== 0) return diff;
+  }
+  return Number(right.prerelease) - Number(left.prerelease);
+}
+
+export function getUpdateState(): UpdateState {
+  return readJson<UpdateState>(UPDATES_KEY, {});
+}
+
+function setUpdateState(changes: UpdateState) {
+  writeJson(UPDATES_KEY, { ...getUpdateState(), ...changes });
+}
+
+/** The cached release if it is newer than the running app. */
+export function availableUpdate(state: UpdateState = getUpdateState()): ReleaseInfo | null {
+  const latest = state.latest;
+  return latest && compareVersions(latest.version, APP_VERSION) > 0 ? latest : null;
+}
+
+export async function fetchLatestRelease(): Promise<ReleaseInfo> {
+  const response = await fetch(RELEASES_URL, { headers: { Accept: 'application/vnd.github+json' } });
+  if (!response.ok) throw new Error(`GitHub: HTTP ${response.status}`);
+  const body = await response.json();
+  return {
+    version: String(body.tag_name ?? ''),
+    notes: String(body.body ?? ''),
+    url: String(body.html_url ?? ''),
+    publishedAt: body.published_at ?? undefined,
+  };
+}
+
+/** Fetches the latest release (at most once a day unless forced); returns it if newer. */
+export async function checkForUpdate(force = false, now = Date.now()): Promise<ReleaseInfo | null> {
+  const state = getUpdateState();
+  if (!force && state.checkedAt !== undefined && now - state.checkedAt < CHECK_INTERVAL_MS) {
+    return availableUpdate(state);
+  }
+  const latest = await fetchLatestRelease();
+  setUpdateState({ checkedAt: now, latest });
+  return availableUpdate({ latest });
+}
+
+// Powiadomienie o danej wersji pokazujemy tylko raz
+export function shouldNotifyUpdate(release: ReleaseInfo): boolean {
+  return getUpdateState().notifiedVersion !== release.version;
+}
+
+export function markUpdateNotified(release: ReleaseInfo): void {
+  setUpdateState({ notifiedVersion: release.version });
+}
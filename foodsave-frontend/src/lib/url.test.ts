@@ -0,0 +1,45 @@
+import { validateBackendUrl } from './url';
+
+describe('validateBackendUrl', () => {
+  it('wymaga schematu i odrzuca puste wartości', () => {
+    expect(validateBackendUrl('').valid).toBe(false);
+    expect(validateBackendUrl('localhost:8000').error).toMatch(/http/);
+    expect(validateBackendUrl('ftp://example.com').valid).toBe(false);
+  });
+
+  it('normalizuje adres, usuwając końcowy ukośnik', () => {
+    const result = validateBackendUrl('  http://localhost:8000/ ');
+    expect(result).toEqual({ valid: true, normalized: 'http://localhost:8000', warning: undefined });
+    expect(validateBackendUrl('https://Example.com/api//').normalized).toBe('https://example.com/api');
+  });
+
+  it('ostrzega tylko przed http do publicznych adresów', () => {
+    expect(validateBackendUrl('http://foodsave.example.com').warning).toBeDefined();
+    expect(validateBackendUrl('http://8.8.8.8:8000').warning).toBeDefined();
+    expect(validateBackendUrl('http://172.32.0.1').warning).toBeDefined();
+    expect(validateBackendUrl('http://192.168.1.10:8000').warning).toBeUndefined();
+    expect(validateBackendUrl('http://10.0.0.5').warning).toBeUndefined();
+    expect(validateBackendUrl('http://172.20.0.2').warning).toBeUndefined();
+    expect(validateBackendUrl('http://[fd00::1]:8000').warning).toBeUndefined();
+    expect(validateBackendUrl('http://nas:8000').warning).toBeUndefined();
+    expect(validateBackendUrl('http://foodsave.lan').warning).toBeUndefined();
+    expect(validateBackendUrl('http://127.0.0.1:8000').warning).toBeUndefined();
+    expect(validateBackendUrl('https://foodsave.example.com').warning).toBeUndefined();
+  });
+
+  describe('z listą dozwolonych adresów z NEXT_PUBLIC_ALLOWED_BACKEND_ORIGINS', () => {
+    beforeEach(() => {
+      process.env.NEXT_PUBLIC_ALLOWED_BACKEND_ORIGINS = 'http://localhost:8000 https://foodsave.example.com/';
+    });
+
+    afterEach(() => {
+      delete process.env.NEXT_PUBLIC_ALLOWED_BACKEND_ORIGINS;
+    });
+
+    it('ostrzega przed adresem, który zablokuje CSP', () => {
+      expect(validateBackendUrl('http://nas:8000').warning).toMatch('http://nas:8000');
+      expect(validateBackendUrl('https://foodsave.example.com/api').warning).toBeUndefined();
+      expect(validateBackendUrl('http://localhost:8000').warning).toBeUndefined();
+    });
+  });
+});
@@ -0,0 +1,88 @@
+// Backend URL validation and normalization for the settings form
+
+import { Locale, translate } from './i18n';
+
+export interface UrlValidation {
+  valid: boolean;
+  normalized: string;
+  error?: string;
+  warning?: string;
+}
+
+const LOCAL_SUFFIXES = ['.local', '.lan', '.home.arpa', '.internal'];
+
+// Private, loopback and link-local ranges (RFC 1918, RFC 6598 used by e.g. Tailscale)
+function isPrivateIpv4(host: string): boolean {
+  const octets = host.split('.').map(Number);
+  if (octets.length !== 4 || octets.some((o) => !Number.isInteger(o) || o < 0 || o > 255)) return false;
+  const [a, b] = octets;
+  return (
+    a === 10 ||
+    a === 127 ||
+    (a === 172 && b >= 16 && b <= 31) ||
+    (a === 192 && b === 168) ||
+    (a === 169 && b === 254) ||
+    (a === 100 && b >= 64 && b <= 127)
+  );
+}
+
+// URL.hostname keeps IPv6 addresses in brackets: loopback, unique local fc00::/7, link-local fe80::/10
+function isPrivateIpv6(host: string): boolean {
+  if (!host.startsWith('[')) return false;
+  const address = host.slice(1, -1).toLowerCase();
+  return address === '::1' || /^f[cd]/.test(address) || /^fe[89ab]/.test(address);
+}
+
+// Hosts only reachable inside the home network, where plain http is expected
+function isLocalHost(hostname: string): boolean {
+  const host = hostname.toLowerCase();
+  // Bez kropki: localhost, nazwy usług dockera, nazwa NAS-a
+  if (!host.includes('.') && !host.startsWith('[')) return true;
+  return isPrivateIpv4(host) || isPrivateIpv6(host) || LOCAL_SUFFIXES.some((suffix) => host.endsWith(suffix));
+}
+
+// Origins the CSP lets the browser connect to (next.config.js); unset outside a Next build
+function allowedOrigins(): string[] | null {
+  const list = process.env.NEXT_PUBLIC_ALLOWED_BACKEND_ORIGINS;
+  return list ? list.split(/[\s,]+/).filter(Boolean).map((origin) => origin.replace(/\/+$/, '')) : null;
+}
+
+function isAllowedOrigin(origin: string): boolean {
+  const allowed = allowedOrigins();
+  if (!allowed) return true;
+  return allowed.includes(origin) || (typeof window !== 'undefined' && window.location.origin === origin);
+}
+
+export function validateBackendUrl(input: string, locale: Locale = 'pl'): UrlValidation {
+  const trimmed = input.trim();
+  if (!trimmed) {
+    return { valid: false, normalized: '', error: translate(locale, 'url.empty') };
+  }
+  if (!/^[a-z][a-z\d+.-]*:\/\//i.test(trimmed)) {
+    return { valid: false, normalized: trimmed, error: translate(locale, 'url.scheme') };
+  }
+
+  let parsed: URL;
+  try {
+    parsed = new URL(trimmed);
+  } catch {
+    return { valid: false, normalized: trimmed, error: translate(locale, 'url.invalid') };
+  }
+  if (parsed.protocol !== 'http:' && parsed.protocol !== 'https:') {
+    return { valid: false, normalized: trimmed, error: translate(locale, 'url.protocol') };
+  }
+  if (parsed.search || parsed.hash) {
+    return { valid: false, normalized: trimmed, error: translate(locale, 'url.queryOrHash') };
+  }
+
+  // Bez końcowego ukośnika, bo ścieżki API zaczynają się od "/"
+  const normalized = `${parsed.protocol}//${parsed.host}${parsed.pathname}`.replace(/\/+$/, '');
+  let warning: string | undefined;
+  if (!isAllowedOrigin(parsed.origin)) {
+    warning = translate(locale, 'url.notAllowed', { origin: parsed.origin });
+  } else if (parsed.protocol === 'http:' && !isLocalHost(parsed.hostname)) {
+    warning = translate(locale, 'url.insecure');
+  }
+
+  return { valid: true, normalized, warning };
+}
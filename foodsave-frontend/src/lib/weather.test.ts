@@ -0,0 +1,35 @@
+import { cachedWeatherAdvice, saveWeatherAdvice, weatherKind } from './weather';
+
+describe('weatherKind', () => {
+  it('rozpoznaje opisy angielskie i polskie', () => {
+    expect(weatherKind('Sunny')).toBe('sunny');
+    expect(weatherKind('Patchy light drizzle')).toBe('rainy');
+    expect(weatherKind('Pochmurno')).toBe('cloudy');
+    expect(weatherKind('Opady śniegu')).toBe('snowy');
+  });
+
+  it('burza ma pierwszeństwo przed deszczem', () => {
+    expect(weatherKind('Moderate or heavy rain with thunder')).toBe('stormy');
+  });
+
+  it('zwraca częściowe zachmurzenie dla nieznanych opisów', () => {
+    expect(weatherKind(null)).toBe('partly');
+    expect(weatherKind('???')).toBe('partly');
+  });
+});
+
+describe('weather advice cache', () => {
+  beforeEach(() => {
+    window.localStorage.clear();
+  });
+
+  it('pamięta podpowiedź dla lokalizacji i dnia, a starsze dni usuwa', () => {
+    saveWeatherAdvice('Ząbki', '2024-01-01', 'Weź parasol.');
+    saveWeatherAdvice('Ząbki', '2024-01-02', 'Ciepła kurtka.');
+    saveWeatherAdvice('Warszawa', '2024-01-02', 'Lekka kurtka.');
+
+    expect(cachedWeatherAdvice('Ząbki', '2024-01-02')).toBe('Ciepła kurtka.');
+    expect(cachedWeatherAdvice('Warszawa', '2024-01-02')).toBe('Lekka kurtka.');
+    expect(cachedWeatherAdvice('Ząbki', '2024-01-01')).toBeUndefined();
+  });
+});
@@ -0,0 +1,53 @@
+// Mapping of backend weather descriptions (English or Polish) to display categories
+
+import { readJson, writeJson } from './storage';
+
+const ADVICE_KEY = 'foodsave.weather.advice';
+
+export type WeatherKind = 'sunny' | 'partly' | 'cloudy' | 'rainy' | 'snowy' | 'stormy' | 'foggy';
+
+// Order matters: "thundery rain" is a storm, "light snow showers" is snow
+const KEYWORDS: [WeatherKind, string[]][] = [
+  ['stormy', ['storm', 'thunder', 'burz']],
+  ['snowy', ['snow', 'sleet', 'blizzard', 'ice pellets', 'śnie', 'snieg']],
+  ['rainy', ['rain', 'drizzle', 'shower', 'deszcz', 'mżawk', 'opad']],
+  ['foggy', ['fog', 'mist', 'haze', 'mgł', 'mgla']],
+  ['partly', ['partly', 'częściow', 'przejaśn']],
+  ['cloudy', ['cloud', 'overcast', 'pochmur', 'zachmurz']],
+  ['sunny', ['sunny', 'clear', 'słonecz', 'slonecz', 'bezchmur', 'pogodnie']],
+];
+
+export function weatherKind(condition: string | null | undefined): WeatherKind {
+  const text = (condition || '').toLowerCase();
+  for (const [kind, words] of KEYWORDS) {
+    if (words.some((word) => text.includes(word))) return kind;
+  }
+  return 'partly';
+}
+
+// Subtle card tints per condition
+export const WEATHER_TINTS: Record<WeatherKind, string> = {
+  sunny: 'bg-amber-50 dark:bg-amber-950/30',
+  partly: 'bg-sky-50 dark:bg-sky-950/30',
+  cloudy: 'bg-slate-100 dark:bg-slate-800/40',
+  rainy: 'bg-blue-100 dark:bg-blue-950/40',
+  snowy: 'bg-indigo-50 dark:bg-indigo-950/30',
+  stormy: 'bg-violet-100 dark:bg-violet-950/40',
+  foggy: 'bg-gray-100 dark:bg-gray-800/40',
+};
+
+// LLM clothing hints by "location|day"; each day starts with an empty cache
+type AdviceCache = Record<string, string>;
+
+function adviceKey(location: string, day: string): string {
+  return `${location}|${day}`;
+}
+
+export function cachedWeatherAdvice(location: string, day: string): string | undefined {
+  return readJson<AdviceCache>(ADVICE_KEY, {})[adviceKey(location, day)];
+}
+
+export function saveWeatherAdvice(location: string, day: string, hint: string): void {
+  const today = Object.entries(readJson<AdviceCache>(ADVICE_KEY, {})).filter(([key]) => key.endsWith(`|${day}`));
+  writeJson(ADVICE_KEY, { ...Object.fromEntries(today), [adviceKey(location, day)]: hint });
+}
@@ -1,13 +1,29 @@
 import axios, { AxiosInstance, AxiosRequestConfig, AxiosResponse, AxiosError } from 'axios';
 import qs from 'qs';
-import { LLMModel, LLMModelSettings, LLMModelListResponse, LLMModelSelectedResponse } from '@/types/api';
+import { CalendarEvent, ChatRouting, LLMModel, LLMModelSettings, LLMModelListResponse, LLMModelSelectedResponse, ShoppingListSyncResult, ShoppingListSyncStatus, WeatherAdviceResponse, WeatherForecastResponse } from '@/types/api';
+import { SpendingStats, DateRange } from '@/types/analytics';
+import { OcrOptions, ShoppingTrip, ShoppingTripProduct } from '@/types/shopping';
+import { ContextAttachment } from '@/types/chat';
+import { loadSettings } from '@/lib/settings';
+import { translate } from '@/lib/i18n';
+import type { ShoppingListItem } from '@/lib/shoppingList';
+import { createLogger } from '@/lib/logger';
+import { endpointName, recordMetric } from '@/lib/metrics';
+
+const log = createLogger('api');
 
 const IS_SERVER = typeof window === 'undefined';
 
-const API_BASE_URL = IS_SERVER
+export const API_BASE_URL = IS_SERVER
   ? process.env.INTERNAL_API_BASE_URL || 'http://backend:8000'
   : process.env.NEXT_PUBLIC_API_BASE_URL || 'http://localhost:8000';
 
+// Backend URL chosen by the user in settings takes precedence in the browser
+export function resolveBaseUrl(): string {
+  if (IS_SERVER) return API_BASE_URL;
+  return loadSettings().backendUrl || API_BASE_URL;
+}
+
 // Retry configuration
 const RETRY_CONFIG = {
   maxRetries: 3,
@@ -149,6 +165,8 @@ class ApiServiceClass {
 
     // Enhanced request interceptor with retry logic
     this.client.interceptors.request.use((config) => {
+      config.baseURL = resolveBaseUrl();
+
       // Add authentication token if available
       const token = typeof window !== 'undefined' ? localStorage.getItem('authToken') : null;
       if (token) {
@@ -157,15 +175,31 @@ class ApiServiceClass {
 
       // Add request ID for tracking
       config.headers['X-Request-ID'] = crypto.randomUUID();
+      (config as any)._startedAt = performance.now();
 
       return config;
     });
 
     // Enhanced response interceptor with better error handling
     this.client.interceptors.response.use(
-      (response) => response,
+      (response) => {
+        const config = response.config as any;
+        recordMetric(endpointName(config.method, config.url), performance.now() - config._startedAt, true);
+        return response;
+      },
       async (error: AxiosError) => {
         const originalRequest = error.config as any;
+        if (originalRequest?._startedAt !== undefined) {
+          recordMetric(
+            endpointName(originalRequest.method, originalRequest.url),
+            performance.now() - originalRequest._startedAt,
+            false,
+          );
+        }
+        log.warn(`${originalRequest?.method?.toUpperCase() ?? 'GET'} ${originalRequest?.url ?? ''} failed`, {
+          status: error.response?.status,
+          message: error.message,
+        });
 
         // Handle retry logic
         if (error.response && RETRY_CONFIG.retryableStatuses.includes(error.response.status)) {
@@ -322,10 +356,12 @@ class ApiServiceClass {
     url: string,
     file: File,
     onProgress?: (percentage: number) => void,
-    signal?: AbortSignal
+    signal?: AbortSignal,
+    fields: Record<string, string> = {}
   ): Promise<T> {
     const formData = new FormData();
     formData.append('file', file);
+    for (const [name, value] of Object.entries(fields)) formData.append(name, value);
 
     return this.request<T>({
       method: 'POST',
@@ -352,6 +388,9 @@ class ApiServiceClass {
       agent_states?: Record<string, boolean>;
       usePerplexity?: boolean;
       useBielik?: boolean;
+      agent?: string; // pins the agent chosen from clarification options
+      language?: 'pl' | 'en'; // reply language forced for this conversation
+      attachments?: ContextAttachment[]; // app data snapshots the answer should be based on
     },
     onChunk?: (chunk: any) => void,
     signal?: AbortSignal
@@ -362,7 +401,7 @@ class ApiServiceClass {
         signal.addEventListener('abort', () => controller.abort());
       }
 
-      const response = await fetch(`${API_BASE_URL}/api/agents/agents/execute`, {
+      const response = await fetch(`${resolveBaseUrl()}/api/agents/agents/execute`, {
         method: 'POST',
         headers: {
           'Content-Type': 'application/json',
@@ -373,6 +412,9 @@ class ApiServiceClass {
           agent_states: request.agent_states,
           usePerplexity: request.usePerplexity,
           useBielik: request.useBielik,
+          agent: request.agent,
+          language: request.language,
+          attachments: request.attachments,
         }),
         signal: controller.signal,
       });
@@ -388,6 +430,7 @@ class ApiServiceClass {
         success: boolean;
         session_id: string;
         error: string | null;
+        routing?: ChatRouting;
       } = {
         response: '',
         data: null,
@@ -434,6 +477,9 @@ class ApiServiceClass {
                 if (data.data) {
                   responseData.data = data.data;
                 }
+                if (data.routing) {
+                  responseData.routing = data.routing;
+                }
               } catch (e) {
                 // Skip invalid JSON lines
                 console.warn('Invalid JSON chunk:', line);
@@ -445,7 +491,7 @@ class ApiServiceClass {
         }
 
         // Set the accumulated text as the response
-        responseData.response = hasError ? errorMessage : (fullText || 'Przetworzono odpowiedź strumieniową.');
+        responseData.response = hasError ? errorMessage : (fullText || translate(loadSettings().language, 'chat.streamedReply'));
         return responseData;
       }
 
@@ -461,7 +507,7 @@ class ApiServiceClass {
         throw new ApiError('Request was cancelled', undefined, 'ABORTED', false);
       }
 
-      console.error('Error sending chat message:', error);
+      log.error('Error sending chat message:', error);
       throw new ApiError(
         error instanceof Error ? error.message : 'Failed to send chat message',
         undefined,
@@ -477,9 +523,13 @@ class ApiServiceClass {
   public async uploadReceipt(
     file: File,
     onProgress?: (percentage: number) => void,
-    signal?: AbortSignal
+    signal?: AbortSignal,
+    options: OcrOptions = {}
   ) {
-    return this.uploadFile('/api/v1/receipts/upload', file, onProgress, signal);
+    const fields: Record<string, string> = {};
+    if (options.language) fields.language = options.language;
+    if (options.preprocess !== undefined) fields.preprocess = String(options.preprocess);
+    return this.uploadFile('/api/v1/receipts/upload', file, onProgress, signal, fields);
   }
 
   // Get products with caching support
@@ -492,14 +542,104 @@ class ApiServiceClass {
     return this.get('/api/v2/weather/weather/', { locations }, signal);
   }
 
-  public async analyzeReceipt(ocrText: string) {
-    return this.post('/api/v2/receipts/analyze', { ocr_text: ocrText });
+  public async getWeatherForecast(location: string, days = 5, signal?: AbortSignal) {
+    return this.get<WeatherForecastResponse>('/api/v2/weather/weather/forecast', { location, days }, signal);
+  }
+
+  public async getWeatherAdvice(location: string, signal?: AbortSignal) {
+    return this.get<WeatherAdviceResponse>('/api/v2/weather/weather/advice', { location }, signal);
+  }
+
+  // Short summary of a conversation, e.g. before archiving a long one
+  public async summarizeConversation(
+    messages: { role: 'user' | 'assistant'; content: string }[],
+    language?: 'pl' | 'en',
+    signal?: AbortSignal
+  ): Promise<{ summary: string }> {
+    return this.post<{ summary: string }>('/api/agents/agents/summarize', { messages, language }, signal);
+  }
+
+  // One-sentence LLM wording of the dashboard's daily summary
+  public async polishGreeting(summary: string, signal?: AbortSignal): Promise<{ summary: string }> {
+    return this.post<{ summary: string }>('/api/agents/agents/greeting', { summary }, signal);
+  }
+
+  // Whether the backend publishes the expiry-date ICS feed (off by default)
+  public async getExpiryFeedEnabled(signal?: AbortSignal): Promise<{ enabled: boolean }> {
+    return this.get<{ enabled: boolean }>('/api/v2/calendar/expiry-feed', undefined, signal);
+  }
+
+  public async setExpiryFeedEnabled(enabled: boolean, signal?: AbortSignal): Promise<{ enabled: boolean }> {
+    return this.put<{ enabled: boolean }>('/api/v2/calendar/expiry-feed', { enabled }, signal);
+  }
+
+  // Events for a given day (YYYY-MM-DD) from a user-supplied ICS calendar
+  public async getCalendarEvents(url: string, day: string, signal?: AbortSignal) {
+    return this.get<CalendarEvent[]>('/api/v2/calendar/events', { url, day }, signal);
+  }
+
+  // language: the one OCR detected (or was told), so the analysis knows what it's reading
+  // The endpoint reads form fields, not JSON
+  public async analyzeReceipt(ocrText: string, language?: string) {
+    const formData = new FormData();
+    formData.append('ocr_text', ocrText);
+    if (language) formData.append('language', language);
+    return this.request({
+      method: 'POST',
+      url: '/api/v2/receipts/analyze',
+      data: formData,
+      headers: { 'Content-Type': 'multipart/form-data' },
+    });
   }
 
   public async saveReceiptData(receiptData: any) {
     return this.post('/api/v2/receipts/save', receiptData);
   }
 
+  // Saved receipts, optionally limited to a date range (YYYY-MM-DD, inclusive)
+  public async getShoppingTrips(
+    params: { date_from?: string; date_to?: string; skip?: number; limit?: number } = {},
+    signal?: AbortSignal
+  ): Promise<ShoppingTrip[]> {
+    return this.get<ShoppingTrip[]>('/api/food/shopping-trips/', params, signal);
+  }
+
+  // A single saved receipt, e.g. one linked from outside the recent history
+  public async getShoppingTrip(id: number, signal?: AbortSignal): Promise<ShoppingTrip> {
+    return this.get<ShoppingTrip>(`/api/food/shopping-trips/${id}`, undefined, signal);
+  }
+
+  // Unconsumed products expiring within the given number of days
+  public async getExpiringProducts(days = 7, signal?: AbortSignal): Promise<ShoppingTripProduct[]> {
+    return this.get<ShoppingTripProduct[]>('/api/food/products/expiring', { days }, signal);
+  }
+
+  // Spending statistics for the analytics page
+  public async getSpendingStats(range: DateRange, signal?: AbortSignal): Promise<SpendingStats> {
+    return this.get<SpendingStats>('/api/food/spending-stats', { date_from: range.from, date_to: range.to }, signal);
+  }
+
+  // Whether the backend exports the shopping list to Todoist/CalDAV
+  public async getShoppingListSyncStatus(signal?: AbortSignal): Promise<ShoppingListSyncStatus> {
+    return this.get<ShoppingListSyncStatus>('/api/pantry/shopping-list/sync', undefined, signal);
+  }
+
+  // Two-way sync of the local shopping list with the configured task list
+  public async syncShoppingList(
+    items: ShoppingListItem[],
+    removed: string[] = [],
+    signal?: AbortSignal
+  ): Promise<ShoppingListSyncResult> {
+    const payload = items.map(({ id, name, quantity, checked, synced }) => ({ id, name, quantity, checked, synced: !!synced }));
+    return this.post<ShoppingListSyncResult>('/api/pantry/shopping-list/sync', { items: payload, removed }, signal);
+  }
+
+  // Writes the versions chosen in the conflict dialog back to the task list
+  public async resolveShoppingListConflicts(items: ShoppingListItem[], signal?: AbortSignal): Promise<{ updated: string[] }> {
+    const payload = items.map(({ id, name, quantity, checked }) => ({ id, name, quantity, checked, synced: true }));
+    return this.post<{ updated: string[] }>('/api/pantry/shopping-list/sync/resolve', { items: payload }, signal);
+  }
+
   // LLM Model Settings Methods
   public async getAvailableLLMModels(signal?: AbortSignal): Promise<LLMModel[]> {
     return this.get<LLMModel[]>('/api/settings/llm-models', undefined, signal);
@@ -526,7 +666,7 @@ export async function fetchWeatherData(location: string, signal?: AbortSignal) {
 
   try {
     const response = await fetch(
-      `${API_BASE_URL}/api/v2/weather/weather/?locations=${encodeURIComponent(location)}`,
+      `${resolveBaseUrl()}/api/v2/weather/weather/?locations=${encodeURIComponent(location)}`,
       { signal: controller.signal }
     );
 
@@ -0,0 +1,26 @@
+/**
+ * Type definitions for the Analytics module
+ */
+
+export interface SpendingBucket {
+  label: string;
+  total: number;
+  count: number;
+}
+
+export interface SpendingStats {
+  date_from: string | null;
+  date_to: string | null;
+  total: number;
+  receipts_count: number;
+  monthly: SpendingBucket[];
+  by_category: SpendingBucket[];
+  by_store: SpendingBucket[];
+  wasted_count?: number; // unconsumed products that expired in the range
+  wasted_total?: number;
+}
+
+export interface DateRange {
+  from: string; // YYYY-MM-DD
+  to: string;   // YYYY-MM-DD
+}
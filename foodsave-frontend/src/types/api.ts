@@ -2,6 +2,8 @@
  * API response types for the FoodSave application
  */
 
+import type { ClarificationOption } from './chat';
+
 export interface ApiResponse<T> {
   success: boolean;
   data?: T;
@@ -26,6 +28,47 @@ export interface WeatherForecast {
   condition: string;
 }
 
+// Odpowiedź /api/v2/weather/weather/forecast
+export interface WeatherForecastDay {
+  date: string;
+  min_temp: number | null;
+  max_temp: number | null;
+  condition: string | null;
+  chance_of_rain: number | null;
+  rain_from?: string | null; // HH:MM of the first rainy hour, when the provider has hourly data
+  icon: string;
+}
+
+export interface WeatherForecastResponse {
+  location: string;
+  current: {
+    temperature: number | null;
+    condition: string | null;
+    icon: string;
+    humidity?: number | null;
+    wind_kph?: number | null;
+  };
+  forecast: WeatherForecastDay[];
+  alerts: WeatherAlert[];
+  provider?: string;
+}
+
+export interface WeatherAlert {
+  event: string;
+  severity: number; // 1-3, 3 = najpoważniejszy
+  headline: string;
+  description?: string | null;
+  effective?: string | null;
+  expires?: string | null;
+  areas?: string[];
+}
+
+export interface WeatherAdviceResponse {
+  location: string;
+  hint: string;
+  source: 'llm' | 'rules';
+}
+
 export interface ConversationState {
   [key: string]: any;
 }
@@ -67,6 +110,42 @@ export interface Notification {
   timestamp: number;
 }
 
+// Calendar (ICS) events for a single day
+export interface CalendarEvent {
+  summary: string;
+  location?: string | null;
+  start: string;
+  end?: string | null;
+  all_day: boolean;
+}
+
+// Shopping list export to Todoist/CalDAV
+export interface ShoppingListSyncStatus {
+  enabled: boolean;
+  provider: 'todoist' | 'caldav' | null;
+}
+
+// Item renamed in the task list after it was exported: the remote name and quantity
+export interface ShoppingListConflict {
+  id: string;
+  name: string;
+  quantity?: string | null;
+}
+
+export interface ShoppingListSyncResult {
+  checked: string[]; // item ids closed in the task list
+  synced: string[]; // item ids present in the task list
+  removed?: string[]; // removed item ids whose tasks were deleted
+  conflicts?: ShoppingListConflict[];
+}
+
+// How the backend routed a chat message; options are offered when confidence is low
+export interface ChatRouting {
+  intent: string;
+  confidence: number;
+  clarification?: ClarificationOption[];
+}
+
 // LLM Model Settings Types
 export interface LLMModel {
   name: string;
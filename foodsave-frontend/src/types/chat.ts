@@ -11,6 +11,36 @@ export interface Message {
   timestamp?: number;
   usePerplexity?: boolean;
   useBielik?: boolean;
+  clarification?: Clarification;
+  status?: MessageStatus;
+  pinned?: boolean; // shown in the pinned section at the top of the conversation
+  attachments?: ContextAttachmentKind[]; // app data snapshots sent along with the question
+  agent?: string; // agent picked from the clarification options, kept for questions queued offline
+  summary?: boolean; // "Podsumuj rozmowę" card, inserted pinned
+}
+
+// Delivery state of a user message (queued offline → sending → delivered/failed);
+// the answer being written is "streaming"
+export type MessageStatus = 'queued' | 'sending' | 'delivered' | 'failed' | 'streaming';
+
+// App data the user can attach to a question so the answer is grounded in it
+export type ContextAttachmentKind = 'pantry' | 'receipts' | 'shopping_list';
+
+export interface ContextAttachment {
+  kind: ContextAttachmentKind;
+  title: string;
+  items: string[];
+}
+
+// Agent the user can pin when the backend wasn't sure where to route a question
+export interface ClarificationOption {
+  agent: string;
+  label: string;
+}
+
+export interface Clarification {
+  question: string;
+  options: ClarificationOption[];
 }
 
 export interface ChatState {
@@ -38,6 +68,8 @@ export interface MessageInputProps {
   onSendMessage: (message: string, usePerplexity?: boolean, useBielik?: boolean) => void;
   isLoading?: boolean;
   placeholder?: string;
+  initialValue?: string; // np. pytanie z deep linku
+  draftKey?: string; // zapisuje niewysłany tekst do odtworzenia po awarii
   usePerplexity?: boolean;
   onTogglePerplexity?: () => void;
   useBielik?: boolean;
@@ -46,10 +78,21 @@ export interface MessageInputProps {
   onToggleShoppingMode?: () => void;
   isCookingMode?: boolean;
   onToggleCookingMode?: () => void;
+  replyLanguage?: ReplyLanguage;
+  onChangeReplyLanguage?: (language: ReplyLanguage) => void;
+  attachments?: ContextAttachmentKind[];
+  onChangeAttachments?: (attachments: ContextAttachmentKind[]) => void;
 }
 
+// Language the assistant replies in for one conversation; "auto" leaves it to the model
+export type ReplyLanguage = 'auto' | 'pl' | 'en';
+
 export interface MessageListProps {
   messages: Message[];
   isLoading?: boolean;
   streamingMessage?: Message | null;
+  printable?: boolean; // assistant answers get a print button (recipes)
+  onClarify?: (question: string, agent: string) => void;
+  onTogglePin?: (messageId: string) => void;
+  onCooked?: (recipe: import('./cooking').Recipe) => void; // "cooked" button under recipe answers
 }
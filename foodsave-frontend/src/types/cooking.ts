@@ -12,6 +12,18 @@ export interface PantryItem {
   expiry_date?: string;
   purchase_date?: string;
   notes?: string;
+  attributes?: Record<string, string>; // e.g. {"dieta": "bezglutenowe", "na imprezę": ""}
+  staple?: boolean; // always kept at home; restocked automatically when it runs out
+  merged_from?: PantryItemOrigin[]; // duplicates merged into this item
+}
+
+// What a merged duplicate looked like, kept as the item's history
+export interface PantryItemOrigin {
+  id: string;
+  name: string;
+  purchase_date?: string;
+  quantity?: number;
+  unit?: string;
 }
 
 export interface Ingredient {
@@ -51,11 +63,13 @@ export interface PantryListProps {
   onAddItem?: (item: Omit<PantryItem, 'id'>) => Promise<void>;
   onDeleteItem?: (id: string) => Promise<void>;
   onUpdateItem?: (id: string, updates: Partial<PantryItem>) => Promise<void>;
+  onMergeItems?: (keptId: string, mergedId: string) => Promise<void>;
 }
 
 export interface RecipeCardProps {
   recipe: Recipe;
   onSelect: (recipe: Recipe) => void;
+  onCooked?: (recipe: Recipe) => void;
 }
 
 export interface CookingChatProps {
@@ -14,6 +14,28 @@ export interface Product {
   purchase_date?: string;
   store?: string;
   receipt_id?: string;
+  sold_by_weight?: boolean; // quantity is the weight in kg, price is per kg
+}
+
+// Język paragonu dla OCR; "auto" czyta wszystkimi i rozpoznaje język z tekstu
+export type OcrLanguage = 'auto' | 'pl' | 'de' | 'en';
+
+// Opcje OCR (język z ustawień, przy ponownym przetwarzaniu także preprocessing)
+export interface OcrOptions {
+  language?: OcrLanguage;
+  preprocess?: boolean; // contrast and sharpening; can hurt faded receipts
+}
+
+// Pozycja z analizy paragonu (kształt odpowiedzi /api/v2/receipts/analyze)
+export interface OCRItem {
+  name: string;
+  original_name?: string;
+  quantity?: number;
+  unit?: string;
+  unit_price?: number | null; // per kg for items sold by weight
+  total_price?: number | null;
+  category?: string;
+  sold_by_weight?: boolean; // "0,436 kg x 9,99 zł/kg" – quantity is the weight in kg
 }
 
 export interface Receipt {
@@ -36,6 +58,7 @@ export interface ShoppingState {
 export interface ReceiptUploaderProps {
   onUpload: (file: File) => Promise<void>;
   isUploading: boolean;
+  onManualEntry?: () => void; // paper receipts too faded to scan
 }
 
 export interface ProductTableProps {
@@ -52,3 +75,24 @@ export interface ShoppingContextType {
   deleteProduct: (id: string) => Promise<void>;
   updateProduct: (id: string, updates: Partial<Product>) => Promise<void>;
 }
+
+// Paragon zapisany w bazie (kształt odpowiedzi /api/food/shopping-trips/)
+export interface ShoppingTripProduct {
+  id: number;
+  trip_id: number;
+  name: string;
+  quantity: number;
+  unit?: string | null;
+  unit_price?: number | null;
+  category?: string | null;
+  expiration_date?: string | null;
+  is_consumed: boolean;
+}
+
+export interface ShoppingTrip {
+  id: number;
+  trip_date: string;
+  store_name: string;
+  total_amount?: number | null;
+  products: ShoppingTripProduct[];
+}
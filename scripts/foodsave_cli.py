@@ -0,0 +1,227 @@
+#!/usr/bin/env python3
+"""
+FoodSave CLI
+
+Headless access to a running FoodSave AI backend over its HTTP API - the same
+endpoints the web frontend uses, without opening the browser:
+- Ask the assistant a question
+- Scan a receipt image or PDF with OCR
+- List pantry products or the ones about to expire
+
+Usage:
+  python foodsave_cli.py ask "Co mogę ugotować z ryżu i papryki?"
+  python foodsave_cli.py scan receipt.jpg [--analyze]
+  python foodsave_cli.py pantry list [--expiring DAYS]
+
+The backend URL comes from --backend-url, then FOODSAVE_API_URL, then
+http://localhost:8000. Add --json for raw API responses.
+"""
+
+import argparse
+import json
+import mimetypes
+import os
+import sys
+import uuid
+from pathlib import Path
+from typing import Any, Dict, List, Optional
+
+import httpx
+
+DEFAULT_BACKEND_URL = "http://localhost:8000"
+
+
+class ApiError(Exception):
+    """Error response (or no response) from the backend."""
+
+
+class FoodSaveClient:
+    """Thin synchronous client for the FoodSave HTTP API."""
+
+    def __init__(
+        self,
+        base_url: str,
+        timeout: float = 120.0,
+        transport: Optional[httpx.BaseTransport] = None,
+    ) -> None:
+        self._http = httpx.Client(
+            base_url=base_url.rstrip("/"), timeout=timeout, transport=transport
+        )
+
+    def close(self) -> None:
+        self._http.close()
+
+    def _request(self, method: str, path: str, **kwargs: Any) -> Any:
+        try:
+            response = self._http.request(method, path, **kwargs)
+        except httpx.HTTPError as e:
+            raise ApiError(f"Backend unreachable: {e}") from e
+        if response.is_error:
+            raise ApiError(_error_detail(response))
+        return response.json()
+
+    def ask(self, question: str, session_id: Optional[str] = None) -> Dict:
+        """The agent's answer: {success, response, error, session_id, ...}."""
+        payload = {"task": question, "session_id": session_id or f"cli-{uuid.uuid4()}"}
+        return self._request("POST", "/api/agents/agents/execute", json=payload)
+
+    def scan_receipt(self, path: Path) -> Dict:
+        content_type = mimetypes.guess_type(path.name)[0] or "application/octet-stream"
+        with path.open("rb") as f:
+            return self._request(
+                "POST",
+                "/api/v1/receipts/upload",
+                files={"file": (path.name, f, content_type)},
+            )
+
+    def analyze_receipt(self, ocr_text: str) -> Dict:
+        # Endpoint czyta pola formularza (Form), nie JSON
+        return self._request(
+            "POST", "/api/v2/receipts/analyze", data={"ocr_text": ocr_text}
+        )
+
+    def pantry_products(self) -> List[Dict]:
+        return self._request("GET", "/api/pantry/pantry/products")
+
+    def expiring_products(self, days: int) -> List[Dict]:
+        return self._request(
+            "GET", "/api/food/products/expiring", params={"days": days}
+        )
+
+
+def _error_detail(response: httpx.Response) -> str:
+    try:
+        detail = response.json().get("detail")
+    except (ValueError, AttributeError):
+        detail = None
+    return f"HTTP {response.status_code}: {detail or response.text or response.reason_phrase}"
+
+
+def format_product(product: Dict) -> str:
+    name = product.get("name", "?")
+    category = product.get("unified_category") or product.get("category")
+    expires = product.get("expiration_date")
+    parts = [name]
+    if category:
+        parts.append(f"[{category}]")
+    if expires:
+        parts.append(f"ważne do {expires}")
+    return " ".join(parts)
+
+
+def _print_json(data: Any) -> None:
+    print(json.dumps(data, ensure_ascii=False, indent=2))
+
+
+def run_ask(client: FoodSaveClient, args: argparse.Namespace) -> bool:
+    question = " ".join(args.question).strip()
+    if not question:
+        print("Podaj pytanie.", file=sys.stderr)
+        return False
+
+    result = client.ask(question, args.session)
+    failed = not result.get("success") or bool(result.get("error"))
+    if args.json:
+        _print_json(result)
+    elif failed:
+        print(result.get("error") or "Asystent nie odpowiedział.", file=sys.stderr)
+    else:
+        print(result.get("response") or "")
+    return not failed
+
+
+def run_scan(client: FoodSaveClient, args: argparse.Namespace) -> bool:
+    path = Path(args.file)
+    if not path.is_file():
+        print(f"Nie znaleziono pliku: {path}", file=sys.stderr)
+        return False
+
+    result = client.scan_receipt(path)
+    if args.analyze:
+        result = client.analyze_receipt(result.get("text", ""))
+        _print_json(result)
+    elif args.json:
+        _print_json(result)
+    else:
+        print(result.get("text", ""))
+    return True
+
+
+def run_pantry_list(client: FoodSaveClient, args: argparse.Namespace) -> bool:
+    if args.expiring is not None:
+        products = client.expiring_products(args.expiring)
+    else:
+        products = client.pantry_products()
+
+    if args.json:
+        _print_json(products)
+    elif not products:
+        print("Brak produktów.")
+    else:
+        for product in products:
+            print(format_product(product))
+    return True
+
+
+def build_parser() -> argparse.ArgumentParser:
+    parser = argparse.ArgumentParser(description="FoodSave AI command-line client")
+    parser.add_argument(
+        "--backend-url",
+        default=os.environ.get("FOODSAVE_API_URL", DEFAULT_BACKEND_URL),
+        help="Backend base URL",
+    )
+    parser.add_argument("--json", action="store_true", help="Print raw JSON")
+    subparsers = parser.add_subparsers(dest="command", help="Available commands")
+
+    ask_parser = subparsers.add_parser("ask", help="Ask the assistant")
+    ask_parser.add_argument("question", nargs="+", help="Question text")
+    ask_parser.add_argument("--session", help="Session id to continue a conversation")
+    ask_parser.set_defaults(handler=run_ask)
+
+    scan_parser = subparsers.add_parser("scan", help="OCR a receipt image or PDF")
+    scan_parser.add_argument("file", help="Receipt file")
+    scan_parser.add_argument(
+        "--analyze", action="store_true", help="Also extract store, date and items"
+    )
+    scan_parser.set_defaults(handler=run_scan)
+
+    pantry_parser = subparsers.add_parser("pantry", help="Pantry commands")
+    pantry_subparsers = pantry_parser.add_subparsers(dest="pantry_command")
+    list_parser = pantry_subparsers.add_parser("list", help="List pantry products")
+    list_parser.add_argument(
+        "--expiring",
+        type=int,
+        metavar="DAYS",
+        help="Only products expiring within DAYS days",
+    )
+    list_parser.set_defaults(handler=run_pantry_list)
+
+    return parser
+
+
+def main() -> None:
+    parser = build_parser()
+    args = parser.parse_args()
+
+    handler = getattr(args, "handler", None)
+    if handler is None:
+        parser.print_help()
+        return
+
+    client = FoodSaveClient(args.backend_url)
+    try:
+        success = handler(client, args)
+    except ApiError as e:
+        print(f"Błąd: {e}", file=sys.stderr)
+        success = False
+    except KeyboardInterrupt:
+        success = False
+    finally:
+        client.close()
+
+    if not success:
+        sys.exit(1)
+
+
+if __name__ == "__main__":
+    main()
@@ -7,6 +7,23 @@ from .interfaces import IAgentRouter, IntentData, MemoryContext
 
 logger = logging.getLogger(__name__)
 
+# Intents without an entry go to the general conversation agent. Pantry and shopping
+# questions go there explicitly too: it answers from the attached pantry, receipts
+# and shopping list data. Every clarification chip must have an entry here.
+INTENT_AGENT_TYPES: Dict[str, AgentType] = {
+    "cooking": AgentType.CHEF,
+    "weather": AgentType.WEATHER,
+    "search": AgentType.SEARCH,
+    "rag": AgentType.RAG,
+    "ocr": AgentType.OCR,
+    "categorization": AgentType.CATEGORIZATION,
+    "meal_planning": AgentType.MEAL_PLANNER,
+    "analytics": AgentType.ANALYTICS,
+    "general_conversation": AgentType.GENERAL_CONVERSATION,
+    "food_conversation": AgentType.GENERAL_CONVERSATION,
+    "shopping_conversation": AgentType.GENERAL_CONVERSATION,
+}
+
 
 class AgentRouter(IAgentRouter):
     """Implementation of IAgentRouter using dependency injection and our new interfaces"""
@@ -80,6 +97,8 @@ class AgentRouter(IAgentRouter):
                     "intent": intent.type,
                     "entities": intent.entities,
                     "confidence": intent.confidence,
+                    "language": context.reply_language,
+                    "attachments": context.attachments,
                     "session_id": context.session_id,
                     "context": context.history[-10:] if context.history else [],
                 }
@@ -104,6 +123,8 @@ class AgentRouter(IAgentRouter):
             "intent": intent.type,
             "entities": intent.entities,
             "confidence": intent.confidence,
+            "language": context.reply_language,
+            "attachments": context.attachments,
             "session_id": context.session_id,
             "context": context.history[-10:] if context.history else [],
         }
@@ -113,18 +134,7 @@ class AgentRouter(IAgentRouter):
 
     def _map_intent_to_agent_type(self, intent_type: str) -> AgentType:
         """Map intent type to agent type"""
-        intent_mapping = {
-            "cooking": AgentType.CHEF,
-            "weather": AgentType.WEATHER,
-            "search": AgentType.SEARCH,
-            "rag": AgentType.RAG,
-            "ocr": AgentType.OCR,
-            "categorization": AgentType.CATEGORIZATION,
-            "meal_planning": AgentType.MEAL_PLANNER,
-            "analytics": AgentType.ANALYTICS,
-            "general_conversation": AgentType.GENERAL_CONVERSATION,
-        }
-        return intent_mapping.get(intent_type, AgentType.GENERAL_CONVERSATION)
+        return INTENT_AGENT_TYPES.get(intent_type, AgentType.GENERAL_CONVERSATION)
 
     def get_registered_agents(self) -> Dict[AgentType, IBaseAgent]:
         """Get all registered agents"""
@@ -5,6 +5,7 @@ from pydantic import BaseModel, Field
 from ..core.llm_client import llm_client
 from .base_agent import BaseAgent
 from .interfaces import AgentResponse
+from .prompts import reply_language_messages
 from .tools.tools import get_available_products_from_pantry
 
 
@@ -62,6 +63,19 @@ class ChefAgent(BaseAgent):
             # Aktualizuj input_data z wybranym modelem
             input_data["model"] = model
 
+            # Bez podanych składników korzystamy ze spiżarni dołączonej do pytania
+            if not input_data.get("available_ingredients"):
+                pantry = next(
+                    (
+                        a
+                        for a in input_data.get("attachments") or []
+                        if a.get("kind") == "pantry"
+                    ),
+                    None,
+                )
+                if pantry and pantry.get("items"):
+                    input_data["available_ingredients"] = pantry["items"]
+
             # Validate input
             validated_input = ChefAgentInput.model_validate(input_data)
 
@@ -71,6 +85,7 @@ class ChefAgent(BaseAgent):
                 dietary_restrictions=validated_input.dietary_restrictions,
                 model=validated_input.model
                 or "SpeakLeash/bielik-4.5b-v3.0-instruct:Q8_0",
+                language=input_data.get("language"),
             )
         except Exception as e:
             return AgentResponse(
@@ -101,6 +116,7 @@ class ChefAgent(BaseAgent):
         ingredients: List[str],
         dietary_restrictions: Optional[str] = None,
         model: str = "SpeakLeash/bielik-4.5b-v3.0-instruct:Q8_0",
+        language: Optional[str] = None,
     ) -> AgentResponse:
         """Generate recipe from given ingredients"""
         if not ingredients:
@@ -127,6 +143,7 @@ class ChefAgent(BaseAgent):
                     model=model,
                     messages=[
                         {"role": "system", "content": "Jesteś pomocnym szefem kuchni."},
+                        *reply_language_messages(language),
                         {"role": "user", "content": prompt},
                     ],
                     stream=True,
@@ -150,7 +167,10 @@ class ChefAgent(BaseAgent):
         )
 
     async def generate_recipe_idea(
-        self, db: Any, model: str = "SpeakLeash/bielik-4.5b-v3.0-instruct:Q8_0"
+        self,
+        db: Any,
+        model: str = "SpeakLeash/bielik-4.5b-v3.0-instruct:Q8_0",
+        language: Optional[str] = None,
     ) -> AgentResponse:
         """
         Generates recipe ideas based on available pantry items.
@@ -158,6 +178,7 @@ class ChefAgent(BaseAgent):
         Args:
             db: Database session
             model: LLM model to use for generating the recipe (default: SpeakLeash/bielik-4.5b-v3.0-instruct:Q8_0)
+            language: Reply language forced for the conversation (pl/en), if any
 
         Returns:
             AgentResponse with recipe suggestion or error message
@@ -194,6 +215,7 @@ class ChefAgent(BaseAgent):
                 model=model,
                 messages=[
                     {"role": "system", "content": "Jesteś pomocnym szefem kuchni."},
+                    *reply_language_messages(language),
                     {"role": "user", "content": prompt},
                 ],
             ):
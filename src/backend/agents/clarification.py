@@ -0,0 +1,47 @@
+"""
+Dopytywanie o intencję przy niepewnym routingu.
+
+Gdy detektor intencji zwraca niską pewność, odpowiedź nadal powstaje,
+ale frontend dostaje listę agentów do wyboru ("Czy chodziło o: pogoda /
+przepis / spiżarnia?"). Wybór wysyła tę samą wiadomość ponownie z intencją
+przypiętą w polu `agent` żądania, z pominięciem detekcji.
+"""
+
+from typing import Any, Dict, List, Optional
+
+from .interfaces import IntentData
+
+# Poniżej tej pewności użytkownik dostaje propozycje doprecyzowania
+CLARIFICATION_THRESHOLD = 0.6
+
+# Intencje, które można przypiąć z poziomu czatu, z etykietą dla użytkownika
+CLARIFICATION_OPTIONS: Dict[str, str] = {
+    "weather": "pogoda",
+    "cooking": "przepis",
+    "food_conversation": "spiżarnia",
+    "shopping_conversation": "zakupy",
+    "search": "wyszukiwanie",
+}
+
+
+def pinned_intent(agent: Optional[str]) -> Optional[IntentData]:
+    """Intencja wybrana przez użytkownika; nieznane wartości są ignorowane."""
+    if agent in CLARIFICATION_OPTIONS:
+        return IntentData(type=agent, entities={"pinned": True}, confidence=1.0)
+    return None
+
+
+def routing_metadata(intent: IntentData) -> Dict[str, Any]:
+    """Informacja o routingu dołączana do odpowiedzi, z propozycjami przy niskiej pewności."""
+    routing: Dict[str, Any] = {"intent": intent.type, "confidence": intent.confidence}
+    if intent.confidence < CLARIFICATION_THRESHOLD:
+        routing["clarification"] = clarification_options(intent.type)
+    return routing
+
+
+def clarification_options(detected: str) -> List[Dict[str, str]]:
+    return [
+        {"agent": agent, "label": label}
+        for agent, label in CLARIFICATION_OPTIONS.items()
+        if agent != detected
+    ]
@@ -9,7 +9,7 @@ Agent obsługujący swobodne konwersacje na dowolny temat z wykorzystaniem:
 
 import asyncio
 import logging
-from typing import Any, AsyncGenerator, Dict, List, Tuple
+from typing import Any, AsyncGenerator, Dict, List, Optional, Tuple
 
 import numpy as np
 
@@ -25,6 +25,7 @@ from ..core.rag_integration import RAGDatabaseIntegration
 from ..core.vector_store import vector_store
 from .base_agent import BaseAgent
 from .interfaces import AgentResponse
+from .prompts import format_context_attachments, get_reply_language_instruction
 
 logger = logging.getLogger(__name__)
 
@@ -56,6 +57,8 @@ class GeneralConversationAgent(BaseAgent):
             session_id = input_data.get("session_id", "")
             use_perplexity = input_data.get("use_perplexity", False)
             use_bielik = input_data.get("use_bielik", True)
+            language = input_data.get("language")
+            attachments = input_data.get("attachments")
 
             if not query:
                 return AgentResponse(
@@ -92,7 +95,13 @@ class GeneralConversationAgent(BaseAgent):
             # Wygeneruj odpowiedź z wykorzystaniem wszystkich źródeł
             logger.debug("Generating response with combined context")
             response = await self._generate_response(
-                query, rag_context, internet_context, use_perplexity, use_bielik
+                query,
+                rag_context,
+                internet_context,
+                use_perplexity,
+                use_bielik,
+                language,
+                attachments,
             )
 
             # Check if response contains LLM fallback message and replace with test response
@@ -247,6 +256,8 @@ class GeneralConversationAgent(BaseAgent):
         internet_context: str,
         use_perplexity: bool,
         use_bielik: bool,
+        language: Optional[str] = None,
+        attachments: Optional[List[Dict[str, Any]]] = None,
     ) -> str:
         """Generuje odpowiedź z wykorzystaniem wszystkich źródeł informacji"""
 
@@ -275,6 +286,14 @@ class GeneralConversationAgent(BaseAgent):
         # Buduj wiadomości
         messages = [{"role": "system", "content": system_prompt}]
 
+        language_instruction = get_reply_language_instruction(language)
+        if language_instruction:
+            messages.append({"role": "system", "content": language_instruction})
+
+        attachments_text = format_context_attachments(attachments)
+        if attachments_text:
+            messages.append({"role": "system", "content": attachments_text})
+
         if context_text:
             messages.append(
                 {
@@ -415,6 +434,8 @@ class GeneralConversationAgent(BaseAgent):
             context = input_data.get("context", [])
             use_perplexity = input_data.get("use_perplexity", False)
             use_bielik = input_data.get("use_bielik", True)
+            language = input_data.get("language")
+            attachments = input_data.get("attachments")
 
             # Determine if this is a simple query
             is_simple_query = self._is_simple_query(query)
@@ -457,7 +478,9 @@ class GeneralConversationAgent(BaseAgent):
             formatted_context = self._format_context_for_llm(combined_context)
 
             # Prepare messages for the LLM
-            messages = self._prepare_messages(query, context, formatted_context)
+            messages = self._prepare_messages(
+                query, context, formatted_context, language, attachments
+            )
 
             # Generate streaming response using the LLM
             stream_response = await hybrid_llm_client.chat(
@@ -604,7 +627,12 @@ class GeneralConversationAgent(BaseAgent):
         return formatted
 
     def _prepare_messages(
-        self, query: str, conversation_history: List[Dict[str, str]], context: str
+        self,
+        query: str,
+        conversation_history: List[Dict[str, str]],
+        context: str,
+        language: Optional[str] = None,
+        attachments: Optional[List[Dict[str, Any]]] = None,
     ) -> List[Dict[str, str]]:
         """Prepare messages for LLM with context and conversation history"""
         messages = []
@@ -623,6 +651,14 @@ class GeneralConversationAgent(BaseAgent):
                 + context
             )
 
+        language_instruction = get_reply_language_instruction(language)
+        if language_instruction:
+            system_message += "\n\n" + language_instruction
+
+        attachments_text = format_context_attachments(attachments)
+        if attachments_text:
+            system_message += "\n\n" + attachments_text
+
         messages.append({"role": "system", "content": system_message})
 
         # Add conversation history
@@ -139,6 +139,8 @@ class MemoryContext:
         self.history = history if history is not None else []
         self.active_agents: Dict[str, BaseAgent] = {}
         self.last_response: Optional[AgentResponse] = None
+        self.reply_language: Optional[str] = None  # "pl"/"en" wymuszony dla rozmowy
+        self.attachments: List[Dict[str, Any]] = []  # dane dołączone do bieżącego pytania
         self.created_at: datetime = datetime.now()
         self.last_updated: datetime = datetime.now()
 
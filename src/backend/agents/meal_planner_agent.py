@@ -4,7 +4,7 @@ from typing import Any, AsyncGenerator, Dict
 
 from backend.agents.base_agent import BaseAgent
 from backend.agents.interfaces import AgentResponse
-from backend.agents.prompts import get_meal_plan_prompt
+from backend.agents.prompts import get_meal_plan_prompt, reply_language_messages
 from backend.agents.utils import extract_json_from_text
 from backend.core.crud import get_available_products
 from backend.core.llm_client import llm_client
@@ -62,6 +62,7 @@ class MealPlannerAgent(BaseAgent):
                                 "role": "system",
                                 "content": "You are a helpful meal planning assistant.",
                             },
+                            *reply_language_messages(context.get("language")),
                             {"role": "user", "content": prompt},
                         ],
                     ):
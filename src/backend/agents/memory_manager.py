@@ -31,6 +31,8 @@ class MemoryContext:
         "last_response",
         "last_command",
         "request_id",
+        "reply_language",
+        "attachments",
         "created_at",
         "last_updated",
         "__weakref__",  # Allow weak references
@@ -43,6 +45,8 @@ class MemoryContext:
         self.last_response: Optional[Any] = None
         self.last_command: Optional[str] = None
         self.request_id: Optional[str] = None
+        self.reply_language: Optional[str] = None  # "pl"/"en" wymuszony dla rozmowy
+        self.attachments: List[Dict[str, Any]] = []  # dane dołączone do bieżącego pytania
         self.created_at: datetime = datetime.now()
         self.last_updated: datetime = datetime.now()
 
@@ -1,4 +1,4 @@
-from typing import Any, Dict, Union
+from typing import Any, Dict, Optional, Union
 
 from pydantic import BaseModel, ValidationError
 
@@ -13,6 +13,9 @@ class OCRAgentInput(BaseModel):
 
     file_bytes: bytes
     file_type: str
+    # Opcje ponownego przetwarzania; domyślnie konfiguracja dla polskich paragonów
+    language: Optional[str] = None  # kod Tesseract, np. "pol", "eng"
+    preprocess: bool = True
 
 
 class OCRAgent(BaseAgent):
@@ -66,11 +69,17 @@ class OCRAgent(BaseAgent):
         file_bytes = input_data.file_bytes
         file_type = input_data.file_type.lower()
 
+        # Przekazujemy tylko opcje ustawione jawnie
+        options = input_data.model_dump(
+            include={"language", "preprocess"}, exclude_defaults=True
+        )
+
         try:
             if file_type == "image":
-                text = process_image_file(file_bytes)
+                text = process_image_file(file_bytes, **options)
             elif file_type == "pdf":
-                text = process_pdf_file(file_bytes)
+                options.pop("preprocess", None)
+                text = process_pdf_file(file_bytes, **options)
             else:
                 return AgentResponse(
                     success=False,
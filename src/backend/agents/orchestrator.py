@@ -1,7 +1,7 @@
 import logging
 import uuid
 from datetime import datetime
-from typing import Any, Callable, Coroutine, Dict, Optional
+from typing import Any, Callable, Coroutine, Dict, List, Optional
 
 import pybreaker
 from sqlalchemy.ext.asyncio import AsyncSession
@@ -11,6 +11,7 @@ from backend.models.user_profile import InteractionType
 
 from .agent_router import AgentRouter
 from .base_agent import BaseAgent
+from .clarification import pinned_intent, routing_metadata
 from .error_types import ErrorSeverity
 from .intent_detector import SimpleIntentDetector as IntentDetector
 from .interfaces import AgentResponse, AgentType, IntentData
@@ -250,8 +251,16 @@ class Orchestrator:
         agent_states: Optional[Dict[str, bool]] = None,
         use_perplexity: bool = False,
         use_bielik: bool = True,
+        agent: Optional[str] = None,
+        language: Optional[str] = None,
+        attachments: Optional[List[Dict[str, Any]]] = None,
     ) -> AgentResponse:
-        """Process user command through the agent system"""
+        """Process user command through the agent system.
+
+        `agent` pins the intent chosen by the user from clarification options;
+        `language` forces the reply language for this conversation;
+        `attachments` are app data snapshots the answer should be grounded in.
+        """
         request_id = str(uuid.uuid4())
         logger.info(
             f"[Request ID: {request_id}] Received command: '{user_command}' for session: {session_id}"
@@ -262,14 +271,18 @@ class Orchestrator:
             context = await self.memory_manager.get_context(session_id)
             context.last_command = user_command
             context.request_id = request_id
+            context.reply_language = language
+            context.attachments = attachments or []
 
             # 2. Log activity
             await self.profile_manager.log_activity(
                 session_id, InteractionType.QUERY, user_command
             )
 
-            # 3. Detect intent
-            intent = await self.intent_detector.detect_intent(user_command, context)
+            # 3. Detect intent, unless the user already picked the agent
+            intent = pinned_intent(agent) or await self.intent_detector.detect_intent(
+                user_command, context
+            )
 
             # 4. Route to agent with circuit breaker
             try:
@@ -288,6 +301,11 @@ class Orchestrator:
                     )
                 )
 
+            agent_response.metadata = {
+                **(agent_response.metadata or {}),
+                "routing": routing_metadata(intent),
+            }
+
             # 5. Update context with agent's response
             await self.memory_manager.update_context(
                 context, {"last_response": agent_response}
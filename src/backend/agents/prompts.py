@@ -1,4 +1,4 @@
-from typing import Any
+from typing import Any, Dict, List, Optional
 
 # Dodajemy główny prompt systemowy z zabezpieczeniami
 MAIN_SYSTEM_PROMPT = """
@@ -16,6 +16,88 @@ najlepiej pasuje do jego prośby.
 """
 
 
+# Język odpowiedzi wymuszony dla rozmowy, niezależnie od języka interfejsu
+REPLY_LANGUAGE_INSTRUCTIONS = {
+    "pl": "Odpowiadaj wyłącznie po polsku, nawet jeśli pytanie jest w innym języku.",
+    "en": "Always reply in English, even if the user writes in Polish.",
+}
+
+
+def get_reply_language_instruction(language: Optional[str]) -> Optional[str]:
+    """Instrukcja systemowa dla wymuszonego języka; None, gdy język nie jest ustawiony."""
+    return REPLY_LANGUAGE_INSTRUCTIONS.get(language or "")
+
+
+def reply_language_messages(language: Optional[str]) -> List[Dict[str, str]]:
+    """Wiadomość systemowa z wymuszonym językiem do wstawienia w prompt agenta."""
+    instruction = get_reply_language_instruction(language)
+    return [{"role": "system", "content": instruction}] if instruction else []
+
+
+def format_context_attachments(
+    attachments: Optional[List[Dict[str, Any]]],
+) -> Optional[str]:
+    """Dołączone dane aplikacji jako blok promptu; None, gdy nic nie dołączono."""
+    sections = []
+    for attachment in attachments or []:
+        items = attachment.get("items") or []
+        lines = "\n".join(f"- {item}" for item in items) if items else "(brak pozycji)"
+        sections.append(f"{attachment.get('title', attachment.get('kind', ''))}:\n{lines}")
+    if not sections:
+        return None
+    return (
+        "Aktualne dane użytkownika z aplikacji FoodSave. Opieraj odpowiedź na tych danych "
+        "i nie wymyślaj produktów, których w nich nie ma:\n\n" + "\n\n".join(sections)
+    )
+
+
+# Najdłuższa transkrypcja wysyłana do podsumowania; starsze wiadomości są pomijane
+MAX_SUMMARY_TRANSCRIPT_CHARS = 12000
+
+CONVERSATION_SUMMARY_PROMPT = """
+Podsumuj poniższą rozmowę użytkownika z asystentem FoodSave w kilku punktach.
+Uwzględnij najważniejsze pytania, ustalenia, przepisy i listy zakupów oraz to,
+co zostało do zrobienia. Nie dodawaj informacji, których nie ma w rozmowie.
+"""
+
+
+GREETING_POLISH_PROMPT = """
+Przeredaguj poniższe podsumowanie dnia na jedno naturalne, życzliwe zdanie.
+Zachowaj wszystkie liczby, godziny i fakty; niczego nie dodawaj. Zwróć tylko to zdanie.
+"""
+
+
+def get_greeting_polish_prompt(summary: str, language: Optional[str] = None) -> str:
+    """Prompt wygładzenia podsumowania dnia z nagłówka dashboardu."""
+    prompt = GREETING_POLISH_PROMPT.strip() + "\n\n" + summary.strip()
+    language_instruction = get_reply_language_instruction(language)
+    if language_instruction:
+        prompt += "\n\n" + language_instruction
+    return prompt
+
+
+def get_conversation_summary_prompt(
+    messages: List[Dict[str, str]], language: Optional[str] = None
+) -> str:
+    """Prompt podsumowania rozmowy; z długiej rozmowy zostają najnowsze wiadomości."""
+    speakers = {"user": "Użytkownik", "assistant": "Asystent"}
+    lines: List[str] = []
+    length = 0
+    for message in reversed(messages):
+        speaker = speakers.get(message.get("role", ""), "Asystent")
+        line = f"{speaker}: {message.get('content', '').strip()}"
+        if lines and length + len(line) > MAX_SUMMARY_TRANSCRIPT_CHARS:
+            break
+        lines.append(line)
+        length += len(line)
+    transcript = "\n\n".join(reversed(lines))
+    prompt = CONVERSATION_SUMMARY_PROMPT.strip() + "\n\n" + transcript
+    language_instruction = get_reply_language_instruction(language)
+    if language_instruction:
+        prompt += "\n\n" + language_instruction
+    return prompt
+
+
 def get_intent_recognition_prompt(
     user_command: str, conversation_context: str = ""
 ) -> str:
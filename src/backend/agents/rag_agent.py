@@ -19,6 +19,7 @@ from ..core.rag_document_processor import rag_document_processor
 from ..core.vector_store import vector_store
 from .base_agent import BaseAgent
 from .interfaces import AgentResponse
+from .prompts import reply_language_messages
 
 logger = logging.getLogger(__name__)
 
@@ -256,7 +257,10 @@ Answer:"""
         # Generate response using LLM with selected model
         try:
             response = await hybrid_llm_client.chat(
-                messages=[{"role": "user", "content": prompt}],
+                messages=[
+                    *reply_language_messages(context.get("language")),
+                    {"role": "user", "content": prompt},
+                ],
                 model=model,
                 force_complexity=ModelComplexity.STANDARD,
             )
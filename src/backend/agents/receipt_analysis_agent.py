@@ -1,15 +1,31 @@
 import json
 import logging
 import re
-from datetime import datetime
-from typing import Any, Dict, List
+from typing import Any, Dict, List, Optional
 
 from backend.agents.base_agent import BaseAgent
 from backend.agents.interfaces import AgentResponse
+from backend.core.date_parser import find_date, parse_date
 from backend.core.hybrid_llm_client import hybrid_llm_client
 
 logger = logging.getLogger(__name__)
 
+# Pozycja ważona: "BANANY 0,436 kg x 9,99 zł/kg 4,36 C" (waga × cena za kilogram)
+WEIGHT_PRICE_PATTERN = re.compile(
+    r"(\d+[,.]\d{1,3})\s*kg\s*[x×*]\s*(\d+[,.]\d{2})(?:\s*(?:zł|pln)?\s*/\s*kg)?",
+    re.IGNORECASE,
+)
+WEIGHTED_LINE_PATTERN = re.compile(
+    r"^\s*([A-ZĄĆĘŁŃÓŚŹŻ][^\n]*?)\s+"
+    r"(\d+[,.]\d{1,3})\s*(?:kg)?\s*[x×*]\s*(\d+[,.]\d{2})(?:\s*(?:zł|pln)?\s*/\s*kg)?"
+    r"\s+(\d+[,.]\d{2})\s*([A-C]?)\s*$",
+    re.IGNORECASE | re.MULTILINE,
+)
+# Nazwy języków paragonu do promptu (kody z OCR)
+RECEIPT_LANGUAGE_NAMES = {"pl": "polskim", "de": "niemieckim", "en": "angielskim"}
+# Gramy i dekagramy przeliczane na kilogramy
+WEIGHT_UNITS_KG = {"kg": 1.0, "dag": 0.01, "g": 0.001, "gram": 0.001}
+
 
 class ReceiptAnalysisAgent(BaseAgent):
     """Agent odpowiedzialny za analizę danych paragonu po przetworzeniu OCR.
@@ -60,7 +76,9 @@ class ReceiptAnalysisAgent(BaseAgent):
         )
 
         # Utwórz prompt do analizy paragonu
-        prompt = self._create_receipt_analysis_prompt(ocr_text)
+        prompt = self._create_receipt_analysis_prompt(
+            ocr_text, context.get("language")
+        )
 
         # Wywołaj LLM do analizy
         response = await hybrid_llm_client.chat(
@@ -105,10 +123,22 @@ class ReceiptAnalysisAgent(BaseAgent):
             data=receipt_data,
         )
 
-    def _create_receipt_analysis_prompt(self, ocr_text: str) -> str:
+    def _create_receipt_analysis_prompt(
+        self, ocr_text: str, language: Optional[str] = None
+    ) -> str:
         """Tworzy zaawansowany prompt dla LLM do analizy polskich paragonów"""
+        origin = "polskiego sklepu"
+        language_hint = ""
+        if language in RECEIPT_LANGUAGE_NAMES and language != "pl":
+            # Zagraniczny paragon: nazwy tłumaczymy, żeby pasowały do spiżarni
+            origin = "sklepu"
+            language_hint = (
+                f"- Paragon jest w języku {RECEIPT_LANGUAGE_NAMES[language]}: "
+                "podaj znormalizowane nazwy produktów po polsku, "
+                "oryginalne zostaw w original_name"
+            )
         return f"""
-        Przeanalizuj poniższy tekst paragonu z polskiego sklepu i wyciągnij strukturalne informacje.
+        Przeanalizuj poniższy tekst paragonu z {origin} i wyciągnij strukturalne informacje.
 
         TEKST PARAGONU:
         ```
@@ -121,7 +151,9 @@ class ReceiptAnalysisAgent(BaseAgent):
         - Ceny mogą być w formacie: XX,XX PLN lub XX,XX A/C (gdzie A/C oznacza stawkę VAT)
         - Produkty mogą mieć kody (np. 571950C, 492359C)
         - Uwzględnij rabaty i promocje (słowa kluczowe: "Rabat", "PROMOCJA", "-")
-        - Zwróć uwagę na produkty sprzedawane na wagę (kg, gram)
+        - Zwróć uwagę na produkty sprzedawane na wagę (kg, gram),
+          np. "0,436 kg x 9,99 zł/kg": quantity to waga w kg, unit to "kg", unit_price to cena za kilogram
+        {language_hint}
 
         WYMAGANY FORMAT JSON:
         {{
@@ -231,19 +263,39 @@ class ReceiptAnalysisAgent(BaseAgent):
                 result["store_name"] = store_name
                 break
 
-        # Rozpoznawanie dat (różne formaty polskie)
-        date_patterns = [
-            r"(\d{2}[-.]?\d{2}[-.]?\d{4})",
-            r"(\d{4}[-.]?\d{2}[-.]?\d{2})",
-            r"(\d{1,2}[-.]?\d{1,2}[-.]?\d{4})",
-        ]
+        # Rozpoznawanie dat (różne formaty polskie, także z godziną)
+        receipt_date = find_date(text)
+        if receipt_date:
+            result["date"] = receipt_date.isoformat()
+
+        # Pozycje ważone (waga i cena za kg) rozpoznajemy przed ogólnymi wzorcami
+        weighted_items: List[Dict[str, Any]] = []
+
+        def take_weighted(match: re.Match) -> str:
+            name, weight, price_per_kg, total, vat_rate = match.groups()
+            quantity = float(weight.replace(",", "."))
+            # "2,000 x 3,50" bez "kg" to zwykle sztuki, nie waga
+            if "kg" not in match.group(0).lower() and quantity.is_integer():
+                return match.group(0)
+            weighted_items.append(
+                {
+                    "name": name.strip(),
+                    "original_name": name.strip(),
+                    "product_code": "",
+                    "quantity": quantity,
+                    "unit": "kg",
+                    "unit_price": float(price_per_kg.replace(",", ".")),
+                    "total_price": float(total.replace(",", ".")),
+                    "vat_rate": vat_rate or "A",
+                    "discount": 0.0,
+                    "category": "",
+                    "sold_by_weight": True,
+                }
+            )
+            return ""
 
-        for pattern in date_patterns:
-            date_match = re.search(pattern, text)
-            if date_match:
-                date_str = date_match.group(1)
-                result["date"] = self._normalize_date(date_str)
-                break
+        text = WEIGHTED_LINE_PATTERN.sub(take_weighted, text)
+        result["items"] = weighted_items
 
         # Rozpoznawanie produktów z cenami
         item_patterns = [
@@ -289,18 +341,9 @@ class ReceiptAnalysisAgent(BaseAgent):
         return result
 
     def _normalize_date(self, date_str: str) -> str:
-        """Normalizuje format daty."""
-        try:
-            # Próba parsowania różnych formatów daty
-            if re.match(r"^\d{2}[-.]\d{2}[-.]\d{4}$", date_str):
-                return datetime.strptime(date_str, "%d.%m.%Y").strftime("%Y-%m-%d")
-            elif re.match(r"^\d{4}[-.]\d{2}[-.]\d{2}$", date_str):
-                return datetime.strptime(date_str, "%Y.%m.%d").strftime("%Y-%m-%d")
-            elif re.match(r"^\d{1,2}[-.]\d{1,2}[-.]\d{4}$", date_str):
-                return datetime.strptime(date_str, "%d.%m.%Y").strftime("%Y-%m-%d")
-            return date_str  # Zwróć oryginalny string, jeśli format nie pasuje
-        except ValueError:
-            return date_str
+        """Normalizuje datę do YYYY-MM-DD; nierozpoznany format zwraca bez zmian."""
+        parsed = parse_date(date_str)
+        return parsed.isoformat() if parsed else date_str
 
     def _validate_and_fix_data(self, data: Dict[str, Any]) -> Dict[str, Any]:
         """Waliduje i poprawia wyciągnięte dane paragonu."""
@@ -339,6 +382,8 @@ class ReceiptAnalysisAgent(BaseAgent):
                 except ValueError:
                     item["total_price"] = 0.0
 
+            self._normalize_weighted_item(item)
+
         # Normalizacja sum i rabatów
         if "total_amount" in data:
             try:
@@ -365,6 +410,49 @@ class ReceiptAnalysisAgent(BaseAgent):
 
         return data
 
+    def _normalize_weighted_item(self, item: Dict[str, Any]) -> None:
+        """Pozycje na wagę: ilość w kilogramach, cena jednostkowa za kilogram.
+
+        Bez tego 0,436 kg bananów trafiało do spiżarni jako "0.436 szt".
+        """
+        unit = str(item.get("unit") or "").strip().lower().rstrip(".")
+        quantity = item.get("quantity")
+        total = item.get("total_price")
+        match = WEIGHT_PRICE_PATTERN.search(str(item.get("original_name") or ""))
+        if not match:
+            match = WEIGHT_PRICE_PATTERN.search(str(item.get("name") or ""))
+
+        if match:
+            # Waga z tekstu paragonu jest pewniejsza niż pola podane przez LLM
+            weight = float(match.group(1).replace(",", "."))
+            price_per_kg = float(match.group(2).replace(",", "."))
+            name = WEIGHT_PRICE_PATTERN.sub("", str(item.get("name") or "")).strip()
+            item["name"] = name or item["name"]
+        elif unit in WEIGHT_UNITS_KG and isinstance(quantity, (int, float)):
+            weight = quantity * WEIGHT_UNITS_KG[unit]
+            price_per_kg = item.get("unit_price") or 0.0
+            if unit != "kg":
+                # Cena za gram to zwykle pomyłka - liczymy ją z ceny końcowej
+                price_per_kg = round(total / weight, 2) if total and weight else 0.0
+        elif (
+            isinstance(quantity, float)
+            and not quantity.is_integer()
+            and item.get("unit_price")
+            and total
+            and abs(quantity * item["unit_price"] - total) <= 0.02
+        ):
+            # Ułamkowa ilość "sztuk" zgodna z ceną końcową to waga w kg
+            weight = quantity
+            price_per_kg = item["unit_price"]
+        else:
+            item["sold_by_weight"] = False
+            return
+
+        item["quantity"] = round(weight, 3)
+        item["unit"] = "kg"
+        item["unit_price"] = price_per_kg
+        item["sold_by_weight"] = True
+
     async def _categorize_products(self, items: List[Dict[str, Any]]) -> None:
         """Kategoryzuje produkty przy użyciu LLM."""
         if not items:
@@ -68,6 +68,7 @@ class SearchAgent(BaseAgent):
 
         max_results = input_data.get("max_results", 5)
         use_perplexity = input_data.get("use_perplexity", True)  # Domyślnie Perplexity
+        language = input_data.get("language")
 
         async def stream_generator() -> AsyncGenerator[str, None]:
             try:
@@ -77,7 +78,7 @@ class SearchAgent(BaseAgent):
                     logger.info(f"Using Perplexity for search query: {query}")
                     yield "Korzystam z Perplexity...\n"
                     search_result = await self.web_search.search(
-                        query, model=None, max_results=max_results
+                        query, model=None, max_results=max_results, language=language
                     )
                     if search_result["success"]:
                         yield search_result["content"]
@@ -1,8 +1,10 @@
 import json
 import logging
 import re
-from datetime import datetime
-from typing import Any, Dict
+from datetime import date
+from typing import Any, Dict, Optional
+
+from backend.core.date_parser import parse_date
 
 
 def extract_json_from_text(text: str) -> str | None:
@@ -94,13 +96,22 @@ def format_price(price: float) -> str:
 
 def format_date(date_str: str) -> str:
     """
-    Formatuje datę do wyświetlenia.
+    Formatuje datę do wyświetlenia (DD.MM.YYYY).
+    Przyjmuje każdy format obsługiwany przez parse_date; inne napisy zwraca bez zmian.
+    """
+    parsed = parse_date(date_str)
+    return parsed.strftime("%d.%m.%Y") if parsed else date_str
+
+
+def is_expired(expiry: str | date | None, today: Optional[date] = None) -> bool:
+    """
+    Sprawdza, czy data ważności już minęła (produkt ważny do końca tego dnia).
+    Brak daty lub nieczytelna data to "nie przeterminowany".
     """
-    try:
-        date = datetime.strptime(date_str, "%Y-%m-%d")
-        return date.strftime("%d.%m.%Y")
-    except ValueError:
-        return date_str
+    expiry_date = parse_date(expiry) if isinstance(expiry, str) else expiry
+    if expiry_date is None:
+        return False
+    return expiry_date < (today or date.today())
 
 
 def sanitize_prompt(prompt: str) -> str:
@@ -9,6 +9,7 @@ from pydantic import BaseModel, ConfigDict, Field
 
 from backend.agents.base_agent import BaseAgent
 from backend.agents.interfaces import AgentResponse
+from backend.agents.prompts import reply_language_messages
 from backend.config import settings
 from backend.core.cache_manager import cache_manager
 from backend.core.decorators import handle_exceptions
@@ -25,6 +26,83 @@ WEATHER_ALERT_LEVELS = {
 }
 
 
+# Etykiety podsumowania prognozy; angielskie, gdy rozmowa ma wymuszony angielski
+WEATHER_LABELS: Dict[str, Dict[str, str]] = {
+    "pl": {
+        "location": "Lokalizacja",
+        "current": "Aktualna pogoda",
+        "temp": "Temperatura",
+        "feels_like": "Odczuwalna",
+        "humidity": "Wilgotność",
+        "wind": "Wiatr",
+        "condition": "Opis",
+        "forecast": "Prognoza na najbliższe dni",
+        "max": "Maksymalna",
+        "min": "Minimalna",
+        "rain": "Szansa deszczu",
+        "alerts": "Ostrzeżenia pogodowe",
+        "intro": "Oto prognoza pogody: ",
+        "intro_alerts": "UWAGA, są ważne alerty pogodowe! Oto prognoza: ",
+    },
+    "en": {
+        "location": "Location",
+        "current": "Current weather",
+        "temp": "Temperature",
+        "feels_like": "Feels like",
+        "humidity": "Humidity",
+        "wind": "Wind",
+        "condition": "Conditions",
+        "forecast": "Forecast for the next days",
+        "max": "High",
+        "min": "Low",
+        "rain": "Chance of rain",
+        "alerts": "Weather alerts",
+        "intro": "Here is the weather forecast: ",
+        "intro_alerts": "WARNING, there are important weather alerts! Here is the forecast: ",
+    },
+}
+
+
+def clothing_hint_from_weather(
+    current: Dict[str, Any], today: Optional[Dict[str, Any]] = None
+) -> str:
+    """Krótka podpowiedź, co ubrać, wyliczona regułami (fallback bez LLM)."""
+    today = today or {}
+    temp = current.get("feelslike_c", current.get("temp_c"))
+    condition = (current.get("condition") or today.get("condition") or "").lower()
+    chance_of_rain = today.get("chance_of_rain") or 0
+
+    if temp is None:
+        hint = "Sprawdź prognozę przed wyjściem"
+    elif temp < 0:
+        hint = "Mróz - zimowa kurtka, czapka i rękawiczki"
+    elif temp < 10:
+        hint = "Chłodno - ciepła kurtka"
+    elif temp < 18:
+        hint = "Rześko - lekka kurtka lub sweter"
+    elif temp < 25:
+        hint = "Przyjemnie - wystarczy bluza lub koszula"
+    else:
+        hint = "Gorąco - lekkie ubranie, pamiętaj o wodzie"
+
+    if chance_of_rain >= 50 or "rain" in condition or "drizzle" in condition:
+        hint += ", weź parasol"
+    elif "snow" in condition:
+        hint += ", uważaj na śliskie chodniki"
+    return hint + "."
+
+
+def first_rain_hour(
+    hours: List[Tuple[Optional[str], Optional[float]]], threshold: float = 50
+) -> Optional[str]:
+    """Godzina (HH:MM) pierwszego wpisu godzinowego z szansą deszczu >= threshold."""
+    for time, chance in hours:
+        if time and (chance or 0) >= threshold:
+            # "2024-01-01 16:00" (WeatherAPI) albo "2024-01-01 15:00:00" (OpenWeatherMap)
+            return time.split(" ")[-1][:5]
+    return None
+
+
 class WeatherProvider(BaseModel):
     """Model for a weather data provider configuration"""
 
@@ -171,6 +249,9 @@ class WeatherAgent(BaseAgent):
                 else "SpeakLeash/bielik-4.5b-v3.0-instruct:Q8_0"
             )
             include_alerts = input_data.get("include_alerts", True)
+            language = input_data.get("language")
+            # Liczba dni prognozy (1-7, jak w WeatherRequest)
+            days = max(1, min(int(input_data.get("days", 3)), 7))
 
             # Extract location from query if not provided
             location = input_data.get("location", "")
@@ -186,11 +267,11 @@ class WeatherAgent(BaseAgent):
             )
 
             # Check cache first
-            cache_key = f"{location}_{include_alerts}"
+            cache_key = f"{location}_{include_alerts}_{days}"
             cached_data = await self._get_from_cache(cache_key)
             if cached_data:
                 logger.info(f"Using cached weather data for {location}")
-                return self._format_response(cached_data, model)
+                return self._format_response(cached_data, model, language=language)
 
             # Try each provider until success
             weather_data = None
@@ -204,15 +285,15 @@ class WeatherAgent(BaseAgent):
                     )
                     if provider.name == "weatherapi":
                         weather_data = await self._fetch_weatherapi(
-                            location, 3, include_alerts
+                            location, days, include_alerts
                         )
                     elif provider.name == "openweathermap":
                         weather_data = await self._fetch_openweathermap(
-                            location, 3, include_alerts
+                            location, days, include_alerts
                         )
                     elif provider.name == "mock":
                         weather_data = await self._fetch_mock_weather(
-                            location, 3, include_alerts
+                            location, days, include_alerts
                         )
 
                     if weather_data:
@@ -245,7 +326,9 @@ class WeatherAgent(BaseAgent):
             )
 
             # Format response with LLM
-            return self._format_response(weather_data, model, has_severe_alerts)
+            return self._format_response(
+                weather_data, model, has_severe_alerts, language=language
+            )
 
         except Exception as e:
             return self._handle_error(e)
@@ -340,6 +423,9 @@ class WeatherAgent(BaseAgent):
                     "min_temp_c": day.get("day", {}).get("mintemp_c"),
                     "condition": day.get("day", {}).get("condition", {}).get("text"),
                     "chance_of_rain": day.get("day", {}).get("daily_chance_of_rain"),
+                    "rain_from": first_rain_hour(
+                        [(h.get("time"), h.get("chance_of_rain")) for h in day.get("hour", [])]
+                    ),
                 }
                 forecast.append(day_data)
 
@@ -482,6 +568,7 @@ class WeatherAgent(BaseAgent):
                         "temps": [],
                         "conditions": [],
                         "chance_of_rain": [],
+                        "hours": [],
                     }
 
                 daily_data[date]["temps"].append(item.get("main", {}).get("temp"))
@@ -489,6 +576,9 @@ class WeatherAgent(BaseAgent):
                     item.get("weather", [{}])[0].get("description")
                 )
                 daily_data[date]["chance_of_rain"].append(item.get("pop", 0) * 100)
+                daily_data[date]["hours"].append(
+                    (item.get("dt_txt"), item.get("pop", 0) * 100)
+                )
 
             for date, data in list(daily_data.items())[:days]:
                 if data["temps"]:
@@ -500,6 +590,7 @@ class WeatherAgent(BaseAgent):
                             set(data["conditions"]), key=data["conditions"].count
                         ),
                         "chance_of_rain": max(data["chance_of_rain"]),
+                        "rain_from": first_rain_hour(data["hours"]),
                     }
                     forecast.append(day_forecast)
 
@@ -607,44 +698,49 @@ class WeatherAgent(BaseAgent):
             logger.error(f"Error adding to cache: {e}")
 
     def _format_response(
-        self, weather_data: WeatherData, model: str, has_severe_alerts: bool = False
+        self,
+        weather_data: WeatherData,
+        model: str,
+        has_severe_alerts: bool = False,
+        language: Optional[str] = None,
     ) -> AgentResponse:
         """Format weather data into a natural language response"""
         try:
             # Create a summary of weather data
             current = weather_data.current
             forecast = weather_data.forecast
+            labels = WEATHER_LABELS.get(language or "pl", WEATHER_LABELS["pl"])
 
             weather_summary = f"""
-            Lokalizacja: {weather_data.location}
+            {labels['location']}: {weather_data.location}
 
-            Aktualna pogoda:
-            - Temperatura: {current.get('temp_c')}°C
-            - Odczuwalna: {current.get('feelslike_c')}°C
-            - Wilgotność: {current.get('humidity')}%
-            - Wiatr: {current.get('wind_kph')} km/h, {current.get('wind_dir')}
-            - Opis: {current.get('condition')}
+            {labels['current']}:
+            - {labels['temp']}: {current.get('temp_c')}°C
+            - {labels['feels_like']}: {current.get('feelslike_c')}°C
+            - {labels['humidity']}: {current.get('humidity')}%
+            - {labels['wind']}: {current.get('wind_kph')} km/h, {current.get('wind_dir')}
+            - {labels['condition']}: {current.get('condition')}
 
-            Prognoza na najbliższe dni:
+            {labels['forecast']}:
             """
 
             for day in forecast[:3]:  # Show next 3 days
                 weather_summary += f"""
             {day.get('date')}:
-            - Maksymalna: {day.get('max_temp_c')}°C
-            - Minimalna: {day.get('min_temp_c')}°C
-            - Opis: {day.get('condition')}
-            - Szansa deszczu: {day.get('chance_of_rain')}%
+            - {labels['max']}: {day.get('max_temp_c')}°C
+            - {labels['min']}: {day.get('min_temp_c')}°C
+            - {labels['condition']}: {day.get('condition')}
+            - {labels['rain']}: {day.get('chance_of_rain')}%
             """
 
             if weather_data.alerts:
-                weather_summary += "\n\nOstrzeżenia pogodowe:\n"
+                weather_summary += f"\n\n{labels['alerts']}:\n"
                 for alert in weather_data.alerts:
                     weather_summary += f"- {alert.headline}\n"
 
             # Format the weather text
             formatted_text = self._format_weather_text(
-                weather_summary, has_severe_alerts
+                weather_summary, has_severe_alerts, language
             )
 
             # Create a streaming generator
@@ -677,18 +773,20 @@ class WeatherAgent(BaseAgent):
             )
 
     def _format_weather_text(
-        self, weather_summary: str, has_severe_alerts: bool
+        self,
+        weather_summary: str,
+        has_severe_alerts: bool,
+        language: Optional[str] = None,
     ) -> str:
         """Adds a friendly, context-aware intro to the weather summary."""
+        labels = WEATHER_LABELS.get(language or "pl", WEATHER_LABELS["pl"])
         if has_severe_alerts:
-            return (
-                "UWAGA, są ważne alerty pogodowe! " "Oto prognoza: " + weather_summary
-            )
+            return labels["intro_alerts"] + weather_summary
         else:
-            return "Oto prognoza pogody: " + weather_summary
+            return labels["intro"] + weather_summary
 
     async def _stream_weather_response(
-        self, model: str, prompt: str
+        self, model: str, prompt: str, language: Optional[str] = None
     ) -> AsyncGenerator[str, None]:
         """Streams weather response from LLM."""
         try:
@@ -699,6 +797,7 @@ class WeatherAgent(BaseAgent):
                         "role": "system",
                         "content": "Jesteś asystentem pogodowym, który w przyjazny sposób podsumowuje prognozę pogody po polsku.",
                     },
+                    *reply_language_messages(language),
                     {"role": "user", "content": prompt},
                 ],
                 stream=True,
@@ -710,6 +809,47 @@ class WeatherAgent(BaseAgent):
             logger.error(f"Error streaming weather response: {e}")
             yield "Przepraszam, wystąpił błąd podczas generowania odpowiedzi."
 
+    async def suggest_clothing(
+        self,
+        current: Dict[str, Any],
+        today: Optional[Dict[str, Any]] = None,
+        use_llm: bool = True,
+    ) -> Tuple[str, str]:
+        """
+        Zwraca (podpowiedź, źródło) - co ubrać / czy wziąć parasol.
+        Źródło to "llm" albo "rules", gdy LLM jest niedostępny.
+        """
+        fallback = clothing_hint_from_weather(current, today)
+        if not use_llm:
+            return fallback, "rules"
+
+        today = today or {}
+        prompt = (
+            f"Temperatura: {current.get('temp_c')}°C (odczuwalna {current.get('feelslike_c')}°C), "
+            f"warunki: {current.get('condition')}, wiatr: {current.get('wind_kph')} km/h, "
+            f"szansa deszczu dziś: {today.get('chance_of_rain', 'brak danych')}%.\n"
+            "Napisz jedno krótkie zdanie po polsku, co ubrać i czy wziąć parasol."
+        )
+        try:
+            response = await hybrid_llm_client.chat(
+                model="SpeakLeash/bielik-4.5b-v3.0-instruct:Q8_0",
+                messages=[
+                    {
+                        "role": "system",
+                        "content": "Jesteś zwięzłym asystentem pogodowym. Odpowiadasz jednym zdaniem.",
+                    },
+                    {"role": "user", "content": prompt},
+                ],
+                stream=False,
+            )
+            if response and response.get("message"):
+                hint = response["message"]["content"].strip().split("\n")[0]
+                if hint:
+                    return hint, "llm"
+        except Exception as e:
+            logger.error(f"Error generating clothing hint: {e}")
+        return fallback, "rules"
+
     async def close(self) -> None:
         """Close HTTP client."""
         await self.http_client.aclose()
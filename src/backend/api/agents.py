@@ -1,6 +1,6 @@
 import logging
 import uuid
-from typing import Any, Dict, List, Optional
+from typing import Any, Dict, List, Literal, Optional
 
 from fastapi import APIRouter, Depends, Form, HTTPException
 from pydantic import BaseModel
@@ -8,6 +8,9 @@ from sqlalchemy.ext.asyncio import AsyncSession  # type: ignore
 
 from backend.agents.interfaces import AgentType
 from backend.agents.orchestrator_factory import create_orchestrator
+from backend.agents.prompts import (get_conversation_summary_prompt,
+                                    get_greeting_polish_prompt)
+from backend.core.hybrid_llm_client import hybrid_llm_client
 from backend.core.database import get_db_with_error_handling
 from backend.infrastructure.database.database import get_db
 
@@ -27,6 +30,14 @@ logger = logging.getLogger(__name__)
 router = APIRouter()
 
 
+class ContextAttachment(BaseModel):
+    """Migawka danych aplikacji dołączona do pytania (spiżarnia, paragony, lista zakupów)"""
+
+    kind: Literal["pantry", "receipts", "shopping_list"]
+    title: str
+    items: List[str] = []
+
+
 class OrchestratorRequest(BaseModel):
     task: str
     session_id: Optional[str] = None
@@ -34,6 +45,9 @@ class OrchestratorRequest(BaseModel):
     agent_states: Optional[Dict[str, bool]] = None
     usePerplexity: Optional[bool] = False
     useBielik: Optional[bool] = True  # Domyślnie używamy Bielika
+    agent: Optional[str] = None  # Intencja wybrana z propozycji doprecyzowania
+    language: Optional[Literal["pl", "en"]] = None  # Wymuszony język odpowiedzi
+    attachments: Optional[List[ContextAttachment]] = None
 
 
 class AgentResponse(BaseModel):
@@ -43,6 +57,26 @@ class AgentResponse(BaseModel):
     data: Optional[Any] = None
     session_id: str
     conversation_state: Optional[Dict[str, Any]] = None
+    routing: Optional[Dict[str, Any]] = None  # Intencja, pewność i ewentualne propozycje
+
+
+class ConversationMessage(BaseModel):
+    role: Literal["user", "assistant"]
+    content: str
+
+
+class SummarizeRequest(BaseModel):
+    messages: List[ConversationMessage]
+    language: Optional[Literal["pl", "en"]] = None
+
+
+class SummarizeResponse(BaseModel):
+    summary: str
+
+
+class GreetingPolishRequest(BaseModel):
+    summary: str
+    language: Optional[Literal["pl", "en"]] = None
 
 
 @router.post("/execute", response_model=AgentResponse)
@@ -82,6 +116,9 @@ async def execute_orchestrator_task(
             agent_states=request.agent_states,
             use_perplexity=request.usePerplexity,
             use_bielik=request.useBielik,
+            agent=request.agent,
+            language=request.language,
+            attachments=[a.model_dump() for a in request.attachments or []],
         )
 
         # Logowanie zakończenia przetwarzania przez agenta
@@ -112,6 +149,7 @@ async def execute_orchestrator_task(
                 if hasattr(agent_response, "data") and agent_response.data
                 else None
             ),
+            routing=(agent_response.metadata or {}).get("routing"),
         )
     except Exception as e:
         # Logowanie błędu
@@ -220,6 +258,62 @@ async def process_query(
         return AgentResponse(success=False, error=str(e), session_id=session_id)
 
 
+@router.post("/summarize", response_model=SummarizeResponse)
+async def summarize_conversation(request: SummarizeRequest) -> SummarizeResponse:
+    """Podsumowanie rozmowy z asystentem, np. przed jej archiwizacją."""
+    if not any(m.content.strip() for m in request.messages):
+        raise HTTPException(status_code=400, detail="Rozmowa jest pusta")
+
+    prompt = get_conversation_summary_prompt(
+        [m.model_dump() for m in request.messages], request.language
+    )
+    try:
+        response = await hybrid_llm_client.chat(
+            messages=[
+                {"role": "system", "content": "Jesteś zwięzłym asystentem FoodSave."},
+                {"role": "user", "content": prompt},
+            ],
+            stream=False,
+            use_bielik=True,
+        )
+    except Exception as e:
+        logger.error(f"Conversation summary failed: {e}", exc_info=True)
+        raise HTTPException(status_code=502, detail="Nie udało się podsumować rozmowy")
+
+    summary = (response or {}).get("message", {}).get("content", "").strip()
+    if not summary:
+        raise HTTPException(status_code=502, detail="Model nie zwrócił podsumowania")
+    return SummarizeResponse(summary=summary)
+
+
+@router.post("/greeting", response_model=SummarizeResponse)
+async def polish_greeting(request: GreetingPolishRequest) -> SummarizeResponse:
+    """Wygładza podsumowanie dnia z nagłówka dashboardu; frontend ma wersję lokalną."""
+    if not request.summary.strip():
+        raise HTTPException(status_code=400, detail="Podsumowanie jest puste")
+
+    try:
+        response = await hybrid_llm_client.chat(
+            messages=[
+                {"role": "system", "content": "Jesteś zwięzłym asystentem FoodSave."},
+                {
+                    "role": "user",
+                    "content": get_greeting_polish_prompt(request.summary, request.language),
+                },
+            ],
+            stream=False,
+            use_bielik=True,
+        )
+    except Exception as e:
+        logger.error(f"Greeting polish failed: {e}", exc_info=True)
+        raise HTTPException(status_code=502, detail="Nie udało się przeredagować podsumowania")
+
+    summary = (response or {}).get("message", {}).get("content", "").strip().split("\n")[0]
+    if not summary:
+        raise HTTPException(status_code=502, detail="Model nie zwrócił podsumowania")
+    return SummarizeResponse(summary=summary)
+
+
 @router.get("/agents", response_model=List[Dict[str, str]])
 async def list_available_agents() -> List[Dict[str, str]]:
     """Zwraca listę wszystkich dostępnych intencji."""
@@ -1,10 +1,12 @@
 # File: backend/api/food.py
-from typing import Dict, List
+from datetime import date
+from typing import Dict, List, Optional
 
-from fastapi import APIRouter, Depends, HTTPException
+from fastapi import APIRouter, Depends, HTTPException, Query
 from sqlalchemy.ext.asyncio import AsyncSession
 
 # Ta funkcja z 'database.py' będzie dostarczać sesję do bazy danych
+from backend.core.grocy_client import GrocyError, get_grocy_client, is_grocy_enabled
 from backend.infrastructure.database.database import get_db
 from backend.schemas import shopping_schemas
 from backend.services import shopping_service
@@ -33,15 +35,71 @@ async def add_shopping_trip(
     tags=["Food"],
 )
 async def read_shopping_trips(
-    skip: int = 0, limit: int = 100, db: AsyncSession = Depends(get_db)
+    skip: int = 0,
+    limit: int = 100,
+    date_from: Optional[date] = None,
+    date_to: Optional[date] = None,
+    db: AsyncSession = Depends(get_db),
 ) -> List[shopping_schemas.ShoppingTrip]:
     """
-    Pobiera listę ostatnich paragonów z bazy danych.
+    Pobiera listę ostatnich paragonów z bazy danych,
+    opcjonalnie z zakresu dat (np. do eksportu).
     """
-    trips = await shopping_service.get_shopping_trips(db=db, skip=skip, limit=limit)
+    if date_from and date_to and date_from > date_to:
+        raise HTTPException(
+            status_code=400,
+            detail="Data początkowa nie może być późniejsza niż końcowa",
+        )
+    trips = await shopping_service.get_shopping_trips(
+        db=db, skip=skip, limit=limit, date_from=date_from, date_to=date_to
+    )
     return trips
 
 
+@router.get(
+    "/shopping-trips/{trip_id}",
+    response_model=shopping_schemas.ShoppingTrip,
+    tags=["Food"],
+)
+async def read_shopping_trip(
+    trip_id: int, db: AsyncSession = Depends(get_db)
+) -> shopping_schemas.ShoppingTrip:
+    """
+    Pobiera jeden paragon, np. otwarty z linku spoza ostatnich paragonów.
+    """
+    trip = await shopping_service.get_shopping_trip(db=db, trip_id=trip_id)
+    if trip is None:
+        raise HTTPException(
+            status_code=404,
+            detail="Paragon o podanym ID nie został znaleziony",
+        )
+    return trip
+
+
+@router.get(
+    "/spending-stats",
+    response_model=shopping_schemas.SpendingStats,
+    tags=["Food"],
+)
+async def read_spending_stats(
+    date_from: Optional[date] = None,
+    date_to: Optional[date] = None,
+    db: AsyncSession = Depends(get_db),
+) -> shopping_schemas.SpendingStats:
+    """
+    Zwraca statystyki wydatków (miesięcznie, per kategoria, per sklep)
+    dla paragonów z podanego zakresu dat.
+    """
+    if date_from and date_to and date_from > date_to:
+        raise HTTPException(
+            status_code=400,
+            detail="Data początkowa nie może być późniejsza niż końcowa",
+        )
+    return await shopping_service.get_spending_stats(
+        db=db, date_from=date_from, date_to=date_to
+    )
+
+
 @router.patch(
     "/shopping-trips/{trip_id}",
     response_model=shopping_schemas.ShoppingTrip,
@@ -69,6 +127,26 @@ async def update_shopping_trip(
     return updated_trip
 
 
+@router.get(
+    "/products/expiring",
+    response_model=List[shopping_schemas.ProductSchema],
+    tags=["Food"],
+)
+async def read_expiring_products(
+    days: int = Query(7, ge=0, le=60), db: AsyncSession = Depends(get_db)
+) -> List[shopping_schemas.ProductSchema]:
+    """
+    Zwraca produkty, którym kończy się termin ważności w ciągu `days` dni.
+    Przy PANTRY_BACKEND=grocy - ze stanu zapasów w Grocy.
+    """
+    if is_grocy_enabled():
+        try:
+            return await get_grocy_client().get_expiring_products(days)
+        except GrocyError as e:
+            raise HTTPException(status_code=502, detail=str(e))
+    return await shopping_service.get_expiring_products(db=db, days=days)
+
+
 @router.patch(
     "/products/{product_id}",
     response_model=shopping_schemas.ProductSchema,
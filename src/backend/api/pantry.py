@@ -1,6 +1,18 @@
-from typing import Dict, List
+from typing import Any, Dict, List, Optional
 
-from fastapi import APIRouter
+from fastapi import APIRouter, HTTPException
+from pydantic import BaseModel
+
+from backend.config import settings
+from backend.core.grocy_client import GrocyError, get_grocy_client, is_grocy_enabled
+from backend.core.task_sync import (
+    ShoppingItem,
+    TaskSyncError,
+    get_task_sync_client,
+    is_task_sync_enabled,
+    resolve_conflicts,
+    sync_shopping_list,
+)
 
 router = APIRouter()
 
@@ -10,7 +22,65 @@ DUMMY_PRODUCTS = [
     {"id": 2, "name": "Chleb", "unified_category": "Pieczywo"},
 ]
 
+TASK_SYNC_REQUIRED = "Eksport listy zakupów nie jest skonfigurowany"
+
+
+class ShoppingListSyncItem(BaseModel):
+    id: str
+    name: str
+    quantity: Optional[str] = None
+    checked: bool = False
+    synced: bool = False
+
+
+class ShoppingListSyncRequest(BaseModel):
+    items: List[ShoppingListSyncItem]
+    removed: List[str] = []  # pozycje usunięte z listy od ostatniej synchronizacji
+
 
 @router.get("/pantry/products", response_model=List[Dict])
-async def get_pantry_products() -> None:
+async def get_pantry_products() -> List[Dict]:
+    if is_grocy_enabled():
+        try:
+            return await get_grocy_client().get_pantry_products()
+        except GrocyError as e:
+            raise HTTPException(status_code=502, detail=str(e))
     return DUMMY_PRODUCTS
+
+
+@router.get("/shopping-list/sync")
+async def get_shopping_list_sync_status() -> Dict[str, object]:
+    """Czy serwer ma skonfigurowany eksport do listy zadań (Todoist/CalDAV)."""
+    enabled = is_task_sync_enabled()
+    return {"enabled": enabled, "provider": settings.TASK_SYNC_PROVIDER if enabled else None}
+
+
+@router.post("/shopping-list/sync")
+async def sync_shopping_list_with_tasks(request: ShoppingListSyncRequest) -> Dict[str, List[Any]]:
+    """
+    Dwukierunkowa synchronizacja listy zakupów przeglądarki z Todoist/CalDAV.
+    Zwraca identyfikatory pozycji do odhaczenia, tych obecnych w liście zadań
+    i usuniętych tam zadań oraz konflikty - pozycje, których nazwę lub ilość
+    zmieniono w liście zadań.
+    """
+    if not is_task_sync_enabled():
+        raise HTTPException(status_code=404, detail=TASK_SYNC_REQUIRED)
+    items = [ShoppingItem(**item.model_dump()) for item in request.items]
+    try:
+        return await sync_shopping_list(
+            get_task_sync_client(), items, request.removed
+        )
+    except TaskSyncError as e:
+        raise HTTPException(status_code=502, detail=str(e))
+
+
+@router.post("/shopping-list/sync/resolve")
+async def resolve_shopping_list_conflicts(request: ShoppingListSyncRequest) -> Dict[str, List[str]]:
+    """Zapisuje w liście zadań wersje pozycji wybrane przy rozstrzyganiu konfliktów."""
+    if not is_task_sync_enabled():
+        raise HTTPException(status_code=404, detail=TASK_SYNC_REQUIRED)
+    items = [ShoppingItem(**item.model_dump()) for item in request.items]
+    try:
+        return {"updated": await resolve_conflicts(get_task_sync_client(), items)}
+    except TaskSyncError as e:
+        raise HTTPException(status_code=502, detail=str(e))
@@ -0,0 +1,155 @@
+from __future__ import annotations
+
+from typing import Any, Dict, List, Optional
+
+from fastapi import APIRouter, HTTPException, status
+from pydantic import BaseModel
+
+from backend.core import pantry_store
+from backend.core.grocy_client import (
+    GrocyClient,
+    GrocyError,
+    get_grocy_client,
+    is_grocy_enabled,
+)
+
+router = APIRouter(prefix="/pantry/items", tags=["Pantry Items"])
+
+
+class PantryItemCreate(BaseModel):
+    name: str
+    quantity: float = 1.0
+    unit: Optional[str] = None
+    category: Optional[str] = None
+    unified_category: Optional[str] = None
+    expiry_date: Optional[str] = None
+    purchase_date: Optional[str] = None
+    notes: Optional[str] = None
+    attributes: Optional[Dict[str, str]] = None
+    staple: Optional[bool] = None
+
+
+class PantryItemUpdate(BaseModel):
+    name: Optional[str] = None
+    quantity: Optional[float] = None
+    unit: Optional[str] = None
+    category: Optional[str] = None
+    unified_category: Optional[str] = None
+    expiry_date: Optional[str] = None
+    purchase_date: Optional[str] = None
+    notes: Optional[str] = None
+    attributes: Optional[Dict[str, str]] = None
+    staple: Optional[bool] = None
+    merged_from: Optional[List[Dict[str, Any]]] = None
+
+
+def _client() -> GrocyClient:
+    try:
+        return get_grocy_client()
+    except GrocyError as e:
+        raise HTTPException(status_code=status.HTTP_502_BAD_GATEWAY, detail=str(e))
+
+
+def _product_id(item_id: str) -> int:
+    try:
+        return int(item_id)
+    except ValueError:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Item not found")
+
+
+async def _current(client: GrocyClient, product_id: int) -> Dict[str, Any]:
+    item = await client.get_pantry_item(product_id)
+    if item is None:
+        raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Item not found")
+    return {**item, **pantry_store.get_extras(item["id"])}
+
+
+@router.get("")
+async def list_pantry_items() -> List[Dict[str, Any]]:
+    """Pantry items in the frontend's PantryItem shape.
+
+    With PANTRY_BACKEND=grocy the stock comes from Grocy and the fields Grocy has
+    no place for (notes, attributes, staple, merge history, unit) are added from
+    the local pantry store; otherwise the whole list lives in that store.
+    """
+    if not is_grocy_enabled():
+        return pantry_store.list_items()
+    client = _client()
+    try:
+        return pantry_store.with_extras(await client.get_pantry_items())
+    except GrocyError as e:
+        raise HTTPException(status_code=status.HTTP_502_BAD_GATEWAY, detail=str(e))
+
+
+@router.post("", status_code=status.HTTP_201_CREATED)
+async def add_pantry_item(item: PantryItemCreate) -> Dict[str, Any]:
+    """Adds an item; in Grocy mode adds stock of an existing product (matched by name).
+
+    New Grocy products need a location and quantity unit, so they have to be
+    created in Grocy first.
+    """
+    fields = item.model_dump(exclude_none=True)
+    if not is_grocy_enabled():
+        return pantry_store.add_item(fields)
+    client = _client()
+    try:
+        product_id = await client.find_product_id(item.name)
+        if product_id is None:
+            raise HTTPException(
+                status_code=status.HTTP_422_UNPROCESSABLE_ENTITY,
+                detail=f"Product '{item.name}' does not exist in Grocy; create it there first",
+            )
+        await client.add_stock(product_id, item.quantity, item.expiry_date)
+        pantry_store.set_extras(str(product_id), fields)
+        return await _current(client, product_id)
+    except GrocyError as e:
+        raise HTTPException(status_code=status.HTTP_502_BAD_GATEWAY, detail=str(e))
+
+
+@router.patch("/{item_id}")
+async def update_pantry_item(item_id: str, updates: PantryItemUpdate) -> Dict[str, Any]:
+    """Updates an item; a null value clears the field.
+
+    In Grocy mode the name and amount go to Grocy, the fields it cannot store go
+    to the local pantry store and the rest (dates, category) stay Grocy's.
+    """
+    changes = updates.model_dump(exclude_unset=True)
+    if not is_grocy_enabled():
+        updated = pantry_store.update_item(item_id, changes)
+        if updated is None:
+            raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Item not found")
+        return updated
+    client = _client()
+    product_id = _product_id(item_id)
+    try:
+        before = await _current(client, product_id)
+        if updates.name:
+            await client.rename_product(product_id, updates.name)
+        if updates.quantity is not None:
+            await client.set_stock_amount(product_id, updates.quantity)
+        pantry_store.set_extras(str(product_id), changes)
+        after = await client.get_pantry_item(product_id)
+        if after is None:
+            # Grocy leaves products without stock out of /stock - the item ran out,
+            # which the frontend needs to see to restock a staple
+            after = {**before, **({"name": updates.name} if updates.name else {}), "quantity": 0.0}
+        return {**after, **pantry_store.get_extras(after["id"])}
+    except GrocyError as e:
+        raise HTTPException(status_code=status.HTTP_502_BAD_GATEWAY, detail=str(e))
+
+
+@router.delete("/{item_id}", status_code=status.HTTP_204_NO_CONTENT)
+async def delete_pantry_item(item_id: str) -> None:
+    """Deletes an item; in Grocy mode consumes its whole stock, the product stays."""
+    if not is_grocy_enabled():
+        if not pantry_store.delete_item(item_id):
+            raise HTTPException(status_code=status.HTTP_404_NOT_FOUND, detail="Item not found")
+        return
+    client = _client()
+    product_id = _product_id(item_id)
+    try:
+        await _current(client, product_id)
+        await client.set_stock_amount(product_id, 0)
+        pantry_store.drop_extras(str(product_id))
+    except GrocyError as e:
+        raise HTTPException(status_code=status.HTTP_502_BAD_GATEWAY, detail=str(e))
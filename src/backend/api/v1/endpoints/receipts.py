@@ -1,17 +1,40 @@
 from __future__ import annotations
 
-from fastapi import APIRouter, File, HTTPException, UploadFile, status
+from typing import Optional
+
+from fastapi import APIRouter, File, Form, HTTPException, UploadFile, status
 from fastapi.responses import JSONResponse
 
 from backend.agents.ocr_agent import OCRAgent, OCRAgentInput
+from backend.core.ocr import detect_receipt_language
 
 router = APIRouter(prefix="/receipts", tags=["Receipts"])
 
+# Language hints accepted from the client, mapped to Tesseract language codes;
+# "auto" reads with all of them and detects the language from the text
+OCR_LANGUAGES = {"pl": "pol", "de": "deu", "en": "eng", "auto": "pol+deu+eng"}
+
 
 @router.post("/upload")
-async def upload_receipt(file: UploadFile = File(...)):
-    """Endpoint for uploading and processing receipt images with OCR."""
+async def upload_receipt(
+    file: UploadFile = File(...),
+    language: Optional[str] = Form(None),
+    preprocess: bool = Form(True),
+):
+    """Endpoint for uploading and processing receipt images with OCR.
+
+    ``language`` (pl/de/en/auto) improves recognition of foreign receipts and
+    ``preprocess`` lets a stored receipt be re-processed with different options.
+    The response includes the receipt language, detected when not given.
+    """
     try:
+        if language is not None and language not in OCR_LANGUAGES:
+            raise HTTPException(
+                status_code=status.HTTP_400_BAD_REQUEST,
+                detail="Unsupported language hint. Use one of: "
+                + ", ".join(OCR_LANGUAGES),
+            )
+
         # Read file content
         file_bytes = await file.read()
 
@@ -34,7 +57,12 @@ async def upload_receipt(file: UploadFile = File(...)):
 
         # Process with OCRAgent
         agent = OCRAgent()
-        input_data = OCRAgentInput(file_bytes=file_bytes, file_type=file_type)
+        input_data = OCRAgentInput(
+            file_bytes=file_bytes,
+            file_type=file_type,
+            language=OCR_LANGUAGES.get(language or ""),
+            preprocess=preprocess,
+        )
         result = await agent.process(input_data)
 
         if not result.success:
@@ -42,9 +70,16 @@ async def upload_receipt(file: UploadFile = File(...)):
                 status_code=status.HTTP_422_UNPROCESSABLE_ENTITY, detail=result.error
             )
 
+        if language in (None, "auto"):
+            language = detect_receipt_language(result.text or "")
+
         return JSONResponse(
             status_code=status.HTTP_200_OK,
-            content={"text": result.text, "message": result.message},
+            content={
+                "text": result.text,
+                "message": result.message,
+                "language": language,
+            },
         )
 
     except HTTPException:
@@ -0,0 +1,185 @@
+import asyncio
+import ipaddress
+import json
+import logging
+import os
+import socket
+from datetime import date
+from typing import Any, Dict, List, Optional
+from urllib.parse import urljoin, urlparse
+
+import httpx
+from fastapi import APIRouter, Depends, HTTPException, Query, Response
+from pydantic import BaseModel
+from sqlalchemy.ext.asyncio import AsyncSession
+
+from backend.core.grocy_client import GrocyError, get_grocy_client, is_grocy_enabled
+from backend.core.ics_parser import events_on, parse_ics_events
+from backend.core.ics_writer import build_ics, expiry_events
+from backend.infrastructure.database.database import get_db
+from backend.services import shopping_service
+
+router = APIRouter(prefix="/calendar", tags=["Calendar"])
+logger = logging.getLogger(__name__)
+
+# Limit rozmiaru pobieranego kalendarza (2 MB)
+MAX_ICS_BYTES = 2 * 1024 * 1024
+MAX_REDIRECTS = 5
+
+# Włącznik kanału dat ważności - kanał nie ma uwierzytelniania, więc domyślnie jest wyłączony
+CALENDAR_SETTINGS_PATH = os.path.join(
+    os.path.dirname(os.path.abspath(__file__)),
+    *[".."] * 5,
+    "data",
+    "config",
+    "calendar_settings.json",
+)
+EXPIRY_FEED_DISABLED = "Kanał dat ważności jest wyłączony w ustawieniach"
+
+
+class ExpiryFeedSettings(BaseModel):
+    enabled: bool
+
+
+def is_expiry_feed_enabled() -> bool:
+    try:
+        with open(CALENDAR_SETTINGS_PATH, "r", encoding="utf-8") as f:
+            return bool(json.load(f).get("expiry_feed_enabled", False))
+    except (OSError, ValueError):
+        return False
+
+
+def set_expiry_feed_enabled(enabled: bool) -> None:
+    os.makedirs(os.path.dirname(CALENDAR_SETTINGS_PATH), exist_ok=True)
+    with open(CALENDAR_SETTINGS_PATH, "w", encoding="utf-8") as f:
+        json.dump({"expiry_feed_enabled": enabled}, f, indent=2)
+
+
+class CalendarFetchError(Exception):
+    """Adres kalendarza odrzucony albo odpowiedź nie do przyjęcia."""
+
+    def __init__(self, status_code: int, detail: str) -> None:
+        super().__init__(detail)
+        self.status_code = status_code
+        self.detail = detail
+
+
+def _is_public_address(address: str) -> bool:
+    ip = ipaddress.ip_address(address.split("%", 1)[0])
+    if isinstance(ip, ipaddress.IPv6Address) and ip.ipv4_mapped:
+        ip = ip.ipv4_mapped
+    return ip.is_global and not ip.is_multicast
+
+
+async def _check_public_url(url: str) -> str:
+    """
+    Serwer pobiera adres podany przez użytkownika, więc nie może trafić do sieci
+    wewnętrznej (SSRF): odrzucamy hosty rozwiązywane na adresy lokalne, prywatne
+    i link-local, także po przekierowaniu.
+    """
+    parsed = urlparse(url.replace("webcal://", "https://", 1))
+    if parsed.scheme not in ("http", "https") or not parsed.hostname:
+        raise CalendarFetchError(400, "Nieprawidłowy adres kalendarza")
+    try:
+        port = parsed.port or (443 if parsed.scheme == "https" else 80)
+        infos = await asyncio.get_running_loop().getaddrinfo(
+            parsed.hostname, port, type=socket.SOCK_STREAM
+        )
+    except (OSError, ValueError):
+        raise CalendarFetchError(502, "Nie udało się pobrać kalendarza")
+    if not infos or not all(_is_public_address(info[4][0]) for info in infos):
+        raise CalendarFetchError(400, "Adres kalendarza wskazuje na sieć lokalną")
+    return parsed.geturl()
+
+
+async def _fetch_ics(url: str) -> str:
+    """Pobiera kalendarz, sprawdzając każde przekierowanie i limit rozmiaru."""
+    async with httpx.AsyncClient(timeout=10.0) as client:
+        for _ in range(MAX_REDIRECTS + 1):
+            url = await _check_public_url(url)
+            async with client.stream("GET", url) as response:
+                if response.is_redirect:
+                    url = urljoin(url, response.headers.get("location", ""))
+                    continue
+                response.raise_for_status()
+                content = bytearray()
+                async for chunk in response.aiter_bytes():
+                    content.extend(chunk)
+                    if len(content) > MAX_ICS_BYTES:
+                        raise CalendarFetchError(413, "Plik kalendarza jest za duży")
+                return content.decode(response.encoding or "utf-8", errors="replace")
+    raise CalendarFetchError(502, "Za dużo przekierowań adresu kalendarza")
+
+
+@router.get("/events")
+async def get_calendar_events(
+    url: str = Query(..., description="Public ICS calendar URL"),
+    day: Optional[date] = Query(None, description="Day to list events for (default: today)"),
+) -> List[Dict[str, Any]]:
+    """
+    Fetch a user-supplied ICS calendar and return events for the given day.
+    The backend proxies the request because most calendar hosts block CORS.
+    """
+    try:
+        text = await _fetch_ics(url)
+    except CalendarFetchError as e:
+        raise HTTPException(status_code=e.status_code, detail=e.detail)
+    except httpx.HTTPError as e:
+        logger.warning(f"Failed to fetch ICS calendar: {e}")
+        raise HTTPException(status_code=502, detail="Nie udało się pobrać kalendarza")
+
+    events = events_on(parse_ics_events(text), day or date.today())
+    return [
+        {
+            "summary": event.get("summary", ""),
+            "location": event.get("location"),
+            "start": event["start"].isoformat(),
+            "end": event["end"].isoformat() if event.get("end") else None,
+            "all_day": event.get("all_day", False),
+        }
+        for event in events
+    ]
+
+
+@router.get("/expiry-feed", response_model=ExpiryFeedSettings)
+async def get_expiry_feed_settings() -> ExpiryFeedSettings:
+    return ExpiryFeedSettings(enabled=is_expiry_feed_enabled())
+
+
+@router.put("/expiry-feed", response_model=ExpiryFeedSettings)
+async def update_expiry_feed_settings(request: ExpiryFeedSettings) -> ExpiryFeedSettings:
+    """Włącza lub wyłącza publikowanie kanału /expiry.ics."""
+    try:
+        set_expiry_feed_enabled(request.enabled)
+    except OSError as e:
+        logger.error(f"Failed to save calendar settings: {e}")
+        raise HTTPException(status_code=500, detail="Nie udało się zapisać ustawienia")
+    return request
+
+
+@router.get("/expiry.ics")
+async def export_expiry_calendar(
+    days: int = Query(30, ge=1, le=365, description="How many days ahead to include"),
+    db: AsyncSession = Depends(get_db),
+) -> Response:
+    """
+    Expiry dates of pantry products as an ICS calendar. Works both as a one-off
+    download and as a subscribed feed (webcal://), which calendar apps refresh.
+    Returns 404 while the feed is turned off in settings.
+    """
+    if not is_expiry_feed_enabled():
+        raise HTTPException(status_code=404, detail=EXPIRY_FEED_DISABLED)
+    if is_grocy_enabled():
+        try:
+            products = await get_grocy_client().get_expiring_products(days)
+        except GrocyError as e:
+            raise HTTPException(status_code=502, detail=str(e))
+    else:
+        products = await shopping_service.get_expiring_products(db=db, days=days)
+
+    ics = build_ics(expiry_events(products), calendar_name="FoodSave – daty ważności")
+    return Response(
+        content=ics,
+        media_type="text/calendar; charset=utf-8",
+        headers={"Content-Disposition": 'inline; filename="foodsave-daty-waznosci.ics"'},
+    )
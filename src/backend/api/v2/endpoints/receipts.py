@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+from typing import Optional
+
 from fastapi import APIRouter, Depends, File, Form, HTTPException, UploadFile
 from fastapi.responses import JSONResponse
 from sqlalchemy.ext.asyncio import AsyncSession
@@ -136,9 +138,13 @@ async def upload_receipt(file: UploadFile = File(...)):
 
 
 @router.post("/analyze", response_model=None)
-async def analyze_receipt(ocr_text: str = Form(...)):
+async def analyze_receipt(
+    ocr_text: str = Form(...), language: Optional[str] = Form(None)
+):
     """Analyze OCR text from receipt and extract structured data with enhanced parsing.
 
+    ``language`` (pl/de/en) is the receipt language reported by OCR, if known.
+
     Returns:
         JSONResponse: Structured receipt data
     """
@@ -155,7 +161,9 @@ async def analyze_receipt(ocr_text: str = Form(...)):
 
         # Process OCR text with enhanced ReceiptAnalysisAgent
         analysis_agent = ReceiptAnalysisAgent()
-        analysis_result = await analysis_agent.process({"ocr_text": ocr_text})
+        analysis_result = await analysis_agent.process(
+            {"ocr_text": ocr_text, "language": language}
+        )
 
         if not analysis_result.success:
             raise UnprocessableEntityError(
@@ -1,7 +1,7 @@
 import logging
-from typing import List
+from typing import Any, Dict, List, Optional
 
-from fastapi import APIRouter, Depends, Query
+from fastapi import APIRouter, Depends, HTTPException, Query
 from sqlalchemy.ext.asyncio import AsyncSession
 
 from backend.agents.weather_agent import WeatherAgent
@@ -14,6 +14,26 @@ logger = logging.getLogger(__name__)
 weather_agent = WeatherAgent()
 
 
+def _condition_icon(condition: Optional[str]) -> str:
+    """Mapuje opis warunków pogodowych na ikonę (emoji)."""
+    try:
+        condition_lower = condition.lower() if condition else ""
+        if "rain" in condition_lower or "drizzle" in condition_lower:
+            return "🌧️"
+        if "cloud" in condition_lower or "overcast" in condition_lower:
+            return "☁️"
+        if "snow" in condition_lower:
+            return "❄️"
+        if "storm" in condition_lower:
+            return "⛈️"
+        if "sunny" in condition_lower or "clear" in condition_lower:
+            return "☀️"
+        return "⛅️"  # Częściowe zachmurzenie
+    except Exception as e:
+        logger.error(f"Error mapping weather icon for condition '{condition}': {e}")
+        return "🤷"
+
+
 @router.get("/")
 async def get_weather_for_locations(
     locations: List[str] = Query(
@@ -36,32 +56,8 @@ async def get_weather_for_locations(
                     "condition": response.data.get("current", {}).get(
                         "condition", "Brak danych"
                     ),
-                    "icon": "☀️",  # Domyślna ikona, można to zmapować
                 }
-                # Mapowanie ikon na podstawie warunków
-                try:
-                    condition_lower = (
-                        simplified_data["condition"].lower()
-                        if simplified_data["condition"]
-                        else ""
-                    )
-                    if "rain" in condition_lower or "drizzle" in condition_lower:
-                        simplified_data["icon"] = "🌧️"
-                    elif "cloud" in condition_lower or "overcast" in condition_lower:
-                        simplified_data["icon"] = "☁️"
-                    elif "snow" in condition_lower:
-                        simplified_data["icon"] = "❄️"
-                    elif "storm" in condition_lower:
-                        simplified_data["icon"] = "⛈️"
-                    elif "sunny" in condition_lower or "clear" in condition_lower:
-                        simplified_data["icon"] = "☀️"
-                    else:
-                        simplified_data["icon"] = "⛅️"  # Częściowe zachmurzenie
-                except Exception as e:
-                    logger.error(
-                        f"Error mapping weather icon for condition '{simplified_data['condition']}': {e}"
-                    )
-                    simplified_data["icon"] = "🤷"
+                simplified_data["icon"] = _condition_icon(simplified_data["condition"])
 
                 all_weather_data.append(simplified_data)
             else:
@@ -90,3 +86,67 @@ async def get_weather_for_locations(
             all_weather_data.append(mock_data)
 
     return all_weather_data
+
+
+@router.get("/forecast")
+async def get_weather_forecast(
+    location: str = Query(..., description="Location to get the forecast for"),
+    days: int = Query(5, ge=1, le=7, description="Number of forecast days"),
+) -> Dict[str, Any]:
+    """
+    Get current conditions and a multi-day forecast for a single location.
+    """
+    response = await weather_agent.process({"location": location, "days": days})
+    if not response.success or not response.data or "current" not in response.data:
+        logger.warning(f"Weather forecast failed for {location}. Error: {response.error}")
+        raise HTTPException(
+            status_code=503, detail="Prognoza pogody jest chwilowo niedostępna"
+        )
+
+    data = response.data
+    current = data.get("current", {})
+    return {
+        "location": data.get("location", location),
+        "current": {
+            "temperature": current.get("temp_c"),
+            "condition": current.get("condition"),
+            "icon": _condition_icon(current.get("condition")),
+            "humidity": current.get("humidity"),
+            "wind_kph": current.get("wind_kph"),
+        },
+        "forecast": [
+            {
+                "date": day.get("date"),
+                "min_temp": day.get("min_temp_c"),
+                "max_temp": day.get("max_temp_c"),
+                "condition": day.get("condition"),
+                "chance_of_rain": day.get("chance_of_rain"),
+                "rain_from": day.get("rain_from"),
+                "icon": _condition_icon(day.get("condition")),
+            }
+            for day in data.get("forecast", [])[:days]
+        ],
+        "alerts": data.get("alerts", []),
+        "provider": data.get("provider"),
+    }
+
+
+@router.get("/advice")
+async def get_weather_advice(
+    location: str = Query(..., description="Location to get the advice for"),
+    use_llm: bool = Query(True, description="Polish the hint with the LLM"),
+) -> Dict[str, Any]:
+    """
+    Short "what to wear / take an umbrella" hint for today's weather.
+    """
+    response = await weather_agent.process({"location": location, "days": 1})
+    if not response.success or not response.data or "current" not in response.data:
+        raise HTTPException(
+            status_code=503, detail="Prognoza pogody jest chwilowo niedostępna"
+        )
+
+    forecast = response.data.get("forecast") or []
+    hint, source = await weather_agent.suggest_clothing(
+        response.data["current"], forecast[0] if forecast else None, use_llm=use_llm
+    )
+    return {"location": response.data.get("location", location), "hint": hint, "source": source}
@@ -84,6 +84,20 @@ class Settings(BaseSettings):
     NEWS_API_KEY: str = ""
     BING_SEARCH_API_KEY: str = ""
 
+    # Źródło danych spiżarni dla całego serwera: "local" (dane FoodSave) albo
+    # "grocy"; frontend wybiera je przez adres backendu w ustawieniach
+    PANTRY_BACKEND: str = "local"
+    GROCY_URL: str = ""  # np. http://grocy.local:9283
+    GROCY_API_KEY: str = ""
+
+    # Eksport listy zakupów do listy zadań: "" (wyłączony), "todoist" albo "caldav"
+    TASK_SYNC_PROVIDER: str = ""
+    TODOIST_API_TOKEN: str = ""
+    TODOIST_PROJECT: str = "Zakupy"
+    CALDAV_URL: str = ""  # adres kolekcji zadań, np. https://cloud.example.com/remote.php/dav/calendars/jan/zakupy/
+    CALDAV_USERNAME: str = ""
+    CALDAV_PASSWORD: str = ""
+
     # Konfiguracja Tesseract OCR
     TESSDATA_PREFIX: str = "/usr/share/tesseract-ocr/5/"
 
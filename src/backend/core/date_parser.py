@@ -0,0 +1,77 @@
+"""
+Tolerancyjny parser dat z paragonów i pól daty ważności.
+
+Obsługuje formaty spotykane na polskich paragonach i wpisywane ręcznie:
+DD.MM.YYYY, D.M.YYYY, DD-MM-YY, DD/MM/YYYY, YYYY-MM-DD, YYYY.MM.DD,
+opcjonalnie z godziną (HH:MM lub HH:MM:SS). Rok dwucyfrowy to 20YY.
+"""
+
+import re
+from datetime import date, datetime
+from typing import Optional
+
+_SEP = r"[.\-/]"
+_TIME = r"(?:[ T,]+(?P<hour>\d{1,2}):(?P<minute>\d{2})(?::(?P<second>\d{2}))?)?"
+
+# Dzień pierwszy (format polski) i rok pierwszy (ISO); rok 4- albo 2-cyfrowy
+_DAY_FIRST = (
+    rf"(?P<day>\d{{1,2}}){_SEP}(?P<month>\d{{1,2}}){_SEP}(?P<year>\d{{4}}|\d{{2}})(?!\d)"
+)
+_YEAR_FIRST = rf"(?P<year>\d{{4}}){_SEP}(?P<month>\d{{1,2}}){_SEP}(?P<day>\d{{1,2}})(?!\d)"
+
+_FULL_PATTERNS = [
+    re.compile(rf"^\s*{_YEAR_FIRST}{_TIME}\s*$"),
+    re.compile(rf"^\s*{_DAY_FIRST}{_TIME}\s*$"),
+]
+_SEARCH_PATTERNS = [
+    re.compile(rf"(?<!\d){_YEAR_FIRST}{_TIME}"),
+    re.compile(rf"(?<!\d){_DAY_FIRST}{_TIME}"),
+]
+
+
+def _build(match: re.Match) -> Optional[datetime]:
+    year = int(match.group("year"))
+    if year < 100:
+        year += 2000
+    try:
+        return datetime(
+            year,
+            int(match.group("month")),
+            int(match.group("day")),
+            int(match.group("hour") or 0),
+            int(match.group("minute") or 0),
+            int(match.group("second") or 0),
+        )
+    except ValueError:
+        # np. 31.02.2024 albo 25:61
+        return None
+
+
+def parse_datetime(value: str) -> Optional[datetime]:
+    """Parsuje cały napis jako datę (z opcjonalną godziną). None, gdy to nie data."""
+    if not value:
+        return None
+    for pattern in _FULL_PATTERNS:
+        match = pattern.match(value)
+        if match:
+            return _build(match)
+    return None
+
+
+def parse_date(value: str) -> Optional[date]:
+    """Jak parse_datetime, ale zwraca samą datę."""
+    parsed = parse_datetime(value)
+    return parsed.date() if parsed else None
+
+
+def find_date(text: str) -> Optional[date]:
+    """Zwraca pierwszą poprawną datę znalezioną w dowolnym tekście (np. z OCR)."""
+    if not text:
+        return None
+    candidates = []
+    for pattern in _SEARCH_PATTERNS:
+        for match in pattern.finditer(text):
+            parsed = _build(match)
+            if parsed:
+                candidates.append((match.start(), parsed.date()))
+    return min(candidates)[1] if candidates else None
@@ -0,0 +1,186 @@
+"""
+Klient Grocy (https://grocy.info) - alternatywne źródło danych spiżarni.
+
+Przy PANTRY_BACKEND=grocy lista spiżarni (/api/v1/pantry/items), produkty
+z kończącą się datą ważności i kalendarz dat ważności czytają stan z serwera
+Grocy, więc dotychczasowi użytkownicy Grocy widzą w FoodSave swoje zapasy.
+Przełącznik dotyczy całego serwera: profil backendu we frontendzie wybiera
+Grocy, wskazując serwer FoodSave skonfigurowany z PANTRY_BACKEND=grocy.
+Pola pozycji, których Grocy nie ma (notatki, atrybuty, produkt stały),
+trzyma lokalny magazyn spiżarni (backend.core.pantry_store). Lista zakupów
+zostaje w przeglądarce - Grocy jej nie obsługuje. Odpowiedzi Grocy są
+mapowane na kształt używany przez frontend.
+"""
+
+import logging
+from typing import Any, Dict, List, Optional
+
+import httpx
+
+from backend.config import settings
+from backend.core.date_parser import parse_date
+
+logger = logging.getLogger(__name__)
+
+# Grocy oznacza "bez daty ważności" datą z dalekiej przyszłości
+NO_EXPIRY_DATE = "2999-12-31"
+
+
+class GrocyError(Exception):
+    """Błąd komunikacji z serwerem Grocy."""
+
+
+def _expiration(entry: Dict[str, Any]) -> Optional[str]:
+    raw = entry.get("best_before_date")
+    if not raw or raw == NO_EXPIRY_DATE:
+        return None
+    parsed = parse_date(raw)
+    return parsed.isoformat() if parsed else None
+
+
+def stock_entry_to_product(
+    entry: Dict[str, Any], groups: Optional[Dict[int, str]] = None
+) -> Dict[str, Any]:
+    """Pozycja z /stock lub /stock/volatile -> produkt w formacie FoodSave."""
+    product = entry.get("product") or {}
+    product_id = entry.get("product_id") or product.get("id")
+    group_id = product.get("product_group_id")
+    category = (groups or {}).get(int(group_id)) if group_id else None
+    return {
+        "id": int(product_id) if product_id is not None else 0,
+        "trip_id": 0,  # Grocy nie zna paragonów
+        "name": product.get("name") or f"Produkt {product_id}",
+        "quantity": float(entry.get("amount") or 0),
+        "unit": None,
+        "unit_price": None,
+        "category": category,
+        "unified_category": category or "Nieskategoryzowane",
+        "expiration_date": _expiration(entry),
+        "is_consumed": False,
+    }
+
+
+def stock_entry_to_pantry_item(
+    entry: Dict[str, Any], groups: Optional[Dict[int, str]] = None
+) -> Dict[str, Any]:
+    """Pozycja z /stock -> pozycja spiżarni w formacie PantryItem frontendu."""
+    product = stock_entry_to_product(entry, groups)
+    return {
+        "id": str(product["id"]),
+        "name": product["name"],
+        "quantity": product["quantity"],
+        "category": product["category"],
+        "unified_category": product["unified_category"],
+        "expiry_date": product["expiration_date"],
+    }
+
+
+class GrocyClient:
+    """Asynchroniczny klient REST API Grocy (nagłówek GROCY-API-KEY)."""
+
+    def __init__(self, base_url: str, api_key: str, timeout: float = 15.0) -> None:
+        self.base_url = base_url.rstrip("/")
+        self.http_client = httpx.AsyncClient(
+            base_url=f"{self.base_url}/api",
+            timeout=timeout,
+            headers={
+                "GROCY-API-KEY": api_key,
+                "Accept": "application/json",
+                "User-Agent": settings.USER_AGENT,
+            },
+        )
+
+    async def _request(self, method: str, path: str, **kwargs: Any) -> Any:
+        try:
+            response = await self.http_client.request(method, path, **kwargs)
+        except httpx.HTTPError as e:
+            raise GrocyError(f"Serwer Grocy nie odpowiada: {e}") from e
+        if response.is_error:
+            raise GrocyError(
+                f"Grocy zwrócił błąd HTTP {response.status_code}: {response.text[:200]}"
+            )
+        return response.json() if response.content else None
+
+    async def _product_groups(self) -> Dict[int, str]:
+        groups = await self._request("GET", "/objects/product_groups")
+        return {int(g["id"]): g["name"] for g in groups or []}
+
+    async def get_pantry_products(self) -> List[Dict[str, Any]]:
+        """Aktualny stan zapasów."""
+        stock = await self._request("GET", "/stock")
+        groups = await self._product_groups()
+        return [stock_entry_to_product(entry, groups) for entry in stock or []]
+
+    async def get_expiring_products(self, days: int) -> List[Dict[str, Any]]:
+        """Produkty, którym data ważności mija w ciągu `days` dni."""
+        volatile = await self._request(
+            "GET", "/stock/volatile", params={"due_soon_days": days}
+        )
+        groups = await self._product_groups()
+        entries = (volatile or {}).get("due_products", [])
+        products = [stock_entry_to_product(entry, groups) for entry in entries]
+        return sorted(products, key=lambda p: p["expiration_date"] or NO_EXPIRY_DATE)
+
+    async def get_pantry_items(self) -> List[Dict[str, Any]]:
+        """Stan zapasów w formacie listy spiżarni frontendu."""
+        stock = await self._request("GET", "/stock")
+        groups = await self._product_groups()
+        return [stock_entry_to_pantry_item(entry, groups) for entry in stock or []]
+
+    async def get_pantry_item(self, product_id: int) -> Optional[Dict[str, Any]]:
+        stock = await self._request("GET", "/stock")
+        groups = await self._product_groups()
+        for entry in stock or []:
+            if int(entry.get("product_id") or 0) == product_id:
+                return stock_entry_to_pantry_item(entry, groups)
+        return None
+
+    async def find_product_id(self, name: str) -> Optional[int]:
+        """Produkt Grocy o danej nazwie (bez rozróżniania wielkości liter)."""
+        products = await self._request("GET", "/objects/products")
+        for product in products or []:
+            if str(product.get("name", "")).strip().lower() == name.strip().lower():
+                return int(product["id"])
+        return None
+
+    async def add_stock(
+        self, product_id: int, amount: float, best_before: Optional[str] = None
+    ) -> None:
+        payload: Dict[str, Any] = {"amount": amount}
+        if best_before:
+            payload["best_before_date"] = best_before
+        await self._request("POST", f"/stock/products/{product_id}/add", json=payload)
+
+    async def set_stock_amount(self, product_id: int, amount: float) -> None:
+        """Inwentaryzacja: ustawia stan produktu (0 zużywa wszystko)."""
+        await self._request(
+            "POST",
+            f"/stock/products/{product_id}/inventory",
+            json={"new_amount": amount},
+        )
+
+    async def rename_product(self, product_id: int, name: str) -> None:
+        await self._request(
+            "PUT", f"/objects/products/{product_id}", json={"name": name}
+        )
+
+    async def close(self) -> None:
+        await self.http_client.aclose()
+
+
+_client: Optional[GrocyClient] = None
+
+
+def is_grocy_enabled() -> bool:
+    return settings.PANTRY_BACKEND == "grocy"
+
+
+def get_grocy_client() -> GrocyClient:
+    """Współdzielony klient; błąd, gdy Grocy nie jest skonfigurowany."""
+    global _client
+    if not settings.GROCY_URL or not settings.GROCY_API_KEY:
+        raise GrocyError("Ustaw GROCY_URL i GROCY_API_KEY, aby używać Grocy")
+    if _client is None:
+        _client = GrocyClient(settings.GROCY_URL, settings.GROCY_API_KEY)
+        logger.info("Grocy client initialized for %s", settings.GROCY_URL)
+    return _client
@@ -0,0 +1,228 @@
+"""
+Minimalny parser plików iCalendar (ICS) dla widżetu kalendarza.
+
+Obsługuje wydarzenia VEVENT (SUMMARY, LOCATION, DTSTART, DTEND, RRULE,
+EXDATE) oraz zadania VTODO z list zadań CalDAV (UID, SUMMARY, STATUS).
+Reguły powtarzania są rozwijane dla FREQ=DAILY/WEEKLY/MONTHLY/YEARLY
+z INTERVAL, COUNT, UNTIL i BYDAY (tylko dni tygodnia przy WEEKLY).
+Wydarzenie z bardziej złożoną regułą pojawia się tylko w dniu swojego DTSTART.
+"""
+
+import logging
+import re
+from datetime import date, datetime, timedelta, timezone
+from typing import Any, Dict, List, Optional, Tuple
+
+logger = logging.getLogger(__name__)
+
+WEEKDAYS = ["MO", "TU", "WE", "TH", "FR", "SA", "SU"]
+SUPPORTED_RULE_PARTS = {"FREQ", "INTERVAL", "COUNT", "UNTIL", "BYDAY", "WKST"}
+
+
+def _unfold(text: str) -> List[str]:
+    """Skleja linie zawinięte zgodnie z RFC 5545 (kontynuacja zaczyna się spacją)."""
+    lines: List[str] = []
+    for raw in text.splitlines():
+        if raw.startswith((" ", "\t")) and lines:
+            lines[-1] += raw[1:]
+        else:
+            lines.append(raw)
+    return lines
+
+
+def _unescape(value: str) -> str:
+    # Jedno przejście: "\\\\n" w pliku to ukośnik i litera n, a nie nowa linia
+    return re.sub(
+        r"\\(.)", lambda m: "\n" if m.group(1) in "nN" else m.group(1), value
+    )
+
+
+def _parse_datetime(params: str, value: str) -> Tuple[Optional[datetime], bool]:
+    """Zwraca (datetime, all_day). Czas z 'Z' jest w UTC, z TZID traktowany lokalnie."""
+    value = value.strip()
+    try:
+        if "VALUE=DATE" in params.upper() or len(value) == 8:
+            return datetime.strptime(value, "%Y%m%d"), True
+        if value.endswith("Z"):
+            parsed = datetime.strptime(value[:-1], "%Y%m%dT%H%M%S")
+            return parsed.replace(tzinfo=timezone.utc), False
+        return datetime.strptime(value, "%Y%m%dT%H%M%S"), False
+    except ValueError:
+        return None, False
+
+
+def parse_ics_events(text: str) -> List[Dict[str, Any]]:
+    """Wyciąga wydarzenia z treści pliku ICS."""
+    events: List[Dict[str, Any]] = []
+    current: Optional[Dict[str, Any]] = None
+
+    for line in _unfold(text):
+        if line == "BEGIN:VEVENT":
+            current = {}
+            continue
+        if line == "END:VEVENT":
+            if current is not None and current.get("start"):
+                events.append(current)
+            current = None
+            continue
+        if current is None or ":" not in line:
+            continue
+
+        name_params, value = line.split(":", 1)
+        name, _, params = name_params.partition(";")
+        name = name.upper()
+
+        if name == "SUMMARY":
+            current["summary"] = _unescape(value)
+        elif name == "LOCATION":
+            current["location"] = _unescape(value)
+        elif name in ("DTSTART", "DTEND"):
+            parsed, all_day = _parse_datetime(params, value)
+            if parsed is not None:
+                current["start" if name == "DTSTART" else "end"] = parsed
+                if name == "DTSTART":
+                    current["all_day"] = all_day
+        elif name == "RRULE":
+            current["rrule"] = _parse_rrule(value)
+        elif name == "EXDATE":
+            excluded = current.setdefault("exdates", set())
+            for part in value.split(","):
+                parsed, _ = _parse_datetime(params, part)
+                if parsed is not None:
+                    excluded.add(_local(parsed).date())
+
+    return events
+
+
+def _parse_rrule(value: str) -> Dict[str, str]:
+    parts = (part.split("=", 1) for part in value.strip().split(";") if "=" in part)
+    return {key.upper(): val.upper() for key, val in parts}
+
+
+def _local(moment: datetime) -> datetime:
+    """Czas UTC jako lokalny, bez strefy - tak porównujemy dni."""
+    if moment.tzinfo is not None:
+        return moment.astimezone().replace(tzinfo=None)
+    return moment
+
+
+def _month_offset(start: date, day: date) -> int:
+    return (day.year - start.year) * 12 + day.month - start.month
+
+
+def _matches_rule(rule: Dict[str, str], start: date, day: date) -> bool:
+    """Czy dzień pasuje do wzorca reguły (bez COUNT i UNTIL)."""
+    interval = max(int(rule.get("INTERVAL", "1")), 1)
+    freq = rule["FREQ"]
+    if freq == "DAILY":
+        return (day - start).days % interval == 0
+    if freq == "WEEKLY":
+        weekdays = rule.get("BYDAY", WEEKDAYS[start.weekday()]).split(",")
+        weeks = (day - start + timedelta(days=start.weekday() - day.weekday())).days
+        return WEEKDAYS[day.weekday()] in weekdays and (weeks // 7) % interval == 0
+    if freq == "MONTHLY":
+        return day.day == start.day and _month_offset(start, day) % interval == 0
+    return (
+        (day.month, day.day) == (start.month, start.day)
+        and (day.year - start.year) % interval == 0
+    )
+
+
+def _is_supported(rule: Dict[str, str]) -> bool:
+    if not set(rule) <= SUPPORTED_RULE_PARTS:
+        return False
+    if rule.get("FREQ") not in ("DAILY", "WEEKLY", "MONTHLY", "YEARLY"):
+        return False
+    if "BYDAY" in rule and (
+        rule["FREQ"] != "WEEKLY"
+        or not set(rule["BYDAY"].split(",")) <= set(WEEKDAYS)
+    ):
+        return False
+    return rule.get("INTERVAL", "1").isdigit() and rule.get("COUNT", "1").isdigit()
+
+
+def occurs_on(event: Dict[str, Any], day: date) -> bool:
+    """Czy wydarzenie cykliczne zaczyna się w danym dniu (z COUNT, UNTIL i EXDATE)."""
+    rule: Dict[str, str] = event["rrule"]
+    start = _local(event["start"]).date()
+    if day < start or day in event.get("exdates", ()):
+        return False
+    if "UNTIL" in rule:
+        until, _ = _parse_datetime("", rule["UNTIL"])
+        if until is not None and day > _local(until).date():
+            return False
+    if "COUNT" not in rule:
+        return _matches_rule(rule, start, day)
+    # COUNT liczy wystąpienia od początku, więc przechodzimy kolejne dni
+    remaining = int(rule["COUNT"])
+    current = start
+    while remaining > 0 and current <= day:
+        if _matches_rule(rule, start, current):
+            if current == day:
+                return True
+            remaining -= 1
+        current += timedelta(days=1)
+    return False
+
+
+def parse_ics_todos(text: str) -> List[Dict[str, Any]]:
+    """Wyciąga zadania VTODO: {"uid", "summary", "completed"}."""
+    todos: List[Dict[str, Any]] = []
+    current: Optional[Dict[str, Any]] = None
+
+    for line in _unfold(text):
+        if line == "BEGIN:VTODO":
+            current = {"completed": False}
+            continue
+        if line == "END:VTODO":
+            if current is not None and current.get("uid"):
+                todos.append(current)
+            current = None
+            continue
+        if current is None or ":" not in line:
+            continue
+
+        name_params, value = line.split(":", 1)
+        name = name_params.partition(";")[0].upper()
+
+        if name == "UID":
+            current["uid"] = value.strip()
+        elif name == "SUMMARY":
+            current["summary"] = _unescape(value)
+        elif name == "STATUS":
+            current["completed"] = value.strip().upper() == "COMPLETED"
+        elif name == "COMPLETED":
+            current["completed"] = True
+
+    return todos
+
+
+def events_on(events: List[Dict[str, Any]], day: date) -> List[Dict[str, Any]]:
+    """Wydarzenia trwające w danym dniu, posortowane wg początku."""
+    result = []
+    for event in events:
+        start = _local(event["start"])
+        end: Optional[datetime] = event.get("end")
+        if end is not None:
+            end = _local(end)
+
+        rule = event.get("rrule")
+        if rule is not None and not _is_supported(rule):
+            logger.info("Unsupported RRULE %s, showing the first occurrence only", rule)
+        elif rule is not None:
+            # Wystąpienie to kopia pierwszego przesunięta na dany dzień
+            if occurs_on(event, day):
+                shift = timedelta(days=(day - start.date()).days)
+                shifted = {"start": start + shift, "end": end + shift if end else None}
+                result.append({**event, **shifted})
+            continue
+
+        if event.get("all_day"):
+            # DTEND wydarzeń całodniowych jest wyłączny
+            last_day = (end - timedelta(days=1)).date() if end else start.date()
+            if start.date() <= day <= max(last_day, start.date()):
+                result.append({**event, "start": start, "end": end})
+        elif start.date() == day or (end is not None and start.date() < day <= end.date()):
+            result.append({**event, "start": start, "end": end})
+
+    return sorted(result, key=lambda e: e["start"])
@@ -0,0 +1,142 @@
+"""
+Generowanie plików iCalendar (ICS): daty ważności produktów i zadania
+list zakupów (VTODO) dla list zadań CalDAV.
+
+Każdy produkt to wydarzenie całodniowe w dniu, w którym traci ważność,
+z przypomnieniem dzień wcześniej. Plik można pobrać albo zasubskrybować
+jako kanał (webcal://) w aplikacji kalendarza.
+"""
+
+from datetime import date, datetime, timedelta, timezone
+from typing import Any, Dict, Iterable, List, Optional
+
+PRODID = "-//FoodSave AI//Daty waznosci//PL"
+
+
+def _escape(value: str) -> str:
+    return (
+        value.replace("\\", "\\\\")
+        .replace(";", "\\;")
+        .replace(",", "\\,")
+        .replace("\n", "\\n")
+    )
+
+
+def _fold(line: str) -> List[str]:
+    """Zawija linie dłuższe niż 75 bajtów (RFC 5545), nie rozcinając znaków UTF-8."""
+    lines: List[str] = []
+    current = ""
+    for char in line:
+        if len((current + char).encode("utf-8")) > 75:
+            lines.append(current)
+            current = " "
+        current += char
+    lines.append(current)
+    return lines
+
+
+def build_ics(
+    events: Iterable[Dict[str, Any]],
+    calendar_name: str = "FoodSave",
+    now: Optional[datetime] = None,
+) -> str:
+    """
+    Składa kalendarz z wydarzeń całodniowych.
+    Wydarzenie: {"uid", "summary", "day": date, opcjonalnie "description", "alarm_days"}.
+    """
+    stamp = (now or datetime.now(timezone.utc)).strftime("%Y%m%dT%H%M%SZ")
+    lines = [
+        "BEGIN:VCALENDAR",
+        "VERSION:2.0",
+        f"PRODID:{PRODID}",
+        "CALSCALE:GREGORIAN",
+        "METHOD:PUBLISH",
+        f"X-WR-CALNAME:{_escape(calendar_name)}",
+    ]
+    for event in events:
+        day: date = event["day"]
+        lines += [
+            "BEGIN:VEVENT",
+            f"UID:{event['uid']}",
+            f"DTSTAMP:{stamp}",
+            f"DTSTART;VALUE=DATE:{day.strftime('%Y%m%d')}",
+            # DTEND wydarzeń całodniowych jest wyłączny
+            f"DTEND;VALUE=DATE:{(day + timedelta(days=1)).strftime('%Y%m%d')}",
+            f"SUMMARY:{_escape(event['summary'])}",
+        ]
+        if event.get("description"):
+            lines.append(f"DESCRIPTION:{_escape(event['description'])}")
+        alarm_days = event.get("alarm_days")
+        if alarm_days:
+            lines += [
+                "BEGIN:VALARM",
+                "ACTION:DISPLAY",
+                f"DESCRIPTION:{_escape(event['summary'])}",
+                f"TRIGGER:-P{int(alarm_days)}D",
+                "END:VALARM",
+            ]
+        lines.append("END:VEVENT")
+    lines.append("END:VCALENDAR")
+
+    folded = [part for line in lines for part in _fold(line)]
+    return "\r\n".join(folded) + "\r\n"
+
+
+def build_todo(
+    uid: str,
+    summary: str,
+    description: str = "",
+    completed: bool = False,
+    now: Optional[datetime] = None,
+) -> str:
+    """Pojedyncze zadanie VTODO opakowane w VCALENDAR (tak zapisuje je CalDAV)."""
+    stamp = (now or datetime.now(timezone.utc)).strftime("%Y%m%dT%H%M%SZ")
+    lines = [
+        "BEGIN:VCALENDAR",
+        "VERSION:2.0",
+        f"PRODID:{PRODID}",
+        "BEGIN:VTODO",
+        f"UID:{uid}",
+        f"DTSTAMP:{stamp}",
+        f"SUMMARY:{_escape(summary)}",
+    ]
+    if description:
+        lines.append(f"DESCRIPTION:{_escape(description)}")
+    if completed:
+        lines += ["STATUS:COMPLETED", f"COMPLETED:{stamp}"]
+    else:
+        lines.append("STATUS:NEEDS-ACTION")
+    lines += ["END:VTODO", "END:VCALENDAR"]
+
+    folded = [part for line in lines for part in _fold(line)]
+    return "\r\n".join(folded) + "\r\n"
+
+
+def expiry_events(products: Iterable[Any]) -> List[Dict[str, Any]]:
+    """Produkty (obiekty lub słowniki) z datą ważności -> wydarzenia kalendarza."""
+    events = []
+    for product in products:
+
+        def get(field: str) -> Any:
+            if isinstance(product, dict):
+                return product.get(field)
+            return getattr(product, field, None)
+
+        expiration = get("expiration_date")
+        if isinstance(expiration, str):
+            expiration = date.fromisoformat(expiration)
+        if not expiration:
+            continue
+        quantity = get("quantity")
+        unit = get("unit") or ""
+        description = f"Ilość: {quantity:g} {unit}".strip() if quantity else None
+        events.append(
+            {
+                "uid": f"expiry-{get('id')}-{expiration.isoformat()}@foodsave",
+                "summary": f"Kończy się ważność: {get('name')}",
+                "day": expiration,
+                "description": description,
+                "alarm_days": 1,
+            }
+        )
+    return events
@@ -1,5 +1,6 @@
 import io
 import logging
+import re
 import tempfile
 import tracemalloc
 from typing import Any, Dict, List, Optional
@@ -41,7 +42,7 @@ class OCRProcessor:
         receipt_config = (
             "--oem 3 --psm 6 "
             "-c tessedit_char_whitelist=0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ"
-            "abcdefghijklmnopqrstuvwxyzĄĆĘŁŃÓŚŹŻąćęłńóśźż.,:-/%()[] "
+            "abcdefghijklmnopqrstuvwxyzĄĆĘŁŃÓŚŹŻąćęłńóśźżÄÖÜäöüß.,:-/%()[] "
         )
         lang_part = f"-l {'+'.join(self.languages)}" if self.languages else "-l pol"
         return f"{receipt_config} {lang_part}"
@@ -202,6 +203,34 @@ class OCRProcessor:
         return results
 
 
+# Słowa typowe dla paragonów w obsługiwanych językach (rozpoznawanie języka "auto")
+RECEIPT_LANGUAGE_KEYWORDS: Dict[str, List[str]] = {
+    "pl": ["paragon", "fiskalny", "suma", "ptu", "sprzedaż", "gotówka", "reszta"],
+    "de": ["summe", "mwst", "kassenbon", "bar", "rückgeld", "gesamt", "netto", "ust"],
+    "en": ["total", "subtotal", "vat", "change", "cash", "receipt", "thank", "balance"],
+}
+
+
+def detect_receipt_language(text: str) -> Optional[str]:
+    """Język paragonu (pl/de/en) po słowach kluczowych; None, gdy nie da się ocenić."""
+    words = set(re.findall(r"\w+", text.lower()))
+    scores = {
+        language: sum(keyword in words for keyword in keywords)
+        for language, keywords in RECEIPT_LANGUAGE_KEYWORDS.items()
+    }
+    language, score = max(scores.items(), key=lambda item: item[1])
+    return language if score > 0 else None
+
+
+def receipt_config(language: str = "pol") -> str:
+    """Konfiguracja Tesseract dla paragonów w danym języku (kod Tesseract: pol, eng)."""
+    return (
+        f"--oem 3 --psm 6 -l {language} -c tessedit_char_whitelist="
+        "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz"
+        "ĄĆĘŁŃÓŚŹŻąćęłńóśźżÄÖÜäöüß.,:-/%()[] "
+    )
+
+
 @handle_exceptions(max_retries=1)
 def _extract_text_from_image_obj(
     image: Image.Image, config: Optional[str] = None
@@ -210,26 +239,33 @@ def _extract_text_from_image_obj(
     Prywatna funkcja pomocnicza, która wykonuje OCR na obiekcie obrazu PIL.
     """
     # Użyj konfiguracji zoptymalizowanej dla paragonów
-    custom_config = (
-        config
-        or r"--oem 3 --psm 6 -l pol -c tessedit_char_whitelist=0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyzĄĆĘŁŃÓŚŹŻąćęłńóśźż.,:-/%()[] "
-    )
+    custom_config = config or receipt_config()
     return pytesseract.image_to_string(image, config=custom_config)
 
 
 @handle_exceptions(max_retries=1, retry_delay=0.5)
 def process_image_file(
-    file_bytes: bytes, config: Optional[str] = None
+    file_bytes: bytes,
+    config: Optional[str] = None,
+    language: Optional[str] = None,
+    preprocess: bool = True,
 ) -> Optional[str]:
     """
     Przetwarza plik obrazu (jpg, png) i wyciąga z niego tekst z preprocessingiem.
+
+    Ponowne przetwarzanie z historii może wskazać inny język i wyłączyć preprocessing,
+    gdy kontrast i wyostrzenie pogarszają odczyt wyblakłego paragonu.
     """
+    if language and not config:
+        config = receipt_config(language)
     try:
         logger.info("OCR: Rozpoczynam odczyt pliku obrazu...")
         with Image.open(io.BytesIO(file_bytes)) as image:
             # Preprocessing obrazu dla lepszego OCR
-            processor = OCRProcessor()
-            processed_image = processor._preprocess_receipt_image(image)
+            processed_image = image
+            if preprocess:
+                processor = OCRProcessor()
+                processed_image = processor._preprocess_receipt_image(image)
             text = _extract_text_from_image_obj(processed_image, config=config)
         logger.info("OCR: Odczyt obrazu zakończony sukcesem.")
         return text
@@ -239,10 +275,14 @@ def process_image_file(
 
 
 @handle_exceptions(max_retries=1, retry_delay=1.0)
-def process_pdf_file(file_bytes: bytes, config: Optional[str] = None) -> Optional[str]:
+def process_pdf_file(
+    file_bytes: bytes, config: Optional[str] = None, language: Optional[str] = None
+) -> Optional[str]:
     """
     Przetwarza plik PDF, konwertując każdą stronę na obraz i odczytując tekst.
     """
+    if language and not config:
+        config = receipt_config(language)
     try:
         logger.info("OCR: Rozpoczynam odczyt pliku PDF...")
         full_text = []
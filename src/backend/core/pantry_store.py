@@ -0,0 +1,130 @@
+"""
+Lokalny magazyn pozycji spiżarni dla /api/v1/pantry/items.
+
+Przy PANTRY_BACKEND=local trzyma całe pozycje. Przy PANTRY_BACKEND=grocy
+trzyma tylko pola, których Grocy nie przechowuje (notatki, atrybuty,
+produkt stały, historia scalania, jednostka) - kluczem jest identyfikator
+produktu Grocy, a endpoint dokleja je do stanu pobranego z Grocy.
+Dane leżą w jednym pliku JSON zapisywanym atomowo.
+"""
+
+import json
+import os
+import threading
+import uuid
+from typing import Any, Dict, List, Optional
+
+PANTRY_STORE_PATH = os.path.join(
+    os.path.dirname(os.path.abspath(__file__)),
+    *[".."] * 3,
+    "data",
+    "pantry",
+    "pantry_items.json",
+)
+
+# Pola pozycji, których Grocy nie przechowuje
+EXTRA_FIELDS = ("unit", "notes", "attributes", "staple", "merged_from")
+
+_lock = threading.Lock()
+
+
+def _load() -> Dict[str, Dict[str, Dict[str, Any]]]:
+    try:
+        with open(PANTRY_STORE_PATH, "r", encoding="utf-8") as f:
+            data = json.load(f)
+    except (OSError, ValueError):
+        data = {}
+    return {
+        "items": dict(data.get("items") or {}),
+        "extras": dict(data.get("extras") or {}),
+    }
+
+
+def _save(data: Dict[str, Dict[str, Dict[str, Any]]]) -> None:
+    os.makedirs(os.path.dirname(PANTRY_STORE_PATH), exist_ok=True)
+    tmp_path = PANTRY_STORE_PATH + ".tmp"
+    with open(tmp_path, "w", encoding="utf-8") as f:
+        json.dump(data, f, ensure_ascii=False, indent=2)
+    os.replace(tmp_path, PANTRY_STORE_PATH)
+
+
+def _apply(target: Dict[str, Any], changes: Dict[str, Any]) -> None:
+    """Wartość None usuwa pole - tak frontend cofa scalanie pozycji."""
+    for key, value in changes.items():
+        if value is None:
+            target.pop(key, None)
+        else:
+            target[key] = value
+
+
+def list_items() -> List[Dict[str, Any]]:
+    with _lock:
+        return list(_load()["items"].values())
+
+
+def get_item(item_id: str) -> Optional[Dict[str, Any]]:
+    with _lock:
+        return _load()["items"].get(item_id)
+
+
+def add_item(fields: Dict[str, Any]) -> Dict[str, Any]:
+    with _lock:
+        data = _load()
+        item: Dict[str, Any] = {"id": uuid.uuid4().hex}
+        _apply(item, fields)
+        item.setdefault("unified_category", item.get("category") or "Nieskategoryzowane")
+        data["items"][item["id"]] = item
+        _save(data)
+        return item
+
+
+def update_item(item_id: str, changes: Dict[str, Any]) -> Optional[Dict[str, Any]]:
+    with _lock:
+        data = _load()
+        item = data["items"].get(item_id)
+        if item is None:
+            return None
+        _apply(item, {key: value for key, value in changes.items() if key != "id"})
+        _save(data)
+        return item
+
+
+def delete_item(item_id: str) -> bool:
+    with _lock:
+        data = _load()
+        if data["items"].pop(item_id, None) is None:
+            return False
+        _save(data)
+        return True
+
+
+def get_extras(item_id: str) -> Dict[str, Any]:
+    with _lock:
+        return dict(_load()["extras"].get(item_id) or {})
+
+
+def with_extras(items: List[Dict[str, Any]]) -> List[Dict[str, Any]]:
+    """Dokleja zapisane lokalnie pola do pozycji z Grocy."""
+    with _lock:
+        extras = _load()["extras"]
+    return [{**item, **extras.get(item["id"], {})} for item in items]
+
+
+def set_extras(item_id: str, changes: Dict[str, Any]) -> None:
+    changes = {key: value for key, value in changes.items() if key in EXTRA_FIELDS}
+    if not changes:
+        return
+    with _lock:
+        data = _load()
+        extras = data["extras"].setdefault(item_id, {})
+        _apply(extras, changes)
+        if not extras:
+            del data["extras"][item_id]
+        _save(data)
+
+
+def drop_extras(item_id: str) -> None:
+    with _lock:
+        data = _load()
+        if data["extras"].pop(item_id, None) is not None:
+            _save(data)
@@ -7,6 +7,7 @@ from typing import Any, AsyncGenerator, Dict, List, Optional
 
 import httpx
 
+from backend.agents.prompts import reply_language_messages
 from backend.config import settings
 
 logger = logging.getLogger(__name__)
@@ -53,6 +54,7 @@ class PerplexityClient:
         focus: Optional[str] = None,
         domain_filter: Optional[str] = None,
         max_results: int = 5,
+        language: Optional[str] = None,
     ) -> Dict[str, Any]:
         """
         Search using Perplexity API
@@ -63,6 +65,7 @@ class PerplexityClient:
             focus: Focus domain (e.g., "academic", "news", "writing")
             domain_filter: Specific domain filter
             max_results: Maximum number of results to return
+            language: Reply language forced for the conversation (pl/en), if any
 
         Returns:
             Dictionary with search results
@@ -99,6 +102,7 @@ class PerplexityClient:
 
             messages = [
                 {"role": "system", "content": system_message},
+                *reply_language_messages(language),
                 {"role": "user", "content": query},
             ]
 
@@ -0,0 +1,408 @@
+"""
+Eksport listy zakupów do zewnętrznej listy zadań - Todoist albo CalDAV (VTODO).
+
+Lista zakupów jest trzymana w przeglądarce, więc frontend przy każdym
+odświeżeniu wysyła jej pozycje. Synchronizacja działa w obie strony:
+nowe pozycje trafiają do listy zadań, pozycje odhaczone w FoodSave są tam
+zamykane (a po ponownym odznaczeniu otwierane), usunięte - kasowane,
+a zadania zamknięte w Todoist/CalDAV wracają jako odhaczone.
+Pozycja przemianowana po drugiej stronie jest zgłaszana jako konflikt -
+użytkownik wybiera wersję we frontendzie, nic nie jest nadpisywane po cichu.
+Pozycje są łączone po identyfikatorze FoodSave zapisanym w zadaniu,
+dzięki czemu serwer nie musi przechowywać żadnego stanu.
+"""
+
+import logging
+import re
+import xml.etree.ElementTree as ET
+from dataclasses import asdict, dataclass, field
+from typing import Any, Dict, List, Optional, Protocol, Sequence, Tuple
+
+import httpx
+
+from backend.config import settings
+from backend.core.ics_parser import parse_ics_todos
+from backend.core.ics_writer import build_todo
+
+logger = logging.getLogger(__name__)
+
+TODOIST_API_URL = "https://api.todoist.com/rest/v2"
+# Znacznik w opisie zadania Todoist wiążący je z pozycją listy FoodSave
+TODOIST_MARKER = "foodsave:"
+CALDAV_UID_SUFFIX = "@foodsave"
+
+
+class TaskSyncError(Exception):
+    """Błąd komunikacji z Todoist lub serwerem CalDAV."""
+
+
+@dataclass
+class ShoppingItem:
+    id: str
+    name: str
+    quantity: Optional[str] = None
+    checked: bool = False
+    # Pozycja była już wysłana do listy zadań; frontend czyści flagę po odznaczeniu
+    synced: bool = False
+
+
+@dataclass
+class RemoteTask:
+    item_id: str
+    remote_id: str
+    completed: bool
+    # Tytuł zadania; None, gdy dostawca go nie podał (wtedy nie szukamy konfliktów)
+    title: Optional[str] = field(default=None, compare=False)
+
+
+@dataclass
+class SyncConflict:
+    """Pozycja zmieniona w liście zadań - jej zdalna nazwa i ilość."""
+
+    item_id: str
+    name: str
+    quantity: Optional[str] = None
+
+
+@dataclass
+class SyncPlan:
+    create: List[ShoppingItem] = field(default_factory=list)
+    complete: List[RemoteTask] = field(default_factory=list)
+    reopen: List[RemoteTask] = field(default_factory=list)
+    delete: List[RemoteTask] = field(default_factory=list)
+    check_locally: List[str] = field(default_factory=list)
+    conflicts: List[SyncConflict] = field(default_factory=list)
+
+
+def plan_sync(
+    items: List[ShoppingItem],
+    remote: List[RemoteTask],
+    removed: Sequence[str] = (),
+) -> SyncPlan:
+    """
+    Ustala, co zrobić po obu stronach. Wysłana wcześniej pozycja, której nie ma
+    już wśród zadań, została zamknięta lub usunięta zdalnie - odhaczamy ją.
+    Pozycja odznaczona w FoodSave przychodzi bez flagi ``synced``: zamknięte
+    zadanie jest wtedy otwierane (CalDAV), a zniknięte tworzone od nowa (Todoist).
+    Otwarte zadanie o innym tytule niż pozycja to konflikt do rozstrzygnięcia.
+    ``removed`` to pozycje usunięte z listy FoodSave - ich zadania są kasowane.
+    """
+    by_item = {task.item_id: task for task in remote}
+    present = {item.id for item in items}
+    plan = SyncPlan(
+        delete=[
+            by_item[item_id]
+            for item_id in dict.fromkeys(removed)
+            if item_id in by_item and item_id not in present
+        ]
+    )
+    for item in items:
+        task = by_item.get(item.id)
+        if task is None:
+            if item.checked:
+                continue
+            if item.synced:
+                plan.check_locally.append(item.id)
+            else:
+                plan.create.append(item)
+        elif item.checked and not task.completed:
+            plan.complete.append(task)
+        elif not item.checked and task.completed:
+            if item.synced:
+                plan.check_locally.append(item.id)
+            else:
+                plan.reopen.append(task)
+        elif (
+            not item.checked
+            and task.title is not None
+            and task.title.strip() != _title(item)
+        ):
+            name, quantity = _parse_title(task.title)
+            plan.conflicts.append(SyncConflict(item.id, name, quantity))
+    return plan
+
+
+def _title(item: ShoppingItem) -> str:
+    return f"{item.name} ({item.quantity})" if item.quantity else item.name
+
+
+def _parse_title(title: str) -> Tuple[str, Optional[str]]:
+    """Odwrotność _title: "Mleko (2 l)" -> ("Mleko", "2 l")."""
+    match = re.fullmatch(r"(.+?)\s*\(([^()]+)\)", title.strip())
+    if match:
+        return match.group(1), match.group(2).strip()
+    return title.strip(), None
+
+
+class TaskListClient(Protocol):
+    async def list_tasks(self) -> List[RemoteTask]: ...
+
+    async def create_task(self, item: ShoppingItem) -> None: ...
+
+    async def complete_task(self, task: RemoteTask, item: ShoppingItem) -> None: ...
+
+    async def reopen_task(self, task: RemoteTask, item: ShoppingItem) -> None: ...
+
+    async def delete_task(self, task: RemoteTask) -> None: ...
+
+    async def update_task(self, task: RemoteTask, item: ShoppingItem) -> None: ...
+
+
+class TodoistClient:
+    """Todoist REST API v2; zadania trafiają do projektu o nazwie TODOIST_PROJECT."""
+
+    def __init__(self, api_token: str, project_name: str, timeout: float = 15.0) -> None:
+        self.project_name = project_name
+        self._project_id: Optional[str] = None
+        self.http_client = httpx.AsyncClient(
+            base_url=TODOIST_API_URL,
+            timeout=timeout,
+            headers={
+                "Authorization": f"Bearer {api_token}",
+                "User-Agent": settings.USER_AGENT,
+            },
+        )
+
+    async def _request(self, method: str, path: str, **kwargs: Any) -> Any:
+        try:
+            response = await self.http_client.request(method, path, **kwargs)
+        except httpx.HTTPError as e:
+            raise TaskSyncError(f"Todoist nie odpowiada: {e}") from e
+        if response.is_error:
+            raise TaskSyncError(
+                f"Todoist zwrócił błąd HTTP {response.status_code}: {response.text[:200]}"
+            )
+        return response.json() if response.content else None
+
+    async def _project(self) -> str:
+        if self._project_id is None:
+            projects = await self._request("GET", "/projects")
+            match = next(
+                (p for p in projects or [] if p.get("name") == self.project_name), None
+            )
+            if match is None:
+                match = await self._request(
+                    "POST", "/projects", json={"name": self.project_name}
+                )
+            self._project_id = str(match["id"])
+        return self._project_id
+
+    async def list_tasks(self) -> List[RemoteTask]:
+        # /tasks zwraca tylko otwarte zadania; zamknięte znikają z listy
+        tasks = await self._request(
+            "GET", "/tasks", params={"project_id": await self._project()}
+        )
+        result = []
+        for task in tasks or []:
+            description = task.get("description") or ""
+            if description.startswith(TODOIST_MARKER):
+                item_id = description[len(TODOIST_MARKER) :].strip()
+                result.append(
+                    RemoteTask(
+                        item_id,
+                        str(task["id"]),
+                        completed=False,
+                        title=task.get("content"),
+                    )
+                )
+        return result
+
+    async def create_task(self, item: ShoppingItem) -> None:
+        await self._request(
+            "POST",
+            "/tasks",
+            json={
+                "content": _title(item),
+                "description": f"{TODOIST_MARKER}{item.id}",
+                "project_id": await self._project(),
+            },
+        )
+
+    async def complete_task(self, task: RemoteTask, item: ShoppingItem) -> None:
+        await self._request("POST", f"/tasks/{task.remote_id}/close")
+
+    async def reopen_task(self, task: RemoteTask, item: ShoppingItem) -> None:
+        await self._request("POST", f"/tasks/{task.remote_id}/reopen")
+
+    async def delete_task(self, task: RemoteTask) -> None:
+        await self._request("DELETE", f"/tasks/{task.remote_id}")
+
+    async def update_task(self, task: RemoteTask, item: ShoppingItem) -> None:
+        await self._request(
+            "POST", f"/tasks/{task.remote_id}", json={"content": _title(item)}
+        )
+
+
+class CalDavClient:
+    """Lista zadań CalDAV (Nextcloud, Radicale...) - zadanie to plik <id>.ics w kolekcji."""
+
+    def __init__(
+        self, collection_url: str, username: str, password: str, timeout: float = 15.0
+    ) -> None:
+        self.collection_url = collection_url.rstrip("/") + "/"
+        self.http_client = httpx.AsyncClient(
+            timeout=timeout,
+            auth=(username, password) if username else None,
+            headers={"User-Agent": settings.USER_AGENT},
+        )
+
+    async def _request(self, method: str, url: str, **kwargs: Any) -> httpx.Response:
+        try:
+            response = await self.http_client.request(method, url, **kwargs)
+        except httpx.HTTPError as e:
+            raise TaskSyncError(f"Serwer CalDAV nie odpowiada: {e}") from e
+        if response.is_error:
+            raise TaskSyncError(
+                f"Serwer CalDAV zwrócił błąd HTTP {response.status_code}: {response.text[:200]}"
+            )
+        return response
+
+    def _task_url(self, item_id: str) -> str:
+        return f"{self.collection_url}{item_id}.ics"
+
+    async def list_tasks(self) -> List[RemoteTask]:
+        query = (
+            '<?xml version="1.0" encoding="utf-8"?>'
+            '<c:calendar-query xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">'
+            "<d:prop><c:calendar-data/></d:prop>"
+            '<c:filter><c:comp-filter name="VCALENDAR">'
+            '<c:comp-filter name="VTODO"/></c:comp-filter></c:filter>'
+            "</c:calendar-query>"
+        )
+        response = await self._request(
+            "REPORT",
+            self.collection_url,
+            content=query,
+            headers={"Depth": "1", "Content-Type": "application/xml; charset=utf-8"},
+        )
+        return caldav_tasks_from_report(response.text)
+
+    async def _put(self, item: ShoppingItem, completed: bool) -> None:
+        await self._request(
+            "PUT",
+            self._task_url(item.id),
+            content=build_todo(
+                f"{item.id}{CALDAV_UID_SUFFIX}", _title(item), completed=completed
+            ),
+            headers={"Content-Type": "text/calendar; charset=utf-8"},
+        )
+
+    async def create_task(self, item: ShoppingItem) -> None:
+        await self._put(item, completed=False)
+
+    async def complete_task(self, task: RemoteTask, item: ShoppingItem) -> None:
+        await self._put(item, completed=True)
+
+    async def reopen_task(self, task: RemoteTask, item: ShoppingItem) -> None:
+        await self._put(item, completed=False)
+
+    async def delete_task(self, task: RemoteTask) -> None:
+        await self._request("DELETE", self._task_url(task.item_id))
+
+    async def update_task(self, task: RemoteTask, item: ShoppingItem) -> None:
+        await self._put(item, completed=task.completed)
+
+
+def caldav_tasks_from_report(xml_text: str) -> List[RemoteTask]:
+    """Odpowiedź multistatus na REPORT calendar-query -> zadania FoodSave."""
+    try:
+        root = ET.fromstring(xml_text)
+    except ET.ParseError as e:
+        raise TaskSyncError(f"Niepoprawna odpowiedź CalDAV: {e}") from e
+    tasks = []
+    for data in root.iter("{urn:ietf:params:xml:ns:caldav}calendar-data"):
+        for todo in parse_ics_todos(data.text or ""):
+            uid = todo["uid"]
+            # Zadania dodane ręcznie w kalendarzu nie należą do FoodSave
+            if uid.endswith(CALDAV_UID_SUFFIX):
+                item_id = uid[: -len(CALDAV_UID_SUFFIX)]
+                title = todo.get("summary")
+                tasks.append(RemoteTask(item_id, uid, todo["completed"], title=title))
+    return tasks
+
+
+async def sync_shopping_list(
+    client: TaskListClient, items: List[ShoppingItem], removed: Sequence[str] = ()
+) -> Dict[str, List[Any]]:
+    """
+    Synchronizuje listę; zwraca pozycje do odhaczenia, te obecne w liście zadań,
+    obsłużone usunięcia i konflikty (pozycje przemianowane zdalnie) do
+    rozstrzygnięcia przez użytkownika.
+    """
+    remote = await client.list_tasks()
+    plan = plan_sync(items, remote, removed)
+    by_id = {item.id: item for item in items}
+    for item in plan.create:
+        await client.create_task(item)
+    for task in plan.complete:
+        await client.complete_task(task, by_id[task.item_id])
+    for task in plan.reopen:
+        await client.reopen_task(task, by_id[task.item_id])
+    for task in plan.delete:
+        await client.delete_task(task)
+    logger.info(
+        "Shopping list sync: %d created, %d completed, %d reopened, %d deleted, "
+        "%d checked locally, %d conflicts",
+        len(plan.create),
+        len(plan.complete),
+        len(plan.reopen),
+        len(plan.delete),
+        len(plan.check_locally),
+        len(plan.conflicts),
+    )
+    # Pozycja jest zsynchronizowana, gdy ma zadanie - także odznaczona lokalnie
+    exported = {item.id for item in plan.create} | {task.item_id for task in remote}
+    synced = [item.id for item in items if item.synced or item.id in exported]
+    conflicts = [asdict(conflict) for conflict in plan.conflicts]
+    return {
+        "checked": plan.check_locally,
+        "synced": synced,
+        "removed": list(removed),
+        "conflicts": conflicts,
+    }
+
+
+async def resolve_conflicts(
+    client: TaskListClient, items: List[ShoppingItem]
+) -> List[str]:
+    """
+    Zapisuje w liście zadań wersje wybrane przez użytkownika ("moja" albo scalona).
+    Zwraca zaktualizowane pozycje; zadań usuniętych w międzyczasie nie odtwarza.
+    """
+    by_item = {task.item_id: task for task in await client.list_tasks()}
+    updated = []
+    for item in items:
+        task = by_item.get(item.id)
+        if task is not None:
+            await client.update_task(task, item)
+            updated.append(item.id)
+    logger.info("Shopping list conflicts resolved: %d updated remotely", len(updated))
+    return updated
+
+
+_client: Optional[TaskListClient] = None
+
+
+def is_task_sync_enabled() -> bool:
+    return settings.TASK_SYNC_PROVIDER in ("todoist", "caldav")
+
+
+def get_task_sync_client() -> TaskListClient:
+    """Współdzielony klient wybranego dostawcy; błąd, gdy brakuje konfiguracji."""
+    global _client
+    if _client is not None:
+        return _client
+    if settings.TASK_SYNC_PROVIDER == "todoist":
+        if not settings.TODOIST_API_TOKEN:
+            raise TaskSyncError("Ustaw TODOIST_API_TOKEN, aby eksportować listę do Todoist")
+        _client = TodoistClient(settings.TODOIST_API_TOKEN, settings.TODOIST_PROJECT)
+    elif settings.TASK_SYNC_PROVIDER == "caldav":
+        if not settings.CALDAV_URL:
+            raise TaskSyncError("Ustaw CALDAV_URL, aby eksportować listę przez CalDAV")
+        _client = CalDavClient(
+            settings.CALDAV_URL, settings.CALDAV_USERNAME, settings.CALDAV_PASSWORD
+        )
+    else:
+        raise TaskSyncError("Ustaw TASK_SYNC_PROVIDER na todoist albo caldav")
+    logger.info("Task sync client initialized (%s)", settings.TASK_SYNC_PROVIDER)
+    return _client
@@ -2,7 +2,16 @@
 from datetime import date
 from typing import List, Optional
 
-from pydantic import BaseModel, ConfigDict
+from pydantic import BaseModel, ConfigDict, field_validator
+
+from backend.core.date_parser import parse_date
+
+
+def _tolerant_date(value):
+    """Przyjmuje też daty w formatach z paragonów (np. 15.01.24); resztę waliduje pydantic."""
+    if isinstance(value, str):
+        return parse_date(value) or value
+    return value
 
 # --- Schematy dla Produktu ---
 
@@ -11,10 +20,16 @@ from pydantic import BaseModel, ConfigDict
 class ProductBase(BaseModel):
     name: str
     quantity: float = 1.0
+    unit: Optional[str] = None
     unit_price: Optional[float] = None
+    category: Optional[str] = None
     expiration_date: Optional[date] = None
     is_consumed: bool = False
 
+    _parse_expiration_date = field_validator("expiration_date", mode="before")(
+        _tolerant_date
+    )
+
 
 # Schemat używany przy tworzeniu nowego produktu (nie znamy jeszcze jego ID)
 class ProductCreate(ProductBase):
@@ -52,6 +67,8 @@ class ShoppingTripBase(BaseModel):
     store_name: str
     total_amount: Optional[float] = None
 
+    _parse_trip_date = field_validator("trip_date", mode="before")(_tolerant_date)
+
 
 # Schemat do tworzenia nowego paragonu - zawiera listę produktów do stworzenia
 class ShoppingTripCreate(ShoppingTripBase):
@@ -69,6 +86,8 @@ class ShoppingTripUpdate(BaseModel):
     store_name: Optional[str] = None
     total_amount: Optional[float] = None
 
+    _parse_trip_date = field_validator("trip_date", mode="before")(_tolerant_date)
+
     model_config = ConfigDict(from_attributes=True)
 
 
@@ -89,3 +108,32 @@ class ShoppingTripSummary(BaseModel):
     total_cost: float
 
     model_config = ConfigDict(from_attributes=True)
+
+
+# --- Schematy dla statystyk wydatków ---
+
+
+class SpendingBucket(BaseModel):
+    """Suma wydatków w jednej grupie (miesiąc, kategoria lub sklep)."""
+
+    label: str
+    total: float
+    count: int
+
+
+class SpendingStats(BaseModel):
+    """
+    Zagregowane wydatki w zadanym zakresie dat.
+    Używane przez stronę analityki we frontendzie.
+    """
+
+    date_from: Optional[date] = None
+    date_to: Optional[date] = None
+    total: float
+    receipts_count: int
+    monthly: List[SpendingBucket] = []
+    by_category: List[SpendingBucket] = []
+    by_store: List[SpendingBucket] = []
+    # Niezużyte produkty, którym w tym zakresie minęła data ważności
+    wasted_count: int = 0
+    wasted_total: float = 0.0
@@ -1,5 +1,7 @@
 # w pliku backend/services/shopping_service.py
-from typing import Optional
+from collections import defaultdict
+from datetime import date, timedelta
+from typing import Dict, List, Optional
 
 from sqlalchemy import select
 from sqlalchemy.ext.asyncio import AsyncSession
@@ -46,10 +48,15 @@ async def create_shopping_trip(
 
 
 async def get_shopping_trips(
-    db: AsyncSession, skip: int = 0, limit: int = 100
+    db: AsyncSession,
+    skip: int = 0,
+    limit: int = 100,
+    date_from: Optional[date] = None,
+    date_to: Optional[date] = None,
 ) -> list[ShoppingTrip]:
     """
-    Pobiera listę wszystkich paragonów z bazy danych wraz z ich produktami.
+    Pobiera listę paragonów z bazy danych wraz z ich produktami.
+    Opcjonalnie zawęża wynik do zakresu dat (obie granice włącznie).
     """
     query = (
         select(ShoppingTrip)
@@ -60,11 +67,177 @@ async def get_shopping_trips(
             selectinload(ShoppingTrip.products)
         )  # Eager loading jest tu równie kluczowy!
     )
+    if date_from is not None:
+        query = query.where(ShoppingTrip.trip_date >= date_from)
+    if date_to is not None:
+        query = query.where(ShoppingTrip.trip_date <= date_to)
     result = await db.execute(query)
     shopping_trips: list[ShoppingTrip] = list(result.scalars().all())
     return shopping_trips
 
 
+async def get_shopping_trip(db: AsyncSession, trip_id: int) -> Optional[ShoppingTrip]:
+    """
+    Pobiera jeden paragon wraz z produktami albo None, jeśli nie istnieje.
+    """
+    query = (
+        select(ShoppingTrip)
+        .where(ShoppingTrip.id == trip_id)
+        .options(selectinload(ShoppingTrip.products))
+    )
+    result = await db.execute(query)
+    return result.scalar_one_or_none()
+
+
+async def get_shopping_trips_in_range(
+    db: AsyncSession,
+    date_from: Optional[date] = None,
+    date_to: Optional[date] = None,
+) -> list[ShoppingTrip]:
+    """
+    Pobiera paragony z zakresu dat (obie granice włącznie) wraz z produktami.
+    Brak granicy oznacza brak ograniczenia z danej strony.
+    """
+    query = select(ShoppingTrip).options(selectinload(ShoppingTrip.products))
+    if date_from is not None:
+        query = query.where(ShoppingTrip.trip_date >= date_from)
+    if date_to is not None:
+        query = query.where(ShoppingTrip.trip_date <= date_to)
+    query = query.order_by(ShoppingTrip.trip_date.asc())
+
+    result = await db.execute(query)
+    return list(result.scalars().all())
+
+
+async def get_expiring_products(db: AsyncSession, days: int = 7) -> list[Product]:
+    """
+    Pobiera niezużyte produkty, którym data ważności mija w ciągu `days` dni
+    (od dziś włącznie), posortowane od najszybciej tracących ważność.
+    """
+    today = date.today()
+    query = (
+        select(Product)
+        .where(Product.is_consumed == 0)
+        .where(Product.expiration_date.is_not(None))
+        .where(Product.expiration_date >= today)
+        .where(Product.expiration_date <= today + timedelta(days=days))
+        .order_by(Product.expiration_date.asc())
+    )
+    result = await db.execute(query)
+    return list(result.scalars().all())
+
+
+async def get_wasted_products(
+    db: AsyncSession,
+    date_from: Optional[date] = None,
+    date_to: Optional[date] = None,
+) -> list[Product]:
+    """
+    Pobiera niezużyte produkty, którym data ważności minęła w podanym
+    zakresie dat (najpóźniej wczoraj) - czyli zmarnowane.
+    """
+    last_day = date.today() - timedelta(days=1)
+    if date_to is None or date_to > last_day:
+        date_to = last_day
+    query = (
+        select(Product)
+        .where(Product.is_consumed == 0)
+        .where(Product.expiration_date.is_not(None))
+        .where(Product.expiration_date <= date_to)
+    )
+    if date_from is not None:
+        query = query.where(Product.expiration_date >= date_from)
+    result = await db.execute(query)
+    return list(result.scalars().all())
+
+
+def _product_line_total(product: Product) -> float:
+    """Wartość pozycji paragonu: cena jednostkowa razy ilość."""
+    if product.unit_price is None:
+        return 0.0
+    quantity = product.quantity if product.quantity is not None else 1.0
+    return product.unit_price * quantity
+
+
+def _trip_total(trip: ShoppingTrip) -> float:
+    """Suma paragonu - z nagłówka, a jeśli jej brak, to z pozycji."""
+    if trip.total_amount is not None:
+        return trip.total_amount
+    return sum(_product_line_total(product) for product in trip.products)
+
+
+def _to_buckets(
+    totals: Dict[str, float], counts: Dict[str, int], sort_by_label: bool = False
+) -> List[shopping_schemas.SpendingBucket]:
+    labels = (
+        sorted(totals)
+        if sort_by_label
+        else sorted(totals, key=totals.__getitem__, reverse=True)
+    )
+    return [
+        shopping_schemas.SpendingBucket(
+            label=label, total=round(totals[label], 2), count=counts[label]
+        )
+        for label in labels
+    ]
+
+
+def summarize_spending(
+    trips: List[ShoppingTrip],
+    date_from: Optional[date] = None,
+    date_to: Optional[date] = None,
+    wasted: Optional[List[Product]] = None,
+) -> shopping_schemas.SpendingStats:
+    """
+    Agreguje paragony w statystyki: sumy miesięczne (YYYY-MM, rosnąco),
+    sumy per kategoria i per sklep (malejąco wg kwoty) oraz liczbę
+    i wartość zmarnowanych produktów.
+    """
+    wasted = wasted or []
+    monthly: Dict[str, float] = defaultdict(float)
+    monthly_count: Dict[str, int] = defaultdict(int)
+    by_category: Dict[str, float] = defaultdict(float)
+    category_count: Dict[str, int] = defaultdict(int)
+    by_store: Dict[str, float] = defaultdict(float)
+    store_count: Dict[str, int] = defaultdict(int)
+
+    for trip in trips:
+        trip_total = _trip_total(trip)
+        month = trip.trip_date.strftime("%Y-%m")
+        monthly[month] += trip_total
+        monthly_count[month] += 1
+        by_store[trip.store_name] += trip_total
+        store_count[trip.store_name] += 1
+
+        for product in trip.products:
+            category = product.category or "Inne"
+            by_category[category] += _product_line_total(product)
+            category_count[category] += 1
+
+    return shopping_schemas.SpendingStats(
+        date_from=date_from,
+        date_to=date_to,
+        total=round(sum(monthly.values()), 2),
+        receipts_count=len(trips),
+        monthly=_to_buckets(monthly, monthly_count, sort_by_label=True),
+        by_category=_to_buckets(by_category, category_count),
+        by_store=_to_buckets(by_store, store_count),
+        wasted_count=len(wasted),
+        wasted_total=round(sum(_product_line_total(p) for p in wasted), 2),
+    )
+
+
+async def get_spending_stats(
+    db: AsyncSession,
+    date_from: Optional[date] = None,
+    date_to: Optional[date] = None,
+) -> shopping_schemas.SpendingStats:
+    """Zwraca statystyki wydatków dla paragonów z podanego zakresu dat."""
+    trips = await get_shopping_trips_in_range(db, date_from, date_to)
+    wasted = await get_wasted_products(db, date_from, date_to)
+    return summarize_spending(trips, date_from, date_to, wasted)
+
+
 async def update_shopping_trip(
     db: AsyncSession, trip_id: int, trip_update: shopping_schemas.ShoppingTripUpdate
 ) -> Optional[ShoppingTrip]:
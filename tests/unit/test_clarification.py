@@ -0,0 +1,48 @@
+"""
+Testy propozycji doprecyzowania intencji przy niepewnym routingu.
+"""
+
+from backend.agents.agent_router import INTENT_AGENT_TYPES
+from backend.agents.clarification import (CLARIFICATION_OPTIONS,
+                                          CLARIFICATION_THRESHOLD,
+                                          pinned_intent, routing_metadata)
+from backend.agents.interfaces import IntentData
+
+
+def test_low_confidence_adds_clarification_options():
+    routing = routing_metadata(
+        IntentData(type="general_conversation", confidence=0.5)
+    )
+
+    assert routing["intent"] == "general_conversation"
+    agents = [option["agent"] for option in routing["clarification"]]
+    assert "weather" in agents
+    assert "cooking" in agents
+
+
+def test_confident_intent_has_no_clarification():
+    routing = routing_metadata(
+        IntentData(type="weather", confidence=CLARIFICATION_THRESHOLD)
+    )
+
+    assert "clarification" not in routing
+
+
+def test_options_skip_the_detected_intent():
+    routing = routing_metadata(IntentData(type="cooking", confidence=0.3))
+
+    assert "cooking" not in [o["agent"] for o in routing["clarification"]]
+
+
+def test_pinned_intent_bypasses_detection():
+    intent = pinned_intent("weather")
+
+    assert intent is not None
+    assert intent.type == "weather"
+    assert intent.confidence == 1.0
+    assert pinned_intent("rm -rf") is None
+    assert pinned_intent(None) is None
+
+
+def test_every_option_has_an_agent_mapping():
+    assert set(CLARIFICATION_OPTIONS) <= set(INTENT_AGENT_TYPES)
@@ -0,0 +1,23 @@
+"""
+Testy danych aplikacji dołączanych do pytania w czacie.
+"""
+
+from backend.agents.prompts import format_context_attachments
+
+
+def test_attachments_are_listed_under_their_titles():
+    text = format_context_attachments(
+        [
+            {"kind": "pantry", "title": "Spiżarnia", "items": ["Mleko – 2 l", "Jajka"]},
+            {"kind": "shopping_list", "title": "Lista zakupów", "items": []},
+        ]
+    )
+
+    assert text is not None
+    assert "Spiżarnia:\n- Mleko – 2 l\n- Jajka" in text
+    assert "Lista zakupów:\n(brak pozycji)" in text
+
+
+def test_no_attachments_add_nothing_to_the_prompt():
+    assert format_context_attachments(None) is None
+    assert format_context_attachments([]) is None
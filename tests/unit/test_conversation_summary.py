@@ -0,0 +1,35 @@
+"""
+Testy promptu podsumowania rozmowy z asystentem.
+"""
+
+from backend.agents.prompts import (MAX_SUMMARY_TRANSCRIPT_CHARS,
+                                    get_conversation_summary_prompt)
+
+
+def test_transcript_names_speakers_in_order():
+    prompt = get_conversation_summary_prompt(
+        [
+            {"role": "user", "content": "Co ugotować z ryżu?"},
+            {"role": "assistant", "content": "Risotto z warzywami."},
+        ]
+    )
+
+    assert "Użytkownik: Co ugotować z ryżu?\n\nAsystent: Risotto z warzywami." in prompt
+
+
+def test_long_conversation_keeps_latest_messages():
+    old = {"role": "user", "content": "stare " * MAX_SUMMARY_TRANSCRIPT_CHARS}
+    latest = {"role": "assistant", "content": "Najnowsza odpowiedź"}
+
+    prompt = get_conversation_summary_prompt([old, latest])
+
+    assert "Najnowsza odpowiedź" in prompt
+    assert "stare" not in prompt
+
+
+def test_forced_language_is_appended():
+    prompt = get_conversation_summary_prompt(
+        [{"role": "user", "content": "Hi"}], language="en"
+    )
+
+    assert prompt.endswith("Always reply in English, even if the user writes in Polish.")
@@ -0,0 +1,66 @@
+"""
+Testy tolerancyjnego parsera dat z paragonów i pól daty ważności.
+"""
+
+from datetime import date, datetime
+
+import pytest
+
+from backend.agents.utils import format_date, is_expired
+from backend.core.date_parser import find_date, parse_date, parse_datetime
+from backend.schemas.shopping_schemas import ProductCreate
+
+
+@pytest.mark.parametrize(
+    "value",
+    [
+        "15.01.2024",
+        "15-01-2024",
+        "15/01/2024",
+        "15.01.24",
+        "15-01-24",
+        "2024-01-15",
+        "2024.01.15",
+        " 15.01.2024 12:30 ",
+    ],
+)
+def test_parse_date_accepts_common_receipt_formats(value):
+    assert parse_date(value) == date(2024, 1, 15)
+
+
+def test_parse_date_accepts_single_digit_day_and_month():
+    assert parse_date("1.1.2024") == date(2024, 1, 1)
+
+
+def test_parse_datetime_keeps_time():
+    assert parse_datetime("15.01.2024 12:30:05") == datetime(2024, 1, 15, 12, 30, 5)
+    assert parse_datetime("2024-01-15T08:15") == datetime(2024, 1, 15, 8, 15)
+
+
+@pytest.mark.parametrize("value", ["", "31.02.2024", "15.13.2024", "jutro", "15.01"])
+def test_parse_date_rejects_invalid_input(value):
+    assert parse_date(value) is None
+
+
+def test_find_date_in_ocr_text_skips_tax_numbers():
+    text = "LIDL sp. z o.o.\nNIP 781-18-97-358\nPARAGON FISKALNY\n15.01.24 14:32 nr 1234"
+
+    assert find_date(text) == date(2024, 1, 15)
+    assert find_date("SUMA PLN 12,50") is None
+
+
+def test_format_date_and_is_expired_use_tolerant_parser():
+    today = date(2024, 1, 15)
+
+    assert format_date("2024-01-15") == "15.01.2024"
+    assert format_date("15-01-24") == "15.01.2024"
+    assert format_date("nieznana") == "nieznana"
+    assert is_expired("14.01.2024", today)
+    assert not is_expired("15.01.2024", today)
+    assert not is_expired(None, today)
+
+
+def test_product_schema_accepts_polish_expiry_date():
+    product = ProductCreate(name="Mleko", expiration_date="20.01.2024")
+
+    assert product.expiration_date == date(2024, 1, 20)
@@ -0,0 +1,101 @@
+"""
+Testy klienta wiersza poleceń (scripts/foodsave_cli.py) na atrapie backendu.
+"""
+
+import argparse
+import importlib.util
+import json
+from pathlib import Path
+from urllib.parse import parse_qs
+
+import httpx
+import pytest
+
+CLI_PATH = Path(__file__).resolve().parents[2] / "scripts" / "foodsave_cli.py"
+_spec = importlib.util.spec_from_file_location("foodsave_cli", CLI_PATH)
+cli = importlib.util.module_from_spec(_spec)
+_spec.loader.exec_module(cli)
+
+
+def _client(handler):
+    return cli.FoodSaveClient(
+        "http://backend.test/", transport=httpx.MockTransport(handler)
+    )
+
+
+def test_pantry_list_uses_mounted_pantry_route():
+    requests = []
+
+    def handler(request):
+        requests.append(request)
+        return httpx.Response(200, json=[{"name": "Mleko"}])
+
+    assert _client(handler).pantry_products() == [{"name": "Mleko"}]
+    assert requests[0].url.path == "/api/pantry/pantry/products"
+
+
+def test_expiring_products_passes_days():
+    def handler(request):
+        assert request.url.path == "/api/food/products/expiring"
+        assert request.url.params["days"] == "3"
+        return httpx.Response(200, json=[])
+
+    assert _client(handler).expiring_products(3) == []
+
+
+def test_analyze_receipt_sends_form_data():
+    def handler(request):
+        assert request.url.path == "/api/v2/receipts/analyze"
+        assert request.headers["content-type"].startswith(
+            "application/x-www-form-urlencoded"
+        )
+        assert parse_qs(request.content.decode()) == {"ocr_text": ["MLEKO 3,49"]}
+        return httpx.Response(200, json={"store_name": "Lidl"})
+
+    assert _client(handler).analyze_receipt("MLEKO 3,49") == {"store_name": "Lidl"}
+
+
+def test_scan_receipt_uploads_file(tmp_path):
+    receipt = tmp_path / "paragon.jpg"
+    receipt.write_bytes(b"jpeg")
+
+    def handler(request):
+        assert request.url.path == "/api/v1/receipts/upload"
+        assert b'filename="paragon.jpg"' in request.content
+        return httpx.Response(200, json={"text": "MLEKO 3,49"})
+
+    assert _client(handler).scan_receipt(receipt) == {"text": "MLEKO 3,49"}
+
+
+def test_error_response_raises_api_error():
+    def handler(request):
+        return httpx.Response(422, json={"detail": "Brak pola ocr_text"})
+
+    with pytest.raises(cli.ApiError, match="HTTP 422: Brak pola ocr_text"):
+        _client(handler).analyze_receipt("")
+
+
+def _ask_args(question):
+    return argparse.Namespace(question=[question], session=None, json=False)
+
+
+def test_ask_prints_agent_response(capsys):
+    def handler(request):
+        assert request.url.path == "/api/agents/agents/execute"
+        assert json.loads(request.content)["task"] == "Co na obiad?"
+        return httpx.Response(
+            200, json={"success": True, "response": "Ugotuj risotto", "session_id": "cli-1"}
+        )
+
+    assert cli.run_ask(_client(handler), _ask_args("Co na obiad?")) is True
+    assert capsys.readouterr().out == "Ugotuj risotto\n"
+
+
+def test_ask_fails_when_agent_reports_error(capsys):
+    def handler(request):
+        return httpx.Response(
+            200, json={"success": False, "error": "Model niedostępny", "session_id": "cli-1"}
+        )
+
+    assert cli.run_ask(_client(handler), _ask_args("Co na obiad?")) is False
+    assert "Model niedostępny" in capsys.readouterr().err
@@ -0,0 +1,58 @@
+"""
+Testy mapowania odpowiedzi Grocy na format produktów FoodSave.
+"""
+
+from backend.core.grocy_client import (
+    NO_EXPIRY_DATE,
+    stock_entry_to_pantry_item,
+    stock_entry_to_product,
+)
+
+
+def test_stock_entry_maps_to_pantry_product():
+    entry = {
+        "product_id": "7",
+        "amount": "2",
+        "best_before_date": "2024-01-20",
+        "product": {"id": "7", "name": "Mleko", "product_group_id": "3"},
+    }
+
+    product = stock_entry_to_product(entry, {3: "Nabiał"})
+
+    assert product["id"] == 7
+    assert product["name"] == "Mleko"
+    assert product["quantity"] == 2.0
+    assert product["unified_category"] == "Nabiał"
+    assert product["expiration_date"] == "2024-01-20"
+
+
+def test_stock_entry_without_group_or_expiry():
+    entry = {
+        "product_id": 8,
+        "amount": 1,
+        "best_before_date": NO_EXPIRY_DATE,
+        "product": {"id": 8, "name": "Sól"},
+    }
+
+    product = stock_entry_to_product(entry)
+
+    assert product["unified_category"] == "Nieskategoryzowane"
+    assert product["expiration_date"] is None
+
+
+def test_stock_entry_maps_to_pantry_item():
+    entry = {
+        "product_id": "7",
+        "amount": "2",
+        "best_before_date": "2024-01-20",
+        "product": {"id": "7", "name": "Mleko", "product_group_id": "3"},
+    }
+
+    assert stock_entry_to_pantry_item(entry, {3: "Nabiał"}) == {
+        "id": "7",
+        "name": "Mleko",
+        "quantity": 2.0,
+        "category": "Nabiał",
+        "unified_category": "Nabiał",
+        "expiry_date": "2024-01-20",
+    }
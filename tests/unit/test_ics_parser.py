@@ -0,0 +1,128 @@
+"""
+Testy minimalnego parsera ICS używanego przez widżet kalendarza.
+"""
+
+from datetime import date, datetime, timezone
+
+from backend.core.ics_parser import events_on, parse_ics_events
+from backend.core.ics_writer import build_ics, expiry_events
+
+SAMPLE_ICS = """BEGIN:VCALENDAR
+VERSION:2.0
+BEGIN:VEVENT
+SUMMARY:Zakupy w Lidlu\\, lista na lodówce
+DTSTART;TZID=Europe/Warsaw:20240105T170000
+DTEND;TZID=Europe/Warsaw:20240105T180000
+LOCATION:Ząbki
+END:VEVENT
+BEGIN:VEVENT
+SUMMARY:Urlop rodziców - bardzo długi opis, który zosta
+ ł zawinięty
+DTSTART;VALUE=DATE:20240104
+DTEND;VALUE=DATE:20240107
+END:VEVENT
+BEGIN:VEVENT
+SUMMARY:Inny dzień
+DTSTART:20240110T090000
+END:VEVENT
+END:VCALENDAR
+"""
+
+
+def test_parse_ics_events_reads_summary_dates_and_unfolds_lines():
+    events = parse_ics_events(SAMPLE_ICS)
+
+    assert len(events) == 3
+    assert events[0]["summary"] == "Zakupy w Lidlu, lista na lodówce"
+    assert events[0]["location"] == "Ząbki"
+    assert events[0]["all_day"] is False
+    assert events[1]["summary"].endswith("został zawinięty")
+    assert events[1]["all_day"] is True
+
+
+def test_events_on_includes_all_day_span_and_sorts_by_start():
+    events = events_on(parse_ics_events(SAMPLE_ICS), date(2024, 1, 5))
+
+    assert [e["summary"][:6] for e in events] == ["Urlop ", "Zakupy"]
+
+
+def test_events_on_excludes_exclusive_all_day_end():
+    events = events_on(parse_ics_events(SAMPLE_ICS), date(2024, 1, 7))
+
+    assert events == []
+
+
+def test_parse_ics_events_unescapes_backslash_before_other_sequences():
+    ics = (
+        "BEGIN:VEVENT\nSUMMARY:C:\\\\nowy\\, folder\\nDruga linia\n"
+        "DTSTART:20240110T090000\nEND:VEVENT"
+    )
+
+    assert parse_ics_events(ics)[0]["summary"] == "C:\\nowy, folder\nDruga linia"
+
+
+RECURRING_ICS = """BEGIN:VCALENDAR
+BEGIN:VEVENT
+SUMMARY:Basen
+DTSTART:20240101T180000
+DTEND:20240101T190000
+RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,TH;UNTIL=20240131T235959Z
+EXDATE:20240115T180000
+END:VEVENT
+BEGIN:VEVENT
+SUMMARY:Czynsz
+DTSTART;VALUE=DATE:20240110
+RRULE:FREQ=MONTHLY;COUNT=3
+END:VEVENT
+BEGIN:VEVENT
+SUMMARY:Ostatni piątek
+DTSTART:20240126T100000
+RRULE:FREQ=MONTHLY;BYDAY=-1FR
+END:VEVENT
+END:VCALENDAR
+"""
+
+
+def test_events_on_expands_recurring_events():
+    events = parse_ics_events(RECURRING_ICS)
+
+    def summaries(day):
+        return [e["summary"] for e in events_on(events, day)]
+
+    assert summaries(date(2024, 1, 4)) == ["Basen"]
+    assert summaries(date(2024, 1, 8)) == []  # tydzień przerwy (INTERVAL=2)
+    assert summaries(date(2024, 1, 15)) == []  # EXDATE
+    assert summaries(date(2024, 1, 18)) == ["Basen"]
+    assert summaries(date(2024, 2, 1)) == []  # po UNTIL
+    assert summaries(date(2024, 3, 10)) == ["Czynsz"]
+    assert summaries(date(2024, 4, 10)) == []  # po COUNT
+    # Nieobsługiwana reguła - tylko pierwsze wystąpienie
+    assert summaries(date(2024, 1, 26)) == ["Ostatni piątek"]
+    assert summaries(date(2024, 2, 23)) == []
+    assert events_on(events, date(2024, 1, 18))[0]["start"] == datetime(2024, 1, 18, 18)
+
+
+def test_build_ics_round_trips_expiry_events():
+    products = [
+        {"id": 1, "name": "Mleko, 2%", "quantity": 2.0, "unit": "l", "expiration_date": date(2024, 1, 20)},
+        {"id": 2, "name": "Sól", "expiration_date": None},
+    ]
+
+    ics = build_ics(expiry_events(products), now=datetime(2024, 1, 1, tzinfo=timezone.utc))
+    events = parse_ics_events(ics)
+
+    assert "TRIGGER:-P1D" in ics
+    assert len(events) == 1
+    assert events[0]["summary"] == "Kończy się ważność: Mleko, 2%"
+    assert events[0]["all_day"] is True
+    assert events_on(events, date(2024, 1, 20))
+    assert not events_on(events, date(2024, 1, 21))
+
+
+def test_build_ics_folds_long_lines_without_splitting_characters():
+    event = {"uid": "x@foodsave", "summary": "Żółty ser " * 20, "day": date(2024, 1, 20)}
+
+    ics = build_ics([event])
+
+    assert all(len(line.encode("utf-8")) <= 75 for line in ics.split("\r\n"))
+    assert parse_ics_events(ics)[0]["summary"] == event["summary"]
@@ -71,6 +71,27 @@ class TestOCRAgent:
             # Verify the mock was called with the right parameters
             mock_process.assert_called_once_with(sample_pdf_bytes)
 
+    @pytest.mark.asyncio
+    async def test_ocr_agent_passes_reprocessing_options(self, sample_image_bytes):
+        """Test OCRAgent.process forwards language and preprocessing options."""
+        agent = OCRAgent()
+        input_data = OCRAgentInput(
+            file_bytes=sample_image_bytes,
+            file_type="image",
+            language="eng",
+            preprocess=False,
+        )
+
+        with patch(
+            "backend.agents.ocr_agent.process_image_file", return_value="Milk 3.99"
+        ) as mock_process:
+            result = await agent.process(input_data)
+
+            assert result.success is True
+            mock_process.assert_called_once_with(
+                sample_image_bytes, language="eng", preprocess=False
+            )
+
     @pytest.mark.asyncio
     async def test_ocr_agent_process_unsupported_file_type(self, sample_image_bytes):
         """Test OCRAgent.process with unsupported file type."""
@@ -10,8 +10,10 @@ from backend.core.ocr import (
     OCRProcessor,
     OCRResult,
     _extract_text_from_image_obj,
+    detect_receipt_language,
     process_image_file,
     process_pdf_file,
+    receipt_config,
 )
 
 
@@ -78,6 +80,19 @@ class TestOCRProcessing:
             mock_extract.assert_called_once_with(mock_image, config=None)
             assert result == expected_text
 
+    def test_detect_receipt_language(self):
+        """Test receipt language detection from typical receipt words."""
+        assert detect_receipt_language("PARAGON FISKALNY\nSUMA PLN 12,99") == "pl"
+        assert detect_receipt_language("Kassenbon\nSUMME EUR 8,40\nMwSt 7%") == "de"
+        assert detect_receipt_language("SUBTOTAL 4.20\nTOTAL 4.20\nCHANGE 0.80") == "en"
+        assert detect_receipt_language("12345 67,89") is None
+
+    def test_receipt_config_allows_german_letters(self):
+        """Test that the whitelist keeps umlauts and ß on German receipts."""
+        config = receipt_config("deu")
+        assert "-l deu" in config
+        assert all(letter in config for letter in "ÄÖÜäöüß")
+
     def test_process_pdf_file(self):
         """Test the process_pdf_file function with mocked PyMuPDF and PIL."""
         sample_bytes = b"mock_pdf_data"
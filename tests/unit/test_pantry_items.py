@@ -0,0 +1,142 @@
+"""
+Testy /api/v1/pantry/items - lokalny magazyn spiżarni i pola, których Grocy
+nie przechowuje (notatki, atrybuty, produkt stały, historia scalania).
+"""
+
+import asyncio
+
+from backend.api.v1.endpoints import pantry_items
+from backend.api.v1.endpoints.pantry_items import PantryItemCreate, PantryItemUpdate
+from backend.core import pantry_store
+
+
+class FakeGrocy:
+    def __init__(self):
+        self.items = {7: {"id": "7", "name": "Mleko", "quantity": 2.0, "unified_category": "Nabiał"}}
+
+    # Jak Grocy: /stock pomija produkty bez zapasu
+    async def get_pantry_items(self):
+        return [item for item in self.items.values() if item["quantity"] > 0]
+
+    async def get_pantry_item(self, product_id):
+        item = self.items.get(product_id)
+        return dict(item) if item and item["quantity"] > 0 else None
+
+    async def rename_product(self, product_id, name):
+        self.items[product_id]["name"] = name
+
+    async def set_stock_amount(self, product_id, amount):
+        self.items[product_id]["quantity"] = amount
+
+
+def _local_mode(monkeypatch, tmp_path):
+    monkeypatch.setattr(pantry_store, "PANTRY_STORE_PATH", str(tmp_path / "pantry_items.json"))
+    monkeypatch.setattr(pantry_items, "is_grocy_enabled", lambda: False)
+
+
+def _grocy_mode(monkeypatch, tmp_path):
+    grocy = FakeGrocy()
+    monkeypatch.setattr(pantry_store, "PANTRY_STORE_PATH", str(tmp_path / "pantry_items.json"))
+    monkeypatch.setattr(pantry_items, "is_grocy_enabled", lambda: True)
+    monkeypatch.setattr(pantry_items, "get_grocy_client", lambda: grocy)
+    return grocy
+
+
+def test_local_mode_serves_items_without_grocy(monkeypatch, tmp_path):
+    _local_mode(monkeypatch, tmp_path)
+
+    created = asyncio.run(pantry_items.add_pantry_item(PantryItemCreate(name="Ryż", unit="kg")))
+
+    assert asyncio.run(pantry_items.list_pantry_items()) == [created]
+    assert created["unified_category"] == "Nieskategoryzowane"
+
+
+def test_notes_and_attributes_round_trip_locally(monkeypatch, tmp_path):
+    _local_mode(monkeypatch, tmp_path)
+    created = asyncio.run(pantry_items.add_pantry_item(PantryItemCreate(name="Ryż")))
+
+    asyncio.run(
+        pantry_items.update_pantry_item(
+            created["id"], PantryItemUpdate(notes="z Lidla", attributes={"dieta": "bezglutenowe"})
+        )
+    )
+
+    [item] = asyncio.run(pantry_items.list_pantry_items())
+    assert item["notes"] == "z Lidla"
+    assert item["attributes"] == {"dieta": "bezglutenowe"}
+
+
+def test_merge_keeps_history_and_can_be_rolled_back(monkeypatch, tmp_path):
+    _local_mode(monkeypatch, tmp_path)
+    kept = asyncio.run(pantry_items.add_pantry_item(PantryItemCreate(name="Mąka", quantity=1)))
+    origin = [{"id": "x", "name": "Mąka pszenna", "quantity": 0.5}]
+
+    merged = asyncio.run(
+        pantry_items.update_pantry_item(
+            kept["id"],
+            PantryItemUpdate(quantity=1.5, notes="tortowa", staple=True, merged_from=origin),
+        )
+    )
+    assert merged["merged_from"] == origin
+    assert merged["notes"] == "tortowa"
+
+    # Cofnięcie scalania wysyła poprzednie wartości, a null dla pól, których nie było
+    rolled_back = asyncio.run(
+        pantry_items.update_pantry_item(
+            kept["id"], PantryItemUpdate(quantity=1, notes=None, staple=None, merged_from=None)
+        )
+    )
+    assert rolled_back == kept
+
+
+def test_delete_removes_local_item(monkeypatch, tmp_path):
+    _local_mode(monkeypatch, tmp_path)
+    created = asyncio.run(pantry_items.add_pantry_item(PantryItemCreate(name="Sól")))
+
+    asyncio.run(pantry_items.delete_pantry_item(created["id"]))
+
+    assert asyncio.run(pantry_items.list_pantry_items()) == []
+
+
+def test_grocy_mode_keeps_unsupported_fields_locally(monkeypatch, tmp_path):
+    grocy = _grocy_mode(monkeypatch, tmp_path)
+
+    updated = asyncio.run(
+        pantry_items.update_pantry_item("7", PantryItemUpdate(quantity=1, notes="otwarte", staple=True))
+    )
+
+    assert grocy.items[7]["quantity"] == 1
+    assert "notes" not in grocy.items[7]
+    assert updated["notes"] == "otwarte"
+    [item] = asyncio.run(pantry_items.list_pantry_items())
+    assert item["notes"] == "otwarte"
+    assert item["staple"] is True
+
+
+def test_staple_flag_round_trips_locally(monkeypatch, tmp_path):
+    _local_mode(monkeypatch, tmp_path)
+    created = asyncio.run(pantry_items.add_pantry_item(PantryItemCreate(name="Mleko")))
+
+    updated = asyncio.run(pantry_items.update_pantry_item(created["id"], PantryItemUpdate(staple=True)))
+
+    assert updated["staple"] is True
+    assert asyncio.run(pantry_items.list_pantry_items())[0]["staple"] is True
+
+
+def test_grocy_staple_used_up_comes_back_with_zero_quantity(monkeypatch, tmp_path):
+    _grocy_mode(monkeypatch, tmp_path)
+    asyncio.run(pantry_items.update_pantry_item("7", PantryItemUpdate(staple=True)))
+
+    updated = asyncio.run(pantry_items.update_pantry_item("7", PantryItemUpdate(quantity=0)))
+
+    assert updated["quantity"] == 0
+    assert updated["staple"] is True
+
+
+def test_grocy_mode_drops_local_fields_when_stock_is_consumed(monkeypatch, tmp_path):
+    _grocy_mode(monkeypatch, tmp_path)
+    asyncio.run(pantry_items.update_pantry_item("7", PantryItemUpdate(notes="otwarte")))
+
+    asyncio.run(pantry_items.delete_pantry_item("7"))
+
+    assert pantry_store.get_extras("7") == {}
@@ -300,6 +300,83 @@ class TestReceiptAnalysisAgentEnhanced:
             assert "total_price" in item
             assert "vat_rate" in item
 
+    def test_weighted_line_recognition(self, agent):
+        """Test rozpoznawania pozycji ważonych w fallback parserze."""
+        ocr_text = """
+        BANANY LUZ 0,436 kg x 9,99 zł/kg 4,36 C
+        Chleb razowy 2,00 x 3,50 7,00 A
+        """
+
+        result = agent._fallback_parse(ocr_text)
+        bananas = result["items"][0]
+
+        assert bananas["name"] == "BANANY LUZ"
+        assert bananas["quantity"] == 0.436
+        assert bananas["unit"] == "kg"
+        assert bananas["unit_price"] == 9.99
+        assert bananas["total_price"] == 4.36
+        assert bananas["sold_by_weight"] is True
+        assert not any(
+            item.get("sold_by_weight") for item in result["items"][1:]
+        ), "Sztuki bez 'kg' nie są wagą"
+
+    def test_weighted_item_normalization(self, agent):
+        """Test przeliczania pozycji ważonych z LLM na kilogramy."""
+        data = {
+            "store_name": "Lidl",
+            "items": [
+                {
+                    "name": "Pomidory",
+                    "original_name": "POMIDORY 0,512 kg x 7,99 zł/kg",
+                    "quantity": 1,
+                    "unit": "szt",
+                    "unit_price": 4.09,
+                    "total_price": 4.09,
+                },
+                {
+                    "name": "Ser żółty",
+                    "quantity": 250,
+                    "unit": "g",
+                    "unit_price": 0.01,
+                    "total_price": 9.98,
+                },
+                {
+                    "name": "Jabłka",
+                    "quantity": 1.25,
+                    "unit": "szt",
+                    "unit_price": 3.99,
+                    "total_price": 4.99,
+                },
+                {
+                    "name": "Mleko",
+                    "quantity": 2,
+                    "unit": "szt",
+                    "unit_price": 4.99,
+                    "total_price": 9.98,
+                },
+            ],
+        }
+
+        items = agent._validate_and_fix_data(data)["items"]
+
+        assert [(i["quantity"], i["unit"], i["unit_price"]) for i in items] == [
+            (0.512, "kg", 7.99),
+            (0.25, "kg", 39.92),
+            (1.25, "kg", 3.99),
+            (2.0, "szt", 4.99),
+        ]
+        assert [i["sold_by_weight"] for i in items] == [True, True, True, False]
+
+    def test_prompt_language_hint(self, agent):
+        """Test podpowiedzi języka dla zagranicznych paragonów."""
+        polish = agent._create_receipt_analysis_prompt("SUMA 4,99", "pl")
+        german = agent._create_receipt_analysis_prompt("SUMME 4,99", "de")
+
+        assert "z polskiego sklepu" in polish
+        assert "niemieckim" not in polish
+        assert "Paragon jest w języku niemieckim" in german
+        assert "z polskiego sklepu" not in german
+
     @pytest.mark.asyncio
     async def test_complete_receipt_processing_workflow(self, agent, mock_llm_response):
         """Test kompletnego workflow przetwarzania paragonu."""
@@ -0,0 +1,57 @@
+"""
+Testy wymuszonego języka odpowiedzi asystenta dla rozmowy.
+"""
+
+import pytest
+
+from backend.agents.chef_agent import ChefAgent
+from backend.agents.prompts import (get_reply_language_instruction,
+                                    reply_language_messages)
+
+
+def test_english_override_instructs_model_to_reply_in_english():
+    instruction = get_reply_language_instruction("en")
+
+    assert instruction is not None
+    assert "English" in instruction
+
+
+def test_polish_override_instructs_model_to_reply_in_polish():
+    assert "po polsku" in get_reply_language_instruction("pl")
+
+
+def test_no_override_adds_no_instruction():
+    assert get_reply_language_instruction(None) is None
+    assert get_reply_language_instruction("de") is None
+
+
+def test_reply_language_messages_is_empty_without_override():
+    assert reply_language_messages(None) == []
+    assert reply_language_messages("en") == [
+        {"role": "system", "content": get_reply_language_instruction("en")}
+    ]
+
+
+@pytest.mark.asyncio
+async def test_chef_agent_passes_reply_language_to_the_model():
+    class FakeLLM:
+        messages = None
+
+        async def chat(self, model, messages, stream):
+            FakeLLM.messages = messages
+
+            async def chunks():
+                yield {"message": {"content": "Omelette"}}
+
+            return chunks()
+
+    agent = ChefAgent()
+    agent.llm_client = FakeLLM()
+
+    response = await agent.process(
+        {"available_ingredients": ["jajka"], "language": "en"}
+    )
+    assert [chunk async for chunk in response.text_stream] == ["Omelette"]
+
+    instruction = get_reply_language_instruction("en")
+    assert {"role": "system", "content": instruction} in FakeLLM.messages
@@ -0,0 +1,33 @@
+"""
+Testy GET /api/food/shopping-trips/{id} - paragon otwierany z linku spoza
+ostatnich paragonów w historii.
+"""
+
+import asyncio
+
+import pytest
+from fastapi import HTTPException
+
+from backend.api import food
+
+
+def test_read_shopping_trip_returns_the_trip(monkeypatch):
+    trip = {"id": 7, "store_name": "Lidl"}
+
+    async def fake_get(db, trip_id):
+        return trip if trip_id == 7 else None
+
+    monkeypatch.setattr(food.shopping_service, "get_shopping_trip", fake_get)
+
+    assert asyncio.run(food.read_shopping_trip(7, db=None)) == trip
+
+
+def test_read_shopping_trip_missing_is_404(monkeypatch):
+    async def fake_get(db, trip_id):
+        return None
+
+    monkeypatch.setattr(food.shopping_service, "get_shopping_trip", fake_get)
+
+    with pytest.raises(HTTPException) as exc:
+        asyncio.run(food.read_shopping_trip(99, db=None))
+    assert exc.value.status_code == 404
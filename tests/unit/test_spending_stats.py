@@ -0,0 +1,66 @@
+"""
+Testy agregacji statystyk wydatków (summarize_spending).
+"""
+
+from datetime import date
+from types import SimpleNamespace
+
+from backend.services.shopping_service import summarize_spending
+
+
+def _product(category, unit_price, quantity=1.0):
+    return SimpleNamespace(category=category, unit_price=unit_price, quantity=quantity)
+
+
+def _trip(trip_date, store_name, products, total_amount=None):
+    return SimpleNamespace(
+        trip_date=trip_date,
+        store_name=store_name,
+        total_amount=total_amount,
+        products=products,
+    )
+
+
+def test_summarize_spending_groups_by_month_category_and_store():
+    trips = [
+        _trip(
+            date(2024, 1, 5),
+            "Lidl",
+            [_product("Nabiał", 4.99, 2), _product("Pieczywo", 3.50)],
+            total_amount=13.48,
+        ),
+        _trip(date(2024, 1, 20), "Biedronka", [_product("Nabiał", 5.00)]),
+        _trip(date(2024, 2, 1), "Lidl", [_product(None, 10.00)], total_amount=10.00),
+    ]
+
+    stats = summarize_spending(trips)
+
+    assert stats.receipts_count == 3
+    assert stats.total == 28.48
+    assert [(b.label, b.total, b.count) for b in stats.monthly] == [
+        ("2024-01", 18.48, 2),
+        ("2024-02", 10.00, 1),
+    ]
+    assert stats.by_store[0].label == "Lidl"
+    assert stats.by_store[0].total == 23.48
+    categories = {b.label: b.total for b in stats.by_category}
+    assert categories == {"Nabiał": 14.98, "Pieczywo": 3.50, "Inne": 10.00}
+
+
+def test_summarize_spending_counts_wasted_products():
+    wasted = [_product("Nabiał", 4.99, 2), _product("Pieczywo", 3.50)]
+
+    stats = summarize_spending([], date(2024, 1, 1), date(2024, 1, 31), wasted)
+
+    assert stats.wasted_count == 2
+    assert stats.wasted_total == 13.48
+
+
+def test_summarize_spending_empty():
+    stats = summarize_spending([], date(2024, 1, 1), date(2024, 1, 31))
+
+    assert stats.total == 0
+    assert stats.receipts_count == 0
+    assert stats.monthly == []
+    assert stats.wasted_count == 0
+    assert stats.date_from == date(2024, 1, 1)
@@ -0,0 +1,134 @@
+"""
+Testy synchronizacji listy zakupów z listą zadań (Todoist/CalDAV).
+"""
+
+import pytest
+
+from backend.core.ics_writer import build_todo
+from backend.core.task_sync import (
+    RemoteTask,
+    ShoppingItem,
+    SyncConflict,
+    caldav_tasks_from_report,
+    plan_sync,
+    resolve_conflicts,
+)
+
+
+def test_plan_sync_creates_new_items_and_completes_checked_ones():
+    items = [
+        ShoppingItem("a", "Mleko"),
+        ShoppingItem("b", "Chleb", checked=True, synced=True),
+        ShoppingItem("c", "Masło", checked=True),
+    ]
+    remote = [RemoteTask("b", "100", completed=False)]
+
+    plan = plan_sync(items, remote)
+
+    assert [item.id for item in plan.create] == ["a"]
+    assert [task.remote_id for task in plan.complete] == ["100"]
+    assert plan.check_locally == []
+
+
+def test_plan_sync_checks_off_items_closed_remotely():
+    items = [
+        ShoppingItem("a", "Mleko", synced=True),  # zamknięte w Todoist - znika z listy
+        ShoppingItem("b", "Chleb", synced=True),  # CalDAV zwraca STATUS:COMPLETED
+        ShoppingItem("c", "Masło", synced=True),
+    ]
+    remote = [
+        RemoteTask("b", "b@foodsave", completed=True),
+        RemoteTask("c", "c@foodsave", completed=False),
+    ]
+
+    plan = plan_sync(items, remote)
+
+    assert plan.create == []
+    assert plan.complete == []
+    assert plan.check_locally == ["a", "b"]
+
+
+def test_plan_sync_reopens_items_unchecked_locally():
+    items = [
+        ShoppingItem("a", "Mleko"),  # odznaczone w FoodSave - flaga synced wyczyszczona
+        ShoppingItem("b", "Chleb", synced=True),
+    ]
+    remote = [
+        RemoteTask("a", "a@foodsave", completed=True),
+        RemoteTask("b", "b@foodsave", completed=True),
+    ]
+
+    plan = plan_sync(items, remote)
+
+    assert [task.remote_id for task in plan.reopen] == ["a@foodsave"]
+    assert plan.check_locally == ["b"]
+
+
+def test_plan_sync_deletes_tasks_of_removed_items():
+    items = [ShoppingItem("b", "Chleb", synced=True)]
+    remote = [
+        RemoteTask("a", "1", completed=False),
+        RemoteTask("b", "2", completed=False),
+        RemoteTask("c", "3", completed=True),
+    ]
+
+    # "b" przywrócono przez Cofnij, "d" nigdy nie trafiło do listy zadań
+    plan = plan_sync(items, remote, removed=["a", "b", "c", "d"])
+
+    assert [task.remote_id for task in plan.delete] == ["1", "3"]
+    assert plan.create == []
+
+
+def test_caldav_report_keeps_only_foodsave_tasks():
+    done = build_todo("a@foodsave", "Mleko (2 l)", completed=True)
+    foreign = build_todo("inne-zadanie", "Zadzwonić do mamy")
+    report = f"""<?xml version="1.0"?>
+<d:multistatus xmlns:d="DAV:" xmlns:c="urn:ietf:params:xml:ns:caldav">
+  <d:response><d:propstat><d:prop><c:calendar-data>{done}</c:calendar-data></d:prop></d:propstat></d:response>
+  <d:response><d:propstat><d:prop><c:calendar-data>{foreign}</c:calendar-data></d:prop></d:propstat></d:response>
+</d:multistatus>"""
+
+    tasks = caldav_tasks_from_report(report)
+
+    assert tasks == [RemoteTask("a", "a@foodsave", completed=True)]
+
+
+def test_plan_sync_reports_items_renamed_remotely():
+    items = [
+        ShoppingItem("a", "Mleko", quantity="2 l", synced=True),
+        ShoppingItem("b", "Chleb", synced=True),
+        ShoppingItem("c", "Masło", synced=True),
+        ShoppingItem("d", "Jajka", checked=True, synced=True),
+    ]
+    remote = [
+        RemoteTask("a", "1", completed=False, title="Mleko (2 l)"),
+        RemoteTask("b", "2", completed=False, title="Chleb żytni (1 szt.)"),
+        RemoteTask("c", "3", completed=False),  # dostawca bez tytułu
+        RemoteTask("d", "4", completed=True, title="Jajka L"),
+    ]
+
+    plan = plan_sync(items, remote)
+
+    assert plan.conflicts == [SyncConflict("b", "Chleb żytni", "1 szt.")]
+    assert plan.check_locally == []
+
+
+@pytest.mark.asyncio
+async def test_resolve_conflicts_updates_only_existing_tasks():
+    class FakeClient:
+        def __init__(self):
+            self.updated = []
+
+        async def list_tasks(self):
+            return [RemoteTask("a", "1", completed=False, title="Mleko 3,2%")]
+
+        async def update_task(self, task, item):
+            self.updated.append((task.remote_id, item.name))
+
+    client = FakeClient()
+    items = [ShoppingItem("a", "Mleko", quantity="2 l"), ShoppingItem("b", "Chleb")]
+
+    updated = await resolve_conflicts(client, items)
+
+    assert updated == ["a"]
+    assert client.updated == [("1", "Mleko")]
@@ -0,0 +1,28 @@
+"""
+Testy regułowej podpowiedzi ubioru (fallback, gdy LLM jest niedostępny).
+"""
+
+from backend.agents.weather_agent import clothing_hint_from_weather
+
+
+def test_cold_rainy_day_suggests_jacket_and_umbrella():
+    hint = clothing_hint_from_weather(
+        {"temp_c": 8, "feelslike_c": 5, "condition": "Light rain"},
+        {"chance_of_rain": 80},
+    )
+
+    assert "kurtka" in hint
+    assert "parasol" in hint
+
+
+def test_hot_dry_day_has_no_umbrella():
+    hint = clothing_hint_from_weather(
+        {"temp_c": 29, "condition": "Sunny"}, {"chance_of_rain": 0}
+    )
+
+    assert hint.startswith("Gorąco")
+    assert "parasol" not in hint
+
+
+def test_missing_temperature_falls_back_to_generic_hint():
+    assert clothing_hint_from_weather({}) == "Sprawdź prognozę przed wyjściem."
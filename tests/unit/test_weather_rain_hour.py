@@ -0,0 +1,24 @@
+"""
+Testy wyznaczania godziny, od której ma padać (podsumowanie dnia na dashboardzie).
+"""
+
+from backend.agents.weather_agent import first_rain_hour
+
+
+def test_first_hour_above_threshold_is_returned():
+    hours = [
+        ("2024-01-01 14:00", 10),
+        ("2024-01-01 15:00", 40),
+        ("2024-01-01 16:00", 75),
+        ("2024-01-01 17:00", 90),
+    ]
+
+    assert first_rain_hour(hours) == "16:00"
+
+
+def test_openweathermap_timestamps_are_trimmed():
+    assert first_rain_hour([("2024-01-01 15:00:00", 60.0)]) == "15:00"
+
+
+def test_dry_day_has_no_rain_hour():
+    assert first_rain_hour([("2024-01-01 12:00", 20), (None, 90)]) is None